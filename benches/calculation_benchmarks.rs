@@ -7,12 +7,18 @@
 //! - Batch of 100 timesheets: < 100ms mean
 //! - Batch of 1000 timesheets: < 500ms mean
 //!
+//! The `calculate_pay_throughput` group calls the engine directly, without
+//! HTTP, and reports timesheets per second for single-shift and 14-shift
+//! timesheets, both one at a time and spread across all available cores
+//! sharing one configuration, and one at a time with the audit trace
+//! suppressed.
+//!
 //! Run with: `cargo bench`
 //! HTML reports are generated in `target/criterion/`
 
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
 
-use award_engine::api::{create_router, AppState, CalculationRequest};
+use award_engine::api::{AppState, CalculationRequest, calculate_pay, create_router};
 use award_engine::config::ConfigLoader;
 
 use axum::{body::Body, http::Request};
@@ -306,6 +312,62 @@ fn bench_scaling(c: &mut Criterion) {
     group.finish();
 }
 
+/// Calculations per thread in each iteration of the parallel benchmark.
+const CALCULATIONS_PER_THREAD: usize = 100;
+
+/// Benchmark: Direct `calculate_pay` throughput, sequential and parallel.
+///
+/// Criterion reports the measured throughput in timesheets per second.
+fn bench_calculate_pay_throughput(c: &mut Criterion) {
+    let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+
+    let mut group = c.benchmark_group("calculate_pay_throughput");
+
+    for shift_count in [1, 14] {
+        let request = create_request_with_shifts(shift_count);
+
+        group.throughput(Throughput::Elements(1));
+        group.bench_with_input(
+            BenchmarkId::new("sequential", shift_count),
+            &request,
+            |b, request| b.iter(|| black_box(calculate_pay(request.clone(), &config).unwrap())),
+        );
+
+        // The same timesheet with the audit trace suppressed
+        let mut without_audit = request.clone();
+        without_audit.include_audit = false;
+        group.bench_with_input(
+            BenchmarkId::new("sequential_without_audit", shift_count),
+            &without_audit,
+            |b, request| b.iter(|| black_box(calculate_pay(request.clone(), &config).unwrap())),
+        );
+
+        // Every thread borrows the same configuration
+        let calculations = threads * CALCULATIONS_PER_THREAD;
+        group.throughput(Throughput::Elements(calculations as u64));
+        group.bench_with_input(
+            BenchmarkId::new(format!("parallel_{}_threads", threads), shift_count),
+            &request,
+            |b, request| {
+                b.iter(|| {
+                    std::thread::scope(|scope| {
+                        for _ in 0..threads {
+                            scope.spawn(|| {
+                                for _ in 0..CALCULATIONS_PER_THREAD {
+                                    black_box(calculate_pay(request.clone(), &config).unwrap());
+                                }
+                            });
+                        }
+                    })
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_single_shift,
@@ -313,5 +375,6 @@ criterion_group!(
     bench_batch_100,
     bench_batch_1000,
     bench_scaling,
+    bench_calculate_pay_throughput,
 );
 criterion_main!(benches);
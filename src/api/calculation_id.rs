@@ -0,0 +1,145 @@
+//! Deterministic calculation IDs.
+//!
+//! A calculation normally gets a random (v4) `calculation_id`. A request can
+//! instead ask for an ID derived from its inputs, so that submitting the same
+//! request twice yields the same ID and downstream systems can deduplicate
+//! results.
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::config::ConfigLoader;
+
+use super::request::CalculationRequest;
+
+/// The UUIDv5 namespace deterministic calculation IDs are derived in.
+pub const CALCULATION_ID_NAMESPACE: Uuid = Uuid::from_u128(0xd0722e82_f947_4f3a_9143_31634bebea5b);
+
+/// Derives a UUIDv5 `calculation_id` from a request and the configuration it
+/// is calculated under.
+///
+/// The ID hashes a canonical JSON form of the request's employee, pay period,
+/// shifts (sorted by start time), timezone and state, together with the award
+/// code and the whole loaded configuration: award metadata, classifications,
+/// every rate file, penalties and the holiday calendar. Any change to the
+/// configuration, such as a new rate file or a changed penalty multiplier,
+/// therefore yields a different ID for the same request, even when the award
+/// version is unchanged. `include_audit` only changes what is reported, and
+/// `partial_results` only whether a failing shift fails the request, so both
+/// are left out. `amounts_in_cents` is only hashed when set, as it changes how
+/// the rate overrides are read; requests in dollars keep their IDs.
+pub fn deterministic_calculation_id(request: &CalculationRequest, config: &ConfigLoader) -> Uuid {
+    let mut shifts: Vec<_> = request.shifts.iter().collect();
+    shifts.sort_by(|a, b| (a.start_time, &a.id).cmp(&(b.start_time, &b.id)));
+
+    // serde_json objects are key-sorted, so equal inputs serialize identically
+    let mut canonical = json!({
+        "award_code": config.award().code,
+        "config": {
+            "award": config.config(),
+            "holidays": config.holiday_calendar(),
+        },
+        "request": {
+            "employee": request.employee,
+            "pay_period": request.pay_period,
+            "shifts": shifts,
+            "timezone": request.timezone,
+            "state": request.state,
+        },
+    });
+    if request.amounts_in_cents {
+        canonical["request"]["amounts_in_cents"] = json!(true);
+    }
+
+    Uuid::new_v5(&CALCULATION_ID_NAMESPACE, canonical.to_string().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AwardConfig;
+    use rust_decimal::Decimal;
+
+    fn request() -> CalculationRequest {
+        CalculationRequest::from_json(json!({
+            "employee": {
+                "id": "emp_001",
+                "employment_type": "full_time",
+                "classification_code": "dce_level_3",
+                "date_of_birth": "1990-01-15",
+                "employment_start_date": "2023-06-01"
+            },
+            "pay_period": { "start_date": "2026-01-12", "end_date": "2026-01-18" },
+            "shifts": [
+                {
+                    "id": "shift_001",
+                    "date": "2026-01-13",
+                    "start_time": "2026-01-13T09:00:00",
+                    "end_time": "2026-01-13T17:00:00"
+                },
+                {
+                    "id": "shift_002",
+                    "date": "2026-01-14",
+                    "start_time": "2026-01-14T09:00:00",
+                    "end_time": "2026-01-14T17:00:00"
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn load_config() -> ConfigLoader {
+        ConfigLoader::load("./config/ma000018").expect("Failed to load config")
+    }
+
+    #[test]
+    fn test_identical_requests_yield_identical_ids() {
+        let config = load_config();
+
+        let first = deterministic_calculation_id(&request(), &config);
+        let second = deterministic_calculation_id(&request(), &config);
+
+        assert_eq!(first, second);
+        assert_eq!(first.get_version_num(), 5);
+
+        // Shift order and include_audit do not change the ID
+        let mut reordered = request();
+        reordered.shifts.reverse();
+        reordered.include_audit = false;
+        assert_eq!(deterministic_calculation_id(&reordered, &config), first);
+
+        // A different shift does
+        let mut changed = request();
+        changed.shifts[0].id = "shift_003".to_string();
+        assert_ne!(deterministic_calculation_id(&changed, &config), first);
+
+        // So does reading the amounts as cents
+        let mut cents = request();
+        cents.amounts_in_cents = true;
+        assert_ne!(deterministic_calculation_id(&cents, &config), first);
+    }
+
+    #[test]
+    fn test_rate_change_yields_a_different_id() {
+        let config = ConfigLoader::from_config(load_config().config().clone());
+        let mut rates = config.config().rates().to_vec();
+        let rate = rates
+            .last_mut()
+            .unwrap()
+            .rates
+            .get_mut("dce_level_3")
+            .unwrap();
+        rate.hourly += Decimal::new(1, 2);
+        let changed = ConfigLoader::from_config(AwardConfig::new(
+            config.award().clone(),
+            config.config().classifications().clone(),
+            rates,
+            config.config().penalties().clone(),
+        ));
+
+        assert_ne!(
+            deterministic_calculation_id(&request(), &changed),
+            deterministic_calculation_id(&request(), &config)
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Clocks for stamping calculation results.
+//!
+//! A result records when it was calculated (`timestamp`) and how long it took
+//! (`audit_trace.duration_us`). Both are read from a [`Clock`], so tests and
+//! replays can pin them with a [`FixedClock`] and produce a byte-for-byte
+//! repeatable result (together with `deterministic_id`).
+
+use std::fmt;
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+
+/// A source of the time a calculation was performed and how long it took.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current time, recorded as the result's `timestamp`.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Returns the microseconds elapsed since `start`, recorded as the
+    /// result's `duration_us`.
+    fn elapsed_us(&self, start: Instant) -> u64;
+}
+
+/// The system clock, used unless another clock is configured.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn elapsed_us(&self, start: Instant) -> u64 {
+        start.elapsed().as_micros() as u64
+    }
+}
+
+/// A clock that always reports the same time and duration.
+///
+/// # Example
+///
+/// ```
+/// use award_engine::api::{Clock, FixedClock};
+/// use chrono::{TimeZone, Utc};
+/// use std::time::Instant;
+///
+/// let timestamp = Utc.with_ymd_and_hms(2026, 1, 19, 9, 0, 0).unwrap();
+/// let clock = FixedClock::new(timestamp, 250);
+///
+/// assert_eq!(clock.now(), timestamp);
+/// assert_eq!(clock.elapsed_us(Instant::now()), 250);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    /// The time reported as every result's `timestamp`.
+    pub timestamp: DateTime<Utc>,
+    /// The duration reported as every result's `duration_us`.
+    pub duration_us: u64,
+}
+
+impl FixedClock {
+    /// Creates a clock that reports `timestamp` and `duration_us`.
+    pub fn new(timestamp: DateTime<Utc>, duration_us: u64) -> Self {
+        Self {
+            timestamp,
+            duration_us,
+        }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn elapsed_us(&self, _start: Instant) -> u64 {
+        self.duration_us
+    }
+}
@@ -0,0 +1,125 @@
+//! Request correlation IDs.
+//!
+//! Every request is given a correlation ID that is recorded on each log
+//! event it produces, returned in the `X-Correlation-Id` response header and
+//! set on any calculation result. A client can send its own ID in the
+//! `X-Correlation-Id` request header to trace a payroll run end to end;
+//! otherwise the engine generates one.
+
+use std::fmt;
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::warn;
+use uuid::Uuid;
+
+/// The header a correlation ID is read from and returned in.
+pub const CORRELATION_ID_HEADER: HeaderName = HeaderName::from_static("x-correlation-id");
+
+/// The longest correlation ID accepted from a client.
+pub const MAX_CORRELATION_ID_LENGTH: usize = 128;
+
+/// The correlation ID of the request being handled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CorrelationId(String);
+
+impl CorrelationId {
+    /// Generates a new random correlation ID.
+    fn generate() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+
+    /// Returns the correlation ID as a string.
+    pub(super) fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CorrelationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Returns the client's correlation ID if it is one the engine accepts.
+///
+/// The ID is written to every log event for the request, so only IDs of 1
+/// to [`MAX_CORRELATION_ID_LENGTH`] ASCII letters, digits, `-`, `_`, `.` or
+/// `:` are accepted. Anything else, such as a value containing a newline or
+/// control characters, could forge or break log lines.
+fn accepted_correlation_id(value: &HeaderValue) -> Option<CorrelationId> {
+    let value = value.to_str().ok()?;
+    let valid = !value.is_empty()
+        && value.len() <= MAX_CORRELATION_ID_LENGTH
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'));
+    valid.then(|| CorrelationId(value.to_string()))
+}
+
+/// Middleware that gives each request a correlation ID.
+///
+/// The ID is taken from the `X-Correlation-Id` request header when it is
+/// acceptable, and generated otherwise; an unacceptable header is logged and
+/// replaced rather than failing the request. Handlers read the ID from the
+/// request extensions, and it is returned in the `X-Correlation-Id` response
+/// header.
+pub(super) async fn propagate_correlation_id(mut request: Request, next: Next) -> Response {
+    let correlation_id = match request.headers().get(&CORRELATION_ID_HEADER) {
+        Some(value) => accepted_correlation_id(value).unwrap_or_else(|| {
+            let correlation_id = CorrelationId::generate();
+            // The rejected value is not logged, as it may be the injection
+            warn!(
+                correlation_id = %correlation_id,
+                header_length = value.len(),
+                "Ignoring invalid X-Correlation-Id header"
+            );
+            correlation_id
+        }),
+        None => CorrelationId::generate(),
+    };
+    let header_value =
+        HeaderValue::from_str(correlation_id.as_str()).expect("correlation IDs are ASCII");
+    request.extensions_mut().insert(correlation_id);
+
+    let mut response = next.run(request).await;
+    response
+        .headers_mut()
+        .insert(CORRELATION_ID_HEADER, header_value);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accepted(value: &str) -> Option<String> {
+        accepted_correlation_id(&HeaderValue::from_str(value).unwrap())
+            .map(|id| id.as_str().to_string())
+    }
+
+    #[test]
+    fn test_client_ids_are_accepted() {
+        assert_eq!(
+            accepted("payrun-2026-01:emp_001"),
+            Some("payrun-2026-01:emp_001".to_string())
+        );
+        assert_eq!(
+            accepted(&"a".repeat(MAX_CORRELATION_ID_LENGTH)).map(|id| id.len()),
+            Some(MAX_CORRELATION_ID_LENGTH)
+        );
+    }
+
+    #[test]
+    fn test_unsafe_ids_are_rejected() {
+        assert_eq!(accepted(""), None);
+        assert_eq!(accepted("run 1"), None);
+        assert_eq!(accepted("run1\" level=error"), None);
+        assert_eq!(accepted(&"a".repeat(MAX_CORRELATION_ID_LENGTH + 1)), None);
+        assert!(accepted_correlation_id(&HeaderValue::from_bytes(b"run\xff1").unwrap()).is_none());
+    }
+}
@@ -0,0 +1,402 @@
+//! Comparison of two calculation results for the same roster.
+//!
+//! This module backs `POST /calculate/diff`, which calculates one request
+//! under two award configurations (e.g. the current and a newly loaded award
+//! version) so payroll can see how pay changes, and
+//! `POST /calculate/compare-employment`, which calculates it under two
+//! employment types to model the cost of a casual becoming permanent or the
+//! reverse. For that comparison it can also model a hypothetical casual
+//! loading on part-time hours, which is never paid.
+
+use std::collections::{HashMap, VecDeque};
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::calculation::casual_loading_multiplier;
+use crate::models::{CalculationResult, EmploymentType, PayCategory, PayLine};
+
+/// An old and new value and the difference between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecimalDelta {
+    /// The value in the first result (under the old award, or the employee's
+    /// own employment type).
+    pub old: Decimal,
+    /// The value in the second result (under the new award, or the compared
+    /// employment type).
+    pub new: Decimal,
+    /// `new - old`.
+    pub delta: Decimal,
+}
+
+impl DecimalDelta {
+    fn new(old: Decimal, new: Decimal) -> Self {
+        Self {
+            old,
+            new,
+            delta: new - old,
+        }
+    }
+}
+
+/// The change to each pay total.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TotalsDiff {
+    /// The change in gross pay.
+    pub gross_pay: DecimalDelta,
+    /// The change in ordinary hours.
+    pub ordinary_hours: DecimalDelta,
+    /// The change in overtime hours.
+    pub overtime_hours: DecimalDelta,
+    /// The change in penalty hours.
+    pub penalty_hours: DecimalDelta,
+    /// The change in total allowances.
+    pub allowances_total: DecimalDelta,
+}
+
+impl TotalsDiff {
+    /// Compares the totals of two results.
+    fn between(old: &CalculationResult, new: &CalculationResult) -> Self {
+        Self {
+            gross_pay: DecimalDelta::new(old.totals.gross_pay, new.totals.gross_pay),
+            ordinary_hours: DecimalDelta::new(old.totals.ordinary_hours, new.totals.ordinary_hours),
+            overtime_hours: DecimalDelta::new(old.totals.overtime_hours, new.totals.overtime_hours),
+            penalty_hours: DecimalDelta::new(old.totals.penalty_hours, new.totals.penalty_hours),
+            allowances_total: DecimalDelta::new(
+                old.totals.allowances_total,
+                new.totals.allowances_total,
+            ),
+        }
+    }
+}
+
+/// How a pay line differs between the two results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayLineChange {
+    /// The line has the same hours, rate and amount in both results.
+    Unchanged,
+    /// The line is in both results with different hours, rate or amount.
+    Changed,
+    /// The line is only in the new result.
+    Added,
+    /// The line is only in the old result.
+    Removed,
+}
+
+/// The difference for one pay line.
+///
+/// Lines are matched by date, shift and pay category. A line missing from one
+/// result counts as zero hours, rate and amount on that side.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayLineDiff {
+    /// The date the pay line applies to.
+    pub date: NaiveDate,
+    /// The ID of the shift the pay line originated from.
+    pub shift_id: String,
+    /// The category of pay.
+    pub category: PayCategory,
+    /// How the line changed.
+    pub change: PayLineChange,
+    /// The change in hours.
+    pub hours: DecimalDelta,
+    /// The change in hourly rate.
+    pub rate: DecimalDelta,
+    /// The change in amount.
+    pub amount: DecimalDelta,
+}
+
+/// The response for `POST /calculate/diff`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalculationDiff {
+    /// The award code the roster was first calculated under.
+    pub old_award: String,
+    /// The award code the roster was then calculated under.
+    pub new_award: String,
+    /// The result under the old award.
+    pub old: CalculationResult,
+    /// The result under the new award.
+    pub new: CalculationResult,
+    /// The change to each pay total.
+    pub totals: TotalsDiff,
+    /// The difference for every pay line in either result, in payslip order.
+    pub pay_lines: Vec<PayLineDiff>,
+}
+
+impl CalculationDiff {
+    /// Compares the results of calculating one roster under two awards.
+    pub fn new(
+        old_award: impl Into<String>,
+        new_award: impl Into<String>,
+        old: CalculationResult,
+        new: CalculationResult,
+    ) -> Self {
+        let totals = TotalsDiff::between(&old, &new);
+        let pay_lines = diff_pay_lines(&old.pay_lines, &new.pay_lines);
+
+        Self {
+            old_award: old_award.into(),
+            new_award: new_award.into(),
+            old,
+            new,
+            totals,
+            pay_lines,
+        }
+    }
+}
+
+/// The response for `POST /calculate/compare-employment`.
+///
+/// The totals compare the employee's own employment type (`old`) with the
+/// compared type (`new`), so a positive gross pay delta means the roster
+/// costs more under the compared type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmploymentComparison {
+    /// The employee's own employment type.
+    pub employment_type: EmploymentType,
+    /// The employment type the roster was also calculated under.
+    pub compare_employment_type: EmploymentType,
+    /// The result under the employee's own employment type.
+    pub result: CalculationResult,
+    /// The result under the compared employment type.
+    pub compare_result: CalculationResult,
+    /// The change to each pay total.
+    pub totals: TotalsDiff,
+    /// The part-time side's ordinary hours with a hypothetical casual loading;
+    /// only present when requested with `hypothetical_part_time_loading`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hypothetical_part_time_loading: Option<HypotheticalPartTimeLoading>,
+}
+
+impl EmploymentComparison {
+    /// Compares the results of calculating one roster under two employment
+    /// types.
+    pub fn new(
+        employment_type: EmploymentType,
+        compare_employment_type: EmploymentType,
+        result: CalculationResult,
+        compare_result: CalculationResult,
+    ) -> Self {
+        Self {
+            employment_type,
+            compare_employment_type,
+            totals: TotalsDiff::between(&result, &compare_result),
+            result,
+            compare_result,
+            hypothetical_part_time_loading: None,
+        }
+    }
+
+    /// Adds the hypothetical casual loading of the part-time side of the
+    /// comparison; a comparison with no part-time side is left unchanged.
+    pub fn with_hypothetical_part_time_loading(mut self) -> Self {
+        let part_time_result = if self.employment_type == EmploymentType::PartTime {
+            Some(&self.result)
+        } else if self.compare_employment_type == EmploymentType::PartTime {
+            Some(&self.compare_result)
+        } else {
+            None
+        };
+        self.hypothetical_part_time_loading =
+            part_time_result.map(HypotheticalPartTimeLoading::from_result);
+        self
+    }
+}
+
+/// What a part-time employee's ordinary hours would cost with the casual
+/// loading, for comparing against a casual's cost.
+///
+/// This is a modelling figure only. Part-time employees are not paid the
+/// casual loading, and the part-time result it is derived from is calculated
+/// without it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HypotheticalPartTimeLoading {
+    /// States that the figures are hypothetical and not pay.
+    pub note: String,
+    /// The ordinary hours in the part-time result.
+    pub ordinary_hours: Decimal,
+    /// The part-time ordinary rate, averaged over the ordinary hours when the
+    /// rate changes within the pay period.
+    pub base_rate: Decimal,
+    /// The casual loading multiplier applied (1.25).
+    pub casual_loading: Decimal,
+    /// `base_rate × casual_loading`.
+    pub loaded_rate: Decimal,
+    /// The ordinary pay in the part-time result.
+    pub ordinary_pay: Decimal,
+    /// The ordinary pay with the casual loading applied.
+    pub loaded_ordinary_pay: Decimal,
+}
+
+impl HypotheticalPartTimeLoading {
+    /// Applies the casual loading to the ordinary pay lines of a part-time
+    /// result. Weekend, overtime and other pay lines are left out.
+    pub fn from_result(result: &CalculationResult) -> Self {
+        let ordinary_lines = result
+            .pay_lines
+            .iter()
+            .filter(|line| line.category == PayCategory::Ordinary);
+        let (ordinary_hours, ordinary_pay) = ordinary_lines
+            .fold((Decimal::ZERO, Decimal::ZERO), |(hours, pay), line| {
+                (hours + line.hours, pay + line.amount)
+            });
+        let base_rate = if ordinary_hours.is_zero() {
+            Decimal::ZERO
+        } else {
+            ordinary_pay / ordinary_hours
+        };
+        let casual_loading = casual_loading_multiplier();
+
+        Self {
+            note: "Hypothetical: part-time employees are not paid a casual loading; \
+                   these figures are for cost modelling only"
+                .to_string(),
+            ordinary_hours,
+            base_rate,
+            casual_loading,
+            loaded_rate: base_rate * casual_loading,
+            ordinary_pay,
+            loaded_ordinary_pay: ordinary_pay * casual_loading,
+        }
+    }
+}
+
+/// Matches pay lines by date, shift and category and compares each pair.
+///
+/// When several lines share a key they are paired in order.
+fn diff_pay_lines(old: &[PayLine], new: &[PayLine]) -> Vec<PayLineDiff> {
+    // Indexes of the old lines not yet matched, by key
+    let mut unmatched: HashMap<(NaiveDate, &str, PayCategory), VecDeque<usize>> = HashMap::new();
+    for (index, line) in old.iter().enumerate() {
+        unmatched
+            .entry((line.date, line.shift_id.as_str(), line.category))
+            .or_default()
+            .push_back(index);
+    }
+
+    let mut matched = vec![false; old.len()];
+    let mut diffs: Vec<PayLineDiff> = new
+        .iter()
+        .map(|line| {
+            let old_line = unmatched
+                .get_mut(&(line.date, line.shift_id.as_str(), line.category))
+                .and_then(VecDeque::pop_front)
+                .map(|index| {
+                    matched[index] = true;
+                    &old[index]
+                });
+            line_diff(old_line, Some(line))
+        })
+        .collect();
+    diffs.extend(
+        old.iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(line, _)| line_diff(Some(line), None)),
+    );
+
+    diffs.sort_by_key(|d| (d.date, d.category.ordinal()));
+    diffs
+}
+
+/// Compares a pair of matched pay lines, either of which may be absent.
+fn line_diff(old: Option<&PayLine>, new: Option<&PayLine>) -> PayLineDiff {
+    let line = new.or(old).expect("at least one pay line to compare");
+    let value = |line: Option<&PayLine>, field: fn(&PayLine) -> Decimal| {
+        line.map(field).unwrap_or(Decimal::ZERO)
+    };
+    let change = match (old, new) {
+        (Some(old), Some(new))
+            if old.hours == new.hours && old.rate == new.rate && old.amount == new.amount =>
+        {
+            PayLineChange::Unchanged
+        }
+        (Some(_), Some(_)) => PayLineChange::Changed,
+        (None, _) => PayLineChange::Added,
+        (_, None) => PayLineChange::Removed,
+    };
+
+    PayLineDiff {
+        date: line.date,
+        shift_id: line.shift_id.clone(),
+        category: line.category,
+        change,
+        hours: DecimalDelta::new(value(old, |l| l.hours), value(new, |l| l.hours)),
+        rate: DecimalDelta::new(value(old, |l| l.rate), value(new, |l| l.rate)),
+        amount: DecimalDelta::new(value(old, |l| l.amount), value(new, |l| l.amount)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn line(day: u32, category: PayCategory, hours: &str, rate: &str) -> PayLine {
+        PayLine {
+            date: NaiveDate::from_ymd_opt(2026, 1, day).unwrap(),
+            shift_id: format!("shift_{}", day),
+            category,
+            hours: dec(hours),
+            rate: dec(rate),
+            amount: dec(hours) * dec(rate),
+            clause_ref: "14.2".to_string(),
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_matched_lines_report_rate_changes() {
+        let old = vec![
+            line(13, PayCategory::Ordinary, "8", "28.54"),
+            line(14, PayCategory::Ordinary, "8", "28.54"),
+        ];
+        let new = vec![
+            line(13, PayCategory::Ordinary, "8", "29.40"),
+            line(14, PayCategory::Ordinary, "8", "28.54"),
+        ];
+
+        let diffs = diff_pay_lines(&old, &new);
+
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0].change, PayLineChange::Changed);
+        assert_eq!(diffs[0].rate.delta, dec("0.86"));
+        assert_eq!(diffs[0].amount.delta, dec("6.88"));
+        assert_eq!(diffs[1].change, PayLineChange::Unchanged);
+        assert_eq!(diffs[1].amount.delta, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_lines_in_only_one_result_are_added_or_removed() {
+        let old = vec![
+            line(13, PayCategory::Ordinary, "8", "28.54"),
+            line(13, PayCategory::Overtime150, "2", "42.81"),
+        ];
+        let new = vec![
+            line(13, PayCategory::Ordinary, "8", "28.54"),
+            line(13, PayCategory::Overtime200, "2", "57.08"),
+        ];
+
+        let diffs = diff_pay_lines(&old, &new);
+
+        let changes: Vec<(PayCategory, PayLineChange)> =
+            diffs.iter().map(|d| (d.category, d.change)).collect();
+        assert_eq!(
+            changes,
+            vec![
+                (PayCategory::Ordinary, PayLineChange::Unchanged),
+                (PayCategory::Overtime150, PayLineChange::Removed),
+                (PayCategory::Overtime200, PayLineChange::Added),
+            ]
+        );
+        assert_eq!(diffs[1].amount.new, Decimal::ZERO);
+        assert_eq!(diffs[1].amount.delta, dec("-85.62"));
+        assert_eq!(diffs[2].rate.old, Decimal::ZERO);
+    }
+}
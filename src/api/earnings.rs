@@ -0,0 +1,109 @@
+//! Flat earnings exports for payroll systems.
+//!
+//! This module backs `POST /calculate/earnings`, which calculates a request as
+//! `/calculate` does and returns the result as a flat list of earnings, one
+//! row per earning code and rate, ready for import into a payroll system. The
+//! codes come from the award's [`EarningCodes`], so the same calculation can
+//! be exported to whichever system the configuration targets.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::config::EarningCodes;
+use crate::models::CalculationResult;
+
+/// One row of an earnings export: the hours or units paid under an earning
+/// code at one rate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EarningLine {
+    /// The payroll system's earning code.
+    pub earning_code: String,
+    /// A description of the earning (e.g. `Overtime (150%)`).
+    pub description: String,
+    /// The hours (for pay lines) or units (for allowances) paid.
+    pub quantity: Decimal,
+    /// The rate paid per hour or unit.
+    pub rate: Decimal,
+    /// The amount paid.
+    pub amount: Decimal,
+}
+
+/// A calculation result as a flat list of earnings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EarningsExport {
+    /// The `calculation_id` of the result the earnings were taken from.
+    pub calculation_id: Uuid,
+    /// The employee the earnings were calculated for.
+    pub employee_id: String,
+    /// The start date of the pay period (inclusive).
+    pub pay_period_start: NaiveDate,
+    /// The end date of the pay period (inclusive).
+    pub pay_period_end: NaiveDate,
+    /// The earnings, pay lines first and then allowances, each in the order
+    /// its code first appears in the result.
+    pub earnings: Vec<EarningLine>,
+    /// The result's gross pay, which the earnings add up to.
+    pub gross_pay: Decimal,
+}
+
+impl EarningsExport {
+    /// Flattens a result into earnings using the given earning codes.
+    ///
+    /// Pay lines with the same earning code and rate are combined into one
+    /// row, adding their hours and amounts; lines paid at different rates
+    /// (e.g. a shift with a rate override) keep separate rows so each row's
+    /// quantity times its rate is its amount. Allowances are combined the
+    /// same way. A category or allowance type without a configured code is
+    /// exported under its snake_case name.
+    pub fn from_result(result: &CalculationResult, codes: &EarningCodes) -> Self {
+        let pay_lines = result.pay_lines.iter().map(|line| {
+            (
+                codes.pay_category_code(line.category),
+                line.category.to_string(),
+                line.hours,
+                line.rate,
+                line.amount,
+            )
+        });
+        let allowances = result.allowances.iter().map(|allowance| {
+            (
+                codes.allowance_code(&allowance.allowance_type),
+                allowance.description.clone(),
+                allowance.units,
+                allowance.rate,
+                allowance.amount,
+            )
+        });
+
+        let mut earnings: Vec<EarningLine> = Vec::new();
+        for (earning_code, description, quantity, rate, amount) in pay_lines.chain(allowances) {
+            match earnings
+                .iter_mut()
+                .find(|line| line.earning_code == earning_code && line.rate == rate)
+            {
+                Some(line) => {
+                    line.quantity += quantity;
+                    line.amount += amount;
+                }
+                None => earnings.push(EarningLine {
+                    earning_code,
+                    description,
+                    quantity,
+                    rate,
+                    amount,
+                }),
+            }
+        }
+
+        Self {
+            calculation_id: result.calculation_id,
+            employee_id: result.employee_id.clone(),
+            pay_period_start: result.pay_period.start_date,
+            pay_period_end: result.pay_period.end_date,
+            earnings,
+            gross_pay: result.totals.gross_pay,
+        }
+    }
+}
@@ -2,41 +2,96 @@
 //!
 //! This module contains the handler functions for all API endpoints.
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::Infallible;
 use std::time::Instant;
 
 use axum::{
-    extract::{rejection::JsonRejection, State},
-    http::{header, StatusCode},
-    response::IntoResponse,
-    routing::{get, post},
     Json, Router,
+    body::{Body, Bytes},
+    extract::{
+        DefaultBodyLimit, Extension, Query, State,
+        rejection::{JsonRejection, QueryRejection},
+    },
+    http::{HeaderMap, Method, StatusCode, Uri, header},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
 };
-use chrono::Utc;
-use rust_decimal::Decimal;
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Tz;
+use futures_util::stream;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde_json::Value;
 use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::calculation::{
-    calculate_laundry_allowance, calculate_ordinary_hours, calculate_saturday_pay,
+    DayType, LAUNDRY_ALLOWANCE_TAG, apply_time_rounding, apply_timezone, assign_weekend_days,
+    calculate_broken_shift_allowance, calculate_first_aid_allowance, calculate_laundry_allowance,
+    calculate_leave_accrual, calculate_minimum_daily_pay, calculate_ordinary_hours,
+    calculate_public_holiday_overtime, calculate_public_holidays_in_lieu,
+    calculate_public_holidays_not_worked, calculate_qualification_allowance,
+    calculate_saturday_pay, calculate_span_penalty, calculate_split_shift_travel,
     calculate_sunday_pay, calculate_weekday_overtime, calculate_weekend_overtime,
-    detect_daily_overtime, get_base_rate, get_day_type, segment_by_day, DayType,
-    DEFAULT_DAILY_OVERTIME_THRESHOLD,
+    check_early_commencement, check_employee_tags, check_meal_break_taken, detect_consecutive_days,
+    detect_cumulative_daily_overtime, detect_public_holidays, flag_shift_rate_override,
+    get_base_rate, get_shift_base_rate, is_worked_time, laundry_shift_excluded_step,
+    paid_public_holidays_not_worked, reconcile_shift_hours, round_shift_seconds, segment_by_day,
+    select_daily_threshold, unapplied_rules, week_key,
 };
+use crate::config::{ConfigLoader, ShiftSeconds};
+use crate::error::EngineError;
 use crate::models::{
-    AllowancePayment, AuditStep, AuditTrace, AuditWarning, CalculationResult, Employee,
-    PayCategory, PayLine, PayPeriod, PayTotals, Shift,
+    AllowancePayment, AuditStep, AuditTrace, AuditWarning, CENTS_PER_DOLLAR, CalculationInput,
+    CalculationResult, Employee, HOURS_PRECISION, InterpretedShift, OvertimeTier, PayCategory,
+    PayLine, PayPeriod, PayTotals, RESULT_SCHEMA_VERSION, Shift, ShiftResult, UnappliedRule,
 };
 
-use super::request::CalculationRequest;
-use super::response::{ApiError, ApiErrorResponse, HealthResponse, InfoResponse};
+use super::calculation_id::deterministic_calculation_id;
+use super::clock::{Clock, SystemClock};
+use super::correlation::{CorrelationId, propagate_correlation_id};
+use super::diff::{CalculationDiff, EmploymentComparison};
+use super::earnings::EarningsExport;
+use super::request::{
+    CalculateQuery, CalculationDiffRequest, CalculationRequest, EmploymentComparisonRequest,
+    RequestValidationError,
+};
+use super::response::{
+    ApiError, ApiErrorResponse, HealthResponse, InfoResponse, RulesResponse, SelfTestResponse,
+    ValidationResponse,
+};
 use super::state::AppState;
+use super::upload::{
+    EmployeeUploadError, NDJSON_CONTENT_TYPE, RosterUpload, RosterUploadLine, RosterUploadQuery,
+    RosterUploadResponse, parse_roster_csv, roster_upload_lines,
+};
+use super::validation::validate_request;
+
+/// The largest roster CSV `/calculate/upload` accepts, in bytes.
+const MAX_UPLOAD_BYTES: usize = 32 * 1024 * 1024;
 
 /// Creates the API router with all endpoints.
 pub fn create_router(state: AppState) -> Router {
     Router::new()
         .route("/calculate", post(calculate_handler))
+        .route("/calculate/diff", post(calculate_diff_handler))
+        .route(
+            "/calculate/compare-employment",
+            post(compare_employment_handler),
+        )
+        .route("/calculate/earnings", post(calculate_earnings_handler))
+        .route(
+            "/calculate/upload",
+            post(calculate_upload_handler).layer(DefaultBodyLimit::max(MAX_UPLOAD_BYTES)),
+        )
+        .route("/validate", post(validate_handler))
         .route("/health", get(health_handler))
         .route("/info", get(info_handler))
+        .route("/rules", get(rules_handler))
+        .route("/selftest", post(selftest_handler))
+        .method_not_allowed_fallback(method_not_allowed_handler)
+        .layer(middleware::from_fn(propagate_correlation_id))
         .with_state(state)
 }
 
@@ -44,7 +99,10 @@ pub fn create_router(state: AppState) -> Router {
 ///
 /// Returns the health status and version of the service.
 /// Returns 200 OK when healthy, 503 Service Unavailable when unhealthy.
-async fn health_handler(State(state): State<AppState>) -> impl IntoResponse {
+async fn health_handler(
+    State(state): State<AppState>,
+    Extension(correlation_id): Extension<CorrelationId>,
+) -> impl IntoResponse {
     // Verify configuration is available by attempting to access it
     let config_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         let _ = state.config().config();
@@ -54,7 +112,7 @@ async fn health_handler(State(state): State<AppState>) -> impl IntoResponse {
         Ok(_) => {
             // Configuration is accessible, service is healthy
             let response = HealthResponse::healthy();
-            info!("Health check: healthy");
+            info!(correlation_id = %correlation_id, "Health check: healthy");
             (
                 StatusCode::OK,
                 [(header::CONTENT_TYPE, "application/json")],
@@ -65,7 +123,10 @@ async fn health_handler(State(state): State<AppState>) -> impl IntoResponse {
         Err(_) => {
             // Configuration access failed, service is unhealthy
             let response = HealthResponse::unhealthy("Configuration cannot be loaded");
-            warn!("Health check: unhealthy - configuration error");
+            warn!(
+                correlation_id = %correlation_id,
+                "Health check: unhealthy - configuration error"
+            );
             (
                 StatusCode::SERVICE_UNAVAILABLE,
                 [(header::CONTENT_TYPE, "application/json")],
@@ -79,10 +140,77 @@ async fn health_handler(State(state): State<AppState>) -> impl IntoResponse {
 /// Handler for GET /info endpoint.
 ///
 /// Returns information about the engine version and supported awards.
-async fn info_handler(State(state): State<AppState>) -> impl IntoResponse {
+async fn info_handler(
+    State(state): State<AppState>,
+    Extension(correlation_id): Extension<CorrelationId>,
+) -> impl IntoResponse {
     let config = state.config();
     let response = InfoResponse::from_config(config);
-    info!("Info request: returning {} supported award(s)", response.supported_awards.len());
+    info!(
+        correlation_id = %correlation_id,
+        "Info request: returning {} supported award(s)",
+        response.supported_awards.len()
+    );
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        Json(response),
+    )
+        .into_response()
+}
+
+/// Handler for GET /rules endpoint.
+///
+/// Returns the catalogue of rules the engine can apply, with the rule IDs
+/// recorded in audit steps and the award clauses they implement.
+async fn rules_handler(Extension(correlation_id): Extension<CorrelationId>) -> impl IntoResponse {
+    let response = RulesResponse::from_catalogue();
+    info!(
+        correlation_id = %correlation_id,
+        "Rules request: returning {} rule(s)",
+        response.rules.len()
+    );
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        Json(response),
+    )
+        .into_response()
+}
+
+/// Handler for POST /selftest endpoint.
+///
+/// Runs the configuration self-test and returns the outcome of each check.
+/// The response is 200 OK whether or not the checks pass; `passed` reports
+/// the overall outcome.
+async fn selftest_handler(
+    State(state): State<AppState>,
+    Extension(correlation_id): Extension<CorrelationId>,
+) -> impl IntoResponse {
+    let response = SelfTestResponse::from_config(state.config());
+    let failed: Vec<&str> = response
+        .checks
+        .iter()
+        .filter(|check| !check.passed)
+        .map(|check| check.check.as_str())
+        .collect();
+    if failed.is_empty() {
+        info!(
+            correlation_id = %correlation_id,
+            award_code = %response.award_code,
+            "Self-test passed {} check(s)",
+            response.checks.len()
+        );
+    } else {
+        warn!(
+            correlation_id = %correlation_id,
+            award_code = %response.award_code,
+            failed_checks = ?failed,
+            "Self-test failed {} of {} check(s)",
+            failed.len(),
+            response.checks.len()
+        );
+    }
     (
         StatusCode::OK,
         [(header::CONTENT_TYPE, "application/json")],
@@ -91,91 +219,99 @@ async fn info_handler(State(state): State<AppState>) -> impl IntoResponse {
         .into_response()
 }
 
+/// Fallback for a known route requested with an unsupported method.
+///
+/// Returns 405 Method Not Allowed with a JSON error body; the router adds the
+/// `Allow` header listing the supported methods.
+async fn method_not_allowed_handler(
+    Extension(correlation_id): Extension<CorrelationId>,
+    method: Method,
+    uri: Uri,
+) -> impl IntoResponse {
+    warn!(
+        correlation_id = %correlation_id,
+        method = %method,
+        path = %uri.path(),
+        "Method not allowed"
+    );
+    (
+        StatusCode::METHOD_NOT_ALLOWED,
+        [(header::CONTENT_TYPE, "application/json")],
+        Json(ApiError::method_not_allowed(method.as_str(), uri.path())),
+    )
+        .into_response()
+}
+
 /// Handler for POST /calculate endpoint.
 ///
-/// Accepts a calculation request and returns the calculated pay result.
+/// Accepts a calculation request and returns the calculated pay result. With
+/// `?diagnostics=true`, the result also lists the rules the calculation did
+/// not apply in `unapplied_rules`.
 async fn calculate_handler(
     State(state): State<AppState>,
-    payload: Result<Json<CalculationRequest>, JsonRejection>,
+    Extension(correlation_id): Extension<CorrelationId>,
+    query: Result<Query<CalculateQuery>, QueryRejection>,
+    payload: Result<Json<Value>, JsonRejection>,
 ) -> impl IntoResponse {
-    // Generate correlation ID for request tracking
-    let correlation_id = Uuid::new_v4();
     info!(correlation_id = %correlation_id, "Processing calculation request");
 
-    // Handle JSON parsing errors
-    let request = match payload {
-        Ok(Json(req)) => req,
+    let Query(query) = match query {
+        Ok(query) => query,
         Err(rejection) => {
-            let error = match rejection {
-                JsonRejection::JsonDataError(err) => {
-                    // Get the body text which contains the detailed error from serde
-                    let body_text = err.body_text();
-                    warn!(
-                        correlation_id = %correlation_id,
-                        error = %body_text,
-                        "JSON data error"
-                    );
-                    // Check if it's a missing field error
-                    if body_text.contains("missing field") {
-                        ApiError::new("VALIDATION_ERROR", body_text)
-                    } else {
-                        ApiError::malformed_json(body_text)
-                    }
-                }
-                JsonRejection::JsonSyntaxError(err) => {
-                    warn!(
-                        correlation_id = %correlation_id,
-                        error = %err,
-                        "JSON syntax error"
-                    );
-                    ApiError::malformed_json(format!("Invalid JSON syntax: {}", err))
-                }
-                JsonRejection::MissingJsonContentType(_) => {
-                    ApiError::new("MISSING_CONTENT_TYPE", "Content-Type must be application/json")
-                }
-                _ => ApiError::malformed_json("Failed to parse request body"),
+            let err = RequestValidationError::InvalidValue {
+                message: rejection.body_text(),
             };
+            warn!(
+                correlation_id = %correlation_id,
+                error = %err,
+                "Calculation query validation failed"
+            );
+            return ApiErrorResponse::from(err).into_response();
+        }
+    };
+
+    // Handle JSON parsing and request validation errors
+    let mut request = match parse_request(payload, &correlation_id, CalculationRequest::from_json) {
+        Ok(req) => req,
+        Err(api_error) => {
             return (
-                StatusCode::BAD_REQUEST,
+                api_error.status,
                 [(header::CONTENT_TYPE, "application/json")],
-                Json(error),
+                Json(api_error.error),
             )
                 .into_response();
         }
     };
 
-    // Convert request types to domain types
-    let employee: Employee = request.employee.into();
-    let pay_period: PayPeriod = request.pay_period.into();
-    let shifts: Vec<Shift> = request.shifts.into_iter().map(Into::into).collect();
-
-    // Validate the classification exists
-    let config = state.config();
-    if let Err(err) = config.get_classification(&employee.classification_code) {
-        warn!(
-            correlation_id = %correlation_id,
-            classification = %employee.classification_code,
-            "Classification not found"
-        );
-        let api_error: ApiErrorResponse = err.into();
-        return (
-            api_error.status,
-            [(header::CONTENT_TYPE, "application/json")],
-            Json(api_error.error),
-        )
-            .into_response();
+    if let Err(api_error) = check_shift_count(request.shifts.len(), &state, &correlation_id) {
+        return api_error.into_response();
     }
+    request.diagnostics = query.diagnostics;
+
+    let config = match request_config(&request, &state) {
+        Ok(config) => config,
+        Err(api_error) => return api_error.into_response(),
+    };
 
-    // Perform the calculation
+    // Validate and perform the calculation
+    let shifts_count = request.shifts.len();
     let start_time = Instant::now();
-    match perform_calculation(&employee, &pay_period, &shifts, config) {
-        Ok(result) => {
+    match calculate_pay_with_clock(request, config, state.clock()) {
+        Ok(mut result) => {
+            stamp_result(&mut result, &state, &correlation_id);
             let duration = start_time.elapsed();
+            // One event per result, with each figure as its own field so
+            // payroll runs can be queried in a log pipeline
             info!(
                 correlation_id = %correlation_id,
-                employee_id = %employee.id,
-                shifts_count = shifts.len(),
+                employee_id = %result.employee_id,
+                award_code = %config.award().code,
                 gross_pay = %result.totals.gross_pay,
+                ordinary_hours = %result.totals.ordinary_hours,
+                penalty_hours = %result.totals.penalty_hours,
+                overtime_hours = %result.totals.overtime_hours,
+                allowances_total = %result.totals.allowances_total,
+                shifts_count,
                 duration_us = duration.as_micros(),
                 "Calculation completed successfully"
             );
@@ -187,12 +323,14 @@ async fn calculate_handler(
                 .into_response()
         }
         Err(err) => {
+            let message = err.to_string();
+            let api_error: ApiErrorResponse = err.into();
             warn!(
                 correlation_id = %correlation_id,
-                error = %err,
+                code = %api_error.error.code,
+                error = %message,
                 "Calculation failed"
             );
-            let api_error: ApiErrorResponse = err.into();
             (
                 api_error.status,
                 [(header::CONTENT_TYPE, "application/json")],
@@ -203,561 +341,4911 @@ async fn calculate_handler(
     }
 }
 
-/// Performs the pay calculation for an employee's shifts.
-fn perform_calculation(
-    employee: &Employee,
-    pay_period: &PayPeriod,
-    shifts: &[Shift],
-    config: &crate::config::ConfigLoader,
-) -> Result<CalculationResult, crate::error::EngineError> {
-    let start_time = Instant::now();
-    let mut all_pay_lines: Vec<PayLine> = Vec::new();
-    let mut all_audit_steps: Vec<AuditStep> = Vec::new();
-    let all_warnings: Vec<AuditWarning> = Vec::new();
-    let mut step_number: u32 = 1;
-
-    let award_config = config.config();
+/// Handler for POST /calculate/diff endpoint.
+///
+/// Calculates the request under the `old_award` and `new_award`
+/// configurations and returns both results with the change to each pay line
+/// and total. Both award codes must name a configuration held by the state.
+async fn calculate_diff_handler(
+    State(state): State<AppState>,
+    Extension(correlation_id): Extension<CorrelationId>,
+    payload: Result<Json<Value>, JsonRejection>,
+) -> impl IntoResponse {
+    info!(correlation_id = %correlation_id, "Processing calculation diff request");
 
-    // Get the effective date for rate lookups (use first shift date or pay period start)
-    let effective_date = shifts
-        .first()
-        .map(|s| s.date)
-        .unwrap_or(pay_period.start_date);
+    let diff_request =
+        match parse_request(payload, &correlation_id, CalculationDiffRequest::from_json) {
+            Ok(req) => req,
+            Err(api_error) => return api_error.into_response(),
+        };
+    let shifts_count = diff_request.request.shifts.len();
+    if let Err(api_error) = check_shift_count(shifts_count, &state, &correlation_id) {
+        return api_error.into_response();
+    }
 
-    // Get base rate for the employee
-    let base_rate_result = get_base_rate(employee, effective_date, award_config, step_number)?;
-    let base_rate = base_rate_result.rate;
-    all_audit_steps.push(base_rate_result.audit_step);
-    step_number += 1;
+    match calculate_diff(diff_request, &state, &correlation_id) {
+        Ok(diff) => {
+            info!(
+                correlation_id = %correlation_id,
+                old_award = %diff.old_award,
+                new_award = %diff.new_award,
+                gross_pay_delta = %diff.totals.gross_pay.delta,
+                "Calculation diff completed successfully"
+            );
+            (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, "application/json")],
+                Json(diff),
+            )
+                .into_response()
+        }
+        Err(api_error) => {
+            warn!(
+                correlation_id = %correlation_id,
+                code = %api_error.error.code,
+                "Calculation diff failed"
+            );
+            api_error.into_response()
+        }
+    }
+}
 
-    // Process each shift
-    for shift in shifts {
-        // Segment the shift by day (handles overnight shifts)
-        let segments = segment_by_day(shift);
-        let total_worked_hours = shift.worked_hours();
-
-        // Detect daily overtime for the entire shift
-        let overtime_detection = detect_daily_overtime(
-            total_worked_hours,
-            DEFAULT_DAILY_OVERTIME_THRESHOLD,
-            step_number,
-        );
-        all_audit_steps.push(overtime_detection.audit_step.clone());
-        step_number += 1;
+/// Returns the configuration a request is calculated under: the award it
+/// names, or the primary award when it names none.
+fn request_config<'a>(
+    request: &CalculationRequest,
+    state: &'a AppState,
+) -> Result<&'a ConfigLoader, ApiErrorResponse> {
+    match &request.award_code {
+        Some(code) => state.award(code).ok_or_else(|| ApiErrorResponse {
+            status: StatusCode::BAD_REQUEST,
+            error: ApiError::unknown_award(code),
+        }),
+        None => Ok(state.config()),
+    }
+}
 
-        // Track if we've already handled ordinary hours for this shift
-        let mut ordinary_hours_remaining = overtime_detection.ordinary_hours;
+/// Calculates a diff request under both awards and compares the results.
+fn calculate_diff(
+    diff_request: CalculationDiffRequest,
+    state: &AppState,
+    correlation_id: &CorrelationId,
+) -> Result<CalculationDiff, ApiErrorResponse> {
+    let award = |code: &str| {
+        state.award(code).ok_or_else(|| ApiErrorResponse {
+            status: StatusCode::BAD_REQUEST,
+            error: ApiError::unknown_award(code),
+        })
+    };
+    let old_config = award(&diff_request.old_award)?;
+    let new_config = award(&diff_request.new_award)?;
 
-        for segment in &segments {
-            let day_type = get_day_type(segment.start_time);
+    let mut results = [
+        calculate_pay_with_clock(diff_request.request.clone(), old_config, state.clock())?,
+        calculate_pay_with_clock(diff_request.request, new_config, state.clock())?,
+    ];
+    for result in &mut results {
+        stamp_result(result, state, correlation_id);
+    }
+    let [old, new] = results;
 
-            // Calculate hours for this segment, limited by remaining ordinary hours
-            let segment_ordinary_hours = if ordinary_hours_remaining >= segment.hours {
-                ordinary_hours_remaining -= segment.hours;
-                segment.hours
-            } else {
-                let hours = ordinary_hours_remaining;
-                ordinary_hours_remaining = Decimal::ZERO;
-                hours
-            };
+    Ok(CalculationDiff::new(
+        diff_request.old_award,
+        diff_request.new_award,
+        old,
+        new,
+    ))
+}
 
-            match day_type {
-                DayType::Weekday => {
-                    if segment_ordinary_hours > Decimal::ZERO {
-                        // Calculate ordinary hours using the existing function
-                        let ordinary_result = calculate_ordinary_hours(
-                            shift,
-                            employee,
-                            award_config,
-                            step_number,
-                        )?;
-
-                        // Adjust the pay line for the actual segment hours
-                        let mut pay_line = ordinary_result.pay_line;
-                        pay_line.shift_id = shift.id.clone();
-                        pay_line.date = segment.start_time.date();
-                        pay_line.hours = segment_ordinary_hours;
-                        pay_line.amount = segment_ordinary_hours * pay_line.rate;
-
-                        all_pay_lines.push(pay_line);
-                        let steps_count = ordinary_result.audit_steps.len();
-                        all_audit_steps.extend(ordinary_result.audit_steps);
-                        step_number += steps_count as u32;
-                    }
-                }
-                DayType::Saturday => {
-                    if segment_ordinary_hours > Decimal::ZERO {
-                        // Create a segment for the ordinary hours
-                        let mut seg = segment.clone();
-                        seg.hours = segment_ordinary_hours;
-
-                        let saturday_result = calculate_saturday_pay(
-                            &seg,
-                            employee,
-                            base_rate,
-                            award_config,
-                            step_number,
-                        );
+/// Handler for POST /calculate/compare-employment endpoint.
+///
+/// Calculates the request under the employee's own employment type and under
+/// `compare_employment_type`, and returns both results with the change to
+/// each total. Everything that depends on employment type, such as casual
+/// loading, weekend penalties and public holidays not worked, is recalculated
+/// for the compared type.
+async fn compare_employment_handler(
+    State(state): State<AppState>,
+    Extension(correlation_id): Extension<CorrelationId>,
+    payload: Result<Json<Value>, JsonRejection>,
+) -> impl IntoResponse {
+    info!(correlation_id = %correlation_id, "Processing employment comparison request");
 
-                        let mut pay_line = saturday_result.pay_line;
-                        pay_line.shift_id = shift.id.clone();
-                        all_pay_lines.push(pay_line);
-                        all_audit_steps.push(saturday_result.audit_step);
-                        step_number += 1;
-                    }
-                }
-                DayType::Sunday => {
-                    if segment_ordinary_hours > Decimal::ZERO {
-                        // Create a segment for the ordinary hours
-                        let mut seg = segment.clone();
-                        seg.hours = segment_ordinary_hours;
-
-                        let sunday_result = calculate_sunday_pay(
-                            &seg,
-                            employee,
-                            base_rate,
-                            award_config,
-                            step_number,
-                        );
+    let comparison_request = match parse_request(
+        payload,
+        &correlation_id,
+        EmploymentComparisonRequest::from_json,
+    ) {
+        Ok(req) => req,
+        Err(api_error) => return api_error.into_response(),
+    };
+    let shifts_count = comparison_request.request.shifts.len();
+    if let Err(api_error) = check_shift_count(shifts_count, &state, &correlation_id) {
+        return api_error.into_response();
+    }
+    let config = match request_config(&comparison_request.request, &state) {
+        Ok(config) => config,
+        Err(api_error) => return api_error.into_response(),
+    };
 
-                        let mut pay_line = sunday_result.pay_line;
-                        pay_line.shift_id = shift.id.clone();
-                        all_pay_lines.push(pay_line);
-                        all_audit_steps.push(sunday_result.audit_step);
-                        step_number += 1;
-                    }
-                }
-            }
+    match compare_employment(comparison_request, config, &state, &correlation_id) {
+        Ok(comparison) => {
+            info!(
+                correlation_id = %correlation_id,
+                employment_type = comparison.employment_type.as_str(),
+                compare_employment_type = comparison.compare_employment_type.as_str(),
+                gross_pay_delta = %comparison.totals.gross_pay.delta,
+                "Employment comparison completed successfully"
+            );
+            (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, "application/json")],
+                Json(comparison),
+            )
+                .into_response()
         }
-
-        // Calculate overtime if applicable
-        if overtime_detection.overtime_hours > Decimal::ZERO {
-            // Determine the day type of the shift (use the primary shift date)
-            let primary_day_type = get_day_type(shift.start_time);
-
-            match primary_day_type {
-                DayType::Weekday => {
-                    let overtime_result = calculate_weekday_overtime(
-                        overtime_detection.overtime_hours,
-                        base_rate,
-                        employee,
-                        award_config,
-                        shift.date,
-                        &shift.id,
-                        step_number,
-                    );
-
-                    all_pay_lines.extend(overtime_result.pay_lines);
-                    let steps_count = overtime_result.audit_steps.len();
-                    all_audit_steps.extend(overtime_result.audit_steps);
-                    step_number += steps_count as u32;
-                }
-                DayType::Saturday => {
-                    let overtime_result = calculate_weekend_overtime(
-                        overtime_detection.overtime_hours,
-                        base_rate,
-                        employee,
-                        award_config,
-                        DayType::Saturday,
-                        shift.date,
-                        &shift.id,
-                        step_number,
-                    );
-
-                    if let Some(pay_line) = overtime_result.pay_line {
-                        all_pay_lines.push(pay_line);
-                    }
-                    if let Some(audit_step) = overtime_result.audit_step {
-                        all_audit_steps.push(audit_step);
-                        step_number += 1;
-                    }
-                }
-                DayType::Sunday => {
-                    let overtime_result = calculate_weekend_overtime(
-                        overtime_detection.overtime_hours,
-                        base_rate,
-                        employee,
-                        award_config,
-                        DayType::Sunday,
-                        shift.date,
-                        &shift.id,
-                        step_number,
-                    );
-
-                    if let Some(pay_line) = overtime_result.pay_line {
-                        all_pay_lines.push(pay_line);
-                    }
-                    if let Some(audit_step) = overtime_result.audit_step {
-                        all_audit_steps.push(audit_step);
-                        step_number += 1;
-                    }
-                }
-            }
+        Err(err) => {
+            let message = err.to_string();
+            let api_error: ApiErrorResponse = err.into();
+            warn!(
+                correlation_id = %correlation_id,
+                code = %api_error.error.code,
+                error = %message,
+                "Employment comparison failed"
+            );
+            api_error.into_response()
         }
     }
+}
 
-    // Calculate laundry allowance
-    let (laundry_per_shift, laundry_per_week) = config.get_allowance_rates(effective_date)?;
-    let laundry_result = calculate_laundry_allowance(
-        employee,
-        shifts.len() as u32,
-        laundry_per_shift,
-        laundry_per_week,
-        step_number,
-    );
-    all_audit_steps.push(laundry_result.audit_step);
+/// Calculates a roster under the employee's own employment type and the
+/// compared one, and compares the results.
+fn compare_employment(
+    comparison_request: EmploymentComparisonRequest,
+    config: &ConfigLoader,
+    state: &AppState,
+    correlation_id: &CorrelationId,
+) -> Result<EmploymentComparison, EngineError> {
+    let request = comparison_request.request;
+    let employment_type = request.employee.employment_type;
+    let mut compare_request = request.clone();
+    compare_request.employee.employment_type = comparison_request.compare_employment_type;
 
-    let allowances: Vec<AllowancePayment> = laundry_result.allowance.into_iter().collect();
+    let mut results = [
+        calculate_pay_with_clock(request, config, state.clock())?,
+        calculate_pay_with_clock(compare_request, config, state.clock())?,
+    ];
+    for result in &mut results {
+        stamp_result(result, state, correlation_id);
+    }
+    let [result, compare_result] = results;
 
-    // Calculate totals
-    let pay_lines_total: Decimal = all_pay_lines.iter().map(|pl| pl.amount).sum();
-    let allowances_total: Decimal = allowances.iter().map(|a| a.amount).sum();
-    let gross_pay = pay_lines_total + allowances_total;
+    let comparison = EmploymentComparison::new(
+        employment_type,
+        comparison_request.compare_employment_type,
+        result,
+        compare_result,
+    );
+    Ok(if comparison_request.hypothetical_part_time_loading {
+        comparison.with_hypothetical_part_time_loading()
+    } else {
+        comparison
+    })
+}
 
-    let ordinary_hours: Decimal = all_pay_lines
-        .iter()
-        .filter(|pl| matches!(pl.category, PayCategory::Ordinary | PayCategory::OrdinaryCasual))
-        .map(|pl| pl.hours)
-        .sum();
+/// Handler for POST /calculate/earnings endpoint.
+///
+/// Calculates the request as `/calculate` does and returns the result as a
+/// flat list of earnings under the award's configured earning codes, for
+/// import into a payroll system.
+async fn calculate_earnings_handler(
+    State(state): State<AppState>,
+    Extension(correlation_id): Extension<CorrelationId>,
+    payload: Result<Json<Value>, JsonRejection>,
+) -> impl IntoResponse {
+    info!(correlation_id = %correlation_id, "Processing earnings export request");
 
-    let overtime_hours: Decimal = all_pay_lines
-        .iter()
-        .filter(|pl| matches!(pl.category, PayCategory::Overtime150 | PayCategory::Overtime200))
-        .map(|pl| pl.hours)
-        .sum();
+    let request = match parse_request(payload, &correlation_id, CalculationRequest::from_json) {
+        Ok(req) => req,
+        Err(api_error) => return api_error.into_response(),
+    };
+    if let Err(api_error) = check_shift_count(request.shifts.len(), &state, &correlation_id) {
+        return api_error.into_response();
+    }
+    let config = match request_config(&request, &state) {
+        Ok(config) => config,
+        Err(api_error) => return api_error.into_response(),
+    };
 
-    let penalty_hours: Decimal = all_pay_lines
-        .iter()
-        .filter(|pl| {
-            matches!(
-                pl.category,
-                PayCategory::Saturday
-                    | PayCategory::SaturdayCasual
-                    | PayCategory::Sunday
-                    | PayCategory::SundayCasual
+    match calculate_pay_with_clock(request, config, state.clock()) {
+        Ok(mut result) => {
+            stamp_result(&mut result, &state, &correlation_id);
+            let export = EarningsExport::from_result(&result, config.earning_codes());
+            info!(
+                correlation_id = %correlation_id,
+                employee_id = %export.employee_id,
+                earnings_count = export.earnings.len(),
+                gross_pay = %export.gross_pay,
+                "Earnings export completed successfully"
+            );
+            (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, "application/json")],
+                Json(export),
             )
-        })
-        .map(|pl| pl.hours)
-        .sum();
-
-    let duration_us = start_time.elapsed().as_micros() as u64;
+                .into_response()
+        }
+        Err(err) => {
+            let message = err.to_string();
+            let api_error: ApiErrorResponse = err.into();
+            warn!(
+                correlation_id = %correlation_id,
+                code = %api_error.error.code,
+                error = %message,
+                "Earnings export failed"
+            );
+            api_error.into_response()
+        }
+    }
+}
 
-    Ok(CalculationResult {
-        calculation_id: Uuid::new_v4(),
-        timestamp: Utc::now(),
-        engine_version: env!("CARGO_PKG_VERSION").to_string(),
-        employee_id: employee.id.clone(),
-        pay_period: pay_period.clone(),
-        pay_lines: all_pay_lines,
-        allowances,
-        totals: PayTotals {
-            gross_pay,
-            ordinary_hours,
-            overtime_hours,
-            penalty_hours,
-            allowances_total,
-        },
-        audit_trace: AuditTrace {
-            steps: all_audit_steps,
-            warnings: all_warnings,
-            duration_us,
-        },
-    })
+/// Sets the details of the serving engine and request on a result.
+fn stamp_result(result: &mut CalculationResult, state: &AppState, correlation_id: &CorrelationId) {
+    result.engine_version = state.engine_version().to_string();
+    result.build_metadata = state.build_metadata().map(str::to_string);
+    result.correlation_id = Some(correlation_id.to_string());
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::api::request::{
-        CalculationRequest, EmployeeRequest, PayPeriodRequest, ShiftRequest,
-    };
-    use crate::config::ConfigLoader;
-    use crate::models::EmploymentType;
-    use axum::{
-        body::Body,
-        http::{Request, StatusCode},
-    };
-    use chrono::{NaiveDate, NaiveDateTime};
-    use tower::ServiceExt;
+/// Handler for POST /calculate/upload endpoint.
+///
+/// Takes a roster as a `text/csv` body, with the pay period in the query
+/// string, and calculates pay for each employee on it. Rows that cannot be
+/// read are reported by line number and left out, and an employee whose
+/// calculation fails is reported without failing the others, so the
+/// response is 200 OK unless the CSV itself cannot be read.
+async fn calculate_upload_handler(
+    State(state): State<AppState>,
+    Extension(correlation_id): Extension<CorrelationId>,
+    headers: HeaderMap,
+    query: Result<Query<RosterUploadQuery>, QueryRejection>,
+    body: Bytes,
+) -> impl IntoResponse {
+    info!(correlation_id = %correlation_id, "Processing roster upload");
 
-    fn create_test_state() -> AppState {
-        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
-        AppState::new(config)
+    let is_csv = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("text/csv"));
+    if !is_csv {
+        warn!(
+            correlation_id = %correlation_id,
+            "Request Content-Type is not text/csv"
+        );
+        return ApiErrorResponse {
+            status: StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            error: ApiError::unsupported_media_type("text/csv"),
+        }
+        .into_response();
     }
 
-    fn make_datetime(date_str: &str, time_str: &str) -> NaiveDateTime {
+    let Query(query) = match query {
+        Ok(query) => query,
+        Err(rejection) => {
+            let err = RequestValidationError::InvalidValue {
+                message: rejection.body_text(),
+            };
+            warn!(
+                correlation_id = %correlation_id,
+                error = %err,
+                "Upload query validation failed"
+            );
+            return ApiErrorResponse::from(err).into_response();
+        }
+    };
+
+    let upload = match parse_roster_csv(body.as_ref(), &query) {
+        Ok(upload) => upload,
+        Err(err) => {
+            warn!(
+                correlation_id = %correlation_id,
+                error = %err,
+                code = err.code(),
+                "Roster CSV could not be read"
+            );
+            return ApiErrorResponse::from(err).into_response();
+        }
+    };
+    let shifts_count = upload.requests.iter().map(|r| r.shifts.len()).sum();
+    if let Err(api_error) = check_shift_count(shifts_count, &state, &correlation_id) {
+        return api_error.into_response();
+    }
+
+    if accepts_ndjson(&headers) {
+        return stream_roster_upload(upload, state, correlation_id);
+    }
+
+    let mut response = RosterUploadResponse {
+        results: Vec::with_capacity(upload.requests.len()),
+        employee_errors: Vec::new(),
+        row_errors: upload.row_errors,
+    };
+    for request in upload.requests {
+        match calculate_upload_employee(request, &state, &correlation_id) {
+            Ok(result) => response.results.push(result),
+            Err(error) => response.employee_errors.push(error),
+        }
+    }
+
+    info!(
+        correlation_id = %correlation_id,
+        results_count = response.results.len(),
+        employee_errors_count = response.employee_errors.len(),
+        row_errors_count = response.row_errors.len(),
+        "Roster upload completed"
+    );
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        Json(response),
+    )
+        .into_response()
+}
+
+/// Returns whether the request's `Accept` header asks for JSON Lines.
+fn accepts_ndjson(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|media_range| media_range.split(';').next())
+        .any(|mime| mime.trim().eq_ignore_ascii_case(NDJSON_CONTENT_TYPE))
+}
+
+/// Streams a roster upload's results as JSON Lines.
+///
+/// Each employee is calculated as the body is sent, so the response starts
+/// before the whole roster is calculated and only one result is held at a
+/// time. Each line is a [`RosterUploadLine`] and the last is the summary.
+fn stream_roster_upload(
+    upload: RosterUpload,
+    state: AppState,
+    correlation_id: CorrelationId,
+) -> Response {
+    let lines = roster_upload_lines(upload, {
+        let correlation_id = correlation_id.clone();
+        move |request| calculate_upload_employee(request, &state, &correlation_id)
+    })
+    .map(move |line| {
+        if let RosterUploadLine::Summary(summary) = &line {
+            info!(
+                correlation_id = %correlation_id,
+                results_count = summary.results_count,
+                employee_errors_count = summary.employee_errors_count,
+                row_errors_count = summary.row_errors_count,
+                "Streamed roster upload completed"
+            );
+        }
+        let mut json = serde_json::to_vec(&line).expect("upload lines serialize to JSON");
+        json.push(b'\n');
+        Ok::<_, Infallible>(json)
+    });
+
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, NDJSON_CONTENT_TYPE)],
+        Body::from_stream(stream::iter(lines)),
+    )
+        .into_response()
+}
+
+/// Calculates pay for one employee on a roster upload.
+fn calculate_upload_employee(
+    request: CalculationRequest,
+    state: &AppState,
+    correlation_id: &CorrelationId,
+) -> Result<CalculationResult, EmployeeUploadError> {
+    let employee_id = request.employee.id.clone();
+    let calculation = request_config(&request, state).and_then(|config| {
+        calculate_pay_with_clock(request, config, state.clock()).map_err(ApiErrorResponse::from)
+    });
+    match calculation {
+        Ok(mut result) => {
+            stamp_result(&mut result, state, correlation_id);
+            Ok(result)
+        }
+        Err(api_error) => Err(EmployeeUploadError {
+            employee_id,
+            error: api_error.error,
+        }),
+    }
+}
+
+/// Handler for POST /validate endpoint.
+///
+/// Runs the same input validations as `/calculate` without performing the
+/// pay calculation. Returns 200 OK with `{"valid": true}` or with the list
+/// of validation problems found.
+async fn validate_handler(
+    State(state): State<AppState>,
+    Extension(correlation_id): Extension<CorrelationId>,
+    payload: Result<Json<Value>, JsonRejection>,
+) -> impl IntoResponse {
+    info!(correlation_id = %correlation_id, "Processing validation request");
+
+    let request = match parse_request(payload, &correlation_id, CalculationRequest::from_json) {
+        Ok(req) => req,
+        Err(api_error) => {
+            return (
+                api_error.status,
+                [(header::CONTENT_TYPE, "application/json")],
+                Json(api_error.error),
+            )
+                .into_response();
+        }
+    };
+
+    if let Err(api_error) = check_shift_count(request.shifts.len(), &state, &correlation_id) {
+        return api_error.into_response();
+    }
+    let config = match request_config(&request, &state) {
+        Ok(config) => config,
+        Err(api_error) => return api_error.into_response(),
+    };
+
+    let mut problems = Vec::new();
+    if request.require_shifts && request.shifts.is_empty() {
+        problems.push(EngineError::NoShifts {
+            employee_id: request.employee.id.clone(),
+        });
+    }
+    let mut employee: Employee = request.employee.into();
+    let pay_period: PayPeriod = request.pay_period.into();
+    let mut shifts: Vec<Shift> = Vec::with_capacity(request.shifts.len());
+    for shift in request.shifts {
+        match Shift::try_from(shift) {
+            Ok(shift) => shifts.push(shift),
+            Err(err) => problems.push(err),
+        }
+    }
+    if request.amounts_in_cents {
+        rates_from_cents(&mut employee, &mut shifts);
+    }
+
+    problems.extend(validate_request(&employee, &pay_period, &shifts, config));
+    let response = ValidationResponse::from_errors(problems);
+    info!(
+        correlation_id = %correlation_id,
+        valid = response.valid,
+        problems_count = response.problems.len(),
+        "Validation completed"
+    );
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        Json(response),
+    )
+        .into_response()
+}
+
+/// Converts the employee's and the shifts' rate overrides from cents to the
+/// dollars the engine calculates in.
+fn rates_from_cents(employee: &mut Employee, shifts: &mut [Shift]) {
+    employee.base_hourly_rate = employee
+        .base_hourly_rate
+        .map(|rate| rate / CENTS_PER_DOLLAR);
+    for shift in shifts {
+        shift.rate_override = shift.rate_override.map(|rate| rate / CENTS_PER_DOLLAR);
+    }
+}
+
+/// Rejects a request with more shifts than the state allows, as 413 Payload
+/// Too Large.
+fn check_shift_count(
+    shifts_count: usize,
+    state: &AppState,
+    correlation_id: &CorrelationId,
+) -> Result<(), ApiErrorResponse> {
+    let max_shifts = state.max_shifts_per_request();
+    if shifts_count <= max_shifts {
+        return Ok(());
+    }
+    warn!(
+        correlation_id = %correlation_id,
+        shifts_count,
+        max_shifts,
+        "Request has too many shifts"
+    );
+    Err(ApiErrorResponse {
+        status: StatusCode::PAYLOAD_TOO_LARGE,
+        error: ApiError::too_many_shifts(shifts_count, max_shifts),
+    })
+}
+
+/// Turns the extracted JSON body into a request using `from_json`.
+///
+/// JSON extraction failures and request validation errors are returned as
+/// API errors with their HTTP status.
+fn parse_request<T>(
+    payload: Result<Json<Value>, JsonRejection>,
+    correlation_id: &CorrelationId,
+    from_json: fn(Value) -> Result<T, RequestValidationError>,
+) -> Result<T, ApiErrorResponse> {
+    let body = match payload {
+        Ok(Json(body)) => body,
+        Err(rejection) => return Err(json_rejection_error(rejection, correlation_id)),
+    };
+
+    from_json(body).map_err(|err| {
+        warn!(
+            correlation_id = %correlation_id,
+            error = %err,
+            code = err.code(),
+            "Request body validation failed"
+        );
+        err.into()
+    })
+}
+
+/// Converts a JSON extraction rejection into an error response.
+///
+/// A missing or non-JSON `Content-Type` is 415 Unsupported Media Type; any
+/// other rejection is 400 Bad Request.
+fn json_rejection_error(
+    rejection: JsonRejection,
+    correlation_id: &CorrelationId,
+) -> ApiErrorResponse {
+    let mut status = StatusCode::BAD_REQUEST;
+    let error = match rejection {
+        JsonRejection::JsonDataError(err) => {
+            let body_text = err.body_text();
+            warn!(
+                correlation_id = %correlation_id,
+                error = %body_text,
+                "JSON data error"
+            );
+            ApiError::malformed_json(body_text)
+        }
+        JsonRejection::JsonSyntaxError(err) => {
+            warn!(
+                correlation_id = %correlation_id,
+                error = %err,
+                "JSON syntax error"
+            );
+            ApiError::malformed_json(format!("Invalid JSON syntax: {}", err))
+        }
+        JsonRejection::MissingJsonContentType(_) => {
+            warn!(
+                correlation_id = %correlation_id,
+                "Request Content-Type is not application/json"
+            );
+            status = StatusCode::UNSUPPORTED_MEDIA_TYPE;
+            ApiError::unsupported_media_type("application/json")
+        }
+        _ => ApiError::malformed_json("Failed to parse request body"),
+    };
+    ApiErrorResponse { status, error }
+}
+
+/// Calculates pay for a request, applying the same validation as `/calculate`.
+///
+/// The request is rejected with the first validation problem found. The
+/// configuration is only borrowed and the engine keeps no shared mutable
+/// state, so one [`ConfigLoader`] can serve calculations on many threads at
+/// once.
+///
+/// The result's `calculation_id` is random unless the request sets
+/// `deterministic_id`, in which case it is derived from the request and the
+/// configuration. When the request sets `diagnostics`, the result lists the
+/// rules the calculation did not apply in `unapplied_rules`. When it sets
+/// `echo_request`, the result's `input` holds the inputs as interpreted. When
+/// it sets `amounts_in_cents`, its rate overrides are read as cents and the
+/// result's amounts are given in cents. When it sets `require_shifts`, a
+/// request without shifts fails with [`EngineError::NoShifts`] rather than
+/// producing a zero-pay result.
+///
+/// # Example
+///
+/// ```
+/// use award_engine::api::{CalculationRequest, calculate_pay};
+/// use award_engine::config::ConfigLoader;
+///
+/// let config = ConfigLoader::load_embedded()?;
+/// let request = CalculationRequest::from_json(serde_json::json!({
+///     "employee": {
+///         "id": "emp_001",
+///         "employment_type": "full_time",
+///         "classification_code": "dce_level_3",
+///         "date_of_birth": "1990-01-15",
+///         "employment_start_date": "2023-06-01"
+///     },
+///     "pay_period": { "start_date": "2026-01-12", "end_date": "2026-01-18" },
+///     "shifts": [{
+///         "id": "shift_001",
+///         "date": "2026-01-13",
+///         "start_time": "2026-01-13T09:00:00",
+///         "end_time": "2026-01-13T17:00:00"
+///     }]
+/// }))
+/// .unwrap();
+///
+/// let result = calculate_pay(request, &config)?;
+/// assert_eq!(result.pay_lines.len(), 1);
+/// # Ok::<(), award_engine::error::EngineError>(())
+/// ```
+pub fn calculate_pay(
+    request: CalculationRequest,
+    config: &ConfigLoader,
+) -> Result<CalculationResult, EngineError> {
+    calculate_pay_with_clock(request, config, &SystemClock)
+}
+
+/// Calculates pay for a request as [`calculate_pay`] does, reading the
+/// result's `timestamp` and `duration_us` from `clock`.
+///
+/// With a [`FixedClock`](super::FixedClock) and `deterministic_id`, the same
+/// request and configuration always produce the same result, so results can
+/// be compared against golden files or replayed.
+pub fn calculate_pay_with_clock(
+    request: CalculationRequest,
+    config: &ConfigLoader,
+    clock: &dyn Clock,
+) -> Result<CalculationResult, EngineError> {
+    let start_time = Instant::now();
+    let calculation_id = request
+        .deterministic_id
+        .then(|| deterministic_calculation_id(&request, config));
+    if request.require_shifts && request.shifts.is_empty() {
+        return Err(EngineError::NoShifts {
+            employee_id: request.employee.id,
+        });
+    }
+    // A shift given by its expected hours makes the whole result an estimate
+    let estimate = request
+        .shifts
+        .iter()
+        .any(|shift| shift.expected_hours.is_some());
+    let mut employee: Employee = request.employee.into();
+    let pay_period: PayPeriod = request.pay_period.into();
+    let mut shifts = request
+        .shifts
+        .into_iter()
+        .map(Shift::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    if request.amounts_in_cents {
+        rates_from_cents(&mut employee, &mut shifts);
+    }
+
+    if let Some(err) = validate_request(&employee, &pay_period, &shifts, config)
+        .into_iter()
+        .next()
+    {
+        return Err(err);
+    }
+
+    // Audit steps are only built when the request asks for them or for
+    // diagnostics; unapplied rules are read from the full audit trace, which
+    // is then dropped if the request did not ask for it
+    let mut result = perform_calculation(
+        &employee,
+        &pay_period,
+        &shifts,
+        request.timezone,
+        request.state.as_deref(),
+        request.include_audit || request.diagnostics,
+        request.partial_results,
+        config,
+    )?;
+    result.estimate = estimate;
+    if request.diagnostics {
+        let unapplied = unapplied_rules(&result.audit_trace.steps)
+            .into_iter()
+            .map(|rule| UnappliedRule {
+                rule_id: rule.rule_id.to_string(),
+                rule_name: rule.rule_name.to_string(),
+                clause_ref: rule.clause_ref.to_string(),
+            })
+            .collect();
+        result.unapplied_rules = Some(unapplied);
+        if !request.include_audit {
+            result.audit_trace.steps = Vec::new();
+        }
+    }
+    if let Some(max_audit_steps) = config.config().penalties().max_audit_steps {
+        result.audit_trace.truncate_steps(max_audit_steps);
+    }
+    if request.echo_request {
+        result.input = Some(CalculationInput {
+            employee,
+            pay_period: result.pay_period.clone(),
+            shifts: shifts.into_iter().map(InterpretedShift::from).collect(),
+        });
+    }
+    if request.amounts_in_cents {
+        result.convert_amounts_to_cents();
+    }
+    if let Some(calculation_id) = calculation_id {
+        result.calculation_id = calculation_id;
+    }
+    result.timestamp = clock.now();
+    result.audit_trace.duration_us = clock.elapsed_us(start_time);
+    Ok(result)
+}
+
+/// Warning code for a shift left out of a partial result because it could
+/// not be calculated.
+pub const SHIFT_CALCULATION_FAILED_WARNING: &str = "SHIFT_CALCULATION_FAILED";
+
+/// Warning code for a gross pay above the configured `max_expected_gross`.
+pub const GROSS_EXCEEDS_EXPECTED_WARNING: &str = "GROSS_EXCEEDS_EXPECTED";
+
+/// Performs the pay calculation for an employee's shifts.
+///
+/// When `include_audit` is false, no audit steps are built: the applied
+/// clauses are collected from the pay lines and allowances, not the steps.
+/// Warnings are always kept, so every field other than the steps is the same
+/// either way.
+///
+/// A shift that fails fails the whole calculation, unless `partial_results`
+/// is true: the shift is then left out of the pay lines, totals and every
+/// period-level step (span of hours, minimum daily pay, allowances), and a
+/// [`SHIFT_CALCULATION_FAILED_WARNING`] names it and the error.
+#[allow(clippy::too_many_arguments)]
+fn perform_calculation(
+    employee: &Employee,
+    pay_period: &PayPeriod,
+    shifts: &[Shift],
+    timezone: Option<Tz>,
+    state: Option<&str>,
+    include_audit: bool,
+    partial_results: bool,
+    config: &ConfigLoader,
+) -> Result<CalculationResult, EngineError> {
+    let start_time = Instant::now();
+    let mut all_pay_lines: Vec<PayLine> = Vec::new();
+    let mut overtime_detail: Vec<OvertimeTier> = Vec::new();
+    let mut all_audit_steps: Vec<AuditStep> = Vec::new();
+    let mut all_warnings: Vec<AuditWarning> = Vec::new();
+    let mut step_number: u32 = 1;
+
+    let award_config = config.config();
+
+    // Flag tags the engine does not recognise (e.g. a misspelt allowance tag)
+    all_warnings.extend(check_employee_tags(employee));
+
+    // Merge the requested public holidays with the state's calendar holidays
+    let holidays = detect_public_holidays(
+        pay_period,
+        state,
+        config.holiday_calendar(),
+        step_number,
+        include_audit,
+    );
+    if let Some(audit_step) = holidays.audit_step {
+        all_audit_steps.push(audit_step);
+        step_number += 1;
+    }
+    let pay_period = &PayPeriod {
+        public_holidays: holidays.public_holidays,
+        period_type: pay_period.resolved_period_type(),
+        ..pay_period.clone()
+    };
+
+    // Shift and break times recorded to the second are rounded to the nearest
+    // minute, unless the award pays worked time to the second
+    let minute_shifts: Vec<Shift>;
+    let shifts = match award_config.penalties().shift_seconds {
+        ShiftSeconds::Exact => shifts,
+        ShiftSeconds::NearestMinute => {
+            let rounding = round_shift_seconds(shifts, step_number, include_audit);
+            step_number += rounding.audit_steps.len() as u32;
+            all_audit_steps.extend(rounding.audit_steps);
+            minute_shifts = rounding.shifts;
+            &minute_shifts
+        }
+    };
+
+    // Get the effective date for rate lookups (use earliest shift date or pay period start)
+    let effective_date = shifts
+        .iter()
+        .map(|s| s.date)
+        .min()
+        .unwrap_or(pay_period.start_date);
+
+    // A public holiday not worked is paid even when no shifts were worked
+    let paid_holidays_not_worked = award_config.penalties().public_holiday_not_worked.is_some()
+        && !paid_public_holidays_not_worked(employee, pay_period, shifts).is_empty();
+
+    // A pay period with no shifts produces an empty, zero-total result. No base
+    // rate is needed, so the lookup is skipped rather than run against the
+    // pay period start date (which may not have an operative rate).
+    let base_rate = if shifts.is_empty() && !paid_holidays_not_worked {
+        if include_audit {
+            all_audit_steps.push(AuditStep {
+                step_number,
+                rule_id: "no_shifts".to_string(),
+                rule_name: "No Shifts To Process".to_string(),
+                clause_ref: "N/A".to_string(),
+                input: serde_json::json!({
+                    "employee_id": employee.id,
+                    "pay_period_start": pay_period.start_date.to_string(),
+                    "pay_period_end": pay_period.end_date.to_string(),
+                    "shift_count": 0
+                }),
+                output: serde_json::json!({
+                    "pay_lines": 0,
+                    "allowances": 0,
+                    "gross_pay": "0"
+                }),
+                reasoning:
+                    "No shifts to process: no base rate, penalties, overtime or allowances apply"
+                        .to_string(),
+            });
+        }
+        step_number += 1;
+        Decimal::ZERO
+    } else {
+        let base_rate_result = get_base_rate(
+            employee,
+            effective_date,
+            award_config,
+            step_number,
+            include_audit,
+        )?;
+        all_audit_steps.extend(base_rate_result.audit_step);
+        all_warnings.extend(base_rate_result.warning);
+        step_number += 1;
+        base_rate_result.rate
+    };
+
+    // Process shifts in start order so that, when several shifts fall on the
+    // same day, overtime is attributed to the later shift(s)
+    let mut ordered_shifts: Vec<&Shift> = shifts.iter().collect();
+    ordered_shifts.sort_by_key(|s| s.start_time);
+    let mut hours_worked_by_day: HashMap<NaiveDate, Decimal> = HashMap::new();
+    let mut shift_base_rate = base_rate;
+    let mut shift_base_rates: HashMap<&str, Decimal> = HashMap::new();
+    let mut shift_worked_hours: HashMap<&str, Decimal> = HashMap::new();
+    let mut failed_shift_ids: HashSet<&str> = HashSet::new();
+
+    // Process each shift. With partial results, a shift that fails is rolled
+    // back (its pay lines, audit steps and hours) and reported as a warning,
+    // so the remaining shifts are still paid
+    for shift in ordered_shifts {
+        let shift_pay_lines_start = all_pay_lines.len();
+        let shift_overtime_detail_start = overtime_detail.len();
+        let shift_audit_steps_start = all_audit_steps.len();
+        let shift_warnings_start = all_warnings.len();
+        let shift_step_number = step_number;
+        let prior_shift_base_rate = shift_base_rate;
+        let prior_day_hours = hours_worked_by_day.get(&shift.date).copied();
+        // A shift worked under another classification is paid as if the
+        // employee held it
+        let shift_employee = employee.for_shift(shift);
+        let employee = shift_employee.as_ref();
+
+        let outcome: Result<(), EngineError> = 'shift: {
+            // Each shift is paid at the rate operative on its own date, so a rate
+            // change part way through the pay period applies from that date. The
+            // lookup is only recorded in the audit trail when the rate changes,
+            // or when the shift has its own override rate or classification.
+            let own_rate = shift.rate_override.is_some() || shift.classification_code.is_some();
+            let base_rate = if own_rate {
+                let rate_result = match get_shift_base_rate(
+                    shift,
+                    employee,
+                    award_config,
+                    step_number,
+                    include_audit,
+                ) {
+                    Ok(rate_result) => rate_result,
+                    Err(error) => break 'shift Err(error),
+                };
+                all_audit_steps.extend(rate_result.audit_step);
+                all_warnings.extend(rate_result.warning);
+                step_number += 1;
+                rate_result.rate
+            } else if shift.date == effective_date {
+                base_rate
+            } else {
+                let rate_result = match get_base_rate(
+                    employee,
+                    shift.date,
+                    award_config,
+                    step_number,
+                    include_audit,
+                ) {
+                    Ok(rate_result) => rate_result,
+                    Err(error) => break 'shift Err(error),
+                };
+                if rate_result.rate != shift_base_rate {
+                    all_audit_steps.extend(rate_result.audit_step);
+                    step_number += 1;
+                    shift_base_rate = rate_result.rate;
+                }
+                rate_result.rate
+            };
+
+            // Segment the shift by day (handles overnight shifts), adjust for any
+            // daylight saving transition in the request timezone, assign the
+            // segments to days under the configured weekend definition, then
+            // apply any configured time rounding before rates are applied
+            let mut worked_hours = shift.worked_hours();
+            let mut segments = segment_by_day(shift);
+            if let Some(timezone) = timezone {
+                let adjustment = apply_timezone(
+                    &shift.id,
+                    worked_hours,
+                    segments,
+                    timezone,
+                    step_number,
+                    include_audit,
+                );
+                if let Some(audit_step) = adjustment.audit_step {
+                    all_audit_steps.push(audit_step);
+                    step_number += 1;
+                }
+                worked_hours = adjustment.worked_hours;
+                segments = adjustment.segments;
+            }
+            let assignment = assign_weekend_days(
+                shift,
+                segments,
+                award_config.penalties().weekend_days,
+                step_number,
+                include_audit,
+            );
+            if let Some(audit_step) = assignment.audit_step {
+                all_audit_steps.push(audit_step);
+                step_number += 1;
+            }
+            let segments = assignment.segments;
+            let rounding = apply_time_rounding(
+                &shift.id,
+                worked_hours,
+                segments,
+                award_config.penalties().time_rounding,
+                step_number,
+                include_audit,
+            );
+            if let Some(audit_step) = rounding.audit_step {
+                all_audit_steps.push(audit_step);
+                step_number += 1;
+            }
+            let segments = rounding.segments;
+            let total_worked_hours = rounding.worked_hours;
+
+            // Pick the daily threshold for the day the shift starts on
+            let threshold = select_daily_threshold(
+                &shift.id,
+                shift.date,
+                &award_config.penalties().overtime,
+                step_number,
+                include_audit,
+            );
+            if let Some(audit_step) = threshold.audit_step {
+                all_audit_steps.push(audit_step);
+                step_number += 1;
+            }
+
+            // Detect daily overtime for the entire shift, counting hours from
+            // earlier shifts on the same day towards the daily threshold
+            let prior_hours = hours_worked_by_day
+                .entry(shift.date)
+                .or_insert(Decimal::ZERO);
+            let overtime_detection = detect_cumulative_daily_overtime(
+                *prior_hours,
+                total_worked_hours,
+                threshold.threshold,
+                employee.max_ordinary_hours_per_day,
+                step_number,
+                include_audit,
+            );
+            *prior_hours += total_worked_hours;
+            all_audit_steps.extend(overtime_detection.audit_step);
+            step_number += 1;
+
+            // Track if we've already handled ordinary hours for this shift
+            let mut ordinary_hours_remaining = overtime_detection.ordinary_hours;
+            // Overtime falls in the segments left once the ordinary hours are
+            // used up, so each is paid at the rates of the day it is worked on
+            let mut overtime_portions: Vec<(DayType, NaiveDate, Decimal)> = Vec::new();
+
+            for segment in &segments {
+                let day_type = segment.day_type;
+
+                // Calculate hours for this segment, limited by remaining ordinary hours
+                let segment_ordinary_hours = if ordinary_hours_remaining >= segment.hours {
+                    ordinary_hours_remaining -= segment.hours;
+                    segment.hours
+                } else {
+                    let hours = ordinary_hours_remaining;
+                    ordinary_hours_remaining = Decimal::ZERO;
+                    hours
+                };
+                let segment_overtime_hours = segment.hours - segment_ordinary_hours;
+                if segment_overtime_hours > Decimal::ZERO {
+                    overtime_portions.push((
+                        day_type,
+                        segment.start_time.date(),
+                        segment_overtime_hours,
+                    ));
+                }
+
+                match day_type {
+                    DayType::Weekday => {
+                        if segment_ordinary_hours > Decimal::ZERO {
+                            // Calculate ordinary hours using the existing function
+                            let ordinary_result = match calculate_ordinary_hours(
+                                shift,
+                                employee,
+                                award_config,
+                                step_number,
+                                include_audit,
+                            ) {
+                                Ok(ordinary_result) => ordinary_result,
+                                Err(error) => break 'shift Err(error),
+                            };
+
+                            // Adjust the pay line for the actual segment hours
+                            let mut pay_line = ordinary_result.pay_line;
+                            pay_line.shift_id = shift.id.clone();
+                            pay_line.date = segment.start_time.date();
+                            pay_line.hours = segment_ordinary_hours;
+                            pay_line.amount = segment_ordinary_hours * pay_line.rate;
+
+                            all_pay_lines.push(pay_line);
+                            let steps_count = ordinary_result.audit_steps.len();
+                            all_audit_steps.extend(ordinary_result.audit_steps);
+                            step_number += steps_count as u32;
+                        }
+                    }
+                    DayType::Saturday => {
+                        if segment_ordinary_hours > Decimal::ZERO {
+                            // Create a segment for the ordinary hours
+                            let mut seg = segment.clone();
+                            seg.hours = segment_ordinary_hours;
+
+                            let saturday_result = calculate_saturday_pay(
+                                &seg,
+                                employee,
+                                base_rate,
+                                award_config,
+                                step_number,
+                                include_audit,
+                            );
+
+                            let mut pay_line = saturday_result.pay_line;
+                            pay_line.shift_id = shift.id.clone();
+                            all_pay_lines.push(pay_line);
+                            let mut audit_step = saturday_result.audit_step;
+                            if let (Some(audit_step), Some(override_rate)) =
+                                (&mut audit_step, shift.rate_override)
+                            {
+                                flag_shift_rate_override(audit_step, override_rate);
+                            }
+                            all_audit_steps.extend(audit_step);
+                            step_number += 1;
+                        }
+                    }
+                    DayType::Sunday => {
+                        if segment_ordinary_hours > Decimal::ZERO {
+                            // Create a segment for the ordinary hours
+                            let mut seg = segment.clone();
+                            seg.hours = segment_ordinary_hours;
+
+                            let sunday_result = calculate_sunday_pay(
+                                &seg,
+                                employee,
+                                base_rate,
+                                award_config,
+                                step_number,
+                                include_audit,
+                            );
+
+                            let mut pay_line = sunday_result.pay_line;
+                            pay_line.shift_id = shift.id.clone();
+                            all_pay_lines.push(pay_line);
+                            let mut audit_step = sunday_result.audit_step;
+                            if let (Some(audit_step), Some(override_rate)) =
+                                (&mut audit_step, shift.rate_override)
+                            {
+                                flag_shift_rate_override(audit_step, override_rate);
+                            }
+                            all_audit_steps.extend(audit_step);
+                            step_number += 1;
+                        }
+                    }
+                }
+            }
+
+            // Pay each day's portion of the overtime at that day's rates: a
+            // public holiday's (when configured), the weekday tiers, or the
+            // Saturday or Sunday rates
+            for (day_type, date, portion_hours) in overtime_portions {
+                let public_holiday_overtime = award_config
+                    .penalties()
+                    .overtime
+                    .public_holiday
+                    .as_ref()
+                    .filter(|_| pay_period.is_public_holiday(date));
+                let (pay_lines, tiers, audit_steps) = match public_holiday_overtime {
+                    Some(public_holiday_config) => {
+                        let overtime_result = calculate_public_holiday_overtime(
+                            portion_hours,
+                            base_rate,
+                            employee,
+                            public_holiday_config,
+                            award_config.penalties().overtime.casual_ot_base,
+                            date,
+                            &shift.id,
+                            step_number,
+                            include_audit,
+                        );
+                        (
+                            overtime_result.pay_lines,
+                            overtime_result.tiers,
+                            overtime_result.audit_steps,
+                        )
+                    }
+                    None if day_type == DayType::Weekday => {
+                        let overtime_result = calculate_weekday_overtime(
+                            portion_hours,
+                            base_rate,
+                            employee,
+                            award_config,
+                            date,
+                            &shift.id,
+                            step_number,
+                            include_audit,
+                        );
+                        (
+                            overtime_result.pay_lines,
+                            overtime_result.tiers,
+                            overtime_result.audit_steps,
+                        )
+                    }
+                    None => {
+                        let overtime_result = calculate_weekend_overtime(
+                            portion_hours,
+                            base_rate,
+                            employee,
+                            award_config,
+                            day_type,
+                            date,
+                            &shift.id,
+                            step_number,
+                            include_audit,
+                        );
+                        (
+                            overtime_result.pay_lines,
+                            overtime_result.tiers,
+                            overtime_result.audit_steps,
+                        )
+                    }
+                };
+
+                all_pay_lines.extend(pay_lines);
+                overtime_detail.extend(tiers);
+                step_number += audit_steps.len() as u32;
+                all_audit_steps.extend(audit_steps);
+            }
+
+            // Check that a long shift included a meal break
+            if let Some(meal_break_config) = &award_config.penalties().meal_break {
+                let check = check_meal_break_taken(
+                    shift,
+                    base_rate,
+                    meal_break_config,
+                    step_number,
+                    include_audit,
+                );
+                if let Some(audit_step) = check.audit_step {
+                    all_audit_steps.push(audit_step);
+                    step_number += 1;
+                }
+                all_pay_lines.extend(check.pay_line);
+                all_warnings.extend(check.warning);
+            }
+
+            // Pay the loading for a shift commencing before the early hour
+            if let Some(early_config) = &award_config.penalties().early_commencement {
+                let check = check_early_commencement(
+                    shift,
+                    total_worked_hours,
+                    base_rate,
+                    early_config,
+                    step_number,
+                    include_audit,
+                );
+                if let Some(audit_step) = check.audit_step {
+                    all_audit_steps.push(audit_step);
+                    step_number += 1;
+                }
+                all_pay_lines.extend(check.pay_line);
+            }
+
+            // Guard against the segment and overtime split dropping or
+            // double-paying hours
+            all_warnings.extend(reconcile_shift_hours(
+                &shift.id,
+                total_worked_hours,
+                &all_pay_lines[shift_pay_lines_start..],
+            ));
+            shift_base_rates.insert(shift.id.as_str(), base_rate);
+            shift_worked_hours.insert(shift.id.as_str(), total_worked_hours);
+            Ok(())
+        };
+
+        if let Err(error) = outcome {
+            if !partial_results {
+                return Err(error);
+            }
+            all_pay_lines.truncate(shift_pay_lines_start);
+            overtime_detail.truncate(shift_overtime_detail_start);
+            all_audit_steps.truncate(shift_audit_steps_start);
+            all_warnings.truncate(shift_warnings_start);
+            step_number = shift_step_number;
+            shift_base_rate = prior_shift_base_rate;
+            match prior_day_hours {
+                Some(hours) => hours_worked_by_day.insert(shift.date, hours),
+                None => hours_worked_by_day.remove(&shift.date),
+            };
+            failed_shift_ids.insert(shift.id.as_str());
+            all_warnings.push(AuditWarning {
+                code: SHIFT_CALCULATION_FAILED_WARNING.to_string(),
+                message: format!(
+                    "Shift '{}' could not be calculated and is left out of the totals: {}",
+                    shift.id, error
+                ),
+                severity: "high".to_string(),
+            });
+        }
+    }
+
+    // The steps below only see the shifts that were calculated, so a shift
+    // left out of a partial result earns no penalties, top-ups or allowances.
+    // It was still rostered, so its day does not count as a public holiday
+    // not worked
+    let all_shifts = shifts;
+    let calculated_shifts: Vec<Shift> = shifts
+        .iter()
+        .filter(|s| !failed_shift_ids.contains(s.id.as_str()))
+        .cloned()
+        .collect();
+    let shifts = calculated_shifts.as_slice();
+
+    // Pay public holidays the employee would ordinarily have worked but did not
+    if let Some(not_worked_config) = &award_config.penalties().public_holiday_not_worked {
+        let not_worked_result = calculate_public_holidays_not_worked(
+            employee,
+            pay_period,
+            all_shifts,
+            base_rate,
+            not_worked_config,
+            step_number,
+            include_audit,
+        );
+        all_pay_lines.extend(not_worked_result.pay_lines);
+        let steps_count = not_worked_result.audit_steps.len();
+        all_audit_steps.extend(not_worked_result.audit_steps);
+        step_number += steps_count as u32;
+    }
+
+    // Flag days worked beyond the configured run of consecutive days
+    if let Some(consecutive_days_config) = &award_config.penalties().consecutive_days
+        && !shifts.is_empty()
+    {
+        let consecutive_days = detect_consecutive_days(
+            employee,
+            shifts,
+            consecutive_days_config,
+            step_number,
+            include_audit,
+        );
+        step_number += consecutive_days.audit_steps.len() as u32;
+        all_audit_steps.extend(consecutive_days.audit_steps);
+    }
+
+    // Days are penalised and topped up at the base rate their shifts were
+    // paid, so an override rate, classification or dated rate carries over
+    let paid_base_rate = |shift: &Shift| {
+        shift_base_rates
+            .get(shift.id.as_str())
+            .copied()
+            .unwrap_or(base_rate)
+    };
+
+    // Penalise days whose span of hours, breaks and gaps included, is too long
+    if let Some(span_of_hours_config) = &award_config.penalties().span_of_hours {
+        let span_result = calculate_span_penalty(
+            shifts,
+            paid_base_rate,
+            span_of_hours_config,
+            step_number,
+            include_audit,
+        );
+        all_pay_lines.extend(span_result.pay_lines);
+        step_number += span_result.audit_steps.len() as u32;
+        all_audit_steps.extend(span_result.audit_steps);
+    }
+
+    // Top up days paid less than the minimum daily hours, counting every hour
+    // already paid for the day's shifts
+    if let Some(minimum_daily_config) = &award_config.penalties().minimum_daily_pay {
+        let minimum_result = calculate_minimum_daily_pay(
+            shifts,
+            &all_pay_lines,
+            employee,
+            paid_base_rate,
+            minimum_daily_config,
+            step_number,
+            include_audit,
+        );
+        all_pay_lines.extend(minimum_result.pay_lines);
+        step_number += minimum_result.audit_steps.len() as u32;
+        all_audit_steps.extend(minimum_result.audit_steps);
+    }
+
+    // Calculate laundry allowance (only when shifts were worked)
+    let mut allowances: Vec<AllowancePayment> = Vec::new();
+    let allowance_steps_start = all_audit_steps.len();
+    if !shifts.is_empty() {
+        let (laundry_per_shift, laundry_per_week) = config.get_allowance_rates(effective_date)?;
+
+        // The laundry cap is weekly, so count shifts per award week. Shifts
+        // paid for less than the configured minimum do not count
+        let week_start_day = award_config.penalties().week_start_day;
+        let min_shift_hours = award_config.penalties().laundry_min_shift_hours;
+        let has_laundry_tag = employee.tags.iter().any(|tag| tag == LAUNDRY_ALLOWANCE_TAG);
+        let mut shifts_by_week: BTreeMap<NaiveDate, u32> = BTreeMap::new();
+        for shift in shifts {
+            let worked_hours = shift_worked_hours
+                .get(shift.id.as_str())
+                .copied()
+                .unwrap_or_else(|| shift.worked_hours());
+            if worked_hours >= min_shift_hours {
+                *shifts_by_week
+                    .entry(week_key(shift.date, week_start_day))
+                    .or_insert(0) += 1;
+            } else if include_audit && has_laundry_tag {
+                all_audit_steps.push(laundry_shift_excluded_step(
+                    shift,
+                    worked_hours,
+                    min_shift_hours,
+                    step_number,
+                ));
+                step_number += 1;
+            }
+        }
+
+        for num_shifts in shifts_by_week.into_values() {
+            let laundry_result = calculate_laundry_allowance(
+                employee,
+                num_shifts,
+                laundry_per_shift,
+                laundry_per_week,
+                step_number,
+                include_audit,
+            );
+            all_audit_steps.extend(laundry_result.audit_step);
+            allowances.extend(laundry_result.allowance);
+            step_number += 1;
+        }
+
+        // Weekly allowances are paid for each award week in the pay period
+        let weeks = pay_period.weeks_in_period(week_start_day);
+
+        if let Some(qualification_rate) = config.get_qualification_allowance_rate(effective_date)? {
+            // The hours paid, after any daylight saving adjustment and time
+            // rounding
+            let hours_worked: Decimal = shift_worked_hours.values().copied().sum();
+            let qualification_result = calculate_qualification_allowance(
+                employee,
+                hours_worked,
+                weeks,
+                &qualification_rate,
+                step_number,
+                include_audit,
+            );
+            all_audit_steps.extend(qualification_result.audit_step);
+            allowances.extend(qualification_result.allowance);
+            step_number += 1;
+        }
+
+        if let Some(first_aid_rate) = config.get_first_aid_allowance_rate(effective_date)? {
+            let first_aid_result = calculate_first_aid_allowance(
+                employee,
+                weeks,
+                first_aid_rate,
+                step_number,
+                include_audit,
+            );
+            all_audit_steps.extend(first_aid_result.audit_step);
+            allowances.extend(first_aid_result.allowance);
+            step_number += 1;
+        }
+
+        // Broken shifts attract both the allowance and, once per day, the
+        // travel reimbursement
+        if let Some(broken_shift_rate) = config.get_broken_shift_allowance_rate(effective_date)? {
+            let broken_shift_result = calculate_broken_shift_allowance(
+                employee,
+                shifts,
+                broken_shift_rate,
+                step_number,
+                include_audit,
+            );
+            all_audit_steps.extend(broken_shift_result.audit_step);
+            allowances.extend(broken_shift_result.allowance);
+            step_number += 1;
+        }
+
+        if let Some(travel_rate) = config.get_split_shift_travel_rate(effective_date)? {
+            let travel_result = calculate_split_shift_travel(
+                employee,
+                shifts,
+                travel_rate,
+                step_number,
+                include_audit,
+            );
+            all_audit_steps.extend(travel_result.audit_step);
+            allowances.extend(travel_result.allowance);
+            step_number += 1;
+        }
+    }
+
+    // Consolidate the allowance steps so the period's allowances can be
+    // reviewed in one place
+    if include_audit && !allowances.is_empty() {
+        let summary =
+            allowance_summary_step(&all_audit_steps[allowance_steps_start..], step_number);
+        all_audit_steps.push(summary);
+        step_number += 1;
+    }
+
+    // Carry each shift's reference and cost centre onto its pay lines for GL
+    // coding; they play no part in the calculation
+    let shifts_by_id: HashMap<&str, &Shift> = shifts.iter().map(|s| (s.id.as_str(), s)).collect();
+    for pay_line in &mut all_pay_lines {
+        if let Some(shift) = shifts_by_id.get(pay_line.shift_id.as_str()) {
+            pay_line.reference.clone_from(&shift.reference);
+            pay_line.cost_centre.clone_from(&shift.cost_centre);
+        }
+    }
+
+    // Sort pay lines for payslip display: by date, then ordinary, penalty and
+    // overtime lines (the sort is stable, so shift order is kept within a category)
+    all_pay_lines.sort_by_key(|pl| (pl.date, pl.category.ordinal()));
+
+    // Round to the cent for payslips that print each line, keeping the lines
+    // in step with the rounded gross
+    if award_config.penalties().rounding_reconciliation {
+        let rounding_step = reconcile_rounding(
+            &mut all_pay_lines,
+            &mut allowances,
+            step_number,
+            include_audit,
+        );
+        for tier in &mut overtime_detail {
+            tier.amount = round_to_cents(tier.amount);
+        }
+        if let Some(rounding_step) = rounding_step {
+            all_audit_steps.push(rounding_step);
+            step_number += 1;
+        }
+    }
+
+    // Calculate totals
+    let pay_lines_total: Decimal = all_pay_lines.iter().map(|pl| pl.amount).sum();
+    let mut gross_by_category: BTreeMap<PayCategory, Decimal> = BTreeMap::new();
+    for pay_line in &all_pay_lines {
+        *gross_by_category
+            .entry(pay_line.category)
+            .or_insert(Decimal::ZERO) += pay_line.amount;
+    }
+    let allowances_total: Decimal = allowances.iter().map(|a| a.amount).sum();
+    let mut allowances_by_type: BTreeMap<String, Decimal> = BTreeMap::new();
+    for allowance in &allowances {
+        *allowances_by_type
+            .entry(allowance.allowance_type.to_string())
+            .or_insert(Decimal::ZERO) += allowance.amount;
+    }
+    let gross_pay = pay_lines_total + allowances_total;
+
+    // Flag an implausibly large gross, which usually points to a data entry
+    // error such as a mis-keyed shift; the result itself is unchanged
+    if let Some(max_expected_gross) = award_config.penalties().max_expected_gross
+        && gross_pay > max_expected_gross
+    {
+        all_warnings.push(AuditWarning {
+            code: GROSS_EXCEEDS_EXPECTED_WARNING.to_string(),
+            message: format!(
+                "Gross pay ${} for employee '{}' exceeds the expected maximum of ${}; check the shifts for data entry errors",
+                gross_pay.normalize(),
+                employee.id,
+                max_expected_gross.normalize()
+            ),
+            severity: "high".to_string(),
+        });
+    }
+
+    let ordinary_hours = total_hours(&all_pay_lines, |category| {
+        matches!(
+            category,
+            PayCategory::Ordinary | PayCategory::OrdinaryCasual | PayCategory::MinimumDailyPay
+        )
+    });
+
+    let overtime_hours = total_hours(&all_pay_lines, |category| {
+        matches!(
+            category,
+            PayCategory::Overtime150
+                | PayCategory::Overtime200
+                | PayCategory::Overtime250
+                | PayCategory::PublicHolidayOvertime
+        )
+    });
+
+    let penalty_hours = total_hours(&all_pay_lines, |category| {
+        matches!(
+            category,
+            PayCategory::Saturday
+                | PayCategory::SaturdayCasual
+                | PayCategory::Sunday
+                | PayCategory::SundayCasual
+        )
+    });
+
+    // Hours worked count the categories that pay for time worked, as the
+    // per-shift hours reconciliation does
+    let total_hours_worked = total_hours(&all_pay_lines, is_worked_time);
+
+    // Accrue leave on the ordinary hours paid, weekend and public holiday not
+    // worked hours included; with no hours paid nothing accrues, so no step is
+    // recorded. Days in lieu for public holidays on rostered days off are
+    // recorded alongside
+    let accruals = award_config
+        .penalties()
+        .leave_accrual
+        .as_ref()
+        .map(|accrual_config| {
+            let accrual_hours = ordinary_hours
+                + penalty_hours
+                + total_hours(&all_pay_lines, |category| {
+                    category == PayCategory::PublicHolidayNotWorked
+                });
+            let accrual_result = calculate_leave_accrual(
+                employee,
+                accrual_hours,
+                accrual_config,
+                step_number,
+                include_audit,
+            );
+            if accrual_hours > Decimal::ZERO {
+                all_audit_steps.extend(accrual_result.audit_step);
+                step_number += 1;
+            }
+            let mut accruals = accrual_result.accruals;
+            if let Some(in_lieu_config) = &accrual_config.public_holiday_in_lieu {
+                let in_lieu_result = calculate_public_holidays_in_lieu(
+                    employee,
+                    pay_period,
+                    all_shifts,
+                    in_lieu_config,
+                    step_number,
+                    include_audit,
+                );
+                step_number += in_lieu_result.audit_steps.len() as u32;
+                all_audit_steps.extend(in_lieu_result.audit_steps);
+                accruals.days_in_lieu = in_lieu_result.days_in_lieu;
+            }
+            accruals
+        });
+
+    // Close the audit trail with how the gross pay was assembled
+    if include_audit {
+        all_audit_steps.push(AuditStep {
+            step_number,
+            rule_id: "gross_pay_reconciliation".to_string(),
+            rule_name: "Gross Pay Reconciliation".to_string(),
+            clause_ref: "N/A".to_string(),
+            input: serde_json::json!({
+                "pay_lines_count": all_pay_lines.len(),
+                "pay_lines_total": pay_lines_total.normalize().to_string(),
+                "allowances_count": allowances.len(),
+                "allowances_total": allowances_total.normalize().to_string()
+            }),
+            output: serde_json::json!({
+                "gross_pay": gross_pay.normalize().to_string()
+            }),
+            reasoning: format!(
+                "Pay lines total ${} + allowances total ${} = gross pay ${}",
+                pay_lines_total.normalize(),
+                allowances_total.normalize(),
+                gross_pay.normalize()
+            ),
+        });
+    }
+
+    // Group pay lines by shift, in the order the shifts were processed
+    let mut breakdown_order: Vec<&Shift> = shifts.iter().collect();
+    breakdown_order.sort_by_key(|s| s.start_time);
+    let shift_breakdown: Vec<ShiftResult> = breakdown_order
+        .into_iter()
+        .map(|shift| {
+            let pay_lines: Vec<PayLine> = all_pay_lines
+                .iter()
+                .filter(|pl| pl.shift_id == shift.id)
+                .cloned()
+                .collect();
+            let subtotal = pay_lines.iter().map(|pl| pl.amount).sum();
+            ShiftResult {
+                shift_id: shift.id.clone(),
+                pay_lines,
+                subtotal,
+            }
+        })
+        .collect();
+
+    let duration_us = start_time.elapsed().as_micros() as u64;
+
+    let mut result = CalculationResult {
+        calculation_id: Uuid::new_v4(),
+        timestamp: Utc::now(),
+        engine_version: env!("CARGO_PKG_VERSION").to_string(),
+        schema_version: RESULT_SCHEMA_VERSION.to_string(),
+        build_metadata: None,
+        correlation_id: None,
+        employee_id: employee.id.clone(),
+        pay_period: pay_period.clone(),
+        pay_lines: all_pay_lines,
+        shift_breakdown,
+        overtime_detail,
+        allowances,
+        applied_clauses: vec![],
+        unapplied_rules: None,
+        totals: PayTotals {
+            gross_pay,
+            ordinary_hours,
+            overtime_hours,
+            penalty_hours,
+            total_hours_worked,
+            allowances_total,
+            allowances_by_type,
+            gross_by_category,
+        },
+        accruals,
+        input: None,
+        estimate: false,
+        audit_trace: AuditTrace {
+            steps: all_audit_steps,
+            warnings: all_warnings,
+            duration_us,
+        },
+    };
+    result.applied_clauses = result.collect_applied_clauses();
+
+    Ok(result)
+}
+
+/// Builds the step summarising the allowances paid, from their audit steps.
+///
+/// Each allowance paid is listed with the reasoning of its steps (one per
+/// award week for the weekly-capped laundry allowance), how many of them hit
+/// a cap, and its total for the pay period. Allowances the employee was not
+/// eligible for are left out.
+fn allowance_summary_step(allowance_steps: &[AuditStep], step_number: u32) -> AuditStep {
+    let mut rule_ids: Vec<&str> = Vec::new();
+    for step in allowance_steps {
+        if step.output["eligible"] == true && !rule_ids.contains(&step.rule_id.as_str()) {
+            rule_ids.push(&step.rule_id);
+        }
+    }
+
+    let mut summaries = Vec::new();
+    let mut descriptions = Vec::new();
+    let mut period_total = Decimal::ZERO;
+    for rule_id in rule_ids {
+        let steps: Vec<&AuditStep> = allowance_steps
+            .iter()
+            .filter(|step| step.rule_id == rule_id && step.output["eligible"] == true)
+            .collect();
+        let total: Decimal = steps
+            .iter()
+            .filter_map(|step| step.output["amount"].as_str()?.parse::<Decimal>().ok())
+            .sum();
+        let caps_applied = steps
+            .iter()
+            .filter(|step| step.output["cap_applied"] == true)
+            .count();
+        period_total += total;
+
+        descriptions.push(if caps_applied > 0 {
+            format!(
+                "{} ${} (capped in {} of {})",
+                steps[0].rule_name,
+                total.normalize(),
+                caps_applied,
+                steps.len()
+            )
+        } else {
+            format!("{} ${}", steps[0].rule_name, total.normalize())
+        });
+        summaries.push(serde_json::json!({
+            "rule_id": rule_id,
+            "rule_name": steps[0].rule_name,
+            "clause_ref": steps[0].clause_ref,
+            "calculations": steps.iter().map(|s| s.reasoning.as_str()).collect::<Vec<_>>(),
+            "caps_applied": caps_applied,
+            "total": total.normalize().to_string()
+        }));
+    }
+
+    AuditStep {
+        step_number,
+        rule_id: "allowance_summary".to_string(),
+        rule_name: "Allowance Summary".to_string(),
+        clause_ref: "N/A".to_string(),
+        input: serde_json::json!({
+            "allowances": summaries
+        }),
+        output: serde_json::json!({
+            "allowances_total": period_total.normalize().to_string()
+        }),
+        reasoning: format!(
+            "{} = allowances total ${}",
+            descriptions.join(" + "),
+            period_total.normalize()
+        ),
+    }
+}
+
+/// Rounds a dollar amount to the cent, with half a cent rounded up.
+fn round_to_cents(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+}
+
+/// Rounds each pay line and allowance to the cent, then adds the rounding
+/// residual to the largest pay line so the rounded lines add up to the
+/// rounded gross pay, and builds the step recording the adjustment when
+/// `include_audit` is set.
+///
+/// Rounding each line on its own can leave their sum a cent or more away from
+/// the rounded sum of the unrounded lines; the residual is that difference.
+/// The adjusted line's rate is left as it was, so its amount may differ from
+/// hours × rate by the residual.
+fn reconcile_rounding(
+    pay_lines: &mut [PayLine],
+    allowances: &mut [AllowancePayment],
+    step_number: u32,
+    include_audit: bool,
+) -> Option<AuditStep> {
+    let pay_lines_count = pay_lines.len();
+    let unrounded_total: Decimal = pay_lines.iter().map(|pl| pl.amount).sum::<Decimal>()
+        + allowances.iter().map(|a| a.amount).sum::<Decimal>();
+    let gross_pay = round_to_cents(unrounded_total);
+
+    for pay_line in pay_lines.iter_mut() {
+        pay_line.amount = round_to_cents(pay_line.amount);
+    }
+    for allowance in allowances.iter_mut() {
+        allowance.amount = round_to_cents(allowance.amount);
+    }
+    let rounded_total: Decimal = pay_lines.iter().map(|pl| pl.amount).sum::<Decimal>()
+        + allowances.iter().map(|a| a.amount).sum::<Decimal>();
+    let residual = gross_pay - rounded_total;
+
+    // The first of equally large lines, in payslip order
+    let mut adjusted_line = pay_lines
+        .iter_mut()
+        .rev()
+        .max_by_key(|pl| pl.amount)
+        .filter(|_| !residual.is_zero());
+    if let Some(pay_line) = &mut adjusted_line {
+        pay_line.amount += residual;
+    }
+    if !include_audit {
+        return None;
+    }
+
+    let (adjusted, reasoning) = match adjusted_line {
+        Some(pay_line) => (
+            serde_json::json!({
+                "date": pay_line.date.to_string(),
+                "shift_id": pay_line.shift_id,
+                "category": pay_line.category,
+                "amount": pay_line.amount.normalize().to_string()
+            }),
+            format!(
+                "Rounded lines total ${} against a rounded gross of ${}: ${} added to the largest pay line ({} on {}), now ${}",
+                rounded_total.normalize(),
+                gross_pay.normalize(),
+                residual.normalize(),
+                pay_line.category,
+                pay_line.date,
+                pay_line.amount.normalize()
+            ),
+        ),
+        None => (
+            serde_json::Value::Null,
+            format!(
+                "Rounded lines total ${}, matching the rounded gross; no adjustment needed",
+                rounded_total.normalize()
+            ),
+        ),
+    };
+
+    Some(AuditStep {
+        step_number,
+        rule_id: "rounding_reconciliation".to_string(),
+        rule_name: "Rounding Reconciliation".to_string(),
+        clause_ref: "N/A".to_string(),
+        input: serde_json::json!({
+            "pay_lines_count": pay_lines_count,
+            "allowances_count": allowances.len(),
+            "unrounded_total": unrounded_total.normalize().to_string()
+        }),
+        output: serde_json::json!({
+            "rounded_lines_total": rounded_total.normalize().to_string(),
+            "gross_pay": gross_pay.normalize().to_string(),
+            "residual": residual.normalize().to_string(),
+            "adjusted_line": adjusted
+        }),
+        reasoning,
+    })
+}
+
+/// Sums the hours of the pay lines whose category matches, held to
+/// [`HOURS_PRECISION`] so the totals read the same as the pay lines
+/// (e.g. `8` rather than `8.0000`).
+fn total_hours(pay_lines: &[PayLine], include: impl Fn(PayCategory) -> bool) -> Decimal {
+    pay_lines
+        .iter()
+        .filter(|pl| include(pl.category))
+        .map(|pl| pl.hours)
+        .sum::<Decimal>()
+        .round_dp(HOURS_PRECISION)
+        .normalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::request::{
+        CalculationRequest, EmployeeRequest, PayPeriodRequest, ShiftRequest,
+    };
+    use crate::config::{
+        AwardConfig, ConfigLoader, PenaltyConfig, TimeRounding, WeekendDayBasis, WeekendDays,
+    };
+    use crate::models::{AUDIT_TRUNCATED_RULE_ID, AllowanceType, EmploymentType};
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use chrono::{NaiveDate, NaiveDateTime, Weekday};
+    use tower::ServiceExt;
+
+    fn create_test_state() -> AppState {
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        AppState::new(config)
+    }
+
+    fn make_datetime(date_str: &str, time_str: &str) -> NaiveDateTime {
         NaiveDateTime::parse_from_str(&format!("{} {}", date_str, time_str), "%Y-%m-%d %H:%M:%S")
             .unwrap()
     }
 
-    fn make_date(date_str: &str) -> NaiveDate {
-        NaiveDate::parse_from_str(date_str, "%Y-%m-%d").unwrap()
+    fn make_date(date_str: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date_str, "%Y-%m-%d").unwrap()
+    }
+
+    fn create_valid_request() -> CalculationRequest {
+        CalculationRequest {
+            employee: EmployeeRequest {
+                id: "emp_001".to_string(),
+                employment_type: EmploymentType::FullTime,
+                classification_code: "dce_level_3".to_string(),
+                date_of_birth: make_date("1985-03-15"),
+                employment_start_date: make_date("2020-01-01"),
+                base_hourly_rate: None,
+                max_ordinary_hours_per_day: None,
+                ordinary_days: vec![],
+                rostered_days_off: vec![],
+                tags: vec![],
+            },
+            pay_period: PayPeriodRequest {
+                start_date: make_date("2026-01-13"),
+                end_date: make_date("2026-01-19"),
+                public_holidays: vec![],
+                period_type: None,
+            },
+            shifts: vec![ShiftRequest {
+                id: "shift_001".to_string(),
+                date: make_date("2026-01-13"),
+                start_time: make_datetime("2026-01-13", "09:00:00"),
+                end_time: Some(make_datetime("2026-01-13", "17:00:00")),
+                duration_minutes: None,
+                expected_hours: None,
+                breaks: vec![],
+                rate_override: None,
+                classification_code: None,
+                reference: None,
+                cost_centre: None,
+            }],
+            timezone: None,
+            state: None,
+            award_code: None,
+            include_audit: true,
+            deterministic_id: false,
+            partial_results: false,
+            require_shifts: false,
+            amounts_in_cents: false,
+            diagnostics: false,
+            echo_request: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_api_001_valid_request_returns_200() {
+        let state = create_test_state();
+        let router = create_router(state);
+
+        let request = create_valid_request();
+        let body = serde_json::to_string(&request).unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Verify Content-Type header
+        let content_type = response.headers().get("content-type").unwrap();
+        assert_eq!(content_type, "application/json");
+
+        // Verify response body is valid CalculationResult
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: CalculationResult = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(result.employee_id, "emp_001");
+        assert!(!result.pay_lines.is_empty());
+        assert!(result.totals.gross_pay > Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_client_correlation_id_is_echoed_and_recorded() {
+        let router = create_router(create_test_state());
+        let body = serde_json::to_string(&create_valid_request()).unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .header("X-Correlation-Id", "payrun-2026-01:emp_001")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("x-correlation-id").unwrap(),
+            "payrun-2026-01:emp_001"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: CalculationResult = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            result.correlation_id.as_deref(),
+            Some("payrun-2026-01:emp_001")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_correlation_id_is_replaced() {
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .header("X-Correlation-Id", "run 1\tlevel=error")
+                    .body(Body::from("{not json"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Error responses carry the correlation ID too
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let correlation_id = response.headers().get("x-correlation-id").unwrap();
+        assert!(Uuid::parse_str(correlation_id.to_str().unwrap()).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_api_002_malformed_json_returns_400() {
+        let state = create_test_state();
+        let router = create_router(state);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from("{invalid json"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(error.code, "MALFORMED_JSON");
+    }
+
+    /// Posts a diff of a 10-hour Tuesday shift between the loaded award and a
+    /// new version with a higher rate that pays all weekday overtime at 200%.
+    async fn post_diff(old_award: &str, new_award: &str) -> (StatusCode, Value) {
+        use std::str::FromStr;
+
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let config = loader.config();
+        let mut rates = config.rates().to_vec();
+        for rate_config in &mut rates {
+            rate_config.rates.get_mut("dce_level_3").unwrap().hourly =
+                Decimal::from_str("29.40").unwrap();
+        }
+        let mut penalties = config.penalties().clone();
+        let mut double_time = penalties.overtime.weekday.tiers[1].clone();
+        double_time.threshold_hours = Decimal::ZERO;
+        penalties.overtime.weekday.tiers = vec![double_time];
+        let new_version = ConfigLoader::from_config(AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            rates,
+            penalties,
+        ));
+        let state = create_test_state().with_award("MA000018-2026", new_version);
+
+        let mut request = serde_json::to_value(create_valid_request()).unwrap();
+        request["shifts"][0]["start_time"] = Value::from("2026-01-13T07:00:00");
+        request["old_award"] = Value::from(old_award);
+        request["new_award"] = Value::from(new_award);
+
+        let response = create_router(state)
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate/diff")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_calculate_diff_reports_changed_added_and_removed_lines() {
+        use crate::api::{CalculationDiff, PayLineChange};
+        use std::str::FromStr;
+
+        let (status, body) = post_diff("MA000018", "MA000018-2026").await;
+
+        assert_eq!(status, StatusCode::OK);
+        let diff: CalculationDiff = serde_json::from_value(body).unwrap();
+        assert_eq!(diff.old_award, "MA000018");
+        assert_eq!(diff.new_award, "MA000018-2026");
+
+        let changes: Vec<(PayCategory, PayLineChange)> = diff
+            .pay_lines
+            .iter()
+            .map(|d| (d.category, d.change))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (PayCategory::Ordinary, PayLineChange::Changed),
+                (PayCategory::Overtime150, PayLineChange::Removed),
+                (PayCategory::Overtime200, PayLineChange::Added),
+            ]
+        );
+        assert_eq!(
+            diff.pay_lines[0].rate.delta,
+            Decimal::from_str("0.86").unwrap()
+        );
+        assert_eq!(diff.pay_lines[0].hours.delta, Decimal::ZERO);
+        assert_eq!(diff.pay_lines[1].amount.new, Decimal::ZERO);
+        assert_eq!(diff.pay_lines[2].rate.old, Decimal::ZERO);
+
+        // Old: 8 × 28.54 + 2 × 42.81 = 313.94
+        // New: 8 × 29.40 + 2 × 58.80 = 352.80
+        assert_eq!(
+            diff.totals.gross_pay.delta,
+            Decimal::from_str("38.86").unwrap()
+        );
+        assert_eq!(
+            diff.totals.gross_pay.delta,
+            diff.new.totals.gross_pay - diff.old.totals.gross_pay
+        );
+        assert_eq!(diff.totals.overtime_hours.delta, Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_compare_employment_recalculates_under_the_other_type() {
+        use crate::api::EmploymentComparison;
+        use std::str::FromStr;
+
+        // A full-time employee who works Tuesday and has Wednesday, a public
+        // holiday, as an ordinary day off
+        let mut request = serde_json::to_value(create_valid_request()).unwrap();
+        request["employee"]["ordinary_days"] = serde_json::json!(["Tue", "Wed"]);
+        request["pay_period"]["public_holidays"] =
+            serde_json::json!([{ "date": "2026-01-14", "name": "Test Holiday" }]);
+        request["compare_employment_type"] = Value::from("casual");
+
+        let response = create_router(create_test_state())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate/compare-employment")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let comparison: EmploymentComparison = serde_json::from_slice(&body).unwrap();
+        assert_eq!(comparison.employment_type, EmploymentType::FullTime);
+        assert_eq!(comparison.compare_employment_type, EmploymentType::Casual);
+
+        // The casual is paid the 25% loading but not the public holiday
+        let categories = |result: &CalculationResult| -> Vec<PayCategory> {
+            result.pay_lines.iter().map(|line| line.category).collect()
+        };
+        assert!(categories(&comparison.result).contains(&PayCategory::PublicHolidayNotWorked));
+        assert_eq!(
+            categories(&comparison.compare_result),
+            vec![PayCategory::OrdinaryCasual]
+        );
+        assert_eq!(
+            comparison.compare_result.pay_lines[0].rate,
+            Decimal::from_str("35.675").unwrap()
+        );
+        assert_eq!(
+            comparison.totals.gross_pay.delta,
+            comparison.compare_result.totals.gross_pay - comparison.result.totals.gross_pay
+        );
+    }
+
+    #[tokio::test]
+    async fn test_earnings_export_maps_overtime_to_the_configured_code() {
+        use crate::api::EarningsExport;
+        use crate::config::EarningCodes;
+        use std::str::FromStr;
+
+        let mut codes = EarningCodes::default();
+        codes
+            .pay_categories
+            .insert(PayCategory::Ordinary, "ORD".to_string());
+        codes
+            .pay_categories
+            .insert(PayCategory::Overtime150, "XOT-150".to_string());
+        let config = ConfigLoader::load("./config/ma000018")
+            .expect("Failed to load config")
+            .with_earning_codes(codes);
+        // 07:00-18:00: 8 ordinary hours, 2 at time and a half and 1 at double time
+        let mut request = create_valid_request();
+        request.shifts[0].start_time = make_datetime("2026-01-13", "07:00:00");
+        request.shifts[0].end_time = Some(make_datetime("2026-01-13", "18:00:00"));
+
+        let response = create_router(AppState::new(config))
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate/earnings")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let export: EarningsExport = serde_json::from_slice(&body).unwrap();
+        let codes: Vec<&str> = export
+            .earnings
+            .iter()
+            .map(|line| line.earning_code.as_str())
+            .collect();
+        // Overtime200 has no configured code, so keeps its own name
+        assert_eq!(codes, vec!["ORD", "XOT-150", "overtime200"]);
+        let overtime = &export.earnings[1];
+        assert_eq!(overtime.description, "Overtime (150%)");
+        assert_eq!(overtime.quantity, Decimal::from(2));
+        assert_eq!(overtime.rate, Decimal::from_str("42.81").unwrap());
+        let total: Decimal = export.earnings.iter().map(|line| line.amount).sum();
+        assert_eq!(total, export.gross_pay);
+    }
+
+    #[tokio::test]
+    async fn test_compare_employment_models_a_hypothetical_part_time_loading() {
+        use crate::api::EmploymentComparison;
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let mut request = serde_json::to_value(create_valid_request()).unwrap();
+        request["employee"]["employment_type"] = Value::from("part_time");
+        request["compare_employment_type"] = Value::from("casual");
+        request["hypothetical_part_time_loading"] = Value::Bool(true);
+
+        let response = create_router(create_test_state())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate/compare-employment")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(request.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let comparison: EmploymentComparison = serde_json::from_slice(&body).unwrap();
+
+        // The part-time result itself is paid without any loading
+        assert_eq!(comparison.result.pay_lines.len(), 1);
+        assert_eq!(
+            comparison.result.pay_lines[0].category,
+            PayCategory::Ordinary
+        );
+        assert_eq!(comparison.result.totals.gross_pay, dec("228.32"));
+        assert_eq!(comparison.totals.gross_pay.old, dec("228.32"));
+
+        // 8 hours × $28.54 × 1.25, matching the casual's ordinary pay
+        let hypothetical = comparison
+            .hypothetical_part_time_loading
+            .expect("hypothetical loading should be modelled");
+        assert!(hypothetical.note.starts_with("Hypothetical"));
+        assert_eq!(hypothetical.ordinary_hours, dec("8"));
+        assert_eq!(hypothetical.base_rate, dec("28.54"));
+        assert_eq!(hypothetical.loaded_rate, dec("35.675"));
+        assert_eq!(hypothetical.ordinary_pay, dec("228.32"));
+        assert_eq!(hypothetical.loaded_ordinary_pay, dec("285.4"));
+        assert_eq!(
+            hypothetical.loaded_ordinary_pay,
+            comparison.compare_result.totals.gross_pay
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calculate_diff_rejects_unknown_award() {
+        let (status, body) = post_diff("MA000018", "MA000999").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "UNKNOWN_AWARD");
+        assert_eq!(body["message"], "Award not found: MA000999");
+    }
+
+    #[tokio::test]
+    async fn test_form_encoded_body_returns_415() {
+        for uri in ["/calculate", "/validate"] {
+            let router = create_router(create_test_state());
+
+            let response = router
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(uri)
+                        .header("Content-Type", "application/x-www-form-urlencoded")
+                        .body(Body::from("employee_id=emp_001"))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let error: ApiError = serde_json::from_slice(&body).unwrap();
+            assert_eq!(error.code, "UNSUPPORTED_MEDIA_TYPE");
+            assert_eq!(error.message, "Content-Type must be application/json");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_roster_upload_calculates_each_employee() {
+        let csv = "\
+employee_id,classification,employment_type,date,start,end
+emp_001,dce_level_3,full_time,2026-01-13,09:00,17:00
+emp_002,no_such_level,casual,2026-01-13,09:00,17:00
+emp_001,dce_level_3,full_time,2026-01-14,nine,17:00
+emp_001,dce_level_3,full_time,2026-01-15,09:00,13:00
+";
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate/upload?start_date=2026-01-12&end_date=2026-01-18&include_audit=false")
+                    .header("Content-Type", "text/csv")
+                    .body(Body::from(csv))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let upload: RosterUploadResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(upload.results.len(), 1);
+        assert_eq!(upload.results[0].employee_id, "emp_001");
+        assert_eq!(upload.results[0].totals.ordinary_hours, Decimal::from(12));
+        assert!(upload.results[0].audit_trace.steps.is_empty());
+        assert_eq!(upload.employee_errors.len(), 1);
+        assert_eq!(upload.employee_errors[0].employee_id, "emp_002");
+        assert_eq!(
+            upload.employee_errors[0].error.code,
+            "CLASSIFICATION_NOT_FOUND"
+        );
+        assert_eq!(upload.row_errors.len(), 1);
+        assert_eq!(upload.row_errors[0].line, 4);
+    }
+
+    #[tokio::test]
+    async fn test_roster_upload_calculates_each_employee_under_their_award() {
+        use std::str::FromStr;
+
+        // A second award, paying dce_level_3 a different rate
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let config = loader.config();
+        let mut award = config.award().clone();
+        award.code = "MA000100".to_string();
+        let mut rates = config.rates().to_vec();
+        for rate_config in &mut rates {
+            rate_config.rates.get_mut("dce_level_3").unwrap().hourly =
+                Decimal::from_str("30.00").unwrap();
+        }
+        let second_award = ConfigLoader::from_config(AwardConfig::new(
+            award,
+            config.classifications().clone(),
+            rates,
+            config.penalties().clone(),
+        ));
+        let state = create_test_state().with_award("MA000100", second_award);
+
+        let csv = "\
+employee_id,classification,employment_type,date,start,end,award_code
+emp_001,dce_level_3,full_time,2026-01-13,09:00,17:00,
+emp_002,dce_level_3,full_time,2026-01-13,09:00,17:00,MA000100
+emp_003,dce_level_3,full_time,2026-01-13,09:00,17:00,MA000999
+";
+        let response = create_router(state)
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate/upload?start_date=2026-01-12&end_date=2026-01-18")
+                    .header("Content-Type", "text/csv")
+                    .body(Body::from(csv))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let upload: RosterUploadResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(upload.results.len(), 2);
+        // 8 hours at $28.54 under MA000018 and at $30.00 under MA000100
+        assert_eq!(upload.results[0].employee_id, "emp_001");
+        assert_eq!(
+            upload.results[0].totals.gross_pay,
+            Decimal::from_str("228.32").unwrap()
+        );
+        assert_eq!(upload.results[1].employee_id, "emp_002");
+        assert_eq!(
+            upload.results[1].totals.gross_pay,
+            Decimal::from_str("240.00").unwrap()
+        );
+        // An unknown award fails only that employee
+        assert_eq!(upload.employee_errors.len(), 1);
+        assert_eq!(upload.employee_errors[0].employee_id, "emp_003");
+        assert_eq!(upload.employee_errors[0].error.code, "UNKNOWN_AWARD");
+    }
+
+    #[tokio::test]
+    async fn test_calculate_rejects_unknown_award_code() {
+        let mut request = create_valid_request();
+        request.award_code = Some("MA000999".to_string());
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "UNKNOWN_AWARD");
+    }
+
+    #[tokio::test]
+    async fn test_calculate_before_the_earliest_rate_is_no_operative_rate() {
+        // The shipped rates take effect on 2025-07-01
+        let mut request = create_valid_request();
+        request.pay_period.start_date = make_date("2025-06-03");
+        request.pay_period.end_date = make_date("2025-06-09");
+        request.shifts[0].date = make_date("2025-06-03");
+        request.shifts[0].start_time = make_datetime("2025-06-03", "09:00:00");
+        request.shifts[0].end_time = Some(make_datetime("2025-06-03", "17:00:00"));
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "NO_OPERATIVE_RATE");
+        assert!(error.message.contains("dce_level_3"));
+    }
+
+    #[tokio::test]
+    async fn test_roster_upload_streams_json_lines() {
+        use crate::api::{RosterUploadLine, RosterUploadSummary};
+
+        let csv = "\
+employee_id,classification,employment_type,date,start,end
+emp_002,dce_level_3,casual,2026-01-13,09:00,17:00
+emp_001,no_such_level,full_time,2026-01-13,09:00,17:00
+emp_003,dce_level_3,full_time,2026-01-14,nine,17:00
+";
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate/upload?start_date=2026-01-12&end_date=2026-01-18")
+                    .header("Content-Type", "text/csv")
+                    .header("Accept", "application/x-ndjson, application/json;q=0.5")
+                    .body(Body::from(csv))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/x-ndjson"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.ends_with('\n'));
+        let lines: Vec<RosterUploadLine> = body
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(matches!(&lines[0], RosterUploadLine::RowError(e) if e.line == 4));
+        // Employees are streamed in the order they appear in the CSV
+        assert!(matches!(&lines[1], RosterUploadLine::Result(r) if r.employee_id == "emp_002"));
+        assert!(
+            matches!(&lines[2], RosterUploadLine::EmployeeError(e) if e.employee_id == "emp_001")
+        );
+        assert!(body.lines().last().unwrap().contains(r#""type":"summary""#));
+        let RosterUploadLine::Summary(summary) = lines[3] else {
+            panic!("expected the summary last, got {:?}", lines[3]);
+        };
+        assert_eq!(
+            summary,
+            RosterUploadSummary {
+                results_count: 1,
+                employee_errors_count: 1,
+                row_errors_count: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_roster_upload_requires_csv_content_type() {
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate/upload?start_date=2026-01-12&end_date=2026-01-18")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from("{}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.message, "Content-Type must be text/csv");
+    }
+
+    #[tokio::test]
+    async fn test_too_many_shifts_returns_413_with_counts() {
+        let mut request = create_valid_request();
+        let shift = request.shifts[0].clone();
+        request.shifts = (0..3)
+            .map(|i| ShiftRequest {
+                id: format!("shift_{}", i),
+                ..shift.clone()
+            })
+            .collect();
+        let body = serde_json::to_string(&request).unwrap();
+
+        for uri in ["/calculate", "/validate"] {
+            let router = create_router(create_test_state().with_max_shifts_per_request(2));
+
+            let response = router
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(uri)
+                        .header("Content-Type", "application/json")
+                        .body(Body::from(body.clone()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let error: ApiError = serde_json::from_slice(&body).unwrap();
+            assert_eq!(error.code, "TOO_MANY_SHIFTS");
+            assert_eq!(
+                error.message,
+                "Request has 3 shifts, more than the limit of 2"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_reads_rates_in_cents_as_calculate_does() {
+        let send = |uri: &'static str, body: Value| {
+            create_router(create_test_state()).oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+        };
+        let mut body = serde_json::to_value(create_valid_request()).unwrap();
+        body["amounts_in_cents"] = serde_json::json!(true);
+        body["shifts"][0]["rate_override"] = serde_json::json!(-3050);
+
+        // Both endpoints report the invalid rate in the dollars the engine
+        // checks it in
+        let expected = "Invalid shift 'shift_001': rate_override must be greater than zero \
+                        (found -30.50)";
+        let response = send("/calculate", body.clone()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(error.message, expected);
+
+        let response = send("/validate", body.clone()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let validation: ValidationResponse = serde_json::from_slice(&bytes).unwrap();
+        assert!(!validation.valid);
+        assert_eq!(validation.problems.len(), 1);
+        assert_eq!(validation.problems[0].message, expected);
+
+        // A valid rate in cents passes both
+        body["shifts"][0]["rate_override"] = serde_json::json!(3050);
+        let response = send("/calculate", body.clone()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = send("/validate", body).await.unwrap();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let validation: ValidationResponse = serde_json::from_slice(&bytes).unwrap();
+        assert!(validation.valid);
+    }
+
+    #[tokio::test]
+    async fn test_empty_shifts_pay_nothing_unless_shifts_are_required() {
+        let mut request = create_valid_request();
+        request.shifts = vec![];
+        let send = |request: &CalculationRequest| {
+            let body = serde_json::to_string(request).unwrap();
+            create_router(create_test_state()).oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+        };
+
+        // By default an empty roster is a valid, zero-pay result
+        let response = send(&request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: CalculationResult = serde_json::from_slice(&body).unwrap();
+        assert!(result.pay_lines.is_empty());
+        assert_eq!(result.totals.gross_pay, Decimal::ZERO);
+        assert_eq!(result.audit_trace.steps[0].rule_id, "no_shifts");
+
+        // A strict pipeline asks for the omitted roster to be rejected
+        request.require_shifts = true;
+        let response = send(&request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "NO_SHIFTS");
+        assert_eq!(
+            error.message,
+            "No shifts to calculate for employee 'emp_001'"
+        );
+    }
+
+    #[test]
+    fn test_expected_hours_give_an_estimate_of_the_actual_pay() {
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let actual = calculate_pay(create_valid_request(), &config).unwrap();
+
+        let mut request = create_valid_request();
+        request.shifts[0].end_time = None;
+        request.shifts[0].expected_hours = Some(Decimal::from(8));
+        let estimate = calculate_pay(request, &config).unwrap();
+
+        assert!(!actual.estimate);
+        assert!(estimate.estimate);
+        assert_eq!(estimate.pay_lines, actual.pay_lines);
+        assert_eq!(estimate.totals, actual.totals);
+        let json = serde_json::to_value(&estimate).unwrap();
+        assert_eq!(json["estimate"], true);
+    }
+
+    #[tokio::test]
+    async fn test_roster_upload_limits_shifts_across_employees() {
+        let csv = "\
+employee_id,classification,employment_type,date,start,end
+emp_001,dce_level_3,full_time,2026-01-13,09:00,17:00
+emp_002,dce_level_3,casual,2026-01-13,09:00,17:00
+";
+        let router = create_router(create_test_state().with_max_shifts_per_request(1));
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate/upload?start_date=2026-01-12&end_date=2026-01-18")
+                    .header("Content-Type", "text/csv")
+                    .body(Body::from(csv))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            error.message,
+            "Request has 2 shifts, more than the limit of 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_on_calculate_returns_405_with_allow_header() {
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/calculate")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers().get("allow").unwrap(), "POST");
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/json"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "METHOD_NOT_ALLOWED");
+        assert_eq!(error.message, "Method GET is not allowed on /calculate");
+    }
+
+    #[tokio::test]
+    async fn test_post_on_health_returns_405_with_allow_header() {
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers().get("allow").unwrap(), "GET,HEAD");
+    }
+
+    #[tokio::test]
+    async fn test_api_003_missing_employee_id_returns_400() {
+        let state = create_test_state();
+        let router = create_router(state);
+
+        // JSON with missing employee.id field
+        let body = r#"{
+            "employee": {
+                "employment_type": "full_time",
+                "classification_code": "dce_level_3",
+                "date_of_birth": "1985-03-15",
+                "employment_start_date": "2020-01-01"
+            },
+            "pay_period": {
+                "start_date": "2026-01-13",
+                "end_date": "2026-01-19"
+            },
+            "shifts": []
+        }"#;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+
+        // The error names the missing field by its full path
+        assert_eq!(error.code, "MISSING_FIELD");
+        assert_eq!(error.message, "missing field: employee.id");
+    }
+
+    #[tokio::test]
+    async fn test_api_004_unknown_classification_returns_400() {
+        let state = create_test_state();
+        let router = create_router(state);
+
+        let mut request = create_valid_request();
+        request.employee.classification_code = "unknown".to_string();
+        let body = serde_json::to_string(&request).unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiError = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(error.code, "CLASSIFICATION_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn test_fulltime_weekday_8h_calculation() {
+        let state = create_test_state();
+        let router = create_router(state);
+
+        let request = create_valid_request();
+        let body = serde_json::to_string(&request).unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: CalculationResult = serde_json::from_slice(&body).unwrap();
+
+        // 8 hours * $28.54 = $228.32
+        use std::str::FromStr;
+        assert_eq!(
+            result.totals.gross_pay,
+            Decimal::from_str("228.32").unwrap()
+        );
+        assert_eq!(
+            result.totals.ordinary_hours,
+            Decimal::from_str("8.0").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_casual_saturday_with_laundry() {
+        let state = create_test_state();
+        let router = create_router(state);
+
+        let request = CalculationRequest {
+            employee: EmployeeRequest {
+                id: "emp_cas_001".to_string(),
+                employment_type: EmploymentType::Casual,
+                classification_code: "dce_level_3".to_string(),
+                date_of_birth: make_date("1990-07-22"),
+                employment_start_date: make_date("2024-06-01"),
+                base_hourly_rate: None,
+                max_ordinary_hours_per_day: None,
+                ordinary_days: vec![],
+                rostered_days_off: vec![],
+                tags: vec!["laundry_allowance".to_string()],
+            },
+            pay_period: PayPeriodRequest {
+                start_date: make_date("2026-01-13"),
+                end_date: make_date("2026-01-19"),
+                public_holidays: vec![],
+                period_type: None,
+            },
+            shifts: vec![ShiftRequest {
+                id: "shift_001".to_string(),
+                date: make_date("2026-01-17"), // Saturday
+                start_time: make_datetime("2026-01-17", "09:00:00"),
+                end_time: Some(make_datetime("2026-01-17", "17:00:00")),
+                duration_minutes: None,
+                expected_hours: None,
+                breaks: vec![],
+                rate_override: None,
+                classification_code: None,
+                reference: None,
+                cost_centre: None,
+            }],
+            timezone: None,
+            state: None,
+            award_code: None,
+            include_audit: true,
+            deterministic_id: false,
+            partial_results: false,
+            require_shifts: false,
+            amounts_in_cents: false,
+            diagnostics: false,
+            echo_request: false,
+        };
+
+        let body = serde_json::to_string(&request).unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/calculate")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: CalculationResult = serde_json::from_slice(&body).unwrap();
+
+        // Casual Saturday: 8h * $28.54 * 1.75 = $399.56
+        // Plus laundry: $0.32
+        // Total: $399.88
+        use std::str::FromStr;
+        assert_eq!(
+            result.totals.gross_pay,
+            Decimal::from_str("399.88").unwrap()
+        );
+        assert_eq!(result.allowances.len(), 1);
+        assert_eq!(result.allowances[0].allowance_type, AllowanceType::Laundry);
+    }
+
+    /// Loads the MA000018 config with its penalty config modified by `modify`.
+    fn create_loader_with(modify: impl FnOnce(&mut PenaltyConfig)) -> ConfigLoader {
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let config = loader.config();
+        let mut penalties = config.penalties().clone();
+        modify(&mut penalties);
+        ConfigLoader::from_config(AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            config.rates().to_vec(),
+            penalties,
+        ))
+    }
+
+    fn create_shift(id: &str, date: &str) -> Shift {
+        Shift {
+            id: id.to_string(),
+            date: make_date(date),
+            start_time: make_datetime(date, "09:00:00"),
+            end_time: make_datetime(date, "13:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_time_rounding_applied_before_rates() {
+        let loader = create_loader_with(|p| p.time_rounding = TimeRounding::Up(15));
+
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // 7 hours 52 minutes rounds up to 8.0 hours
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "09:00:00"),
+            end_time: make_datetime("2026-01-13", "16:52:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        use std::str::FromStr;
+        assert_eq!(
+            result.totals.ordinary_hours,
+            Decimal::from_str("8.0").unwrap()
+        );
+        // 8 hours * $28.54 = $228.32
+        assert_eq!(
+            result.totals.gross_pay,
+            Decimal::from_str("228.32").unwrap()
+        );
+        let rounding_step = result
+            .audit_trace
+            .steps
+            .iter()
+            .find(|s| s.rule_id == "time_rounding")
+            .expect("time rounding step should be recorded");
+        assert_eq!(rounding_step.input["raw_worked_hours"], "7.8667");
+        assert_eq!(rounding_step.output["rounded_worked_hours"], "8");
+    }
+
+    #[test]
+    fn test_rounded_overnight_shift_pays_no_spurious_overtime() {
+        let loader = create_loader_with(|p| p.time_rounding = TimeRounding::Up(15));
+
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // 8 hours from Tuesday 22:07 to Wednesday 06:07
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "22:07:00"),
+            end_time: make_datetime("2026-01-14", "06:07:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        assert_eq!(result.totals.ordinary_hours, Decimal::from(8));
+        assert_eq!(result.totals.overtime_hours, Decimal::ZERO);
+        assert_eq!(result.totals.total_hours_worked, Decimal::from(8));
+        assert!(
+            result
+                .audit_trace
+                .warnings
+                .iter()
+                .all(|warning| warning.code != crate::calculation::HOURS_MISMATCH_WARNING)
+        );
+    }
+
+    #[test]
+    fn test_laundry_cap_applies_per_configured_week() {
+        let mut request = create_valid_request();
+        request.employee.tags = vec!["laundry_allowance".to_string()];
+        let employee: Employee = request.employee.into();
+        let pay_period = PayPeriod {
+            start_date: make_date("2026-01-12"),
+            end_date: make_date("2026-01-18"),
+            public_holidays: vec![],
+            period_type: None,
+        };
+        // Monday to Friday plus Sunday 18 Jan
+        let shifts = vec![
+            create_shift("shift_mon", "2026-01-12"),
+            create_shift("shift_tue", "2026-01-13"),
+            create_shift("shift_wed", "2026-01-14"),
+            create_shift("shift_thu", "2026-01-15"),
+            create_shift("shift_fri", "2026-01-16"),
+            create_shift("shift_sun", "2026-01-18"),
+        ];
+        use std::str::FromStr;
+
+        // Monday-start: all 6 shifts are one week, 6 × $0.32 = $1.92 capped at $1.49
+        let monday_start = create_loader_with(|p| p.week_start_day = Weekday::Mon);
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &monday_start,
+        )
+        .unwrap();
+        assert_eq!(result.allowances.len(), 1);
+        assert_eq!(
+            result.totals.allowances_total,
+            Decimal::from_str("1.49").unwrap()
+        );
+
+        // Sunday-start: Sunday 18 Jan starts a new week
+        // Week 1: 5 × $0.32 = $1.60 capped at $1.49; week 2: 1 × $0.32
+        let sunday_start = create_loader_with(|p| p.week_start_day = Weekday::Sun);
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &sunday_start,
+        )
+        .unwrap();
+        assert_eq!(result.allowances.len(), 2);
+        assert_eq!(
+            result.allowances[0].amount,
+            Decimal::from_str("1.49").unwrap()
+        );
+        assert_eq!(
+            result.allowances[1].amount,
+            Decimal::from_str("0.32").unwrap()
+        );
+        assert_eq!(
+            result.totals.allowances_total,
+            Decimal::from_str("1.81").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_laundry_counts_only_shifts_of_the_minimum_length() {
+        use std::str::FromStr;
+
+        let mut request = create_valid_request();
+        request.employee.tags = vec!["laundry_allowance".to_string()];
+        let employee: Employee = request.employee.into();
+        let pay_period = PayPeriod {
+            start_date: make_date("2026-01-12"),
+            end_date: make_date("2026-01-18"),
+            public_holidays: vec![],
+            period_type: None,
+        };
+        let short_shift = |id: &str, date: &str| Shift {
+            end_time: make_datetime(date, "11:00:00"),
+            ..create_shift(id, date)
+        };
+        // Two 4 hour shifts and two 2 hour shifts in the week
+        let shifts = vec![
+            create_shift("shift_mon", "2026-01-12"),
+            short_shift("shift_tue", "2026-01-13"),
+            create_shift("shift_wed", "2026-01-14"),
+            short_shift("shift_thu", "2026-01-15"),
+        ];
+        let loader = create_loader_with(|p| p.laundry_min_shift_hours = Decimal::from(4));
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        // Only the 4 hour shifts count: 2 × $0.32
+        assert_eq!(result.allowances.len(), 1);
+        assert_eq!(result.allowances[0].units, Decimal::from(2));
+        assert_eq!(
+            result.allowances[0].amount,
+            Decimal::from_str("0.64").unwrap()
+        );
+        let excluded: Vec<&str> = result
+            .audit_trace
+            .steps
+            .iter()
+            .filter(|step| step.rule_id == "laundry_allowance" && step.output["eligible"] == false)
+            .map(|step| step.input["shift_id"].as_str().unwrap())
+            .collect();
+        assert_eq!(excluded, vec!["shift_tue", "shift_thu"]);
+    }
+
+    #[test]
+    fn test_laundry_counts_a_shift_rounded_up_to_the_minimum_length() {
+        use std::str::FromStr;
+
+        let mut request = create_valid_request();
+        request.employee.tags = vec!["laundry_allowance".to_string()];
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // 3h50m and 3h40m worked: the first rounds up to the 4 hour minimum,
+        // the second only to 3.75 hours
+        let shifts = vec![
+            Shift {
+                end_time: make_datetime("2026-01-13", "12:50:00"),
+                ..create_shift("shift_tue", "2026-01-13")
+            },
+            Shift {
+                end_time: make_datetime("2026-01-14", "12:40:00"),
+                ..create_shift("shift_wed", "2026-01-14")
+            },
+        ];
+        let loader = create_loader_with(|p| {
+            p.laundry_min_shift_hours = Decimal::from(4);
+            p.time_rounding = TimeRounding::Up(15);
+        });
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        assert_eq!(result.allowances[0].units, Decimal::ONE);
+        assert_eq!(
+            result.allowances[0].amount,
+            Decimal::from_str("0.32").unwrap()
+        );
+        let excluded = result
+            .audit_trace
+            .steps
+            .iter()
+            .find(|step| step.rule_id == "laundry_allowance" && step.output["eligible"] == false)
+            .expect("the shorter shift should be excluded");
+        assert_eq!(excluded.input["shift_id"], "shift_wed");
+        assert_eq!(excluded.input["worked_hours"], "3.75");
+    }
+
+    #[test]
+    fn test_per_hour_qualification_allowance_is_paid_on_rounded_hours() {
+        use crate::config::{QualificationAllowanceBasis, QualificationAllowanceRate};
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let config = create_loader_with(|p| p.time_rounding = TimeRounding::Up(15));
+        let config = config.config();
+        let mut rates = config.rates().to_vec();
+        for rate in &mut rates {
+            rate.allowances.qualification = Some(QualificationAllowanceRate {
+                amount: dec("0.50"),
+                basis: QualificationAllowanceBasis::PerHour,
+            });
+        }
+        let loader = ConfigLoader::from_config(AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            rates,
+            config.penalties().clone(),
+        ));
+        let mut request = create_valid_request();
+        request.employee.tags = vec!["qualification_allowance".to_string()];
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // 3h50m worked, rounded up to 4 hours
+        let shifts = vec![Shift {
+            end_time: make_datetime("2026-01-13", "12:50:00"),
+            ..create_shift("shift_001", "2026-01-13")
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        assert_eq!(result.totals.total_hours_worked, dec("4"));
+        let allowance = result
+            .allowances
+            .iter()
+            .find(|a| a.allowance_type == AllowanceType::Qualification)
+            .expect("qualification allowance should be paid");
+        // 4 paid hours × $0.50, not 3.83 raw hours
+        assert_eq!(allowance.units, dec("4"));
+        assert_eq!(allowance.amount, dec("2"));
+    }
+
+    #[test]
+    fn test_allowance_summary_consolidates_a_fortnights_allowances() {
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let mut request = create_valid_request();
+        request.employee.tags = vec![
+            "laundry_allowance".to_string(),
+            "qualification_allowance".to_string(),
+        ];
+        let employee: Employee = request.employee.into();
+        let pay_period = PayPeriod {
+            start_date: make_date("2026-01-12"),
+            end_date: make_date("2026-01-25"),
+            public_holidays: vec![],
+            period_type: None,
+        };
+        // Monday to Friday of the first week, Monday of the second
+        let shifts = vec![
+            create_shift("shift_mon", "2026-01-12"),
+            create_shift("shift_tue", "2026-01-13"),
+            create_shift("shift_wed", "2026-01-14"),
+            create_shift("shift_thu", "2026-01-15"),
+            create_shift("shift_fri", "2026-01-16"),
+            create_shift("shift_mon_2", "2026-01-19"),
+        ];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &config,
+        )
+        .unwrap();
+
+        let steps = &result.audit_trace.steps;
+        let position = steps
+            .iter()
+            .position(|s| s.rule_id == "allowance_summary")
+            .expect("allowance summary step");
+        let summary = &steps[position];
+        // After every allowance step
+        assert_eq!(steps[position - 1].rule_id, "qualification_allowance");
+        assert!(
+            !steps[position..]
+                .iter()
+                .any(|s| s.rule_id.ends_with("_allowance"))
+        );
+
+        let allowances = summary.input["allowances"].as_array().unwrap();
+        assert_eq!(allowances.len(), 2);
+        // Week 1: 5 × $0.32 = $1.60 capped at $1.49; week 2: 1 × $0.32
+        let laundry = &allowances[0];
+        assert_eq!(laundry["rule_id"], "laundry_allowance");
+        assert_eq!(laundry["calculations"].as_array().unwrap().len(), 2);
+        assert_eq!(laundry["caps_applied"], 1);
+        assert_eq!(laundry["total"], "1.81");
+        let qualification = &allowances[1];
+        assert_eq!(qualification["rule_id"], "qualification_allowance");
+        assert_eq!(qualification["caps_applied"], 0);
+        assert_eq!(qualification["total"], "42.8");
+        assert_eq!(
+            summary.output["allowances_total"],
+            result.totals.allowances_total.normalize().to_string()
+        );
+        assert_eq!(
+            summary.reasoning,
+            "Laundry Allowance $1.81 (capped in 1 of 2) + Qualification Allowance $42.8 = allowances total $44.61"
+        );
+    }
+
+    #[test]
+    fn test_rounding_residual_is_added_to_the_largest_line() {
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let line = |shift_id: &str, amount: &str| PayLine {
+            date: make_date("2026-01-13"),
+            shift_id: shift_id.to_string(),
+            category: PayCategory::Ordinary,
+            hours: Decimal::ONE,
+            rate: dec(amount),
+            amount: dec(amount),
+            clause_ref: "22.1".to_string(),
+            reference: None,
+            cost_centre: None,
+        };
+        // Each line rounds down by 0.4 of a cent; together they lose 1.2 cents
+        let mut pay_lines = vec![
+            line("shift_001", "10.004"),
+            line("shift_002", "30.004"),
+            line("shift_003", "20.004"),
+        ];
+
+        let step = reconcile_rounding(&mut pay_lines, &mut [], 7, true).unwrap();
+
+        let amounts: Vec<Decimal> = pay_lines.iter().map(|pl| pl.amount).collect();
+        assert_eq!(amounts, vec![dec("10.00"), dec("30.01"), dec("20.00")]);
+        assert_eq!(amounts.iter().sum::<Decimal>(), dec("60.01"));
+        assert_eq!(step.step_number, 7);
+        assert_eq!(step.rule_id, "rounding_reconciliation");
+        assert_eq!(step.input["unrounded_total"], "60.012");
+        assert_eq!(step.output["rounded_lines_total"], "60");
+        assert_eq!(step.output["gross_pay"], "60.01");
+        assert_eq!(step.output["residual"], "0.01");
+        assert_eq!(step.output["adjusted_line"]["shift_id"], "shift_002");
+    }
+
+    #[test]
+    fn test_rounding_reconciliation_makes_lines_add_up_to_gross() {
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = create_loader_with(|p| p.rounding_reconciliation = true);
+        let mut request = create_valid_request();
+        request.employee.employment_type = EmploymentType::Casual;
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let hour = |id: &str, date: &str| Shift {
+            id: id.to_string(),
+            date: make_date(date),
+            start_time: make_datetime(date, "09:00:00"),
+            end_time: make_datetime(date, "10:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        };
+        let shifts = vec![
+            hour("shift_tue", "2026-01-13"),
+            hour("shift_wed", "2026-01-14"),
+            hour("shift_thu", "2026-01-15"),
+        ];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        // 3 × $35.675 = $107.025, printed as $107.03; each line rounds up to
+        // $35.68, so one gives back the extra cent
+        let amounts: Vec<Decimal> = result.pay_lines.iter().map(|pl| pl.amount).collect();
+        assert_eq!(amounts, vec![dec("35.67"), dec("35.68"), dec("35.68")]);
+        assert_eq!(result.pay_lines[0].shift_id, "shift_tue");
+        assert_eq!(result.totals.gross_pay, dec("107.03"));
+        assert_eq!(
+            amounts.iter().sum::<Decimal>() + result.totals.allowances_total,
+            result.totals.gross_pay
+        );
+        let step = result
+            .audit_trace
+            .steps
+            .iter()
+            .find(|s| s.rule_id == "rounding_reconciliation")
+            .expect("rounding reconciliation should be audited");
+        assert_eq!(step.output["residual"], "-0.01");
+    }
+
+    #[test]
+    fn test_no_allowance_summary_without_allowances() {
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let result = calculate_pay(create_valid_request(), &config).unwrap();
+
+        assert!(result.allowances.is_empty());
+        assert!(
+            !result
+                .audit_trace
+                .steps
+                .iter()
+                .any(|s| s.rule_id == "allowance_summary")
+        );
+    }
+
+    #[test]
+    fn test_shift_rate_override_applies_to_that_shift_only() {
+        use std::str::FromStr;
+
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let employee: Employee = create_valid_request().employee.into();
+        let pay_period = PayPeriod {
+            start_date: make_date("2026-01-12"),
+            end_date: make_date("2026-01-18"),
+            public_holidays: vec![],
+            period_type: None,
+        };
+        let override_rate = Decimal::from_str("40.00").unwrap();
+        let mut tuesday = create_shift("shift_tue", "2026-01-13");
+        tuesday.rate_override = Some(override_rate);
+        let mut saturday = create_shift("shift_sat", "2026-01-17");
+        saturday.rate_override = Some(override_rate);
+        let shifts = vec![tuesday, create_shift("shift_wed", "2026-01-14"), saturday];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        let line = |shift_id: &str| {
+            result
+                .pay_lines
+                .iter()
+                .find(|line| line.shift_id == shift_id)
+                .unwrap()
+        };
+        assert_eq!(line("shift_tue").rate, override_rate);
+        assert_eq!(line("shift_wed").rate, Decimal::from_str("28.54").unwrap());
+        // The Saturday penalty is applied to the override rate: $40.00 × 1.5
+        assert_eq!(line("shift_sat").category, PayCategory::Saturday);
+        assert_eq!(line("shift_sat").rate, Decimal::from(60));
+
+        let steps = &result.audit_trace.steps;
+        let flagged: Vec<&str> = steps
+            .iter()
+            .filter(|step| step.input["shift_rate_override"] == "40")
+            .map(|step| step.rule_id.as_str())
+            .collect();
+        assert_eq!(
+            flagged,
+            vec!["ordinary_hours_calculation", "saturday_penalty"]
+        );
+        let saturday_step = steps
+            .iter()
+            .find(|step| step.rule_id == "saturday_penalty")
+            .unwrap();
+        assert!(
+            saturday_step
+                .reasoning
+                .ends_with("(shift rate override $40 applied)")
+        );
+        assert!(steps.iter().any(|step| step.rule_id == "base_rate_lookup"
+            && step.output["source"] == "shift_override"
+            && step.input["shift_override_rate"] == "40.00"));
+    }
+
+    #[test]
+    fn test_consecutive_days_are_recorded_when_configured() {
+        use crate::config::{ConsecutiveDaysConfig, PenaltyRates};
+        use std::str::FromStr;
+
+        let loader = create_loader_with(|p| {
+            p.consecutive_days = Some(ConsecutiveDaysConfig {
+                run_length: 6,
+                penalty: PenaltyRates {
+                    clause: "X.Y".to_string(),
+                    full_time: Decimal::from_str("2.0").unwrap(),
+                    part_time: Decimal::from_str("2.0").unwrap(),
+                    casual: Decimal::from_str("2.25").unwrap(),
+                },
+            })
+        });
+        let employee: Employee = create_valid_request().employee.into();
+        let pay_period = PayPeriod {
+            start_date: make_date("2026-01-12"),
+            end_date: make_date("2026-01-18"),
+            public_holidays: vec![],
+            period_type: None,
+        };
+        let shifts: Vec<Shift> = (12..=18)
+            .map(|day| create_shift(&format!("shift_{}", day), &format!("2026-01-{}", day)))
+            .collect();
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        let step = result
+            .audit_trace
+            .steps
+            .iter()
+            .find(|s| s.rule_id == "consecutive_days")
+            .expect("consecutive days step should be recorded");
+        assert_eq!(step.output["penalty_days"][0], "2026-01-18");
+    }
+
+    #[test]
+    fn test_partial_results_leave_out_a_failing_shift() {
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let config = loader.config();
+        // A rate file from Thursday that is missing the employee's classification
+        let mut rates = config.rates().to_vec();
+        let mut broken = rates[0].clone();
+        broken.effective_date = make_date("2026-01-15");
+        broken.rates.remove("dce_level_3");
+        rates.push(broken);
+        let loader = ConfigLoader::from_config(AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            rates,
+            config.penalties().clone(),
+        ));
+
+        let employee: Employee = create_valid_request().employee.into();
+        let pay_period: PayPeriod = create_valid_request().pay_period.into();
+        let shifts = vec![
+            create_shift("shift_tue", "2026-01-13"),
+            create_shift("shift_fri", "2026-01-16"),
+            create_shift("shift_wed", "2026-01-14"),
+        ];
+
+        // Fail-fast by default
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        );
+        assert!(result.is_err());
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            true,
+            &loader,
+        )
+        .unwrap();
+
+        assert!(result.pay_lines.iter().all(|pl| pl.shift_id != "shift_fri"));
+        assert_eq!(result.totals.ordinary_hours, Decimal::from(8));
+        assert_eq!(result.totals.total_hours_worked, Decimal::from(8));
+        let failures: Vec<&AuditWarning> = result
+            .audit_trace
+            .warnings
+            .iter()
+            .filter(|w| w.code == SHIFT_CALCULATION_FAILED_WARNING)
+            .collect();
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].message.contains("'shift_fri'"));
+        // The failed shift's steps are rolled back, so step numbers stay sequential
+        for (i, step) in result.audit_trace.steps.iter().enumerate() {
+            assert_eq!(step.step_number, i as u32 + 1);
+        }
+    }
+
+    #[test]
+    fn test_amounts_in_cents_match_the_same_request_in_dollars() {
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let mut dollars_request = create_valid_request();
+        dollars_request.employee.base_hourly_rate = Some(dec("30.00"));
+        dollars_request.employee.tags = vec!["laundry_allowance".to_string()];
+        dollars_request.shifts.push(ShiftRequest {
+            id: "shift_sat".to_string(),
+            date: make_date("2026-01-17"),
+            start_time: make_datetime("2026-01-17", "09:00:00"),
+            end_time: Some(make_datetime("2026-01-17", "13:00:00")),
+            duration_minutes: None,
+            expected_hours: None,
+            breaks: vec![],
+            rate_override: Some(dec("42.50")),
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        });
+        let mut cents_request = dollars_request.clone();
+        cents_request.amounts_in_cents = true;
+        cents_request.employee.base_hourly_rate = Some(dec("3000"));
+        cents_request.shifts[1].rate_override = Some(dec("4250"));
+
+        let dollars = calculate_pay(dollars_request, &config).unwrap();
+        let mut cents = calculate_pay(cents_request, &config).unwrap();
+
+        // 8h × 3000c + 4h × 4250c × 1.5 + 2 × 32c laundry
+        assert_eq!(cents.totals.gross_pay.to_string(), "49564");
+        assert_eq!(cents.pay_lines[0].rate.to_string(), "3000");
+        assert_eq!(cents.allowances[0].amount.to_string(), "64");
+        assert_eq!(
+            cents.totals.gross_pay,
+            dollars.totals.gross_pay * CENTS_PER_DOLLAR
+        );
+        assert_eq!(cents.totals.ordinary_hours, dollars.totals.ordinary_hours);
+
+        cents.convert_amounts_to_dollars();
+        assert_eq!(cents.pay_lines, dollars.pay_lines);
+        assert_eq!(cents.shift_breakdown, dollars.shift_breakdown);
+        assert_eq!(cents.allowances, dollars.allowances);
+        assert_eq!(cents.totals, dollars.totals);
+    }
+
+    #[test]
+    fn test_max_audit_steps_truncates_trace_without_changing_pay() {
+        let unbounded = create_loader_with(|_| {});
+        let bounded = create_loader_with(|p| p.max_audit_steps = Some(5));
+        // A shift running from Friday evening into Sunday spans three days
+        // and several overtime tiers
+        let mut request = create_valid_request();
+        request.shifts = vec![ShiftRequest {
+            id: "shift_long".to_string(),
+            date: make_date("2026-01-16"),
+            start_time: make_datetime("2026-01-16", "18:00:00"),
+            end_time: Some(make_datetime("2026-01-18", "02:00:00")),
+            duration_minutes: None,
+            expected_hours: None,
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let full = calculate_pay(request.clone(), &unbounded).unwrap();
+        let truncated = calculate_pay(request, &bounded).unwrap();
+
+        let total_steps = full.audit_trace.steps.len();
+        assert!(total_steps > 5);
+        let steps = &truncated.audit_trace.steps;
+        assert_eq!(steps.len(), 6);
+        assert_eq!(steps[..5], full.audit_trace.steps[..5]);
+        assert_eq!(steps[5].rule_id, AUDIT_TRUNCATED_RULE_ID);
+        assert_eq!(steps[5].step_number, steps[4].step_number + 1);
+        assert_eq!(steps[5].output["omitted_steps"], total_steps - 5);
+
+        assert_eq!(truncated.pay_lines, full.pay_lines);
+        assert_eq!(truncated.totals, full.totals);
+        assert_eq!(truncated.applied_clauses, full.applied_clauses);
+    }
+
+    #[test]
+    fn test_rate_change_mid_period_applies_from_its_operative_date() {
+        use std::str::FromStr;
+
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let config = loader.config();
+        let mut rates = config.rates().to_vec();
+        let mut increase = rates[0].clone();
+        increase.effective_date = make_date("2026-07-01");
+        increase.rates.get_mut("dce_level_3").unwrap().hourly = Decimal::from_str("29.40").unwrap();
+        rates.push(increase);
+        let loader = ConfigLoader::from_config(AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            rates,
+            config.penalties().clone(),
+        ));
+
+        let employee: Employee = create_valid_request().employee.into();
+        let pay_period = PayPeriod {
+            start_date: make_date("2026-06-22"),
+            end_date: make_date("2026-07-05"),
+            public_holidays: vec![],
+            period_type: None,
+        };
+        // Saturday shifts either side of the increase, listed out of order
+        let shifts = vec![
+            create_shift("shift_july", "2026-07-04"),
+            create_shift("shift_june", "2026-06-27"),
+        ];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        let rate_for = |shift_id: &str| {
+            result
+                .pay_lines
+                .iter()
+                .find(|pl| pl.shift_id == shift_id)
+                .unwrap()
+                .rate
+        };
+        // $28.54 × 1.5 before the increase and $29.40 × 1.5 from 1 July
+        assert_eq!(rate_for("shift_june"), Decimal::from_str("42.81").unwrap());
+        assert_eq!(rate_for("shift_july"), Decimal::from_str("44.10").unwrap());
+        assert_eq!(
+            result.totals.gross_pay,
+            Decimal::from_str("347.64").unwrap()
+        );
+        assert!(result.audit_trace.steps.iter().any(|s| {
+            s.rule_id == "base_rate_lookup" && s.output["rate_effective_date"] == "2026-07-01"
+        }));
+    }
+
+    #[test]
+    fn test_gross_by_category_sums_pay_lines_in_payslip_order() {
+        use std::str::FromStr;
+
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let employee: Employee = create_valid_request().employee.into();
+        let pay_period = create_valid_request().pay_period.into();
+        let mut long_shift = create_shift("shift_long", "2026-01-13");
+        long_shift.end_time = make_datetime("2026-01-13", "19:00:00");
+        let shifts = vec![
+            create_shift("shift_sat", "2026-01-17"),
+            long_shift,
+            create_shift("shift_wed", "2026-01-14"),
+        ];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let by_category: Vec<(PayCategory, Decimal)> = result
+            .totals
+            .gross_by_category
+            .iter()
+            .map(|(category, amount)| (*category, *amount))
+            .collect();
+        // 12h ordinary × $28.54, 4h Saturday × $42.81, 2h overtime × $42.81
+        assert_eq!(
+            by_category,
+            vec![
+                (PayCategory::Ordinary, dec("342.48")),
+                (PayCategory::Saturday, dec("171.24")),
+                (PayCategory::Overtime150, dec("85.62")),
+            ]
+        );
+        let categories_total: Decimal = result.totals.gross_by_category.values().sum();
+        assert_eq!(
+            categories_total + result.totals.allowances_total,
+            result.totals.gross_pay
+        );
+        // Categories are keyed by their serialized names
+        let json = serde_json::to_string(&result.totals).unwrap();
+        assert!(json.contains("\"gross_by_category\":{\"ordinary\":"));
+    }
+
+    #[test]
+    fn test_total_hours_worked_sums_worked_hours_across_categories() {
+        use crate::models::PublicHoliday;
+
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let mut employee: Employee = create_valid_request().employee.into();
+        employee.ordinary_days = vec![chrono::Weekday::Thu];
+        let mut pay_period: PayPeriod = create_valid_request().pay_period.into();
+        pay_period.public_holidays = vec![PublicHoliday {
+            date: make_date("2026-01-15"),
+            name: "Test Holiday".to_string(),
+            region: "national".to_string(),
+        }];
+        let mut long_shift = create_shift("shift_long", "2026-01-13");
+        long_shift.end_time = make_datetime("2026-01-13", "19:00:00");
+        let shifts = vec![
+            create_shift("shift_sat", "2026-01-17"),
+            long_shift,
+            create_shift("shift_wed", "2026-01-14"),
+        ];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        // 8h ordinary + 2h overtime + 4h ordinary + 4h Saturday; the public
+        // holiday not worked is paid but not worked
+        let worked_hours: Decimal = shifts.iter().map(|s| s.worked_hours()).sum();
+        assert_eq!(worked_hours, Decimal::from(18));
+        assert_eq!(result.totals.total_hours_worked, worked_hours);
+        assert!(
+            result
+                .pay_lines
+                .iter()
+                .any(|line| line.category == PayCategory::PublicHolidayNotWorked)
+        );
+        assert_eq!(
+            result.totals.total_hours_worked,
+            result.totals.ordinary_hours
+                + result.totals.penalty_hours
+                + result.totals.overtime_hours
+        );
+    }
+
+    #[test]
+    fn test_holiday_on_rostered_day_off_is_accrued_in_lieu_rather_than_paid() {
+        use crate::config::PublicHolidayInLieuConfig;
+        use crate::models::{DayInLieu, PublicHoliday};
+
+        let loader = create_loader_with(|p| {
+            p.leave_accrual.as_mut().unwrap().public_holiday_in_lieu =
+                Some(PublicHolidayInLieuConfig {
+                    clause: "X.Y".to_string(),
+                    ordinary_hours: Decimal::new(76, 1),
+                });
+        });
+        let mut employee: Employee = create_valid_request().employee.into();
+        employee.ordinary_days = vec![chrono::Weekday::Tue, chrono::Weekday::Thu];
+        employee.rostered_days_off = vec![make_date("2026-01-15")];
+        let mut pay_period: PayPeriod = create_valid_request().pay_period.into();
+        pay_period.public_holidays = vec![PublicHoliday {
+            date: make_date("2026-01-15"),
+            name: "Test Holiday".to_string(),
+            region: "national".to_string(),
+        }];
+        let shifts = vec![create_shift("shift_tue", "2026-01-13")];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        // The Thursday holiday is an ordinary day, but the rostered day off
+        // takes precedence: no payment, a day in lieu instead
+        assert!(
+            !result
+                .pay_lines
+                .iter()
+                .any(|line| line.category == PayCategory::PublicHolidayNotWorked)
+        );
+        let accruals = result.accruals.unwrap();
+        assert_eq!(accruals.accrual_hours, Decimal::from(4));
+        assert_eq!(
+            accruals.days_in_lieu,
+            vec![DayInLieu {
+                date: make_date("2026-01-15"),
+                name: "Test Holiday".to_string(),
+                hours: Decimal::new(76, 1),
+            }]
+        );
+        assert!(
+            result
+                .audit_trace
+                .steps
+                .iter()
+                .any(|step| step.rule_id == "public_holiday_in_lieu")
+        );
+    }
+
+    #[test]
+    fn test_overtime_on_a_public_holiday_is_paid_at_the_public_holiday_rate() {
+        use crate::config::{OvertimeRates, PublicHolidayOvertimeConfig};
+        use crate::models::PublicHoliday;
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = create_loader_with(|p| {
+            p.overtime.public_holiday = Some(PublicHolidayOvertimeConfig {
+                clause: "X.Y".to_string(),
+                rates: OvertimeRates {
+                    full_time: dec("2.5"),
+                    part_time: dec("2.5"),
+                    casual: dec("2.5"),
+                },
+            });
+        });
+        let employee: Employee = create_valid_request().employee.into();
+        let mut pay_period: PayPeriod = create_valid_request().pay_period.into();
+        pay_period.public_holidays = vec![PublicHoliday {
+            date: make_date("2026-01-15"),
+            name: "Test Holiday".to_string(),
+            region: "national".to_string(),
+        }];
+        let mut holiday_shift = create_shift("shift_holiday", "2026-01-15");
+        holiday_shift.start_time = make_datetime("2026-01-15", "08:00:00");
+        holiday_shift.end_time = make_datetime("2026-01-15", "18:00:00");
+        let mut weekday_shift = create_shift("shift_weekday", "2026-01-14");
+        weekday_shift.start_time = make_datetime("2026-01-14", "08:00:00");
+        weekday_shift.end_time = make_datetime("2026-01-14", "18:00:00");
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &[holiday_shift, weekday_shift],
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        // The 10-hour holiday shift: 8h ordinary, then 2h at 250%
+        let holiday_lines: Vec<_> = result
+            .pay_lines
+            .iter()
+            .filter(|line| line.shift_id == "shift_holiday")
+            .map(|line| (line.category, line.hours, line.amount))
+            .collect();
+        assert_eq!(
+            holiday_lines,
+            vec![
+                (PayCategory::Ordinary, dec("8"), dec("228.32")),
+                (PayCategory::PublicHolidayOvertime, dec("2"), dec("142.70")),
+            ]
+        );
+        let step = result
+            .audit_trace
+            .steps
+            .iter()
+            .find(|step| step.rule_id == "public_holiday_overtime")
+            .expect("public holiday overtime step");
+        assert_eq!(step.clause_ref, "X.Y");
+        assert_eq!(result.totals.overtime_hours, dec("4"));
+
+        // Overtime on other days keeps the weekday tiers
+        assert!(result.pay_lines.iter().any(|line| {
+            line.shift_id == "shift_weekday" && line.category == PayCategory::Overtime150
+        }));
     }
 
-    fn create_valid_request() -> CalculationRequest {
-        CalculationRequest {
-            employee: EmployeeRequest {
-                id: "emp_001".to_string(),
-                employment_type: EmploymentType::FullTime,
-                classification_code: "dce_level_3".to_string(),
-                date_of_birth: make_date("1985-03-15"),
-                employment_start_date: make_date("2020-01-01"),
-                base_hourly_rate: None,
-                tags: vec![],
-            },
-            pay_period: PayPeriodRequest {
-                start_date: make_date("2026-01-13"),
-                end_date: make_date("2026-01-19"),
-                public_holidays: vec![],
-            },
-            shifts: vec![ShiftRequest {
-                id: "shift_001".to_string(),
-                date: make_date("2026-01-13"),
-                start_time: make_datetime("2026-01-13", "09:00:00"),
-                end_time: make_datetime("2026-01-13", "17:00:00"),
-                breaks: vec![],
-            }],
+    #[test]
+    fn test_nine_hour_shift_without_meal_break_warns_and_pays_penalty() {
+        use crate::config::MealBreakConfig;
+        use std::str::FromStr;
+
+        let config = |penalty_rate: Option<&str>| {
+            create_loader_with(|p| {
+                p.meal_break = Some(MealBreakConfig {
+                    clause: "X.Y".to_string(),
+                    max_hours_without_break: Decimal::from(5),
+                    min_break_minutes: 30,
+                    penalty_rate: penalty_rate.map(|r| Decimal::from_str(r).unwrap()),
+                })
+            })
+        };
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "08:00:00"),
+            end_time: make_datetime("2026-01-13", "17:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let warn_only = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &config(None),
+        )
+        .unwrap();
+        let with_penalty = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &config(Some("0.5")),
+        )
+        .unwrap();
+
+        for result in [&warn_only, &with_penalty] {
+            assert!(
+                result
+                    .audit_trace
+                    .warnings
+                    .iter()
+                    .any(|w| w.code == "NO_MEAL_BREAK")
+            );
+            assert_eq!(result.totals.ordinary_hours, Decimal::from(8));
+            assert_eq!(result.totals.overtime_hours, Decimal::from(1));
         }
+        // 8 × $28.54 + 1 × $42.81
+        assert_eq!(
+            warn_only.totals.gross_pay,
+            Decimal::from_str("271.13").unwrap()
+        );
+        // Plus 4 hours past the break × $28.54 × 0.5
+        let penalty = with_penalty
+            .pay_lines
+            .iter()
+            .find(|pl| pl.category == PayCategory::MealBreakPenalty)
+            .expect("meal break penalty should be paid");
+        assert_eq!(penalty.amount, Decimal::from_str("57.08").unwrap());
+        assert_eq!(
+            with_penalty.totals.gross_pay,
+            Decimal::from_str("328.21").unwrap()
+        );
     }
 
-    #[tokio::test]
-    async fn test_api_001_valid_request_returns_200() {
-        let state = create_test_state();
-        let router = create_router(state);
+    #[test]
+    fn test_implausibly_large_gross_is_flagged_without_changing_pay() {
+        use std::str::FromStr;
 
+        let loader = |max_expected_gross: Option<&str>| {
+            create_loader_with(|p| {
+                p.max_expected_gross = max_expected_gross.map(|g| Decimal::from_str(g).unwrap())
+            })
+        };
         let request = create_valid_request();
-        let body = serde_json::to_string(&request).unwrap();
-
-        let response = router
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/calculate")
-                    .header("Content-Type", "application/json")
-                    .body(Body::from(body))
-                    .unwrap(),
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // Seven 16-hour days, as a mis-entered roster might read
+        let shifts: Vec<Shift> = (13..=19)
+            .map(|day| {
+                let date = format!("2026-01-{}", day);
+                Shift {
+                    id: format!("shift_{}", day),
+                    date: make_date(&date),
+                    start_time: make_datetime(&date, "06:00:00"),
+                    end_time: make_datetime(&date, "22:00:00"),
+                    breaks: vec![],
+                    rate_override: None,
+                    classification_code: None,
+                    reference: None,
+                    cost_centre: None,
+                }
+            })
+            .collect();
+        let calculate = |max_expected_gross| {
+            perform_calculation(
+                &employee,
+                &pay_period,
+                &shifts,
+                None,
+                None,
+                true,
+                false,
+                &loader(max_expected_gross),
             )
-            .await
-            .unwrap();
+            .unwrap()
+        };
 
-        assert_eq!(response.status(), StatusCode::OK);
+        let unchecked = calculate(None);
+        let flagged = calculate(Some("3000"));
+        let plausible = calculate(Some("100000"));
 
-        // Verify Content-Type header
-        let content_type = response.headers().get("content-type").unwrap();
-        assert_eq!(content_type, "application/json");
+        assert!(unchecked.totals.gross_pay > Decimal::from(3000));
+        let warning = flagged
+            .audit_trace
+            .warnings
+            .iter()
+            .find(|w| w.code == GROSS_EXCEEDS_EXPECTED_WARNING)
+            .expect("gross above the threshold should be flagged");
+        assert_eq!(warning.severity, "high");
+        assert!(warning.message.contains(&format!(
+            "Gross pay ${} for employee 'emp_001' exceeds the expected maximum of $3000",
+            unchecked.totals.gross_pay.normalize()
+        )));
+        assert_eq!(flagged.totals, unchecked.totals);
+        assert_eq!(flagged.pay_lines, unchecked.pay_lines);
+        for result in [&unchecked, &plausible] {
+            assert!(
+                !result
+                    .audit_trace
+                    .warnings
+                    .iter()
+                    .any(|w| w.code == GROSS_EXCEEDS_EXPECTED_WARNING)
+            );
+        }
+    }
 
-        // Verify response body is valid CalculationResult
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let result: CalculationResult = serde_json::from_slice(&body).unwrap();
+    #[test]
+    fn test_split_shift_beyond_span_of_hours_pays_span_penalty() {
+        use crate::config::SpanOfHoursConfig;
+        use std::str::FromStr;
 
-        assert_eq!(result.employee_id, "emp_001");
-        assert!(!result.pay_lines.is_empty());
-        assert!(result.totals.gross_pay > Decimal::ZERO);
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = create_loader_with(|p| {
+            p.span_of_hours = Some(SpanOfHoursConfig {
+                clause: "X.Y".to_string(),
+                max_span_hours: dec("12"),
+                penalty_rate: dec("0.5"),
+            })
+        });
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let part = |id: &str, start: &str, end: &str| Shift {
+            id: id.to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", start),
+            end_time: make_datetime("2026-01-13", end),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        };
+        // 8 hours worked across a 14.5 hour span
+        let shifts = vec![
+            part("shift_am", "06:30:00", "10:30:00"),
+            part("shift_pm", "17:00:00", "21:00:00"),
+        ];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        // The span penalty is paid on top, so no overtime and no extra hours
+        assert_eq!(result.totals.ordinary_hours, dec("8"));
+        assert_eq!(result.totals.overtime_hours, Decimal::ZERO);
+        assert_eq!(result.totals.total_hours_worked, dec("8"));
+        // 2.5 hours beyond the span × $28.54 × 0.5
+        let penalty = result
+            .pay_lines
+            .iter()
+            .find(|pl| pl.category == PayCategory::SpanPenalty)
+            .expect("span penalty should be paid");
+        assert_eq!(penalty.shift_id, "shift_pm");
+        assert_eq!(penalty.amount, dec("35.675"));
+        // 8 × $28.54 + $35.675
+        assert_eq!(result.totals.gross_pay, dec("263.995"));
+        assert!(
+            result
+                .audit_trace
+                .steps
+                .iter()
+                .any(|step| step.rule_id == "span_of_hours")
+        );
     }
 
-    #[tokio::test]
-    async fn test_api_002_malformed_json_returns_400() {
-        let state = create_test_state();
-        let router = create_router(state);
+    #[test]
+    fn test_partial_results_leave_a_failed_shift_out_of_the_span_of_hours() {
+        use crate::config::SpanOfHoursConfig;
+        use std::str::FromStr;
 
-        let response = router
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/calculate")
-                    .header("Content-Type", "application/json")
-                    .body(Body::from("{invalid json"))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = create_loader_with(|p| {
+            p.span_of_hours = Some(SpanOfHoursConfig {
+                clause: "X.Y".to_string(),
+                max_span_hours: dec("12"),
+                penalty_rate: dec("0.5"),
+            })
+        });
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let part = |id: &str, start: &str, end: &str| Shift {
+            id: id.to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", start),
+            end_time: make_datetime("2026-01-13", end),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        };
+        // The evening shift cannot be calculated, so only the morning's 4
+        // hours count towards the day's span
+        let mut shift_pm = part("shift_pm", "17:00:00", "21:00:00");
+        shift_pm.classification_code = Some("no_such_level".to_string());
+        let shifts = vec![part("shift_am", "06:30:00", "10:30:00"), shift_pm];
 
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            false,
+            true,
+            &loader,
+        )
+        .unwrap();
 
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let error: ApiError = serde_json::from_slice(&body).unwrap();
+        assert!(
+            result
+                .pay_lines
+                .iter()
+                .all(|pl| pl.category != PayCategory::SpanPenalty)
+        );
+        assert_eq!(result.totals.gross_pay, dec("114.16")); // 4 × $28.54
+        assert_eq!(result.shift_breakdown.len(), 1);
+        // The failure is still reported without the audit trace
+        assert!(result.audit_trace.steps.is_empty());
+        assert!(result.audit_trace.warnings.iter().any(|w| w.code
+            == SHIFT_CALCULATION_FAILED_WARNING
+            && w.message.contains("'shift_pm'")));
+    }
 
-        assert_eq!(error.code, "MALFORMED_JSON");
+    #[test]
+    fn test_shift_commencing_before_the_early_hour_pays_the_loading() {
+        use crate::config::EarlyCommencementConfig;
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = create_loader_with(|p| {
+            p.early_commencement = Some(EarlyCommencementConfig {
+                clause: "X.Y".to_string(),
+                before: chrono::NaiveTime::from_hms_opt(5, 0, 0).unwrap(),
+                penalty_rate: dec("0.1"),
+            })
+        });
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let shift = |id: &str, date: &str, start: &str, end: &str| Shift {
+            id: id.to_string(),
+            date: make_date(date),
+            start_time: make_datetime(date, start),
+            end_time: make_datetime(date, end),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        };
+        let shifts = vec![
+            shift("early", "2026-01-13", "04:30:00", "12:30:00"),
+            shift("day", "2026-01-14", "07:00:00", "15:00:00"),
+        ];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        // Only the 4:30am start is loaded: 8 hours × $28.54 × 0.1
+        let loadings: Vec<&PayLine> = result
+            .pay_lines
+            .iter()
+            .filter(|pl| pl.category == PayCategory::EarlyCommencementPenalty)
+            .collect();
+        assert_eq!(loadings.len(), 1);
+        assert_eq!(loadings[0].shift_id, "early");
+        assert_eq!(loadings[0].amount, dec("22.832"));
+        // The loading is paid on top, so the hours are unchanged
+        assert_eq!(result.totals.total_hours_worked, dec("16"));
+        assert_eq!(result.totals.gross_pay, dec("479.472"));
+        let steps: Vec<&AuditStep> = result
+            .audit_trace
+            .steps
+            .iter()
+            .filter(|step| step.rule_id == "early_commencement")
+            .collect();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].input["shift_id"], "early");
+        assert!(
+            result
+                .audit_trace
+                .warnings
+                .iter()
+                .all(|warning| warning.code != crate::calculation::HOURS_MISMATCH_WARNING)
+        );
     }
 
-    #[tokio::test]
-    async fn test_api_003_missing_employee_id_returns_400() {
-        let state = create_test_state();
-        let router = create_router(state);
+    #[test]
+    fn test_early_commencement_loading_is_paid_on_the_rounded_hours() {
+        use crate::config::EarlyCommencementConfig;
 
-        // JSON with missing employee.id field
-        let body = r#"{
-            "employee": {
-                "employment_type": "full_time",
-                "classification_code": "dce_level_3",
-                "date_of_birth": "1985-03-15",
-                "employment_start_date": "2020-01-01"
-            },
-            "pay_period": {
-                "start_date": "2026-01-13",
-                "end_date": "2026-01-19"
-            },
-            "shifts": []
-        }"#;
+        let loader = create_loader_with(|p| {
+            p.time_rounding = TimeRounding::Up(15);
+            p.early_commencement = Some(EarlyCommencementConfig {
+                clause: "X.Y".to_string(),
+                before: chrono::NaiveTime::from_hms_opt(5, 0, 0).unwrap(),
+                penalty_rate: Decimal::new(1, 1),
+            })
+        });
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // 7 hours 52 minutes from 04:30, paid as 8 hours
+        let shifts = vec![Shift {
+            id: "early".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "04:30:00"),
+            end_time: make_datetime("2026-01-13", "12:22:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
 
-        let response = router
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/calculate")
-                    .header("Content-Type", "application/json")
-                    .body(Body::from(body))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
 
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let loading = result
+            .pay_lines
+            .iter()
+            .find(|pl| pl.category == PayCategory::EarlyCommencementPenalty)
+            .expect("early commencement loading should be paid");
+        assert_eq!(result.totals.ordinary_hours, Decimal::from(8));
+        assert_eq!(loading.hours, Decimal::from(8));
+    }
 
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let error: ApiError = serde_json::from_slice(&body).unwrap();
+    #[test]
+    fn test_twelve_hour_weekday_shift_reports_two_overtime_tiers() {
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "07:00:00"),
+            end_time: make_datetime("2026-01-13", "19:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            false,
+            false,
+            &loader,
+        )
+        .unwrap();
 
-        // Check that error mentions the missing field
-        // serde may say "missing field `id`" or similar
+        // 4 hours past the 8 hour threshold: 2 at 150%, then 2 at 200%
+        let tiers: Vec<(u32, PayCategory, Decimal, Decimal, Decimal)> = result
+            .overtime_detail
+            .iter()
+            .map(|t| (t.tier, t.category, t.threshold_hours, t.hours, t.multiplier))
+            .collect();
+        assert_eq!(
+            tiers,
+            vec![
+                (1, PayCategory::Overtime150, dec("0"), dec("2"), dec("1.5")),
+                (2, PayCategory::Overtime200, dec("2"), dec("2"), dec("2.0")),
+            ]
+        );
         assert!(
-            error.message.contains("missing field") || error.message.to_lowercase().contains("id"),
-            "Expected error message to mention missing field or id, got: {}",
-            error.message
+            result
+                .overtime_detail
+                .iter()
+                .all(|t| t.shift_id == "shift_001")
         );
+        assert_eq!(result.overtime_detail[0].rate, dec("42.81"));
+        assert_eq!(result.overtime_detail[1].amount, dec("114.16"));
+        let tier_hours: Decimal = result.overtime_detail.iter().map(|t| t.hours).sum();
+        assert_eq!(tier_hours, result.totals.overtime_hours);
     }
 
-    #[tokio::test]
-    async fn test_api_004_unknown_classification_returns_400() {
-        let state = create_test_state();
-        let router = create_router(state);
+    #[test]
+    fn test_short_day_is_topped_up_to_minimum_daily_pay() {
+        use crate::calculation::HOURS_MISMATCH_WARNING;
+        use crate::config::MinimumDailyPayConfig;
+        use std::str::FromStr;
 
-        let mut request = create_valid_request();
-        request.employee.classification_code = "unknown".to_string();
-        let body = serde_json::to_string(&request).unwrap();
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = create_loader_with(|p| {
+            p.minimum_daily_pay = Some(MinimumDailyPayConfig {
+                clause: "X.Y".to_string(),
+                minimum_daily_hours: dec("3"),
+            })
+        });
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "09:00:00"),
+            end_time: make_datetime("2026-01-13", "11:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
 
-        let response = router
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/calculate")
-                    .header("Content-Type", "application/json")
-                    .body(Body::from(body))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
 
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        // A 2 hour shift against a 3 hour daily guarantee: 1 hour topped up
+        let top_up = result
+            .pay_lines
+            .iter()
+            .find(|pl| pl.category == PayCategory::MinimumDailyPay)
+            .expect("minimum daily pay should be topped up");
+        assert_eq!(top_up.shift_id, "shift_001");
+        assert_eq!(top_up.hours, dec("1"));
+        assert_eq!(top_up.amount, dec("28.54"));
+        // The top-up is paid as ordinary hours but was not worked
+        assert_eq!(result.totals.ordinary_hours, dec("3"));
+        assert_eq!(result.totals.total_hours_worked, dec("2"));
+        assert_eq!(result.totals.gross_pay, dec("85.62"));
+        assert!(
+            !result
+                .audit_trace
+                .warnings
+                .iter()
+                .any(|w| w.code == HOURS_MISMATCH_WARNING)
+        );
+        let step = result
+            .audit_trace
+            .steps
+            .iter()
+            .find(|step| step.rule_id == "minimum_daily_pay")
+            .expect("minimum daily pay should be audited");
+        assert_eq!(step.input["paid_hours"], "2");
+    }
 
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let error: ApiError = serde_json::from_slice(&body).unwrap();
+    #[test]
+    fn test_minimum_daily_pay_top_up_uses_the_shifts_override_rate() {
+        use crate::config::MinimumDailyPayConfig;
+        use std::str::FromStr;
 
-        assert_eq!(error.code, "CLASSIFICATION_NOT_FOUND");
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = create_loader_with(|p| {
+            p.minimum_daily_pay = Some(MinimumDailyPayConfig {
+                clause: "X.Y".to_string(),
+                minimum_daily_hours: dec("3"),
+            })
+        });
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "09:00:00"),
+            end_time: make_datetime("2026-01-13", "11:00:00"),
+            breaks: vec![],
+            rate_override: Some(dec("50")),
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        // The top-up hour is paid at the shift's $50, not the $28.54 base rate
+        let top_up = result
+            .pay_lines
+            .iter()
+            .find(|pl| pl.category == PayCategory::MinimumDailyPay)
+            .expect("minimum daily pay should be topped up");
+        assert_eq!(top_up.rate, dec("50"));
+        assert_eq!(top_up.amount, dec("50"));
+        assert_eq!(result.totals.gross_pay, dec("150"));
     }
 
-    #[tokio::test]
-    async fn test_fulltime_weekday_8h_calculation() {
-        let state = create_test_state();
-        let router = create_router(state);
+    #[test]
+    fn test_shift_ending_on_seconds_is_rounded_or_paid_exactly() {
+        use std::str::FromStr;
 
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
         let request = create_valid_request();
-        let body = serde_json::to_string(&request).unwrap();
-
-        let response = router
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/calculate")
-                    .header("Content-Type", "application/json")
-                    .body(Body::from(body))
-                    .unwrap(),
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "09:00:00"),
+            end_time: make_datetime("2026-01-13", "17:00:30"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+        let calculate = |loader: &ConfigLoader| {
+            perform_calculation(
+                &employee,
+                &pay_period,
+                &shifts,
+                None,
+                None,
+                true,
+                false,
+                loader,
             )
-            .await
-            .unwrap();
+            .unwrap()
+        };
 
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let result: CalculationResult = serde_json::from_slice(&body).unwrap();
+        // By default the finish is rounded to 17:01 and the change is audited
+        let rounded = calculate(&create_loader_with(|_| {}));
+        assert_eq!(rounded.totals.total_hours_worked, dec("8.0167"));
+        let step = rounded
+            .audit_trace
+            .steps
+            .iter()
+            .find(|step| step.rule_id == "shift_seconds_rounding")
+            .expect("the rounded finish should be audited");
+        assert_eq!(step.output["end_time"], "2026-01-13 17:01:00");
 
-        // 8 hours * $28.54 = $228.32
-        use std::str::FromStr;
-        assert_eq!(
-            result.totals.gross_pay,
-            Decimal::from_str("228.32").unwrap()
+        // Exact mode pays the 30 seconds to the second
+        let exact = calculate(&create_loader_with(|p| {
+            p.shift_seconds = ShiftSeconds::Exact
+        }));
+        assert_eq!(exact.totals.total_hours_worked, dec("8.0083"));
+        assert!(
+            !exact
+                .audit_trace
+                .steps
+                .iter()
+                .any(|step| step.rule_id == "shift_seconds_rounding")
         );
+    }
+
+    #[test]
+    fn test_broken_shift_pays_allowance_and_one_travel_reimbursement() {
+        use crate::models::Break;
+        use std::str::FromStr;
+
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let config = loader.config();
+        let mut rates = config.rates().to_vec();
+        for rate_config in &mut rates {
+            rate_config.allowances.broken_shift = Some(Decimal::from_str("20.82").unwrap());
+            rate_config.allowances.split_shift_travel = Some(Decimal::from_str("12.50").unwrap());
+        }
+        let loader = ConfigLoader::from_config(AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            rates,
+            config.penalties().clone(),
+        ));
+
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // 07:00-17:00 with an unpaid 2-hour gap in the middle of the day
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "07:00:00"),
+            end_time: make_datetime("2026-01-13", "17:00:00"),
+            breaks: vec![Break {
+                start_time: make_datetime("2026-01-13", "11:00:00"),
+                end_time: make_datetime("2026-01-13", "13:00:00"),
+                is_paid: false,
+                kind: None,
+            }],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        let types: Vec<&str> = result
+            .allowances
+            .iter()
+            .map(|a| a.allowance_type.as_str())
+            .collect();
+        assert_eq!(types, vec!["broken_shift", "split_shift_travel"]);
+        assert_eq!(result.allowances[1].units, Decimal::ONE);
         assert_eq!(
-            result.totals.ordinary_hours,
-            Decimal::from_str("8.0").unwrap()
+            result.totals.allowances_total,
+            Decimal::from_str("33.32").unwrap()
         );
     }
 
-    #[tokio::test]
-    async fn test_casual_saturday_with_laundry() {
-        let state = create_test_state();
-        let router = create_router(state);
-
-        let request = CalculationRequest {
-            employee: EmployeeRequest {
-                id: "emp_cas_001".to_string(),
-                employment_type: EmploymentType::Casual,
-                classification_code: "dce_level_3".to_string(),
-                date_of_birth: make_date("1990-07-22"),
-                employment_start_date: make_date("2024-06-01"),
-                base_hourly_rate: None,
-                tags: vec!["laundry_allowance".to_string()],
-            },
-            pay_period: PayPeriodRequest {
-                start_date: make_date("2026-01-13"),
-                end_date: make_date("2026-01-19"),
+    #[test]
+    fn test_monthly_period_pays_first_aid_for_each_week() {
+        use crate::models::PayPeriodType;
+        use std::str::FromStr;
+
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let config = loader.config();
+        let mut rates = config.rates().to_vec();
+        for rate_config in &mut rates {
+            rate_config.allowances.first_aid_per_week = Some(Decimal::from_str("18.93").unwrap());
+        }
+        let loader = ConfigLoader::from_config(AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            rates,
+            config.penalties().clone(),
+        ));
+
+        let mut employee: Employee = create_valid_request().employee.into();
+        employee.tags = vec!["first_aid_allowance".to_string()];
+
+        // February 2026 spans four Mondays, March 2026 spans five
+        for (start, end, weeks) in [
+            ("2026-02-01", "2026-02-28", 4),
+            ("2026-03-01", "2026-03-31", 5),
+        ] {
+            let pay_period = PayPeriod {
+                start_date: make_date(start),
+                end_date: make_date(end),
                 public_holidays: vec![],
-            },
-            shifts: vec![ShiftRequest {
+                period_type: None,
+            };
+            let date = make_date(start).succ_opt().unwrap();
+            let shifts = vec![Shift {
                 id: "shift_001".to_string(),
-                date: make_date("2026-01-17"), // Saturday
-                start_time: make_datetime("2026-01-17", "09:00:00"),
-                end_time: make_datetime("2026-01-17", "17:00:00"),
+                date,
+                start_time: date.and_hms_opt(9, 0, 0).unwrap(),
+                end_time: date.and_hms_opt(17, 0, 0).unwrap(),
                 breaks: vec![],
-            }],
-        };
+                rate_override: None,
+                classification_code: None,
+                reference: None,
+                cost_centre: None,
+            }];
 
-        let body = serde_json::to_string(&request).unwrap();
-
-        let response = router
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/calculate")
-                    .header("Content-Type", "application/json")
-                    .body(Body::from(body))
-                    .unwrap(),
+            let result = perform_calculation(
+                &employee,
+                &pay_period,
+                &shifts,
+                None,
+                None,
+                true,
+                false,
+                &loader,
             )
-            .await
-            .unwrap();
-
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
             .unwrap();
-        let result: CalculationResult = serde_json::from_slice(&body).unwrap();
 
-        // Casual Saturday: 8h * $28.54 * 1.75 = $399.56
-        // Plus laundry: $0.32
-        // Total: $399.88
-        use std::str::FromStr;
-        assert_eq!(
-            result.totals.gross_pay,
-            Decimal::from_str("399.88").unwrap()
-        );
-        assert_eq!(result.allowances.len(), 1);
-        assert_eq!(result.allowances[0].allowance_type, "laundry");
+            let first_aid = result
+                .allowances
+                .iter()
+                .find(|a| a.allowance_type == AllowanceType::FirstAid)
+                .expect("first aid allowance should be paid");
+            assert_eq!(first_aid.units, Decimal::from(weeks));
+            assert_eq!(
+                first_aid.amount,
+                Decimal::from(weeks) * Decimal::from_str("18.93").unwrap()
+            );
+            assert_eq!(result.pay_period.period_type, Some(PayPeriodType::Monthly));
+        }
     }
 
     #[tokio::test]
@@ -923,4 +5411,934 @@ mod tests {
         sorted.sort();
         assert_eq!(*classifications, sorted);
     }
+
+    #[test]
+    fn test_shared_calculation_state_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ConfigLoader>();
+        assert_send_sync::<AppState>();
+    }
+
+    #[test]
+    fn test_calculate_pay_rejects_invalid_request() {
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let mut request = create_valid_request();
+        request.employee.classification_code = "unknown".to_string();
+
+        let result = calculate_pay(request, &config);
+
+        assert!(matches!(
+            result,
+            Err(EngineError::ClassificationNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_suppressed_audit_leaves_results_unchanged() {
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let mut request = create_valid_request();
+        request.employee.tags = vec!["laundry_allowance".to_string()];
+
+        let with_audit = calculate_pay(request.clone(), &config).unwrap();
+        request.include_audit = false;
+        let without_audit = calculate_pay(request, &config).unwrap();
+
+        assert!(!with_audit.audit_trace.steps.is_empty());
+        assert!(without_audit.audit_trace.steps.is_empty());
+        assert_eq!(
+            without_audit.audit_trace.warnings,
+            with_audit.audit_trace.warnings
+        );
+
+        let numeric = |r: &CalculationResult| {
+            serde_json::to_string(&(
+                &r.pay_lines,
+                &r.shift_breakdown,
+                &r.allowances,
+                &r.totals,
+                &r.applied_clauses,
+            ))
+            .unwrap()
+        };
+        assert_eq!(numeric(&with_audit), numeric(&without_audit));
+    }
+
+    #[test]
+    fn test_no_audit_steps_are_built_without_include_audit() {
+        let loader = create_loader_with(|p| p.rounding_reconciliation = true);
+        let mut request = create_valid_request();
+        request.employee.tags = vec!["laundry_allowance".to_string()];
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // A weekday shift with overtime, a Saturday shift and a short shift
+        // left out of the laundry allowance
+        let mut long_shift = create_shift("shift_001", "2026-01-13");
+        long_shift.end_time = make_datetime("2026-01-13", "19:00:00");
+        let mut short_shift = create_shift("shift_002", "2026-01-15");
+        short_shift.end_time = make_datetime("2026-01-15", "10:00:00");
+        let shifts = vec![
+            long_shift,
+            short_shift,
+            create_shift("shift_003", "2026-01-17"),
+        ];
+
+        let calculate = |include_audit| {
+            perform_calculation(
+                &employee,
+                &pay_period,
+                &shifts,
+                None,
+                None,
+                include_audit,
+                false,
+                &loader,
+            )
+            .unwrap()
+        };
+        let with_audit = calculate(true);
+        let without_audit = calculate(false);
+
+        // The steps are never built, rather than built and then cleared
+        assert!(with_audit.audit_trace.steps.len() > 10);
+        assert!(without_audit.audit_trace.steps.is_empty());
+        assert_eq!(without_audit.pay_lines, with_audit.pay_lines);
+        assert_eq!(without_audit.allowances, with_audit.allowances);
+        assert_eq!(without_audit.applied_clauses, with_audit.applied_clauses);
+        assert!(
+            without_audit
+                .applied_clauses
+                .contains(&"25.1(a)(i)(A)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_deterministic_id_repeats_for_identical_requests() {
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let mut request = create_valid_request();
+
+        let calculation_id = |request: &CalculationRequest| {
+            calculate_pay(request.clone(), &config)
+                .unwrap()
+                .calculation_id
+        };
+
+        let random_ids = [calculation_id(&request), calculation_id(&request)];
+        request.deterministic_id = true;
+        let stable_ids = [calculation_id(&request), calculation_id(&request)];
+
+        assert_ne!(random_ids[0], random_ids[1]);
+        assert_eq!(random_ids[0].get_version_num(), 4);
+        assert_eq!(stable_ids[0], stable_ids[1]);
+        assert_eq!(
+            stable_ids[0],
+            deterministic_calculation_id(&request, &config)
+        );
+    }
+
+    #[test]
+    fn test_shift_reference_and_cost_centre_are_carried_onto_its_pay_lines() {
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let mut request = create_valid_request();
+        let tuesday = &mut request.shifts[0];
+        tuesday.start_time = make_datetime("2026-01-13", "07:00:00");
+        tuesday.end_time = Some(make_datetime("2026-01-13", "19:00:00"));
+        tuesday.reference = Some("backfill for emp_007".to_string());
+        tuesday.cost_centre = Some("CC-100".to_string());
+        let saturday = ShiftRequest {
+            id: "shift_sat".to_string(),
+            date: make_date("2026-01-17"),
+            start_time: make_datetime("2026-01-17", "09:00:00"),
+            end_time: Some(make_datetime("2026-01-17", "13:00:00")),
+            reference: None,
+            cost_centre: Some("CC-200".to_string()),
+            ..request.shifts[0].clone()
+        };
+        request.shifts.push(saturday);
+
+        let result = calculate_pay(request, &config).unwrap();
+
+        let categories: Vec<PayCategory> = result.pay_lines.iter().map(|l| l.category).collect();
+        assert!(categories.contains(&PayCategory::Ordinary));
+        assert!(categories.contains(&PayCategory::Overtime150));
+        assert!(categories.contains(&PayCategory::Saturday));
+        for line in &result.pay_lines {
+            let (reference, cost_centre) = if line.shift_id == "shift_sat" {
+                (None, Some("CC-200"))
+            } else {
+                (Some("backfill for emp_007"), Some("CC-100"))
+            };
+            assert_eq!(line.reference.as_deref(), reference);
+            assert_eq!(line.cost_centre.as_deref(), cost_centre);
+        }
+        // The shift breakdown holds the same pay lines
+        assert!(
+            result.shift_breakdown[0]
+                .pay_lines
+                .iter()
+                .all(|l| l.cost_centre.as_deref() == Some("CC-100"))
+        );
+    }
+
+    #[test]
+    fn test_shifts_under_a_second_classification_are_paid_its_rates() {
+        use crate::config::{Classification, ClassificationRate};
+        use std::str::FromStr;
+
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let base = loader.config();
+        let mut classifications = base.classifications().clone();
+        classifications.insert(
+            "dce_level_4".to_string(),
+            Classification {
+                name: "Direct Care Employee Level 4".to_string(),
+                description: "Senior direct care worker".to_string(),
+                clause: "14.2".to_string(),
+            },
+        );
+        let mut rates = base.rates().to_vec();
+        rates[0].rates.insert(
+            "dce_level_4".to_string(),
+            ClassificationRate {
+                weekly: Decimal::from_str("1120.80").unwrap(),
+                hourly: Decimal::from_str("29.49").unwrap(),
+            },
+        );
+        let config = ConfigLoader::from_config(AwardConfig::new(
+            base.award().clone(),
+            classifications,
+            rates,
+            base.penalties().clone(),
+        ));
+
+        let employee: Employee = create_valid_request().employee.into();
+        let pay_period = PayPeriod {
+            start_date: make_date("2026-01-12"),
+            end_date: make_date("2026-01-18"),
+            public_holidays: vec![],
+            period_type: None,
+        };
+        // Level 3 on Monday and Wednesday, level 4 on Tuesday and Saturday
+        let level_4 = |id: &str, date: &str| Shift {
+            classification_code: Some("dce_level_4".to_string()),
+            ..create_shift(id, date)
+        };
+        let shifts = vec![
+            create_shift("mon", "2026-01-12"),
+            level_4("tue", "2026-01-13"),
+            create_shift("wed", "2026-01-14"),
+            level_4("sat", "2026-01-17"),
+        ];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &config,
+        )
+        .unwrap();
+
+        let rate_of = |shift_id: &str| {
+            let line = result
+                .pay_lines
+                .iter()
+                .find(|l| l.shift_id == shift_id)
+                .unwrap();
+            (line.category, line.rate)
+        };
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        assert_eq!(rate_of("mon"), (PayCategory::Ordinary, dec("28.54")));
+        assert_eq!(rate_of("tue"), (PayCategory::Ordinary, dec("29.49")));
+        assert_eq!(rate_of("wed"), (PayCategory::Ordinary, dec("28.54")));
+        assert_eq!(rate_of("sat"), (PayCategory::Saturday, dec("44.235")));
+        // 4 hours a shift at its classification's rate, the Saturday at 150%
+        assert_eq!(result.totals.gross_pay, dec("523.22"));
+
+        let lookups: Vec<&AuditStep> = result
+            .audit_trace
+            .steps
+            .iter()
+            .filter(|s| s.rule_id == "base_rate_lookup")
+            .collect();
+        assert!(
+            lookups
+                .iter()
+                .any(|s| s.input["classification_code"] == "dce_level_4")
+        );
+    }
+
+    #[test]
+    fn test_echo_request_returns_the_interpreted_inputs() {
+        use crate::models::PayPeriodType;
+
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let body = serde_json::json!({
+            "employee": {
+                "id": "emp_001",
+                "employment_type": "full_time",
+                "classification_code": "dce_level_3",
+                "date_of_birth": "1985-03-15",
+                "employment_start_date": "2020-01-01"
+            },
+            "pay_period": { "start_date": "2026-01-13", "end_date": "2026-01-26" },
+            "shifts": [{
+                "id": "shift_001",
+                "date": "2026-01-13",
+                "start_time": "2026-01-13T09:00:00",
+                "duration_minutes": 510,
+                "breaks": [{
+                    "start_time": "2026-01-13T12:00:00",
+                    "duration": "PT30M",
+                    "is_paid": false
+                }]
+            }],
+            "state": "NSW",
+            "echo_request": true
+        });
+        let request = CalculationRequest::from_json(body.clone()).unwrap();
+
+        let result = calculate_pay(request, &config).unwrap();
+
+        let input = result.input.expect("input should be echoed");
+        assert_eq!(input.employee.id, "emp_001");
+        let shift = &input.shifts[0];
+        assert_eq!(
+            shift.shift.end_time,
+            make_datetime("2026-01-13", "17:30:00")
+        );
+        assert_eq!(
+            shift.shift.breaks[0].end_time,
+            make_datetime("2026-01-13", "12:30:00")
+        );
+        assert_eq!(shift.worked_hours, Decimal::from(8));
+        // The pay period is the one calculated, with its resolved type and
+        // the state's holidays (Australia Day)
+        assert_eq!(input.pay_period, result.pay_period);
+        assert_eq!(
+            input.pay_period.period_type,
+            Some(PayPeriodType::Fortnightly)
+        );
+        assert_eq!(input.pay_period.public_holidays.len(), 1);
+
+        // An echoed rate is in cents when the request's amounts are
+        let mut cents_body = body.clone();
+        cents_body["amounts_in_cents"] = serde_json::json!(true);
+        cents_body["employee"]["base_hourly_rate"] = serde_json::json!(3000);
+        let request = CalculationRequest::from_json(cents_body).unwrap();
+        let result = calculate_pay(request, &config).unwrap();
+        let input = result.input.unwrap();
+        assert_eq!(input.employee.base_hourly_rate, Some(Decimal::from(3000)));
+
+        // Without the flag nothing is echoed
+        let mut body = body;
+        body["echo_request"] = serde_json::json!(false);
+        let request = CalculationRequest::from_json(body).unwrap();
+        let result = calculate_pay(request, &config).unwrap();
+        let json = serde_json::to_value(&result).unwrap();
+        assert!(result.input.is_none());
+        assert!(json.get("input").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fixed_clock_and_deterministic_id_repeat_the_result_exactly() {
+        use crate::api::{CORRELATION_ID_HEADER, FixedClock};
+        use chrono::TimeZone;
+
+        let timestamp = Utc.with_ymd_and_hms(2026, 1, 19, 9, 30, 0).unwrap();
+        let state = create_test_state().with_clock(FixedClock::new(timestamp, 1500));
+        let mut request = create_valid_request();
+        request.deterministic_id = true;
+        let body = serde_json::to_string(&request).unwrap();
+
+        let mut responses = Vec::new();
+        for _ in 0..2 {
+            let response = create_router(state.clone())
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/calculate")
+                        .header("Content-Type", "application/json")
+                        .header(CORRELATION_ID_HEADER, "replay-001")
+                        .body(Body::from(body.clone()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            responses.push(
+                axum::body::to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        assert_eq!(responses[0], responses[1]);
+        let result: CalculationResult = serde_json::from_slice(&responses[0]).unwrap();
+        assert_eq!(result.timestamp, timestamp);
+        assert_eq!(result.audit_trace.duration_us, 1500);
+        assert_eq!(
+            result.calculation_id,
+            deterministic_calculation_id(&request, state.config())
+        );
+    }
+
+    #[test]
+    fn test_calculate_pay_runs_in_parallel_on_shared_config() {
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let expected = calculate_pay(create_valid_request(), &config)
+            .unwrap()
+            .totals;
+
+        let totals: Vec<PayTotals> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    scope.spawn(|| {
+                        calculate_pay(create_valid_request(), &config)
+                            .unwrap()
+                            .totals
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert_eq!(totals.len(), 8);
+        assert!(totals.iter().all(|t| *t == expected));
+    }
+
+    #[test]
+    fn test_hour_totals_match_displayed_pay_line_hours() {
+        use std::str::FromStr;
+
+        let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+
+        // Overnight Saturday shifts of 3.5 + 4.5 hours and 2.1167 + 2.7666 hours
+        for (start, end, expected) in [
+            ("20:30:00", "04:30:00", "8"),
+            ("21:53:00", "02:46:00", "4.8833"),
+        ] {
+            let shifts = vec![Shift {
+                id: "shift_001".to_string(),
+                date: make_date("2026-01-17"),
+                start_time: make_datetime("2026-01-17", start),
+                end_time: make_datetime("2026-01-18", end),
+                breaks: vec![],
+                rate_override: None,
+                classification_code: None,
+                reference: None,
+                cost_centre: None,
+            }];
+
+            let result = perform_calculation(
+                &employee,
+                &pay_period,
+                &shifts,
+                None,
+                None,
+                false,
+                false,
+                &config,
+            )
+            .unwrap();
+
+            let json = serde_json::to_value(&result).unwrap();
+            let displayed_total: Decimal = json["pay_lines"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|pl| Decimal::from_str(pl["hours"].as_str().unwrap()).unwrap())
+                .sum();
+            assert_eq!(json["totals"]["penalty_hours"], expected);
+            assert_eq!(result.totals.penalty_hours, displayed_total);
+        }
+    }
+
+    #[test]
+    fn test_overnight_shift_with_overtime_reconciles_hours() {
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // Friday 20:00 to Saturday 08:00: 4 weekday hours, 4 Saturday hours
+        // and 4 hours of overtime
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-16"),
+            start_time: make_datetime("2026-01-16", "20:00:00"),
+            end_time: make_datetime("2026-01-17", "08:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        let paid_hours: Decimal = result.pay_lines.iter().map(|pl| pl.hours).sum();
+        assert_eq!(paid_hours, shifts[0].worked_hours());
+        assert_eq!(result.totals.overtime_hours, Decimal::from(4));
+        assert!(
+            !result
+                .audit_trace
+                .warnings
+                .iter()
+                .any(|w| w.code == "HOURS_MISMATCH")
+        );
+    }
+
+    #[test]
+    fn test_overtime_in_monday_portion_of_sunday_night_shift_gets_weekday_tiers() {
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period = PayPeriod {
+            start_date: make_date("2026-01-12"),
+            end_date: make_date("2026-01-25"),
+            public_holidays: vec![],
+            period_type: None,
+        };
+        // Sunday 22:00 to Monday 10:00: 2 Sunday hours and 6 Monday hours are
+        // ordinary, leaving 4 hours of overtime, all on Monday
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-18"),
+            start_time: make_datetime("2026-01-18", "22:00:00"),
+            end_time: make_datetime("2026-01-19", "10:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        let overtime: Vec<&PayLine> = result
+            .pay_lines
+            .iter()
+            .filter(|pl| {
+                matches!(
+                    pl.category,
+                    PayCategory::Overtime150 | PayCategory::Overtime200
+                )
+            })
+            .collect();
+        assert_eq!(overtime.len(), 2);
+        assert!(overtime.iter().all(|pl| pl.date == make_date("2026-01-19")));
+        // First 2 hours at 150%, then 200%, rather than Sunday's flat 200%
+        assert_eq!(overtime[0].category, PayCategory::Overtime150);
+        assert_eq!(overtime[0].hours, dec("2"));
+        assert_eq!(overtime[0].amount, dec("85.62"));
+        assert_eq!(overtime[1].category, PayCategory::Overtime200);
+        assert_eq!(overtime[1].hours, dec("2"));
+        assert_eq!(overtime[1].amount, dec("114.16"));
+        assert!(
+            result
+                .audit_trace
+                .steps
+                .iter()
+                .any(|step| step.rule_id == "overtime_tier_1")
+        );
+        assert!(
+            !result
+                .audit_trace
+                .steps
+                .iter()
+                .any(|step| step.rule_id == "weekend_overtime")
+        );
+    }
+
+    #[test]
+    fn test_overtime_spanning_sunday_and_monday_is_paid_at_each_days_rates() {
+        use std::str::FromStr;
+
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period = PayPeriod {
+            start_date: make_date("2026-01-12"),
+            end_date: make_date("2026-01-25"),
+            public_holidays: vec![],
+            period_type: None,
+        };
+        // Sunday 12:00 to Monday 02:00: 8 ordinary Sunday hours, then 4 hours
+        // of overtime on Sunday and 2 on Monday
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-18"),
+            start_time: make_datetime("2026-01-18", "12:00:00"),
+            end_time: make_datetime("2026-01-19", "02:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        let overtime: Vec<(NaiveDate, PayCategory, Decimal, Decimal)> = result
+            .pay_lines
+            .iter()
+            .filter(|pl| {
+                matches!(
+                    pl.category,
+                    PayCategory::Overtime150 | PayCategory::Overtime200
+                )
+            })
+            .map(|pl| (pl.date, pl.category, pl.hours, pl.amount))
+            .collect();
+        assert_eq!(
+            overtime,
+            vec![
+                // Sunday overtime at 200%
+                (
+                    make_date("2026-01-18"),
+                    PayCategory::Overtime200,
+                    dec("4"),
+                    dec("228.32")
+                ),
+                // Monday overtime starts the weekday tiers at 150%
+                (
+                    make_date("2026-01-19"),
+                    PayCategory::Overtime150,
+                    dec("2"),
+                    dec("85.62")
+                ),
+            ]
+        );
+        assert_eq!(result.totals.overtime_hours, dec("6"));
+    }
+
+    #[tokio::test]
+    async fn test_rules_lists_every_rule_a_calculation_records() {
+        let router = create_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/rules")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: RulesResponse = serde_json::from_slice(&body).unwrap();
+        let laundry = result
+            .rules
+            .iter()
+            .find(|rule| rule.rule_id == "laundry_allowance")
+            .unwrap();
+        assert_eq!(laundry.rule_name, "Laundry Allowance");
+        assert_eq!(laundry.clause_ref, "15.2(b)");
+
+        // A casual working a long overnight weekend shift records a wide
+        // spread of rules; each must be in the catalogue
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let mut request = create_valid_request();
+        request.employee.employment_type = EmploymentType::Casual;
+        request.employee.tags = vec!["laundry_allowance".to_string()];
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-16"),
+            start_time: make_datetime("2026-01-16", "18:00:00"),
+            end_time: make_datetime("2026-01-17", "08:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+        let calculation = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        for step in &calculation.audit_trace.steps {
+            assert!(
+                result.rules.iter().any(|rule| rule.rule_id == step.rule_id),
+                "rule '{}' is missing from the catalogue",
+                step.rule_id
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_selftest_reports_each_check() {
+        async fn selftest(state: AppState) -> SelfTestResponse {
+            let response = create_router(state)
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/selftest")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice(&body).unwrap()
+        }
+
+        let report = selftest(create_test_state()).await;
+        assert!(report.passed);
+        assert_eq!(report.award_code, "MA000018");
+        assert_eq!(report.checks.len(), 4);
+
+        // A configuration built in code skips load-time validation, so only
+        // the self-test catches a Saturday penalty below ordinary time
+        let loader = create_loader_with(|p| p.penalties.saturday.full_time = Decimal::new(9, 1));
+        let report = selftest(AppState::new(loader)).await;
+        assert!(!report.passed);
+        let failed: Vec<&str> = report
+            .checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.check.as_str())
+            .collect();
+        assert_eq!(failed, vec!["penalty_multipliers", "casual_loading"]);
+    }
+
+    #[test]
+    fn test_saturday_threshold_override_defers_overtime() {
+        let loader = create_loader_with(|p| {
+            p.overtime
+                .daily_thresholds
+                .insert(Weekday::Sat, Decimal::from(10));
+        });
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-17"),
+            start_time: make_datetime("2026-01-17", "08:00:00"),
+            end_time: make_datetime("2026-01-17", "17:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        assert_eq!(result.totals.overtime_hours, Decimal::ZERO);
+        assert_eq!(result.totals.penalty_hours, Decimal::from(9));
+        let step = result
+            .audit_trace
+            .steps
+            .iter()
+            .find(|s| s.rule_id == "daily_overtime_threshold")
+            .unwrap();
+        assert_eq!(step.output["threshold"], "10");
+        assert_eq!(step.output["source"], "daily_thresholds");
+        assert!(
+            step.reasoning
+                .starts_with("Saturday has a configured daily overtime threshold")
+        );
+
+        // Other days keep the default threshold
+        let mut weekday_shifts = shifts.clone();
+        weekday_shifts[0].date = make_date("2026-01-16");
+        weekday_shifts[0].start_time = make_datetime("2026-01-16", "08:00:00");
+        weekday_shifts[0].end_time = make_datetime("2026-01-16", "17:00:00");
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &weekday_shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+        assert_eq!(result.totals.overtime_hours, Decimal::from(1));
+    }
+
+    #[test]
+    fn test_friday_night_shift_is_wholly_saturday_under_shift_commencement() {
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // Friday 22:00 to Saturday 06:00
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-16"),
+            start_time: make_datetime("2026-01-16", "22:00:00"),
+            end_time: make_datetime("2026-01-17", "06:00:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+
+        // Calendar days (the default) split the shift at midnight
+        let loader = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+        assert_eq!(result.totals.ordinary_hours, Decimal::from(2));
+        assert_eq!(result.totals.penalty_hours, Decimal::from(6));
+
+        let loader = create_loader_with(|p| {
+            p.weekend_days = WeekendDays {
+                basis: WeekendDayBasis::ShiftCommencement,
+                next_day_from: chrono::NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                min_segment_minutes: 0,
+            };
+        });
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        assert_eq!(result.pay_lines.len(), 1);
+        assert_eq!(result.pay_lines[0].category, PayCategory::Saturday);
+        assert_eq!(result.pay_lines[0].hours, Decimal::from(8));
+        assert_eq!(result.totals.ordinary_hours, Decimal::ZERO);
+        assert_eq!(result.totals.penalty_hours, Decimal::from(8));
+        let step = result
+            .audit_trace
+            .steps
+            .iter()
+            .find(|s| s.rule_id == "shift_commencement_day")
+            .unwrap();
+        assert_eq!(step.output["commencement_day"], "2026-01-17");
+        assert_eq!(step.output["day_type"], "Saturday");
+    }
+
+    #[test]
+    fn test_short_midnight_crossing_is_paid_as_friday() {
+        let request = create_valid_request();
+        let employee: Employee = request.employee.into();
+        let pay_period: PayPeriod = request.pay_period.into();
+        // Friday 16:05 to Saturday 00:05
+        let shifts = vec![Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-16"),
+            start_time: make_datetime("2026-01-16", "16:05:00"),
+            end_time: make_datetime("2026-01-17", "00:05:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }];
+        let loader = create_loader_with(|p| p.weekend_days.min_segment_minutes = 15);
+
+        let result = perform_calculation(
+            &employee,
+            &pay_period,
+            &shifts,
+            None,
+            None,
+            true,
+            false,
+            &loader,
+        )
+        .unwrap();
+
+        assert!(
+            result
+                .pay_lines
+                .iter()
+                .all(|line| line.category == PayCategory::Ordinary)
+        );
+        assert_eq!(result.totals.ordinary_hours, Decimal::from(8));
+        assert_eq!(result.totals.penalty_hours, Decimal::ZERO);
+        let step = result
+            .audit_trace
+            .steps
+            .iter()
+            .find(|s| s.rule_id == "short_segment_merge")
+            .unwrap();
+        assert_eq!(step.input["segment_hours"], "0.0833");
+    }
 }
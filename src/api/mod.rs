@@ -1,14 +1,43 @@
 //! HTTP API module for the Award Interpretation Engine.
 //!
 //! This module provides the REST API endpoints for calculating pay
-//! based on the Aged Care Award 2010.
+//! based on the Aged Care Award 2010, and for validating requests
+//! without calculating.
 
+mod calculation_id;
+mod clock;
+mod correlation;
+mod diff;
+mod earnings;
 mod handlers;
 mod request;
 mod response;
 mod state;
+mod upload;
+mod validation;
 
-pub use handlers::create_router;
-pub use request::CalculationRequest;
-pub use response::{ApiError, HealthResponse, InfoResponse};
-pub use state::AppState;
+pub use calculation_id::{CALCULATION_ID_NAMESPACE, deterministic_calculation_id};
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use correlation::{CORRELATION_ID_HEADER, MAX_CORRELATION_ID_LENGTH};
+pub use diff::{
+    CalculationDiff, DecimalDelta, EmploymentComparison, HypotheticalPartTimeLoading,
+    PayLineChange, PayLineDiff, TotalsDiff,
+};
+pub use earnings::{EarningLine, EarningsExport};
+pub use handlers::{
+    GROSS_EXCEEDS_EXPECTED_WARNING, SHIFT_CALCULATION_FAILED_WARNING, calculate_pay,
+    calculate_pay_with_clock, create_router,
+};
+pub use request::{
+    AUSTRALIAN_STATES, CalculateQuery, CalculationDiffRequest, CalculationRequest,
+    EmploymentComparisonRequest, RequestValidationError,
+};
+pub use response::{
+    ApiError, HealthResponse, InfoResponse, RuleSummary, RulesResponse, SelfTestResponse,
+    ValidationResponse,
+};
+pub use state::{AppState, DEFAULT_MAX_SHIFTS_PER_REQUEST};
+pub use upload::{
+    EmployeeUploadError, NDJSON_CONTENT_TYPE, ROSTER_COLUMNS, RosterRowError, RosterUploadLine,
+    RosterUploadQuery, RosterUploadResponse, RosterUploadSummary,
+};
@@ -1,12 +1,21 @@
 //! Request types for the Award Interpretation Engine API.
 //!
-//! This module defines the JSON request structures for the `/calculate` endpoint.
+//! This module defines the JSON request structures for the `/calculate` endpoint
+//! and the pipeline that turns a raw JSON body into a [`CalculationRequest`].
 
-use chrono::{NaiveDate, NaiveDateTime};
+use axum::http::StatusCode;
+use chrono::{Duration, NaiveDate, NaiveDateTime, Weekday};
+use chrono_tz::Tz;
 use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
 
-use crate::models::{Break, Employee, EmploymentType, PayPeriod, PublicHoliday, Shift};
+use crate::error::EngineError;
+use crate::models::{
+    Break, BreakKind, Employee, EmploymentType, PayPeriod, PayPeriodType, PublicHoliday, Shift,
+};
 
 /// Request body for the `/calculate` endpoint.
 ///
@@ -20,6 +29,369 @@ pub struct CalculationRequest {
     pub pay_period: PayPeriodRequest,
     /// The shifts worked during the pay period.
     pub shifts: Vec<ShiftRequest>,
+    /// The IANA timezone the shift times are recorded in (e.g. `Australia/Sydney`).
+    ///
+    /// When set, worked hours are measured in real elapsed time, so a shift
+    /// spanning a daylight saving transition is paid an hour less (or more).
+    /// When absent, shift times are treated as naive wall-clock times, as before.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<Tz>,
+    /// The Australian state or territory the work was performed in (e.g. `NSW`).
+    ///
+    /// When set, public holidays for the state are taken from the holiday
+    /// calendar and merged with any listed in `pay_period.public_holidays`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// The award to calculate under, by award code (e.g. `MA000018`); the
+    /// server's primary award when absent.
+    ///
+    /// The code must name an award the server has loaded, otherwise the
+    /// request fails with `UNKNOWN_AWARD`. `/calculate/diff` names its awards
+    /// separately and ignores this field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub award_code: Option<String>,
+    /// Whether to return the audit trace (defaults to true).
+    ///
+    /// When false, the audit steps are not built, unless `diagnostics` is
+    /// requested: unapplied rules are read from the steps, which are then
+    /// left out of the response. The trace then only reports its warnings and
+    /// `duration_us`. Pay lines, allowances, totals and applied clauses are
+    /// the same either way.
+    #[serde(default = "default_include_audit")]
+    pub include_audit: bool,
+    /// Whether to derive `calculation_id` from the request instead of
+    /// generating a random one (defaults to false).
+    ///
+    /// When true, the same request calculated under the same configuration
+    /// always gets the same ID, so downstream systems can deduplicate results.
+    /// See [`deterministic_calculation_id`](super::deterministic_calculation_id).
+    #[serde(default)]
+    pub deterministic_id: bool,
+    /// Whether a shift that cannot be calculated is left out instead of
+    /// failing the whole request (defaults to false).
+    ///
+    /// When true, the remaining shifts are still paid and each failed shift is
+    /// reported as a `SHIFT_CALCULATION_FAILED` warning in the audit trace.
+    /// A failed shift also counts towards no span of hours, minimum daily pay
+    /// or allowance.
+    #[serde(default)]
+    pub partial_results: bool,
+    /// Whether a request with no shifts is rejected instead of calculated
+    /// (defaults to false).
+    ///
+    /// By default an empty `shifts` array is valid and produces a zero-pay
+    /// result with a `no_shifts` audit step (any public holidays not worked
+    /// are still paid). When true, it fails with `NO_SHIFTS`, so a pipeline
+    /// can catch a roster that was left out.
+    #[serde(default)]
+    pub require_shifts: bool,
+    /// Whether money is expressed in integer cents rather than dollars
+    /// (defaults to false).
+    ///
+    /// When true, `employee.base_hourly_rate` and each shift's
+    /// `rate_override` are read as cents, and every monetary amount in the
+    /// result is given in cents (see
+    /// [`CalculationResult::convert_amounts_to_cents`]). The audit trace
+    /// still records the calculation in dollars.
+    ///
+    /// [`CalculationResult::convert_amounts_to_cents`]: crate::models::CalculationResult::convert_amounts_to_cents
+    #[serde(default)]
+    pub amounts_in_cents: bool,
+    /// Whether to echo the inputs back as the result's `input`, as the
+    /// engine interpreted them (defaults to false).
+    ///
+    /// When true, the result includes the normalized employee, pay period and
+    /// shifts, showing for example the end time derived from a shift's
+    /// `duration_minutes` and its worked hours after unpaid breaks. Off by
+    /// default to keep responses small.
+    #[serde(default)]
+    pub echo_request: bool,
+    /// Whether to list the rules the engine supports but did not apply, as
+    /// the result's `unapplied_rules` (defaults to false).
+    ///
+    /// Not read from the request body: `/calculate` sets it from the
+    /// `diagnostics` query parameter.
+    #[serde(skip)]
+    pub diagnostics: bool,
+}
+
+fn default_include_audit() -> bool {
+    true
+}
+
+/// Query parameters for `POST /calculate`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CalculateQuery {
+    /// Whether to return the result's `unapplied_rules` (defaults to false).
+    #[serde(default)]
+    pub diagnostics: bool,
+}
+
+/// The Australian state and territory codes accepted in `state`.
+pub const AUSTRALIAN_STATES: &[&str] = &["ACT", "NSW", "NT", "QLD", "SA", "TAS", "VIC", "WA"];
+
+impl CalculationRequest {
+    /// Builds a calculation request from a parsed JSON body.
+    ///
+    /// Required fields are checked structurally before deserializing, so a
+    /// missing field is always reported as [`RequestValidationError::MissingField`]
+    /// with its full path (e.g. `employee.id` or `shifts[0].start_time`),
+    /// independent of serde's error wording.
+    pub fn from_json(body: Value) -> Result<Self, RequestValidationError> {
+        if !body.is_object() {
+            return Err(RequestValidationError::NotAnObject);
+        }
+
+        check_required_fields(&body, "", &["employee", "pay_period", "shifts"])?;
+        check_required_fields(
+            &body["employee"],
+            "employee.",
+            &[
+                "id",
+                "employment_type",
+                "classification_code",
+                "date_of_birth",
+                "employment_start_date",
+            ],
+        )?;
+        check_required_fields(
+            &body["pay_period"],
+            "pay_period.",
+            &["start_date", "end_date"],
+        )?;
+        if let Some(holidays) = body["pay_period"]["public_holidays"].as_array() {
+            for (i, holiday) in holidays.iter().enumerate() {
+                let prefix = format!("pay_period.public_holidays[{}].", i);
+                check_required_fields(holiday, &prefix, &["date", "name"])?;
+            }
+        }
+        if let Some(shifts) = body["shifts"].as_array() {
+            for (i, shift) in shifts.iter().enumerate() {
+                let prefix = format!("shifts[{}].", i);
+                check_required_fields(shift, &prefix, &["id", "date", "start_time"])?;
+                // A shift length or expected hours can stand in for the end time
+                if shift.get("duration_minutes").is_none() && shift.get("expected_hours").is_none()
+                {
+                    check_required_fields(shift, &prefix, &["end_time"])?;
+                }
+                if let Some(breaks) = shift["breaks"].as_array() {
+                    for (j, brk) in breaks.iter().enumerate() {
+                        let prefix = format!("shifts[{}].breaks[{}].", i, j);
+                        check_required_fields(brk, &prefix, &["start_time"])?;
+                        // A break duration can stand in for the end time
+                        if brk.get("duration").is_none() {
+                            check_required_fields(brk, &prefix, &["end_time"])?;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut request: Self =
+            serde_json::from_value(body).map_err(|err| RequestValidationError::InvalidValue {
+                message: err.to_string(),
+            })?;
+
+        if let Some(state) = request.state.as_mut() {
+            *state = state.to_ascii_uppercase();
+            if !AUSTRALIAN_STATES.contains(&state.as_str()) {
+                return Err(RequestValidationError::InvalidValue {
+                    message: format!(
+                        "unknown state '{}', expected one of {}",
+                        state,
+                        AUSTRALIAN_STATES.join(", ")
+                    ),
+                });
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+/// A request to calculate the same roster under two award configurations.
+///
+/// The body is a [`CalculationRequest`] with two extra fields naming the award
+/// codes to compare.
+#[derive(Debug, Clone)]
+pub struct CalculationDiffRequest {
+    /// The award code of the configuration to compare from.
+    pub old_award: String,
+    /// The award code of the configuration to compare to.
+    pub new_award: String,
+    /// The roster to calculate under both configurations.
+    pub request: CalculationRequest,
+}
+
+impl CalculationDiffRequest {
+    /// Builds a diff request from a parsed JSON body.
+    ///
+    /// `old_award` and `new_award` are required strings; the rest of the body
+    /// is validated as for [`CalculationRequest::from_json`].
+    pub fn from_json(mut body: Value) -> Result<Self, RequestValidationError> {
+        let Some(object) = body.as_object_mut() else {
+            return Err(RequestValidationError::NotAnObject);
+        };
+
+        let mut award_code = |field: &str| match object.remove(field) {
+            Some(Value::String(code)) => Ok(code),
+            Some(_) => Err(RequestValidationError::InvalidValue {
+                message: format!("{} must be an award code string", field),
+            }),
+            None => Err(RequestValidationError::MissingField {
+                field: field.to_string(),
+            }),
+        };
+        let old_award = award_code("old_award")?;
+        let new_award = award_code("new_award")?;
+
+        Ok(Self {
+            old_award,
+            new_award,
+            request: CalculationRequest::from_json(body)?,
+        })
+    }
+}
+
+/// A request to calculate the same roster under two employment types.
+///
+/// The body is a [`CalculationRequest`] with an extra
+/// `compare_employment_type` field naming the employment type to compare the
+/// employee's own against.
+#[derive(Debug, Clone)]
+pub struct EmploymentComparisonRequest {
+    /// The employment type to calculate the roster under as well as the
+    /// employee's own.
+    pub compare_employment_type: EmploymentType,
+    /// Whether to model the part-time side with a hypothetical casual
+    /// loading (see [`HypotheticalPartTimeLoading`](crate::api::HypotheticalPartTimeLoading)).
+    pub hypothetical_part_time_loading: bool,
+    /// The roster to calculate under both employment types.
+    pub request: CalculationRequest,
+}
+
+impl EmploymentComparisonRequest {
+    /// Builds an employment comparison request from a parsed JSON body.
+    ///
+    /// `compare_employment_type` is required and must differ from
+    /// `employee.employment_type`. The optional `hypothetical_part_time_loading`
+    /// flag needs one of the two employment types to be part-time. The rest of
+    /// the body is validated as for [`CalculationRequest::from_json`].
+    pub fn from_json(mut body: Value) -> Result<Self, RequestValidationError> {
+        let Some(object) = body.as_object_mut() else {
+            return Err(RequestValidationError::NotAnObject);
+        };
+
+        let Some(value) = object.remove("compare_employment_type") else {
+            return Err(RequestValidationError::MissingField {
+                field: "compare_employment_type".to_string(),
+            });
+        };
+        let compare_employment_type: EmploymentType =
+            serde_json::from_value(value).map_err(|_| RequestValidationError::InvalidValue {
+                message: "compare_employment_type must be one of full_time, part_time or casual"
+                    .to_string(),
+            })?;
+
+        let hypothetical_part_time_loading = match object.remove("hypothetical_part_time_loading") {
+            None | Some(Value::Null) => false,
+            Some(Value::Bool(flag)) => flag,
+            Some(_) => {
+                return Err(RequestValidationError::InvalidValue {
+                    message: "hypothetical_part_time_loading must be true or false".to_string(),
+                });
+            }
+        };
+
+        let request = CalculationRequest::from_json(body)?;
+        if request.employee.employment_type == compare_employment_type {
+            return Err(RequestValidationError::InvalidValue {
+                message: format!(
+                    "compare_employment_type must differ from the employee's employment type ({})",
+                    compare_employment_type.as_str()
+                ),
+            });
+        }
+        if hypothetical_part_time_loading
+            && request.employee.employment_type != EmploymentType::PartTime
+            && compare_employment_type != EmploymentType::PartTime
+        {
+            return Err(RequestValidationError::InvalidValue {
+                message: "hypothetical_part_time_loading needs part_time as one of the compared employment types"
+                    .to_string(),
+            });
+        }
+
+        Ok(Self {
+            compare_employment_type,
+            hypothetical_part_time_loading,
+            request,
+        })
+    }
+}
+
+/// Checks that each required field is present on a JSON object.
+///
+/// Values that are not objects are left for deserialization to reject.
+fn check_required_fields(
+    value: &Value,
+    prefix: &str,
+    fields: &[&str],
+) -> Result<(), RequestValidationError> {
+    let Some(object) = value.as_object() else {
+        return Ok(());
+    };
+    match fields.iter().find(|field| !object.contains_key(**field)) {
+        Some(field) => Err(RequestValidationError::MissingField {
+            field: format!("{}{}", prefix, field),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Errors produced while turning a JSON body into a [`CalculationRequest`].
+///
+/// Each variant maps to a stable error code and HTTP status, so clients can
+/// handle request errors without parsing messages.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RequestValidationError {
+    /// The request body is valid JSON but not a JSON object.
+    #[error("request body must be a JSON object")]
+    NotAnObject,
+
+    /// A required field was not provided.
+    #[error("missing field: {field}")]
+    MissingField {
+        /// The path of the missing field (e.g. `employee.id`).
+        field: String,
+    },
+
+    /// A field has a value of the wrong type or an unsupported value.
+    #[error("invalid request: {message}")]
+    InvalidValue {
+        /// A description of the invalid value.
+        message: String,
+    },
+}
+
+impl RequestValidationError {
+    /// Returns the stable error code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            RequestValidationError::NotAnObject => "MALFORMED_JSON",
+            RequestValidationError::MissingField { .. } => "MISSING_FIELD",
+            RequestValidationError::InvalidValue { .. } => "VALIDATION_ERROR",
+        }
+    }
+
+    /// Returns the HTTP status for this error.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            RequestValidationError::NotAnObject
+            | RequestValidationError::MissingField { .. }
+            | RequestValidationError::InvalidValue { .. } => StatusCode::BAD_REQUEST,
+        }
+    }
 }
 
 /// Employee information in a calculation request.
@@ -38,6 +410,15 @@ pub struct EmployeeRequest {
     /// Optional override for the base hourly rate.
     #[serde(default)]
     pub base_hourly_rate: Option<Decimal>,
+    /// Optional maximum ordinary hours per day, below the daily overtime threshold.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_ordinary_hours_per_day: Option<Decimal>,
+    /// The days of the week the employee ordinarily works (e.g. `["mon", "tue"]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ordinary_days: Vec<Weekday>,
+    /// The employee's rostered days off in the pay period (e.g. `["2026-01-26"]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rostered_days_off: Vec<NaiveDate>,
     /// Tags for categorizing employees (e.g., qualifications, departments).
     #[serde(default)]
     pub tags: Vec<String>,
@@ -53,6 +434,9 @@ pub struct PayPeriodRequest {
     /// Public holidays that fall within this pay period.
     #[serde(default)]
     pub public_holidays: Vec<PublicHolidayRequest>,
+    /// The length of the pay period; inferred from the dates when omitted.
+    #[serde(default)]
+    pub period_type: Option<PayPeriodType>,
 }
 
 /// Public holiday information in a calculation request.
@@ -80,23 +464,69 @@ pub struct ShiftRequest {
     pub date: NaiveDate,
     /// The start time of the shift.
     pub start_time: NaiveDateTime,
-    /// The end time of the shift.
-    pub end_time: NaiveDateTime,
+    /// The end time of the shift (may be omitted when `duration_minutes` is given).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<NaiveDateTime>,
+    /// The length of the shift in minutes, for rostering systems that send
+    /// shift lengths rather than end times.
+    ///
+    /// When `end_time` is absent it is derived as `start_time + duration_minutes`;
+    /// when both are given they must agree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_minutes: Option<u32>,
+    /// The hours the shift is expected to run, for forecasting a roster
+    /// before its actual times are known.
+    ///
+    /// Given instead of `end_time` and `duration_minutes`; the end time is
+    /// derived as `start_time + expected_hours` and the result is flagged as
+    /// an estimate (see
+    /// [`CalculationResult::estimate`](crate::models::CalculationResult::estimate)).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_hours: Option<Decimal>,
     /// Breaks taken during the shift.
     #[serde(default)]
     pub breaks: Vec<BreakRequest>,
+    /// An agreed hourly rate for this shift only, replacing the employee's
+    /// base rate (see [`Shift::rate_override`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_override: Option<Decimal>,
+    /// The classification this shift is worked under, when it differs from
+    /// the employee's (see [`Shift::classification_code`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classification_code: Option<String>,
+    /// A payroll note or reference, copied onto the shift's pay lines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// A cost centre code, copied onto the shift's pay lines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_centre: Option<String>,
 }
 
 /// Break information in a calculation request.
+///
+/// A break is given either by its start and end times, or by its start time
+/// and an ISO 8601 `duration` (e.g. `PT30M`) for systems that send break
+/// lengths.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BreakRequest {
     /// The start time of the break.
     pub start_time: NaiveDateTime,
-    /// The end time of the break.
-    pub end_time: NaiveDateTime,
+    /// The end time of the break (may be omitted when `duration` is given).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<NaiveDateTime>,
+    /// The length of the break as an ISO 8601 duration of hours, minutes and
+    /// seconds (e.g. `PT30M` or `PT1H15M`).
+    ///
+    /// When `end_time` is absent it is derived as `start_time + duration`;
+    /// when both are given they must agree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
     /// Whether the break is paid.
     #[serde(default)]
     pub is_paid: bool,
+    /// The kind of break (inferred from the duration when absent).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<BreakKind>,
 }
 
 impl From<EmployeeRequest> for Employee {
@@ -108,6 +538,9 @@ impl From<EmployeeRequest> for Employee {
             date_of_birth: req.date_of_birth,
             employment_start_date: req.employment_start_date,
             base_hourly_rate: req.base_hourly_rate,
+            max_ordinary_hours_per_day: req.max_ordinary_hours_per_day,
+            ordinary_days: req.ordinary_days,
+            rostered_days_off: req.rostered_days_off,
             tags: req.tags,
         }
     }
@@ -119,6 +552,7 @@ impl From<PayPeriodRequest> for PayPeriod {
             start_date: req.start_date,
             end_date: req.end_date,
             public_holidays: req.public_holidays.into_iter().map(Into::into).collect(),
+            period_type: req.period_type,
         }
     }
 }
@@ -133,26 +567,161 @@ impl From<PublicHolidayRequest> for PublicHoliday {
     }
 }
 
-impl From<ShiftRequest> for Shift {
-    fn from(req: ShiftRequest) -> Self {
-        Shift {
+impl TryFrom<ShiftRequest> for Shift {
+    type Error = EngineError;
+
+    /// Converts a shift request, deriving the end time from `duration_minutes`
+    /// or `expected_hours` when `end_time` is not given, and each break's end
+    /// time from its `duration` in the same way.
+    ///
+    /// Fails with [`EngineError::InvalidShift`] when none is given, when both
+    /// `end_time` and `duration_minutes` are given and `end_time` is not
+    /// `start_time + duration_minutes`, or when `expected_hours` is given
+    /// alongside either of them or is not positive, and with
+    /// [`EngineError::InvalidBreak`] when a break's times are inconsistent in
+    /// the same way or its duration cannot be read.
+    fn try_from(req: ShiftRequest) -> Result<Self, Self::Error> {
+        let invalid = |message: String| EngineError::InvalidShift {
+            shift_id: req.id.clone(),
+            message,
+        };
+
+        if req.expected_hours.is_some()
+            && (req.end_time.is_some() || req.duration_minutes.is_some())
+        {
+            return Err(invalid(
+                "expected_hours cannot be combined with end_time or duration_minutes".to_string(),
+            ));
+        }
+
+        let end_time = match (req.end_time, req.duration_minutes) {
+            (Some(end_time), None) => end_time,
+            (end_time, Some(minutes)) => {
+                let derived = req
+                    .start_time
+                    .checked_add_signed(Duration::minutes(i64::from(minutes)))
+                    .ok_or_else(|| {
+                        invalid(format!("duration_minutes {} is out of range", minutes))
+                    })?;
+                match end_time {
+                    Some(end_time) if end_time != derived => {
+                        return Err(invalid(format!(
+                            "end_time {} does not match start_time + duration_minutes ({})",
+                            end_time, derived
+                        )));
+                    }
+                    _ => derived,
+                }
+            }
+            (None, None) => match req.expected_hours {
+                Some(hours) if hours > Decimal::ZERO => (hours * Decimal::from(3600))
+                    .round()
+                    .to_i64()
+                    .and_then(|seconds| {
+                        req.start_time
+                            .checked_add_signed(Duration::seconds(seconds))
+                    })
+                    .ok_or_else(|| invalid(format!("expected_hours {} is out of range", hours)))?,
+                Some(hours) => {
+                    return Err(invalid(format!(
+                        "expected_hours must be positive, got {}",
+                        hours
+                    )));
+                }
+                None => {
+                    return Err(invalid(
+                        "one of end_time, duration_minutes or expected_hours is required"
+                            .to_string(),
+                    ));
+                }
+            },
+        };
+
+        let breaks = req
+            .breaks
+            .into_iter()
+            .enumerate()
+            .map(|(break_index, brk)| {
+                brk.into_break()
+                    .map_err(|message| EngineError::InvalidBreak {
+                        shift_id: req.id.clone(),
+                        break_index,
+                        message,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Shift {
             id: req.id,
             date: req.date,
             start_time: req.start_time,
-            end_time: req.end_time,
-            breaks: req.breaks.into_iter().map(Into::into).collect(),
-        }
+            end_time,
+            breaks,
+            rate_override: req.rate_override,
+            classification_code: req.classification_code,
+            reference: req.reference,
+            cost_centre: req.cost_centre,
+        })
     }
 }
 
-impl From<BreakRequest> for Break {
-    fn from(req: BreakRequest) -> Self {
-        Break {
-            start_time: req.start_time,
-            end_time: req.end_time,
-            is_paid: req.is_paid,
+impl BreakRequest {
+    /// Converts the break, deriving its end time from `duration` when
+    /// `end_time` is not given.
+    fn into_break(self) -> Result<Break, String> {
+        let end_time = match (self.end_time, &self.duration) {
+            (Some(end_time), None) => end_time,
+            (end_time, Some(duration)) => {
+                let derived = parse_iso8601_duration(duration)
+                    .and_then(|length| self.start_time.checked_add_signed(length))
+                    .ok_or_else(|| {
+                        format!(
+                            "duration '{}' is not an ISO 8601 duration of hours, minutes and seconds (e.g. PT30M)",
+                            duration
+                        )
+                    })?;
+                match end_time {
+                    Some(end_time) if end_time != derived => {
+                        return Err(format!(
+                            "end_time {} does not match start_time + duration ({})",
+                            end_time, derived
+                        ));
+                    }
+                    _ => derived,
+                }
+            }
+            (None, None) => return Err("one of end_time or duration is required".to_string()),
+        };
+
+        Ok(Break {
+            start_time: self.start_time,
+            end_time,
+            is_paid: self.is_paid,
+            kind: self.kind,
+        })
+    }
+}
+
+/// Parses a positive ISO 8601 duration made of hours, minutes and seconds, in
+/// that order (e.g. `PT30M`, `PT1H15M` or `PT45S`).
+fn parse_iso8601_duration(value: &str) -> Option<Duration> {
+    let mut rest = value.strip_prefix("PT")?;
+    let mut total = Duration::zero();
+    for (designator, unit) in [
+        ('H', Duration::hours(1)),
+        ('M', Duration::minutes(1)),
+        ('S', Duration::seconds(1)),
+    ] {
+        if let Some(position) = rest.find(designator) {
+            let digits = &rest[..position];
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            total += unit * digits.parse::<i32>().ok()?;
+            rest = &rest[position + 1..];
         }
     }
+    (rest.is_empty() && total > Duration::zero()).then_some(total)
 }
 
 #[cfg(test)]
@@ -214,7 +783,12 @@ mod tests {
 
         let request: CalculationRequest = serde_json::from_str(json).unwrap();
         assert_eq!(request.employee.employment_type, EmploymentType::Casual);
-        assert!(request.employee.tags.contains(&"laundry_allowance".to_string()));
+        assert!(
+            request
+                .employee
+                .tags
+                .contains(&"laundry_allowance".to_string())
+        );
     }
 
     #[test]
@@ -226,6 +800,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec!["laundry_allowance".to_string()],
         };
 
@@ -233,4 +810,353 @@ mod tests {
         assert_eq!(employee.id, "emp_001");
         assert!(employee.tags.contains(&"laundry_allowance".to_string()));
     }
+
+    fn valid_body() -> Value {
+        serde_json::json!({
+            "employee": {
+                "id": "emp_001",
+                "employment_type": "full_time",
+                "classification_code": "dce_level_3",
+                "date_of_birth": "1985-03-15",
+                "employment_start_date": "2020-01-01"
+            },
+            "pay_period": {
+                "start_date": "2026-01-13",
+                "end_date": "2026-01-19"
+            },
+            "shifts": [
+                {
+                    "id": "shift_001",
+                    "date": "2026-01-13",
+                    "start_time": "2026-01-13T09:00:00",
+                    "end_time": "2026-01-13T17:00:00",
+                    "breaks": [
+                        {"start_time": "2026-01-13T12:00:00", "end_time": "2026-01-13T12:30:00"}
+                    ]
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_timezone_is_optional() {
+        let request = CalculationRequest::from_json(valid_body()).unwrap();
+        assert!(request.timezone.is_none());
+
+        let mut body = valid_body();
+        body["timezone"] = Value::from("Australia/Sydney");
+        let request = CalculationRequest::from_json(body).unwrap();
+        assert_eq!(request.timezone, Some(chrono_tz::Australia::Sydney));
+
+        let mut body = valid_body();
+        body["timezone"] = Value::from("Australia/Atlantis");
+        assert!(CalculationRequest::from_json(body).is_err());
+    }
+
+    #[test]
+    fn test_include_audit_defaults_to_true() {
+        let request = CalculationRequest::from_json(valid_body()).unwrap();
+        assert!(request.include_audit);
+
+        let mut body = valid_body();
+        body["include_audit"] = Value::from(false);
+        let request = CalculationRequest::from_json(body).unwrap();
+        assert!(!request.include_audit);
+    }
+
+    #[test]
+    fn test_diff_request_requires_both_award_codes() {
+        let mut body = valid_body();
+        body["old_award"] = Value::from("MA000018");
+        body["new_award"] = Value::from("MA000018-2026");
+        let diff = CalculationDiffRequest::from_json(body.clone()).unwrap();
+        assert_eq!(diff.old_award, "MA000018");
+        assert_eq!(diff.new_award, "MA000018-2026");
+        assert_eq!(diff.request.employee.id, "emp_001");
+
+        body.as_object_mut().unwrap().remove("new_award");
+        let err = CalculationDiffRequest::from_json(body).unwrap_err();
+        assert_eq!(
+            err,
+            RequestValidationError::MissingField {
+                field: "new_award".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_employment_comparison_request_needs_a_different_type() {
+        let mut body = valid_body();
+        body["compare_employment_type"] = Value::from("casual");
+        let comparison = EmploymentComparisonRequest::from_json(body.clone()).unwrap();
+        assert_eq!(comparison.compare_employment_type, EmploymentType::Casual);
+        assert_eq!(comparison.request.employee.id, "emp_001");
+
+        body["compare_employment_type"] = body["employee"]["employment_type"].clone();
+        let err = EmploymentComparisonRequest::from_json(body.clone()).unwrap_err();
+        assert!(err.to_string().contains("must differ"));
+
+        body["compare_employment_type"] = Value::from("contractor");
+        let err = EmploymentComparisonRequest::from_json(body).unwrap_err();
+        assert_eq!(err.code(), "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_hypothetical_part_time_loading_needs_a_part_time_side() {
+        let mut body = valid_body();
+        body["compare_employment_type"] = Value::from("casual");
+        let comparison = EmploymentComparisonRequest::from_json(body.clone()).unwrap();
+        assert!(!comparison.hypothetical_part_time_loading);
+
+        body["hypothetical_part_time_loading"] = Value::Bool(true);
+        let err = EmploymentComparisonRequest::from_json(body.clone()).unwrap_err();
+        assert!(err.to_string().contains("needs part_time"));
+
+        body["employee"]["employment_type"] = Value::from("part_time");
+        let comparison = EmploymentComparisonRequest::from_json(body.clone()).unwrap();
+        assert!(comparison.hypothetical_part_time_loading);
+
+        body["hypothetical_part_time_loading"] = Value::from("yes");
+        let err = EmploymentComparisonRequest::from_json(body).unwrap_err();
+        assert_eq!(err.code(), "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_state_is_normalised_and_checked() {
+        let mut body = valid_body();
+        body["state"] = Value::from("vic");
+        let request = CalculationRequest::from_json(body).unwrap();
+        assert_eq!(request.state.as_deref(), Some("VIC"));
+
+        let mut body = valid_body();
+        body["state"] = Value::from("XYZ");
+        let err = CalculationRequest::from_json(body).unwrap_err();
+        assert_eq!(err.code(), "VALIDATION_ERROR");
+        assert!(err.to_string().contains("unknown state 'XYZ'"));
+    }
+
+    #[test]
+    fn test_from_json_accepts_valid_body() {
+        let request = CalculationRequest::from_json(valid_body()).unwrap();
+        assert_eq!(request.employee.id, "emp_001");
+        assert_eq!(request.shifts[0].breaks.len(), 1);
+    }
+
+    #[test]
+    fn test_from_json_reports_missing_field_path() {
+        let mut body = valid_body();
+        body["employee"].as_object_mut().unwrap().remove("id");
+
+        let err = CalculationRequest::from_json(body).unwrap_err();
+        assert_eq!(
+            err,
+            RequestValidationError::MissingField {
+                field: "employee.id".to_string()
+            }
+        );
+        assert_eq!(err.code(), "MISSING_FIELD");
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(err.to_string(), "missing field: employee.id");
+    }
+
+    #[test]
+    fn test_from_json_reports_missing_nested_field_path() {
+        let mut body = valid_body();
+        body["shifts"][0]["breaks"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("end_time");
+
+        let err = CalculationRequest::from_json(body).unwrap_err();
+        assert_eq!(
+            err,
+            RequestValidationError::MissingField {
+                field: "shifts[0].breaks[0].end_time".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_duration_minutes_stands_in_for_end_time() {
+        let mut body = valid_body();
+        let shift = body["shifts"][0].as_object_mut().unwrap();
+        shift.remove("end_time");
+        shift.insert("duration_minutes".to_string(), Value::from(450));
+
+        let request = CalculationRequest::from_json(body).unwrap();
+        let shift = Shift::try_from(request.shifts[0].clone()).unwrap();
+        assert_eq!(
+            shift.end_time,
+            NaiveDate::from_ymd_opt(2026, 1, 13)
+                .unwrap()
+                .and_hms_opt(16, 30, 0)
+                .unwrap()
+        );
+
+        let mut body = valid_body();
+        body["shifts"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("end_time");
+        let err = CalculationRequest::from_json(body).unwrap_err();
+        assert_eq!(
+            err,
+            RequestValidationError::MissingField {
+                field: "shifts[0].end_time".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_shift_rate_override_is_carried_into_shift() {
+        let mut body = valid_body();
+        body["shifts"][0]["rate_override"] = Value::from("42.50");
+
+        let request = CalculationRequest::from_json(body).unwrap();
+        let shift = Shift::try_from(request.shifts[0].clone()).unwrap();
+        assert_eq!(shift.rate_override, Some(Decimal::new(4250, 2)));
+
+        let request = CalculationRequest::from_json(valid_body()).unwrap();
+        let shift = Shift::try_from(request.shifts[0].clone()).unwrap();
+        assert_eq!(shift.rate_override, None);
+    }
+
+    #[test]
+    fn test_shift_conversion_rejects_inconsistent_duration() {
+        let mut body = valid_body();
+        body["shifts"][0]["duration_minutes"] = Value::from(480);
+        let request = CalculationRequest::from_json(body).unwrap();
+        assert!(Shift::try_from(request.shifts[0].clone()).is_ok());
+
+        let mut body = valid_body();
+        body["shifts"][0]["duration_minutes"] = Value::from(450);
+        let request = CalculationRequest::from_json(body).unwrap();
+        let err = Shift::try_from(request.shifts[0].clone()).unwrap_err();
+        assert!(
+            matches!(err, EngineError::InvalidShift { ref shift_id, .. } if shift_id == "shift_001")
+        );
+        assert!(err.to_string().contains(
+            "end_time 2026-01-13 17:00:00 does not match start_time + duration_minutes (2026-01-13 16:30:00)"
+        ));
+    }
+
+    #[test]
+    fn test_expected_hours_stand_in_for_end_time() {
+        let mut body = valid_body();
+        let shift = body["shifts"][0].as_object_mut().unwrap();
+        shift.remove("end_time");
+        shift.insert("expected_hours".to_string(), Value::from("7.25"));
+
+        let request = CalculationRequest::from_json(body).unwrap();
+        let shift = Shift::try_from(request.shifts[0].clone()).unwrap();
+        assert_eq!(
+            shift.end_time,
+            NaiveDate::from_ymd_opt(2026, 1, 13)
+                .unwrap()
+                .and_hms_opt(16, 15, 0)
+                .unwrap()
+        );
+
+        // Expected hours are an alternative to actual times, not a check on them
+        let mut body = valid_body();
+        body["shifts"][0]["expected_hours"] = Value::from("8");
+        let request = CalculationRequest::from_json(body).unwrap();
+        let err = Shift::try_from(request.shifts[0].clone()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected_hours cannot be combined with end_time or duration_minutes")
+        );
+
+        let mut body = valid_body();
+        let shift = body["shifts"][0].as_object_mut().unwrap();
+        shift.remove("end_time");
+        shift.insert("expected_hours".to_string(), Value::from("0"));
+        let request = CalculationRequest::from_json(body).unwrap();
+        let err = Shift::try_from(request.shifts[0].clone()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected_hours must be positive, got 0")
+        );
+    }
+
+    #[test]
+    fn test_break_duration_gives_the_same_worked_hours_as_end_time() {
+        let request = CalculationRequest::from_json(valid_body()).unwrap();
+        let explicit = Shift::try_from(request.shifts[0].clone()).unwrap();
+
+        let mut body = valid_body();
+        let brk = body["shifts"][0]["breaks"][0].as_object_mut().unwrap();
+        brk.remove("end_time");
+        brk.insert("duration".to_string(), Value::from("PT30M"));
+        let request = CalculationRequest::from_json(body).unwrap();
+        let from_duration = Shift::try_from(request.shifts[0].clone()).unwrap();
+
+        assert_eq!(from_duration.breaks, explicit.breaks);
+        assert_eq!(from_duration.worked_hours(), explicit.worked_hours());
+        assert_eq!(from_duration.worked_hours(), Decimal::new(75, 1));
+    }
+
+    #[test]
+    fn test_break_conversion_rejects_bad_or_inconsistent_duration() {
+        let mut body = valid_body();
+        body["shifts"][0]["breaks"][0]["duration"] = Value::from("PT30M");
+        let request = CalculationRequest::from_json(body).unwrap();
+        assert!(Shift::try_from(request.shifts[0].clone()).is_ok());
+
+        let mut body = valid_body();
+        body["shifts"][0]["breaks"][0]["duration"] = Value::from("PT45M");
+        let request = CalculationRequest::from_json(body).unwrap();
+        let err = Shift::try_from(request.shifts[0].clone()).unwrap_err();
+        assert!(matches!(
+            err,
+            EngineError::InvalidBreak { ref shift_id, break_index: 0, .. } if shift_id == "shift_001"
+        ));
+        assert!(err.to_string().contains(
+            "end_time 2026-01-13 12:30:00 does not match start_time + duration (2026-01-13 12:45:00)"
+        ));
+
+        let mut body = valid_body();
+        body["shifts"][0]["breaks"][0]["duration"] = Value::from("30 minutes");
+        let request = CalculationRequest::from_json(body).unwrap();
+        let err = Shift::try_from(request.shifts[0].clone()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("duration '30 minutes' is not an ISO 8601 duration")
+        );
+    }
+
+    #[test]
+    fn test_parse_iso8601_duration() {
+        assert_eq!(parse_iso8601_duration("PT30M"), Some(Duration::minutes(30)));
+        assert_eq!(
+            parse_iso8601_duration("PT1H15M"),
+            Some(Duration::minutes(75))
+        );
+        assert_eq!(parse_iso8601_duration("PT1H"), Some(Duration::hours(1)));
+        assert_eq!(parse_iso8601_duration("PT90S"), Some(Duration::seconds(90)));
+        // Date parts, out-of-order or empty designators and zero are rejected
+        assert_eq!(parse_iso8601_duration("P1D"), None);
+        assert_eq!(parse_iso8601_duration("PT30M1H"), None);
+        assert_eq!(parse_iso8601_duration("PTM"), None);
+        assert_eq!(parse_iso8601_duration("PT+5M"), None);
+        assert_eq!(parse_iso8601_duration("PT"), None);
+        assert_eq!(parse_iso8601_duration("PT0M"), None);
+    }
+
+    #[test]
+    fn test_from_json_reports_invalid_value() {
+        let mut body = valid_body();
+        body["employee"]["employment_type"] = Value::from("invalid_type");
+
+        let err = CalculationRequest::from_json(body).unwrap_err();
+        assert!(matches!(err, RequestValidationError::InvalidValue { .. }));
+        assert_eq!(err.code(), "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_from_json_rejects_non_object_body() {
+        let err = CalculationRequest::from_json(Value::from(vec![1, 2, 3])).unwrap_err();
+        assert_eq!(err, RequestValidationError::NotAnObject);
+        assert_eq!(err.code(), "MALFORMED_JSON");
+    }
 }
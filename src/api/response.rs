@@ -4,14 +4,17 @@
 //! for the HTTP API.
 
 use axum::{
+    Json,
     http::StatusCode,
     response::{IntoResponse, Response},
-    Json,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::config::SelfTestCheck;
 use crate::error::EngineError;
 
+use super::request::RequestValidationError;
+
 /// Health check response structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthResponse {
@@ -90,7 +93,19 @@ impl ApiError {
         Self::with_details(
             "CLASSIFICATION_NOT_FOUND",
             format!("Classification not found: {}", code),
-            format!("The classification code '{}' is not supported by this engine", code),
+            format!(
+                "The classification code '{}' is not supported by this engine",
+                code
+            ),
+        )
+    }
+
+    /// Creates an unknown award error response.
+    pub fn unknown_award(code: &str) -> Self {
+        Self::with_details(
+            "UNKNOWN_AWARD",
+            format!("Award not found: {}", code),
+            format!("No award configuration is loaded under the code '{}'", code),
         )
     }
 
@@ -99,6 +114,40 @@ impl ApiError {
         Self::new("MALFORMED_JSON", message)
     }
 
+    /// Creates an unsupported media type error response for an endpoint that
+    /// expects `content_type` (e.g. `application/json`).
+    pub fn unsupported_media_type(content_type: &str) -> Self {
+        Self::with_details(
+            "UNSUPPORTED_MEDIA_TYPE",
+            format!("Content-Type must be {}", content_type),
+            format!(
+                "Send the request body with the header 'Content-Type: {}'",
+                content_type
+            ),
+        )
+    }
+
+    /// Creates an error response for a request with more shifts than allowed.
+    pub fn too_many_shifts(count: usize, max_shifts: usize) -> Self {
+        Self::with_details(
+            "TOO_MANY_SHIFTS",
+            format!(
+                "Request has {} shifts, more than the limit of {}",
+                count, max_shifts
+            ),
+            "Split the shifts across several requests",
+        )
+    }
+
+    /// Creates a method not allowed error response.
+    pub fn method_not_allowed(method: &str, path: &str) -> Self {
+        Self::with_details(
+            "METHOD_NOT_ALLOWED",
+            format!("Method {} is not allowed on {}", method, path),
+            "The Allow header lists the methods this endpoint supports",
+        )
+    }
+
     /// Creates a missing field error response.
     pub fn missing_field(field: impl Into<String>) -> Self {
         let field = field.into();
@@ -124,6 +173,17 @@ impl IntoResponse for ApiErrorResponse {
     }
 }
 
+impl From<RequestValidationError> for ApiErrorResponse {
+    fn from(error: RequestValidationError) -> Self {
+        let status = error.status();
+        let error = match error {
+            RequestValidationError::MissingField { field } => ApiError::missing_field(field),
+            other => ApiError::new(other.code(), other.to_string()),
+        };
+        ApiErrorResponse { status, error }
+    }
+}
+
 impl From<EngineError> for ApiErrorResponse {
     fn from(error: EngineError) -> Self {
         match error {
@@ -135,7 +195,7 @@ impl From<EngineError> for ApiErrorResponse {
                     format!("Configuration file not found: {}", path),
                 ),
             },
-            EngineError::ConfigParseError { path, message } => ApiErrorResponse {
+            EngineError::ConfigParseError { path, message, .. } => ApiErrorResponse {
                 status: StatusCode::INTERNAL_SERVER_ERROR,
                 error: ApiError::with_details(
                     "CONFIG_ERROR",
@@ -143,6 +203,18 @@ impl From<EngineError> for ApiErrorResponse {
                     format!("Failed to parse {}: {}", path, message),
                 ),
             },
+            EngineError::InvalidConfig {
+                path,
+                field,
+                message,
+            } => ApiErrorResponse {
+                status: StatusCode::INTERNAL_SERVER_ERROR,
+                error: ApiError::with_details(
+                    "CONFIG_ERROR",
+                    "Invalid configuration",
+                    format!("{}: {} {}", path, field, message),
+                ),
+            },
             EngineError::ClassificationNotFound { code } => ApiErrorResponse {
                 status: StatusCode::BAD_REQUEST,
                 error: ApiError::classification_not_found(&code),
@@ -161,6 +233,20 @@ impl From<EngineError> for ApiErrorResponse {
                     "The requested classification does not have a rate for the specified date",
                 ),
             },
+            EngineError::NoOperativeRate {
+                classification,
+                date,
+            } => ApiErrorResponse {
+                status: StatusCode::BAD_REQUEST,
+                error: ApiError::with_details(
+                    "NO_OPERATIVE_RATE",
+                    format!(
+                        "No rate operative for classification '{}' on {}",
+                        classification, date
+                    ),
+                    "The classification's earliest rate takes effect after this date",
+                ),
+            },
             EngineError::InvalidShift { shift_id, message } => ApiErrorResponse {
                 status: StatusCode::BAD_REQUEST,
                 error: ApiError::with_details(
@@ -169,6 +255,37 @@ impl From<EngineError> for ApiErrorResponse {
                     "The shift data contains invalid information",
                 ),
             },
+            error @ EngineError::DuplicateShiftIds { .. } => ApiErrorResponse {
+                status: StatusCode::BAD_REQUEST,
+                error: ApiError::with_details(
+                    "DUPLICATE_SHIFT_ID",
+                    error.to_string(),
+                    "Each shift must have a unique id, as pay lines are tagged by shift_id",
+                ),
+            },
+            EngineError::InvalidBreak {
+                shift_id,
+                break_index,
+                message,
+            } => ApiErrorResponse {
+                status: StatusCode::BAD_REQUEST,
+                error: ApiError::with_details(
+                    "INVALID_BREAK",
+                    format!(
+                        "Invalid break {} in shift '{}': {}",
+                        break_index, shift_id, message
+                    ),
+                    "The break data contains invalid information",
+                ),
+            },
+            error @ EngineError::NoShifts { .. } => ApiErrorResponse {
+                status: StatusCode::BAD_REQUEST,
+                error: ApiError::with_details(
+                    "NO_SHIFTS",
+                    error.to_string(),
+                    "The request sets require_shifts, so shifts must not be empty",
+                ),
+            },
             EngineError::InvalidEmployee { field, message } => ApiErrorResponse {
                 status: StatusCode::BAD_REQUEST,
                 error: ApiError::with_details(
@@ -177,14 +294,46 @@ impl From<EngineError> for ApiErrorResponse {
                     "The employee data contains invalid information",
                 ),
             },
-            EngineError::CalculationError { message } => ApiErrorResponse {
-                status: StatusCode::INTERNAL_SERVER_ERROR,
+            EngineError::InvalidBaseRate { employee_id, rate } => ApiErrorResponse {
+                status: StatusCode::BAD_REQUEST,
                 error: ApiError::with_details(
-                    "CALCULATION_ERROR",
-                    "Calculation failed",
-                    message,
+                    "INVALID_BASE_RATE",
+                    format!(
+                        "Invalid base hourly rate for employee '{}': {} must not be negative",
+                        employee_id, rate
+                    ),
+                    "Omit base_hourly_rate to use the classification rate",
                 ),
             },
+            EngineError::CalculationError { message } => ApiErrorResponse {
+                status: StatusCode::INTERNAL_SERVER_ERROR,
+                error: ApiError::with_details("CALCULATION_ERROR", "Calculation failed", message),
+            },
+        }
+    }
+}
+
+/// Response for the POST /validate endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationResponse {
+    /// Whether the request passed all validations.
+    pub valid: bool,
+    /// The validation problems found (omitted when valid).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub problems: Vec<ApiError>,
+}
+
+impl ValidationResponse {
+    /// Creates a ValidationResponse from the validation errors found.
+    pub fn from_errors(errors: Vec<EngineError>) -> Self {
+        let problems: Vec<ApiError> = errors
+            .into_iter()
+            .map(|err| ApiErrorResponse::from(err).error)
+            .collect();
+
+        Self {
+            valid: problems.is_empty(),
+            problems,
         }
     }
 }
@@ -236,6 +385,62 @@ impl InfoResponse {
     }
 }
 
+/// A rule in the engine's rule catalogue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleSummary {
+    /// The rule ID audit steps record (e.g. "saturday_penalty").
+    pub rule_id: String,
+    /// The human-readable name of the rule.
+    pub rule_name: String,
+    /// The award clause the rule implements.
+    pub clause_ref: String,
+}
+
+/// Response for the GET /rules endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesResponse {
+    /// Every rule the engine can apply, in the order a calculation applies them.
+    pub rules: Vec<RuleSummary>,
+}
+
+impl RulesResponse {
+    /// Creates a RulesResponse from the engine's rule catalogue.
+    pub fn from_catalogue() -> Self {
+        let rules = crate::calculation::rule_catalogue()
+            .into_iter()
+            .map(|rule| RuleSummary {
+                rule_id: rule.rule_id.to_string(),
+                rule_name: rule.rule_name.to_string(),
+                clause_ref: rule.clause_ref.to_string(),
+            })
+            .collect();
+        Self { rules }
+    }
+}
+
+/// Response for the POST /selftest endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfTestResponse {
+    /// The code of the award configuration that was checked.
+    pub award_code: String,
+    /// Whether every check passed.
+    pub passed: bool,
+    /// Each check, with the problems it found.
+    pub checks: Vec<SelfTestCheck>,
+}
+
+impl SelfTestResponse {
+    /// Runs the self-test against the loaded configuration.
+    pub fn from_config(config: &crate::config::ConfigLoader) -> Self {
+        let report = config.self_test();
+        Self {
+            award_code: config.award().code.clone(),
+            passed: report.passed,
+            checks: report.checks,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,6 +478,46 @@ mod tests {
         assert_eq!(api_error.error.code, "CLASSIFICATION_NOT_FOUND");
     }
 
+    #[test]
+    fn test_no_operative_rate_is_a_bad_request() {
+        let engine_error = EngineError::NoOperativeRate {
+            classification: "dce_level_4".to_string(),
+            date: chrono::NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
+        };
+        let api_error: ApiErrorResponse = engine_error.into();
+        assert_eq!(api_error.status, StatusCode::BAD_REQUEST);
+        assert_eq!(api_error.error.code, "NO_OPERATIVE_RATE");
+    }
+
+    #[test]
+    fn test_no_shifts_is_a_bad_request() {
+        let engine_error = EngineError::NoShifts {
+            employee_id: "emp_001".to_string(),
+        };
+        let api_error: ApiErrorResponse = engine_error.into();
+        assert_eq!(api_error.status, StatusCode::BAD_REQUEST);
+        assert_eq!(api_error.error.code, "NO_SHIFTS");
+    }
+
+    #[test]
+    fn test_validation_response_valid_omits_problems() {
+        let response = ValidationResponse::from_errors(vec![]);
+        assert!(response.valid);
+        let json = serde_json::to_string(&response).unwrap();
+        assert_eq!(json, "{\"valid\":true}");
+    }
+
+    #[test]
+    fn test_validation_response_lists_problems() {
+        let response = ValidationResponse::from_errors(vec![EngineError::InvalidShift {
+            shift_id: "s1".to_string(),
+            message: "end_time must be after start_time".to_string(),
+        }]);
+        assert!(!response.valid);
+        assert_eq!(response.problems.len(), 1);
+        assert_eq!(response.problems[0].code, "INVALID_SHIFT");
+    }
+
     #[test]
     fn test_health_response_healthy() {
         let response = HealthResponse::healthy();
@@ -3,9 +3,19 @@
 //! This module defines the shared application state that is available
 //! to all request handlers.
 
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
+use tracing::warn;
+
 use crate::config::ConfigLoader;
+use crate::error::EngineResult;
+
+use super::clock::{Clock, SystemClock};
+
+/// The default largest number of shifts accepted in one request.
+pub const DEFAULT_MAX_SHIFTS_PER_REQUEST: usize = 400;
 
 /// Shared application state.
 ///
@@ -15,6 +25,17 @@ use crate::config::ConfigLoader;
 pub struct AppState {
     /// The loaded award configuration.
     config: Arc<ConfigLoader>,
+    /// Further award configurations, keyed by award code (e.g. a new award
+    /// version loaded alongside the current one).
+    awards: HashMap<String, Arc<ConfigLoader>>,
+    /// Overrides the cargo package version reported in calculation results.
+    engine_version: Option<String>,
+    /// Optional build metadata reported in calculation results.
+    build_metadata: Option<String>,
+    /// The largest number of shifts accepted in one request.
+    max_shifts_per_request: usize,
+    /// The clock results are timestamped and timed with.
+    clock: Arc<dyn Clock>,
 }
 
 impl AppState {
@@ -22,18 +43,120 @@ impl AppState {
     pub fn new(config: ConfigLoader) -> Self {
         Self {
             config: Arc::new(config),
+            awards: HashMap::new(),
+            engine_version: None,
+            build_metadata: None,
+            max_shifts_per_request: DEFAULT_MAX_SHIFTS_PER_REQUEST,
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Creates application state from a configuration directory.
+    ///
+    /// When the directory does not exist, the MA000018 configuration embedded
+    /// in the crate is used instead. A directory that exists but cannot be
+    /// loaded is still an error, naming the file at fault (and the line and
+    /// column for malformed YAML) so a server can report it and exit cleanly.
+    pub fn from_config_path<P: AsRef<Path>>(path: P) -> EngineResult<Self> {
+        let path = path.as_ref();
+        let config = if path.exists() {
+            ConfigLoader::load(path)?
+        } else {
+            warn!(
+                path = %path.display(),
+                "Config directory not found, using embedded MA000018 configuration"
+            );
+            ConfigLoader::load_embedded()?
+        };
+        Ok(Self::new(config))
+    }
+
+    /// Registers a further award configuration under the given award code.
+    ///
+    /// The primary configuration is always available under its own award
+    /// code; registered configurations can be compared against it with
+    /// `POST /calculate/diff`.
+    pub fn with_award(mut self, code: impl Into<String>, config: ConfigLoader) -> Self {
+        self.awards.insert(code.into(), Arc::new(config));
+        self
+    }
+
+    /// Sets an engine version to report instead of the cargo package version.
+    pub fn with_engine_version(mut self, engine_version: impl Into<String>) -> Self {
+        self.engine_version = Some(engine_version.into());
+        self
+    }
+
+    /// Sets build metadata (e.g. a commit hash or deployment tag) to report
+    /// in calculation results.
+    pub fn with_build_metadata(mut self, build_metadata: impl Into<String>) -> Self {
+        self.build_metadata = Some(build_metadata.into());
+        self
+    }
+
+    /// Sets the largest number of shifts accepted in one request (defaults to
+    /// [`DEFAULT_MAX_SHIFTS_PER_REQUEST`]).
+    ///
+    /// For `POST /calculate/upload` the limit applies to the shifts of all
+    /// employees on the roster together.
+    pub fn with_max_shifts_per_request(mut self, max_shifts: usize) -> Self {
+        self.max_shifts_per_request = max_shifts;
+        self
+    }
+
+    /// Sets the clock results are timestamped and timed with (defaults to
+    /// [`SystemClock`]), e.g. a [`FixedClock`](super::FixedClock) for
+    /// repeatable results in tests.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
     /// Returns a reference to the configuration loader.
     pub fn config(&self) -> &ConfigLoader {
         &self.config
     }
+
+    /// Returns the configuration for an award code.
+    ///
+    /// The primary configuration is found by the code in its award metadata,
+    /// other configurations by the code they were registered under.
+    pub fn award(&self, code: &str) -> Option<&ConfigLoader> {
+        if self.config.award().code == code {
+            return Some(&self.config);
+        }
+        self.awards.get(code).map(Arc::as_ref)
+    }
+
+    /// Returns the engine version reported in calculation results.
+    ///
+    /// This is the configured override if set, otherwise the cargo package version.
+    pub fn engine_version(&self) -> &str {
+        self.engine_version
+            .as_deref()
+            .unwrap_or(env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Returns the build metadata reported in calculation results, if set.
+    pub fn build_metadata(&self) -> Option<&str> {
+        self.build_metadata.as_deref()
+    }
+
+    /// Returns the largest number of shifts accepted in one request.
+    pub fn max_shifts_per_request(&self) -> usize {
+        self.max_shifts_per_request
+    }
+
+    /// Returns the clock results are timestamped and timed with.
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::EngineError;
 
     #[test]
     fn test_app_state_is_clone() {
@@ -41,4 +164,63 @@ mod tests {
         fn assert_clone<T: Clone>() {}
         assert_clone::<AppState>();
     }
+
+    fn create_config() -> ConfigLoader {
+        ConfigLoader::load("./config/ma000018").expect("Failed to load config")
+    }
+
+    #[test]
+    fn test_engine_version_defaults_to_cargo_version() {
+        let state = AppState::new(create_config());
+        assert_eq!(state.engine_version(), env!("CARGO_PKG_VERSION"));
+        assert!(state.build_metadata().is_none());
+    }
+
+    #[test]
+    fn test_engine_version_and_build_metadata_overrides() {
+        let state = AppState::new(create_config())
+            .with_engine_version("2026.10.1")
+            .with_build_metadata("git:4f36973");
+        assert_eq!(state.engine_version(), "2026.10.1");
+        assert_eq!(state.build_metadata(), Some("git:4f36973"));
+    }
+
+    #[test]
+    fn test_award_finds_primary_and_registered_configs() {
+        let state = AppState::new(create_config()).with_award("MA000018-2026", create_config());
+
+        assert!(state.award("MA000018").is_some());
+        assert!(state.award("MA000018-2026").is_some());
+        assert!(state.award("MA000100").is_none());
+    }
+
+    #[test]
+    fn test_from_config_path_loads_existing_directory() {
+        let state = AppState::from_config_path("./config/ma000018").unwrap();
+        assert_eq!(state.config().award().code, "MA000018");
+    }
+
+    #[test]
+    fn test_from_config_path_falls_back_to_embedded_config() {
+        let state = AppState::from_config_path("/nonexistent/config/ma000018").unwrap();
+        assert_eq!(state.config().award().code, "MA000018");
+    }
+
+    #[test]
+    fn test_from_config_path_reports_broken_directory() {
+        // The directory exists but is not an award configuration
+        assert!(AppState::from_config_path("./src").is_err());
+    }
+
+    #[test]
+    fn test_from_config_path_reports_malformed_yaml() {
+        let result = AppState::from_config_path("./tests/fixtures/malformed_config");
+
+        let err = result.err().expect("malformed config should not load");
+        assert!(matches!(
+            err,
+            EngineError::ConfigParseError { line: Some(6), .. }
+        ));
+        assert!(err.to_string().contains("award.yaml"));
+    }
 }
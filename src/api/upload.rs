@@ -0,0 +1,524 @@
+//! CSV roster uploads.
+//!
+//! This module backs `POST /calculate/upload`, which takes a whole roster as
+//! CSV (one shift per row) and calculates pay for each employee on it. Rows
+//! are read one at a time, and a malformed row is reported with its line
+//! number rather than failing the upload. A client that accepts
+//! `application/x-ndjson` has the results streamed as JSON Lines as each
+//! employee is calculated.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use chrono::{Duration, NaiveDate, NaiveTime};
+use csv::StringRecord;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{CalculationResult, EmploymentType, PayPeriodType};
+
+use super::request::{
+    CalculationRequest, EmployeeRequest, PayPeriodRequest, RequestValidationError, ShiftRequest,
+};
+use super::response::ApiError;
+
+/// The columns every roster CSV must have.
+pub const ROSTER_COLUMNS: &[&str] = &[
+    "employee_id",
+    "classification",
+    "employment_type",
+    "date",
+    "start",
+    "end",
+];
+
+/// Query parameters for `POST /calculate/upload`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RosterUploadQuery {
+    /// The start date of the pay period (inclusive).
+    pub start_date: NaiveDate,
+    /// The end date of the pay period (inclusive).
+    pub end_date: NaiveDate,
+    /// The length of the pay period; inferred from the dates when omitted.
+    #[serde(default)]
+    pub period_type: Option<PayPeriodType>,
+    /// Whether to return the audit trace with each result (defaults to true).
+    #[serde(default = "default_include_audit")]
+    pub include_audit: bool,
+}
+
+fn default_include_audit() -> bool {
+    true
+}
+
+/// One shift row of a roster CSV.
+#[derive(Debug, Deserialize)]
+struct RosterRow {
+    employee_id: String,
+    classification: String,
+    employment_type: EmploymentType,
+    date: NaiveDate,
+    start: String,
+    end: String,
+    #[serde(default)]
+    shift_id: Option<String>,
+    #[serde(default)]
+    award_code: Option<String>,
+}
+
+/// A roster row that could not be read.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RosterRowError {
+    /// The line number of the row in the CSV (the header is line 1).
+    pub line: u64,
+    /// What was wrong with the row.
+    pub message: String,
+}
+
+/// An employee on the roster whose pay could not be calculated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmployeeUploadError {
+    /// The employee's ID.
+    pub employee_id: String,
+    /// The error the calculation failed with.
+    pub error: ApiError,
+}
+
+/// The response for `POST /calculate/upload`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterUploadResponse {
+    /// The result for each employee, in the order they first appear in the CSV.
+    pub results: Vec<CalculationResult>,
+    /// The employees whose pay could not be calculated.
+    pub employee_errors: Vec<EmployeeUploadError>,
+    /// The rows that could not be read; these shifts are left out.
+    pub row_errors: Vec<RosterRowError>,
+}
+
+/// The media type of a streamed roster upload response.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// One line of a streamed roster upload response.
+///
+/// Each line is a JSON object whose `type` field names the variant and whose
+/// other fields are those of the wrapped value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RosterUploadLine {
+    /// A row that could not be read.
+    RowError(RosterRowError),
+    /// An employee's calculated pay.
+    Result(Box<CalculationResult>),
+    /// An employee whose pay could not be calculated.
+    EmployeeError(EmployeeUploadError),
+    /// The counts for the whole upload; always the last line.
+    Summary(RosterUploadSummary),
+}
+
+/// The counts reported on the last line of a streamed roster upload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RosterUploadSummary {
+    /// The number of employees whose pay was calculated.
+    pub results_count: usize,
+    /// The number of employees whose pay could not be calculated.
+    pub employee_errors_count: usize,
+    /// The number of rows that could not be read.
+    pub row_errors_count: usize,
+}
+
+/// A roster CSV parsed into a calculation request per employee.
+#[derive(Debug, Clone)]
+pub struct RosterUpload {
+    /// A calculation request for each employee, in the order they first
+    /// appear in the CSV.
+    pub requests: Vec<CalculationRequest>,
+    /// The rows that could not be read.
+    pub row_errors: Vec<RosterRowError>,
+}
+
+/// Reads a roster CSV and groups its shifts into a request per employee.
+///
+/// Each row is one shift. `start` and `end` are times on `date` (`HH:MM` or
+/// `HH:MM:SS`); an end at or before the start finishes the next day. A row
+/// naming a different classification or employment type from the employee's
+/// first row is reported as a row error. An optional `shift_id` column names
+/// the shift; otherwise it is named after its line (e.g. `line_2`). An
+/// optional `award_code` column calculates the employee under that award
+/// instead of the primary one, so a mixed workforce can share one upload; it
+/// must be the same on each of the employee's rows.
+///
+/// The CSV carries no date of birth or employment start date. They do not
+/// affect the calculation, so both are set to the pay period start date.
+///
+/// Fails only when the header cannot be read or lacks a required column.
+pub fn parse_roster_csv(
+    reader: impl Read,
+    query: &RosterUploadQuery,
+) -> Result<RosterUpload, RequestValidationError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+    let headers = reader
+        .headers()
+        .map_err(|err| RequestValidationError::InvalidValue {
+            message: format!("could not read the CSV header: {}", err),
+        })?
+        .clone();
+    if let Some(column) = ROSTER_COLUMNS
+        .iter()
+        .find(|column| !headers.iter().any(|header| header == **column))
+    {
+        return Err(RequestValidationError::MissingField {
+            field: column.to_string(),
+        });
+    }
+
+    let mut requests: Vec<CalculationRequest> = Vec::new();
+    let mut by_employee: HashMap<String, usize> = HashMap::new();
+    let mut row_errors = Vec::new();
+    // Rows are read one at a time into a reused record, so the whole file is
+    // never held as parsed rows
+    let mut record = StringRecord::new();
+    loop {
+        match reader.read_record(&mut record) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(err) if err.is_io_error() => {
+                return Err(RequestValidationError::InvalidValue {
+                    message: format!("could not read the CSV: {}", err),
+                });
+            }
+            Err(err) => {
+                row_errors.push(RosterRowError {
+                    line: err.position().map_or(0, |position| position.line()),
+                    message: err.to_string(),
+                });
+                continue;
+            }
+        }
+        let line = record.position().map_or(0, |position| position.line());
+        let row: RosterRow = match record.deserialize(Some(&headers)) {
+            Ok(row) => row,
+            Err(err) => {
+                row_errors.push(RosterRowError {
+                    line,
+                    message: err.to_string(),
+                });
+                continue;
+            }
+        };
+
+        let shift = match shift_from_row(&row, line) {
+            Ok(shift) => shift,
+            Err(message) => {
+                row_errors.push(RosterRowError { line, message });
+                continue;
+            }
+        };
+
+        match by_employee.get(&row.employee_id) {
+            Some(&index) => {
+                let employee = &requests[index].employee;
+                if employee.classification_code != row.classification
+                    || employee.employment_type != row.employment_type
+                {
+                    row_errors.push(RosterRowError {
+                        line,
+                        message: format!(
+                            "employee '{}' is listed with classification '{}' and employment type '{}' on an earlier row",
+                            row.employee_id,
+                            employee.classification_code,
+                            employee.employment_type.as_str()
+                        ),
+                    });
+                    continue;
+                }
+                if requests[index].award_code != row.award_code {
+                    row_errors.push(RosterRowError {
+                        line,
+                        message: format!(
+                            "employee '{}' is listed under a different award on an earlier row",
+                            row.employee_id
+                        ),
+                    });
+                    continue;
+                }
+                requests[index].shifts.push(shift);
+            }
+            None => {
+                by_employee.insert(row.employee_id.clone(), requests.len());
+                requests.push(employee_request(row, shift, query));
+            }
+        }
+    }
+
+    Ok(RosterUpload {
+        requests,
+        row_errors,
+    })
+}
+
+/// Returns the lines of a streamed response for a parsed roster.
+///
+/// The row errors come first, then a result or employee error for each
+/// employee in the order they first appear in the CSV, then the summary.
+/// Each employee is calculated with `calculate` only when its line is
+/// reached, so results can be sent as they are calculated rather than held
+/// until the whole roster is done.
+pub(super) fn roster_upload_lines(
+    upload: RosterUpload,
+    mut calculate: impl FnMut(CalculationRequest) -> Result<CalculationResult, EmployeeUploadError>,
+) -> impl Iterator<Item = RosterUploadLine> {
+    let mut summary = Some(RosterUploadSummary {
+        results_count: 0,
+        employee_errors_count: 0,
+        row_errors_count: upload.row_errors.len(),
+    });
+    let mut row_errors = upload.row_errors.into_iter();
+    let mut requests = upload.requests.into_iter();
+
+    std::iter::from_fn(move || {
+        if let Some(row_error) = row_errors.next() {
+            return Some(RosterUploadLine::RowError(row_error));
+        }
+        let Some(request) = requests.next() else {
+            return summary.take().map(RosterUploadLine::Summary);
+        };
+        let counts = summary.as_mut()?;
+        Some(match calculate(request) {
+            Ok(result) => {
+                counts.results_count += 1;
+                RosterUploadLine::Result(Box::new(result))
+            }
+            Err(error) => {
+                counts.employee_errors_count += 1;
+                RosterUploadLine::EmployeeError(error)
+            }
+        })
+    })
+}
+
+/// Builds a shift from a row, finishing it the next day when it ends at or
+/// before its start time.
+fn shift_from_row(row: &RosterRow, line: u64) -> Result<ShiftRequest, String> {
+    let start = parse_time(&row.start).ok_or_else(|| invalid_time("start", &row.start))?;
+    let end = parse_time(&row.end).ok_or_else(|| invalid_time("end", &row.end))?;
+    let start_time = row.date.and_time(start);
+    let mut end_time = row.date.and_time(end);
+    if end_time <= start_time {
+        end_time += Duration::days(1);
+    }
+
+    Ok(ShiftRequest {
+        id: row
+            .shift_id
+            .clone()
+            .filter(|id| !id.is_empty())
+            .unwrap_or_else(|| format!("line_{}", line)),
+        date: row.date,
+        start_time,
+        end_time: Some(end_time),
+        duration_minutes: None,
+        expected_hours: None,
+        breaks: vec![],
+        rate_override: None,
+        classification_code: None,
+        reference: None,
+        cost_centre: None,
+    })
+}
+
+/// Parses a time of day written as `HH:MM` or `HH:MM:SS`.
+fn parse_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+        .ok()
+}
+
+fn invalid_time(column: &str, value: &str) -> String {
+    format!("{} '{}' is not a time (expected HH:MM)", column, value)
+}
+
+/// Starts a calculation request for an employee from their first row.
+fn employee_request(
+    row: RosterRow,
+    shift: ShiftRequest,
+    query: &RosterUploadQuery,
+) -> CalculationRequest {
+    CalculationRequest {
+        employee: EmployeeRequest {
+            id: row.employee_id,
+            employment_type: row.employment_type,
+            classification_code: row.classification,
+            date_of_birth: query.start_date,
+            employment_start_date: query.start_date,
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags: vec![],
+        },
+        pay_period: PayPeriodRequest {
+            start_date: query.start_date,
+            end_date: query.end_date,
+            public_holidays: vec![],
+            period_type: query.period_type,
+        },
+        shifts: vec![shift],
+        timezone: None,
+        state: None,
+        award_code: row.award_code,
+        include_audit: query.include_audit,
+        deterministic_id: false,
+        partial_results: false,
+        require_shifts: false,
+        amounts_in_cents: false,
+        diagnostics: false,
+        echo_request: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query() -> RosterUploadQuery {
+        RosterUploadQuery {
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 12).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 1, 18).unwrap(),
+            period_type: None,
+            include_audit: true,
+        }
+    }
+
+    #[test]
+    fn test_rows_are_grouped_by_employee() {
+        let csv = "\
+employee_id,classification,employment_type,date,start,end
+emp_001,dce_level_3,full_time,2026-01-13,09:00,17:00
+emp_002,dce_level_3,casual,2026-01-13,22:00,06:00
+emp_001,dce_level_3,full_time,2026-01-14,09:00:00,13:00:00
+";
+
+        let upload = parse_roster_csv(csv.as_bytes(), &query()).unwrap();
+
+        assert!(upload.row_errors.is_empty());
+        assert_eq!(upload.requests.len(), 2);
+        assert_eq!(upload.requests[0].employee.id, "emp_001");
+        assert_eq!(upload.requests[0].shifts.len(), 2);
+        assert_eq!(upload.requests[0].shifts[0].id, "line_2");
+        assert_eq!(upload.requests[0].shifts[1].id, "line_4");
+        // The overnight shift finishes the next morning
+        let overnight = &upload.requests[1].shifts[0];
+        assert_eq!(
+            overnight.end_time,
+            NaiveDate::from_ymd_opt(2026, 1, 14)
+                .unwrap()
+                .and_hms_opt(6, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_malformed_rows_are_reported_with_line_numbers() {
+        let csv = "\
+employee_id,classification,employment_type,date,start,end
+emp_001,dce_level_3,full_time,2026-01-13,09:00,17:00
+emp_001,dce_level_3,salaried,2026-01-14,09:00,17:00
+emp_001,dce_level_3,full_time,2026-01-15,9am,17:00
+emp_001,dce_level_3,casual,2026-01-16,09:00,17:00
+emp_001,dce_level_3,full_time,2026-01-17,09:00,17:00
+";
+
+        let upload = parse_roster_csv(csv.as_bytes(), &query()).unwrap();
+
+        let lines: Vec<u64> = upload.row_errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![3, 4, 5]);
+        assert!(
+            upload.row_errors[1]
+                .message
+                .contains("start '9am' is not a time")
+        );
+        assert!(upload.row_errors[2].message.contains("on an earlier row"));
+        assert_eq!(upload.requests.len(), 1);
+        assert_eq!(upload.requests[0].shifts.len(), 2);
+    }
+
+    #[test]
+    fn test_award_code_column_is_per_employee() {
+        let csv = "\
+employee_id,classification,employment_type,date,start,end,award_code
+emp_001,dce_level_3,full_time,2026-01-13,09:00,17:00,
+emp_002,dce_level_3,casual,2026-01-13,09:00,17:00,MA000100
+emp_002,dce_level_3,casual,2026-01-14,09:00,17:00,MA000018
+emp_002,dce_level_3,casual,2026-01-15,09:00,17:00,MA000100
+";
+
+        let upload = parse_roster_csv(csv.as_bytes(), &query()).unwrap();
+
+        assert_eq!(upload.requests[0].award_code, None);
+        assert_eq!(upload.requests[1].award_code.as_deref(), Some("MA000100"));
+        assert_eq!(upload.requests[1].shifts.len(), 2);
+        assert_eq!(upload.row_errors.len(), 1);
+        assert_eq!(upload.row_errors[0].line, 4);
+        assert!(
+            upload.row_errors[0]
+                .message
+                .contains("under a different award")
+        );
+    }
+
+    #[test]
+    fn test_streamed_lines_end_with_the_summary() {
+        let csv = "\
+employee_id,classification,employment_type,date,start,end
+emp_001,dce_level_3,full_time,2026-01-13,09:00,17:00
+emp_002,dce_level_3,casual,2026-01-13,9am,17:00
+emp_003,dce_level_3,casual,2026-01-13,09:00,17:00
+";
+        let upload = parse_roster_csv(csv.as_bytes(), &query()).unwrap();
+
+        let mut calculated = Vec::new();
+        let lines: Vec<RosterUploadLine> = roster_upload_lines(upload, |request| {
+            calculated.push(request.employee.id.clone());
+            Err(EmployeeUploadError {
+                employee_id: request.employee.id,
+                error: ApiError::malformed_json("not calculated"),
+            })
+        })
+        .collect();
+
+        assert_eq!(calculated, vec!["emp_001", "emp_003"]);
+        assert_eq!(lines.len(), 4);
+        assert!(matches!(&lines[0], RosterUploadLine::RowError(e) if e.line == 3));
+        assert!(
+            matches!(&lines[1], RosterUploadLine::EmployeeError(e) if e.employee_id == "emp_001")
+        );
+        assert!(
+            matches!(&lines[2], RosterUploadLine::EmployeeError(e) if e.employee_id == "emp_003")
+        );
+        let RosterUploadLine::Summary(summary) = lines[3] else {
+            panic!("expected the summary last, got {:?}", lines[3]);
+        };
+        assert_eq!(
+            summary,
+            RosterUploadSummary {
+                results_count: 0,
+                employee_errors_count: 2,
+                row_errors_count: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_missing_column_fails_the_upload() {
+        let csv = "employee_id,classification,employment_type,date,start\n";
+
+        let err = parse_roster_csv(csv.as_bytes(), &query()).unwrap_err();
+
+        assert_eq!(
+            err,
+            RequestValidationError::MissingField {
+                field: "end".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,505 @@
+//! Input validation for calculation requests.
+//!
+//! These checks are shared by the `/calculate` and `/validate` endpoints so
+//! that both apply exactly the same rules to roster data.
+
+use std::collections::HashSet;
+
+use chrono::TimeDelta;
+use rust_decimal::Decimal;
+
+use crate::config::ConfigLoader;
+use crate::error::EngineError;
+use crate::models::{Employee, PayPeriod, Shift};
+
+/// Runs all input validations and returns every problem found.
+///
+/// The checks are:
+/// - the employee's classification exists in the award configuration
+/// - the employee's base hourly rate override, if any, is not negative
+/// - no two shifts share an ID
+/// - each shift ends after it starts
+/// - each shift's rate override, if any, is positive
+/// - each shift's classification, if any, exists in the award configuration
+/// - each shift date falls within the pay period
+/// - each break lies within its shift and no two breaks in a shift overlap
+/// - each shift's unpaid breaks leave some time worked
+/// - no two shifts overlap
+///
+/// An empty vector means the request is valid.
+pub(crate) fn validate_request(
+    employee: &Employee,
+    pay_period: &PayPeriod,
+    shifts: &[Shift],
+    config: &ConfigLoader,
+) -> Vec<EngineError> {
+    let mut problems = Vec::new();
+
+    if let Err(err) = config.get_classification(&employee.classification_code) {
+        problems.push(err);
+    }
+
+    if let Some(rate) = employee.base_hourly_rate
+        && rate < Decimal::ZERO
+    {
+        problems.push(EngineError::InvalidBaseRate {
+            employee_id: employee.id.clone(),
+            rate,
+        });
+    }
+
+    problems.extend(find_duplicate_shift_ids(shifts));
+
+    for shift in shifts {
+        if shift.end_time <= shift.start_time {
+            problems.push(EngineError::InvalidShift {
+                shift_id: shift.id.clone(),
+                message: "end_time must be after start_time".to_string(),
+            });
+        }
+
+        if let Some(rate) = shift.rate_override
+            && rate <= Decimal::ZERO
+        {
+            problems.push(EngineError::InvalidShift {
+                shift_id: shift.id.clone(),
+                message: format!("rate_override must be greater than zero (found {})", rate),
+            });
+        }
+
+        if let Some(code) = &shift.classification_code
+            && let Err(err) = config.get_classification(code)
+        {
+            problems.push(err);
+        }
+
+        if !pay_period.contains_date(shift.date) {
+            problems.push(EngineError::InvalidShift {
+                shift_id: shift.id.clone(),
+                message: format!(
+                    "shift date {} is outside the pay period {} to {}",
+                    shift.date, pay_period.start_date, pay_period.end_date
+                ),
+            });
+        }
+    }
+
+    for shift in shifts {
+        let break_problems = find_invalid_breaks(shift);
+        // Overlapping or out-of-bounds breaks would miscount the total
+        if break_problems.is_empty() {
+            problems.extend(find_excessive_break_time(shift));
+        } else {
+            problems.extend(break_problems);
+        }
+    }
+
+    problems.extend(find_overlapping_shifts(shifts));
+
+    problems
+}
+
+/// Finds shift IDs used by more than one shift, reported together as a
+/// single problem.
+fn find_duplicate_shift_ids(shifts: &[Shift]) -> Option<EngineError> {
+    let mut seen = HashSet::new();
+    let mut duplicates: Vec<String> = Vec::new();
+    for shift in shifts {
+        if !seen.insert(shift.id.as_str()) && !duplicates.contains(&shift.id) {
+            duplicates.push(shift.id.clone());
+        }
+    }
+
+    (!duplicates.is_empty()).then_some(EngineError::DuplicateShiftIds {
+        shift_ids: duplicates,
+    })
+}
+
+/// Finds breaks that fall outside their shift or overlap another break.
+fn find_invalid_breaks(shift: &Shift) -> Vec<EngineError> {
+    let mut problems = Vec::new();
+
+    for (index, brk) in shift.breaks.iter().enumerate() {
+        let invalid = |message: String| EngineError::InvalidBreak {
+            shift_id: shift.id.clone(),
+            break_index: index,
+            message,
+        };
+
+        if brk.end_time <= brk.start_time {
+            problems.push(invalid("end_time must be after start_time".to_string()));
+            continue;
+        }
+        if brk.start_time < shift.start_time || brk.end_time > shift.end_time {
+            problems.push(invalid(format!(
+                "break {} to {} is outside the shift {} to {}",
+                brk.start_time, brk.end_time, shift.start_time, shift.end_time
+            )));
+        }
+        if let Some(other) = shift.breaks[..index].iter().position(|earlier| {
+            earlier.end_time > earlier.start_time
+                && brk.start_time < earlier.end_time
+                && earlier.start_time < brk.end_time
+        }) {
+            problems.push(invalid(format!("overlaps break {}", other)));
+        }
+    }
+
+    problems
+}
+
+/// Finds a shift whose unpaid breaks take up its whole span, leaving no time
+/// worked.
+///
+/// The problem is reported against the break that brings the unpaid total to
+/// the shift's length. Shifts with invalid times are skipped; they are
+/// reported separately.
+fn find_excessive_break_time(shift: &Shift) -> Option<EngineError> {
+    let span = shift.end_time - shift.start_time;
+    if span <= TimeDelta::zero() {
+        return None;
+    }
+
+    let mut unpaid = TimeDelta::zero();
+    for (index, brk) in shift.breaks.iter().enumerate() {
+        if brk.is_paid {
+            continue;
+        }
+        unpaid += brk.end_time - brk.start_time;
+        if unpaid >= span {
+            return Some(EngineError::InvalidBreak {
+                shift_id: shift.id.clone(),
+                break_index: index,
+                message: format!(
+                    "unpaid breaks total {} minutes, leaving no time worked in the {} minute shift",
+                    unpaid.num_minutes(),
+                    span.num_minutes()
+                ),
+            });
+        }
+    }
+
+    None
+}
+
+/// Finds shifts whose worked time overlaps an earlier shift.
+///
+/// Shifts with invalid times are skipped; they are reported separately.
+fn find_overlapping_shifts(shifts: &[Shift]) -> Vec<EngineError> {
+    let mut ordered: Vec<&Shift> = shifts
+        .iter()
+        .filter(|s| s.end_time > s.start_time)
+        .collect();
+    ordered.sort_by_key(|s| s.start_time);
+
+    let mut problems = Vec::new();
+    let mut latest: Option<&Shift> = None;
+    for shift in ordered {
+        if let Some(previous) = latest {
+            if shift.start_time < previous.end_time {
+                problems.push(EngineError::InvalidShift {
+                    shift_id: shift.id.clone(),
+                    message: format!("overlaps shift '{}'", previous.id),
+                });
+            }
+            if shift.end_time <= previous.end_time {
+                continue;
+            }
+        }
+        latest = Some(shift);
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Break, EmploymentType};
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    fn config() -> ConfigLoader {
+        ConfigLoader::load("./config/ma000018").unwrap()
+    }
+
+    fn employee(classification: &str) -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type: EmploymentType::FullTime,
+            classification_code: classification.to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn pay_period() -> PayPeriod {
+        PayPeriod {
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 12).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 1, 18).unwrap(),
+            public_holidays: vec![],
+            period_type: None,
+        }
+    }
+
+    fn shift(id: &str, start: &str, end: &str) -> Shift {
+        let start_time = NaiveDateTime::parse_from_str(start, "%Y-%m-%dT%H:%M:%S").unwrap();
+        Shift {
+            id: id.to_string(),
+            date: start_time.date(),
+            start_time,
+            end_time: NaiveDateTime::parse_from_str(end, "%Y-%m-%dT%H:%M:%S").unwrap(),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_valid_request_has_no_problems() {
+        let shifts = vec![
+            shift("s1", "2026-01-13T09:00:00", "2026-01-13T17:00:00"),
+            shift("s2", "2026-01-13T17:00:00", "2026-01-13T21:00:00"),
+        ];
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &shifts, &config());
+
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_shift_ids_are_reported_once() {
+        let shifts = vec![
+            shift("s1", "2026-01-13T09:00:00", "2026-01-13T17:00:00"),
+            shift("s1", "2026-01-14T09:00:00", "2026-01-14T17:00:00"),
+            shift("s2", "2026-01-15T09:00:00", "2026-01-15T17:00:00"),
+            shift("s1", "2026-01-16T09:00:00", "2026-01-16T17:00:00"),
+            shift("s2", "2026-01-17T09:00:00", "2026-01-17T17:00:00"),
+        ];
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &shifts, &config());
+
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(
+            &problems[0],
+            EngineError::DuplicateShiftIds { shift_ids } if shift_ids == &["s1", "s2"]
+        ));
+    }
+
+    #[test]
+    fn test_non_positive_shift_rate_override_is_reported() {
+        let mut zero = shift("s1", "2026-01-13T09:00:00", "2026-01-13T17:00:00");
+        zero.rate_override = Some(Decimal::ZERO);
+        let mut positive = shift("s2", "2026-01-14T09:00:00", "2026-01-14T17:00:00");
+        positive.rate_override = Some(Decimal::new(3200, 2));
+
+        let problems = validate_request(
+            &employee("dce_level_3"),
+            &pay_period(),
+            &[zero, positive],
+            &config(),
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(
+            problems[0].to_string(),
+            "Invalid shift 's1': rate_override must be greater than zero (found 0)"
+        );
+    }
+
+    #[test]
+    fn test_negative_base_rate_override_is_reported() {
+        let mut negative = employee("dce_level_3");
+        negative.base_hourly_rate = Some(Decimal::new(-3000, 2));
+        let mut zero = employee("dce_level_3");
+        zero.base_hourly_rate = Some(Decimal::ZERO);
+
+        let problems = validate_request(&negative, &pay_period(), &[], &config());
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(problems[0], EngineError::InvalidBaseRate { .. }));
+        assert!(validate_request(&zero, &pay_period(), &[], &config()).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_classification_is_reported() {
+        let problems = validate_request(&employee("unknown"), &pay_period(), &[], &config());
+
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(
+            problems[0],
+            EngineError::ClassificationNotFound { .. }
+        ));
+    }
+
+    #[test]
+    fn test_unknown_shift_classification_is_reported() {
+        let mut other_role = shift("s1", "2026-01-13T09:00:00", "2026-01-13T17:00:00");
+        other_role.classification_code = Some("unknown".to_string());
+
+        let problems = validate_request(
+            &employee("dce_level_3"),
+            &pay_period(),
+            &[other_role],
+            &config(),
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(
+            &problems[0],
+            EngineError::ClassificationNotFound { code } if code == "unknown"
+        ));
+    }
+
+    #[test]
+    fn test_shift_ending_before_start_is_reported() {
+        let shifts = vec![shift("s1", "2026-01-13T17:00:00", "2026-01-13T09:00:00")];
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &shifts, &config());
+
+        assert_eq!(problems.len(), 1);
+        assert!(
+            problems[0]
+                .to_string()
+                .contains("end_time must be after start_time")
+        );
+    }
+
+    #[test]
+    fn test_shift_outside_pay_period_is_reported() {
+        let shifts = vec![shift("s1", "2026-01-20T09:00:00", "2026-01-20T17:00:00")];
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &shifts, &config());
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].to_string().contains("outside the pay period"));
+    }
+
+    #[test]
+    fn test_overlapping_shifts_are_reported() {
+        let shifts = vec![
+            shift("s1", "2026-01-13T09:00:00", "2026-01-13T17:00:00"),
+            shift("s2", "2026-01-13T16:00:00", "2026-01-13T20:00:00"),
+        ];
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &shifts, &config());
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].to_string().contains("overlaps shift 's1'"));
+    }
+
+    #[test]
+    fn test_all_problems_are_collected() {
+        let shifts = vec![
+            shift("s1", "2026-01-13T17:00:00", "2026-01-13T09:00:00"),
+            shift("s2", "2026-01-25T09:00:00", "2026-01-25T17:00:00"),
+        ];
+
+        let problems = validate_request(&employee("unknown"), &pay_period(), &shifts, &config());
+
+        assert_eq!(problems.len(), 3);
+    }
+
+    fn with_break(mut shift: Shift, start: &str, end: &str) -> Shift {
+        shift.breaks.push(Break {
+            start_time: NaiveDateTime::parse_from_str(start, "%Y-%m-%dT%H:%M:%S").unwrap(),
+            end_time: NaiveDateTime::parse_from_str(end, "%Y-%m-%dT%H:%M:%S").unwrap(),
+            is_paid: false,
+            kind: None,
+        });
+        shift
+    }
+
+    #[test]
+    fn test_break_starting_before_shift_is_reported() {
+        let shift = shift("s1", "2026-01-13T09:00:00", "2026-01-13T17:00:00");
+        let shifts = vec![with_break(
+            shift,
+            "2026-01-13T08:30:00",
+            "2026-01-13T09:30:00",
+        )];
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &shifts, &config());
+
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(
+            problems[0],
+            EngineError::InvalidBreak { ref shift_id, break_index: 0, .. } if shift_id == "s1"
+        ));
+        assert!(problems[0].to_string().contains("outside the shift"));
+    }
+
+    #[test]
+    fn test_overlapping_breaks_are_reported() {
+        let shift = shift("s1", "2026-01-13T09:00:00", "2026-01-13T17:00:00");
+        let shift = with_break(shift, "2026-01-13T12:00:00", "2026-01-13T12:30:00");
+        let shifts = vec![with_break(
+            shift,
+            "2026-01-13T12:15:00",
+            "2026-01-13T12:45:00",
+        )];
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &shifts, &config());
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(
+            problems[0].to_string(),
+            "Invalid break 1 in shift 's1': overlaps break 0"
+        );
+    }
+
+    #[test]
+    fn test_break_as_long_as_the_shift_is_reported() {
+        let shift = shift("s1", "2026-01-13T09:00:00", "2026-01-13T11:00:00");
+        let shift = with_break(shift, "2026-01-13T09:00:00", "2026-01-13T11:00:00");
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &[shift], &config());
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(
+            problems[0].to_string(),
+            "Invalid break 0 in shift 's1': unpaid breaks total 120 minutes, leaving no time worked in the 120 minute shift"
+        );
+    }
+
+    #[test]
+    fn test_paid_breaks_do_not_count_towards_break_time() {
+        let mut shift = shift("s1", "2026-01-13T09:00:00", "2026-01-13T11:00:00");
+        shift = with_break(shift, "2026-01-13T09:00:00", "2026-01-13T11:00:00");
+        shift.breaks[0].is_paid = true;
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &[shift], &config());
+
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_multiple_valid_breaks_are_accepted() {
+        let shift = shift("s1", "2026-01-13T09:00:00", "2026-01-13T17:00:00");
+        let shift = with_break(shift, "2026-01-13T10:30:00", "2026-01-13T10:45:00");
+        let shifts = vec![with_break(
+            shift,
+            "2026-01-13T12:00:00",
+            "2026-01-13T12:30:00",
+        )];
+
+        let problems =
+            validate_request(&employee("dce_level_3"), &pay_period(), &shifts, &config());
+
+        assert!(problems.is_empty());
+    }
+}
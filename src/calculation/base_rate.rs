@@ -1,22 +1,50 @@
 //! Base rate lookup functionality.
 //!
 //! This module provides functions for determining an employee's base hourly rate,
-//! either from their employee override or from the award configuration.
+//! either from a shift or employee override or from the award configuration.
 
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 
 use crate::config::AwardConfig;
 use crate::error::{EngineError, EngineResult};
-use crate::models::{AuditStep, Employee};
+use crate::models::{AuditStep, AuditWarning, Employee, Shift};
+
+use super::casual_loading::casual_loaded_rate;
+use super::rules::RuleInfo;
+
+/// The base rate lookup rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "base_rate_lookup",
+    rule_name: "Base Rate Lookup",
+    clause_ref: "14.2",
+}];
+
+/// The warning code for an override rate far from the classification rate.
+pub const OVERRIDE_RATE_DEVIATION_WARNING: &str = "OVERRIDE_RATE_DEVIATION";
 
 /// The result of a base rate lookup, including the rate and audit step.
 #[derive(Debug, Clone)]
 pub struct BaseRateLookupResult {
     /// The determined base hourly rate.
     pub rate: Decimal,
-    /// The audit step recording this lookup.
-    pub audit_step: AuditStep,
+    /// The audit step recording this lookup, when the audit trace is wanted.
+    pub audit_step: Option<AuditStep>,
+    /// A warning when an override rate differs from the classification rate
+    /// by more than the configured tolerance.
+    pub warning: Option<AuditWarning>,
+}
+
+impl BaseRateLookupResult {
+    /// Returns the rate the employee is paid for ordinary hours: the base rate
+    /// with the 25% casual loading (clause 10.4(b)) for a casual employee, or
+    /// the base rate unchanged otherwise.
+    ///
+    /// This is the same rate [`apply_casual_loading`](super::apply_casual_loading)
+    /// produces, without its audit step.
+    pub fn loaded_rate(&self, employee: &Employee) -> Decimal {
+        casual_loaded_rate(self.rate, employee)
+    }
 }
 
 /// Determines an employee's base hourly rate.
@@ -25,16 +53,24 @@ pub struct BaseRateLookupResult {
 /// 1. If `employee.base_hourly_rate` is `Some`, use that override value
 /// 2. Otherwise, look up the rate from the config by classification code and effective date
 ///
+/// When an override is used, the audit step records the classification rate
+/// it replaced, and a warning is returned if the two differ by more than the
+/// configured `override_rate_warning_percent`.
+///
 /// # Arguments
 ///
 /// * `employee` - The employee to look up the rate for
 /// * `effective_date` - The date for which to find the applicable rate
 /// * `config` - The award configuration containing classification rates
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
 ///
 /// # Returns
 ///
 /// Returns a `BaseRateLookupResult` containing the rate and an audit step, or an error if:
 /// - The classification code is not found in the config (`ClassificationNotFound`)
+/// - The classification's earliest rate takes effect after the effective date
+///   (`NoOperativeRate`)
 /// - No rate exists for the classification on the effective date (`RateNotFound`)
 ///
 /// # Award Reference
@@ -53,35 +89,263 @@ pub fn get_base_rate(
     effective_date: NaiveDate,
     config: &AwardConfig,
     step_number: u32,
+    include_audit: bool,
 ) -> EngineResult<BaseRateLookupResult> {
     // Check if employee has an override rate
     if let Some(override_rate) = employee.base_hourly_rate {
-        let audit_step = AuditStep {
+        return Ok(override_base_rate(
+            employee,
+            RateOverride::Employee(override_rate),
+            effective_date,
+            config,
+            step_number,
+            include_audit,
+        ));
+    }
+
+    let (rate, rate_effective_date) = classification_rate(employee, effective_date, config)?;
+    let audit_step = include_audit.then(|| AuditStep {
+        step_number,
+        rule_id: "base_rate_lookup".to_string(),
+        rule_name: "Base Rate Lookup".to_string(),
+        clause_ref: "14.2".to_string(),
+        input: serde_json::json!({
+            "classification_code": employee.classification_code,
+            "effective_date": effective_date.to_string()
+        }),
+        output: serde_json::json!({
+            "rate": rate.to_string(),
+            "source": "config",
+            "rate_effective_date": rate_effective_date.to_string()
+        }),
+        reasoning: format!(
+            "Looked up rate for classification '{}' effective {}: ${}",
+            employee.classification_code, rate_effective_date, rate
+        ),
+    });
+
+    Ok(BaseRateLookupResult {
+        rate,
+        audit_step,
+        warning: None,
+    })
+}
+
+/// Determines the base hourly rate a shift is paid at.
+///
+/// A shift's `rate_override` takes precedence over both the employee override
+/// and the classification rate; otherwise this is [`get_base_rate`] for the
+/// shift's date. As with an employee override, the audit step records the
+/// classification rate the shift override replaced and a warning is returned
+/// when the two differ by more than the configured tolerance.
+///
+/// A shift worked under its own `classification_code` is looked up under that
+/// classification rather than the employee's, without the employee's
+/// override (see [`Employee::for_shift`]).
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::get_shift_base_rate;
+/// use award_engine::config::ConfigLoader;
+/// use award_engine::models::{Employee, EmploymentType, Shift};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let config = ConfigLoader::load("./config/ma000018").unwrap();
+/// let employee = Employee {
+///     id: "emp_001".to_string(),
+///     employment_type: EmploymentType::FullTime,
+///     classification_code: "dce_level_3".to_string(),
+///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 1).unwrap(),
+///     employment_start_date: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
+///     tags: vec![],
+/// };
+/// let date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
+/// let shift = Shift {
+///     id: "shift_001".to_string(),
+///     date,
+///     start_time: date.and_hms_opt(9, 0, 0).unwrap(),
+///     end_time: date.and_hms_opt(17, 0, 0).unwrap(),
+///     breaks: vec![],
+///     rate_override: Some(Decimal::new(3200, 2)),
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
+/// };
+///
+/// let result = get_shift_base_rate(&shift, &employee, config.config(), 1, true).unwrap();
+/// assert_eq!(result.rate, Decimal::new(3200, 2));
+/// assert_eq!(result.audit_step.unwrap().output["source"], "shift_override");
+/// ```
+pub fn get_shift_base_rate(
+    shift: &Shift,
+    employee: &Employee,
+    config: &AwardConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> EngineResult<BaseRateLookupResult> {
+    let employee = employee.for_shift(shift);
+    match shift.rate_override {
+        Some(override_rate) => Ok(override_base_rate(
+            &employee,
+            RateOverride::Shift(&shift.id, override_rate),
+            shift.date,
+            config,
+            step_number,
+            include_audit,
+        )),
+        None => get_base_rate(&employee, shift.date, config, step_number, include_audit),
+    }
+}
+
+/// Marks an ordinary hours or penalty audit step as paid at a shift's
+/// override rate, recording the rate in its input.
+pub(crate) fn flag_shift_rate_override(audit_step: &mut AuditStep, override_rate: Decimal) {
+    audit_step.input["shift_rate_override"] =
+        serde_json::Value::String(override_rate.normalize().to_string());
+    audit_step.reasoning = format!(
+        "{} (shift rate override ${} applied)",
+        audit_step.reasoning,
+        override_rate.normalize()
+    );
+}
+
+/// Where an override rate came from.
+#[derive(Clone, Copy)]
+enum RateOverride<'a> {
+    /// The employee's base hourly rate override.
+    Employee(Decimal),
+    /// A shift's rate override, with the shift ID.
+    Shift(&'a str, Decimal),
+}
+
+impl RateOverride<'_> {
+    fn rate(self) -> Decimal {
+        match self {
+            RateOverride::Employee(rate) | RateOverride::Shift(_, rate) => rate,
+        }
+    }
+
+    /// Describes the override in reasoning and warning messages.
+    fn label(self) -> String {
+        match self {
+            RateOverride::Employee(_) => "Employee override rate".to_string(),
+            RateOverride::Shift(shift_id, _) => format!("Shift '{}' override rate", shift_id),
+        }
+    }
+
+    /// Names the record a suspicious override rate should be checked in.
+    fn record(self) -> &'static str {
+        match self {
+            RateOverride::Employee(_) => "the employee record",
+            RateOverride::Shift(..) => "the shift",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            RateOverride::Employee(_) => "employee_override",
+            RateOverride::Shift(..) => "shift_override",
+        }
+    }
+}
+
+/// Builds the lookup result for an employee or shift override rate.
+///
+/// The classification rate is still looked up so the audit step can show what
+/// the override replaced; when it cannot be found the override is used alone.
+fn override_base_rate(
+    employee: &Employee,
+    rate_override: RateOverride,
+    effective_date: NaiveDate,
+    config: &AwardConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> BaseRateLookupResult {
+    let override_rate = rate_override.rate();
+    let classification = classification_rate(employee, effective_date, config)
+        .ok()
+        .map(|(rate, _)| rate);
+    // Deviation from the classification rate, as a percentage of it
+    let deviation = classification
+        .filter(|rate| !rate.is_zero())
+        .map(|rate| ((override_rate - rate) / rate * Decimal::ONE_HUNDRED).round_dp(2));
+
+    let audit_step = include_audit.then(|| {
+        let reasoning = match (classification, deviation) {
+            (Some(rate), Some(deviation)) => format!(
+                "{} ${} applied instead of the classification rate ${} for '{}' ({}% difference)",
+                rate_override.label(),
+                override_rate,
+                rate,
+                employee.classification_code,
+                deviation.normalize()
+            ),
+            _ => format!(
+                "{} ${} applied; no classification rate found for '{}' on {}",
+                rate_override.label(),
+                override_rate,
+                employee.classification_code,
+                effective_date
+            ),
+        };
+
+        AuditStep {
             step_number,
             rule_id: "base_rate_lookup".to_string(),
             rule_name: "Base Rate Lookup".to_string(),
             clause_ref: "14.2".to_string(),
             input: serde_json::json!({
                 "classification_code": employee.classification_code,
-                "employee_override_rate": override_rate.to_string(),
+                format!("{}_rate", rate_override.source()): override_rate.to_string(),
+                "classification_rate": classification.map(|rate| rate.to_string()),
                 "effective_date": effective_date.to_string()
             }),
             output: serde_json::json!({
                 "rate": override_rate.to_string(),
-                "source": "employee_override"
+                "source": rate_override.source(),
+                "deviation_percent": deviation.map(|d| d.normalize().to_string())
             }),
-            reasoning: format!(
-                "Using employee override rate ${} instead of classification lookup",
-                override_rate
+            reasoning,
+        }
+    });
+
+    let threshold = config.penalties().override_rate_warning_percent;
+    let warning = deviation
+        .filter(|deviation| deviation.abs() > threshold)
+        .map(|deviation| AuditWarning {
+            code: OVERRIDE_RATE_DEVIATION_WARNING.to_string(),
+            message: format!(
+                "{} ${} differs from the classification rate ${} by {}%, more than the {}% tolerance; check {}",
+                rate_override.label(),
+                override_rate,
+                classification.unwrap_or_default(),
+                deviation.normalize(),
+                threshold.normalize(),
+                rate_override.record()
             ),
-        };
-
-        return Ok(BaseRateLookupResult {
-            rate: override_rate,
-            audit_step,
+            severity: "medium".to_string(),
         });
+
+    BaseRateLookupResult {
+        rate: override_rate,
+        audit_step,
+        warning,
     }
+}
 
+/// Looks up the classification rate effective on the given date, returning
+/// the rate and the date the rate configuration took effect.
+fn classification_rate(
+    employee: &Employee,
+    effective_date: NaiveDate,
+    config: &AwardConfig,
+) -> EngineResult<(Decimal, NaiveDate)> {
     // Check if classification exists in config
     if !config
         .classifications()
@@ -95,61 +359,47 @@ pub fn get_base_rate(
     // Find the applicable rate for the effective date
     // Rates are sorted by effective_date ascending, so we find the most recent
     // rate that is on or before the effective_date (searching from the end)
-    let applicable_rate = config
+    config
         .rates()
         .iter()
-        .rfind(|r| r.effective_date <= effective_date);
-
-    match applicable_rate {
-        Some(rate_config) => {
-            // Check if the classification has a rate in this rate config
-            match rate_config.rates.get(&employee.classification_code) {
-                Some(classification_rate) => {
-                    let rate = classification_rate.hourly;
-                    let audit_step = AuditStep {
-                        step_number,
-                        rule_id: "base_rate_lookup".to_string(),
-                        rule_name: "Base Rate Lookup".to_string(),
-                        clause_ref: "14.2".to_string(),
-                        input: serde_json::json!({
-                            "classification_code": employee.classification_code,
-                            "effective_date": effective_date.to_string()
-                        }),
-                        output: serde_json::json!({
-                            "rate": rate.to_string(),
-                            "source": "config",
-                            "rate_effective_date": rate_config.effective_date.to_string()
-                        }),
-                        reasoning: format!(
-                            "Looked up rate for classification '{}' effective {}: ${}",
-                            employee.classification_code, rate_config.effective_date, rate
-                        ),
-                    };
-
-                    Ok(BaseRateLookupResult { rate, audit_step })
-                }
-                None => Err(EngineError::RateNotFound {
-                    classification: employee.classification_code.clone(),
+        .rfind(|r| r.effective_date <= effective_date)
+        .and_then(|rate_config| {
+            rate_config
+                .rates
+                .get(&employee.classification_code)
+                .map(|rate| (rate.hourly, rate_config.effective_date))
+        })
+        .ok_or_else(|| {
+            let classification = employee.classification_code.clone();
+            let earliest_rate = config
+                .rates()
+                .iter()
+                .filter(|r| r.rates.contains_key(&classification))
+                .map(|r| r.effective_date)
+                .min();
+            match earliest_rate {
+                Some(starts) if starts > effective_date => EngineError::NoOperativeRate {
+                    classification,
+                    date: effective_date,
+                },
+                _ => EngineError::RateNotFound {
+                    classification,
                     date: effective_date,
-                }),
+                },
             }
-        }
-        None => Err(EngineError::RateNotFound {
-            classification: employee.classification_code.clone(),
-            date: effective_date,
-        }),
-    }
+        })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::{
-        AllowanceRates, AwardMetadata, Classification, ClassificationRate, OvertimeConfig,
-        OvertimeRates, OvertimeSection, Penalties, PenaltyConfig, PenaltyRates, RateConfig,
-        WeekendOvertimeConfig,
+        AllowanceRates, AwardMetadata, CasualOvertimeBase, CasualPenaltyComposition,
+        Classification, ClassificationRate, OvertimeConfig, OvertimeRates, OvertimeSection,
+        OvertimeStacking, Penalties, PenaltyConfig, PenaltyRates, RateConfig, ShiftSeconds,
+        TimeRounding, WeekdayOvertimeTier, WeekendDays, WeekendOvertimeConfig,
     };
-    use crate::models::EmploymentType;
+    use crate::models::{EmploymentType, PayCategory};
     use std::collections::HashMap;
     use std::str::FromStr;
 
@@ -190,6 +440,10 @@ mod tests {
             allowances: AllowanceRates {
                 laundry_per_shift: dec("0.32"),
                 laundry_per_week: dec("1.49"),
+                qualification: None,
+                broken_shift: None,
+                split_shift_travel: None,
+                first_aid_per_week: None,
             },
         }];
 
@@ -207,24 +461,41 @@ mod tests {
                     part_time: dec("2.0"),
                     casual: dec("2.25"),
                 },
+                classifications: HashMap::new(),
+                casual_composition: CasualPenaltyComposition::Additive,
             },
             overtime: OvertimeSection {
                 daily_threshold_hours: 8,
+                daily_thresholds: HashMap::new(),
+                casual_ot_base: CasualOvertimeBase::Loaded,
                 weekday: OvertimeConfig {
                     clause: "25.1".to_string(),
-                    first_two_hours: OvertimeRates {
-                        full_time: dec("1.5"),
-                        part_time: dec("1.5"),
-                        casual: dec("1.75"),
-                    },
-                    after_two_hours: OvertimeRates {
-                        full_time: dec("2.0"),
-                        part_time: dec("2.0"),
-                        casual: dec("2.25"),
-                    },
+                    tiers: vec![
+                        WeekdayOvertimeTier {
+                            threshold_hours: Decimal::ZERO,
+                            category: PayCategory::Overtime150,
+                            rates: OvertimeRates {
+                                full_time: dec("1.5"),
+                                part_time: dec("1.5"),
+                                casual: dec("1.75"),
+                            },
+                        },
+                        WeekdayOvertimeTier {
+                            threshold_hours: dec("2"),
+                            category: PayCategory::Overtime200,
+                            rates: OvertimeRates {
+                                full_time: dec("2.0"),
+                                part_time: dec("2.0"),
+                                casual: dec("2.25"),
+                            },
+                        },
+                    ],
                 },
                 weekend: WeekendOvertimeConfig {
                     clause: "25.1(a)(i)(B)".to_string(),
+                    tiered: false,
+                    tiers: vec![],
+                    overtime_stacking: OvertimeStacking::Replace,
                     saturday: OvertimeRates {
                         full_time: dec("2.0"),
                         part_time: dec("2.0"),
@@ -236,7 +507,24 @@ mod tests {
                         casual: dec("2.5"),
                     },
                 },
+                public_holiday: None,
             },
+            time_rounding: TimeRounding::None,
+            shift_seconds: ShiftSeconds::NearestMinute,
+            weekend_days: WeekendDays::default(),
+            week_start_day: chrono::Weekday::Mon,
+            laundry_min_shift_hours: Decimal::ZERO,
+            public_holiday_not_worked: None,
+            consecutive_days: None,
+            meal_break: None,
+            leave_accrual: None,
+            override_rate_warning_percent: dec("25"),
+            span_of_hours: None,
+            early_commencement: None,
+            minimum_daily_pay: None,
+            max_expected_gross: None,
+            rounding_reconciliation: false,
+            max_audit_steps: None,
         };
 
         AwardConfig::new(metadata, classifications, rates, penalties)
@@ -250,6 +538,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: override_rate,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -261,23 +552,43 @@ mod tests {
         let employee = create_test_employee("dce_level_3", None);
         let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
 
-        let result = get_base_rate(&employee, effective_date, &config, 1).unwrap();
+        let result = get_base_rate(&employee, effective_date, &config, 1, true).unwrap();
 
         assert_eq!(result.rate, dec("28.54"));
-        assert_eq!(result.audit_step.rule_id, "base_rate_lookup");
-        assert_eq!(result.audit_step.clause_ref, "14.2");
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.rule_id, "base_rate_lookup");
+        assert_eq!(step.clause_ref, "14.2");
         assert!(
-            result.audit_step.input["classification_code"]
+            step.input["classification_code"]
                 .as_str()
                 .unwrap()
                 .contains("dce_level_3")
         );
-        assert!(
-            result.audit_step.output["rate"]
-                .as_str()
-                .unwrap()
-                .contains("28.54")
-        );
+        assert!(step.output["rate"].as_str().unwrap().contains("28.54"));
+    }
+
+    #[test]
+    fn test_loaded_rate_equals_base_rate_for_full_time() {
+        let config = create_test_config();
+        let employee = create_test_employee("dce_level_3", None);
+        let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+
+        let result = get_base_rate(&employee, effective_date, &config, 1, true).unwrap();
+
+        assert_eq!(result.loaded_rate(&employee), dec("28.54"));
+    }
+
+    #[test]
+    fn test_loaded_rate_includes_casual_loading() {
+        let config = create_test_config();
+        let mut employee = create_test_employee("dce_level_3", None);
+        employee.employment_type = EmploymentType::Casual;
+        let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+
+        let result = get_base_rate(&employee, effective_date, &config, 1, true).unwrap();
+
+        assert_eq!(result.rate, dec("28.54"));
+        assert_eq!(result.loaded_rate(&employee), dec("35.675"));
     }
 
     /// BR-002: override rate takes precedence
@@ -287,19 +598,115 @@ mod tests {
         let employee = create_test_employee("dce_level_3", Some(dec("32.00")));
         let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
 
-        let result = get_base_rate(&employee, effective_date, &config, 1).unwrap();
+        let result = get_base_rate(&employee, effective_date, &config, 1, true).unwrap();
 
         assert_eq!(result.rate, dec("32.00"));
-        assert_eq!(result.audit_step.rule_id, "base_rate_lookup");
-        assert_eq!(result.audit_step.clause_ref, "14.2");
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.rule_id, "base_rate_lookup");
+        assert_eq!(step.clause_ref, "14.2");
         assert!(
-            result.audit_step.output["source"]
+            step.output["source"]
                 .as_str()
                 .unwrap()
                 .contains("employee_override")
         );
     }
 
+    #[test]
+    fn test_override_audit_records_classification_rate() {
+        let config = create_test_config();
+        let employee = create_test_employee("dce_level_3", Some(dec("30.00")));
+        let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+
+        let result = get_base_rate(&employee, effective_date, &config, 1, true).unwrap();
+
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.input["classification_rate"], "28.54");
+        assert_eq!(step.output["deviation_percent"], "5.12");
+        assert_eq!(
+            step.reasoning,
+            "Employee override rate $30.00 applied instead of the classification rate $28.54 for 'dce_level_3' (5.12% difference)"
+        );
+        // Within the 25% tolerance
+        assert!(result.warning.is_none());
+    }
+
+    #[test]
+    fn test_override_far_from_classification_rate_warns() {
+        let config = create_test_config();
+        let employee = create_test_employee("dce_level_3", Some(dec("285.40")));
+        let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+
+        let result = get_base_rate(&employee, effective_date, &config, 1, true).unwrap();
+
+        assert_eq!(result.rate, dec("285.40"));
+        let warning = result.warning.expect("deviation should be flagged");
+        assert_eq!(warning.code, OVERRIDE_RATE_DEVIATION_WARNING);
+        assert!(warning.message.contains("by 900%"));
+    }
+
+    #[test]
+    fn test_override_without_classification_rate_is_still_used() {
+        let config = create_test_config();
+        let employee = create_test_employee("unknown", Some(dec("32.00")));
+        let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+
+        let result = get_base_rate(&employee, effective_date, &config, 1, true).unwrap();
+
+        assert_eq!(result.rate, dec("32.00"));
+        let step = result.audit_step.unwrap();
+        assert!(step.input["classification_rate"].is_null());
+        assert!(result.warning.is_none());
+    }
+
+    fn create_test_shift(rate_override: Option<Decimal>) -> Shift {
+        let date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+        Shift {
+            id: "shift_001".to_string(),
+            date,
+            start_time: date.and_hms_opt(9, 0, 0).unwrap(),
+            end_time: date.and_hms_opt(17, 0, 0).unwrap(),
+            breaks: vec![],
+            rate_override,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_shift_override_takes_precedence_over_employee_override() {
+        let config = create_test_config();
+        let employee = create_test_employee("dce_level_3", Some(dec("30.00")));
+        let shift = create_test_shift(Some(dec("40.00")));
+
+        let result = get_shift_base_rate(&shift, &employee, &config, 1, true).unwrap();
+
+        assert_eq!(result.rate, dec("40.00"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.output["source"], "shift_override");
+        assert_eq!(step.input["shift_override_rate"], "40.00");
+        assert_eq!(
+            step.reasoning,
+            "Shift 'shift_001' override rate $40.00 applied instead of the classification rate $28.54 for 'dce_level_3' (40.15% difference)"
+        );
+        let warning = result.warning.expect("deviation should be flagged");
+        assert!(warning.message.ends_with("check the shift"));
+    }
+
+    #[test]
+    fn test_shift_without_override_uses_base_rate() {
+        let config = create_test_config();
+        let employee = create_test_employee("dce_level_3", Some(dec("30.00")));
+        let shift = create_test_shift(None);
+
+        let result = get_shift_base_rate(&shift, &employee, &config, 1, true).unwrap();
+
+        assert_eq!(result.rate, dec("30.00"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.output["source"], "employee_override");
+    }
+
     /// BR-003: unknown classification returns error
     #[test]
     fn test_unknown_classification_returns_error() {
@@ -307,7 +714,7 @@ mod tests {
         let employee = create_test_employee("unknown", None);
         let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
 
-        let result = get_base_rate(&employee, effective_date, &config, 1);
+        let result = get_base_rate(&employee, effective_date, &config, 1, true);
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -325,30 +732,126 @@ mod tests {
         let employee = create_test_employee("dce_level_3", None);
         let effective_date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
 
-        let result = get_base_rate(&employee, effective_date, &config, 1);
+        let result = get_base_rate(&employee, effective_date, &config, 1, true);
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            EngineError::RateNotFound {
+            EngineError::NoOperativeRate {
                 classification,
                 date,
             } => {
                 assert_eq!(classification, "dce_level_3");
                 assert_eq!(date, NaiveDate::from_ymd_opt(2020, 1, 1).unwrap());
             }
-            other => panic!("Expected RateNotFound, got {:?}", other),
+            other => panic!("Expected NoOperativeRate, got {:?}", other),
         }
     }
 
+    #[test]
+    fn test_classification_whose_earliest_rate_starts_later_has_no_operative_rate() {
+        let base = create_test_config();
+        let mut classifications = base.classifications().clone();
+        classifications.insert(
+            "dce_level_4".to_string(),
+            Classification {
+                name: "Direct Care Employee Level 4".to_string(),
+                description: "Senior direct care worker".to_string(),
+                clause: "14.2".to_string(),
+            },
+        );
+        // dce_level_4 is first paid from the 2026-07-01 rates
+        let mut later = base.rates()[0].clone();
+        later.effective_date = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
+        later.rates.insert(
+            "dce_level_4".to_string(),
+            ClassificationRate {
+                weekly: dec("1120.80"),
+                hourly: dec("29.49"),
+            },
+        );
+        let mut rates = base.rates().to_vec();
+        rates.push(later);
+        let config = AwardConfig::new(
+            base.award().clone(),
+            classifications,
+            rates,
+            base.penalties().clone(),
+        );
+        let shift_date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
+
+        let result = get_base_rate(
+            &create_test_employee("dce_level_4", None),
+            shift_date,
+            &config,
+            1,
+            true,
+        );
+
+        match result.unwrap_err() {
+            EngineError::NoOperativeRate {
+                classification,
+                date,
+            } => {
+                assert_eq!(classification, "dce_level_4");
+                assert_eq!(date, shift_date);
+            }
+            other => panic!("Expected NoOperativeRate, got {:?}", other),
+        }
+        // Once its rate takes effect the classification is paid
+        let operative = get_base_rate(
+            &create_test_employee("dce_level_4", None),
+            NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+            &config,
+            1,
+            true,
+        )
+        .unwrap();
+        assert_eq!(operative.rate, dec("29.49"));
+    }
+
+    #[test]
+    fn test_classification_without_any_rate_is_rate_not_found() {
+        let base = create_test_config();
+        let mut classifications = base.classifications().clone();
+        classifications.insert(
+            "dce_level_4".to_string(),
+            Classification {
+                name: "Direct Care Employee Level 4".to_string(),
+                description: "Senior direct care worker".to_string(),
+                clause: "14.2".to_string(),
+            },
+        );
+        let config = AwardConfig::new(
+            base.award().clone(),
+            classifications,
+            base.rates().to_vec(),
+            base.penalties().clone(),
+        );
+
+        let result = get_base_rate(
+            &create_test_employee("dce_level_4", None),
+            NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
+            &config,
+            1,
+            true,
+        );
+
+        assert!(matches!(
+            result.unwrap_err(),
+            EngineError::RateNotFound { .. }
+        ));
+    }
+
     #[test]
     fn test_audit_step_has_correct_step_number() {
         let config = create_test_config();
         let employee = create_test_employee("dce_level_3", None);
         let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
 
-        let result = get_base_rate(&employee, effective_date, &config, 5).unwrap();
+        let result = get_base_rate(&employee, effective_date, &config, 5, true).unwrap();
 
-        assert_eq!(result.audit_step.step_number, 5);
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 5);
     }
 
     #[test]
@@ -357,8 +860,9 @@ mod tests {
         let employee = create_test_employee("dce_level_3", None);
         let effective_date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
 
-        let result = get_base_rate(&employee, effective_date, &config, 1).unwrap();
+        let result = get_base_rate(&employee, effective_date, &config, 1, true).unwrap();
 
-        assert!(result.audit_step.reasoning.contains("28.54"));
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("28.54"));
     }
 }
@@ -0,0 +1,238 @@
+//! Broken shift allowance calculation functionality.
+//!
+//! This module provides functions for calculating the broken shift allowance
+//! for employees who work a shift split by an unpaid gap, as per clause 22.9
+//! of the Aged Care Award 2010.
+
+use rust_decimal::Decimal;
+
+use crate::models::{AllowancePayment, AllowanceType, AuditStep, Employee, Shift};
+
+use super::rules::RuleInfo;
+
+/// The broken shift allowance rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "broken_shift_allowance",
+    rule_name: "Broken Shift Allowance",
+    clause_ref: BROKEN_SHIFT_ALLOWANCE_CLAUSE,
+}];
+
+/// The clause reference for the broken shift allowance.
+pub const BROKEN_SHIFT_ALLOWANCE_CLAUSE: &str = "22.9";
+
+/// The result of calculating the broken shift allowance, including the payment and audit step.
+#[derive(Debug, Clone)]
+pub struct BrokenShiftAllowanceResult {
+    /// The allowance payment, if any shift was a broken shift.
+    pub allowance: Option<AllowancePayment>,
+    /// The audit step recording this calculation, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Calculates the broken shift allowance for an employee's shifts.
+///
+/// The allowance is paid once for each broken shift, however many gaps the
+/// shift contains. A shift is broken when it has an unpaid break classified
+/// as a shift gap (see [`Shift::is_broken_shift`]).
+///
+/// # Arguments
+///
+/// * `employee` - The employee to calculate allowance for
+/// * `shifts` - The shifts worked in the pay period
+/// * `rate` - The configured allowance per broken shift
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Returns
+///
+/// Returns a `BrokenShiftAllowanceResult` containing:
+/// - `Some(AllowancePayment)` if at least one shift was a broken shift
+/// - `None` if no shift was a broken shift
+///
+/// # Award Reference
+///
+/// Clause 22.9 of the Aged Care Award 2010 provides for broken shifts.
+pub fn calculate_broken_shift_allowance(
+    employee: &Employee,
+    shifts: &[Shift],
+    rate: Decimal,
+    step_number: u32,
+    include_audit: bool,
+) -> BrokenShiftAllowanceResult {
+    let broken_shift_ids: Vec<&str> = shifts
+        .iter()
+        .filter(|s| s.is_broken_shift())
+        .map(|s| s.id.as_str())
+        .collect();
+
+    let input = || {
+        serde_json::json!({
+            "employee_id": employee.id,
+            "shift_count": shifts.len(),
+            "broken_shifts": broken_shift_ids,
+            "rate_per_broken_shift": rate.normalize().to_string()
+        })
+    };
+
+    if broken_shift_ids.is_empty() {
+        let audit_step = include_audit.then(|| AuditStep {
+            step_number,
+            rule_id: "broken_shift_allowance".to_string(),
+            rule_name: "Broken Shift Allowance".to_string(),
+            clause_ref: BROKEN_SHIFT_ALLOWANCE_CLAUSE.to_string(),
+            input: input(),
+            output: serde_json::json!({
+                "eligible": false,
+                "amount": "0.00"
+            }),
+            reasoning: "No broken shifts worked - not eligible for broken shift allowance"
+                .to_string(),
+        });
+
+        return BrokenShiftAllowanceResult {
+            allowance: None,
+            audit_step,
+        };
+    }
+
+    let units = Decimal::from(broken_shift_ids.len());
+    let amount = units * rate;
+
+    let audit_step = include_audit.then(|| AuditStep {
+        step_number,
+        rule_id: "broken_shift_allowance".to_string(),
+        rule_name: "Broken Shift Allowance".to_string(),
+        clause_ref: BROKEN_SHIFT_ALLOWANCE_CLAUSE.to_string(),
+        input: input(),
+        output: serde_json::json!({
+            "eligible": true,
+            "units": units.normalize().to_string(),
+            "amount": amount.normalize().to_string()
+        }),
+        reasoning: format!(
+            "{} broken shift(s) × ${} = ${}",
+            units,
+            rate.normalize(),
+            amount.normalize()
+        ),
+    });
+
+    let allowance = AllowancePayment {
+        allowance_type: AllowanceType::BrokenShift,
+        description: "Broken Shift Allowance".to_string(),
+        units,
+        rate,
+        amount,
+        clause_ref: BROKEN_SHIFT_ALLOWANCE_CLAUSE.to_string(),
+    };
+
+    BrokenShiftAllowanceResult {
+        allowance: Some(allowance),
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Break, BreakKind, EmploymentType};
+    use chrono::{NaiveDate, NaiveDateTime};
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn datetime(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S").unwrap()
+    }
+
+    fn create_test_employee() -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type: EmploymentType::PartTime,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags: vec![],
+        }
+    }
+
+    /// A 07:00-19:00 shift with the given unpaid breaks.
+    fn shift(id: &str, date: &str, breaks: &[(&str, &str, Option<BreakKind>)]) -> Shift {
+        Shift {
+            id: id.to_string(),
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            start_time: datetime(&format!("{}T07:00:00", date)),
+            end_time: datetime(&format!("{}T19:00:00", date)),
+            breaks: breaks
+                .iter()
+                .map(|(start, end, kind)| Break {
+                    start_time: datetime(&format!("{}T{}", date, start)),
+                    end_time: datetime(&format!("{}T{}", date, end)),
+                    is_paid: false,
+                    kind: *kind,
+                })
+                .collect(),
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_broken_shift_is_paid_once_per_shift() {
+        let shifts = vec![shift(
+            "s1",
+            "2026-01-13",
+            &[
+                ("10:00:00", "13:00:00", None),
+                ("15:00:00", "17:00:00", None),
+            ],
+        )];
+
+        let result = calculate_broken_shift_allowance(
+            &create_test_employee(),
+            &shifts,
+            dec("20.82"),
+            3,
+            true,
+        );
+
+        let allowance = result.allowance.unwrap();
+        assert_eq!(allowance.allowance_type, AllowanceType::BrokenShift);
+        assert_eq!(allowance.units, dec("1"));
+        assert_eq!(allowance.amount, dec("20.82"));
+        assert_eq!(allowance.clause_ref, "22.9");
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 3);
+        assert_eq!(step.input["broken_shifts"][0], "s1");
+    }
+
+    #[test]
+    fn test_meal_breaks_do_not_make_a_broken_shift() {
+        let shifts = vec![shift(
+            "s1",
+            "2026-01-13",
+            &[("12:00:00", "14:00:00", Some(BreakKind::MealBreak))],
+        )];
+
+        let result = calculate_broken_shift_allowance(
+            &create_test_employee(),
+            &shifts,
+            dec("20.82"),
+            1,
+            true,
+        );
+
+        assert!(result.allowance.is_none());
+        let step = result.audit_step.unwrap();
+        assert!(!step.output["eligible"].as_bool().unwrap());
+    }
+}
@@ -5,7 +5,25 @@
 
 use rust_decimal::Decimal;
 
-use crate::models::{AuditStep, Employee, EmploymentType};
+use crate::config::{CasualOvertimeBase, CasualPenaltyComposition};
+use crate::models::{AuditStep, Employee};
+
+use super::rules::RuleInfo;
+
+/// The casual loading and casual overtime composition rules, for the rule
+/// catalogue.
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        rule_id: "casual_loading",
+        rule_name: "Casual Loading",
+        clause_ref: "10.4(b)",
+    },
+    RuleInfo {
+        rule_id: "casual_overtime_composition",
+        rule_name: "Casual Overtime Composition",
+        clause_ref: "25.1",
+    },
+];
 
 /// Returns the casual loading multiplier as defined in clause 10.4(b).
 ///
@@ -14,13 +32,24 @@ pub fn casual_loading_multiplier() -> Decimal {
     Decimal::new(125, 2)
 }
 
+/// Returns `base_rate` with the casual loading applied for a casual employee,
+/// or unchanged for a permanent one.
+pub(crate) fn casual_loaded_rate(base_rate: Decimal, employee: &Employee) -> Decimal {
+    if employee.is_casual() {
+        base_rate * casual_loading_multiplier()
+    } else {
+        base_rate
+    }
+}
+
 /// The result of applying casual loading, including the rate and audit step.
 #[derive(Debug, Clone)]
 pub struct CasualLoadingResult {
     /// The rate after applying casual loading (if applicable).
     pub loaded_rate: Decimal,
-    /// The audit step recording this calculation.
-    pub audit_step: AuditStep,
+    /// The audit step recording this calculation, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
 }
 
 /// Applies casual loading to a base rate for casual employees.
@@ -34,6 +63,7 @@ pub struct CasualLoadingResult {
 /// * `base_rate` - The base hourly rate before any loading
 /// * `employee` - The employee to apply loading for
 /// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
 ///
 /// # Returns
 ///
@@ -59,28 +89,28 @@ pub struct CasualLoadingResult {
 ///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
 ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
 ///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
 ///     tags: vec![],
 /// };
 ///
-/// let result = apply_casual_loading(Decimal::from_str("28.54").unwrap(), &employee, 1);
+/// let result = apply_casual_loading(Decimal::from_str("28.54").unwrap(), &employee, 1, true);
 /// assert_eq!(result.loaded_rate, Decimal::from_str("35.675").unwrap());
 /// ```
 pub fn apply_casual_loading(
     base_rate: Decimal,
     employee: &Employee,
     step_number: u32,
+    include_audit: bool,
 ) -> CasualLoadingResult {
-    let employment_type_str = match employee.employment_type {
-        EmploymentType::FullTime => "full_time",
-        EmploymentType::PartTime => "part_time",
-        EmploymentType::Casual => "casual",
-    };
+    let employment_type_str = employee.employment_type.as_str();
 
     if employee.is_casual() {
-        let loaded_rate = base_rate * casual_loading_multiplier();
+        let loaded_rate = casual_loaded_rate(base_rate, employee);
         let multiplier = casual_loading_multiplier();
 
-        let audit_step = AuditStep {
+        let audit_step = include_audit.then(|| AuditStep {
             step_number,
             rule_id: "casual_loading".to_string(),
             rule_name: "Casual Loading".to_string(),
@@ -100,14 +130,14 @@ pub fn apply_casual_loading(
                 multiplier.normalize(),
                 loaded_rate.normalize()
             ),
-        };
+        });
 
         CasualLoadingResult {
             loaded_rate,
             audit_step,
         }
     } else {
-        let audit_step = AuditStep {
+        let audit_step = include_audit.then(|| AuditStep {
             step_number,
             rule_id: "casual_loading".to_string(),
             rule_name: "Casual Loading".to_string(),
@@ -124,7 +154,7 @@ pub fn apply_casual_loading(
                 "No casual loading applied - employee is {} (not casual)",
                 employment_type_str
             ),
-        };
+        });
 
         CasualLoadingResult {
             loaded_rate: base_rate,
@@ -133,9 +163,236 @@ pub fn apply_casual_loading(
     }
 }
 
+/// How a casual overtime multiplier is composed from its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CasualOvertimeComposition {
+    /// The configured casual multiplier, which includes the casual loading.
+    pub configured_multiplier: Decimal,
+    /// The overtime component of the configured multiplier, without loading.
+    pub overtime_multiplier: Decimal,
+    /// The casual loading applied on top of the overtime component (1 when excluded).
+    pub casual_loading: Decimal,
+    /// The multiplier paid on the base rate.
+    pub multiplier: Decimal,
+}
+
+/// Composes a casual overtime multiplier according to the configured base.
+///
+/// Configured casual overtime multipliers include the casual loading. Under
+/// `CasualOvertimeBase::Loaded` the configured multiplier is paid as is;
+/// under `CasualOvertimeBase::Base` the casual loading is removed so that
+/// overtime is paid on the base rate.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::compose_casual_overtime;
+/// use award_engine::config::CasualOvertimeBase;
+/// use rust_decimal::Decimal;
+///
+/// let configured = Decimal::new(1875, 3);
+///
+/// let loaded = compose_casual_overtime(configured, CasualOvertimeBase::Loaded);
+/// assert_eq!(loaded.multiplier, Decimal::new(1875, 3));
+///
+/// let base = compose_casual_overtime(configured, CasualOvertimeBase::Base);
+/// assert_eq!(base.multiplier, Decimal::new(15, 1));
+/// ```
+pub fn compose_casual_overtime(
+    configured_multiplier: Decimal,
+    casual_ot_base: CasualOvertimeBase,
+) -> CasualOvertimeComposition {
+    let overtime_multiplier = configured_multiplier / casual_loading_multiplier();
+    let (casual_loading, multiplier) = match casual_ot_base {
+        CasualOvertimeBase::Loaded => (casual_loading_multiplier(), configured_multiplier),
+        CasualOvertimeBase::Base => (Decimal::ONE, overtime_multiplier),
+    };
+
+    CasualOvertimeComposition {
+        configured_multiplier,
+        overtime_multiplier,
+        casual_loading,
+        multiplier,
+    }
+}
+
+/// How a casual weekend penalty multiplier is composed from its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CasualPenaltyBreakdown {
+    /// How the penalty and the casual loading are combined.
+    pub composition: CasualPenaltyComposition,
+    /// The configured casual multiplier, which adds the casual loading to the penalty.
+    pub configured_multiplier: Decimal,
+    /// The penalty component of the configured multiplier, without loading.
+    pub penalty_multiplier: Decimal,
+    /// The casual loading combined with the penalty.
+    pub casual_loading: Decimal,
+    /// The multiplier paid on the base rate.
+    pub multiplier: Decimal,
+}
+
+impl CasualPenaltyBreakdown {
+    /// Spells out the arithmetic behind the multiplier paid, e.g.
+    /// `150% penalty + 25% casual loading = 175%`.
+    pub fn arithmetic(&self) -> String {
+        let percentage = |m: Decimal| (m * Decimal::from(100)).round_dp(2).normalize();
+        match self.composition {
+            CasualPenaltyComposition::Additive => format!(
+                "{}% penalty + {}% casual loading = {}%",
+                percentage(self.penalty_multiplier),
+                percentage(self.casual_loading - Decimal::ONE),
+                percentage(self.multiplier)
+            ),
+            CasualPenaltyComposition::Compounding => format!(
+                "{}% penalty × {} casual loading = {}%",
+                percentage(self.penalty_multiplier),
+                self.casual_loading.normalize(),
+                percentage(self.multiplier)
+            ),
+        }
+    }
+}
+
+/// Composes a casual weekend penalty multiplier according to the configured
+/// composition.
+///
+/// Configured casual weekend multipliers add the casual loading to the
+/// penalty. Under `CasualPenaltyComposition::Additive` the configured
+/// multiplier is paid as is; under `CasualPenaltyComposition::Compounding`
+/// the penalty component is applied to the casual-loaded rate instead.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::compose_casual_penalty;
+/// use award_engine::config::CasualPenaltyComposition;
+/// use rust_decimal::Decimal;
+///
+/// let configured = Decimal::new(175, 2);
+///
+/// let additive = compose_casual_penalty(configured, CasualPenaltyComposition::Additive);
+/// assert_eq!(additive.multiplier, Decimal::new(175, 2));
+///
+/// let compounding = compose_casual_penalty(configured, CasualPenaltyComposition::Compounding);
+/// assert_eq!(compounding.multiplier, Decimal::new(1875, 3));
+/// ```
+pub fn compose_casual_penalty(
+    configured_multiplier: Decimal,
+    composition: CasualPenaltyComposition,
+) -> CasualPenaltyBreakdown {
+    let casual_loading = casual_loading_multiplier();
+    let penalty_multiplier = configured_multiplier - (casual_loading - Decimal::ONE);
+    let multiplier = match composition {
+        CasualPenaltyComposition::Additive => configured_multiplier,
+        CasualPenaltyComposition::Compounding => penalty_multiplier * casual_loading,
+    };
+
+    CasualPenaltyBreakdown {
+        composition,
+        configured_multiplier,
+        penalty_multiplier,
+        casual_loading,
+        multiplier,
+    }
+}
+
+/// Returns the configuration name of a casual penalty composition.
+pub(crate) fn casual_composition_name(composition: CasualPenaltyComposition) -> &'static str {
+    match composition {
+        CasualPenaltyComposition::Additive => "additive",
+        CasualPenaltyComposition::Compounding => "compounding",
+    }
+}
+
+/// Returns the configuration name of a casual overtime base.
+fn casual_ot_base_name(casual_ot_base: CasualOvertimeBase) -> &'static str {
+    match casual_ot_base {
+        CasualOvertimeBase::Loaded => "loaded",
+        CasualOvertimeBase::Base => "base",
+    }
+}
+
+/// Builds the audit step recording how casual overtime multipliers were composed.
+///
+/// # Arguments
+///
+/// * `casual_ot_base` - The configured casual overtime base
+/// * `tiers` - Each overtime tier's name and composed multiplier
+/// * `clause` - The overtime clause reference
+/// * `step_number` - The step number for audit trail sequencing
+pub fn casual_overtime_audit_step(
+    casual_ot_base: CasualOvertimeBase,
+    tiers: &[(&str, CasualOvertimeComposition)],
+    clause: &str,
+    step_number: u32,
+) -> AuditStep {
+    let percentage = |m: Decimal| (m * Decimal::from(100)).round_dp(2).normalize();
+
+    let configured: Vec<serde_json::Value> = tiers
+        .iter()
+        .map(|(tier, c)| {
+            serde_json::json!({
+                "tier": tier,
+                "configured_multiplier": c.configured_multiplier.normalize().to_string()
+            })
+        })
+        .collect();
+    let composed: Vec<serde_json::Value> = tiers
+        .iter()
+        .map(|(tier, c)| {
+            serde_json::json!({
+                "tier": tier,
+                "overtime_multiplier": c.overtime_multiplier.normalize().to_string(),
+                "casual_loading": c.casual_loading.normalize().to_string(),
+                "multiplier": c.multiplier.normalize().to_string()
+            })
+        })
+        .collect();
+    let compositions: Vec<String> = tiers
+        .iter()
+        .map(|(tier, c)| match casual_ot_base {
+            CasualOvertimeBase::Loaded => format!(
+                "{}: {}% × {} casual loading = {}%",
+                tier,
+                percentage(c.overtime_multiplier),
+                c.casual_loading.normalize(),
+                percentage(c.multiplier)
+            ),
+            CasualOvertimeBase::Base => format!("{}: {}%", tier, percentage(c.multiplier)),
+        })
+        .collect();
+
+    let reasoning = match casual_ot_base {
+        CasualOvertimeBase::Loaded => format!(
+            "Casual overtime paid on the casual-loaded rate: {}",
+            compositions.join("; ")
+        ),
+        CasualOvertimeBase::Base => format!(
+            "Casual overtime paid on the base rate, casual loading excluded: {}",
+            compositions.join("; ")
+        ),
+    };
+
+    AuditStep {
+        step_number,
+        rule_id: "casual_overtime_composition".to_string(),
+        rule_name: "Casual Overtime Composition".to_string(),
+        clause_ref: clause.to_string(),
+        input: serde_json::json!({
+            "casual_ot_base": casual_ot_base_name(casual_ot_base),
+            "tiers": configured
+        }),
+        output: serde_json::json!({
+            "tiers": composed
+        }),
+        reasoning,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::EmploymentType;
     use chrono::NaiveDate;
     use std::str::FromStr;
 
@@ -151,6 +408,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -159,75 +419,53 @@ mod tests {
     #[test]
     fn test_casual_gets_25_percent_loading() {
         let employee = create_test_employee(EmploymentType::Casual);
-        let result = apply_casual_loading(dec("28.54"), &employee, 1);
+        let result = apply_casual_loading(dec("28.54"), &employee, 1, true);
 
         assert_eq!(result.loaded_rate, dec("35.675"));
-        assert_eq!(result.audit_step.rule_id, "casual_loading");
-        assert_eq!(result.audit_step.clause_ref, "10.4(b)");
-        assert_eq!(
-            result.audit_step.input["base_rate"].as_str().unwrap(),
-            "28.54"
-        );
-        assert_eq!(
-            result.audit_step.input["employment_type"].as_str().unwrap(),
-            "casual"
-        );
-        assert_eq!(
-            result.audit_step.output["loaded_rate"].as_str().unwrap(),
-            "35.675"
-        );
-        assert!(result.audit_step.reasoning.contains("28.54"));
-        assert!(result.audit_step.reasoning.contains("1.25"));
-        assert!(result.audit_step.reasoning.contains("35.675"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.rule_id, "casual_loading");
+        assert_eq!(step.clause_ref, "10.4(b)");
+        assert_eq!(step.input["base_rate"].as_str().unwrap(), "28.54");
+        assert_eq!(step.input["employment_type"].as_str().unwrap(), "casual");
+        assert_eq!(step.output["loaded_rate"].as_str().unwrap(), "35.675");
+        assert!(step.reasoning.contains("28.54"));
+        assert!(step.reasoning.contains("1.25"));
+        assert!(step.reasoning.contains("35.675"));
     }
 
     /// CL-002: fulltime gets no loading
     #[test]
     fn test_fulltime_gets_no_loading() {
         let employee = create_test_employee(EmploymentType::FullTime);
-        let result = apply_casual_loading(dec("28.54"), &employee, 1);
+        let result = apply_casual_loading(dec("28.54"), &employee, 1, true);
 
         assert_eq!(result.loaded_rate, dec("28.54"));
-        assert_eq!(result.audit_step.rule_id, "casual_loading");
-        assert_eq!(result.audit_step.clause_ref, "10.4(b)");
-        assert_eq!(
-            result.audit_step.input["employment_type"].as_str().unwrap(),
-            "full_time"
-        );
-        assert_eq!(
-            result.audit_step.output["loading_applied"]
-                .as_bool()
-                .unwrap(),
-            false
-        );
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.rule_id, "casual_loading");
+        assert_eq!(step.clause_ref, "10.4(b)");
+        assert_eq!(step.input["employment_type"].as_str().unwrap(), "full_time");
+        assert!(!step.output["loading_applied"].as_bool().unwrap());
     }
 
     /// CL-003: parttime gets no loading
     #[test]
     fn test_parttime_gets_no_loading() {
         let employee = create_test_employee(EmploymentType::PartTime);
-        let result = apply_casual_loading(dec("28.54"), &employee, 1);
+        let result = apply_casual_loading(dec("28.54"), &employee, 1, true);
 
         assert_eq!(result.loaded_rate, dec("28.54"));
-        assert_eq!(result.audit_step.rule_id, "casual_loading");
-        assert_eq!(result.audit_step.clause_ref, "10.4(b)");
-        assert_eq!(
-            result.audit_step.input["employment_type"].as_str().unwrap(),
-            "part_time"
-        );
-        assert_eq!(
-            result.audit_step.output["loading_applied"]
-                .as_bool()
-                .unwrap(),
-            false
-        );
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.rule_id, "casual_loading");
+        assert_eq!(step.clause_ref, "10.4(b)");
+        assert_eq!(step.input["employment_type"].as_str().unwrap(), "part_time");
+        assert!(!step.output["loading_applied"].as_bool().unwrap());
     }
 
     /// CL-004: casual loading on different rate
     #[test]
     fn test_casual_loading_on_different_rate() {
         let employee = create_test_employee(EmploymentType::Casual);
-        let result = apply_casual_loading(dec("25.00"), &employee, 1);
+        let result = apply_casual_loading(dec("25.00"), &employee, 1, true);
 
         assert_eq!(result.loaded_rate, dec("31.25"));
     }
@@ -236,7 +474,7 @@ mod tests {
     #[test]
     fn test_casual_loading_on_zero_rate() {
         let employee = create_test_employee(EmploymentType::Casual);
-        let result = apply_casual_loading(dec("0.00"), &employee, 1);
+        let result = apply_casual_loading(dec("0.00"), &employee, 1, true);
 
         assert_eq!(result.loaded_rate, dec("0.00"));
     }
@@ -244,9 +482,10 @@ mod tests {
     #[test]
     fn test_audit_step_has_correct_step_number() {
         let employee = create_test_employee(EmploymentType::Casual);
-        let result = apply_casual_loading(dec("28.54"), &employee, 5);
+        let result = apply_casual_loading(dec("28.54"), &employee, 5, true);
 
-        assert_eq!(result.audit_step.step_number, 5);
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 5);
     }
 
     #[test]
@@ -257,38 +496,79 @@ mod tests {
     #[test]
     fn test_audit_reasoning_explains_calculation_for_casual() {
         let employee = create_test_employee(EmploymentType::Casual);
-        let result = apply_casual_loading(dec("28.54"), &employee, 1);
+        let result = apply_casual_loading(dec("28.54"), &employee, 1, true);
 
         // Should contain the calculation: "$28.54 x 1.25 = $35.675"
-        assert!(result.audit_step.reasoning.contains("$28.54"));
-        assert!(result.audit_step.reasoning.contains("x"));
-        assert!(result.audit_step.reasoning.contains("1.25"));
-        assert!(result.audit_step.reasoning.contains("$35.675"));
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("$28.54"));
+        assert!(step.reasoning.contains("x"));
+        assert!(step.reasoning.contains("1.25"));
+        assert!(step.reasoning.contains("$35.675"));
     }
 
     #[test]
     fn test_audit_reasoning_explains_no_loading_for_fulltime() {
         let employee = create_test_employee(EmploymentType::FullTime);
-        let result = apply_casual_loading(dec("28.54"), &employee, 1);
+        let result = apply_casual_loading(dec("28.54"), &employee, 1, true);
 
-        assert!(result.audit_step.reasoning.contains("No casual loading"));
-        assert!(result.audit_step.reasoning.contains("full_time"));
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("No casual loading"));
+        assert!(step.reasoning.contains("full_time"));
     }
 
     #[test]
     fn test_audit_output_shows_loading_applied_true_for_casual() {
         let employee = create_test_employee(EmploymentType::Casual);
-        let result = apply_casual_loading(dec("28.54"), &employee, 1);
+        let result = apply_casual_loading(dec("28.54"), &employee, 1, true);
 
+        let step = result.audit_step.unwrap();
+        assert!(step.output["loading_applied"].as_bool().unwrap());
+        assert_eq!(step.output["multiplier"].as_str().unwrap(), "1.25");
+    }
+
+    #[test]
+    fn test_loaded_casual_overtime_keeps_configured_multiplier() {
+        let composition = compose_casual_overtime(dec("1.875"), CasualOvertimeBase::Loaded);
+
+        assert_eq!(composition.overtime_multiplier, dec("1.5"));
+        assert_eq!(composition.casual_loading, dec("1.25"));
+        assert_eq!(composition.multiplier, dec("1.875"));
+    }
+
+    #[test]
+    fn test_base_casual_overtime_excludes_loading() {
+        let composition = compose_casual_overtime(dec("2.5"), CasualOvertimeBase::Base);
+
+        assert_eq!(composition.overtime_multiplier, dec("2"));
+        assert_eq!(composition.casual_loading, dec("1"));
+        assert_eq!(composition.multiplier, dec("2"));
+    }
+
+    #[test]
+    fn test_casual_overtime_audit_step_shows_composition() {
+        let tiers = [
+            (
+                "tier_1",
+                compose_casual_overtime(dec("1.875"), CasualOvertimeBase::Loaded),
+            ),
+            (
+                "tier_2",
+                compose_casual_overtime(dec("2.5"), CasualOvertimeBase::Loaded),
+            ),
+        ];
+        let step = casual_overtime_audit_step(CasualOvertimeBase::Loaded, &tiers, "25.1", 7);
+
+        assert_eq!(step.step_number, 7);
+        assert_eq!(step.rule_id, "casual_overtime_composition");
+        assert_eq!(step.input["casual_ot_base"], "loaded");
+        assert_eq!(step.input["tiers"][0]["configured_multiplier"], "1.875");
+        assert_eq!(step.output["tiers"][1]["overtime_multiplier"], "2");
+        assert_eq!(step.output["tiers"][1]["casual_loading"], "1.25");
         assert_eq!(
-            result.audit_step.output["loading_applied"]
-                .as_bool()
-                .unwrap(),
-            true
-        );
-        assert_eq!(
-            result.audit_step.output["multiplier"].as_str().unwrap(),
-            "1.25"
+            step.reasoning,
+            "Casual overtime paid on the casual-loaded rate: \
+             tier_1: 150% × 1.25 casual loading = 187.5%; \
+             tier_2: 200% × 1.25 casual loading = 250%"
         );
     }
 }
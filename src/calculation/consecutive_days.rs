@@ -0,0 +1,350 @@
+//! Consecutive days detection functionality.
+//!
+//! This module detects runs of consecutive days worked in a pay period and
+//! flags the days worked beyond a configured run length (e.g. the seventh
+//! consecutive day) for a penalty multiplier.
+//!
+//! Detection produces audit steps and the flagged days; it does not produce
+//! pay lines.
+//!
+//! ## Precedence
+//!
+//! The consecutive-days penalty is for ordinary hours only. Overtime hours on
+//! a flagged day are paid at overtime rates, whether they arise from the daily
+//! threshold or from weekly overtime, in the same way overtime replaces the
+//! weekend penalty.
+//!
+//! Only shifts in the pay period are considered, so a run that started in the
+//! previous pay period is counted from the first day of this one.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::config::ConsecutiveDaysConfig;
+use crate::models::{AuditStep, Employee, EmploymentType, Shift};
+
+use super::rules::RuleInfo;
+
+/// The consecutive days rule, for the rule catalogue; its clause is
+/// configured.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "consecutive_days",
+    rule_name: "Consecutive Days Worked",
+    clause_ref: "configured",
+}];
+
+/// A day worked beyond the configured run of consecutive days.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsecutiveDay {
+    /// The date worked.
+    pub date: NaiveDate,
+    /// The position of the day in its run (e.g. 7 for the seventh day).
+    pub day_in_run: u32,
+    /// The penalty multiplier for the employee's employment type.
+    pub multiplier: Decimal,
+}
+
+/// The result of detecting consecutive days worked.
+#[derive(Debug, Clone)]
+pub struct ConsecutiveDaysDetection {
+    /// The days that attract the consecutive-days penalty, in date order.
+    pub penalty_days: Vec<ConsecutiveDay>,
+    /// An audit step for each run that exceeds the run length, or a single
+    /// step recording that no run did.
+    pub audit_steps: Vec<AuditStep>,
+}
+
+/// Detects runs of consecutive days worked and flags the days beyond the
+/// configured run length.
+///
+/// Shifts are grouped by date, so several shifts on one day count as a single
+/// day worked. Any day without a shift ends the run.
+///
+/// # Arguments
+///
+/// * `employee` - The employee, whose employment type selects the multiplier
+/// * `shifts` - The shifts worked in the pay period
+/// * `config` - The configured run length and penalty multipliers
+/// * `step_number` - The step number of the first audit step
+/// * `include_audit` - Whether to build the audit steps
+///
+/// # Example
+///
+/// ```
+/// use award_engine::calculation::detect_consecutive_days;
+/// use award_engine::config::{ConsecutiveDaysConfig, PenaltyRates};
+/// use award_engine::models::{Employee, EmploymentType, Shift};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let employee = Employee {
+///     id: "emp_001".to_string(),
+///     employment_type: EmploymentType::FullTime,
+///     classification_code: "dce_level_3".to_string(),
+///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
+///     tags: vec![],
+/// };
+/// let config = ConsecutiveDaysConfig {
+///     run_length: 6,
+///     penalty: PenaltyRates {
+///         clause: "X.Y".to_string(),
+///         full_time: Decimal::TWO,
+///         part_time: Decimal::TWO,
+///         casual: Decimal::new(225, 2),
+///     },
+/// };
+/// // Monday 12 January to Sunday 18 January 2026
+/// let shifts: Vec<Shift> = (12..=18)
+///     .map(|day| {
+///         let date = NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+///         Shift {
+///             id: format!("shift_{}", day),
+///             date,
+///             start_time: date.and_hms_opt(9, 0, 0).unwrap(),
+///             end_time: date.and_hms_opt(13, 0, 0).unwrap(),
+///             breaks: vec![],
+///             rate_override: None,
+///             classification_code: None,
+///             reference: None,
+///             cost_centre: None,
+///         }
+///     })
+///     .collect();
+///
+/// let result = detect_consecutive_days(&employee, &shifts, &config, 1, true);
+///
+/// assert_eq!(result.penalty_days.len(), 1);
+/// assert_eq!(result.penalty_days[0].day_in_run, 7);
+/// assert_eq!(result.penalty_days[0].multiplier, Decimal::TWO);
+/// ```
+pub fn detect_consecutive_days(
+    employee: &Employee,
+    shifts: &[Shift],
+    config: &ConsecutiveDaysConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> ConsecutiveDaysDetection {
+    let multiplier = match employee.employment_type {
+        EmploymentType::FullTime => config.penalty.full_time,
+        EmploymentType::PartTime => config.penalty.part_time,
+        EmploymentType::Casual => config.penalty.casual,
+    };
+
+    // Group shifts by date, then split the dates into runs of consecutive days
+    let dates: BTreeSet<NaiveDate> = shifts.iter().map(|s| s.date).collect();
+    let mut runs: Vec<Vec<NaiveDate>> = Vec::new();
+    for date in dates {
+        match runs.last_mut() {
+            Some(run) if run.last().and_then(|d| d.succ_opt()) == Some(date) => run.push(date),
+            _ => runs.push(vec![date]),
+        }
+    }
+
+    let run_length = config.run_length as usize;
+    let mut penalty_days = Vec::new();
+    let mut audit_steps = Vec::new();
+    for run in runs.iter().filter(|run| run.len() > run_length) {
+        let flagged: Vec<ConsecutiveDay> = run
+            .iter()
+            .enumerate()
+            .skip(run_length)
+            .map(|(i, date)| ConsecutiveDay {
+                date: *date,
+                day_in_run: i as u32 + 1,
+                multiplier,
+            })
+            .collect();
+        if include_audit {
+            let affected: Vec<String> = flagged
+                .iter()
+                .map(|day| format!("day {} ({})", day.day_in_run, day.date))
+                .collect();
+
+            audit_steps.push(AuditStep {
+                step_number: step_number + audit_steps.len() as u32,
+                rule_id: "consecutive_days".to_string(),
+                rule_name: "Consecutive Days Worked".to_string(),
+                clause_ref: config.penalty.clause.clone(),
+                input: serde_json::json!({
+                    "employee_id": employee.id,
+                    "employment_type": employee.employment_type.as_str(),
+                    "run_start": run[0].to_string(),
+                    "run_end": run[run.len() - 1].to_string(),
+                    "days_in_run": run.len(),
+                    "run_length": config.run_length
+                }),
+                output: serde_json::json!({
+                    "penalty_days": flagged.iter().map(|d| d.date.to_string()).collect::<Vec<_>>(),
+                    "multiplier": multiplier.normalize().to_string()
+                }),
+                reasoning: format!(
+                    "Worked {} consecutive days from {} to {}, exceeding the {}-day run: {} attract(s) a {}% penalty",
+                    run.len(),
+                    run[0],
+                    run[run.len() - 1],
+                    config.run_length,
+                    affected.join(", "),
+                    (multiplier * Decimal::from(100)).normalize()
+                ),
+            });
+        }
+        penalty_days.extend(flagged);
+    }
+
+    if include_audit && penalty_days.is_empty() {
+        let longest = runs.iter().map(|run| run.len()).max().unwrap_or(0);
+        audit_steps.push(AuditStep {
+            step_number,
+            rule_id: "consecutive_days".to_string(),
+            rule_name: "Consecutive Days Worked".to_string(),
+            clause_ref: config.penalty.clause.clone(),
+            input: serde_json::json!({
+                "employee_id": employee.id,
+                "employment_type": employee.employment_type.as_str(),
+                "longest_run": longest,
+                "run_length": config.run_length
+            }),
+            output: serde_json::json!({
+                "penalty_days": []
+            }),
+            reasoning: format!(
+                "Longest run of consecutive days worked is {}, within the {}-day run - no consecutive-days penalty",
+                longest, config.run_length
+            ),
+        });
+    }
+
+    ConsecutiveDaysDetection {
+        penalty_days,
+        audit_steps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PenaltyRates;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    fn config() -> ConsecutiveDaysConfig {
+        ConsecutiveDaysConfig {
+            run_length: 6,
+            penalty: PenaltyRates {
+                clause: "X.Y".to_string(),
+                full_time: dec("2.0"),
+                part_time: dec("2.0"),
+                casual: dec("2.25"),
+            },
+        }
+    }
+
+    fn create_test_employee(employment_type: EmploymentType) -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags: vec![],
+        }
+    }
+
+    /// One 09:00-13:00 shift on each of the given January 2026 days.
+    fn shifts_on(days: &[u32]) -> Vec<Shift> {
+        days.iter()
+            .map(|&day| Shift {
+                id: format!("shift_{}", day),
+                date: date(day),
+                start_time: date(day).and_hms_opt(9, 0, 0).unwrap(),
+                end_time: date(day).and_hms_opt(13, 0, 0).unwrap(),
+                breaks: vec![],
+                rate_override: None,
+                classification_code: None,
+                reference: None,
+                cost_centre: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_seventh_consecutive_day_is_flagged() {
+        let employee = create_test_employee(EmploymentType::FullTime);
+        let shifts = shifts_on(&[12, 13, 14, 15, 16, 17, 18]);
+
+        let result = detect_consecutive_days(&employee, &shifts, &config(), 4, true);
+
+        assert_eq!(
+            result.penalty_days,
+            vec![ConsecutiveDay {
+                date: date(18),
+                day_in_run: 7,
+                multiplier: dec("2.0"),
+            }]
+        );
+        let step = &result.audit_steps[0];
+        assert_eq!(step.step_number, 4);
+        assert_eq!(step.rule_id, "consecutive_days");
+        assert_eq!(step.input["run_start"], "2026-01-12");
+        assert_eq!(step.input["days_in_run"], 7);
+        assert!(step.reasoning.contains("day 7 (2026-01-18)"));
+    }
+
+    #[test]
+    fn test_gap_day_resets_the_run() {
+        let employee = create_test_employee(EmploymentType::FullTime);
+        // Six days, a day off on the 18th, then two more days
+        let shifts = shifts_on(&[12, 13, 14, 15, 16, 17, 19, 20]);
+
+        let result = detect_consecutive_days(&employee, &shifts, &config(), 1, true);
+
+        assert!(result.penalty_days.is_empty());
+        assert_eq!(result.audit_steps.len(), 1);
+        assert_eq!(result.audit_steps[0].input["longest_run"], 6);
+    }
+
+    #[test]
+    fn test_several_shifts_on_one_day_count_once() {
+        let employee = create_test_employee(EmploymentType::Casual);
+        let mut shifts = shifts_on(&[12, 13, 14, 15, 16, 17, 18, 19]);
+        shifts.extend(shifts_on(&[13, 18]));
+
+        let result = detect_consecutive_days(&employee, &shifts, &config(), 1, true);
+
+        let days: Vec<u32> = result.penalty_days.iter().map(|d| d.day_in_run).collect();
+        assert_eq!(days, vec![7, 8]);
+        assert_eq!(result.penalty_days[0].multiplier, dec("2.25"));
+        assert_eq!(result.audit_steps.len(), 1);
+    }
+
+    #[test]
+    fn test_each_long_run_gets_its_own_audit_step() {
+        let employee = create_test_employee(EmploymentType::PartTime);
+        let shifts = shifts_on(&[1, 2, 3, 4, 5, 6, 7, 9, 10, 11, 12, 13, 14, 15]);
+
+        let result = detect_consecutive_days(&employee, &shifts, &config(), 1, true);
+
+        assert_eq!(result.penalty_days.len(), 2);
+        assert_eq!(result.audit_steps.len(), 2);
+        assert_eq!(result.audit_steps[1].step_number, 2);
+        assert_eq!(result.audit_steps[1].input["run_start"], "2026-01-09");
+    }
+}
@@ -4,11 +4,29 @@
 //! overtime threshold and splitting hours into ordinary and overtime portions
 //! as per the Aged Care Award 2010.
 
+use chrono::{Datelike, NaiveDate};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::config::OvertimeSection;
 use crate::models::AuditStep;
 
+use super::rules::RuleInfo;
+
+/// The daily overtime threshold and detection rules, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        rule_id: "daily_overtime_threshold",
+        rule_name: "Daily Overtime Threshold",
+        clause_ref: "22.1(c)",
+    },
+    RuleInfo {
+        rule_id: "daily_overtime_detection",
+        rule_name: "Daily Overtime Detection",
+        clause_ref: "22.1(c), 25.1",
+    },
+];
+
 /// The result of detecting daily overtime for a shift or segment.
 ///
 /// Contains the split between ordinary hours and overtime hours,
@@ -25,7 +43,7 @@ use crate::models::AuditStep;
 /// let detection = DailyOvertimeDetection {
 ///     ordinary_hours: Decimal::from_str("8.0").unwrap(),
 ///     overtime_hours: Decimal::from_str("2.0").unwrap(),
-///     audit_step: AuditStep {
+///     audit_step: Some(AuditStep {
 ///         step_number: 1,
 ///         rule_id: "daily_overtime_detection".to_string(),
 ///         rule_name: "Daily Overtime Detection".to_string(),
@@ -33,7 +51,7 @@ use crate::models::AuditStep;
 ///         input: serde_json::json!({"worked_hours": "10.0", "threshold": "8.0"}),
 ///         output: serde_json::json!({"ordinary_hours": "8.0", "overtime_hours": "2.0"}),
 ///         reasoning: "10.0 hours worked exceeds 8.0 hour threshold".to_string(),
-///     },
+///     }),
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -42,8 +60,9 @@ pub struct DailyOvertimeDetection {
     pub ordinary_hours: Decimal,
     /// The number of overtime hours (hours exceeding the threshold).
     pub overtime_hours: Decimal,
-    /// The audit step recording this detection.
-    pub audit_step: AuditStep,
+    /// The audit step recording this detection, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
 }
 
 /// Default daily overtime threshold in hours.
@@ -51,23 +70,120 @@ pub struct DailyOvertimeDetection {
 /// Per Aged Care Award 2010 clause 22.1(c), ordinary hours are up to 8 hours per day.
 pub const DEFAULT_DAILY_OVERTIME_THRESHOLD: Decimal = Decimal::from_parts(8, 0, 0, false, 0);
 
+/// The daily overtime threshold selected for a shift.
+#[derive(Debug, Clone)]
+pub struct DailyThresholdSelection {
+    /// The threshold, in hours, above which the day's hours are overtime.
+    pub threshold: Decimal,
+    /// The audit step recording why the threshold was chosen, or `None` when
+    /// no per-day thresholds are configured or the audit trace is not wanted.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Selects the daily overtime threshold for a shift starting on `date`.
+///
+/// A threshold configured for the day of the week in
+/// `overtime.daily_thresholds` applies; any other day uses
+/// `overtime.daily_threshold_hours`. An overnight shift uses the threshold
+/// for the day it starts on.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::select_daily_threshold;
+/// use award_engine::config::ConfigLoader;
+/// use chrono::{NaiveDate, Weekday};
+/// use rust_decimal::Decimal;
+///
+/// let loader = ConfigLoader::load("config/ma000018").unwrap();
+/// let mut overtime = loader.config().penalties().overtime.clone();
+/// overtime.daily_thresholds.insert(Weekday::Sat, Decimal::from(10));
+///
+/// let saturday = NaiveDate::from_ymd_opt(2026, 1, 17).unwrap();
+/// let selection = select_daily_threshold("shift_001", saturday, &overtime, 1, true);
+/// assert_eq!(selection.threshold, Decimal::from(10));
+///
+/// let monday = NaiveDate::from_ymd_opt(2026, 1, 12).unwrap();
+/// let selection = select_daily_threshold("shift_002", monday, &overtime, 1, true);
+/// assert_eq!(selection.threshold, Decimal::from(8));
+/// ```
+pub fn select_daily_threshold(
+    shift_id: &str,
+    date: NaiveDate,
+    overtime: &OvertimeSection,
+    step_number: u32,
+    include_audit: bool,
+) -> DailyThresholdSelection {
+    let threshold = overtime.daily_threshold_for(date.weekday());
+    if !include_audit || overtime.daily_thresholds.is_empty() {
+        return DailyThresholdSelection {
+            threshold,
+            audit_step: None,
+        };
+    }
+
+    let day = date.format("%A").to_string();
+    let default_threshold = Decimal::from(overtime.daily_threshold_hours);
+    let configured = overtime.daily_thresholds.contains_key(&date.weekday());
+    let reasoning = if configured {
+        format!(
+            "{} has a configured daily overtime threshold of {} hours (default {} hours)",
+            day,
+            threshold.normalize(),
+            default_threshold
+        )
+    } else {
+        format!(
+            "No daily overtime threshold is configured for {}; the default of {} hours applies",
+            day, default_threshold
+        )
+    };
+
+    let audit_step = AuditStep {
+        step_number,
+        rule_id: "daily_overtime_threshold".to_string(),
+        rule_name: "Daily Overtime Threshold".to_string(),
+        clause_ref: "22.1(c)".to_string(),
+        input: serde_json::json!({
+            "shift_id": shift_id,
+            "day": day,
+            "default_threshold": default_threshold.to_string()
+        }),
+        output: serde_json::json!({
+            "threshold": threshold.normalize().to_string(),
+            "source": if configured { "daily_thresholds" } else { "default" }
+        }),
+        reasoning,
+    };
+
+    DailyThresholdSelection {
+        threshold,
+        audit_step: Some(audit_step),
+    }
+}
+
 /// Detects whether hours worked exceed the daily overtime threshold.
 ///
 /// Splits the worked hours into ordinary hours (up to the threshold) and
-/// overtime hours (any hours exceeding the threshold).
+/// overtime hours (any hours exceeding the threshold). When an ordinary cap
+/// lower than the threshold is given (e.g. a part-timer's agreed 6-hour day),
+/// ordinary hours stop at the cap and everything above it is overtime.
 ///
 /// # Arguments
 ///
 /// * `worked_hours` - The total hours worked in the shift or segment
 /// * `threshold` - The overtime threshold (typically 8 hours per day)
+/// * `ordinary_cap` - The employee's maximum ordinary hours per day, if any
 /// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
 ///
 /// # Returns
 ///
 /// A [`DailyOvertimeDetection`] containing:
 /// - `ordinary_hours`: Hours up to the threshold (capped at threshold)
 /// - `overtime_hours`: Hours exceeding the threshold (can be zero)
-/// - `audit_step`: Documentation of the detection with clause references
+/// - `audit_step`: Documentation of the detection with clause references,
+///   when `include_audit` is true
 ///
 /// # Award Reference
 ///
@@ -84,7 +200,7 @@ pub const DEFAULT_DAILY_OVERTIME_THRESHOLD: Decimal = Decimal::from_parts(8, 0,
 /// use std::str::FromStr;
 ///
 /// let worked = Decimal::from_str("8.0").unwrap();
-/// let result = detect_daily_overtime(worked, DEFAULT_DAILY_OVERTIME_THRESHOLD, 1);
+/// let result = detect_daily_overtime(worked, DEFAULT_DAILY_OVERTIME_THRESHOLD, None, 1, true);
 ///
 /// assert_eq!(result.ordinary_hours, Decimal::from_str("8.0").unwrap());
 /// assert_eq!(result.overtime_hours, Decimal::ZERO);
@@ -98,7 +214,7 @@ pub const DEFAULT_DAILY_OVERTIME_THRESHOLD: Decimal = Decimal::from_parts(8, 0,
 /// use std::str::FromStr;
 ///
 /// let worked = Decimal::from_str("10.0").unwrap();
-/// let result = detect_daily_overtime(worked, DEFAULT_DAILY_OVERTIME_THRESHOLD, 1);
+/// let result = detect_daily_overtime(worked, DEFAULT_DAILY_OVERTIME_THRESHOLD, None, 1, true);
 ///
 /// assert_eq!(result.ordinary_hours, Decimal::from_str("8.0").unwrap());
 /// assert_eq!(result.overtime_hours, Decimal::from_str("2.0").unwrap());
@@ -112,7 +228,7 @@ pub const DEFAULT_DAILY_OVERTIME_THRESHOLD: Decimal = Decimal::from_parts(8, 0,
 /// use std::str::FromStr;
 ///
 /// let worked = Decimal::from_str("6.0").unwrap();
-/// let result = detect_daily_overtime(worked, DEFAULT_DAILY_OVERTIME_THRESHOLD, 1);
+/// let result = detect_daily_overtime(worked, DEFAULT_DAILY_OVERTIME_THRESHOLD, None, 1, true);
 ///
 /// assert_eq!(result.ordinary_hours, Decimal::from_str("6.0").unwrap());
 /// assert_eq!(result.overtime_hours, Decimal::ZERO);
@@ -120,53 +236,189 @@ pub const DEFAULT_DAILY_OVERTIME_THRESHOLD: Decimal = Decimal::from_parts(8, 0,
 pub fn detect_daily_overtime(
     worked_hours: Decimal,
     threshold: Decimal,
+    ordinary_cap: Option<Decimal>,
     step_number: u32,
+    include_audit: bool,
 ) -> DailyOvertimeDetection {
-    // Calculate ordinary hours (capped at threshold)
-    let ordinary_hours = if worked_hours <= threshold {
+    let (limit, limit_description) = daily_ordinary_limit(threshold, ordinary_cap);
+
+    // Calculate ordinary hours (capped at the limit)
+    let ordinary_hours = if worked_hours <= limit {
         worked_hours
     } else {
-        threshold
+        limit
     };
 
-    // Calculate overtime hours (excess over threshold)
-    let overtime_hours = if worked_hours > threshold {
-        worked_hours - threshold
+    // Calculate overtime hours (excess over the limit)
+    let overtime_hours = if worked_hours > limit {
+        worked_hours - limit
     } else {
         Decimal::ZERO
     };
 
+    if !include_audit {
+        return DailyOvertimeDetection {
+            ordinary_hours,
+            overtime_hours,
+            audit_step: None,
+        };
+    }
+
     // Determine reasoning based on outcome
     let reasoning = if overtime_hours > Decimal::ZERO {
         format!(
-            "{} hours worked exceeds {} hour threshold by {} hours, triggering overtime",
+            "{} hours worked exceeds {} by {} hours, triggering overtime",
             worked_hours.normalize(),
-            threshold.normalize(),
+            limit_description,
             overtime_hours.normalize()
         )
-    } else if worked_hours == threshold {
+    } else if worked_hours == limit {
         format!(
-            "{} hours worked equals {} hour threshold, no overtime triggered",
+            "{} hours worked equals {}, no overtime triggered",
             worked_hours.normalize(),
-            threshold.normalize()
+            limit_description
         )
     } else {
         format!(
-            "{} hours worked is under {} hour threshold, no overtime triggered",
+            "{} hours worked is under {}, no overtime triggered",
             worked_hours.normalize(),
-            threshold.normalize()
+            limit_description
         )
     };
 
+    let mut input = serde_json::json!({
+        "worked_hours": worked_hours.normalize().to_string(),
+        "threshold": threshold.normalize().to_string()
+    });
+    if let Some(cap) = ordinary_cap {
+        input["ordinary_cap"] = serde_json::json!(cap.normalize().to_string());
+    }
+
     let audit_step = AuditStep {
         step_number,
         rule_id: "daily_overtime_detection".to_string(),
         rule_name: "Daily Overtime Detection".to_string(),
         clause_ref: "22.1(c), 25.1".to_string(),
-        input: serde_json::json!({
-            "worked_hours": worked_hours.normalize().to_string(),
-            "threshold": threshold.normalize().to_string()
+        input,
+        output: serde_json::json!({
+            "ordinary_hours": ordinary_hours.normalize().to_string(),
+            "overtime_hours": overtime_hours.normalize().to_string()
         }),
+        reasoning,
+    };
+
+    DailyOvertimeDetection {
+        ordinary_hours,
+        overtime_hours,
+        audit_step: Some(audit_step),
+    }
+}
+
+/// Detects daily overtime for a shift, counting hours already worked that day.
+///
+/// When an employee works more than one shift on the same calendar day, the
+/// daily threshold applies to the day's total. Hours from earlier shifts use
+/// up the threshold first, so any overtime is attributed to the later shift.
+///
+/// With no prior hours this is identical to [`detect_daily_overtime`].
+///
+/// # Arguments
+///
+/// * `prior_hours` - Hours already worked in earlier shifts on the same day
+/// * `worked_hours` - The hours worked in this shift
+/// * `threshold` - The overtime threshold (typically 8 hours per day)
+/// * `ordinary_cap` - The employee's maximum ordinary hours per day, if any
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::{
+///     detect_cumulative_daily_overtime, DEFAULT_DAILY_OVERTIME_THRESHOLD,
+/// };
+/// use rust_decimal::Decimal;
+/// use std::str::FromStr;
+///
+/// // Second 5-hour shift after a 5-hour shift on the same day
+/// let result = detect_cumulative_daily_overtime(
+///     Decimal::from_str("5.0").unwrap(),
+///     Decimal::from_str("5.0").unwrap(),
+///     DEFAULT_DAILY_OVERTIME_THRESHOLD,
+///     None,
+///     1,
+///     true,
+/// );
+///
+/// assert_eq!(result.ordinary_hours, Decimal::from_str("3.0").unwrap());
+/// assert_eq!(result.overtime_hours, Decimal::from_str("2.0").unwrap());
+/// ```
+pub fn detect_cumulative_daily_overtime(
+    prior_hours: Decimal,
+    worked_hours: Decimal,
+    threshold: Decimal,
+    ordinary_cap: Option<Decimal>,
+    step_number: u32,
+    include_audit: bool,
+) -> DailyOvertimeDetection {
+    if prior_hours <= Decimal::ZERO {
+        return detect_daily_overtime(
+            worked_hours,
+            threshold,
+            ordinary_cap,
+            step_number,
+            include_audit,
+        );
+    }
+
+    let (limit, limit_description) = daily_ordinary_limit(threshold, ordinary_cap);
+    let day_hours = prior_hours + worked_hours;
+    let remaining_limit = (limit - prior_hours).max(Decimal::ZERO);
+    let ordinary_hours = worked_hours.min(remaining_limit);
+    let overtime_hours = worked_hours - ordinary_hours;
+
+    if !include_audit {
+        return DailyOvertimeDetection {
+            ordinary_hours,
+            overtime_hours,
+            audit_step: None,
+        };
+    }
+
+    let reasoning = if overtime_hours > Decimal::ZERO {
+        format!(
+            "{} hours worked plus {} earlier hours the same day totals {} hours, exceeding {}; {} hours of this shift are overtime",
+            worked_hours.normalize(),
+            prior_hours.normalize(),
+            day_hours.normalize(),
+            limit_description,
+            overtime_hours.normalize()
+        )
+    } else {
+        format!(
+            "{} hours worked plus {} earlier hours the same day totals {} hours, within {}, no overtime triggered",
+            worked_hours.normalize(),
+            prior_hours.normalize(),
+            day_hours.normalize(),
+            limit_description
+        )
+    };
+
+    let mut input = serde_json::json!({
+        "worked_hours": worked_hours.normalize().to_string(),
+        "prior_hours_same_day": prior_hours.normalize().to_string(),
+        "threshold": threshold.normalize().to_string()
+    });
+    if let Some(cap) = ordinary_cap {
+        input["ordinary_cap"] = serde_json::json!(cap.normalize().to_string());
+    }
+
+    let audit_step = AuditStep {
+        step_number,
+        rule_id: "daily_overtime_detection".to_string(),
+        rule_name: "Daily Overtime Detection".to_string(),
+        clause_ref: "22.1(c), 25.1".to_string(),
+        input,
         output: serde_json::json!({
             "ordinary_hours": ordinary_hours.normalize().to_string(),
             "overtime_hours": overtime_hours.normalize().to_string()
@@ -177,7 +429,27 @@ pub fn detect_daily_overtime(
     DailyOvertimeDetection {
         ordinary_hours,
         overtime_hours,
-        audit_step,
+        audit_step: Some(audit_step),
+    }
+}
+
+/// Returns the daily ordinary hours limit and a description of it for audit reasoning.
+///
+/// An ordinary cap above the overtime threshold has no effect.
+fn daily_ordinary_limit(threshold: Decimal, ordinary_cap: Option<Decimal>) -> (Decimal, String) {
+    match ordinary_cap {
+        Some(cap) if cap < threshold => (
+            cap,
+            format!(
+                "{} hour ordinary cap (below the {} hour threshold)",
+                cap.normalize(),
+                threshold.normalize()
+            ),
+        ),
+        _ => (
+            threshold,
+            format!("{} hour threshold", threshold.normalize()),
+        ),
     }
 }
 
@@ -198,28 +470,20 @@ mod tests {
         let worked_hours = dec("8.0");
         let threshold = dec("8.0");
 
-        let result = detect_daily_overtime(worked_hours, threshold, 1);
+        let result = detect_daily_overtime(worked_hours, threshold, None, 1, true);
 
         assert_eq!(result.ordinary_hours, dec("8.0"));
         assert_eq!(result.overtime_hours, dec("0.0"));
 
         // Verify audit step
-        assert_eq!(result.audit_step.step_number, 1);
-        assert_eq!(result.audit_step.rule_id, "daily_overtime_detection");
-        assert_eq!(result.audit_step.clause_ref, "22.1(c), 25.1");
-        assert_eq!(
-            result.audit_step.input["worked_hours"].as_str().unwrap(),
-            "8"
-        );
-        assert_eq!(result.audit_step.input["threshold"].as_str().unwrap(), "8");
-        assert_eq!(
-            result.audit_step.output["ordinary_hours"].as_str().unwrap(),
-            "8"
-        );
-        assert_eq!(
-            result.audit_step.output["overtime_hours"].as_str().unwrap(),
-            "0"
-        );
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 1);
+        assert_eq!(step.rule_id, "daily_overtime_detection");
+        assert_eq!(step.clause_ref, "22.1(c), 25.1");
+        assert_eq!(step.input["worked_hours"].as_str().unwrap(), "8");
+        assert_eq!(step.input["threshold"].as_str().unwrap(), "8");
+        assert_eq!(step.output["ordinary_hours"].as_str().unwrap(), "8");
+        assert_eq!(step.output["overtime_hours"].as_str().unwrap(), "0");
     }
 
     // ==========================================================================
@@ -230,20 +494,15 @@ mod tests {
         let worked_hours = dec("10.0");
         let threshold = dec("8.0");
 
-        let result = detect_daily_overtime(worked_hours, threshold, 1);
+        let result = detect_daily_overtime(worked_hours, threshold, None, 1, true);
 
         assert_eq!(result.ordinary_hours, dec("8.0"));
         assert_eq!(result.overtime_hours, dec("2.0"));
 
         // Verify audit step output
-        assert_eq!(
-            result.audit_step.output["ordinary_hours"].as_str().unwrap(),
-            "8"
-        );
-        assert_eq!(
-            result.audit_step.output["overtime_hours"].as_str().unwrap(),
-            "2"
-        );
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.output["ordinary_hours"].as_str().unwrap(), "8");
+        assert_eq!(step.output["overtime_hours"].as_str().unwrap(), "2");
     }
 
     // ==========================================================================
@@ -254,7 +513,7 @@ mod tests {
         let worked_hours = dec("12.0");
         let threshold = dec("8.0");
 
-        let result = detect_daily_overtime(worked_hours, threshold, 1);
+        let result = detect_daily_overtime(worked_hours, threshold, None, 1, true);
 
         assert_eq!(result.ordinary_hours, dec("8.0"));
         assert_eq!(result.overtime_hours, dec("4.0"));
@@ -268,7 +527,7 @@ mod tests {
         let worked_hours = dec("6.0");
         let threshold = dec("8.0");
 
-        let result = detect_daily_overtime(worked_hours, threshold, 1);
+        let result = detect_daily_overtime(worked_hours, threshold, None, 1, true);
 
         assert_eq!(result.ordinary_hours, dec("6.0"));
         assert_eq!(result.overtime_hours, dec("0.0"));
@@ -282,7 +541,7 @@ mod tests {
         let worked_hours = dec("8.5");
         let threshold = dec("8.0");
 
-        let result = detect_daily_overtime(worked_hours, threshold, 1);
+        let result = detect_daily_overtime(worked_hours, threshold, None, 1, true);
 
         assert_eq!(result.ordinary_hours, dec("8.0"));
         assert_eq!(result.overtime_hours, dec("0.5"));
@@ -296,7 +555,7 @@ mod tests {
         let worked_hours = dec("11.25");
         let threshold = dec("8.0");
 
-        let result = detect_daily_overtime(worked_hours, threshold, 1);
+        let result = detect_daily_overtime(worked_hours, threshold, None, 1, true);
 
         assert_eq!(result.ordinary_hours, dec("8.0"));
         assert_eq!(result.overtime_hours, dec("3.25"));
@@ -308,35 +567,40 @@ mod tests {
 
     #[test]
     fn test_audit_step_rule_name() {
-        let result = detect_daily_overtime(dec("10.0"), dec("8.0"), 1);
-        assert_eq!(result.audit_step.rule_name, "Daily Overtime Detection");
+        let result = detect_daily_overtime(dec("10.0"), dec("8.0"), None, 1, true);
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.rule_name, "Daily Overtime Detection");
     }
 
     #[test]
     fn test_audit_step_reasoning_for_overtime() {
-        let result = detect_daily_overtime(dec("10.0"), dec("8.0"), 1);
-        assert!(result.audit_step.reasoning.contains("exceeds"));
-        assert!(result.audit_step.reasoning.contains("overtime"));
+        let result = detect_daily_overtime(dec("10.0"), dec("8.0"), None, 1, true);
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("exceeds"));
+        assert!(step.reasoning.contains("overtime"));
     }
 
     #[test]
     fn test_audit_step_reasoning_for_no_overtime() {
-        let result = detect_daily_overtime(dec("6.0"), dec("8.0"), 1);
-        assert!(result.audit_step.reasoning.contains("under"));
-        assert!(result.audit_step.reasoning.contains("no overtime"));
+        let result = detect_daily_overtime(dec("6.0"), dec("8.0"), None, 1, true);
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("under"));
+        assert!(step.reasoning.contains("no overtime"));
     }
 
     #[test]
     fn test_audit_step_reasoning_for_exact_threshold() {
-        let result = detect_daily_overtime(dec("8.0"), dec("8.0"), 1);
-        assert!(result.audit_step.reasoning.contains("equals"));
-        assert!(result.audit_step.reasoning.contains("no overtime"));
+        let result = detect_daily_overtime(dec("8.0"), dec("8.0"), None, 1, true);
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("equals"));
+        assert!(step.reasoning.contains("no overtime"));
     }
 
     #[test]
     fn test_step_number_passed_through() {
-        let result = detect_daily_overtime(dec("10.0"), dec("8.0"), 5);
-        assert_eq!(result.audit_step.step_number, 5);
+        let result = detect_daily_overtime(dec("10.0"), dec("8.0"), None, 5, true);
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 5);
     }
 
     #[test]
@@ -345,16 +609,17 @@ mod tests {
         let worked_hours = dec("12.0");
         let threshold = dec("10.0");
 
-        let result = detect_daily_overtime(worked_hours, threshold, 1);
+        let result = detect_daily_overtime(worked_hours, threshold, None, 1, true);
 
         assert_eq!(result.ordinary_hours, dec("10.0"));
         assert_eq!(result.overtime_hours, dec("2.0"));
-        assert_eq!(result.audit_step.input["threshold"].as_str().unwrap(), "10");
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.input["threshold"].as_str().unwrap(), "10");
     }
 
     #[test]
     fn test_zero_hours_worked() {
-        let result = detect_daily_overtime(dec("0.0"), dec("8.0"), 1);
+        let result = detect_daily_overtime(dec("0.0"), dec("8.0"), None, 1, true);
 
         assert_eq!(result.ordinary_hours, dec("0.0"));
         assert_eq!(result.overtime_hours, dec("0.0"));
@@ -366,7 +631,7 @@ mod tests {
         let worked_hours = dec("8.5");
         let threshold = dec("7.5");
 
-        let result = detect_daily_overtime(worked_hours, threshold, 1);
+        let result = detect_daily_overtime(worked_hours, threshold, None, 1, true);
 
         assert_eq!(result.ordinary_hours, dec("7.5"));
         assert_eq!(result.overtime_hours, dec("1.0"));
@@ -380,7 +645,13 @@ mod tests {
     #[test]
     fn test_detection_with_default_threshold() {
         let worked_hours = dec("10.0");
-        let result = detect_daily_overtime(worked_hours, DEFAULT_DAILY_OVERTIME_THRESHOLD, 1);
+        let result = detect_daily_overtime(
+            worked_hours,
+            DEFAULT_DAILY_OVERTIME_THRESHOLD,
+            None,
+            1,
+            true,
+        );
 
         assert_eq!(result.ordinary_hours, dec("8.0"));
         assert_eq!(result.overtime_hours, dec("2.0"));
@@ -388,7 +659,7 @@ mod tests {
 
     #[test]
     fn test_serialization() {
-        let result = detect_daily_overtime(dec("10.0"), dec("8.0"), 1);
+        let result = detect_daily_overtime(dec("10.0"), dec("8.0"), None, 1, true);
 
         // Verify the result can be serialized
         let json = serde_json::to_string(&result).unwrap();
@@ -400,4 +671,89 @@ mod tests {
         assert_eq!(deserialized.ordinary_hours, dec("8.0"));
         assert_eq!(deserialized.overtime_hours, dec("2.0"));
     }
+
+    // ==========================================================================
+    // Cumulative daily overtime across shifts on the same day
+    // ==========================================================================
+    #[test]
+    fn test_cumulative_no_prior_hours_matches_single_shift() {
+        let cumulative =
+            detect_cumulative_daily_overtime(dec("0"), dec("10.0"), dec("8.0"), None, 1, true);
+        let single = detect_daily_overtime(dec("10.0"), dec("8.0"), None, 1, true);
+
+        assert_eq!(cumulative, single);
+    }
+
+    #[test]
+    fn test_cumulative_second_shift_crosses_threshold() {
+        let result =
+            detect_cumulative_daily_overtime(dec("5.0"), dec("5.0"), dec("8.0"), None, 2, true);
+
+        assert_eq!(result.ordinary_hours, dec("3.0"));
+        assert_eq!(result.overtime_hours, dec("2.0"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.input["prior_hours_same_day"].as_str().unwrap(), "5");
+        assert!(step.reasoning.contains("totals 10 hours"));
+    }
+
+    #[test]
+    fn test_cumulative_threshold_already_used() {
+        let result =
+            detect_cumulative_daily_overtime(dec("9.0"), dec("3.0"), dec("8.0"), None, 1, true);
+
+        assert_eq!(result.ordinary_hours, dec("0"));
+        assert_eq!(result.overtime_hours, dec("3.0"));
+    }
+
+    #[test]
+    fn test_cumulative_within_threshold() {
+        let result =
+            detect_cumulative_daily_overtime(dec("4.0"), dec("4.0"), dec("8.0"), None, 1, true);
+
+        assert_eq!(result.ordinary_hours, dec("4.0"));
+        assert_eq!(result.overtime_hours, dec("0"));
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("no overtime triggered"));
+    }
+
+    // ==========================================================================
+    // Ordinary cap below the overtime threshold
+    // ==========================================================================
+    #[test]
+    fn test_part_time_6_hour_cap_working_8_hours() {
+        let result = detect_daily_overtime(dec("8.0"), dec("8.0"), Some(dec("6.0")), 1, true);
+
+        assert_eq!(result.ordinary_hours, dec("6.0"));
+        assert_eq!(result.overtime_hours, dec("2.0"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.input["ordinary_cap"], "6");
+        assert_eq!(
+            step.reasoning,
+            "8 hours worked exceeds 6 hour ordinary cap (below the 8 hour threshold) by 2 hours, triggering overtime"
+        );
+    }
+
+    #[test]
+    fn test_ordinary_cap_above_threshold_has_no_effect() {
+        let capped = detect_daily_overtime(dec("10.0"), dec("8.0"), Some(dec("9.0")), 1, true);
+
+        assert_eq!(capped.ordinary_hours, dec("8.0"));
+        assert_eq!(capped.overtime_hours, dec("2.0"));
+    }
+
+    #[test]
+    fn test_cumulative_ordinary_cap_counts_earlier_shifts() {
+        // 4h earlier in the day leaves 2h of a 6h cap for a 3h shift
+        let result = detect_cumulative_daily_overtime(
+            dec("4.0"),
+            dec("3.0"),
+            dec("8.0"),
+            Some(dec("6")),
+            1,
+            true,
+        );
+
+        assert_eq!(result.ordinary_hours, dec("2.0"));
+        assert_eq!(result.overtime_hours, dec("1.0"));
+    }
 }
@@ -8,7 +8,7 @@ use chrono::{Datelike, NaiveDateTime, Weekday};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::models::Shift;
+use crate::models::{Shift, hours_from_seconds};
 
 /// Represents the type of day for penalty rate calculation.
 ///
@@ -24,7 +24,7 @@ use crate::models::Shift;
 /// let day_type = DayType::Saturday;
 /// assert_eq!(format!("{:?}", day_type), "Saturday");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DayType {
     /// Monday through Friday - ordinary time rates apply.
@@ -126,6 +126,10 @@ pub struct ShiftSegment {
 /// calendar day the shift spans. Each segment records its start/end times,
 /// day type, and hours worked within that day.
 ///
+/// Unpaid breaks are split at midnight in the same way, and each portion is
+/// deducted from the segment it falls in. A 30 minute break from 23:45 to
+/// 00:15 takes 15 minutes from each side of midnight.
+///
 /// # Arguments
 ///
 /// * `shift` - The shift to segment
@@ -141,7 +145,7 @@ pub struct ShiftSegment {
 /// - A shift crossing midnight returns two segments (before and after midnight)
 /// - Segments are ordered chronologically
 /// - Each segment's day_type matches the day it falls on
-/// - Unpaid breaks are NOT considered in segmentation (they are handled at shift level)
+/// - Unpaid break time is deducted from the segment it falls in; paid breaks are worked time
 ///
 /// # Example
 ///
@@ -158,6 +162,10 @@ pub struct ShiftSegment {
 ///     start_time: NaiveDateTime::parse_from_str("2026-01-17 22:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
 ///     end_time: NaiveDateTime::parse_from_str("2026-01-18 06:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
 ///     breaks: vec![],
+///     rate_override: None,
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
 /// };
 ///
 /// let segments = segment_by_day(&shift);
@@ -173,8 +181,8 @@ pub fn segment_by_day(shift: &Shift) -> Vec<ShiftSegment> {
     let shift_end = shift.end_time;
 
     // If shift doesn't cross midnight, return single segment
-    if current_start.date() == shift_end.date() || current_start == shift_end {
-        let hours = calculate_hours(current_start, shift_end);
+    if !shift.crosses_midnight() {
+        let hours = worked_hours_until(shift, shift_end);
         if hours > Decimal::ZERO {
             segments.push(ShiftSegment {
                 start_time: current_start,
@@ -200,7 +208,11 @@ pub fn segment_by_day(shift: &Shift) -> Vec<ShiftSegment> {
             shift_end
         };
 
-        let hours = calculate_hours(current_start, segment_end);
+        // Measure each segment as the difference of the worked time from the
+        // shift start, so the rounded segment hours add up to the rounded
+        // hours for the whole shift
+        let hours =
+            worked_hours_until(shift, segment_end) - worked_hours_until(shift, current_start);
         if hours > Decimal::ZERO {
             segments.push(ShiftSegment {
                 start_time: current_start,
@@ -216,24 +228,37 @@ pub fn segment_by_day(shift: &Shift) -> Vec<ShiftSegment> {
     segments
 }
 
-/// Calculates the number of hours between two datetimes.
+/// Calculates the hours worked from the start of a shift until `until`,
+/// excluding the unpaid break time taken before it.
 ///
 /// # Arguments
 ///
-/// * `start` - The start datetime
-/// * `end` - The end datetime
+/// * `shift` - The shift being segmented
+/// * `until` - The point in the shift to measure to
 ///
 /// # Returns
 ///
-/// The number of hours as a [`Decimal`].
-fn calculate_hours(start: NaiveDateTime, end: NaiveDateTime) -> Decimal {
-    let duration_minutes = (end - start).num_minutes();
-    Decimal::new(duration_minutes, 0) / Decimal::new(60, 0)
+/// The number of hours as a [`Decimal`], rounded to
+/// [`HOURS_PRECISION`](crate::models::HOURS_PRECISION).
+fn worked_hours_until(shift: &Shift, until: NaiveDateTime) -> Decimal {
+    let unpaid_break_seconds: i64 = shift
+        .breaks
+        .iter()
+        .filter(|b| !b.is_paid)
+        .map(|b| {
+            let start = b.start_time.max(shift.start_time);
+            let end = b.end_time.min(until);
+            (end - start).num_seconds().max(0)
+        })
+        .sum();
+
+    hours_from_seconds((until - shift.start_time).num_seconds() - unpaid_break_seconds)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Break;
     use chrono::NaiveDate;
     use std::str::FromStr;
 
@@ -312,6 +337,10 @@ mod tests {
             start_time: make_datetime("2026-01-14", "09:00:00"),
             end_time: make_datetime("2026-01-14", "17:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
@@ -332,6 +361,10 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
@@ -404,6 +437,10 @@ mod tests {
             start_time: make_datetime("2026-01-17", "09:00:00"),
             end_time: make_datetime("2026-01-17", "17:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
@@ -420,6 +457,10 @@ mod tests {
             start_time: make_datetime("2026-01-18", "08:00:00"),
             end_time: make_datetime("2026-01-18", "16:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
@@ -437,6 +478,10 @@ mod tests {
             start_time: make_datetime("2026-01-16", "22:00:00"),
             end_time: make_datetime("2026-01-17", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
@@ -460,6 +505,10 @@ mod tests {
             start_time: make_datetime("2026-01-18", "22:00:00"),
             end_time: make_datetime("2026-01-19", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
@@ -483,6 +532,10 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
@@ -490,6 +543,31 @@ mod tests {
         assert_eq!(segment_total, shift.worked_hours());
     }
 
+    #[test]
+    fn test_segment_hours_are_rounded_and_sum_to_shift_worked_hours() {
+        // 61 minutes before midnight and 1 minute after: rounding each segment
+        // on its own (1.0167 + 0.0167) would overshoot the shift's 1.0333 hours
+        let shift = Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-13"),
+            start_time: make_datetime("2026-01-13", "22:59:00"),
+            end_time: make_datetime("2026-01-14", "00:01:00"),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        };
+
+        let segments = segment_by_day(&shift);
+        assert_eq!(segments[0].hours, dec("1.0167"));
+        assert_eq!(segments[1].hours, dec("0.0166"));
+
+        let segment_total: Decimal = segments.iter().map(|s| s.hours).sum();
+        assert_eq!(segment_total, dec("1.0333"));
+        assert_eq!(segment_total, shift.worked_hours());
+    }
+
     #[test]
     fn test_segments_ordered_chronologically() {
         let shift = Shift {
@@ -498,6 +576,10 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
@@ -514,6 +596,10 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
@@ -549,12 +635,58 @@ mod tests {
             start_time: make_datetime("2026-01-17", "09:00:00"),
             end_time: make_datetime("2026-01-17", "09:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let segments = segment_by_day(&shift);
         assert!(segments.is_empty());
     }
 
+    #[test]
+    fn test_break_spanning_midnight_is_deducted_from_both_days() {
+        let unpaid_break = Break {
+            start_time: make_datetime("2026-01-17", "23:45:00"),
+            end_time: make_datetime("2026-01-18", "00:15:00"),
+            is_paid: false,
+            kind: None,
+        };
+        let shift = Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-17"),
+            start_time: make_datetime("2026-01-17", "22:00:00"),
+            end_time: make_datetime("2026-01-18", "06:00:00"),
+            breaks: vec![unpaid_break.clone()],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        };
+
+        let segments = segment_by_day(&shift);
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].day_type, DayType::Saturday);
+        assert_eq!(segments[0].hours, dec("1.75"));
+        assert_eq!(segments[1].day_type, DayType::Sunday);
+        assert_eq!(segments[1].hours, dec("5.75"));
+        assert_eq!(segments[0].hours + segments[1].hours, shift.worked_hours());
+
+        // A paid break is worked time and is not deducted
+        let paid_shift = Shift {
+            breaks: vec![Break {
+                is_paid: true,
+                ..unpaid_break
+            }],
+            ..shift
+        };
+        let segments = segment_by_day(&paid_shift);
+        assert_eq!(segments[0].hours, dec("2"));
+        assert_eq!(segments[1].hours, dec("6"));
+    }
+
     #[test]
     fn test_day_type_display() {
         assert_eq!(format!("{}", DayType::Weekday), "Weekday");
@@ -0,0 +1,244 @@
+//! Early commencement loading functionality.
+//!
+//! Some awards pay a loading for shifts that commence very early in the
+//! morning (e.g. before 5am), separately from any weekend penalty. This module
+//! detects a shift commencing before the configured hour and pays the loading
+//! for every hour it is worked.
+//!
+//! The loading is paid on top of the ordinary, weekend or overtime pay for
+//! those hours, so it does not count towards any of the hour totals. The
+//! engine pays no night shift loading, so the loading is never weighed
+//! against one; an award paying both would take the higher of the two.
+
+use rust_decimal::Decimal;
+
+use crate::config::EarlyCommencementConfig;
+use crate::models::{AuditStep, PayCategory, PayLine, Shift};
+
+use super::rules::RuleInfo;
+
+/// The early commencement rule, for the rule catalogue; its clause is
+/// configured.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "early_commencement",
+    rule_name: "Early Commencement",
+    clause_ref: "configured",
+}];
+
+/// The result of checking a shift for early commencement.
+#[derive(Debug, Clone)]
+pub struct EarlyCommencementCheck {
+    /// The loading pay line, when the shift commenced early.
+    pub pay_line: Option<PayLine>,
+    /// The audit step recording the loading, or `None` when the shift did not
+    /// commence early.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Checks whether a shift commenced before the configured early hour and, if
+/// so, pays the early commencement loading.
+///
+/// A shift commences early when its start time on the day it starts is before
+/// `before`. Its `worked_hours` are then paid at `base_rate × penalty_rate` on
+/// a [`PayCategory::EarlyCommencementPenalty`] line. Shifts starting at or
+/// after `before` produce neither a pay line nor an audit step.
+///
+/// # Arguments
+///
+/// * `shift` - The shift to check
+/// * `worked_hours` - The shift's worked hours as paid, after any daylight
+///   saving adjustment and time rounding
+/// * `base_rate` - The employee's base hourly rate, used for the loading
+/// * `config` - The configured early hour and loading
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Example
+///
+/// ```
+/// use award_engine::calculation::check_early_commencement;
+/// use award_engine::config::EarlyCommencementConfig;
+/// use award_engine::models::{PayCategory, Shift};
+/// use chrono::{NaiveDate, NaiveTime};
+/// use rust_decimal::Decimal;
+///
+/// let date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
+/// let shift = Shift {
+///     id: "shift_001".to_string(),
+///     date,
+///     start_time: date.and_hms_opt(4, 0, 0).unwrap(),
+///     end_time: date.and_hms_opt(12, 0, 0).unwrap(),
+///     breaks: vec![],
+///     rate_override: None,
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
+/// };
+/// let config = EarlyCommencementConfig {
+///     clause: "X.Y".to_string(),
+///     before: NaiveTime::from_hms_opt(5, 0, 0).unwrap(),
+///     penalty_rate: Decimal::new(1, 1),
+/// };
+///
+/// let check =
+///     check_early_commencement(&shift, shift.worked_hours(), Decimal::from(30), &config, 1, true);
+///
+/// let line = check.pay_line.unwrap();
+/// assert_eq!(line.category, PayCategory::EarlyCommencementPenalty);
+/// assert_eq!(line.hours, Decimal::from(8));
+/// assert_eq!(line.amount, Decimal::from(24));
+/// ```
+pub fn check_early_commencement(
+    shift: &Shift,
+    worked_hours: Decimal,
+    base_rate: Decimal,
+    config: &EarlyCommencementConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> EarlyCommencementCheck {
+    let commences_at = shift.start_time.time();
+    if commences_at >= config.before {
+        return EarlyCommencementCheck {
+            pay_line: None,
+            audit_step: None,
+        };
+    }
+
+    let rate = base_rate * config.penalty_rate;
+    let amount = worked_hours * rate;
+
+    let audit_step = include_audit.then(|| AuditStep {
+        step_number,
+        rule_id: "early_commencement".to_string(),
+        rule_name: "Early Commencement".to_string(),
+        clause_ref: config.clause.clone(),
+        input: serde_json::json!({
+            "shift_id": shift.id,
+            "start_time": shift.start_time.to_string(),
+            "before": config.before.format("%H:%M").to_string(),
+            "worked_hours": worked_hours.normalize().to_string(),
+            "penalty_rate": config.penalty_rate.normalize().to_string()
+        }),
+        output: serde_json::json!({
+            "rate": rate.normalize().to_string(),
+            "amount": amount.normalize().to_string()
+        }),
+        reasoning: format!(
+            "Shift commenced at {}, before {}: {} hours × ${} = ${}",
+            commences_at.format("%H:%M"),
+            config.before.format("%H:%M"),
+            worked_hours.normalize(),
+            rate.normalize(),
+            amount.normalize()
+        ),
+    });
+
+    let pay_line = PayLine {
+        date: shift.date,
+        shift_id: shift.id.clone(),
+        category: PayCategory::EarlyCommencementPenalty,
+        hours: worked_hours,
+        rate,
+        amount,
+        clause_ref: config.clause.clone(),
+        reference: None,
+        cost_centre: None,
+    };
+
+    EarlyCommencementCheck {
+        pay_line: Some(pay_line),
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, NaiveTime};
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn shift(start: (u32, u32), end: (u32, u32)) -> Shift {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
+        Shift {
+            id: "shift_001".to_string(),
+            date,
+            start_time: date.and_hms_opt(start.0, start.1, 0).unwrap(),
+            end_time: date.and_hms_opt(end.0, end.1, 0).unwrap(),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    fn config() -> EarlyCommencementConfig {
+        EarlyCommencementConfig {
+            clause: "X.Y".to_string(),
+            before: NaiveTime::from_hms_opt(5, 0, 0).unwrap(),
+            penalty_rate: dec("0.1"),
+        }
+    }
+
+    #[test]
+    fn test_shift_commencing_at_half_past_four_is_paid_the_loading() {
+        let shift = shift((4, 30), (12, 30));
+
+        let check = check_early_commencement(
+            &shift,
+            shift.worked_hours(),
+            dec("28.54"),
+            &config(),
+            6,
+            true,
+        );
+
+        let line = check.pay_line.unwrap();
+        assert_eq!(line.category, PayCategory::EarlyCommencementPenalty);
+        assert_eq!(line.hours, dec("8"));
+        assert_eq!(line.rate, dec("2.854"));
+        assert_eq!(line.amount, dec("22.832")); // 8 × 28.54 × 0.1
+        assert_eq!(line.clause_ref, "X.Y");
+        let step = check.audit_step.unwrap();
+        assert_eq!(step.step_number, 6);
+        assert_eq!(step.rule_id, "early_commencement");
+        assert_eq!(step.input["before"], "05:00");
+    }
+
+    #[test]
+    fn test_shift_commencing_at_seven_is_not_paid_the_loading() {
+        let shift = shift((7, 0), (15, 0));
+
+        let check = check_early_commencement(
+            &shift,
+            shift.worked_hours(),
+            dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+
+        assert!(check.pay_line.is_none());
+        assert!(check.audit_step.is_none());
+    }
+
+    #[test]
+    fn test_shift_commencing_at_the_early_hour_is_not_paid_the_loading() {
+        let shift = shift((5, 0), (13, 0));
+
+        let check = check_early_commencement(
+            &shift,
+            shift.worked_hours(),
+            dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+
+        assert!(check.pay_line.is_none());
+    }
+}
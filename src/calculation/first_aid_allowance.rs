@@ -0,0 +1,216 @@
+//! First aid allowance calculation functionality.
+//!
+//! This module provides functions for calculating the weekly first aid
+//! allowance for employees appointed to perform first aid duty, as per
+//! clause 15 of the Aged Care Award 2010.
+
+use rust_decimal::Decimal;
+
+use crate::models::{AllowancePayment, AllowanceType, AuditStep, Employee};
+
+use super::rules::RuleInfo;
+
+/// The first aid allowance rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "first_aid_allowance",
+    rule_name: "First Aid Allowance",
+    clause_ref: FIRST_AID_ALLOWANCE_CLAUSE,
+}];
+
+/// The tag that enables first aid allowance for an employee.
+pub const FIRST_AID_ALLOWANCE_TAG: &str = "first_aid_allowance";
+
+/// The clause reference for first aid allowance.
+pub const FIRST_AID_ALLOWANCE_CLAUSE: &str = "15";
+
+/// The result of calculating first aid allowance, including the payment and audit step.
+#[derive(Debug, Clone)]
+pub struct FirstAidAllowanceResult {
+    /// The allowance payment, if the employee is eligible.
+    pub allowance: Option<AllowancePayment>,
+    /// The audit step recording this calculation, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Calculates first aid allowance for an employee appointed to first aid duty.
+///
+/// The allowance is paid to employees who have the `first_aid_allowance` tag,
+/// once for each award week in the pay period, so a fortnightly period pays
+/// it twice and a monthly period four or five times.
+///
+/// # Arguments
+///
+/// * `employee` - The employee to calculate allowance for
+/// * `weeks` - The number of award weeks in the pay period
+/// * `rate` - The weekly first aid allowance
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Returns
+///
+/// Returns a `FirstAidAllowanceResult` containing:
+/// - `Some(AllowancePayment)` if the employee has the first_aid_allowance tag
+/// - `None` if the employee does not have the tag
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::calculate_first_aid_allowance;
+/// use award_engine::models::{Employee, EmploymentType};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+/// use std::str::FromStr;
+///
+/// let employee = Employee {
+///     id: "emp_001".to_string(),
+///     employment_type: EmploymentType::FullTime,
+///     classification_code: "dce_level_3".to_string(),
+///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
+///     tags: vec!["first_aid_allowance".to_string()],
+/// };
+///
+/// let result =
+///     calculate_first_aid_allowance(&employee, 2, Decimal::from_str("18.93").unwrap(), 1, true);
+///
+/// let allowance = result.allowance.unwrap();
+/// assert_eq!(allowance.amount, Decimal::from_str("37.86").unwrap());
+/// ```
+pub fn calculate_first_aid_allowance(
+    employee: &Employee,
+    weeks: u32,
+    rate: Decimal,
+    step_number: u32,
+    include_audit: bool,
+) -> FirstAidAllowanceResult {
+    let has_tag = employee.tags.contains(&FIRST_AID_ALLOWANCE_TAG.to_string());
+
+    if !has_tag {
+        let audit_step = include_audit.then(|| AuditStep {
+            step_number,
+            rule_id: "first_aid_allowance".to_string(),
+            rule_name: "First Aid Allowance".to_string(),
+            clause_ref: FIRST_AID_ALLOWANCE_CLAUSE.to_string(),
+            input: serde_json::json!({
+                "employee_id": employee.id,
+                "has_first_aid_tag": false,
+                "weeks": weeks
+            }),
+            output: serde_json::json!({
+                "eligible": false,
+                "amount": "0.00"
+            }),
+            reasoning: "Employee does not have 'first_aid_allowance' tag - not eligible for first aid allowance".to_string(),
+        });
+
+        return FirstAidAllowanceResult {
+            allowance: None,
+            audit_step,
+        };
+    }
+
+    let units = Decimal::from(weeks);
+    let amount = units * rate;
+
+    let audit_step = include_audit.then(|| AuditStep {
+        step_number,
+        rule_id: "first_aid_allowance".to_string(),
+        rule_name: "First Aid Allowance".to_string(),
+        clause_ref: FIRST_AID_ALLOWANCE_CLAUSE.to_string(),
+        input: serde_json::json!({
+            "employee_id": employee.id,
+            "has_first_aid_tag": true,
+            "weeks": weeks,
+            "rate": rate.normalize().to_string()
+        }),
+        output: serde_json::json!({
+            "eligible": true,
+            "units": units.to_string(),
+            "amount": amount.normalize().to_string()
+        }),
+        reasoning: format!(
+            "{} week(s) × ${} = ${}",
+            weeks,
+            rate.normalize(),
+            amount.normalize()
+        ),
+    });
+
+    let allowance = AllowancePayment {
+        allowance_type: AllowanceType::FirstAid,
+        description: "First Aid Allowance".to_string(),
+        units,
+        rate,
+        amount,
+        clause_ref: FIRST_AID_ALLOWANCE_CLAUSE.to_string(),
+    };
+
+    FirstAidAllowanceResult {
+        allowance: Some(allowance),
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EmploymentType;
+    use chrono::NaiveDate;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn create_test_employee(tags: Vec<String>) -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type: EmploymentType::PartTime,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags,
+        }
+    }
+
+    #[test]
+    fn test_first_aid_allowance_paid_per_week() {
+        let employee = create_test_employee(vec!["first_aid_allowance".to_string()]);
+
+        let result = calculate_first_aid_allowance(&employee, 5, dec("18.93"), 3, true);
+
+        let allowance = result.allowance.unwrap();
+        assert_eq!(allowance.allowance_type, AllowanceType::FirstAid);
+        assert_eq!(allowance.units, dec("5"));
+        assert_eq!(allowance.rate, dec("18.93"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(allowance.amount, dec("94.65")); // 5 × 18.93
+        assert_eq!(step.step_number, 3);
+        assert_eq!(step.input["weeks"], 5);
+        assert_eq!(step.reasoning, "5 week(s) × $18.93 = $94.65");
+    }
+
+    #[test]
+    fn test_no_first_aid_tag() {
+        let employee = create_test_employee(vec!["laundry_allowance".to_string()]);
+
+        let result = calculate_first_aid_allowance(&employee, 2, dec("18.93"), 1, true);
+
+        assert!(result.allowance.is_none());
+        let step = result.audit_step.unwrap();
+        assert!(!step.output["eligible"].as_bool().unwrap());
+        assert!(
+            step.reasoning
+                .contains("does not have 'first_aid_allowance' tag")
+        );
+    }
+}
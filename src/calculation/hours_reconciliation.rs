@@ -0,0 +1,140 @@
+//! Worked-hours reconciliation.
+//!
+//! A shift's worked hours are split across ordinary, weekend penalty and
+//! overtime pay lines, segment by segment. This module checks that those
+//! lines add back up to the hours worked, so a bug in the splitting that
+//! drops or double-pays hours is reported rather than silently paid.
+
+use rust_decimal::Decimal;
+
+use crate::models::{AuditWarning, PayCategory, PayLine};
+
+/// The warning code for a shift whose paid hours differ from its worked hours.
+pub const HOURS_MISMATCH_WARNING: &str = "HOURS_MISMATCH";
+
+/// Checks that the hours paid for a shift equal the hours it was worked.
+///
+/// Only pay lines for time worked count towards the paid hours. A meal break
+/// penalty is paid on top of hours already paid, and a public holiday not
+/// worked is not tied to a shift, so neither is counted. Pay lines for other
+/// shifts are ignored.
+///
+/// `worked_hours` should be the shift's worked hours after any timezone and
+/// time rounding adjustments, as those are the hours the pay lines are split
+/// from.
+///
+/// Returns a warning when the hours differ, or `None` when they reconcile.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::reconcile_shift_hours;
+/// use award_engine::models::{PayCategory, PayLine};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let line = PayLine {
+///     date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
+///     shift_id: "shift_001".to_string(),
+///     category: PayCategory::Ordinary,
+///     hours: Decimal::from(7),
+///     rate: Decimal::new(2854, 2),
+///     amount: Decimal::new(19978, 2),
+///     clause_ref: "22.1".to_string(),
+///     reference: None,
+///     cost_centre: None,
+/// };
+///
+/// let warning = reconcile_shift_hours("shift_001", Decimal::from(8), &[line]);
+/// assert_eq!(warning.unwrap().code, "HOURS_MISMATCH");
+/// ```
+pub fn reconcile_shift_hours(
+    shift_id: &str,
+    worked_hours: Decimal,
+    pay_lines: &[PayLine],
+) -> Option<AuditWarning> {
+    let paid_hours: Decimal = pay_lines
+        .iter()
+        .filter(|line| line.shift_id == shift_id && is_worked_time(line.category))
+        .map(|line| line.hours)
+        .sum();
+
+    if paid_hours == worked_hours {
+        return None;
+    }
+
+    Some(AuditWarning {
+        code: HOURS_MISMATCH_WARNING.to_string(),
+        message: format!(
+            "Shift '{}' was paid for {} hours but worked {} hours",
+            shift_id,
+            paid_hours.normalize(),
+            worked_hours.normalize()
+        ),
+        severity: "high".to_string(),
+    })
+}
+
+/// Returns whether a pay category pays for time worked.
+pub(crate) fn is_worked_time(category: PayCategory) -> bool {
+    !matches!(
+        category,
+        PayCategory::PublicHolidayNotWorked
+            | PayCategory::MealBreakPenalty
+            | PayCategory::SpanPenalty
+            | PayCategory::MinimumDailyPay
+            | PayCategory::EarlyCommencementPenalty
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn line(shift_id: &str, category: PayCategory, hours: &str) -> PayLine {
+        PayLine {
+            date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
+            shift_id: shift_id.to_string(),
+            category,
+            hours: dec(hours),
+            rate: dec("28.54"),
+            amount: dec(hours) * dec("28.54"),
+            clause_ref: "X.Y".to_string(),
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_reconciled_hours_raise_no_warning() {
+        let lines = vec![
+            line("shift_001", PayCategory::Ordinary, "8"),
+            line("shift_001", PayCategory::Overtime150, "2"),
+            line("shift_001", PayCategory::Overtime200, "0.5"),
+            line("shift_001", PayCategory::MealBreakPenalty, "5.5"),
+            line("shift_002", PayCategory::Ordinary, "4"),
+        ];
+
+        assert!(reconcile_shift_hours("shift_001", dec("10.5"), &lines).is_none());
+    }
+
+    #[test]
+    fn test_dropped_hours_raise_a_warning() {
+        let lines = vec![line("shift_001", PayCategory::Saturday, "6")];
+
+        let warning = reconcile_shift_hours("shift_001", dec("7.5"), &lines).unwrap();
+
+        assert_eq!(warning.code, HOURS_MISMATCH_WARNING);
+        assert_eq!(warning.severity, "high");
+        assert_eq!(
+            warning.message,
+            "Shift 'shift_001' was paid for 6 hours but worked 7.5 hours"
+        );
+    }
+}
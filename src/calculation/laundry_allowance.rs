@@ -2,10 +2,23 @@
 //!
 //! This module provides functions for calculating laundry allowance
 //! for employees as per clause 15.2(b) of the Aged Care Award 2010.
+//!
+//! Where the award's `laundry_min_shift_hours` is set, only shifts worked for
+//! at least that long count towards the allowance; each shorter shift is
+//! recorded with [`laundry_shift_excluded_step`].
 
 use rust_decimal::Decimal;
 
-use crate::models::{AllowancePayment, AuditStep, Employee};
+use crate::models::{AllowancePayment, AllowanceType, AuditStep, Employee, Shift};
+
+use super::rules::RuleInfo;
+
+/// The laundry allowance rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "laundry_allowance",
+    rule_name: "Laundry Allowance",
+    clause_ref: LAUNDRY_ALLOWANCE_CLAUSE,
+}];
 
 /// The tag that enables laundry allowance for an employee.
 pub const LAUNDRY_ALLOWANCE_TAG: &str = "laundry_allowance";
@@ -18,8 +31,9 @@ pub const LAUNDRY_ALLOWANCE_CLAUSE: &str = "15.2(b)";
 pub struct LaundryAllowanceResult {
     /// The allowance payment, if the employee is eligible.
     pub allowance: Option<AllowancePayment>,
-    /// The audit step recording this calculation.
-    pub audit_step: AuditStep,
+    /// The audit step recording this calculation, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
 }
 
 /// Calculates laundry allowance for an employee based on the number of shifts worked.
@@ -34,6 +48,7 @@ pub struct LaundryAllowanceResult {
 /// * `per_shift_rate` - The allowance amount per shift (e.g., $0.32)
 /// * `weekly_cap` - The maximum allowance per week (e.g., $1.49)
 /// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
 ///
 /// # Returns
 ///
@@ -61,6 +76,9 @@ pub struct LaundryAllowanceResult {
 ///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
 ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
 ///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
 ///     tags: vec!["laundry_allowance".to_string()],
 /// };
 ///
@@ -70,6 +88,7 @@ pub struct LaundryAllowanceResult {
 ///     Decimal::from_str("0.32").unwrap(),
 ///     Decimal::from_str("1.49").unwrap(),
 ///     1,
+///     true,
 /// );
 ///
 /// assert!(result.allowance.is_some());
@@ -82,11 +101,12 @@ pub fn calculate_laundry_allowance(
     per_shift_rate: Decimal,
     weekly_cap: Decimal,
     step_number: u32,
+    include_audit: bool,
 ) -> LaundryAllowanceResult {
     let has_tag = employee.tags.contains(&LAUNDRY_ALLOWANCE_TAG.to_string());
 
     if !has_tag {
-        let audit_step = AuditStep {
+        let audit_step = include_audit.then(|| AuditStep {
             step_number,
             rule_id: "laundry_allowance".to_string(),
             rule_name: "Laundry Allowance".to_string(),
@@ -101,7 +121,7 @@ pub fn calculate_laundry_allowance(
                 "amount": "0.00"
             }),
             reasoning: "Employee does not have 'laundry_allowance' tag - not eligible for laundry allowance".to_string(),
-        };
+        });
 
         return LaundryAllowanceResult {
             allowance: None,
@@ -120,47 +140,49 @@ pub fn calculate_laundry_allowance(
         (uncapped_amount, false)
     };
 
-    let reasoning = if cap_applied {
-        format!(
-            "{} shifts × ${} = ${} (capped at weekly maximum ${})",
-            num_shifts,
-            per_shift_rate.normalize(),
-            amount.normalize(),
-            weekly_cap.normalize()
-        )
-    } else {
-        format!(
-            "{} shifts × ${} = ${}",
-            num_shifts,
-            per_shift_rate.normalize(),
-            amount.normalize()
-        )
-    };
+    let audit_step = include_audit.then(|| {
+        let reasoning = if cap_applied {
+            format!(
+                "{} shifts × ${} = ${} (capped at weekly maximum ${})",
+                num_shifts,
+                per_shift_rate.normalize(),
+                amount.normalize(),
+                weekly_cap.normalize()
+            )
+        } else {
+            format!(
+                "{} shifts × ${} = ${}",
+                num_shifts,
+                per_shift_rate.normalize(),
+                amount.normalize()
+            )
+        };
 
-    let audit_step = AuditStep {
-        step_number,
-        rule_id: "laundry_allowance".to_string(),
-        rule_name: "Laundry Allowance".to_string(),
-        clause_ref: LAUNDRY_ALLOWANCE_CLAUSE.to_string(),
-        input: serde_json::json!({
-            "employee_id": employee.id,
-            "has_laundry_tag": true,
-            "num_shifts": num_shifts,
-            "per_shift_rate": per_shift_rate.normalize().to_string(),
-            "weekly_cap": weekly_cap.normalize().to_string()
-        }),
-        output: serde_json::json!({
-            "eligible": true,
-            "units": units.normalize().to_string(),
-            "uncapped_amount": uncapped_amount.normalize().to_string(),
-            "amount": amount.normalize().to_string(),
-            "cap_applied": cap_applied
-        }),
-        reasoning,
-    };
+        AuditStep {
+            step_number,
+            rule_id: "laundry_allowance".to_string(),
+            rule_name: "Laundry Allowance".to_string(),
+            clause_ref: LAUNDRY_ALLOWANCE_CLAUSE.to_string(),
+            input: serde_json::json!({
+                "employee_id": employee.id,
+                "has_laundry_tag": true,
+                "num_shifts": num_shifts,
+                "per_shift_rate": per_shift_rate.normalize().to_string(),
+                "weekly_cap": weekly_cap.normalize().to_string()
+            }),
+            output: serde_json::json!({
+                "eligible": true,
+                "units": units.normalize().to_string(),
+                "uncapped_amount": uncapped_amount.normalize().to_string(),
+                "amount": amount.normalize().to_string(),
+                "cap_applied": cap_applied
+            }),
+            reasoning,
+        }
+    });
 
     let allowance = AllowancePayment {
-        allowance_type: "laundry".to_string(),
+        allowance_type: AllowanceType::Laundry,
         description: "Laundry Allowance".to_string(),
         units,
         rate: per_shift_rate,
@@ -174,6 +196,69 @@ pub fn calculate_laundry_allowance(
     }
 }
 
+/// Creates the audit step for a shift too short to count towards the laundry
+/// allowance.
+///
+/// A shift counts when its `worked_hours`, as paid after any daylight saving
+/// adjustment and time rounding, reach `min_shift_hours`; a shorter shift is left out of the number of shifts
+/// passed to [`calculate_laundry_allowance`], and this step records why.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::laundry_shift_excluded_step;
+/// use award_engine::models::Shift;
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
+/// let shift = Shift {
+///     id: "shift_001".to_string(),
+///     date,
+///     start_time: date.and_hms_opt(9, 0, 0).unwrap(),
+///     end_time: date.and_hms_opt(11, 0, 0).unwrap(),
+///     breaks: vec![],
+///     rate_override: None,
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
+/// };
+///
+/// let step = laundry_shift_excluded_step(&shift, shift.worked_hours(), Decimal::from(4), 1);
+///
+/// assert_eq!(step.rule_id, "laundry_allowance");
+/// assert_eq!(step.output["eligible"], false);
+/// ```
+pub fn laundry_shift_excluded_step(
+    shift: &Shift,
+    worked_hours: Decimal,
+    min_shift_hours: Decimal,
+    step_number: u32,
+) -> AuditStep {
+    AuditStep {
+        step_number,
+        rule_id: "laundry_allowance".to_string(),
+        rule_name: "Laundry Allowance".to_string(),
+        clause_ref: LAUNDRY_ALLOWANCE_CLAUSE.to_string(),
+        input: serde_json::json!({
+            "shift_id": shift.id,
+            "date": shift.date.to_string(),
+            "worked_hours": worked_hours.normalize().to_string(),
+            "min_shift_hours": min_shift_hours.normalize().to_string()
+        }),
+        output: serde_json::json!({
+            "eligible": false,
+            "amount": "0.00"
+        }),
+        reasoning: format!(
+            "Shift {} worked {} hours, less than the {} hours needed to count towards laundry allowance",
+            shift.id,
+            worked_hours.normalize(),
+            min_shift_hours.normalize()
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,6 +278,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags,
         }
     }
@@ -201,12 +289,12 @@ mod tests {
     #[test]
     fn test_la_001_one_shift_with_laundry_tag() {
         let employee = create_test_employee(vec!["laundry_allowance".to_string()]);
-        let result = calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 1);
+        let result = calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 1, true);
 
         assert!(result.allowance.is_some());
         let allowance = result.allowance.unwrap();
 
-        assert_eq!(allowance.allowance_type, "laundry");
+        assert_eq!(allowance.allowance_type, AllowanceType::Laundry);
         assert_eq!(allowance.description, "Laundry Allowance");
         assert_eq!(allowance.units, dec("1"));
         assert_eq!(allowance.rate, dec("0.32"));
@@ -214,32 +302,34 @@ mod tests {
         assert_eq!(allowance.clause_ref, "15.2(b)");
 
         // Verify audit step
-        assert_eq!(result.audit_step.rule_id, "laundry_allowance");
-        assert_eq!(result.audit_step.clause_ref, "15.2(b)");
-        assert!(result.audit_step.output["eligible"].as_bool().unwrap());
-        assert!(!result.audit_step.output["cap_applied"].as_bool().unwrap());
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.rule_id, "laundry_allowance");
+        assert_eq!(step.clause_ref, "15.2(b)");
+        assert!(step.output["eligible"].as_bool().unwrap());
+        assert!(!step.output["cap_applied"].as_bool().unwrap());
     }
 
     /// LA-002: 3 shifts with laundry tag
     #[test]
     fn test_la_002_three_shifts_with_laundry_tag() {
         let employee = create_test_employee(vec!["laundry_allowance".to_string()]);
-        let result = calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1);
+        let result = calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1, true);
 
         assert!(result.allowance.is_some());
         let allowance = result.allowance.unwrap();
 
         assert_eq!(allowance.units, dec("3"));
         assert_eq!(allowance.rate, dec("0.32"));
+        let step = result.audit_step.unwrap();
         assert_eq!(allowance.amount, dec("0.96")); // 3 * 0.32 = 0.96
-        assert!(!result.audit_step.output["cap_applied"].as_bool().unwrap());
+        assert!(!step.output["cap_applied"].as_bool().unwrap());
     }
 
     /// LA-003: 5 shifts hits cap
     #[test]
     fn test_la_003_five_shifts_hits_cap() {
         let employee = create_test_employee(vec!["laundry_allowance".to_string()]);
-        let result = calculate_laundry_allowance(&employee, 5, dec("0.32"), dec("1.49"), 1);
+        let result = calculate_laundry_allowance(&employee, 5, dec("0.32"), dec("1.49"), 1, true);
 
         assert!(result.allowance.is_some());
         let allowance = result.allowance.unwrap();
@@ -248,21 +338,17 @@ mod tests {
         assert_eq!(allowance.rate, dec("0.32"));
         // 5 * 0.32 = 1.60, capped at 1.49
         assert_eq!(allowance.amount, dec("1.49"));
-        assert!(result.audit_step.output["cap_applied"].as_bool().unwrap());
-        assert_eq!(
-            result.audit_step.output["uncapped_amount"]
-                .as_str()
-                .unwrap(),
-            "1.6"
-        );
-        assert!(result.audit_step.reasoning.contains("capped"));
+        let step = result.audit_step.unwrap();
+        assert!(step.output["cap_applied"].as_bool().unwrap());
+        assert_eq!(step.output["uncapped_amount"].as_str().unwrap(), "1.6");
+        assert!(step.reasoning.contains("capped"));
     }
 
     /// LA-004: 6 shifts exceeds cap
     #[test]
     fn test_la_004_six_shifts_exceeds_cap() {
         let employee = create_test_employee(vec!["laundry_allowance".to_string()]);
-        let result = calculate_laundry_allowance(&employee, 6, dec("0.32"), dec("1.49"), 1);
+        let result = calculate_laundry_allowance(&employee, 6, dec("0.32"), dec("1.49"), 1, true);
 
         assert!(result.allowance.is_some());
         let allowance = result.allowance.unwrap();
@@ -271,36 +357,35 @@ mod tests {
         assert_eq!(allowance.rate, dec("0.32"));
         // 6 * 0.32 = 1.92, capped at 1.49
         assert_eq!(allowance.amount, dec("1.49"));
-        assert!(result.audit_step.output["cap_applied"].as_bool().unwrap());
-        assert_eq!(
-            result.audit_step.output["uncapped_amount"]
-                .as_str()
-                .unwrap(),
-            "1.92"
-        );
+        let step = result.audit_step.unwrap();
+        assert!(step.output["cap_applied"].as_bool().unwrap());
+        assert_eq!(step.output["uncapped_amount"].as_str().unwrap(), "1.92");
     }
 
     /// LA-005: no laundry tag
     #[test]
     fn test_la_005_no_laundry_tag() {
         let employee = create_test_employee(vec![]); // No tags
-        let result = calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1);
+        let result = calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1, true);
 
         assert!(result.allowance.is_none());
-        assert!(!result.audit_step.output["eligible"].as_bool().unwrap());
-        assert!(result
-            .audit_step
-            .reasoning
-            .contains("does not have 'laundry_allowance' tag"));
+        let step = result.audit_step.unwrap();
+        assert!(!step.output["eligible"].as_bool().unwrap());
+        assert!(
+            step.reasoning
+                .contains("does not have 'laundry_allowance' tag")
+        );
     }
 
     #[test]
     fn test_employee_with_other_tags_but_not_laundry() {
-        let employee = create_test_employee(vec!["qualified".to_string(), "night_shift".to_string()]);
-        let result = calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1);
+        let employee =
+            create_test_employee(vec!["qualified".to_string(), "night_shift".to_string()]);
+        let result = calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1, true);
 
         assert!(result.allowance.is_none());
-        assert!(!result.audit_step.output["eligible"].as_bool().unwrap());
+        let step = result.audit_step.unwrap();
+        assert!(!step.output["eligible"].as_bool().unwrap());
     }
 
     #[test]
@@ -310,7 +395,7 @@ mod tests {
             "laundry_allowance".to_string(),
             "night_shift".to_string(),
         ]);
-        let result = calculate_laundry_allowance(&employee, 2, dec("0.32"), dec("1.49"), 1);
+        let result = calculate_laundry_allowance(&employee, 2, dec("0.32"), dec("1.49"), 1, true);
 
         assert!(result.allowance.is_some());
         let allowance = result.allowance.unwrap();
@@ -320,15 +405,16 @@ mod tests {
     #[test]
     fn test_audit_step_has_correct_step_number() {
         let employee = create_test_employee(vec!["laundry_allowance".to_string()]);
-        let result = calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 5);
+        let result = calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 5, true);
 
-        assert_eq!(result.audit_step.step_number, 5);
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 5);
     }
 
     #[test]
     fn test_zero_shifts_returns_zero_amount() {
         let employee = create_test_employee(vec!["laundry_allowance".to_string()]);
-        let result = calculate_laundry_allowance(&employee, 0, dec("0.32"), dec("1.49"), 1);
+        let result = calculate_laundry_allowance(&employee, 0, dec("0.32"), dec("1.49"), 1, true);
 
         assert!(result.allowance.is_some());
         let allowance = result.allowance.unwrap();
@@ -342,13 +428,14 @@ mod tests {
         // Let's test with values that hit cap exactly
         let employee = create_test_employee(vec!["laundry_allowance".to_string()]);
         // Using a rate where 3 shifts exactly equals the cap
-        let result = calculate_laundry_allowance(&employee, 3, dec("0.50"), dec("1.50"), 1);
+        let result = calculate_laundry_allowance(&employee, 3, dec("0.50"), dec("1.50"), 1, true);
 
         assert!(result.allowance.is_some());
         let allowance = result.allowance.unwrap();
         // 3 * 0.50 = 1.50, exactly at cap - should NOT apply cap
         assert_eq!(allowance.amount, dec("1.50"));
-        assert!(!result.audit_step.output["cap_applied"].as_bool().unwrap());
+        let step = result.audit_step.unwrap();
+        assert!(!step.output["cap_applied"].as_bool().unwrap());
     }
 
     #[test]
@@ -356,7 +443,7 @@ mod tests {
         let mut employee = create_test_employee(vec!["laundry_allowance".to_string()]);
         employee.employment_type = EmploymentType::Casual;
 
-        let result = calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1);
+        let result = calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1, true);
 
         assert!(result.allowance.is_some());
         let allowance = result.allowance.unwrap();
@@ -390,6 +477,9 @@ mod integration_tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags,
         }
     }
@@ -399,6 +489,7 @@ mod integration_tests {
             start_date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
             end_date: NaiveDate::from_ymd_opt(2026, 1, 19).unwrap(),
             public_holidays: vec![],
+            period_type: None,
         }
     }
 
@@ -411,6 +502,8 @@ mod integration_tests {
             rate: dec("28.54"),
             amount,
             clause_ref: "22.1".to_string(),
+            reference: None,
+            cost_centre: None,
         }
     }
 
@@ -430,7 +523,8 @@ mod integration_tests {
         let pay_line = create_ordinary_pay_line("shift_001", shift_date, dec("228.32"));
 
         // Calculate laundry allowance for 1 shift
-        let laundry_result = calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 4);
+        let laundry_result =
+            calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 4, true);
 
         // Build the calculation result
         let pay_lines = vec![pay_line];
@@ -448,19 +542,32 @@ mod integration_tests {
             calculation_id: Uuid::new_v4(),
             timestamp: Utc::now(),
             engine_version: "1.0.0".to_string(),
+            schema_version: "1.0.0".to_string(),
+            build_metadata: None,
+            correlation_id: None,
             employee_id: employee.id.clone(),
             pay_period,
             pay_lines,
+            shift_breakdown: vec![],
+            overtime_detail: vec![],
             allowances,
+            applied_clauses: vec![],
+            unapplied_rules: None,
             totals: PayTotals {
                 gross_pay,
                 ordinary_hours: dec("8.0"),
                 overtime_hours: dec("0"),
                 penalty_hours: dec("0"),
+                total_hours_worked: dec("8.0"),
                 allowances_total,
+                allowances_by_type: Default::default(),
+                gross_by_category: Default::default(),
             },
+            accruals: None,
+            input: None,
+            estimate: false,
             audit_trace: AuditTrace {
-                steps: vec![laundry_result.audit_step],
+                steps: laundry_result.audit_step.into_iter().collect(),
                 warnings: vec![],
                 duration_us: 1000,
             },
@@ -471,14 +578,12 @@ mod integration_tests {
         assert_eq!(result.allowances.len(), 1);
 
         // Verify pay_lines_total
-        let calculated_pay_lines_total: Decimal =
-            result.pay_lines.iter().map(|pl| pl.amount).sum();
+        let calculated_pay_lines_total: Decimal = result.pay_lines.iter().map(|pl| pl.amount).sum();
         assert_eq!(calculated_pay_lines_total, dec("228.32"));
 
         // Verify allowances_total
         assert_eq!(result.totals.allowances_total, dec("0.32"));
-        let calculated_allowances_total: Decimal =
-            result.allowances.iter().map(|a| a.amount).sum();
+        let calculated_allowances_total: Decimal = result.allowances.iter().map(|a| a.amount).sum();
         assert_eq!(calculated_allowances_total, dec("0.32"));
 
         // Verify gross_pay includes allowances
@@ -490,16 +595,18 @@ mod integration_tests {
 
         // Verify allowance details
         let allowance = &result.allowances[0];
-        assert_eq!(allowance.allowance_type, "laundry");
+        assert_eq!(allowance.allowance_type, AllowanceType::Laundry);
         assert_eq!(allowance.description, "Laundry Allowance");
         assert_eq!(allowance.clause_ref, "15.2(b)");
 
         // Verify audit trace includes allowance calculation
-        assert!(result
-            .audit_trace
-            .steps
-            .iter()
-            .any(|s| s.rule_id == "laundry_allowance"));
+        assert!(
+            result
+                .audit_trace
+                .steps
+                .iter()
+                .any(|s| s.rule_id == "laundry_allowance")
+        );
     }
 
     /// CRAL-002: multiple shifts hit laundry cap
@@ -526,12 +633,15 @@ mod integration_tests {
                 rate: dec("35.675"),
                 amount: dec("285.40"),
                 clause_ref: "22.1".to_string(),
+                reference: None,
+                cost_centre: None,
             };
             pay_lines.push(pay_line);
         }
 
         // Calculate laundry allowance for 5 shifts (should hit cap)
-        let laundry_result = calculate_laundry_allowance(&employee, 5, dec("0.32"), dec("1.49"), 1);
+        let laundry_result =
+            calculate_laundry_allowance(&employee, 5, dec("0.32"), dec("1.49"), 1, true);
 
         let allowances = match laundry_result.allowance {
             Some(a) => vec![a],
@@ -547,19 +657,32 @@ mod integration_tests {
             calculation_id: Uuid::new_v4(),
             timestamp: Utc::now(),
             engine_version: "1.0.0".to_string(),
+            schema_version: "1.0.0".to_string(),
+            build_metadata: None,
+            correlation_id: None,
             employee_id: employee.id.clone(),
             pay_period,
             pay_lines,
+            shift_breakdown: vec![],
+            overtime_detail: vec![],
             allowances,
+            applied_clauses: vec![],
+            unapplied_rules: None,
             totals: PayTotals {
                 gross_pay,
                 ordinary_hours: dec("40.0"),
                 overtime_hours: dec("0"),
                 penalty_hours: dec("0"),
+                total_hours_worked: dec("40.0"),
                 allowances_total,
+                allowances_by_type: Default::default(),
+                gross_by_category: Default::default(),
             },
+            accruals: None,
+            input: None,
+            estimate: false,
             audit_trace: AuditTrace {
-                steps: vec![laundry_result.audit_step],
+                steps: laundry_result.audit_step.into_iter().collect(),
                 warnings: vec![],
                 duration_us: 1000,
             },
@@ -569,8 +692,7 @@ mod integration_tests {
         // 5 shifts * $0.32 = $1.60, but cap is $1.49
         assert_eq!(result.totals.allowances_total, dec("1.49"));
 
-        let calculated_allowances_total: Decimal =
-            result.allowances.iter().map(|a| a.amount).sum();
+        let calculated_allowances_total: Decimal = result.allowances.iter().map(|a| a.amount).sum();
         assert_eq!(calculated_allowances_total, dec("1.49"));
 
         // Verify the cap was applied
@@ -603,7 +725,8 @@ mod integration_tests {
         let pay_line = create_ordinary_pay_line("shift_001", shift_date, dec("228.32"));
 
         // Calculate laundry allowance - should return None
-        let laundry_result = calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 4);
+        let laundry_result =
+            calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 4, true);
 
         // No allowance should be returned
         assert!(laundry_result.allowance.is_none());
@@ -620,19 +743,32 @@ mod integration_tests {
             calculation_id: Uuid::new_v4(),
             timestamp: Utc::now(),
             engine_version: "1.0.0".to_string(),
+            schema_version: "1.0.0".to_string(),
+            build_metadata: None,
+            correlation_id: None,
             employee_id: employee.id.clone(),
             pay_period,
             pay_lines,
+            shift_breakdown: vec![],
+            overtime_detail: vec![],
             allowances,
+            applied_clauses: vec![],
+            unapplied_rules: None,
             totals: PayTotals {
                 gross_pay,
                 ordinary_hours: dec("8.0"),
                 overtime_hours: dec("0"),
                 penalty_hours: dec("0"),
+                total_hours_worked: dec("8.0"),
                 allowances_total,
+                allowances_by_type: Default::default(),
+                gross_by_category: Default::default(),
             },
+            accruals: None,
+            input: None,
+            estimate: false,
             audit_trace: AuditTrace {
-                steps: vec![laundry_result.audit_step],
+                steps: laundry_result.audit_step.into_iter().collect(),
                 warnings: vec![],
                 duration_us: 1000,
             },
@@ -649,11 +785,13 @@ mod integration_tests {
         assert_eq!(result.totals.gross_pay, pay_lines_total);
 
         // Verify audit trace still records the allowance check (even though not eligible)
-        assert!(result
-            .audit_trace
-            .steps
-            .iter()
-            .any(|s| s.rule_id == "laundry_allowance"));
+        assert!(
+            result
+                .audit_trace
+                .steps
+                .iter()
+                .any(|s| s.rule_id == "laundry_allowance")
+        );
     }
 
     /// Test that allowances appear after pay lines in the result structure
@@ -668,7 +806,8 @@ mod integration_tests {
         let shift_date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
         let pay_line = create_ordinary_pay_line("shift_001", shift_date, dec("228.32"));
 
-        let laundry_result = calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 4);
+        let laundry_result =
+            calculate_laundry_allowance(&employee, 1, dec("0.32"), dec("1.49"), 4, true);
         let allowances = match laundry_result.allowance {
             Some(a) => vec![a],
             None => vec![],
@@ -678,19 +817,32 @@ mod integration_tests {
             calculation_id: Uuid::new_v4(),
             timestamp: Utc::now(),
             engine_version: "1.0.0".to_string(),
+            schema_version: "1.0.0".to_string(),
+            build_metadata: None,
+            correlation_id: None,
             employee_id: employee.id.clone(),
             pay_period,
             pay_lines: vec![pay_line],
+            shift_breakdown: vec![],
+            overtime_detail: vec![],
             allowances,
+            applied_clauses: vec![],
+            unapplied_rules: None,
             totals: PayTotals {
                 gross_pay: dec("228.64"),
                 ordinary_hours: dec("8.0"),
                 overtime_hours: dec("0"),
                 penalty_hours: dec("0"),
+                total_hours_worked: dec("8.0"),
                 allowances_total: dec("0.32"),
+                allowances_by_type: Default::default(),
+                gross_by_category: Default::default(),
             },
+            accruals: None,
+            input: None,
+            estimate: false,
             audit_trace: AuditTrace {
-                steps: vec![laundry_result.audit_step],
+                steps: laundry_result.audit_step.into_iter().collect(),
                 warnings: vec![],
                 duration_us: 1000,
             },
@@ -738,7 +890,8 @@ mod integration_tests {
         ];
 
         // Calculate laundry allowance for 3 shifts
-        let laundry_result = calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1);
+        let laundry_result =
+            calculate_laundry_allowance(&employee, 3, dec("0.32"), dec("1.49"), 1, true);
         let allowances = match laundry_result.allowance {
             Some(a) => vec![a],
             None => vec![],
@@ -752,19 +905,32 @@ mod integration_tests {
             calculation_id: Uuid::new_v4(),
             timestamp: Utc::now(),
             engine_version: "1.0.0".to_string(),
+            schema_version: "1.0.0".to_string(),
+            build_metadata: None,
+            correlation_id: None,
             employee_id: employee.id.clone(),
             pay_period,
             pay_lines,
+            shift_breakdown: vec![],
+            overtime_detail: vec![],
             allowances,
+            applied_clauses: vec![],
+            unapplied_rules: None,
             totals: PayTotals {
                 gross_pay,
                 ordinary_hours: dec("24.0"),
                 overtime_hours: dec("0"),
                 penalty_hours: dec("0"),
+                total_hours_worked: dec("24.0"),
                 allowances_total,
+                allowances_by_type: Default::default(),
+                gross_by_category: Default::default(),
             },
+            accruals: None,
+            input: None,
+            estimate: false,
             audit_trace: AuditTrace {
-                steps: vec![laundry_result.audit_step],
+                steps: laundry_result.audit_step.into_iter().collect(),
                 warnings: vec![],
                 duration_us: 1000,
             },
@@ -0,0 +1,257 @@
+//! Leave accrual functionality.
+//!
+//! This module calculates the annual and personal leave an employee accrues
+//! on the ordinary hours paid in a pay period, so payroll systems can record
+//! leave balances alongside pay. The accrual rates are configured, so awards
+//! and enterprise agreements with different entitlements are supported.
+
+use rust_decimal::Decimal;
+
+use crate::config::{AccrualRate, LeaveAccrualConfig};
+use crate::models::{AuditStep, Employee, HOURS_PRECISION, LeaveAccruals};
+
+use super::rules::RuleInfo;
+
+/// The leave accrual rule, for the rule catalogue; its clause is configured.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "leave_accrual",
+    rule_name: "Leave Accrual",
+    clause_ref: "configured",
+}];
+
+/// The result of calculating leave accrual, including the accruals and audit step.
+#[derive(Debug, Clone)]
+pub struct LeaveAccrualResult {
+    /// The leave accrued in the pay period.
+    pub accruals: LeaveAccruals,
+    /// The audit step recording this calculation, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Calculates the annual and personal leave accrued on the ordinary hours paid.
+///
+/// Each kind of leave accrues as `accrual_hours × hours ÷ per_ordinary_hours`
+/// of its configured rate, rounded to [`HOURS_PRECISION`]. With the National
+/// Employment Standards rates of 1 hour per 13 ordinary hours (annual leave)
+/// and 1 hour per 26 (personal leave), a full-time employee working 1976
+/// ordinary hours a year accrues 152 hours (4 weeks) and 76 hours (10 days).
+/// Casual employees accrue no leave, so both are zero.
+///
+/// # Arguments
+///
+/// * `employee` - The employee to calculate accruals for
+/// * `accrual_hours` - The hours paid in the pay period that leave accrues
+///   on (ordinary, weekend and public holiday not worked hours)
+/// * `config` - The configured accrual rates
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::calculate_leave_accrual;
+/// use award_engine::config::{AccrualRate, LeaveAccrualConfig};
+/// use award_engine::models::{Employee, EmploymentType};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let employee = Employee {
+///     id: "emp_001".to_string(),
+///     employment_type: EmploymentType::FullTime,
+///     classification_code: "dce_level_3".to_string(),
+///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
+///     tags: vec![],
+/// };
+/// let config = LeaveAccrualConfig {
+///     clause: "NES".to_string(),
+///     annual_leave: AccrualRate {
+///         hours: Decimal::ONE,
+///         per_ordinary_hours: Decimal::from(13),
+///     },
+///     personal_leave: AccrualRate {
+///         hours: Decimal::ONE,
+///         per_ordinary_hours: Decimal::from(26),
+///     },
+///     public_holiday_in_lieu: None,
+/// };
+///
+/// let result = calculate_leave_accrual(&employee, Decimal::from(52), &config, 1, true);
+///
+/// assert_eq!(result.accruals.annual_leave_hours, Decimal::from(4));
+/// assert_eq!(result.accruals.personal_leave_hours, Decimal::from(2));
+/// ```
+pub fn calculate_leave_accrual(
+    employee: &Employee,
+    accrual_hours: Decimal,
+    config: &LeaveAccrualConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> LeaveAccrualResult {
+    let casual = employee.employment_type.is_casual();
+    let accrue = |rate: &AccrualRate| {
+        if casual {
+            Decimal::ZERO
+        } else {
+            rate.accrue(accrual_hours)
+                .round_dp(HOURS_PRECISION)
+                .normalize()
+        }
+    };
+    let accruals = LeaveAccruals {
+        accrual_hours: accrual_hours.normalize(),
+        annual_leave_hours: accrue(&config.annual_leave),
+        personal_leave_hours: accrue(&config.personal_leave),
+        days_in_lieu: vec![],
+    };
+
+    let audit_step = include_audit.then(|| {
+        let reasoning = if casual {
+            format!(
+                "Casual employees accrue no annual or personal leave ({} accrual hours paid)",
+                accruals.accrual_hours
+            )
+        } else {
+            format!(
+                "{} accrual hours × {}/{} = {} hours annual leave; {} accrual hours × {}/{} = {} hours personal leave",
+                accruals.accrual_hours,
+                config.annual_leave.hours.normalize(),
+                config.annual_leave.per_ordinary_hours.normalize(),
+                accruals.annual_leave_hours,
+                accruals.accrual_hours,
+                config.personal_leave.hours.normalize(),
+                config.personal_leave.per_ordinary_hours.normalize(),
+                accruals.personal_leave_hours
+            )
+        };
+        AuditStep {
+            step_number,
+            rule_id: "leave_accrual".to_string(),
+            rule_name: "Leave Accrual".to_string(),
+            clause_ref: config.clause.clone(),
+            input: serde_json::json!({
+                "employee_id": employee.id,
+                "employment_type": employee.employment_type.as_str(),
+                "accrual_hours": accruals.accrual_hours.to_string(),
+                "annual_leave_rate": format!(
+                    "{}/{}",
+                    config.annual_leave.hours.normalize(),
+                    config.annual_leave.per_ordinary_hours.normalize()
+                ),
+                "personal_leave_rate": format!(
+                    "{}/{}",
+                    config.personal_leave.hours.normalize(),
+                    config.personal_leave.per_ordinary_hours.normalize()
+                )
+            }),
+            output: serde_json::json!({
+                "annual_leave_hours": accruals.annual_leave_hours.to_string(),
+                "personal_leave_hours": accruals.personal_leave_hours.to_string()
+            }),
+            reasoning,
+        }
+    });
+
+    LeaveAccrualResult {
+        accruals,
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EmploymentType;
+    use chrono::NaiveDate;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn employee(employment_type: EmploymentType) -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn nes() -> LeaveAccrualConfig {
+        LeaveAccrualConfig {
+            clause: "NES".to_string(),
+            annual_leave: AccrualRate {
+                hours: Decimal::ONE,
+                per_ordinary_hours: Decimal::from(13),
+            },
+            personal_leave: AccrualRate {
+                hours: Decimal::ONE,
+                per_ordinary_hours: Decimal::from(26),
+            },
+            public_holiday_in_lieu: None,
+        }
+    }
+
+    #[test]
+    fn test_full_time_fortnight_accrues_at_nes_rates() {
+        let result = calculate_leave_accrual(
+            &employee(EmploymentType::FullTime),
+            dec("76"),
+            &nes(),
+            7,
+            true,
+        );
+
+        // 76 / 13 = 5.846153..., 76 / 26 = 2.923076...
+        assert_eq!(result.accruals.accrual_hours, dec("76"));
+        assert_eq!(result.accruals.annual_leave_hours, dec("5.8462"));
+        assert_eq!(result.accruals.personal_leave_hours, dec("2.9231"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 7);
+        assert_eq!(step.clause_ref, "NES");
+        assert_eq!(step.input["annual_leave_rate"], "1/13");
+    }
+
+    #[test]
+    fn test_a_year_of_ordinary_hours_accrues_the_full_entitlement() {
+        let result = calculate_leave_accrual(
+            &employee(EmploymentType::PartTime),
+            dec("1976"),
+            &nes(),
+            1,
+            true,
+        );
+
+        assert_eq!(result.accruals.annual_leave_hours, dec("152"));
+        assert_eq!(result.accruals.personal_leave_hours, dec("76"));
+    }
+
+    #[test]
+    fn test_casual_employees_accrue_no_leave() {
+        let result = calculate_leave_accrual(
+            &employee(EmploymentType::Casual),
+            dec("38"),
+            &nes(),
+            1,
+            true,
+        );
+
+        assert_eq!(result.accruals.accrual_hours, dec("38"));
+        assert_eq!(result.accruals.annual_leave_hours, Decimal::ZERO);
+        assert_eq!(result.accruals.personal_leave_hours, Decimal::ZERO);
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("Casual employees"));
+    }
+}
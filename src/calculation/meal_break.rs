@@ -0,0 +1,342 @@
+//! Meal break compliance functionality.
+//!
+//! This module checks that shifts longer than the configured length include a
+//! meal break. A shift worked through without one raises a warning and, when
+//! the award attaches a penalty to missed meal breaks, is paid that penalty
+//! for the hours worked past the point the break was due.
+//!
+//! The penalty is paid on top of the ordinary, weekend or overtime pay for
+//! those hours, so it does not count towards any of the hour totals.
+
+use chrono::Duration;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::config::MealBreakConfig;
+use crate::models::{AuditStep, AuditWarning, PayCategory, PayLine, Shift};
+
+use super::rules::RuleInfo;
+
+/// The meal break rule, for the rule catalogue; its clause is configured.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "meal_break",
+    rule_name: "Meal Break",
+    clause_ref: "configured",
+}];
+
+/// The warning code for a long shift worked without a meal break.
+pub const NO_MEAL_BREAK_WARNING: &str = "NO_MEAL_BREAK";
+
+/// The result of checking a shift for a meal break.
+#[derive(Debug, Clone)]
+pub struct MealBreakCheck {
+    /// A warning when a meal break was due but not taken.
+    pub warning: Option<AuditWarning>,
+    /// The penalty pay line, when a meal break was missed and a penalty rate
+    /// is configured.
+    pub pay_line: Option<PayLine>,
+    /// The audit step recording the check, or `None` when the shift is not
+    /// long enough to require a meal break.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Checks that a shift long enough to require a meal break included one.
+///
+/// A meal break is required when the shift's worked hours exceed
+/// `max_hours_without_break`. It qualifies when it is unpaid, lasts at least
+/// `min_break_minutes` and starts no later than `max_hours_without_break`
+/// after the shift starts.
+///
+/// # Arguments
+///
+/// * `shift` - The shift to check
+/// * `base_rate` - The employee's base hourly rate, used for the penalty
+/// * `config` - The configured meal break requirement
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Example
+///
+/// ```
+/// use award_engine::calculation::check_meal_break_taken;
+/// use award_engine::config::MealBreakConfig;
+/// use award_engine::models::Shift;
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
+/// let shift = Shift {
+///     id: "shift_001".to_string(),
+///     date,
+///     start_time: date.and_hms_opt(8, 0, 0).unwrap(),
+///     end_time: date.and_hms_opt(17, 0, 0).unwrap(),
+///     breaks: vec![],
+///     rate_override: None,
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
+/// };
+/// let config = MealBreakConfig {
+///     clause: "X.Y".to_string(),
+///     max_hours_without_break: Decimal::from(5),
+///     min_break_minutes: 30,
+///     penalty_rate: None,
+/// };
+///
+/// let check = check_meal_break_taken(&shift, Decimal::new(2854, 2), &config, 1, true);
+///
+/// assert_eq!(check.warning.unwrap().code, "NO_MEAL_BREAK");
+/// assert!(check.pay_line.is_none());
+/// ```
+pub fn check_meal_break_taken(
+    shift: &Shift,
+    base_rate: Decimal,
+    config: &MealBreakConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> MealBreakCheck {
+    let worked_hours = shift.worked_hours();
+    let max_hours = config.max_hours_without_break;
+    if worked_hours <= max_hours {
+        return MealBreakCheck {
+            warning: None,
+            pay_line: None,
+            audit_step: None,
+        };
+    }
+
+    let due_minutes = (max_hours * Decimal::from(60))
+        .round()
+        .to_i64()
+        .unwrap_or(i64::MAX);
+    let due_at = shift
+        .start_time
+        .checked_add_signed(Duration::minutes(due_minutes))
+        .unwrap_or(shift.end_time);
+    let meal_break = shift.breaks.iter().find(|brk| {
+        !brk.is_paid
+            && (brk.end_time - brk.start_time).num_minutes() >= config.min_break_minutes
+            && brk.start_time <= due_at
+    });
+
+    if let Some(meal_break) = meal_break {
+        let audit_step = include_audit.then(|| AuditStep {
+            step_number,
+            rule_id: "meal_break".to_string(),
+            rule_name: "Meal Break".to_string(),
+            clause_ref: config.clause.clone(),
+            input: serde_json::json!({
+                "shift_id": shift.id,
+                "worked_hours": worked_hours.normalize().to_string(),
+                "max_hours_without_break": max_hours.normalize().to_string(),
+                "min_break_minutes": config.min_break_minutes
+            }),
+            output: serde_json::json!({
+                "meal_break_taken": true,
+                "break_start": meal_break.start_time.to_string()
+            }),
+            reasoning: format!(
+                "Meal break taken at {}, within {} hours of the shift starting",
+                meal_break.start_time.time(),
+                max_hours.normalize()
+            ),
+        });
+        return MealBreakCheck {
+            warning: None,
+            pay_line: None,
+            audit_step,
+        };
+    }
+
+    let hours_past_due = worked_hours - max_hours;
+    let warning = AuditWarning {
+        code: NO_MEAL_BREAK_WARNING.to_string(),
+        message: format!(
+            "Shift '{}' worked {} hours without an unpaid meal break of at least {} minutes; a break was due after {} hours",
+            shift.id,
+            worked_hours.normalize(),
+            config.min_break_minutes,
+            max_hours.normalize()
+        ),
+        severity: "medium".to_string(),
+    };
+
+    let pay_line = config.penalty_rate.map(|penalty_rate| {
+        let rate = base_rate * penalty_rate;
+        PayLine {
+            date: shift.date,
+            shift_id: shift.id.clone(),
+            category: PayCategory::MealBreakPenalty,
+            hours: hours_past_due,
+            rate,
+            amount: hours_past_due * rate,
+            clause_ref: config.clause.clone(),
+            reference: None,
+            cost_centre: None,
+        }
+    });
+
+    let audit_step = include_audit.then(|| {
+        let reasoning = match &pay_line {
+            Some(line) => format!(
+                "No meal break taken within {} hours: {} hours past the break × ${} = ${}",
+                max_hours.normalize(),
+                hours_past_due.normalize(),
+                line.rate.normalize(),
+                line.amount.normalize()
+            ),
+            None => format!(
+                "No meal break taken within {} hours: warning only, no penalty is configured",
+                max_hours.normalize()
+            ),
+        };
+        AuditStep {
+            step_number,
+            rule_id: "meal_break".to_string(),
+            rule_name: "Meal Break".to_string(),
+            clause_ref: config.clause.clone(),
+            input: serde_json::json!({
+                "shift_id": shift.id,
+                "worked_hours": worked_hours.normalize().to_string(),
+                "max_hours_without_break": max_hours.normalize().to_string(),
+                "min_break_minutes": config.min_break_minutes,
+                "penalty_rate": config.penalty_rate.map(|r| r.normalize().to_string())
+            }),
+            output: serde_json::json!({
+                "meal_break_taken": false,
+                "hours_past_due": hours_past_due.normalize().to_string(),
+                "amount": pay_line
+                    .as_ref()
+                    .map_or(Decimal::ZERO, |line| line.amount)
+                    .normalize()
+                    .to_string()
+            }),
+            reasoning,
+        }
+    });
+
+    MealBreakCheck {
+        warning: Some(warning),
+        pay_line,
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Break;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn datetime(time: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(&format!("2026-01-13T{}", time), "%Y-%m-%dT%H:%M:%S").unwrap()
+    }
+
+    fn shift(start: &str, end: &str, breaks: Vec<Break>) -> Shift {
+        Shift {
+            id: "shift_001".to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
+            start_time: datetime(start),
+            end_time: datetime(end),
+            breaks,
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    fn unpaid_break(start: &str, end: &str) -> Break {
+        Break {
+            start_time: datetime(start),
+            end_time: datetime(end),
+            is_paid: false,
+            kind: None,
+        }
+    }
+
+    fn config(penalty_rate: Option<Decimal>) -> MealBreakConfig {
+        MealBreakConfig {
+            clause: "X.Y".to_string(),
+            max_hours_without_break: dec("5"),
+            min_break_minutes: 30,
+            penalty_rate,
+        }
+    }
+
+    #[test]
+    fn test_nine_hour_shift_without_break_warns() {
+        let shift = shift("08:00:00", "17:00:00", vec![]);
+
+        let check = check_meal_break_taken(&shift, dec("28.54"), &config(None), 4, true);
+
+        let warning = check.warning.unwrap();
+        assert_eq!(warning.code, NO_MEAL_BREAK_WARNING);
+        assert!(warning.message.contains("Shift 'shift_001' worked 9 hours"));
+        assert!(check.pay_line.is_none());
+        let step = check.audit_step.unwrap();
+        assert_eq!(step.step_number, 4);
+        assert_eq!(step.output["meal_break_taken"], false);
+        assert_eq!(step.output["hours_past_due"], "4");
+    }
+
+    #[test]
+    fn test_missed_break_pays_configured_penalty() {
+        let shift = shift("08:00:00", "17:00:00", vec![]);
+
+        let check =
+            check_meal_break_taken(&shift, dec("28.54"), &config(Some(dec("0.5"))), 1, true);
+
+        assert!(check.warning.is_some());
+        let line = check.pay_line.unwrap();
+        assert_eq!(line.category, PayCategory::MealBreakPenalty);
+        assert_eq!(line.hours, dec("4"));
+        assert_eq!(line.rate, dec("14.27"));
+        assert_eq!(line.amount, dec("57.08")); // 4 × 28.54 × 0.5
+    }
+
+    #[test]
+    fn test_unpaid_break_before_due_point_satisfies_requirement() {
+        let shift = shift(
+            "08:00:00",
+            "17:30:00",
+            vec![unpaid_break("12:30:00", "13:00:00")],
+        );
+
+        let check =
+            check_meal_break_taken(&shift, dec("28.54"), &config(Some(dec("0.5"))), 1, true);
+
+        assert!(check.warning.is_none());
+        assert!(check.pay_line.is_none());
+        assert_eq!(check.audit_step.unwrap().output["meal_break_taken"], true);
+    }
+
+    #[test]
+    fn test_short_late_or_paid_breaks_do_not_qualify() {
+        let short = unpaid_break("12:00:00", "12:15:00");
+        let late = unpaid_break("14:00:00", "14:30:00");
+        let mut paid = unpaid_break("12:00:00", "12:30:00");
+        paid.is_paid = true;
+
+        for brk in [short, late, paid] {
+            let shift = shift("08:00:00", "17:30:00", vec![brk]);
+            let check = check_meal_break_taken(&shift, dec("28.54"), &config(None), 1, true);
+            assert!(check.warning.is_some());
+        }
+    }
+
+    #[test]
+    fn test_shift_within_limit_is_not_checked() {
+        let shift = shift("09:00:00", "14:00:00", vec![]);
+
+        let check = check_meal_break_taken(&shift, dec("28.54"), &config(None), 1, true);
+
+        assert!(check.warning.is_none());
+        assert!(check.audit_step.is_none());
+    }
+}
@@ -0,0 +1,399 @@
+//! Minimum daily pay functionality.
+//!
+//! Some arrangements guarantee a minimum payment for each day worked. This
+//! module tops up a day whose paid hours fall short of the configured minimum.
+//! Unlike a minimum engagement, which applies to each shift on its own, the
+//! guarantee is measured across all of a day's shifts.
+//!
+//! A day's paid hours are read from the pay lines already generated for its
+//! shifts (ordinary, weekend and overtime hours, after any rounding), so hours
+//! already paid to meet another minimum count towards the guarantee and the
+//! two never stack.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::config::MinimumDailyPayConfig;
+use crate::models::{AuditStep, Employee, PayCategory, PayLine, Shift};
+
+use super::casual_loading::casual_loaded_rate;
+use super::hours_reconciliation::is_worked_time;
+use super::rules::RuleInfo;
+
+/// The minimum daily pay rule, for the rule catalogue; its clause is configured.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "minimum_daily_pay",
+    rule_name: "Minimum Daily Pay",
+    clause_ref: "configured",
+}];
+
+/// The result of calculating minimum daily pay top-ups.
+#[derive(Debug, Clone)]
+pub struct MinimumDailyPayResult {
+    /// A top-up pay line for each day paid less than the minimum.
+    pub pay_lines: Vec<PayLine>,
+    /// An audit step for each day paid less than the minimum.
+    pub audit_steps: Vec<AuditStep>,
+}
+
+/// Tops up each day worked to the configured minimum daily hours.
+///
+/// Shifts are grouped by date, and a day's paid hours are the hours of the
+/// pay lines for its shifts that pay for time worked. A shift with no such
+/// pay lines (one left out of a partial result) is ignored, so a day is never
+/// topped up for work that was not paid. When the paid hours are below
+/// `minimum_daily_hours`, the shortfall is paid on a
+/// [`PayCategory::MinimumDailyPay`] line against the day's last shift, at the
+/// ordinary rate that shift was paid: its `base_rate`, with the casual loading
+/// for a casual employee. A shift's override rate, classification or dated
+/// rate therefore carries over to the top-up. Days that meet the minimum
+/// produce neither a pay line nor an audit step.
+///
+/// # Arguments
+///
+/// * `shifts` - The shifts worked in the pay period
+/// * `pay_lines` - The pay lines generated for the shifts so far
+/// * `employee` - The employee, whose employment type sets the rate
+/// * `base_rate` - The base hourly rate each shift was paid at
+/// * `config` - The configured minimum daily hours
+/// * `step_number` - The step number of the first audit step
+/// * `include_audit` - Whether to build the audit steps
+///
+/// # Example
+///
+/// ```
+/// use award_engine::calculation::calculate_minimum_daily_pay;
+/// use award_engine::config::MinimumDailyPayConfig;
+/// use award_engine::models::{Employee, EmploymentType, PayCategory, PayLine, Shift};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
+/// let employee = Employee {
+///     id: "emp_001".to_string(),
+///     employment_type: EmploymentType::PartTime,
+///     classification_code: "dce_level_3".to_string(),
+///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
+///     tags: vec![],
+/// };
+/// let shift = Shift {
+///     id: "shift_001".to_string(),
+///     date,
+///     start_time: date.and_hms_opt(9, 0, 0).unwrap(),
+///     end_time: date.and_hms_opt(11, 0, 0).unwrap(),
+///     breaks: vec![],
+///     rate_override: None,
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
+/// };
+/// let ordinary = PayLine {
+///     date,
+///     shift_id: "shift_001".to_string(),
+///     category: PayCategory::Ordinary,
+///     hours: Decimal::from(2),
+///     rate: Decimal::new(2854, 2),
+///     amount: Decimal::new(5708, 2),
+///     clause_ref: "22.1".to_string(),
+///     reference: None,
+///     cost_centre: None,
+/// };
+/// let config = MinimumDailyPayConfig {
+///     clause: "X.Y".to_string(),
+///     minimum_daily_hours: Decimal::from(3),
+/// };
+///
+/// let result = calculate_minimum_daily_pay(
+///     &[shift],
+///     &[ordinary],
+///     &employee,
+///     |_| Decimal::new(2854, 2),
+///     &config,
+///     1,
+///     true,
+/// );
+///
+/// assert_eq!(result.pay_lines.len(), 1);
+/// assert_eq!(result.pay_lines[0].category, PayCategory::MinimumDailyPay);
+/// assert_eq!(result.pay_lines[0].hours, Decimal::ONE);
+/// assert_eq!(result.pay_lines[0].amount, Decimal::new(2854, 2));
+/// ```
+pub fn calculate_minimum_daily_pay(
+    shifts: &[Shift],
+    pay_lines: &[PayLine],
+    employee: &Employee,
+    base_rate: impl Fn(&Shift) -> Decimal,
+    config: &MinimumDailyPayConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> MinimumDailyPayResult {
+    let worked_lines: Vec<&PayLine> = pay_lines
+        .iter()
+        .filter(|line| is_worked_time(line.category))
+        .collect();
+    let mut shifts_by_day: BTreeMap<NaiveDate, Vec<&Shift>> = BTreeMap::new();
+    for shift in shifts {
+        if worked_lines.iter().any(|line| line.shift_id == shift.id) {
+            shifts_by_day.entry(shift.date).or_default().push(shift);
+        }
+    }
+
+    let mut top_up_lines = Vec::new();
+    let mut audit_steps = Vec::new();
+    for (date, day_shifts) in shifts_by_day {
+        let Some(last_shift) = day_shifts.iter().max_by_key(|s| s.end_time) else {
+            continue;
+        };
+        let paid_hours: Decimal = worked_lines
+            .iter()
+            .filter(|line| day_shifts.iter().any(|s| s.id == line.shift_id))
+            .map(|line| line.hours)
+            .sum();
+        if paid_hours >= config.minimum_daily_hours {
+            continue;
+        }
+
+        let top_up_hours = config.minimum_daily_hours - paid_hours;
+        let rate = casual_loaded_rate(base_rate(last_shift), employee);
+        let amount = top_up_hours * rate;
+
+        if include_audit {
+            audit_steps.push(AuditStep {
+                step_number: step_number + audit_steps.len() as u32,
+                rule_id: "minimum_daily_pay".to_string(),
+                rule_name: "Minimum Daily Pay".to_string(),
+                clause_ref: config.clause.clone(),
+                input: serde_json::json!({
+                    "date": date.to_string(),
+                    "shift_ids": day_shifts.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(),
+                    "paid_hours": paid_hours.normalize().to_string(),
+                    "minimum_daily_hours": config.minimum_daily_hours.normalize().to_string()
+                }),
+                output: serde_json::json!({
+                    "top_up_hours": top_up_hours.normalize().to_string(),
+                    "rate": rate.normalize().to_string(),
+                    "amount": amount.normalize().to_string()
+                }),
+                reasoning: format!(
+                    "{} hours paid on {} is below the {} hour daily minimum: {} hours × ${} = ${}",
+                    paid_hours.normalize(),
+                    date,
+                    config.minimum_daily_hours.normalize(),
+                    top_up_hours.normalize(),
+                    rate.normalize(),
+                    amount.normalize()
+                ),
+            });
+        }
+
+        top_up_lines.push(PayLine {
+            date,
+            shift_id: last_shift.id.clone(),
+            category: PayCategory::MinimumDailyPay,
+            hours: top_up_hours,
+            rate,
+            amount,
+            clause_ref: config.clause.clone(),
+            reference: None,
+            cost_centre: None,
+        });
+    }
+
+    MinimumDailyPayResult {
+        pay_lines: top_up_lines,
+        audit_steps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EmploymentType;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn employee(employment_type: EmploymentType) -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn shift(id: &str, date: &str, start: &str, end: &str) -> Shift {
+        let date = NaiveDate::from_str(date).unwrap();
+        Shift {
+            id: id.to_string(),
+            date,
+            start_time: date.and_time(start.parse().unwrap()),
+            end_time: date.and_time(end.parse().unwrap()),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    fn line(shift: &Shift, category: PayCategory, hours: &str) -> PayLine {
+        PayLine {
+            date: shift.date,
+            shift_id: shift.id.clone(),
+            category,
+            hours: dec(hours),
+            rate: dec("28.54"),
+            amount: dec(hours) * dec("28.54"),
+            clause_ref: "22.1".to_string(),
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    fn config() -> MinimumDailyPayConfig {
+        MinimumDailyPayConfig {
+            clause: "X.Y".to_string(),
+            minimum_daily_hours: dec("3"),
+        }
+    }
+
+    #[test]
+    fn test_short_day_is_topped_up_to_the_minimum() {
+        let short = shift("shift_001", "2026-01-13", "09:00:00", "11:00:00");
+        let lines = vec![line(&short, PayCategory::Ordinary, "2")];
+
+        let result = calculate_minimum_daily_pay(
+            &[short],
+            &lines,
+            &employee(EmploymentType::PartTime),
+            |_| dec("28.54"),
+            &config(),
+            7,
+            true,
+        );
+
+        assert_eq!(result.pay_lines.len(), 1);
+        let top_up = &result.pay_lines[0];
+        assert_eq!(top_up.category, PayCategory::MinimumDailyPay);
+        assert_eq!(top_up.shift_id, "shift_001");
+        assert_eq!(top_up.hours, dec("1"));
+        assert_eq!(top_up.rate, dec("28.54"));
+        assert_eq!(top_up.amount, dec("28.54"));
+        assert_eq!(top_up.clause_ref, "X.Y");
+
+        let step = &result.audit_steps[0];
+        assert_eq!(step.step_number, 7);
+        assert_eq!(step.input["paid_hours"], "2");
+        assert_eq!(step.output["top_up_hours"], "1");
+    }
+
+    #[test]
+    fn test_a_days_shifts_count_together() {
+        // Two 1.5 hour shifts meet a 3 hour minimum between them
+        let morning = shift("morning", "2026-01-13", "07:00:00", "08:30:00");
+        let evening = shift("evening", "2026-01-13", "17:00:00", "18:30:00");
+        let lines = vec![
+            line(&morning, PayCategory::Ordinary, "1.5"),
+            line(&evening, PayCategory::Ordinary, "1.5"),
+        ];
+
+        let result = calculate_minimum_daily_pay(
+            &[morning, evening],
+            &lines,
+            &employee(EmploymentType::PartTime),
+            |_| dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+
+        assert!(result.pay_lines.is_empty());
+        assert!(result.audit_steps.is_empty());
+    }
+
+    #[test]
+    fn test_shift_without_pay_lines_is_not_topped_up() {
+        let unpaid = shift("shift_001", "2026-01-13", "09:00:00", "11:00:00");
+
+        let result = calculate_minimum_daily_pay(
+            &[unpaid],
+            &[],
+            &employee(EmploymentType::PartTime),
+            |_| dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+
+        assert!(result.pay_lines.is_empty());
+    }
+
+    #[test]
+    fn test_casual_top_up_includes_the_loading_and_ignores_other_lines() {
+        let short = shift("shift_001", "2026-01-17", "09:00:00", "11:00:00");
+        // A meal break penalty is not time worked and does not count
+        let lines = vec![
+            line(&short, PayCategory::SaturdayCasual, "2"),
+            line(&short, PayCategory::MealBreakPenalty, "1"),
+        ];
+
+        let result = calculate_minimum_daily_pay(
+            &[short],
+            &lines,
+            &employee(EmploymentType::Casual),
+            |_| dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines[0].hours, dec("1"));
+        assert_eq!(result.pay_lines[0].rate, dec("35.675"));
+    }
+
+    #[test]
+    fn test_top_up_is_paid_at_the_last_shifts_rate() {
+        let morning = shift("morning", "2026-01-13", "07:00:00", "08:00:00");
+        let evening = shift("evening", "2026-01-13", "17:00:00", "18:00:00");
+        let lines = vec![
+            line(&morning, PayCategory::Ordinary, "1"),
+            line(&evening, PayCategory::Ordinary, "1"),
+        ];
+
+        let result = calculate_minimum_daily_pay(
+            &[morning, evening],
+            &lines,
+            &employee(EmploymentType::PartTime),
+            |s| {
+                if s.id == "evening" {
+                    dec("50")
+                } else {
+                    dec("28.54")
+                }
+            },
+            &config(),
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines[0].shift_id, "evening");
+        assert_eq!(result.pay_lines[0].rate, dec("50"));
+        assert_eq!(result.pay_lines[0].amount, dec("50"));
+    }
+}
@@ -4,37 +4,107 @@
 //! including base rate lookup, casual loading, ordinary hours calculations,
 //! day detection for weekend penalty rates, Saturday penalty rates, Sunday penalty rates,
 //! overnight shift calculations that span multiple days, daily overtime detection,
-//! weekday overtime rate calculation, weekend overtime rate calculation, and
-//! laundry allowance calculation.
+//! weekday overtime rate calculation, weekend overtime rate calculation,
+//! laundry allowance calculation, qualification allowance calculation,
+//! broken shift allowance calculation, split shift travel reimbursement,
+//! consecutive days detection, minimum daily pay, early commencement loading,
+//! worked time rounding, daylight saving adjustment, week boundary grouping,
+//! public holiday detection, payment for public holidays not worked,
+//! worked-hours reconciliation, employee tag recognition, overtime audit
+//! summaries, and the catalogue of rules the engine applies.
 
 mod base_rate;
+mod broken_shift_allowance;
 mod casual_loading;
+mod consecutive_days;
 mod daily_overtime;
 mod day_detection;
+mod early_commencement;
+mod first_aid_allowance;
+mod hours_reconciliation;
 mod laundry_allowance;
+mod leave_accrual;
+mod meal_break;
+mod minimum_daily_pay;
 mod ordinary_hours;
 mod overnight_shift;
 mod overtime_audit;
+mod public_holiday_not_worked;
+mod public_holiday_overtime;
+mod public_holidays;
+mod qualification_allowance;
+mod rules;
 mod saturday_penalty;
+mod shift_commencement;
+mod span_of_hours;
+mod split_shift_travel;
 mod sunday_penalty;
+mod tags;
+mod time_rounding;
+mod timezone;
+mod week;
 mod weekday_overtime;
 mod weekend_overtime;
 
-pub use base_rate::{BaseRateLookupResult, get_base_rate};
-pub use casual_loading::{CasualLoadingResult, apply_casual_loading, casual_loading_multiplier};
+pub(crate) use base_rate::flag_shift_rate_override;
+pub use base_rate::{
+    BaseRateLookupResult, OVERRIDE_RATE_DEVIATION_WARNING, get_base_rate, get_shift_base_rate,
+};
+pub use broken_shift_allowance::{
+    BROKEN_SHIFT_ALLOWANCE_CLAUSE, BrokenShiftAllowanceResult, calculate_broken_shift_allowance,
+};
+pub use casual_loading::{
+    CasualLoadingResult, CasualOvertimeComposition, CasualPenaltyBreakdown, apply_casual_loading,
+    casual_loading_multiplier, casual_overtime_audit_step, compose_casual_overtime,
+    compose_casual_penalty,
+};
+pub use consecutive_days::{ConsecutiveDay, ConsecutiveDaysDetection, detect_consecutive_days};
 pub use daily_overtime::{
-    DEFAULT_DAILY_OVERTIME_THRESHOLD, DailyOvertimeDetection, detect_daily_overtime,
+    DEFAULT_DAILY_OVERTIME_THRESHOLD, DailyOvertimeDetection, DailyThresholdSelection,
+    detect_cumulative_daily_overtime, detect_daily_overtime, select_daily_threshold,
 };
 pub use day_detection::{DayType, ShiftSegment, get_day_type, segment_by_day};
+pub use early_commencement::{EarlyCommencementCheck, check_early_commencement};
+pub use first_aid_allowance::{
+    FIRST_AID_ALLOWANCE_CLAUSE, FIRST_AID_ALLOWANCE_TAG, FirstAidAllowanceResult,
+    calculate_first_aid_allowance,
+};
+pub(crate) use hours_reconciliation::is_worked_time;
+pub use hours_reconciliation::{HOURS_MISMATCH_WARNING, reconcile_shift_hours};
+pub use laundry_allowance::{
+    LAUNDRY_ALLOWANCE_CLAUSE, LAUNDRY_ALLOWANCE_TAG, LaundryAllowanceResult,
+    calculate_laundry_allowance, laundry_shift_excluded_step,
+};
+pub use leave_accrual::{LeaveAccrualResult, calculate_leave_accrual};
+pub use meal_break::{MealBreakCheck, NO_MEAL_BREAK_WARNING, check_meal_break_taken};
+pub use minimum_daily_pay::{MinimumDailyPayResult, calculate_minimum_daily_pay};
 pub use ordinary_hours::{OrdinaryHoursResult, calculate_ordinary_hours};
 pub use overnight_shift::{OvernightShiftResult, calculate_overnight_shift};
+pub use overtime_audit::{OvertimeAudit, OvertimeTotals, summarize_overtime};
+pub use public_holiday_not_worked::{
+    PublicHolidayInLieuResult, PublicHolidayNotWorkedResult, calculate_public_holidays_in_lieu,
+    calculate_public_holidays_not_worked, paid_public_holidays_not_worked,
+};
+pub use public_holiday_overtime::{PublicHolidayOvertimeResult, calculate_public_holiday_overtime};
+pub use public_holidays::{PublicHolidayDetectionResult, detect_public_holidays};
+pub use qualification_allowance::{
+    QUALIFICATION_ALLOWANCE_CLAUSE, QUALIFICATION_ALLOWANCE_TAG, QualificationAllowanceResult,
+    calculate_qualification_allowance,
+};
+pub use rules::{RuleInfo, rule_catalogue, unapplied_rules};
 pub use saturday_penalty::{SaturdayPayResult, calculate_saturday_pay};
+pub use shift_commencement::{WeekendDayAssignment, assign_weekend_days};
+pub use span_of_hours::{SpanPenaltyResult, calculate_span_penalty};
+pub use split_shift_travel::{
+    SPLIT_SHIFT_TRAVEL_CLAUSE, SplitShiftTravelResult, calculate_split_shift_travel,
+};
 pub use sunday_penalty::{SundayPayResult, calculate_sunday_pay};
-pub use weekday_overtime::{
-    WEEKDAY_OT_TIER_1_THRESHOLD, WeekdayOvertimeResult, calculate_weekday_overtime,
+pub use tags::{KNOWN_TAGS, UNKNOWN_TAG_WARNING, check_employee_tags};
+pub use time_rounding::{
+    ShiftSecondsRoundingResult, TimeRoundingResult, apply_time_rounding, round_hours,
+    round_shift_seconds,
 };
+pub use timezone::{TimezoneAdjustmentResult, apply_timezone, elapsed_hours};
+pub use week::week_key;
+pub use weekday_overtime::{WeekdayOvertimeResult, calculate_weekday_overtime};
 pub use weekend_overtime::{WeekendOvertimeResult, calculate_weekend_overtime};
-pub use laundry_allowance::{
-    LAUNDRY_ALLOWANCE_CLAUSE, LAUNDRY_ALLOWANCE_TAG, LaundryAllowanceResult,
-    calculate_laundry_allowance,
-};
@@ -7,10 +7,18 @@ use rust_decimal::Decimal;
 
 use crate::config::AwardConfig;
 use crate::error::EngineResult;
-use crate::models::{AuditStep, Employee, EmploymentType, PayCategory, PayLine, Shift};
+use crate::models::{AuditStep, Employee, PayCategory, PayLine, Shift};
 
-use super::base_rate::get_base_rate;
+use super::base_rate::{flag_shift_rate_override, get_shift_base_rate};
 use super::casual_loading::{apply_casual_loading, casual_loading_multiplier};
+use super::rules::RuleInfo;
+
+/// The ordinary hours rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "ordinary_hours_calculation",
+    rule_name: "Ordinary Hours Pay Calculation",
+    clause_ref: "22.1",
+}];
 
 /// The result of calculating ordinary hours, including the pay line and audit steps.
 #[derive(Debug, Clone)]
@@ -25,7 +33,7 @@ pub struct OrdinaryHoursResult {
 /// Calculates pay for ordinary hours worked during a shift.
 ///
 /// This function calculates the ordinary pay for a shift by:
-/// 1. Looking up the base rate from config or employee override
+/// 1. Looking up the base rate from config or a shift or employee override
 /// 2. Applying casual loading if the employee is casual
 /// 3. Generating a pay line with the calculated amount
 ///
@@ -35,6 +43,7 @@ pub struct OrdinaryHoursResult {
 /// * `employee` - The employee who worked the shift
 /// * `config` - The award configuration containing rates
 /// * `start_step_number` - The starting step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit steps
 ///
 /// # Returns
 ///
@@ -60,20 +69,23 @@ pub fn calculate_ordinary_hours(
     employee: &Employee,
     config: &AwardConfig,
     start_step_number: u32,
+    include_audit: bool,
 ) -> EngineResult<OrdinaryHoursResult> {
     let mut audit_steps = Vec::new();
     let mut current_step = start_step_number;
 
     // Step 1: Look up base rate
-    let base_rate_result = get_base_rate(employee, shift.date, config, current_step)?;
+    let base_rate_result =
+        get_shift_base_rate(shift, employee, config, current_step, include_audit)?;
     let base_rate = base_rate_result.rate;
-    audit_steps.push(base_rate_result.audit_step);
+    audit_steps.extend(base_rate_result.audit_step);
     current_step += 1;
 
     // Step 2: Apply casual loading if applicable
-    let casual_loading_result = apply_casual_loading(base_rate, employee, current_step);
+    let casual_loading_result =
+        apply_casual_loading(base_rate, employee, current_step, include_audit);
     let effective_rate = casual_loading_result.loaded_rate;
-    audit_steps.push(casual_loading_result.audit_step);
+    audit_steps.extend(casual_loading_result.audit_step);
     current_step += 1;
 
     // Step 3: Calculate pay and generate pay line
@@ -81,11 +93,10 @@ pub fn calculate_ordinary_hours(
     let amount = hours * effective_rate;
 
     // Determine the pay category and multiplier based on employment type
-    let (category, multiplier) = match employee.employment_type {
-        EmploymentType::Casual => (PayCategory::OrdinaryCasual, casual_loading_multiplier()),
-        EmploymentType::FullTime | EmploymentType::PartTime => {
-            (PayCategory::Ordinary, Decimal::ONE)
-        }
+    let (category, multiplier) = if employee.employment_type.is_permanent() {
+        (PayCategory::Ordinary, Decimal::ONE)
+    } else {
+        (PayCategory::OrdinaryCasual, casual_loading_multiplier())
     };
 
     let pay_line = PayLine {
@@ -96,51 +107,54 @@ pub fn calculate_ordinary_hours(
         rate: effective_rate,
         amount,
         clause_ref: "22.1".to_string(),
+        reference: None,
+        cost_centre: None,
     };
 
     // Create audit step for pay line generation
-    let employment_type_str = match employee.employment_type {
-        EmploymentType::FullTime => "full_time",
-        EmploymentType::PartTime => "part_time",
-        EmploymentType::Casual => "casual",
-    };
-
-    let pay_line_audit = AuditStep {
-        step_number: current_step,
-        rule_id: "ordinary_hours_calculation".to_string(),
-        rule_name: "Ordinary Hours Pay Calculation".to_string(),
-        clause_ref: "22.1".to_string(),
-        input: serde_json::json!({
-            "shift_id": shift.id,
-            "shift_date": shift.date.to_string(),
-            "hours": hours.normalize().to_string(),
-            "base_rate": base_rate.normalize().to_string(),
-            "effective_rate": effective_rate.normalize().to_string(),
-            "employment_type": employment_type_str,
-            "multiplier": multiplier.normalize().to_string()
-        }),
-        output: serde_json::json!({
-            "category": format!("{:?}", category),
-            "amount": amount.normalize().to_string(),
-            "pay_line": {
+    if include_audit {
+        let employment_type_str = employee.employment_type.as_str();
+
+        let mut pay_line_audit = AuditStep {
+            step_number: current_step,
+            rule_id: "ordinary_hours_calculation".to_string(),
+            rule_name: "Ordinary Hours Pay Calculation".to_string(),
+            clause_ref: "22.1".to_string(),
+            input: serde_json::json!({
+                "shift_id": shift.id,
+                "shift_date": shift.date.to_string(),
                 "hours": hours.normalize().to_string(),
-                "rate": effective_rate.normalize().to_string(),
-                "amount": amount.normalize().to_string()
-            }
-        }),
-        reasoning: format!(
-            "Calculated ordinary hours pay: {} hours x ${} = ${} ({})",
-            hours.normalize(),
-            effective_rate.normalize(),
-            amount.normalize(),
-            if employee.is_casual() {
-                format!("casual with {}x multiplier", multiplier.normalize())
-            } else {
-                format!("{} employee at base rate", employment_type_str)
-            }
-        ),
-    };
-    audit_steps.push(pay_line_audit);
+                "base_rate": base_rate.normalize().to_string(),
+                "effective_rate": effective_rate.normalize().to_string(),
+                "employment_type": employment_type_str,
+                "multiplier": multiplier.normalize().to_string()
+            }),
+            output: serde_json::json!({
+                "category": category,
+                "amount": amount.normalize().to_string(),
+                "pay_line": {
+                    "hours": hours.normalize().to_string(),
+                    "rate": effective_rate.normalize().to_string(),
+                    "amount": amount.normalize().to_string()
+                }
+            }),
+            reasoning: format!(
+                "Calculated ordinary hours pay: {} hours x ${} = ${} ({})",
+                hours.normalize(),
+                effective_rate.normalize(),
+                amount.normalize(),
+                if employee.is_casual() {
+                    format!("casual with {}x multiplier", multiplier.normalize())
+                } else {
+                    format!("{} employee at base rate", employment_type_str)
+                }
+            ),
+        };
+        if let Some(override_rate) = shift.rate_override {
+            flag_shift_rate_override(&mut pay_line_audit, override_rate);
+        }
+        audit_steps.push(pay_line_audit);
+    }
 
     Ok(OrdinaryHoursResult {
         pay_line,
@@ -152,10 +166,12 @@ pub fn calculate_ordinary_hours(
 mod tests {
     use super::*;
     use crate::config::{
-        AllowanceRates, AwardMetadata, Classification, ClassificationRate, OvertimeConfig,
-        OvertimeRates, OvertimeSection, Penalties, PenaltyConfig, PenaltyRates, RateConfig,
-        WeekendOvertimeConfig,
+        AllowanceRates, AwardMetadata, CasualOvertimeBase, CasualPenaltyComposition,
+        Classification, ClassificationRate, OvertimeConfig, OvertimeRates, OvertimeSection,
+        OvertimeStacking, Penalties, PenaltyConfig, PenaltyRates, RateConfig, ShiftSeconds,
+        TimeRounding, WeekdayOvertimeTier, WeekendDays, WeekendOvertimeConfig,
     };
+    use crate::models::EmploymentType;
     use chrono::{NaiveDate, NaiveDateTime};
     use std::collections::HashMap;
     use std::str::FromStr;
@@ -206,6 +222,10 @@ mod tests {
             allowances: AllowanceRates {
                 laundry_per_shift: dec("0.32"),
                 laundry_per_week: dec("1.49"),
+                qualification: None,
+                broken_shift: None,
+                split_shift_travel: None,
+                first_aid_per_week: None,
             },
         }];
 
@@ -223,24 +243,41 @@ mod tests {
                     part_time: dec("2.0"),
                     casual: dec("2.25"),
                 },
+                classifications: HashMap::new(),
+                casual_composition: CasualPenaltyComposition::Additive,
             },
             overtime: OvertimeSection {
                 daily_threshold_hours: 8,
+                daily_thresholds: HashMap::new(),
+                casual_ot_base: CasualOvertimeBase::Loaded,
                 weekday: OvertimeConfig {
                     clause: "25.1".to_string(),
-                    first_two_hours: OvertimeRates {
-                        full_time: dec("1.5"),
-                        part_time: dec("1.5"),
-                        casual: dec("1.75"),
-                    },
-                    after_two_hours: OvertimeRates {
-                        full_time: dec("2.0"),
-                        part_time: dec("2.0"),
-                        casual: dec("2.25"),
-                    },
+                    tiers: vec![
+                        WeekdayOvertimeTier {
+                            threshold_hours: Decimal::ZERO,
+                            category: PayCategory::Overtime150,
+                            rates: OvertimeRates {
+                                full_time: dec("1.5"),
+                                part_time: dec("1.5"),
+                                casual: dec("1.75"),
+                            },
+                        },
+                        WeekdayOvertimeTier {
+                            threshold_hours: dec("2"),
+                            category: PayCategory::Overtime200,
+                            rates: OvertimeRates {
+                                full_time: dec("2.0"),
+                                part_time: dec("2.0"),
+                                casual: dec("2.25"),
+                            },
+                        },
+                    ],
                 },
                 weekend: WeekendOvertimeConfig {
                     clause: "25.1(a)(i)(B)".to_string(),
+                    tiered: false,
+                    tiers: vec![],
+                    overtime_stacking: OvertimeStacking::Replace,
                     saturday: OvertimeRates {
                         full_time: dec("2.0"),
                         part_time: dec("2.0"),
@@ -252,7 +289,24 @@ mod tests {
                         casual: dec("2.5"),
                     },
                 },
+                public_holiday: None,
             },
+            time_rounding: TimeRounding::None,
+            shift_seconds: ShiftSeconds::NearestMinute,
+            weekend_days: WeekendDays::default(),
+            week_start_day: chrono::Weekday::Mon,
+            laundry_min_shift_hours: Decimal::ZERO,
+            public_holiday_not_worked: None,
+            consecutive_days: None,
+            meal_break: None,
+            leave_accrual: None,
+            override_rate_warning_percent: Decimal::from(25),
+            span_of_hours: None,
+            early_commencement: None,
+            minimum_daily_pay: None,
+            max_expected_gross: None,
+            rounding_reconciliation: false,
+            max_audit_steps: None,
         };
 
         AwardConfig::new(metadata, classifications, rates, penalties)
@@ -266,6 +320,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -284,6 +341,10 @@ mod tests {
             start_time: make_datetime(date, "09:00:00"),
             end_time: make_datetime(date, &format!("{:02}:{:02}:00", end_hour, end_minute)),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         }
     }
 
@@ -295,7 +356,7 @@ mod tests {
         // Monday
         let shift = create_test_shift("2025-08-04", dec("8.0"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_line.category, PayCategory::Ordinary);
         assert_eq!(result.pay_line.hours, dec("8.0"));
@@ -318,7 +379,7 @@ mod tests {
         // Tuesday
         let shift = create_test_shift("2025-08-05", dec("8.0"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_line.category, PayCategory::Ordinary);
         assert_eq!(result.pay_line.hours, dec("8.0"));
@@ -334,7 +395,7 @@ mod tests {
         // Wednesday
         let shift = create_test_shift("2025-08-06", dec("8.0"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_line.category, PayCategory::OrdinaryCasual);
         assert_eq!(result.pay_line.hours, dec("8.0"));
@@ -352,7 +413,7 @@ mod tests {
         // Thursday
         let shift = create_test_shift("2025-08-07", dec("4.0"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_line.category, PayCategory::Ordinary);
         assert_eq!(result.pay_line.hours, dec("4.0"));
@@ -369,7 +430,7 @@ mod tests {
         // Friday
         let shift = create_test_shift("2025-08-08", dec("7.5"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_line.category, PayCategory::OrdinaryCasual);
         assert_eq!(result.pay_line.hours, dec("7.5"));
@@ -387,7 +448,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::Casual);
         let shift = create_test_shift("2025-08-06", dec("8.0"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         // Verify step numbers are sequential
         assert_eq!(result.audit_steps[0].step_number, 1);
@@ -411,7 +472,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::Casual);
         let shift = create_test_shift("2025-08-06", dec("8.0"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         // The pay line audit step should contain the multiplier
         let pay_line_step = &result.audit_steps[2];
@@ -424,7 +485,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let shift = create_test_shift("2025-08-04", dec("8.0"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         // The pay line audit step should contain the multiplier
         let pay_line_step = &result.audit_steps[2];
@@ -437,7 +498,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let shift = create_test_shift("2025-08-04", dec("8.0"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_line.shift_id, shift.id);
     }
@@ -448,7 +509,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let shift = create_test_shift("2025-08-04", dec("8.0"));
 
-        let result = calculate_ordinary_hours(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_ordinary_hours(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_line.date, shift.date);
     }
@@ -9,14 +9,35 @@ use rust_decimal::Decimal;
 
 use crate::config::AwardConfig;
 use crate::error::EngineResult;
-use crate::models::{AuditStep, Employee, EmploymentType, PayCategory, PayLine, Shift};
+use crate::models::{AuditStep, Employee, PayCategory, PayLine, Shift};
 
 use super::base_rate::get_base_rate;
 use super::casual_loading::apply_casual_loading;
 use super::day_detection::{DayType, ShiftSegment, segment_by_day};
+use super::rules::RuleInfo;
 use super::saturday_penalty::calculate_saturday_pay;
 use super::sunday_penalty::calculate_sunday_pay;
 
+/// The rules recorded when an overnight shift is split by day, for the
+/// rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        rule_id: "shift_segmentation",
+        rule_name: "Shift Day Segmentation",
+        clause_ref: "23",
+    },
+    RuleInfo {
+        rule_id: "weekday_ordinary",
+        rule_name: "Weekday Ordinary Time",
+        clause_ref: "22.1",
+    },
+    RuleInfo {
+        rule_id: "overnight_shift_total",
+        rule_name: "Overnight Shift Total Calculation",
+        clause_ref: "23",
+    },
+];
+
 /// The result of an overnight shift calculation, including multiple pay lines and audit steps.
 ///
 /// For shifts spanning multiple days, this result contains a pay line for each day segment
@@ -48,6 +69,7 @@ pub struct OvernightShiftResult {
 /// * `employee` - The employee who worked the shift
 /// * `config` - The award configuration containing rates and penalties
 /// * `start_step_number` - The starting step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit steps
 ///
 /// # Returns
 ///
@@ -79,6 +101,9 @@ pub struct OvernightShiftResult {
 ///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
 ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
 ///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
 ///     tags: vec![],
 /// };
 ///
@@ -89,9 +114,13 @@ pub struct OvernightShiftResult {
 ///     start_time: NaiveDateTime::parse_from_str("2026-01-17 22:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
 ///     end_time: NaiveDateTime::parse_from_str("2026-01-18 06:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
 ///     breaks: vec![],
+///     rate_override: None,
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
 /// };
 ///
-/// let result = calculate_overnight_shift(&shift, &employee, config, 1).unwrap();
+/// let result = calculate_overnight_shift(&shift, &employee, config, 1, true).unwrap();
 /// // Result contains two pay lines: one for Saturday hours, one for Sunday hours
 /// assert_eq!(result.pay_lines.len(), 2);
 /// ```
@@ -100,65 +129,69 @@ pub fn calculate_overnight_shift(
     employee: &Employee,
     config: &AwardConfig,
     start_step_number: u32,
+    include_audit: bool,
 ) -> EngineResult<OvernightShiftResult> {
     let mut audit_steps = Vec::new();
     let mut current_step = start_step_number;
 
     // Step 1: Look up base rate
-    let base_rate_result = get_base_rate(employee, shift.date, config, current_step)?;
+    let base_rate_result =
+        get_base_rate(employee, shift.date, config, current_step, include_audit)?;
     let base_rate = base_rate_result.rate;
-    audit_steps.push(base_rate_result.audit_step);
+    audit_steps.extend(base_rate_result.audit_step);
     current_step += 1;
 
     // Step 2: Segment the shift by day boundaries
     let segments = segment_by_day(shift);
 
     // Create audit step for segmentation
-    let segment_descriptions: Vec<serde_json::Value> = segments
-        .iter()
-        .map(|s| {
-            serde_json::json!({
-                "day_type": format!("{}", s.day_type),
-                "hours": s.hours.normalize().to_string(),
-                "start_time": s.start_time.to_string(),
-                "end_time": s.end_time.to_string()
+    if include_audit {
+        let segment_descriptions: Vec<serde_json::Value> = segments
+            .iter()
+            .map(|s| {
+                serde_json::json!({
+                    "day_type": format!("{}", s.day_type),
+                    "hours": s.hours.normalize().to_string(),
+                    "start_time": s.start_time.to_string(),
+                    "end_time": s.end_time.to_string()
+                })
             })
-        })
-        .collect();
-
-    let segmentation_step = AuditStep {
-        step_number: current_step,
-        rule_id: "shift_segmentation".to_string(),
-        rule_name: "Shift Day Segmentation".to_string(),
-        clause_ref: "23".to_string(),
-        input: serde_json::json!({
-            "shift_id": shift.id,
-            "start_time": shift.start_time.to_string(),
-            "end_time": shift.end_time.to_string(),
-            "total_hours": shift.worked_hours().normalize().to_string()
-        }),
-        output: serde_json::json!({
-            "segment_count": segments.len(),
-            "segments": segment_descriptions
-        }),
-        reasoning: if segments.len() == 1 {
-            format!(
-                "Shift is entirely within {} - no midnight crossing",
-                segments[0].day_type
-            )
-        } else {
-            format!(
-                "Shift crosses midnight: split into {} segments ({})",
-                segments.len(),
-                segments
-                    .iter()
-                    .map(|s| format!("{}: {}h", s.day_type, s.hours.normalize()))
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            )
-        },
-    };
-    audit_steps.push(segmentation_step);
+            .collect();
+
+        let segmentation_step = AuditStep {
+            step_number: current_step,
+            rule_id: "shift_segmentation".to_string(),
+            rule_name: "Shift Day Segmentation".to_string(),
+            clause_ref: "23".to_string(),
+            input: serde_json::json!({
+                "shift_id": shift.id,
+                "start_time": shift.start_time.to_string(),
+                "end_time": shift.end_time.to_string(),
+                "total_hours": shift.worked_hours().normalize().to_string()
+            }),
+            output: serde_json::json!({
+                "segment_count": segments.len(),
+                "segments": segment_descriptions
+            }),
+            reasoning: if segments.len() == 1 {
+                format!(
+                    "Shift is entirely within {} - no midnight crossing",
+                    segments[0].day_type
+                )
+            } else {
+                format!(
+                    "Shift crosses midnight: split into {} segments ({})",
+                    segments.len(),
+                    segments
+                        .iter()
+                        .map(|s| format!("{}: {}h", s.day_type, s.hours.normalize()))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            },
+        };
+        audit_steps.push(segmentation_step);
+    }
     current_step += 1;
 
     // Step 3: Calculate pay for each segment
@@ -166,40 +199,48 @@ pub fn calculate_overnight_shift(
     let mut total_amount = Decimal::ZERO;
 
     for segment in &segments {
-        let (mut pay_line, segment_audit) =
-            calculate_segment_pay(segment, employee, base_rate, config, current_step)?;
+        let (mut pay_line, segment_audit) = calculate_segment_pay(
+            segment,
+            employee,
+            base_rate,
+            config,
+            current_step,
+            include_audit,
+        )?;
 
         // Set the shift_id on the pay line
         pay_line.shift_id = shift.id.clone();
 
         total_amount += pay_line.amount;
         pay_lines.push(pay_line);
-        audit_steps.push(segment_audit);
+        audit_steps.extend(segment_audit);
         current_step += 1;
     }
 
     // Step 4: Create summary audit step
-    let summary_step = AuditStep {
-        step_number: current_step,
-        rule_id: "overnight_shift_total".to_string(),
-        rule_name: "Overnight Shift Total Calculation".to_string(),
-        clause_ref: "23".to_string(),
-        input: serde_json::json!({
-            "shift_id": shift.id,
-            "segment_count": pay_lines.len(),
-            "segment_amounts": pay_lines.iter().map(|p| p.amount.normalize().to_string()).collect::<Vec<_>>()
-        }),
-        output: serde_json::json!({
-            "total_amount": total_amount.normalize().to_string(),
-            "total_hours": shift.worked_hours().normalize().to_string()
-        }),
-        reasoning: format!(
-            "Total overnight shift pay: {} segment(s) = ${}",
-            pay_lines.len(),
-            total_amount.normalize()
-        ),
-    };
-    audit_steps.push(summary_step);
+    if include_audit {
+        let summary_step = AuditStep {
+            step_number: current_step,
+            rule_id: "overnight_shift_total".to_string(),
+            rule_name: "Overnight Shift Total Calculation".to_string(),
+            clause_ref: "23".to_string(),
+            input: serde_json::json!({
+                "shift_id": shift.id,
+                "segment_count": pay_lines.len(),
+                "segment_amounts": pay_lines.iter().map(|p| p.amount.normalize().to_string()).collect::<Vec<_>>()
+            }),
+            output: serde_json::json!({
+                "total_amount": total_amount.normalize().to_string(),
+                "total_hours": shift.worked_hours().normalize().to_string()
+            }),
+            reasoning: format!(
+                "Total overnight shift pay: {} segment(s) = ${}",
+                pay_lines.len(),
+                total_amount.normalize()
+            ),
+        };
+        audit_steps.push(summary_step);
+    }
 
     Ok(OvernightShiftResult {
         pay_lines,
@@ -217,33 +258,42 @@ fn calculate_segment_pay(
     base_rate: Decimal,
     config: &AwardConfig,
     step_number: u32,
-) -> EngineResult<(PayLine, AuditStep)> {
+    include_audit: bool,
+) -> EngineResult<(PayLine, Option<AuditStep>)> {
     match segment.day_type {
         DayType::Saturday => {
-            let result = calculate_saturday_pay(segment, employee, base_rate, config, step_number);
+            let result = calculate_saturday_pay(
+                segment,
+                employee,
+                base_rate,
+                config,
+                step_number,
+                include_audit,
+            );
             Ok((result.pay_line, result.audit_step))
         }
         DayType::Sunday => {
-            let result = calculate_sunday_pay(segment, employee, base_rate, config, step_number);
+            let result = calculate_sunday_pay(
+                segment,
+                employee,
+                base_rate,
+                config,
+                step_number,
+                include_audit,
+            );
             Ok((result.pay_line, result.audit_step))
         }
         DayType::Weekday => {
             // For weekday segments, apply ordinary time with casual loading if applicable
-            let casual_result = apply_casual_loading(base_rate, employee, step_number);
+            let casual_result =
+                apply_casual_loading(base_rate, employee, step_number, include_audit);
             let effective_rate = casual_result.loaded_rate;
             let amount = segment.hours * effective_rate;
 
-            let (category, clause_ref) = match employee.employment_type {
-                EmploymentType::Casual => (PayCategory::OrdinaryCasual, "10.4(b), 22.1"),
-                EmploymentType::FullTime | EmploymentType::PartTime => {
-                    (PayCategory::Ordinary, "22.1")
-                }
-            };
-
-            let employment_type_str = match employee.employment_type {
-                EmploymentType::FullTime => "full_time",
-                EmploymentType::PartTime => "part_time",
-                EmploymentType::Casual => "casual",
+            let (category, clause_ref) = if employee.employment_type.is_permanent() {
+                (PayCategory::Ordinary, "22.1")
+            } else {
+                (PayCategory::OrdinaryCasual, "10.4(b), 22.1")
             };
 
             let pay_line = PayLine {
@@ -254,9 +304,11 @@ fn calculate_segment_pay(
                 rate: effective_rate,
                 amount,
                 clause_ref: clause_ref.to_string(),
+                reference: None,
+                cost_centre: None,
             };
 
-            let audit_step = AuditStep {
+            let audit_step = include_audit.then(|| AuditStep {
                 step_number,
                 rule_id: "weekday_ordinary".to_string(),
                 rule_name: "Weekday Ordinary Time".to_string(),
@@ -264,13 +316,13 @@ fn calculate_segment_pay(
                 input: serde_json::json!({
                     "hours": segment.hours.normalize().to_string(),
                     "base_rate": base_rate.normalize().to_string(),
-                    "employment_type": employment_type_str,
+                    "employment_type": employee.employment_type.as_str(),
                     "day_type": "Weekday"
                 }),
                 output: serde_json::json!({
                     "effective_rate": effective_rate.normalize().to_string(),
                     "amount": amount.normalize().to_string(),
-                    "category": format!("{:?}", category)
+                    "category": category
                 }),
                 reasoning: format!(
                     "Weekday ordinary time: {} hours × ${} = ${}",
@@ -278,7 +330,7 @@ fn calculate_segment_pay(
                     effective_rate.normalize(),
                     amount.normalize()
                 ),
-            };
+            });
 
             Ok((pay_line, audit_step))
         }
@@ -289,6 +341,7 @@ fn calculate_segment_pay(
 mod tests {
     use super::*;
     use crate::config::ConfigLoader;
+    use crate::models::EmploymentType;
     use chrono::{NaiveDate, NaiveDateTime};
     use std::str::FromStr;
 
@@ -313,6 +366,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -342,9 +398,13 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_lines.len(), 2);
 
@@ -381,9 +441,13 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_lines.len(), 2);
 
@@ -421,9 +485,13 @@ mod tests {
             start_time: make_datetime("2026-01-16", "22:00:00"),
             end_time: make_datetime("2026-01-17", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_lines.len(), 2);
 
@@ -460,9 +528,13 @@ mod tests {
             start_time: make_datetime("2026-01-16", "22:00:00"),
             end_time: make_datetime("2026-01-17", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_lines.len(), 2);
 
@@ -498,9 +570,13 @@ mod tests {
             start_time: make_datetime("2026-01-18", "22:00:00"),
             end_time: make_datetime("2026-01-19", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_lines.len(), 2);
 
@@ -534,9 +610,13 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         // Find the segmentation step
         let segmentation_step = result
@@ -564,9 +644,13 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         // Should have: base rate lookup, segmentation, saturday calc, sunday calc, total
         assert!(result.audit_steps.len() >= 4);
@@ -600,9 +684,13 @@ mod tests {
             start_time: make_datetime("2026-01-17", "09:00:00"),
             end_time: make_datetime("2026-01-17", "17:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_lines.len(), 1);
         assert_eq!(result.pay_lines[0].category, PayCategory::Saturday);
@@ -627,9 +715,13 @@ mod tests {
             start_time: make_datetime("2026-01-14", "09:00:00"),
             end_time: make_datetime("2026-01-14", "17:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         assert_eq!(result.pay_lines.len(), 1);
         assert_eq!(result.pay_lines[0].category, PayCategory::Ordinary);
@@ -652,9 +744,13 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         for pay_line in &result.pay_lines {
             assert_eq!(pay_line.shift_id, "test_shift_123");
@@ -675,9 +771,13 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         let total_hours: Decimal = result.pay_lines.iter().map(|p| p.hours).sum();
         assert_eq!(total_hours, shift.worked_hours());
@@ -698,9 +798,13 @@ mod tests {
             start_time: make_datetime("2026-01-17", "22:00:00"),
             end_time: make_datetime("2026-01-18", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
-        let result = calculate_overnight_shift(&shift, &employee, &config, 1).unwrap();
+        let result = calculate_overnight_shift(&shift, &employee, &config, 1, true).unwrap();
 
         // Same as full-time: $385.29 total
         assert_eq!(result.total_amount, dec("385.29"));
@@ -1,13 +1,137 @@
-//! Overtime audit trail integration tests.
+//! Overtime audit summary.
 //!
-//! This module provides integration tests verifying the complete audit trail
-//! for overtime calculations as per US-4.4 acceptance criteria.
+//! This module summarises the overtime in a calculation result by tier and
+//! day type, giving compliance a focused view of overtime without reading the
+//! whole audit trace. Its tests also verify the complete audit trail for
+//! overtime calculations as per US-4.4 acceptance criteria.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveTime;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{CalculationResult, PayCategory};
+
+use super::{DayType, get_day_type};
+
+/// Overtime hours and dollars for one tier or day type.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OvertimeTotals {
+    /// The overtime hours.
+    pub hours: Decimal,
+    /// The overtime pay.
+    pub amount: Decimal,
+}
+
+impl OvertimeTotals {
+    fn add(&mut self, hours: Decimal, amount: Decimal) {
+        self.hours += hours;
+        self.amount += amount;
+    }
+}
+
+/// A summary of the overtime paid in a calculation result.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OvertimeAudit {
+    /// All overtime hours and pay.
+    pub total: OvertimeTotals,
+    /// Overtime by tier (e.g. `overtime150`), in payslip order.
+    pub by_tier: BTreeMap<PayCategory, OvertimeTotals>,
+    /// Overtime by the day type of the date it is paid on.
+    pub by_day_type: BTreeMap<DayType, OvertimeTotals>,
+    /// Whether any shift worked past its daily overtime threshold.
+    pub daily_threshold_triggered: bool,
+    /// The shifts that worked past their daily overtime threshold, in the
+    /// order they appear in the pay lines.
+    pub daily_threshold_shift_ids: Vec<String>,
+    /// Whether a weekly overtime threshold was triggered; `None` as the engine
+    /// does not yet apply weekly thresholds.
+    pub weekly_threshold_triggered: Option<bool>,
+}
+
+/// Summarises the overtime in a calculation result.
+///
+/// The summary is drawn from the overtime pay lines, so it is the same
+/// whether or not the result includes its audit steps. As every overtime pay
+/// line comes from a shift worked past its daily threshold, any overtime
+/// means the daily threshold was triggered. Each pay line is counted under
+/// the day type of its date, which is the day the shift started on.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::api::{CalculationRequest, calculate_pay};
+/// use award_engine::calculation::{DayType, summarize_overtime};
+/// use award_engine::config::ConfigLoader;
+/// use award_engine::models::PayCategory;
+/// use rust_decimal::Decimal;
+///
+/// let config = ConfigLoader::load_embedded()?;
+/// let request = CalculationRequest::from_json(serde_json::json!({
+///     "employee": {
+///         "id": "emp_001",
+///         "employment_type": "full_time",
+///         "classification_code": "dce_level_3",
+///         "date_of_birth": "1990-01-15",
+///         "employment_start_date": "2023-06-01"
+///     },
+///     "pay_period": { "start_date": "2026-01-12", "end_date": "2026-01-18" },
+///     "shifts": [{
+///         "id": "shift_001",
+///         "date": "2026-01-13",
+///         "start_time": "2026-01-13T07:00:00",
+///         "end_time": "2026-01-13T19:00:00"
+///     }]
+/// }))
+/// .unwrap();
+///
+/// // 12 hours on a Tuesday: 2 hours at 150%, then 2 hours at 200%
+/// let overtime = summarize_overtime(&calculate_pay(request, &config)?);
+/// assert_eq!(overtime.total.hours, Decimal::from(4));
+/// assert_eq!(overtime.by_tier[&PayCategory::Overtime150].amount, Decimal::new(8562, 2));
+/// assert_eq!(overtime.by_day_type[&DayType::Weekday].hours, Decimal::from(4));
+/// assert_eq!(overtime.daily_threshold_shift_ids, vec!["shift_001".to_string()]);
+/// # Ok::<(), award_engine::error::EngineError>(())
+/// ```
+pub fn summarize_overtime(result: &CalculationResult) -> OvertimeAudit {
+    let mut audit = OvertimeAudit::default();
+    let overtime_lines = result.pay_lines.iter().filter(|line| {
+        matches!(
+            line.category,
+            PayCategory::Overtime150
+                | PayCategory::Overtime200
+                | PayCategory::Overtime250
+                | PayCategory::PublicHolidayOvertime
+        )
+    });
+    for line in overtime_lines {
+        audit.total.add(line.hours, line.amount);
+        audit
+            .by_tier
+            .entry(line.category)
+            .or_default()
+            .add(line.hours, line.amount);
+        audit
+            .by_day_type
+            .entry(get_day_type(line.date.and_time(NaiveTime::MIN)))
+            .or_default()
+            .add(line.hours, line.amount);
+        if !audit.daily_threshold_shift_ids.contains(&line.shift_id) {
+            audit.daily_threshold_shift_ids.push(line.shift_id.clone());
+        }
+    }
+    audit.daily_threshold_triggered = !audit.daily_threshold_shift_ids.is_empty();
+    audit
+}
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::api::{CalculationRequest, calculate_pay};
     use crate::calculation::{
-        calculate_weekday_overtime, detect_daily_overtime, get_base_rate,
-        DEFAULT_DAILY_OVERTIME_THRESHOLD,
+        DEFAULT_DAILY_OVERTIME_THRESHOLD, calculate_weekday_overtime, detect_daily_overtime,
+        get_base_rate,
     };
     use crate::config::ConfigLoader;
     use crate::models::{Employee, EmploymentType};
@@ -27,6 +151,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -35,6 +162,80 @@ mod tests {
         NaiveDate::from_ymd_opt(2026, 1, 15).unwrap() // Wednesday (weekday)
     }
 
+    /// Calculates a week's pay for a full-time employee working `shifts`,
+    /// each given as (id, start, end).
+    fn calculate(shifts: &[(&str, &str, &str)]) -> CalculationResult {
+        let shifts: Vec<serde_json::Value> = shifts
+            .iter()
+            .map(|(id, start, end)| {
+                serde_json::json!({
+                    "id": id,
+                    "date": &start[..10],
+                    "start_time": start,
+                    "end_time": end
+                })
+            })
+            .collect();
+        let request = CalculationRequest::from_json(serde_json::json!({
+            "employee": {
+                "id": "emp_001",
+                "employment_type": "full_time",
+                "classification_code": "dce_level_3",
+                "date_of_birth": "1990-01-15",
+                "employment_start_date": "2023-06-01"
+            },
+            "pay_period": { "start_date": "2026-01-12", "end_date": "2026-01-18" },
+            "shifts": shifts
+        }))
+        .unwrap();
+        calculate_pay(request, &ConfigLoader::load("config/ma000018").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_summary_totals_overtime_by_tier_and_day_type() {
+        let result = calculate(&[
+            ("shift_wed", "2026-01-14T07:00:00", "2026-01-14T18:00:00"),
+            ("shift_thu", "2026-01-15T09:00:00", "2026-01-15T17:00:00"),
+            ("shift_sat", "2026-01-17T07:00:00", "2026-01-17T17:00:00"),
+        ]);
+
+        let overtime = summarize_overtime(&result);
+
+        // Wednesday: 2h at 150% and 1h at 200%; Saturday: 2h at 200%
+        assert_eq!(overtime.total.hours, dec("5"));
+        assert_eq!(overtime.total.hours, result.totals.overtime_hours);
+        assert_eq!(overtime.by_tier[&PayCategory::Overtime150].hours, dec("2"));
+        assert_eq!(
+            overtime.by_tier[&PayCategory::Overtime150].amount,
+            dec("85.62")
+        );
+        assert_eq!(overtime.by_tier[&PayCategory::Overtime200].hours, dec("3"));
+        assert_eq!(
+            overtime.by_tier[&PayCategory::Overtime200].amount,
+            dec("171.24")
+        );
+        assert_eq!(overtime.by_day_type[&DayType::Weekday].hours, dec("3"));
+        assert_eq!(overtime.by_day_type[&DayType::Saturday].hours, dec("2"));
+        assert!(!overtime.by_day_type.contains_key(&DayType::Sunday));
+        assert_eq!(overtime.total.amount, dec("256.86"));
+        assert!(overtime.daily_threshold_triggered);
+        assert_eq!(
+            overtime.daily_threshold_shift_ids,
+            vec!["shift_wed".to_string(), "shift_sat".to_string()]
+        );
+        assert_eq!(overtime.weekly_threshold_triggered, None);
+    }
+
+    #[test]
+    fn test_summary_without_overtime_is_empty() {
+        let result = calculate(&[("shift_thu", "2026-01-15T09:00:00", "2026-01-15T17:00:00")]);
+
+        let overtime = summarize_overtime(&result);
+
+        assert_eq!(overtime, OvertimeAudit::default());
+        assert!(!overtime.daily_threshold_triggered);
+    }
+
     // ==========================================================================
     // AT-OT-001: 12h weekday shift audit trace
     // Verifies: Audit trace includes steps in order with correct rule_ids
@@ -52,15 +253,20 @@ mod tests {
         let mut step_number = 1;
 
         // Step 1: Base rate lookup
-        let base_rate_result = get_base_rate(&employee, date, &config, step_number).unwrap();
-        all_audit_steps.push(base_rate_result.audit_step.clone());
+        let base_rate_result = get_base_rate(&employee, date, &config, step_number, true).unwrap();
+        all_audit_steps.extend(base_rate_result.audit_step.clone());
         let base_rate = base_rate_result.rate;
         step_number += 1;
 
         // Step 2: Daily overtime detection
-        let overtime_detection =
-            detect_daily_overtime(worked_hours, DEFAULT_DAILY_OVERTIME_THRESHOLD, step_number);
-        all_audit_steps.push(overtime_detection.audit_step.clone());
+        let overtime_detection = detect_daily_overtime(
+            worked_hours,
+            DEFAULT_DAILY_OVERTIME_THRESHOLD,
+            None,
+            step_number,
+            true,
+        );
+        all_audit_steps.extend(overtime_detection.audit_step.clone());
         step_number += 1;
 
         // Note: For this test, we're focusing on overtime portion (after 8 hours)
@@ -75,11 +281,15 @@ mod tests {
             date,
             "shift_001",
             step_number,
+            true,
         );
         all_audit_steps.extend(overtime_result.audit_steps);
 
         // Verify we have the expected steps
-        assert!(all_audit_steps.len() >= 4, "Expected at least 4 audit steps");
+        assert!(
+            all_audit_steps.len() >= 4,
+            "Expected at least 4 audit steps"
+        );
 
         // Verify step rule_ids and clause_refs as per PRD
         assert_eq!(all_audit_steps[0].rule_id, "base_rate_lookup");
@@ -118,9 +328,13 @@ mod tests {
             date,
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.audit_steps.len() >= 1, "Expected at least 1 audit step");
+        assert!(
+            !result.audit_steps.is_empty(),
+            "Expected at least 1 audit step"
+        );
 
         let tier1_step = &result.audit_steps[0];
 
@@ -128,7 +342,10 @@ mod tests {
         assert_eq!(tier1_step.rule_id, "overtime_tier_1");
 
         // Verify input contains hours and base_rate
-        assert!(tier1_step.input.get("hours").is_some(), "Input should contain hours");
+        assert!(
+            tier1_step.input.get("hours").is_some(),
+            "Input should contain hours"
+        );
         assert!(
             tier1_step.input.get("base_rate").is_some(),
             "Input should contain base_rate"
@@ -178,9 +395,13 @@ mod tests {
             date,
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.audit_steps.len() >= 2, "Expected at least 2 audit steps");
+        assert!(
+            result.audit_steps.len() >= 2,
+            "Expected at least 2 audit steps"
+        );
 
         let tier2_step = &result.audit_steps[1];
 
@@ -188,7 +409,10 @@ mod tests {
         assert_eq!(tier2_step.rule_id, "overtime_tier_2");
 
         // Verify input contains hours and base_rate
-        assert!(tier2_step.input.get("hours").is_some(), "Input should contain hours");
+        assert!(
+            tier2_step.input.get("hours").is_some(),
+            "Input should contain hours"
+        );
         assert!(
             tier2_step.input.get("base_rate").is_some(),
             "Input should contain base_rate"
@@ -224,40 +448,31 @@ mod tests {
         let worked_hours = dec("12.0");
         let threshold = dec("8.0");
 
-        let result = detect_daily_overtime(worked_hours, threshold, 1);
+        let result = detect_daily_overtime(worked_hours, threshold, None, 1, true);
 
         // Verify rule_id and clause_ref
-        assert_eq!(result.audit_step.rule_id, "daily_overtime_detection");
-        assert_eq!(result.audit_step.clause_ref, "22.1(c), 25.1");
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.rule_id, "daily_overtime_detection");
+        assert_eq!(step.clause_ref, "22.1(c), 25.1");
 
         // Verify input contains worked_hours and threshold
-        assert_eq!(
-            result.audit_step.input["worked_hours"].as_str().unwrap(),
-            "12"
-        );
-        assert_eq!(result.audit_step.input["threshold"].as_str().unwrap(), "8");
+        assert_eq!(step.input["worked_hours"].as_str().unwrap(), "12");
+        assert_eq!(step.input["threshold"].as_str().unwrap(), "8");
 
         // Verify output contains ordinary_hours and overtime_hours
-        assert_eq!(
-            result.audit_step.output["ordinary_hours"].as_str().unwrap(),
-            "8"
-        );
-        assert_eq!(
-            result.audit_step.output["overtime_hours"].as_str().unwrap(),
-            "4"
-        );
+        assert_eq!(step.output["ordinary_hours"].as_str().unwrap(), "8");
+        assert_eq!(step.output["overtime_hours"].as_str().unwrap(), "4");
 
         // Verify reasoning explains the detection
         assert!(
-            result.audit_step.reasoning.contains("exceeds")
-                || result.audit_step.reasoning.contains("overtime"),
+            step.reasoning.contains("exceeds") || step.reasoning.contains("overtime"),
             "Reasoning should explain overtime detection"
         );
     }
 
     #[test]
     fn test_weekend_overtime_audit_step_format() {
-        use crate::calculation::{calculate_weekend_overtime, DayType};
+        use crate::calculation::{DayType, calculate_weekend_overtime};
 
         let config = ConfigLoader::load("config/ma000018")
             .unwrap()
@@ -277,10 +492,11 @@ mod tests {
             date,
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.audit_step.is_some());
-        let step = result.audit_step.unwrap();
+        assert_eq!(result.audit_steps.len(), 2);
+        let step = &result.audit_steps[0];
 
         // Verify rule_id
         assert_eq!(step.rule_id, "weekend_overtime");
@@ -325,6 +541,7 @@ mod tests {
             date,
             "shift_001",
             1,
+            true,
         );
 
         // Both tier 1 and tier 2 should mention casual loading
@@ -349,15 +566,20 @@ mod tests {
         let mut step_number = 1;
 
         // Step 1: Base rate lookup
-        let base_rate_result = get_base_rate(&employee, date, &config, step_number).unwrap();
-        all_audit_steps.push(base_rate_result.audit_step.clone());
+        let base_rate_result = get_base_rate(&employee, date, &config, step_number, true).unwrap();
+        all_audit_steps.extend(base_rate_result.audit_step.clone());
         let base_rate = base_rate_result.rate;
         step_number += 1;
 
         // Step 2: Daily overtime detection
-        let overtime_detection =
-            detect_daily_overtime(worked_hours, DEFAULT_DAILY_OVERTIME_THRESHOLD, step_number);
-        all_audit_steps.push(overtime_detection.audit_step.clone());
+        let overtime_detection = detect_daily_overtime(
+            worked_hours,
+            DEFAULT_DAILY_OVERTIME_THRESHOLD,
+            None,
+            step_number,
+            true,
+        );
+        all_audit_steps.extend(overtime_detection.audit_step.clone());
         step_number += 1;
 
         // Steps 3-4: Weekday overtime tiers
@@ -369,6 +591,7 @@ mod tests {
             date,
             "shift_001",
             step_number,
+            true,
         );
         all_audit_steps.extend(overtime_result.audit_steps);
 
@@ -391,11 +614,12 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let date = test_date();
 
-        let result = get_base_rate(&employee, date, &config, 1).unwrap();
+        let result = get_base_rate(&employee, date, &config, 1, true).unwrap();
 
         // Verify clause reference matches PRD requirement
-        assert_eq!(result.audit_step.rule_id, "base_rate_lookup");
-        assert_eq!(result.audit_step.clause_ref, "14.2");
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.rule_id, "base_rate_lookup");
+        assert_eq!(step.clause_ref, "14.2");
     }
 
     #[test]
@@ -411,15 +635,34 @@ mod tests {
         let mut step_number = 1;
 
         // Step 1: Base rate lookup
-        let base_rate_result = get_base_rate(&employee, date, &config, step_number).unwrap();
-        rule_ids.push(base_rate_result.audit_step.rule_id.clone());
+        let base_rate_result = get_base_rate(&employee, date, &config, step_number, true).unwrap();
+        rule_ids.push(
+            base_rate_result
+                .audit_step
+                .as_ref()
+                .unwrap()
+                .rule_id
+                .clone(),
+        );
         let base_rate = base_rate_result.rate;
         step_number += 1;
 
         // Step 2: Daily overtime detection
-        let overtime_detection =
-            detect_daily_overtime(worked_hours, DEFAULT_DAILY_OVERTIME_THRESHOLD, step_number);
-        rule_ids.push(overtime_detection.audit_step.rule_id.clone());
+        let overtime_detection = detect_daily_overtime(
+            worked_hours,
+            DEFAULT_DAILY_OVERTIME_THRESHOLD,
+            None,
+            step_number,
+            true,
+        );
+        rule_ids.push(
+            overtime_detection
+                .audit_step
+                .as_ref()
+                .unwrap()
+                .rule_id
+                .clone(),
+        );
         step_number += 1;
 
         // Steps 3-4: Weekday overtime tiers (for the 4 hours of overtime)
@@ -431,6 +674,7 @@ mod tests {
             date,
             "shift_001",
             step_number,
+            true,
         );
         for step in &overtime_result.audit_steps {
             rule_ids.push(step.rule_id.clone());
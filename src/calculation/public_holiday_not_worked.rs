@@ -0,0 +1,569 @@
+//! Public holiday not worked payment functionality.
+//!
+//! This module provides the payment for a public holiday that a permanent
+//! employee would ordinarily have worked but did not, as per clause 24 of the
+//! Aged Care Award 2010, and the day in lieu for a public holiday that falls
+//! on their rostered day off.
+//!
+//! # Precedence
+//!
+//! Each public holiday in the pay period is settled by the first of these
+//! that applies:
+//!
+//! 1. Worked: the hours are paid at public holiday rates, and neither the
+//!    not-worked payment nor a day in lieu applies.
+//! 2. A rostered day off: the employee would not have worked it, so it is
+//!    not paid; a day in lieu is recorded in the accruals instead when one is
+//!    configured.
+//! 3. One of the employee's ordinary days: the not-worked payment applies.
+//!
+//! Casual employees receive neither, whatever days they list.
+
+use chrono::Datelike;
+use rust_decimal::Decimal;
+
+use crate::config::{PublicHolidayInLieuConfig, PublicHolidayNotWorkedConfig};
+use crate::models::{
+    AuditStep, DayInLieu, Employee, PayCategory, PayLine, PayPeriod, PublicHoliday, Shift,
+};
+
+use super::rules::RuleInfo;
+
+/// The public holiday not worked and day in lieu rules, for the rule
+/// catalogue; their clauses are configured.
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        rule_id: "public_holiday_not_worked",
+        rule_name: "Public Holiday Not Worked",
+        clause_ref: "configured",
+    },
+    RuleInfo {
+        rule_id: "public_holiday_in_lieu",
+        rule_name: "Public Holiday In Lieu",
+        clause_ref: "configured",
+    },
+];
+
+/// The result of assessing public holidays not worked.
+#[derive(Debug, Clone)]
+pub struct PublicHolidayNotWorkedResult {
+    /// A pay line for each public holiday not worked that is paid.
+    pub pay_lines: Vec<PayLine>,
+    /// An audit step for each public holiday in the pay period.
+    pub audit_steps: Vec<AuditStep>,
+}
+
+/// Returns the public holidays in the pay period the employee is paid for
+/// without working.
+///
+/// A holiday is paid when the employee is permanent, the holiday falls on one
+/// of their ordinary days and not on a rostered day off, and no shift was
+/// worked on that date.
+pub fn paid_public_holidays_not_worked<'a>(
+    employee: &Employee,
+    pay_period: &'a PayPeriod,
+    shifts: &[Shift],
+) -> Vec<&'a PublicHoliday> {
+    pay_period
+        .public_holidays
+        .iter()
+        .filter(|holiday| {
+            employee.employment_type.is_permanent()
+                && employee.ordinary_days.contains(&holiday.date.weekday())
+                && !employee.rostered_days_off.contains(&holiday.date)
+                && !shifts.iter().any(|s| s.date == holiday.date)
+        })
+        .collect()
+}
+
+/// Calculates the payment for public holidays not worked.
+///
+/// Each public holiday in the pay period is assessed and recorded in its own
+/// audit step. A paid holiday is paid at the base rate for the employee's
+/// maximum daily ordinary hours, or the configured ordinary hours when the
+/// employee has no daily maximum of their own.
+///
+/// # Arguments
+///
+/// * `employee` - The employee, including their ordinary days
+/// * `pay_period` - The pay period, including its public holidays
+/// * `shifts` - The shifts worked in the pay period
+/// * `base_rate` - The employee's base hourly rate
+/// * `config` - The configured clause and default ordinary hours
+/// * `step_number` - The step number of the first audit step
+/// * `include_audit` - Whether to build the audit steps
+///
+/// # Award Reference
+///
+/// Clause 24 of the Aged Care Award 2010 entitles an employee to be paid for
+/// a public holiday they would ordinarily have worked. Casual employees are
+/// not paid for public holidays they do not work.
+pub fn calculate_public_holidays_not_worked(
+    employee: &Employee,
+    pay_period: &PayPeriod,
+    shifts: &[Shift],
+    base_rate: Decimal,
+    config: &PublicHolidayNotWorkedConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> PublicHolidayNotWorkedResult {
+    let paid = paid_public_holidays_not_worked(employee, pay_period, shifts);
+    let hours = employee
+        .max_ordinary_hours_per_day
+        .unwrap_or(config.ordinary_hours);
+    let ordinary_days: Vec<String> = employee
+        .ordinary_days
+        .iter()
+        .map(|day| day.to_string())
+        .collect();
+
+    let mut pay_lines = Vec::new();
+    let mut audit_steps = Vec::new();
+    for (i, holiday) in pay_period.public_holidays.iter().enumerate() {
+        let weekday = holiday.date.weekday();
+        let worked = shifts.iter().any(|s| s.date == holiday.date);
+        let rostered_day_off = employee.rostered_days_off.contains(&holiday.date);
+        let is_paid = paid.iter().any(|h| h.date == holiday.date);
+
+        let amount = hours * base_rate;
+        if is_paid {
+            pay_lines.push(PayLine {
+                date: holiday.date,
+                shift_id: String::new(),
+                category: PayCategory::PublicHolidayNotWorked,
+                hours,
+                rate: base_rate,
+                amount,
+                clause_ref: config.clause.clone(),
+                reference: None,
+                cost_centre: None,
+            });
+        }
+        if !include_audit {
+            continue;
+        }
+
+        let output = if is_paid {
+            serde_json::json!({
+                "paid": true,
+                "hours": hours.normalize().to_string(),
+                "rate": base_rate.normalize().to_string(),
+                "amount": amount.normalize().to_string()
+            })
+        } else {
+            serde_json::json!({
+                "paid": false,
+                "amount": "0"
+            })
+        };
+
+        let reasoning = if employee.employment_type.is_casual() {
+            format!(
+                "{} ({}) not paid: casual employees are not paid for public holidays not worked",
+                holiday.name, holiday.date
+            )
+        } else if worked {
+            format!(
+                "{} ({}) was worked, so no not-worked payment applies",
+                holiday.name, holiday.date
+            )
+        } else if rostered_day_off {
+            format!(
+                "{} ({}) falls on the employee's rostered day off, so no not-worked payment applies",
+                holiday.name, holiday.date
+            )
+        } else if !is_paid {
+            format!(
+                "{} ({}) falls on a {}, which is not one of the employee's ordinary days",
+                holiday.name, holiday.date, weekday
+            )
+        } else {
+            format!(
+                "{} ({}) falls on an ordinary day ({}) and was not worked: {} hours × ${} = ${}",
+                holiday.name,
+                holiday.date,
+                weekday,
+                hours.normalize(),
+                base_rate.normalize(),
+                amount.normalize()
+            )
+        };
+
+        audit_steps.push(AuditStep {
+            step_number: step_number + i as u32,
+            rule_id: "public_holiday_not_worked".to_string(),
+            rule_name: "Public Holiday Not Worked".to_string(),
+            clause_ref: config.clause.clone(),
+            input: serde_json::json!({
+                "employee_id": employee.id,
+                "employment_type": employee.employment_type.as_str(),
+                "holiday_date": holiday.date.to_string(),
+                "holiday_name": holiday.name,
+                "weekday": weekday.to_string(),
+                "ordinary_days": ordinary_days,
+                "rostered_day_off": rostered_day_off,
+                "worked": worked
+            }),
+            output,
+            reasoning,
+        });
+    }
+
+    PublicHolidayNotWorkedResult {
+        pay_lines,
+        audit_steps,
+    }
+}
+
+/// The result of granting days in lieu for public holidays.
+#[derive(Debug, Clone)]
+pub struct PublicHolidayInLieuResult {
+    /// A day in lieu for each public holiday on a rostered day off.
+    pub days_in_lieu: Vec<DayInLieu>,
+    /// An audit step for each day in lieu granted.
+    pub audit_steps: Vec<AuditStep>,
+}
+
+/// Grants a day in lieu for each public holiday that falls on a permanent
+/// employee's rostered day off and was not worked.
+///
+/// The day in lieu is an entitlement to a later day off, not a payment: it is
+/// recorded in the accruals and produces no pay line. Its hours are the
+/// employee's maximum daily ordinary hours, or the configured ordinary hours
+/// when the employee has no daily maximum of their own. Holidays that do not
+/// qualify produce neither a day in lieu nor an audit step; see the module
+/// documentation for how this interacts with the not-worked payment.
+///
+/// # Arguments
+///
+/// * `employee` - The employee, including their rostered days off
+/// * `pay_period` - The pay period, including its public holidays
+/// * `shifts` - The shifts worked in the pay period
+/// * `config` - The configured clause and default ordinary hours
+/// * `step_number` - The step number of the first audit step
+/// * `include_audit` - Whether to build the audit steps
+pub fn calculate_public_holidays_in_lieu(
+    employee: &Employee,
+    pay_period: &PayPeriod,
+    shifts: &[Shift],
+    config: &PublicHolidayInLieuConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> PublicHolidayInLieuResult {
+    let hours = employee
+        .max_ordinary_hours_per_day
+        .unwrap_or(config.ordinary_hours);
+
+    let mut days_in_lieu = Vec::new();
+    let mut audit_steps = Vec::new();
+    for holiday in &pay_period.public_holidays {
+        if !employee.employment_type.is_permanent()
+            || !employee.rostered_days_off.contains(&holiday.date)
+            || shifts.iter().any(|s| s.date == holiday.date)
+        {
+            continue;
+        }
+
+        if include_audit {
+            audit_steps.push(AuditStep {
+                step_number: step_number + audit_steps.len() as u32,
+                rule_id: "public_holiday_in_lieu".to_string(),
+                rule_name: "Public Holiday In Lieu".to_string(),
+                clause_ref: config.clause.clone(),
+                input: serde_json::json!({
+                    "employee_id": employee.id,
+                    "employment_type": employee.employment_type.as_str(),
+                    "holiday_date": holiday.date.to_string(),
+                    "holiday_name": holiday.name
+                }),
+                output: serde_json::json!({
+                    "day_in_lieu": true,
+                    "hours": hours.normalize().to_string()
+                }),
+                reasoning: format!(
+                    "{} ({}) falls on the employee's rostered day off and was not worked: {} hours in lieu",
+                    holiday.name,
+                    holiday.date,
+                    hours.normalize()
+                ),
+            });
+        }
+        days_in_lieu.push(DayInLieu {
+            date: holiday.date,
+            name: holiday.name.clone(),
+            hours,
+        });
+    }
+
+    PublicHolidayInLieuResult {
+        days_in_lieu,
+        audit_steps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EmploymentType;
+    use chrono::{NaiveDate, NaiveDateTime, Weekday};
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn config() -> PublicHolidayNotWorkedConfig {
+        PublicHolidayNotWorkedConfig {
+            clause: "24".to_string(),
+            ordinary_hours: dec("7.6"),
+        }
+    }
+
+    fn in_lieu_config() -> PublicHolidayInLieuConfig {
+        PublicHolidayInLieuConfig {
+            clause: "24.3".to_string(),
+            ordinary_hours: dec("7.6"),
+        }
+    }
+
+    fn australia_day() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 26).unwrap()
+    }
+
+    fn create_test_employee(
+        employment_type: EmploymentType,
+        ordinary_days: Vec<Weekday>,
+    ) -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days,
+            rostered_days_off: vec![],
+            tags: vec![],
+        }
+    }
+
+    /// A pay period ending on Australia Day, Monday 2026-01-26.
+    fn pay_period() -> PayPeriod {
+        PayPeriod {
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 20).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 1, 26).unwrap(),
+            public_holidays: vec![PublicHoliday {
+                date: NaiveDate::from_ymd_opt(2026, 1, 26).unwrap(),
+                name: "Australia Day".to_string(),
+                region: "national".to_string(),
+            }],
+            period_type: None,
+        }
+    }
+
+    fn shift(id: &str, start: &str, end: &str) -> Shift {
+        let start_time = NaiveDateTime::parse_from_str(start, "%Y-%m-%dT%H:%M:%S").unwrap();
+        Shift {
+            id: id.to_string(),
+            date: start_time.date(),
+            start_time,
+            end_time: NaiveDateTime::parse_from_str(end, "%Y-%m-%dT%H:%M:%S").unwrap(),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    const WEEKDAYS: [Weekday; 5] = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+    ];
+
+    #[test]
+    fn test_holiday_on_ordinary_day_not_worked_is_paid() {
+        let employee = create_test_employee(EmploymentType::FullTime, WEEKDAYS.to_vec());
+
+        let result = calculate_public_holidays_not_worked(
+            &employee,
+            &pay_period(),
+            &[],
+            dec("28.54"),
+            &config(),
+            5,
+            true,
+        );
+
+        assert_eq!(result.pay_lines.len(), 1);
+        let line = &result.pay_lines[0];
+        assert_eq!(line.category, PayCategory::PublicHolidayNotWorked);
+        assert_eq!(line.hours, dec("7.6"));
+        assert_eq!(line.amount, dec("216.904")); // 7.6 × 28.54
+        assert_eq!(line.clause_ref, "24");
+
+        let step = &result.audit_steps[0];
+        assert_eq!(step.step_number, 5);
+        assert_eq!(step.rule_id, "public_holiday_not_worked");
+        assert!(step.output["paid"].as_bool().unwrap());
+        assert_eq!(step.input["weekday"], "Mon");
+    }
+
+    #[test]
+    fn test_daily_ordinary_hours_cap_sets_hours_paid() {
+        let mut employee = create_test_employee(EmploymentType::PartTime, vec![Weekday::Mon]);
+        employee.max_ordinary_hours_per_day = Some(dec("6"));
+
+        let result = calculate_public_holidays_not_worked(
+            &employee,
+            &pay_period(),
+            &[],
+            dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines[0].hours, dec("6"));
+        assert_eq!(result.pay_lines[0].amount, dec("171.24"));
+    }
+
+    #[test]
+    fn test_holiday_on_non_ordinary_day_is_not_paid() {
+        let employee = create_test_employee(EmploymentType::PartTime, vec![Weekday::Tue]);
+
+        let result = calculate_public_holidays_not_worked(
+            &employee,
+            &pay_period(),
+            &[],
+            dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+
+        assert!(result.pay_lines.is_empty());
+        assert!(!result.audit_steps[0].output["paid"].as_bool().unwrap());
+        assert!(
+            result.audit_steps[0]
+                .reasoning
+                .contains("not one of the employee's ordinary days")
+        );
+    }
+
+    #[test]
+    fn test_worked_holiday_is_not_paid_as_not_worked() {
+        let employee = create_test_employee(EmploymentType::FullTime, WEEKDAYS.to_vec());
+        let shifts = vec![shift("s1", "2026-01-26T09:00:00", "2026-01-26T17:00:00")];
+
+        let result = calculate_public_holidays_not_worked(
+            &employee,
+            &pay_period(),
+            &shifts,
+            dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+
+        assert!(result.pay_lines.is_empty());
+        assert!(result.audit_steps[0].input["worked"].as_bool().unwrap());
+    }
+
+    #[test]
+    fn test_casual_is_not_paid_for_holiday_not_worked() {
+        let employee = create_test_employee(EmploymentType::Casual, WEEKDAYS.to_vec());
+
+        let result = calculate_public_holidays_not_worked(
+            &employee,
+            &pay_period(),
+            &[],
+            dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+
+        assert!(result.pay_lines.is_empty());
+        assert!(
+            result.audit_steps[0]
+                .reasoning
+                .contains("casual employees are not paid")
+        );
+    }
+
+    #[test]
+    fn test_holiday_on_rostered_day_off_earns_a_day_in_lieu_not_pay() {
+        let mut employee = create_test_employee(EmploymentType::FullTime, WEEKDAYS.to_vec());
+        employee.rostered_days_off = vec![australia_day()];
+
+        let not_worked = calculate_public_holidays_not_worked(
+            &employee,
+            &pay_period(),
+            &[],
+            dec("28.54"),
+            &config(),
+            1,
+            true,
+        );
+        let in_lieu = calculate_public_holidays_in_lieu(
+            &employee,
+            &pay_period(),
+            &[],
+            &in_lieu_config(),
+            2,
+            true,
+        );
+
+        assert!(not_worked.pay_lines.is_empty());
+        assert!(
+            not_worked.audit_steps[0]
+                .reasoning
+                .contains("rostered day off")
+        );
+        assert_eq!(
+            in_lieu.days_in_lieu,
+            vec![DayInLieu {
+                date: australia_day(),
+                name: "Australia Day".to_string(),
+                hours: dec("7.6"),
+            }]
+        );
+        assert_eq!(in_lieu.audit_steps.len(), 1);
+        assert_eq!(in_lieu.audit_steps[0].step_number, 2);
+        assert_eq!(in_lieu.audit_steps[0].clause_ref, "24.3");
+    }
+
+    #[test]
+    fn test_no_day_in_lieu_when_worked_or_casual() {
+        let mut employee = create_test_employee(EmploymentType::FullTime, WEEKDAYS.to_vec());
+        employee.rostered_days_off = vec![australia_day()];
+        let shifts = vec![shift("s1", "2026-01-26T09:00:00", "2026-01-26T17:00:00")];
+
+        let worked = calculate_public_holidays_in_lieu(
+            &employee,
+            &pay_period(),
+            &shifts,
+            &in_lieu_config(),
+            1,
+            true,
+        );
+        employee.employment_type = EmploymentType::Casual;
+        let casual = calculate_public_holidays_in_lieu(
+            &employee,
+            &pay_period(),
+            &[],
+            &in_lieu_config(),
+            1,
+            true,
+        );
+
+        assert!(worked.days_in_lieu.is_empty());
+        assert!(worked.audit_steps.is_empty());
+        assert!(casual.days_in_lieu.is_empty());
+    }
+}
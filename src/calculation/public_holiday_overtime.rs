@@ -0,0 +1,298 @@
+//! Public holiday overtime rate calculation functionality.
+//!
+//! This module calculates overtime pay for hours worked on a public holiday
+//! when `overtime.public_holiday` is configured. Every overtime hour worked on
+//! the holiday is paid at the configured public holiday rate, in place of the
+//! weekday or weekend overtime rates for the day the holiday falls on. When a
+//! shift's overtime crosses midnight, only the portion on the holiday is paid
+//! here.
+//!
+//! As with the other overtime rates, the configured casual rate includes the
+//! casual loading, which `overtime.casual_ot_base: base` removes.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::calculation::{casual_overtime_audit_step, compose_casual_overtime};
+use crate::config::{CasualOvertimeBase, PublicHolidayOvertimeConfig};
+use crate::models::{AuditStep, Employee, EmploymentType, OvertimeTier, PayCategory, PayLine};
+
+use super::rules::RuleInfo;
+
+/// The public holiday overtime rule, for the rule catalogue; its clause is
+/// configured.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "public_holiday_overtime",
+    rule_name: "Public Holiday Overtime",
+    clause_ref: "configured",
+}];
+
+/// The result of public holiday overtime calculation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicHolidayOvertimeResult {
+    /// The public holiday overtime pay line (empty when there is no overtime).
+    pub pay_lines: Vec<PayLine>,
+    /// The single tier behind the pay line, which applies from the first
+    /// overtime hour.
+    pub tiers: Vec<OvertimeTier>,
+    /// Audit steps recording the calculation.
+    pub audit_steps: Vec<AuditStep>,
+}
+
+/// Calculates overtime pay for a shift worked on a public holiday.
+///
+/// All overtime hours are paid in a single [`PayCategory::PublicHolidayOvertime`]
+/// line at `base_rate × multiplier`, where the multiplier is the configured
+/// rate for the employee's employment type. The configured casual rate
+/// includes the casual loading, which is removed under
+/// `CasualOvertimeBase::Base`; a final audit step records how it was composed.
+///
+/// # Arguments
+///
+/// * `overtime_hours` - The total overtime hours to be paid
+/// * `base_rate` - The base hourly rate (before any loading)
+/// * `employee` - The employee receiving overtime pay
+/// * `config` - The public holiday overtime configuration
+/// * `casual_ot_base` - How casual overtime rates treat the casual loading
+/// * `date` - The date of the shift for pay line records
+/// * `shift_id` - The shift ID for pay line records
+/// * `step_number_start` - The starting step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit steps
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::calculate_public_holiday_overtime;
+/// use award_engine::config::{CasualOvertimeBase, OvertimeRates, PublicHolidayOvertimeConfig};
+/// use award_engine::models::{Employee, EmploymentType, PayCategory};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+/// use std::str::FromStr;
+///
+/// let config = PublicHolidayOvertimeConfig {
+///     clause: "25.1(a)(ii)".to_string(),
+///     rates: OvertimeRates {
+///         full_time: Decimal::from_str("2.5").unwrap(),
+///         part_time: Decimal::from_str("2.5").unwrap(),
+///         casual: Decimal::from_str("3.125").unwrap(),
+///     },
+/// };
+/// let employee = Employee {
+///     id: "emp_001".to_string(),
+///     employment_type: EmploymentType::FullTime,
+///     classification_code: "dce_level_3".to_string(),
+///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
+///     tags: vec![],
+/// };
+///
+/// let result = calculate_public_holiday_overtime(
+///     Decimal::from(2),
+///     Decimal::from_str("28.54").unwrap(),
+///     &employee,
+///     &config,
+///     CasualOvertimeBase::Loaded,
+///     NaiveDate::from_ymd_opt(2026, 1, 26).unwrap(),
+///     "shift_001",
+///     1,
+///     true,
+/// );
+///
+/// assert_eq!(result.pay_lines.len(), 1);
+/// assert_eq!(result.pay_lines[0].category, PayCategory::PublicHolidayOvertime);
+/// assert_eq!(result.pay_lines[0].amount, Decimal::from_str("142.70").unwrap());
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_public_holiday_overtime(
+    overtime_hours: Decimal,
+    base_rate: Decimal,
+    employee: &Employee,
+    config: &PublicHolidayOvertimeConfig,
+    casual_ot_base: CasualOvertimeBase,
+    date: NaiveDate,
+    shift_id: &str,
+    step_number_start: u32,
+    include_audit: bool,
+) -> PublicHolidayOvertimeResult {
+    let mut pay_lines = Vec::new();
+    let mut tiers = Vec::new();
+    let mut audit_steps = Vec::new();
+
+    if overtime_hours <= Decimal::ZERO {
+        return PublicHolidayOvertimeResult {
+            pay_lines,
+            tiers,
+            audit_steps,
+        };
+    }
+
+    let configured = match employee.employment_type {
+        EmploymentType::FullTime => config.rates.full_time,
+        EmploymentType::PartTime => config.rates.part_time,
+        EmploymentType::Casual => config.rates.casual,
+    };
+    let composition = compose_casual_overtime(configured, casual_ot_base);
+    let multiplier = if employee.is_casual() {
+        composition.multiplier
+    } else {
+        configured
+    };
+
+    let rate = base_rate * multiplier;
+    let amount = overtime_hours * rate;
+
+    if include_audit {
+        audit_steps.push(AuditStep {
+            step_number: step_number_start,
+            rule_id: "public_holiday_overtime".to_string(),
+            rule_name: "Public Holiday Overtime".to_string(),
+            clause_ref: config.clause.clone(),
+            input: serde_json::json!({
+                "hours": overtime_hours.normalize().to_string(),
+                "base_rate": base_rate.normalize().to_string(),
+                "employment_type": employee.employment_type.as_str(),
+                "date": date.to_string()
+            }),
+            output: serde_json::json!({
+                "multiplier": multiplier.normalize().to_string(),
+                "rate": rate.normalize().to_string(),
+                "amount": amount.normalize().to_string()
+            }),
+            reasoning: format!(
+                "Overtime on a public holiday at {}%: {} hours × ${} = ${}",
+                (multiplier * Decimal::from(100)).normalize(),
+                overtime_hours.normalize(),
+                rate.normalize(),
+                amount.normalize()
+            ),
+        });
+    }
+
+    let pay_line = PayLine {
+        date,
+        shift_id: shift_id.to_string(),
+        category: PayCategory::PublicHolidayOvertime,
+        hours: overtime_hours,
+        rate,
+        amount,
+        clause_ref: config.clause.clone(),
+        reference: None,
+        cost_centre: None,
+    };
+    tiers.push(OvertimeTier::from_pay_line(
+        &pay_line,
+        1,
+        Decimal::ZERO,
+        multiplier,
+    ));
+    pay_lines.push(pay_line);
+
+    if include_audit && employee.is_casual() {
+        audit_steps.push(casual_overtime_audit_step(
+            casual_ot_base,
+            &[("public_holiday", composition)],
+            &config.clause,
+            step_number_start + 1,
+        ));
+    }
+
+    PublicHolidayOvertimeResult {
+        pay_lines,
+        tiers,
+        audit_steps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::OvertimeRates;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn create_test_employee(employment_type: EmploymentType) -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn config() -> PublicHolidayOvertimeConfig {
+        PublicHolidayOvertimeConfig {
+            clause: "25.1(a)(ii)".to_string(),
+            rates: OvertimeRates {
+                full_time: dec("2.5"),
+                part_time: dec("2.5"),
+                casual: dec("3.125"),
+            },
+        }
+    }
+
+    fn calculate(employment_type: EmploymentType, hours: &str) -> PublicHolidayOvertimeResult {
+        calculate_public_holiday_overtime(
+            dec(hours),
+            dec("28.54"),
+            &create_test_employee(employment_type),
+            &config(),
+            CasualOvertimeBase::Loaded,
+            NaiveDate::from_ymd_opt(2026, 1, 26).unwrap(),
+            "shift_001",
+            5,
+            true,
+        )
+    }
+
+    #[test]
+    fn test_full_time_overtime_paid_at_public_holiday_rate() {
+        let result = calculate(EmploymentType::FullTime, "2");
+
+        assert_eq!(result.pay_lines.len(), 1);
+        let line = &result.pay_lines[0];
+        assert_eq!(line.category, PayCategory::PublicHolidayOvertime);
+        assert_eq!(line.hours, dec("2"));
+        assert_eq!(line.rate, dec("71.35"));
+        assert_eq!(line.amount, dec("142.70"));
+        assert_eq!(line.clause_ref, "25.1(a)(ii)");
+
+        assert_eq!(result.audit_steps.len(), 1);
+        let step = &result.audit_steps[0];
+        assert_eq!(step.step_number, 5);
+        assert_eq!(step.rule_id, "public_holiday_overtime");
+        assert_eq!(step.output["multiplier"], "2.5");
+    }
+
+    #[test]
+    fn test_casual_overtime_includes_casual_loading() {
+        let result = calculate(EmploymentType::Casual, "2");
+
+        // The configured 312.5% is 250% × 1.25 casual loading
+        assert_eq!(result.pay_lines[0].rate, dec("89.1875"));
+        assert_eq!(result.audit_steps.len(), 2);
+        assert_eq!(result.audit_steps[1].step_number, 6);
+        assert_eq!(result.audit_steps[1].clause_ref, "25.1(a)(ii)");
+    }
+
+    #[test]
+    fn test_no_overtime_produces_nothing() {
+        let result = calculate(EmploymentType::FullTime, "0");
+
+        assert!(result.pay_lines.is_empty());
+        assert!(result.audit_steps.is_empty());
+    }
+}
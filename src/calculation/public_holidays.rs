@@ -0,0 +1,228 @@
+//! Public holiday detection functionality.
+//!
+//! This module works out which public holidays apply to a pay period. Holidays
+//! listed explicitly in the request are always kept; when the request names a
+//! state, the state's holidays from the holiday calendar are merged in.
+
+use crate::config::HolidayCalendar;
+use crate::models::{AuditStep, PayPeriod, PublicHoliday};
+
+use super::rules::RuleInfo;
+
+/// The public holiday detection rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "public_holiday_detection",
+    rule_name: "Public Holiday Detection",
+    clause_ref: "N/A",
+}];
+
+/// The result of detecting public holidays for a pay period.
+#[derive(Debug, Clone)]
+pub struct PublicHolidayDetectionResult {
+    /// The public holidays that apply to the pay period, in date order.
+    pub public_holidays: Vec<PublicHoliday>,
+    /// The audit step listing the recognised holidays, or `None` when no state
+    /// was given and no holidays were listed in the request.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Detects the public holidays that apply to a pay period.
+///
+/// Holidays listed in the pay period are kept as given. When a state is
+/// provided, calendar holidays for that state falling within the pay period
+/// are added, except on dates already covered by a listed holiday.
+///
+/// # Arguments
+///
+/// * `pay_period` - The pay period, including any explicitly listed holidays
+/// * `state` - The state the work was performed in, if known
+/// * `calendar` - The configured public holiday calendar
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Returns
+///
+/// Returns a `PublicHolidayDetectionResult` with the merged holidays. No audit
+/// step is produced when there is no state and no listed holiday.
+pub fn detect_public_holidays(
+    pay_period: &PayPeriod,
+    state: Option<&str>,
+    calendar: &HolidayCalendar,
+    step_number: u32,
+    include_audit: bool,
+) -> PublicHolidayDetectionResult {
+    let explicit = &pay_period.public_holidays;
+    if state.is_none() && explicit.is_empty() {
+        return PublicHolidayDetectionResult {
+            public_holidays: vec![],
+            audit_step: None,
+        };
+    }
+
+    let mut recognised: Vec<(PublicHoliday, &str)> =
+        explicit.iter().map(|h| (h.clone(), "request")).collect();
+    if let Some(state) = state {
+        for holiday in calendar.holidays_between(state, pay_period.start_date, pay_period.end_date)
+        {
+            if !explicit.iter().any(|h| h.date == holiday.date) {
+                recognised.push((holiday, "calendar"));
+            }
+        }
+    }
+    recognised.sort_by_key(|(h, _)| h.date);
+
+    let audit_step = include_audit.then(|| {
+        let listed: Vec<serde_json::Value> = recognised
+            .iter()
+            .map(|(h, source)| {
+                serde_json::json!({
+                    "date": h.date.to_string(),
+                    "name": h.name,
+                    "region": h.region,
+                    "source": source
+                })
+            })
+            .collect();
+        let reasoning = if recognised.is_empty() {
+            format!(
+                "No public holidays fall within the pay period {} to {}",
+                pay_period.start_date, pay_period.end_date
+            )
+        } else {
+            let names: Vec<String> = recognised
+                .iter()
+                .map(|(h, source)| format!("{} {} ({})", h.date, h.name, source))
+                .collect();
+            format!(
+                "Recognised {} public holiday(s): {}",
+                recognised.len(),
+                names.join(", ")
+            )
+        };
+
+        AuditStep {
+            step_number,
+            rule_id: "public_holiday_detection".to_string(),
+            rule_name: "Public Holiday Detection".to_string(),
+            clause_ref: "N/A".to_string(),
+            input: serde_json::json!({
+                "state": state,
+                "pay_period_start": pay_period.start_date.to_string(),
+                "pay_period_end": pay_period.end_date.to_string(),
+                "requested_holidays": explicit.len()
+            }),
+            output: serde_json::json!({
+                "public_holidays": listed
+            }),
+            reasoning,
+        }
+    });
+
+    PublicHolidayDetectionResult {
+        public_holidays: recognised.into_iter().map(|(h, _)| h).collect(),
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CalendarHoliday;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn calendar() -> HolidayCalendar {
+        HolidayCalendar {
+            national: vec![CalendarHoliday {
+                date: date(2026, 1, 26),
+                name: "Australia Day".to_string(),
+            }],
+            states: HashMap::from([(
+                "VIC".to_string(),
+                vec![CalendarHoliday {
+                    date: date(2026, 3, 9),
+                    name: "Labour Day".to_string(),
+                }],
+            )]),
+        }
+    }
+
+    fn pay_period(public_holidays: Vec<PublicHoliday>) -> PayPeriod {
+        PayPeriod {
+            start_date: date(2026, 1, 19),
+            end_date: date(2026, 3, 15),
+            public_holidays,
+            period_type: None,
+        }
+    }
+
+    fn holiday(date: NaiveDate, name: &str) -> PublicHoliday {
+        PublicHoliday {
+            date,
+            name: name.to_string(),
+            region: "national".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_no_state_and_no_holidays_produces_no_audit_step() {
+        let result = detect_public_holidays(&pay_period(vec![]), None, &calendar(), 1, true);
+
+        assert!(result.public_holidays.is_empty());
+        assert!(result.audit_step.is_none());
+    }
+
+    #[test]
+    fn test_state_adds_national_and_state_holidays() {
+        let result = detect_public_holidays(&pay_period(vec![]), Some("VIC"), &calendar(), 2, true);
+
+        assert_eq!(result.public_holidays.len(), 2);
+        assert_eq!(result.public_holidays[0].name, "Australia Day");
+        assert_eq!(result.public_holidays[1].region, "VIC");
+
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 2);
+        assert_eq!(step.rule_id, "public_holiday_detection");
+        assert_eq!(step.output["public_holidays"][0]["source"], "calendar");
+    }
+
+    #[test]
+    fn test_explicit_holidays_are_merged_and_take_precedence() {
+        let explicit = vec![
+            holiday(date(2026, 1, 26), "Australia Day (observed)"),
+            holiday(date(2026, 2, 2), "Local Show Day"),
+        ];
+
+        let result =
+            detect_public_holidays(&pay_period(explicit), Some("VIC"), &calendar(), 1, true);
+
+        let names: Vec<&str> = result
+            .public_holidays
+            .iter()
+            .map(|h| h.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["Australia Day (observed)", "Local Show Day", "Labour Day"]
+        );
+
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.output["public_holidays"][0]["source"], "request");
+        assert_eq!(step.output["public_holidays"][2]["source"], "calendar");
+    }
+
+    #[test]
+    fn test_calendar_holidays_outside_pay_period_are_ignored() {
+        let mut period = pay_period(vec![]);
+        period.end_date = date(2026, 2, 28);
+
+        let result = detect_public_holidays(&period, Some("VIC"), &calendar(), 1, true);
+
+        assert_eq!(result.public_holidays.len(), 1);
+        assert_eq!(result.public_holidays[0].name, "Australia Day");
+    }
+}
@@ -0,0 +1,314 @@
+//! Qualification allowance calculation functionality.
+//!
+//! This module provides functions for calculating the qualification (certificate)
+//! allowance for employees as per clause 15 of the Aged Care Award 2010.
+
+use rust_decimal::Decimal;
+
+use crate::config::{QualificationAllowanceBasis, QualificationAllowanceRate};
+use crate::models::{AllowancePayment, AllowanceType, AuditStep, Employee};
+
+use super::rules::RuleInfo;
+
+/// The qualification allowance rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "qualification_allowance",
+    rule_name: "Qualification Allowance",
+    clause_ref: QUALIFICATION_ALLOWANCE_CLAUSE,
+}];
+
+/// The tag that enables qualification allowance for an employee.
+pub const QUALIFICATION_ALLOWANCE_TAG: &str = "qualification_allowance";
+
+/// The clause reference for qualification allowance.
+pub const QUALIFICATION_ALLOWANCE_CLAUSE: &str = "15";
+
+/// The result of calculating qualification allowance, including the payment and audit step.
+#[derive(Debug, Clone)]
+pub struct QualificationAllowanceResult {
+    /// The allowance payment, if the employee is eligible.
+    pub allowance: Option<AllowancePayment>,
+    /// The audit step recording this calculation, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Calculates qualification allowance for an employee holding a relevant qualification.
+///
+/// The allowance is paid to employees who have the `qualification_allowance` tag.
+/// Depending on the configured basis, the amount is paid for each hour worked or
+/// once for each award week in the pay period.
+///
+/// # Arguments
+///
+/// * `employee` - The employee to calculate allowance for
+/// * `hours_worked` - The total hours worked in the pay period
+/// * `weeks` - The number of award weeks in the pay period
+/// * `rate` - The configured qualification allowance amount and basis
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Returns
+///
+/// Returns a `QualificationAllowanceResult` containing:
+/// - `Some(AllowancePayment)` if the employee has the qualification_allowance tag
+/// - `None` if the employee does not have the tag
+///
+/// # Award Reference
+///
+/// Clause 15 of the Aged Care Award 2010 specifies the qualification allowance.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::calculate_qualification_allowance;
+/// use award_engine::config::{QualificationAllowanceBasis, QualificationAllowanceRate};
+/// use award_engine::models::{Employee, EmploymentType};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+/// use std::str::FromStr;
+///
+/// let employee = Employee {
+///     id: "emp_001".to_string(),
+///     employment_type: EmploymentType::FullTime,
+///     classification_code: "dce_level_3".to_string(),
+///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
+///     tags: vec!["qualification_allowance".to_string()],
+/// };
+///
+/// let rate = QualificationAllowanceRate {
+///     amount: Decimal::from_str("0.50").unwrap(),
+///     basis: QualificationAllowanceBasis::PerHour,
+/// };
+///
+/// let result = calculate_qualification_allowance(
+///     &employee,
+///     Decimal::from_str("16").unwrap(),
+///     1,
+///     &rate,
+///     1,
+///     true,
+/// );
+///
+/// let allowance = result.allowance.unwrap();
+/// assert_eq!(allowance.amount, Decimal::from_str("8.00").unwrap());
+/// ```
+pub fn calculate_qualification_allowance(
+    employee: &Employee,
+    hours_worked: Decimal,
+    weeks: u32,
+    rate: &QualificationAllowanceRate,
+    step_number: u32,
+    include_audit: bool,
+) -> QualificationAllowanceResult {
+    let has_tag = employee
+        .tags
+        .contains(&QUALIFICATION_ALLOWANCE_TAG.to_string());
+
+    if !has_tag {
+        let audit_step = include_audit.then(|| AuditStep {
+            step_number,
+            rule_id: "qualification_allowance".to_string(),
+            rule_name: "Qualification Allowance".to_string(),
+            clause_ref: QUALIFICATION_ALLOWANCE_CLAUSE.to_string(),
+            input: serde_json::json!({
+                "employee_id": employee.id,
+                "has_qualification_tag": false,
+                "hours_worked": hours_worked.normalize().to_string()
+            }),
+            output: serde_json::json!({
+                "eligible": false,
+                "amount": "0.00"
+            }),
+            reasoning: "Employee does not have 'qualification_allowance' tag - not eligible for qualification allowance".to_string(),
+        });
+
+        return QualificationAllowanceResult {
+            allowance: None,
+            audit_step,
+        };
+    }
+
+    let (units, basis) = match rate.basis {
+        QualificationAllowanceBasis::PerHour => (hours_worked, "per_hour"),
+        QualificationAllowanceBasis::PerWeek => (Decimal::from(weeks), "per_week"),
+    };
+    let amount = units * rate.amount;
+
+    let audit_step = include_audit.then(|| AuditStep {
+        step_number,
+        rule_id: "qualification_allowance".to_string(),
+        rule_name: "Qualification Allowance".to_string(),
+        clause_ref: QUALIFICATION_ALLOWANCE_CLAUSE.to_string(),
+        input: serde_json::json!({
+            "employee_id": employee.id,
+            "has_qualification_tag": true,
+            "hours_worked": hours_worked.normalize().to_string(),
+            "basis": basis,
+            "rate": rate.amount.normalize().to_string()
+        }),
+        output: serde_json::json!({
+            "eligible": true,
+            "units": units.normalize().to_string(),
+            "amount": amount.normalize().to_string()
+        }),
+        reasoning: match rate.basis {
+            QualificationAllowanceBasis::PerHour => format!(
+                "{} hours × ${} = ${}",
+                hours_worked.normalize(),
+                rate.amount.normalize(),
+                amount.normalize()
+            ),
+            QualificationAllowanceBasis::PerWeek => format!(
+                "{} week(s) × ${} = ${}",
+                weeks,
+                rate.amount.normalize(),
+                amount.normalize()
+            ),
+        },
+    });
+
+    let allowance = AllowancePayment {
+        allowance_type: AllowanceType::Qualification,
+        description: "Qualification Allowance".to_string(),
+        units,
+        rate: rate.amount,
+        amount,
+        clause_ref: QUALIFICATION_ALLOWANCE_CLAUSE.to_string(),
+    };
+
+    QualificationAllowanceResult {
+        allowance: Some(allowance),
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EmploymentType;
+    use chrono::NaiveDate;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn create_test_employee(tags: Vec<String>) -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type: EmploymentType::FullTime,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags,
+        }
+    }
+
+    fn per_hour_rate() -> QualificationAllowanceRate {
+        QualificationAllowanceRate {
+            amount: dec("0.50"),
+            basis: QualificationAllowanceBasis::PerHour,
+        }
+    }
+
+    fn per_week_rate() -> QualificationAllowanceRate {
+        QualificationAllowanceRate {
+            amount: dec("21.40"),
+            basis: QualificationAllowanceBasis::PerWeek,
+        }
+    }
+
+    #[test]
+    fn test_per_hour_allowance_scales_with_hours_worked() {
+        let employee = create_test_employee(vec!["qualification_allowance".to_string()]);
+
+        let short =
+            calculate_qualification_allowance(&employee, dec("8"), 1, &per_hour_rate(), 1, true);
+        let long =
+            calculate_qualification_allowance(&employee, dec("38"), 1, &per_hour_rate(), 1, true);
+
+        let short = short.allowance.unwrap();
+        assert_eq!(short.allowance_type, AllowanceType::Qualification);
+        assert_eq!(short.units, dec("8"));
+        assert_eq!(short.rate, dec("0.50"));
+        assert_eq!(short.amount, dec("4.00")); // 8 × 0.50
+
+        let long = long.allowance.unwrap();
+        assert_eq!(long.units, dec("38"));
+        assert_eq!(long.amount, dec("19.00")); // 38 × 0.50
+    }
+
+    #[test]
+    fn test_per_week_allowance_does_not_scale_with_hours_worked() {
+        let employee = create_test_employee(vec!["qualification_allowance".to_string()]);
+
+        let short =
+            calculate_qualification_allowance(&employee, dec("8"), 1, &per_week_rate(), 1, true);
+        let long =
+            calculate_qualification_allowance(&employee, dec("38"), 1, &per_week_rate(), 1, true);
+
+        for result in [short, long] {
+            let allowance = result.allowance.unwrap();
+            assert_eq!(allowance.units, dec("1"));
+            assert_eq!(allowance.rate, dec("21.40"));
+            assert_eq!(allowance.amount, dec("21.40"));
+            let step = result.audit_step.unwrap();
+            assert_eq!(step.input["basis"], "per_week");
+        }
+    }
+
+    #[test]
+    fn test_per_week_allowance_repeats_for_each_week() {
+        let employee = create_test_employee(vec!["qualification_allowance".to_string()]);
+
+        let result =
+            calculate_qualification_allowance(&employee, dec("76"), 2, &per_week_rate(), 1, true);
+
+        let allowance = result.allowance.unwrap();
+        assert_eq!(allowance.units, dec("2"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(allowance.amount, dec("42.80")); // 2 × 21.40
+        assert_eq!(step.reasoning, "2 week(s) × $21.4 = $42.8");
+    }
+
+    #[test]
+    fn test_qualification_allowance_audit_step() {
+        let employee = create_test_employee(vec!["qualification_allowance".to_string()]);
+        let result =
+            calculate_qualification_allowance(&employee, dec("7.5"), 1, &per_hour_rate(), 4, true);
+
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 4);
+        assert_eq!(step.rule_id, "qualification_allowance");
+        assert_eq!(step.clause_ref, "15");
+        assert_eq!(step.input["basis"], "per_hour");
+        assert_eq!(step.output["amount"], "3.75");
+        assert!(step.output["eligible"].as_bool().unwrap());
+        assert_eq!(step.reasoning, "7.5 hours × $0.5 = $3.75");
+    }
+
+    #[test]
+    fn test_no_qualification_tag() {
+        let employee = create_test_employee(vec!["laundry_allowance".to_string()]);
+        let result =
+            calculate_qualification_allowance(&employee, dec("8"), 1, &per_week_rate(), 1, true);
+
+        assert!(result.allowance.is_none());
+        let step = result.audit_step.unwrap();
+        assert!(!step.output["eligible"].as_bool().unwrap());
+        assert!(
+            step.reasoning
+                .contains("does not have 'qualification_allowance' tag")
+        );
+    }
+}
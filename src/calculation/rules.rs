@@ -0,0 +1,160 @@
+//! The engine's rule catalogue.
+//!
+//! Every audit step the engine records names the rule that produced it. Each
+//! calculation module lists the rules it records in a `RULES` constant, and
+//! this module gathers them into a catalogue of the award provisions the
+//! engine supports.
+
+use crate::models::AuditStep;
+
+/// A rule the engine can apply, as recorded in audit steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleInfo {
+    /// The rule ID audit steps record (e.g. "saturday_penalty").
+    pub rule_id: &'static str,
+    /// The human-readable name of the rule.
+    pub rule_name: &'static str,
+    /// The award clause the rule implements under MA000018, "N/A" for engine
+    /// steps with no clause, or "configured" when the clause is taken from
+    /// the award configuration.
+    pub clause_ref: &'static str,
+}
+
+/// Steps the calculation pipeline records itself rather than through a
+/// calculation module.
+const PIPELINE_RULES: &[RuleInfo] = &[
+    RuleInfo {
+        rule_id: "no_shifts",
+        rule_name: "No Shifts To Process",
+        clause_ref: "N/A",
+    },
+    RuleInfo {
+        rule_id: "allowance_summary",
+        rule_name: "Allowance Summary",
+        clause_ref: "N/A",
+    },
+    RuleInfo {
+        rule_id: "rounding_reconciliation",
+        rule_name: "Rounding Reconciliation",
+        clause_ref: "N/A",
+    },
+    RuleInfo {
+        rule_id: "gross_pay_reconciliation",
+        rule_name: "Gross Pay Reconciliation",
+        clause_ref: "N/A",
+    },
+];
+
+/// Returns every rule the engine can apply, in the order a calculation
+/// applies them.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::rule_catalogue;
+///
+/// let rules = rule_catalogue();
+/// let saturday = rules
+///     .iter()
+///     .find(|rule| rule.rule_id == "saturday_penalty")
+///     .unwrap();
+/// assert_eq!(saturday.clause_ref, "23.1, 23.2(a)");
+/// ```
+pub fn rule_catalogue() -> Vec<RuleInfo> {
+    [
+        super::public_holidays::RULES,
+        super::base_rate::RULES,
+        super::timezone::RULES,
+        super::shift_commencement::RULES,
+        super::time_rounding::RULES,
+        super::daily_overtime::RULES,
+        super::overnight_shift::RULES,
+        super::casual_loading::RULES,
+        super::ordinary_hours::RULES,
+        super::saturday_penalty::RULES,
+        super::sunday_penalty::RULES,
+        super::weekday_overtime::RULES,
+        super::weekend_overtime::RULES,
+        super::public_holiday_overtime::RULES,
+        super::meal_break::RULES,
+        super::early_commencement::RULES,
+        super::public_holiday_not_worked::RULES,
+        super::consecutive_days::RULES,
+        super::span_of_hours::RULES,
+        super::minimum_daily_pay::RULES,
+        super::laundry_allowance::RULES,
+        super::qualification_allowance::RULES,
+        super::first_aid_allowance::RULES,
+        super::broken_shift_allowance::RULES,
+        super::split_shift_travel::RULES,
+        super::leave_accrual::RULES,
+        PIPELINE_RULES,
+    ]
+    .concat()
+}
+
+/// Returns the catalogued rules that none of the audit steps recorded, in
+/// catalogue order.
+///
+/// This lists the provisions the engine supports but did not apply, so a
+/// reviewer can tell that, say, no Sunday penalty was paid because no Sunday
+/// hours were worked rather than because the rule is missing. Steps the
+/// pipeline records itself (such as `gross_pay_reconciliation`) are not award
+/// provisions and are never listed.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::unapplied_rules;
+///
+/// let unapplied = unapplied_rules(&[]);
+/// assert!(unapplied.iter().any(|rule| rule.rule_id == "sunday_penalty"));
+/// assert!(!unapplied.iter().any(|rule| rule.rule_id == "no_shifts"));
+/// ```
+pub fn unapplied_rules(steps: &[AuditStep]) -> Vec<RuleInfo> {
+    rule_catalogue()
+        .into_iter()
+        .filter(|rule| !PIPELINE_RULES.contains(rule))
+        .filter(|rule| !steps.iter().any(|step| step.rule_id == rule.rule_id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_rule_ids_are_unique() {
+        let rules = rule_catalogue();
+        let ids: HashSet<&str> = rules.iter().map(|rule| rule.rule_id).collect();
+
+        assert_eq!(ids.len(), rules.len());
+        assert!(ids.contains("laundry_allowance"));
+        assert!(ids.contains("overtime_tier_1"));
+    }
+
+    #[test]
+    fn test_unapplied_rules_leave_out_recorded_rules() {
+        let step = |rule_id: &str| AuditStep {
+            step_number: 1,
+            rule_id: rule_id.to_string(),
+            rule_name: String::new(),
+            clause_ref: String::new(),
+            input: serde_json::Value::Null,
+            output: serde_json::Value::Null,
+            reasoning: String::new(),
+        };
+        let steps = [step("base_rate_lookup"), step("gross_pay_reconciliation")];
+
+        let unapplied: Vec<&str> = unapplied_rules(&steps)
+            .iter()
+            .map(|rule| rule.rule_id)
+            .collect();
+
+        assert!(!unapplied.contains(&"base_rate_lookup"));
+        assert!(unapplied.contains(&"saturday_penalty"));
+        assert!(!unapplied.contains(&"gross_pay_reconciliation"));
+        assert_eq!(unapplied.len(), rule_catalogue().len() - 5);
+    }
+}
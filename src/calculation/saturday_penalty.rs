@@ -9,14 +9,24 @@ use crate::config::AwardConfig;
 use crate::models::{AuditStep, Employee, EmploymentType, PayCategory, PayLine};
 
 use super::ShiftSegment;
+use super::casual_loading::{casual_composition_name, compose_casual_penalty};
+use super::rules::RuleInfo;
+
+/// The Saturday penalty rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "saturday_penalty",
+    rule_name: "Saturday Penalty Rate",
+    clause_ref: "23.1, 23.2(a)",
+}];
 
 /// The result of a Saturday penalty calculation, including the pay line and audit step.
 #[derive(Debug, Clone)]
 pub struct SaturdayPayResult {
     /// The pay line for the Saturday penalty.
     pub pay_line: PayLine,
-    /// The audit step recording this calculation.
-    pub audit_step: AuditStep,
+    /// The audit step recording this calculation, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
 }
 
 /// Calculates Saturday penalty pay for a shift segment.
@@ -26,6 +36,14 @@ pub struct SaturdayPayResult {
 /// - Part-time: 150% of base rate (clause 23.1)
 /// - Casual: 175% of base rate (clause 23.2(a)) - NOT ordinary rate + casual loading + penalty
 ///
+/// The casual multiplier adds the 25% casual loading to the 150% penalty.
+/// When `penalties.casual_composition` is `compounding`, the penalty is
+/// instead applied to the casual-loaded rate (150% × 1.25 = 187.5%); the
+/// audit step spells out the arithmetic either way.
+///
+/// A multiplier configured for the employee's classification under
+/// `penalties.classifications` replaces the award-wide Saturday rate.
+///
 /// # Arguments
 ///
 /// * `segment` - The shift segment to calculate pay for (must be on a Saturday)
@@ -33,6 +51,7 @@ pub struct SaturdayPayResult {
 /// * `base_rate` - The base hourly rate from the award
 /// * `config` - The award configuration containing penalty rates
 /// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
 ///
 /// # Returns
 ///
@@ -62,6 +81,9 @@ pub struct SaturdayPayResult {
 ///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
 ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
 ///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
 ///     tags: vec![],
 /// };
 ///
@@ -72,7 +94,8 @@ pub struct SaturdayPayResult {
 ///     hours: Decimal::from_str("8.0").unwrap(),
 /// };
 ///
-/// let result = calculate_saturday_pay(&segment, &employee, Decimal::from_str("28.54").unwrap(), config, 1);
+/// let base_rate = Decimal::from_str("28.54").unwrap();
+/// let result = calculate_saturday_pay(&segment, &employee, base_rate, config, 1, true);
 /// // 8.0 hours * $28.54 * 1.50 = $342.48
 /// assert_eq!(result.pay_line.amount, Decimal::from_str("342.48").unwrap());
 /// assert_eq!(result.pay_line.category, award_engine::models::PayCategory::Saturday);
@@ -83,9 +106,12 @@ pub fn calculate_saturday_pay(
     base_rate: Decimal,
     config: &AwardConfig,
     step_number: u32,
+    include_audit: bool,
 ) -> SaturdayPayResult {
-    let penalties = config.penalties();
-    let saturday_penalties = &penalties.penalties.saturday;
+    let saturday_penalties = config
+        .penalties()
+        .penalties
+        .saturday_for(&employee.classification_code);
 
     let (multiplier, category, clause_ref) = match employee.employment_type {
         EmploymentType::FullTime => (
@@ -105,14 +131,16 @@ pub fn calculate_saturday_pay(
         ),
     };
 
+    let penalties = &config.penalties().penalties;
+    let casual_penalty = employee
+        .is_casual()
+        .then(|| compose_casual_penalty(multiplier, penalties.casual_composition));
+    let multiplier = casual_penalty.map_or(multiplier, |breakdown| breakdown.multiplier);
+
     let effective_rate = base_rate * multiplier;
     let amount = segment.hours * effective_rate;
 
-    let employment_type_str = match employee.employment_type {
-        EmploymentType::FullTime => "full_time",
-        EmploymentType::PartTime => "part_time",
-        EmploymentType::Casual => "casual",
-    };
+    let employment_type_str = employee.employment_type.as_str();
 
     let pay_line = PayLine {
         date: segment.start_time.date(),
@@ -122,26 +150,45 @@ pub fn calculate_saturday_pay(
         rate: effective_rate,
         amount,
         clause_ref: clause_ref.clone(),
+        reference: None,
+        cost_centre: None,
     };
 
-    let audit_step = AuditStep {
-        step_number,
-        rule_id: "saturday_penalty".to_string(),
-        rule_name: "Saturday Penalty Rate".to_string(),
-        clause_ref,
-        input: serde_json::json!({
-            "hours": segment.hours.normalize().to_string(),
-            "base_rate": base_rate.normalize().to_string(),
-            "employment_type": employment_type_str,
-            "day_type": "Saturday"
-        }),
-        output: serde_json::json!({
-            "multiplier": multiplier.normalize().to_string(),
-            "effective_rate": effective_rate.normalize().to_string(),
-            "amount": amount.normalize().to_string(),
-            "category": format!("{:?}", category)
-        }),
-        reasoning: format!(
+    if !include_audit {
+        return SaturdayPayResult {
+            pay_line,
+            audit_step: None,
+        };
+    }
+
+    let mut input = serde_json::json!({
+        "hours": segment.hours.normalize().to_string(),
+        "base_rate": base_rate.normalize().to_string(),
+        "employment_type": employment_type_str,
+        "day_type": "Saturday"
+    });
+    let mut output = serde_json::json!({
+        "multiplier": multiplier.normalize().to_string(),
+        "effective_rate": effective_rate.normalize().to_string(),
+        "amount": amount.normalize().to_string(),
+        "category": category
+    });
+    let reasoning = match &casual_penalty {
+        Some(breakdown) => {
+            input["casual_composition"] = casual_composition_name(breakdown.composition).into();
+            output["penalty_multiplier"] =
+                breakdown.penalty_multiplier.normalize().to_string().into();
+            output["casual_loading"] = breakdown.casual_loading.normalize().to_string().into();
+            format!(
+                "Saturday penalty: {} hours × ${} × {} ({}) = ${}",
+                segment.hours.normalize(),
+                base_rate.normalize(),
+                multiplier.normalize(),
+                breakdown.arithmetic(),
+                amount.normalize()
+            )
+        }
+        None => format!(
             "Saturday penalty: {} hours × ${} × {} = ${}",
             segment.hours.normalize(),
             base_rate.normalize(),
@@ -150,9 +197,19 @@ pub fn calculate_saturday_pay(
         ),
     };
 
+    let audit_step = AuditStep {
+        step_number,
+        rule_id: "saturday_penalty".to_string(),
+        rule_name: "Saturday Penalty Rate".to_string(),
+        clause_ref,
+        input,
+        output,
+        reasoning,
+    };
+
     SaturdayPayResult {
         pay_line,
-        audit_step,
+        audit_step: Some(audit_step),
     }
 }
 
@@ -160,7 +217,7 @@ pub fn calculate_saturday_pay(
 mod tests {
     use super::*;
     use crate::calculation::DayType;
-    use crate::config::ConfigLoader;
+    use crate::config::{CasualPenaltyComposition, ConfigLoader};
     use chrono::{NaiveDate, NaiveDateTime};
     use std::str::FromStr;
 
@@ -181,6 +238,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -210,7 +270,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let segment = create_saturday_segment(dec("8.0"));
 
-        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 8.0 * 28.54 * 1.50 = 342.48
         assert_eq!(result.pay_line.amount, dec("342.48"));
@@ -229,7 +289,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::PartTime);
         let segment = create_saturday_segment(dec("8.0"));
 
-        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 8.0 * 28.54 * 1.50 = 342.48
         assert_eq!(result.pay_line.amount, dec("342.48"));
@@ -246,7 +306,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::Casual);
         let segment = create_saturday_segment(dec("8.0"));
 
-        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 8.0 * 28.54 * 1.75 = 399.56
         // Note: Casual rate is 175% of base rate, NOT base + casual loading + penalty
@@ -254,6 +314,9 @@ mod tests {
         assert_eq!(result.pay_line.category, PayCategory::SaturdayCasual);
         assert_eq!(result.pay_line.clause_ref, "23.2(a)");
         assert_eq!(result.pay_line.rate, dec("49.945")); // 28.54 * 1.75
+        // The audit output names the category as it is serialized on pay lines
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.output["category"], "saturday_casual");
     }
 
     // ==========================================================================
@@ -270,7 +333,7 @@ mod tests {
             hours: dec("4.0"),
         };
 
-        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 4.0 * 28.54 * 1.50 = 171.24
         assert_eq!(result.pay_line.amount, dec("171.24"));
@@ -292,7 +355,7 @@ mod tests {
             hours: dec("6.5"),
         };
 
-        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 6.5 * 28.54 * 1.75 = 324.6425, rounded to 324.64 (but Decimal doesn't auto-round)
         // Let's check: 6.5 * 28.54 = 185.51, 185.51 * 1.75 = 324.6425
@@ -312,41 +375,24 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let segment = create_saturday_segment(dec("8.0"));
 
-        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 5);
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 5, true);
 
-        assert_eq!(result.audit_step.step_number, 5);
-        assert_eq!(result.audit_step.rule_id, "saturday_penalty");
-        assert_eq!(result.audit_step.rule_name, "Saturday Penalty Rate");
-        assert_eq!(result.audit_step.clause_ref, "23.1");
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 5);
+        assert_eq!(step.rule_id, "saturday_penalty");
+        assert_eq!(step.rule_name, "Saturday Penalty Rate");
+        assert_eq!(step.clause_ref, "23.1");
 
         // Check input contains expected fields
-        assert_eq!(result.audit_step.input["hours"].as_str().unwrap(), "8");
-        assert_eq!(
-            result.audit_step.input["base_rate"].as_str().unwrap(),
-            "28.54"
-        );
-        assert_eq!(
-            result.audit_step.input["employment_type"].as_str().unwrap(),
-            "full_time"
-        );
-        assert_eq!(
-            result.audit_step.input["day_type"].as_str().unwrap(),
-            "Saturday"
-        );
+        assert_eq!(step.input["hours"].as_str().unwrap(), "8");
+        assert_eq!(step.input["base_rate"].as_str().unwrap(), "28.54");
+        assert_eq!(step.input["employment_type"].as_str().unwrap(), "full_time");
+        assert_eq!(step.input["day_type"].as_str().unwrap(), "Saturday");
 
         // Check output contains expected fields
-        assert_eq!(
-            result.audit_step.output["multiplier"].as_str().unwrap(),
-            "1.5"
-        );
-        assert_eq!(
-            result.audit_step.output["effective_rate"].as_str().unwrap(),
-            "42.81"
-        );
-        assert_eq!(
-            result.audit_step.output["amount"].as_str().unwrap(),
-            "342.48"
-        );
+        assert_eq!(step.output["multiplier"].as_str().unwrap(), "1.5");
+        assert_eq!(step.output["effective_rate"].as_str().unwrap(), "42.81");
+        assert_eq!(step.output["amount"].as_str().unwrap(), "342.48");
     }
 
     #[test]
@@ -355,13 +401,14 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let segment = create_saturday_segment(dec("8.0"));
 
-        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
-        assert!(result.audit_step.reasoning.contains("Saturday penalty"));
-        assert!(result.audit_step.reasoning.contains("8"));
-        assert!(result.audit_step.reasoning.contains("28.54"));
-        assert!(result.audit_step.reasoning.contains("1.5"));
-        assert!(result.audit_step.reasoning.contains("342.48"));
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("Saturday penalty"));
+        assert!(step.reasoning.contains("8"));
+        assert!(step.reasoning.contains("28.54"));
+        assert!(step.reasoning.contains("1.5"));
+        assert!(step.reasoning.contains("342.48"));
     }
 
     #[test]
@@ -370,7 +417,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let segment = create_saturday_segment(dec("8.0"));
 
-        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         assert_eq!(
             result.pay_line.date,
@@ -385,11 +432,76 @@ mod tests {
         let employee = create_test_employee(EmploymentType::Casual);
         let segment = create_saturday_segment(dec("8.0"));
 
-        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // If it were cumulative: 28.54 * 1.25 * 1.50 = 53.5125 rate, 53.5125 * 8 = 428.10
         // But it should be: 28.54 * 1.75 = 49.945 rate, 49.945 * 8 = 399.56
         assert_eq!(result.pay_line.amount, dec("399.56"));
         assert_ne!(result.pay_line.amount, dec("428.10"));
     }
+
+    #[test]
+    fn test_audit_step_is_not_built_when_not_included() {
+        let config = load_config();
+        let employee = create_test_employee(EmploymentType::Casual);
+        let segment = create_saturday_segment(dec("8.0"));
+
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, false);
+
+        assert_eq!(result.pay_line.amount, dec("399.56"));
+        assert!(result.audit_step.is_none());
+    }
+
+    #[test]
+    fn test_casual_composition_is_additive_by_default() {
+        let config = load_config();
+        let employee = create_test_employee(EmploymentType::Casual);
+        let segment = create_saturday_segment(dec("8.0"));
+
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
+
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.input["casual_composition"], "additive");
+        assert_eq!(step.output["penalty_multiplier"], "1.5");
+        assert_eq!(step.output["casual_loading"], "1.25");
+        assert_eq!(step.output["multiplier"], "1.75");
+        assert_eq!(
+            step.reasoning,
+            "Saturday penalty: 8 hours × $28.54 × 1.75 (150% penalty + 25% casual loading = 175%) = $399.56"
+        );
+    }
+
+    #[test]
+    fn test_compounding_composition_applies_penalty_to_loaded_rate() {
+        let config = load_config();
+        let mut penalties = config.penalties().clone();
+        penalties.penalties.casual_composition = CasualPenaltyComposition::Compounding;
+        let config = AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            config.rates().to_vec(),
+            penalties,
+        );
+        let employee = create_test_employee(EmploymentType::Casual);
+        let segment = create_saturday_segment(dec("8.0"));
+
+        let result = calculate_saturday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
+
+        // 28.54 * 1.50 * 1.25 = 53.5125 rate, 53.5125 * 8 = 428.10
+        assert_eq!(result.pay_line.rate, dec("53.5125"));
+        assert_eq!(result.pay_line.amount, dec("428.10"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.input["casual_composition"], "compounding");
+        assert!(
+            step.reasoning
+                .contains("(150% penalty × 1.25 casual loading = 187.5%)")
+        );
+
+        // Permanent employees are unaffected
+        let full_time = create_test_employee(EmploymentType::FullTime);
+        let result = calculate_saturday_pay(&segment, &full_time, dec("28.54"), &config, 1, true);
+        assert_eq!(result.pay_line.amount, dec("342.48"));
+        let step = result.audit_step.unwrap();
+        assert!(step.input.get("casual_composition").is_none());
+    }
 }
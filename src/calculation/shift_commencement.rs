@@ -0,0 +1,389 @@
+//! Weekend day assignment by shift commencement.
+//!
+//! By default a shift is split at midnight and each part is paid as the
+//! calendar day it falls on. Some 24-hour operations instead define weekend
+//! work by when a shift commences, with the night shift belonging to the
+//! following day. This module applies that definition to a shift's segments
+//! when it is configured, and otherwise folds a trivially short crossing past
+//! midnight back into the day before when a minimum segment length is set.
+
+use chrono::{Duration, NaiveDate, NaiveTime};
+use rust_decimal::Decimal;
+
+use crate::config::{WeekendDayBasis, WeekendDays};
+use crate::models::{AuditStep, Shift};
+
+use super::rules::RuleInfo;
+use super::{DayType, ShiftSegment, get_day_type};
+
+/// The weekend day assignment rules, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        rule_id: "shift_commencement_day",
+        rule_name: "Shift Commencement Day",
+        clause_ref: "23.1",
+    },
+    RuleInfo {
+        rule_id: "short_segment_merge",
+        rule_name: "Short Segment Merge",
+        clause_ref: "23.1",
+    },
+];
+
+/// The result of assigning a shift's hours to days.
+#[derive(Debug, Clone)]
+pub struct WeekendDayAssignment {
+    /// The day type the shift as a whole is paid as. Overtime is paid by the
+    /// day type of the segments it falls in, which under shift commencement
+    /// is this one.
+    pub day_type: DayType,
+    /// The shift's segments, each with the day type it is paid as.
+    pub segments: Vec<ShiftSegment>,
+    /// The audit step recording the assignment, or `None` when calendar
+    /// days left the segments unchanged.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Assigns a shift's segments to days under the configured weekend definition.
+///
+/// Under calendar days the shift is paid as the day it starts on and its
+/// segments keep their own days, except that a trailing segment shorter than
+/// `min_segment_minutes` on a different day type from the segment before it
+/// is merged into that segment. Under shift commencement the segments are
+/// merged into one, paid wholly as the day the shift commences on: the
+/// calendar day it starts on, or the next day when it starts at or after
+/// `next_day_from`.
+///
+/// # Arguments
+///
+/// * `shift` - The shift being assigned
+/// * `segments` - The shift's day segments
+/// * `weekend_days` - The configured weekend definition
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::{DayType, assign_weekend_days, segment_by_day};
+/// use award_engine::config::{WeekendDayBasis, WeekendDays};
+/// use award_engine::models::Shift;
+/// use chrono::{NaiveDate, NaiveTime};
+///
+/// // Friday 22:00 to Saturday 06:00
+/// let friday = NaiveDate::from_ymd_opt(2026, 1, 16).unwrap();
+/// let shift = Shift {
+///     id: "shift_001".to_string(),
+///     date: friday,
+///     start_time: friday.and_hms_opt(22, 0, 0).unwrap(),
+///     end_time: friday.succ_opt().unwrap().and_hms_opt(6, 0, 0).unwrap(),
+///     breaks: vec![],
+///     rate_override: None,
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
+/// };
+/// let weekend_days = WeekendDays {
+///     basis: WeekendDayBasis::ShiftCommencement,
+///     next_day_from: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+///     min_segment_minutes: 0,
+/// };
+///
+/// let assignment = assign_weekend_days(&shift, segment_by_day(&shift), weekend_days, 1, true);
+/// assert_eq!(assignment.day_type, DayType::Saturday);
+/// assert_eq!(assignment.segments.len(), 1);
+/// ```
+pub fn assign_weekend_days(
+    shift: &Shift,
+    segments: Vec<ShiftSegment>,
+    weekend_days: WeekendDays,
+    step_number: u32,
+    include_audit: bool,
+) -> WeekendDayAssignment {
+    if weekend_days.basis == WeekendDayBasis::Calendar {
+        let (segments, audit_step) = merge_short_trailing_segment(
+            shift,
+            segments,
+            weekend_days.min_segment_minutes,
+            step_number,
+            include_audit,
+        );
+        return WeekendDayAssignment {
+            day_type: get_day_type(shift.start_time),
+            segments,
+            audit_step,
+        };
+    }
+
+    let commencement_day = commencement_day(shift, weekend_days.next_day_from);
+    let day_type = get_day_type(commencement_day.and_time(NaiveTime::MIN));
+    let hours: Decimal = segments.iter().map(|s| s.hours).sum();
+    let calendar_hours: Vec<String> = segments
+        .iter()
+        .map(|s| format!("{}: {}h", s.day_type, s.hours.normalize()))
+        .collect();
+    let segments = match (segments.first(), segments.last()) {
+        (Some(first), Some(last)) => vec![ShiftSegment {
+            start_time: first.start_time,
+            end_time: last.end_time,
+            day_type,
+            hours,
+        }],
+        _ => segments,
+    };
+
+    let next_day = commencement_day != shift.start_time.date();
+    let audit_step = include_audit.then(|| {
+        let reasoning = if next_day {
+            format!(
+                "Shift commences at {} on {}, at or after {}, so all {} hours are paid as {} ({}) work",
+                shift.start_time.format("%H:%M"),
+                shift.start_time.format("%A"),
+                weekend_days.next_day_from.format("%H:%M"),
+                hours.normalize(),
+                commencement_day.format("%A"),
+                day_type
+            )
+        } else {
+            format!(
+                "Shift commences at {} on {}, so all {} hours are paid as {} work",
+                shift.start_time.format("%H:%M"),
+                shift.start_time.format("%A"),
+                hours.normalize(),
+                day_type
+            )
+        };
+        AuditStep {
+            step_number,
+            rule_id: "shift_commencement_day".to_string(),
+            rule_name: "Shift Commencement Day".to_string(),
+            clause_ref: "23.1".to_string(),
+            input: serde_json::json!({
+                "shift_id": shift.id,
+                "start_time": shift.start_time.to_string(),
+                "next_day_from": weekend_days.next_day_from.format("%H:%M").to_string(),
+                "calendar_segments": calendar_hours
+            }),
+            output: serde_json::json!({
+                "commencement_day": commencement_day.to_string(),
+                "day_type": day_type.to_string(),
+                "hours": hours.normalize().to_string()
+            }),
+            reasoning,
+        }
+    });
+
+    WeekendDayAssignment {
+        day_type,
+        segments,
+        audit_step,
+    }
+}
+
+/// Merges a shift's last segment into the one before it when it is shorter
+/// than `min_segment_minutes` and falls on a different day type, returning
+/// the segments and, when `include_audit` is set, an audit step recording
+/// the merge.
+fn merge_short_trailing_segment(
+    shift: &Shift,
+    mut segments: Vec<ShiftSegment>,
+    min_segment_minutes: u32,
+    step_number: u32,
+    include_audit: bool,
+) -> (Vec<ShiftSegment>, Option<AuditStep>) {
+    let min_hours = Decimal::from(min_segment_minutes) / Decimal::from(60);
+    let short_trailing = match segments.as_slice() {
+        [.., previous, last] => last.hours < min_hours && last.day_type != previous.day_type,
+        _ => false,
+    };
+    if !short_trailing {
+        return (segments, None);
+    }
+
+    let last = segments.pop().expect("a trailing segment was found");
+    let previous = segments.last_mut().expect("a preceding segment was found");
+    previous.end_time = last.end_time;
+    previous.hours += last.hours;
+
+    let audit_step = include_audit.then(|| AuditStep {
+        step_number,
+        rule_id: "short_segment_merge".to_string(),
+        rule_name: "Short Segment Merge".to_string(),
+        clause_ref: "23.1".to_string(),
+        input: serde_json::json!({
+            "shift_id": shift.id,
+            "segment_start": last.start_time.to_string(),
+            "segment_day_type": last.day_type.to_string(),
+            "segment_hours": last.hours.normalize().to_string(),
+            "min_segment_minutes": min_segment_minutes
+        }),
+        output: serde_json::json!({
+            "day_type": previous.day_type.to_string(),
+            "hours": previous.hours.normalize().to_string()
+        }),
+        reasoning: format!(
+            "The {} hours worked on {} from {} are under the {} minute minimum segment, so they are paid as {} work with the rest of the shift",
+            last.hours.normalize(),
+            last.start_time.format("%A"),
+            last.start_time.format("%H:%M"),
+            min_segment_minutes,
+            previous.day_type
+        ),
+    });
+    (segments, audit_step)
+}
+
+/// Returns the day a shift commences on, moving a shift that starts at or
+/// after `next_day_from` to the following day.
+fn commencement_day(shift: &Shift, next_day_from: NaiveTime) -> NaiveDate {
+    let start_date = shift.start_time.date();
+    if next_day_from != NaiveTime::MIN && shift.start_time.time() >= next_day_from {
+        start_date + Duration::days(1)
+    } else {
+        start_date
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculation::segment_by_day;
+
+    fn shift(start: &str, end: &str) -> Shift {
+        let parse = |s: &str| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        Shift {
+            id: "shift_001".to_string(),
+            date: parse(start).date(),
+            start_time: parse(start),
+            end_time: parse(end),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    fn shift_commencement(next_day_from: &str) -> WeekendDays {
+        WeekendDays {
+            basis: WeekendDayBasis::ShiftCommencement,
+            next_day_from: NaiveTime::parse_from_str(next_day_from, "%H:%M").unwrap(),
+            min_segment_minutes: 0,
+        }
+    }
+
+    fn min_segment(minutes: u32) -> WeekendDays {
+        WeekendDays {
+            min_segment_minutes: minutes,
+            ..WeekendDays::default()
+        }
+    }
+
+    #[test]
+    fn test_calendar_days_leave_segments_split() {
+        // Friday 22:00 to Saturday 06:00
+        let shift = shift("2026-01-16 22:00", "2026-01-17 06:00");
+
+        let assignment = assign_weekend_days(
+            &shift,
+            segment_by_day(&shift),
+            WeekendDays::default(),
+            1,
+            true,
+        );
+
+        assert_eq!(assignment.day_type, DayType::Weekday);
+        assert_eq!(assignment.segments.len(), 2);
+        assert_eq!(assignment.segments[1].day_type, DayType::Saturday);
+        assert!(assignment.audit_step.is_none());
+    }
+
+    #[test]
+    fn test_short_saturday_crossing_is_paid_as_friday() {
+        // Friday 16:05 to Saturday 00:05
+        let shift = shift("2026-01-16 16:05", "2026-01-17 00:05");
+
+        let assignment =
+            assign_weekend_days(&shift, segment_by_day(&shift), min_segment(15), 4, true);
+
+        assert_eq!(assignment.day_type, DayType::Weekday);
+        assert_eq!(assignment.segments.len(), 1);
+        assert_eq!(assignment.segments[0].day_type, DayType::Weekday);
+        assert_eq!(assignment.segments[0].hours, Decimal::from(8));
+        assert_eq!(assignment.segments[0].end_time, shift.end_time);
+        let step = assignment.audit_step.unwrap();
+        assert_eq!(step.rule_id, "short_segment_merge");
+        assert_eq!(step.step_number, 4);
+        assert_eq!(step.input["segment_day_type"], "Saturday");
+        assert_eq!(step.output["day_type"], "Weekday");
+    }
+
+    #[test]
+    fn test_segments_at_or_over_the_minimum_are_kept() {
+        // Friday 16:15 to Saturday 00:15 has a 15 minute Saturday segment
+        let shift = shift("2026-01-16 16:15", "2026-01-17 00:15");
+
+        let assignment =
+            assign_weekend_days(&shift, segment_by_day(&shift), min_segment(15), 1, true);
+        assert_eq!(assignment.segments.len(), 2);
+        assert!(assignment.audit_step.is_none());
+
+        // Without a minimum, even a five minute crossing is its own segment
+        let shift = self::shift("2026-01-16 16:05", "2026-01-17 00:05");
+        let assignment = assign_weekend_days(
+            &shift,
+            segment_by_day(&shift),
+            WeekendDays::default(),
+            1,
+            true,
+        );
+        assert_eq!(assignment.segments.len(), 2);
+        assert_eq!(assignment.segments[1].day_type, DayType::Saturday);
+    }
+
+    #[test]
+    fn test_shift_before_the_cutoff_stays_on_its_own_day() {
+        // Saturday 21:00 to Sunday 05:00 commences before 22:00, so it is
+        // wholly a Saturday shift
+        let shift = shift("2026-01-17 21:00", "2026-01-18 05:00");
+
+        let assignment = assign_weekend_days(
+            &shift,
+            segment_by_day(&shift),
+            shift_commencement("22:00"),
+            3,
+            true,
+        );
+
+        assert_eq!(assignment.day_type, DayType::Saturday);
+        assert_eq!(assignment.segments.len(), 1);
+        assert_eq!(assignment.segments[0].day_type, DayType::Saturday);
+        assert_eq!(assignment.segments[0].hours, Decimal::from(8));
+        let step = assignment.audit_step.unwrap();
+        assert_eq!(step.step_number, 3);
+        assert_eq!(step.output["commencement_day"], "2026-01-17");
+    }
+
+    #[test]
+    fn test_sunday_night_shift_belongs_to_monday() {
+        let shift = shift("2026-01-18 22:30", "2026-01-19 06:30");
+
+        let assignment = assign_weekend_days(
+            &shift,
+            segment_by_day(&shift),
+            shift_commencement("22:00"),
+            1,
+            true,
+        );
+
+        assert_eq!(assignment.day_type, DayType::Weekday);
+        assert_eq!(assignment.segments[0].day_type, DayType::Weekday);
+        assert!(
+            assignment
+                .audit_step
+                .unwrap()
+                .reasoning
+                .contains("paid as Monday (Weekday) work")
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! Span of hours penalty functionality.
+//!
+//! This module pays a penalty when a day's span of hours, from the start of
+//! its first shift to the finish of its last, exceeds the configured limit.
+//! Unlike overtime, which counts the hours worked, the span includes unpaid
+//! breaks and the gaps between the parts of a split shift, so a day can attract
+//! the penalty without any overtime being worked.
+//!
+//! The penalty is paid on top of the ordinary, weekend or overtime pay for
+//! those hours, so it does not count towards any of the hour totals.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::config::SpanOfHoursConfig;
+use crate::models::{AuditStep, PayCategory, PayLine, Shift, hours_from_seconds};
+
+use super::rules::RuleInfo;
+
+/// The span of hours rule, for the rule catalogue; its clause is configured.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "span_of_hours",
+    rule_name: "Span of Hours",
+    clause_ref: "configured",
+}];
+
+/// The result of calculating span of hours penalties.
+#[derive(Debug, Clone)]
+pub struct SpanPenaltyResult {
+    /// A penalty pay line for each day whose span exceeds the limit.
+    pub pay_lines: Vec<PayLine>,
+    /// An audit step for each day whose span exceeds the limit.
+    pub audit_steps: Vec<AuditStep>,
+}
+
+/// Calculates the span of hours penalty for each day worked.
+///
+/// Shifts are grouped by date. A day's span runs from the earliest shift start
+/// to the latest shift finish, so it is the [`gross_hours`](Shift::gross_hours)
+/// of its shifts plus any gaps between them. The hours of the span beyond
+/// `max_span_hours` are paid on a [`PayCategory::SpanPenalty`] line against
+/// the day's last shift, at `base_rate × penalty_rate` for the base rate that
+/// shift was paid at, so its override rate, classification or dated rate
+/// carries over to the penalty. Days within the limit produce neither a pay
+/// line nor an audit step.
+///
+/// # Arguments
+///
+/// * `shifts` - The shifts worked in the pay period
+/// * `base_rate` - The base hourly rate each shift was paid at
+/// * `config` - The configured span limit and penalty rate
+/// * `step_number` - The step number of the first audit step
+/// * `include_audit` - Whether to build the audit steps
+///
+/// # Example
+///
+/// ```
+/// use award_engine::calculation::calculate_span_penalty;
+/// use award_engine::config::SpanOfHoursConfig;
+/// use award_engine::models::{PayCategory, Shift};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
+/// let shift = |id: &str, start: u32, end: u32| Shift {
+///     id: id.to_string(),
+///     date,
+///     start_time: date.and_hms_opt(start, 0, 0).unwrap(),
+///     end_time: date.and_hms_opt(end, 0, 0).unwrap(),
+///     breaks: vec![],
+///     rate_override: None,
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
+/// };
+/// // A split shift spanning 07:00 to 20:00
+/// let shifts = vec![shift("morning", 7, 11), shift("evening", 16, 20)];
+/// let config = SpanOfHoursConfig {
+///     clause: "X.Y".to_string(),
+///     max_span_hours: Decimal::from(12),
+///     penalty_rate: Decimal::new(5, 1),
+/// };
+///
+/// let result = calculate_span_penalty(&shifts, |_| Decimal::new(2854, 2), &config, 1, true);
+///
+/// assert_eq!(result.pay_lines.len(), 1);
+/// assert_eq!(result.pay_lines[0].category, PayCategory::SpanPenalty);
+/// assert_eq!(result.pay_lines[0].shift_id, "evening");
+/// assert_eq!(result.pay_lines[0].hours, Decimal::ONE);
+/// assert_eq!(result.pay_lines[0].amount, Decimal::new(1427, 2));
+/// ```
+pub fn calculate_span_penalty(
+    shifts: &[Shift],
+    base_rate: impl Fn(&Shift) -> Decimal,
+    config: &SpanOfHoursConfig,
+    step_number: u32,
+    include_audit: bool,
+) -> SpanPenaltyResult {
+    let mut shifts_by_day: BTreeMap<NaiveDate, Vec<&Shift>> = BTreeMap::new();
+    for shift in shifts {
+        shifts_by_day.entry(shift.date).or_default().push(shift);
+    }
+
+    let mut pay_lines = Vec::new();
+    let mut audit_steps = Vec::new();
+    for (date, day_shifts) in shifts_by_day {
+        let (Some(first_start), Some(last_shift)) = (
+            day_shifts.iter().map(|s| s.start_time).min(),
+            day_shifts.iter().max_by_key(|s| s.end_time),
+        ) else {
+            continue;
+        };
+        let span_hours = hours_from_seconds((last_shift.end_time - first_start).num_seconds());
+        if span_hours <= config.max_span_hours {
+            continue;
+        }
+
+        let gross_hours: Decimal = day_shifts.iter().map(|s| s.gross_hours()).sum();
+        let hours_beyond_span = span_hours - config.max_span_hours;
+        let rate = base_rate(last_shift) * config.penalty_rate;
+        let amount = hours_beyond_span * rate;
+
+        if include_audit {
+            audit_steps.push(AuditStep {
+                step_number: step_number + audit_steps.len() as u32,
+                rule_id: "span_of_hours".to_string(),
+                rule_name: "Span of Hours".to_string(),
+                clause_ref: config.clause.clone(),
+                input: serde_json::json!({
+                    "date": date.to_string(),
+                    "shift_ids": day_shifts.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(),
+                    "first_start": first_start.to_string(),
+                    "last_finish": last_shift.end_time.to_string(),
+                    "gross_hours": gross_hours.normalize().to_string(),
+                    "max_span_hours": config.max_span_hours.normalize().to_string(),
+                    "penalty_rate": config.penalty_rate.normalize().to_string()
+                }),
+                output: serde_json::json!({
+                    "span_hours": span_hours.normalize().to_string(),
+                    "hours_beyond_span": hours_beyond_span.normalize().to_string(),
+                    "rate": rate.normalize().to_string(),
+                    "amount": amount.normalize().to_string()
+                }),
+                reasoning: format!(
+                    "Span of {} hours from {} to {} ({} hours of shifts and {} hours between them) exceeds {} hours: {} hours × ${} = ${}",
+                    span_hours.normalize(),
+                    first_start.time(),
+                    last_shift.end_time.time(),
+                    gross_hours.normalize(),
+                    (span_hours - gross_hours).normalize(),
+                    config.max_span_hours.normalize(),
+                    hours_beyond_span.normalize(),
+                    rate.normalize(),
+                    amount.normalize()
+                ),
+            });
+        }
+
+        pay_lines.push(PayLine {
+            date,
+            shift_id: last_shift.id.clone(),
+            category: PayCategory::SpanPenalty,
+            hours: hours_beyond_span,
+            rate,
+            amount,
+            clause_ref: config.clause.clone(),
+            reference: None,
+            cost_centre: None,
+        });
+    }
+
+    SpanPenaltyResult {
+        pay_lines,
+        audit_steps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Break;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn shift(id: &str, date: &str, start: &str, end: &str) -> Shift {
+        let date = NaiveDate::from_str(date).unwrap();
+        Shift {
+            id: id.to_string(),
+            date,
+            start_time: date.and_time(start.parse().unwrap()),
+            end_time: date.and_time(end.parse().unwrap()),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    fn config() -> SpanOfHoursConfig {
+        SpanOfHoursConfig {
+            clause: "25.5".to_string(),
+            max_span_hours: dec("12"),
+            penalty_rate: dec("0.5"),
+        }
+    }
+
+    #[test]
+    fn test_split_shift_with_long_midday_gap_exceeds_span() {
+        // 06:30-10:30 and 17:00-21:00: 8 hours worked across a 14.5 hour span
+        let shifts = vec![
+            shift("morning", "2026-01-13", "06:30:00", "10:30:00"),
+            shift("evening", "2026-01-13", "17:00:00", "21:00:00"),
+        ];
+
+        let result = calculate_span_penalty(&shifts, |_| dec("28.54"), &config(), 9, true);
+
+        assert_eq!(result.pay_lines.len(), 1);
+        let line = &result.pay_lines[0];
+        assert_eq!(line.category, PayCategory::SpanPenalty);
+        assert_eq!(line.shift_id, "evening");
+        assert_eq!(line.hours, dec("2.5"));
+        assert_eq!(line.rate, dec("14.27"));
+        assert_eq!(line.amount, dec("35.675"));
+        assert_eq!(line.clause_ref, "25.5");
+
+        assert_eq!(result.audit_steps.len(), 1);
+        let step = &result.audit_steps[0];
+        assert_eq!(step.step_number, 9);
+        assert_eq!(step.input["gross_hours"], "8");
+        assert_eq!(step.output["span_hours"], "14.5");
+    }
+
+    #[test]
+    fn test_unpaid_break_counts_towards_span() {
+        // 13 hours worked would be overtime; 11 hours worked with a 2 hour
+        // unpaid gap spans the same 13 hours
+        let mut long_day = shift("shift_001", "2026-01-14", "07:00:00", "20:00:00");
+        long_day.breaks.push(Break {
+            start_time: long_day.date.and_hms_opt(12, 0, 0).unwrap(),
+            end_time: long_day.date.and_hms_opt(14, 0, 0).unwrap(),
+            is_paid: false,
+            kind: None,
+        });
+
+        let result = calculate_span_penalty(&[long_day], |_| dec("28.54"), &config(), 1, true);
+
+        assert_eq!(result.pay_lines.len(), 1);
+        assert_eq!(result.pay_lines[0].hours, dec("1"));
+        assert_eq!(result.audit_steps[0].input["gross_hours"], "13");
+    }
+
+    #[test]
+    fn test_days_within_span_pay_nothing() {
+        // Each day is within the limit; spans are not combined across days
+        let shifts = vec![
+            shift("mon", "2026-01-12", "07:00:00", "19:00:00"),
+            shift("tue_am", "2026-01-13", "08:00:00", "12:00:00"),
+            shift("tue_pm", "2026-01-13", "16:00:00", "19:00:00"),
+        ];
+
+        let result = calculate_span_penalty(&shifts, |_| dec("28.54"), &config(), 1, true);
+
+        assert!(result.pay_lines.is_empty());
+        assert!(result.audit_steps.is_empty());
+    }
+
+    #[test]
+    fn test_penalty_is_paid_at_the_last_shifts_rate() {
+        let shifts = vec![
+            shift("morning", "2026-01-13", "06:30:00", "10:30:00"),
+            shift("evening", "2026-01-13", "17:00:00", "21:00:00"),
+        ];
+
+        let result = calculate_span_penalty(
+            &shifts,
+            |s| {
+                if s.id == "evening" {
+                    dec("40")
+                } else {
+                    dec("28.54")
+                }
+            },
+            &config(),
+            1,
+            true,
+        );
+
+        // 2.5 hours × $40 × 0.5
+        assert_eq!(result.pay_lines[0].rate, dec("20"));
+        assert_eq!(result.pay_lines[0].amount, dec("50"));
+    }
+}
@@ -0,0 +1,265 @@
+//! Split shift travel reimbursement functionality.
+//!
+//! This module provides a flat travel reimbursement for days on which an
+//! employee works a broken (split) shift, on top of the broken shift
+//! allowance.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::models::{AllowancePayment, AllowanceType, AuditStep, Employee, Shift};
+
+use super::rules::RuleInfo;
+
+/// The split shift travel rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "split_shift_travel",
+    rule_name: "Split Shift Travel Reimbursement",
+    clause_ref: SPLIT_SHIFT_TRAVEL_CLAUSE,
+}];
+
+/// The clause reference for the split shift travel reimbursement.
+///
+/// The reimbursement is configured per employer rather than set by an award
+/// clause.
+pub const SPLIT_SHIFT_TRAVEL_CLAUSE: &str = "N/A";
+
+/// The result of calculating the split shift travel reimbursement.
+#[derive(Debug, Clone)]
+pub struct SplitShiftTravelResult {
+    /// The reimbursement payment, if any day had a broken shift.
+    pub allowance: Option<AllowancePayment>,
+    /// The audit step recording this calculation, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Calculates the split shift travel reimbursement for an employee's shifts.
+///
+/// The reimbursement is paid once for each day with a broken shift, however
+/// many gaps or broken shifts fall on that day. It is paid in addition to the
+/// broken shift allowance.
+///
+/// # Arguments
+///
+/// * `employee` - The employee to calculate the reimbursement for
+/// * `shifts` - The shifts worked in the pay period
+/// * `rate` - The configured reimbursement per broken-shift day
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Returns
+///
+/// Returns a `SplitShiftTravelResult` containing:
+/// - `Some(AllowancePayment)` if at least one day had a broken shift
+/// - `None` if no shift was a broken shift
+pub fn calculate_split_shift_travel(
+    employee: &Employee,
+    shifts: &[Shift],
+    rate: Decimal,
+    step_number: u32,
+    include_audit: bool,
+) -> SplitShiftTravelResult {
+    let broken_shift_days: BTreeSet<NaiveDate> = shifts
+        .iter()
+        .filter(|s| s.is_broken_shift())
+        .map(|s| s.date)
+        .collect();
+    let input = || {
+        let days: Vec<String> = broken_shift_days.iter().map(|d| d.to_string()).collect();
+        serde_json::json!({
+            "employee_id": employee.id,
+            "broken_shift_days": days,
+            "rate_per_day": rate.normalize().to_string()
+        })
+    };
+
+    if broken_shift_days.is_empty() {
+        let audit_step = include_audit.then(|| AuditStep {
+            step_number,
+            rule_id: "split_shift_travel".to_string(),
+            rule_name: "Split Shift Travel Reimbursement".to_string(),
+            clause_ref: SPLIT_SHIFT_TRAVEL_CLAUSE.to_string(),
+            input: input(),
+            output: serde_json::json!({
+                "eligible": false,
+                "amount": "0.00"
+            }),
+            reasoning: "No broken shifts worked - no split shift travel reimbursement".to_string(),
+        });
+
+        return SplitShiftTravelResult {
+            allowance: None,
+            audit_step,
+        };
+    }
+
+    let units = Decimal::from(broken_shift_days.len());
+    let amount = units * rate;
+
+    let audit_step = include_audit.then(|| AuditStep {
+        step_number,
+        rule_id: "split_shift_travel".to_string(),
+        rule_name: "Split Shift Travel Reimbursement".to_string(),
+        clause_ref: SPLIT_SHIFT_TRAVEL_CLAUSE.to_string(),
+        input: input(),
+        output: serde_json::json!({
+            "eligible": true,
+            "units": units.normalize().to_string(),
+            "amount": amount.normalize().to_string()
+        }),
+        reasoning: format!(
+            "{} day(s) with a broken shift × ${} = ${}",
+            units,
+            rate.normalize(),
+            amount.normalize()
+        ),
+    });
+
+    let allowance = AllowancePayment {
+        allowance_type: AllowanceType::SplitShiftTravel,
+        description: "Split Shift Travel Reimbursement".to_string(),
+        units,
+        rate,
+        amount,
+        clause_ref: SPLIT_SHIFT_TRAVEL_CLAUSE.to_string(),
+    };
+
+    SplitShiftTravelResult {
+        allowance: Some(allowance),
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculation::calculate_broken_shift_allowance;
+    use crate::models::{Break, EmploymentType};
+    use chrono::NaiveDateTime;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn datetime(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S").unwrap()
+    }
+
+    fn create_test_employee() -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type: EmploymentType::PartTime,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags: vec![],
+        }
+    }
+
+    /// A shift with the given unpaid breaks, all on `date`.
+    fn shift(id: &str, date: &str, start: &str, end: &str, gaps: &[(&str, &str)]) -> Shift {
+        Shift {
+            id: id.to_string(),
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            start_time: datetime(&format!("{}T{}", date, start)),
+            end_time: datetime(&format!("{}T{}", date, end)),
+            breaks: gaps
+                .iter()
+                .map(|(gap_start, gap_end)| Break {
+                    start_time: datetime(&format!("{}T{}", date, gap_start)),
+                    end_time: datetime(&format!("{}T{}", date, gap_end)),
+                    is_paid: false,
+                    kind: None,
+                })
+                .collect(),
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_broken_shift_yields_allowance_and_one_travel_reimbursement() {
+        // One broken shift with two gaps: one allowance, one reimbursement
+        let shifts = vec![shift(
+            "s1",
+            "2026-01-13",
+            "07:00:00",
+            "20:00:00",
+            &[("10:00:00", "13:00:00"), ("15:00:00", "17:00:00")],
+        )];
+        let employee = create_test_employee();
+
+        let broken = calculate_broken_shift_allowance(&employee, &shifts, dec("20.82"), 1, true);
+        let travel = calculate_split_shift_travel(&employee, &shifts, dec("12.50"), 2, true);
+
+        let broken = broken.allowance.unwrap();
+        assert_eq!(broken.allowance_type, AllowanceType::BrokenShift);
+        assert_eq!(broken.amount, dec("20.82"));
+
+        let travel = travel.allowance.unwrap();
+        assert_eq!(travel.allowance_type, AllowanceType::SplitShiftTravel);
+        assert_eq!(travel.units, dec("1"));
+        assert_eq!(travel.amount, dec("12.50"));
+    }
+
+    #[test]
+    fn test_travel_is_paid_once_per_broken_shift_day() {
+        let shifts = vec![
+            shift(
+                "s1",
+                "2026-01-13",
+                "06:00:00",
+                "11:00:00",
+                &[("08:00:00", "09:30:00")],
+            ),
+            shift(
+                "s2",
+                "2026-01-13",
+                "15:00:00",
+                "21:00:00",
+                &[("17:00:00", "18:30:00")],
+            ),
+            shift(
+                "s3",
+                "2026-01-14",
+                "07:00:00",
+                "19:00:00",
+                &[("10:00:00", "14:00:00")],
+            ),
+        ];
+
+        let result =
+            calculate_split_shift_travel(&create_test_employee(), &shifts, dec("12.50"), 1, true);
+
+        let allowance = result.allowance.unwrap();
+        assert_eq!(allowance.units, dec("2"));
+        assert_eq!(allowance.amount, dec("25.00"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(
+            step.input["broken_shift_days"],
+            serde_json::json!(["2026-01-13", "2026-01-14"])
+        );
+    }
+
+    #[test]
+    fn test_no_broken_shift_means_no_reimbursement() {
+        let shifts = vec![shift("s1", "2026-01-13", "09:00:00", "17:00:00", &[])];
+
+        let result =
+            calculate_split_shift_travel(&create_test_employee(), &shifts, dec("12.50"), 1, true);
+
+        assert!(result.allowance.is_none());
+        let step = result.audit_step.unwrap();
+        assert!(!step.output["eligible"].as_bool().unwrap());
+    }
+}
@@ -9,14 +9,24 @@ use crate::config::AwardConfig;
 use crate::models::{AuditStep, Employee, EmploymentType, PayCategory, PayLine};
 
 use super::ShiftSegment;
+use super::casual_loading::{casual_composition_name, compose_casual_penalty};
+use super::rules::RuleInfo;
+
+/// The Sunday penalty rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "sunday_penalty",
+    rule_name: "Sunday Penalty Rate",
+    clause_ref: "23.1, 23.2(b)",
+}];
 
 /// The result of a Sunday penalty calculation, including the pay line and audit step.
 #[derive(Debug, Clone)]
 pub struct SundayPayResult {
     /// The pay line for the Sunday penalty.
     pub pay_line: PayLine,
-    /// The audit step recording this calculation.
-    pub audit_step: AuditStep,
+    /// The audit step recording this calculation, when the audit trace is
+    /// wanted.
+    pub audit_step: Option<AuditStep>,
 }
 
 /// Calculates Sunday penalty pay for a shift segment.
@@ -26,6 +36,12 @@ pub struct SundayPayResult {
 /// - Part-time: 175% of base rate (clause 23.1)
 /// - Casual: 200% of base rate (clause 23.2(b)) - NOT ordinary rate + casual loading + penalty
 ///
+/// As on Saturdays, the casual multiplier adds the casual loading to the
+/// penalty unless `penalties.casual_composition` is `compounding`.
+///
+/// A multiplier configured for the employee's classification under
+/// `penalties.classifications` replaces the award-wide Sunday rate.
+///
 /// # Arguments
 ///
 /// * `segment` - The shift segment to calculate pay for (must be on a Sunday)
@@ -33,6 +49,7 @@ pub struct SundayPayResult {
 /// * `base_rate` - The base hourly rate from the award
 /// * `config` - The award configuration containing penalty rates
 /// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
 ///
 /// # Returns
 ///
@@ -62,6 +79,9 @@ pub struct SundayPayResult {
 ///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
 ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
 ///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
 ///     tags: vec![],
 /// };
 ///
@@ -72,7 +92,8 @@ pub struct SundayPayResult {
 ///     hours: Decimal::from_str("8.0").unwrap(),
 /// };
 ///
-/// let result = calculate_sunday_pay(&segment, &employee, Decimal::from_str("28.54").unwrap(), config, 1);
+/// let base_rate = Decimal::from_str("28.54").unwrap();
+/// let result = calculate_sunday_pay(&segment, &employee, base_rate, config, 1, true);
 /// // 8.0 hours * $28.54 * 1.75 = $399.56
 /// assert_eq!(result.pay_line.amount, Decimal::from_str("399.56").unwrap());
 /// assert_eq!(result.pay_line.category, award_engine::models::PayCategory::Sunday);
@@ -83,9 +104,12 @@ pub fn calculate_sunday_pay(
     base_rate: Decimal,
     config: &AwardConfig,
     step_number: u32,
+    include_audit: bool,
 ) -> SundayPayResult {
-    let penalties = config.penalties();
-    let sunday_penalties = &penalties.penalties.sunday;
+    let sunday_penalties = config
+        .penalties()
+        .penalties
+        .sunday_for(&employee.classification_code);
 
     let (multiplier, category, clause_ref) = match employee.employment_type {
         EmploymentType::FullTime => (
@@ -105,14 +129,16 @@ pub fn calculate_sunday_pay(
         ),
     };
 
+    let penalties = &config.penalties().penalties;
+    let casual_penalty = employee
+        .is_casual()
+        .then(|| compose_casual_penalty(multiplier, penalties.casual_composition));
+    let multiplier = casual_penalty.map_or(multiplier, |breakdown| breakdown.multiplier);
+
     let effective_rate = base_rate * multiplier;
     let amount = segment.hours * effective_rate;
 
-    let employment_type_str = match employee.employment_type {
-        EmploymentType::FullTime => "full_time",
-        EmploymentType::PartTime => "part_time",
-        EmploymentType::Casual => "casual",
-    };
+    let employment_type_str = employee.employment_type.as_str();
 
     let pay_line = PayLine {
         date: segment.start_time.date(),
@@ -122,26 +148,45 @@ pub fn calculate_sunday_pay(
         rate: effective_rate,
         amount,
         clause_ref: clause_ref.clone(),
+        reference: None,
+        cost_centre: None,
     };
 
-    let audit_step = AuditStep {
-        step_number,
-        rule_id: "sunday_penalty".to_string(),
-        rule_name: "Sunday Penalty Rate".to_string(),
-        clause_ref,
-        input: serde_json::json!({
-            "hours": segment.hours.normalize().to_string(),
-            "base_rate": base_rate.normalize().to_string(),
-            "employment_type": employment_type_str,
-            "day_type": "Sunday"
-        }),
-        output: serde_json::json!({
-            "multiplier": multiplier.normalize().to_string(),
-            "effective_rate": effective_rate.normalize().to_string(),
-            "amount": amount.normalize().to_string(),
-            "category": format!("{:?}", category)
-        }),
-        reasoning: format!(
+    if !include_audit {
+        return SundayPayResult {
+            pay_line,
+            audit_step: None,
+        };
+    }
+
+    let mut input = serde_json::json!({
+        "hours": segment.hours.normalize().to_string(),
+        "base_rate": base_rate.normalize().to_string(),
+        "employment_type": employment_type_str,
+        "day_type": "Sunday"
+    });
+    let mut output = serde_json::json!({
+        "multiplier": multiplier.normalize().to_string(),
+        "effective_rate": effective_rate.normalize().to_string(),
+        "amount": amount.normalize().to_string(),
+        "category": category
+    });
+    let reasoning = match &casual_penalty {
+        Some(breakdown) => {
+            input["casual_composition"] = casual_composition_name(breakdown.composition).into();
+            output["penalty_multiplier"] =
+                breakdown.penalty_multiplier.normalize().to_string().into();
+            output["casual_loading"] = breakdown.casual_loading.normalize().to_string().into();
+            format!(
+                "Sunday penalty: {} hours × ${} × {} ({}) = ${}",
+                segment.hours.normalize(),
+                base_rate.normalize(),
+                multiplier.normalize(),
+                breakdown.arithmetic(),
+                amount.normalize()
+            )
+        }
+        None => format!(
             "Sunday penalty: {} hours × ${} × {} = ${}",
             segment.hours.normalize(),
             base_rate.normalize(),
@@ -150,9 +195,19 @@ pub fn calculate_sunday_pay(
         ),
     };
 
+    let audit_step = AuditStep {
+        step_number,
+        rule_id: "sunday_penalty".to_string(),
+        rule_name: "Sunday Penalty Rate".to_string(),
+        clause_ref,
+        input,
+        output,
+        reasoning,
+    };
+
     SundayPayResult {
         pay_line,
-        audit_step,
+        audit_step: Some(audit_step),
     }
 }
 
@@ -160,7 +215,9 @@ pub fn calculate_sunday_pay(
 mod tests {
     use super::*;
     use crate::calculation::DayType;
-    use crate::config::ConfigLoader;
+    use crate::config::{
+        CasualPenaltyComposition, ClassificationPenalties, ConfigLoader, PenaltyRateOverrides,
+    };
     use chrono::{NaiveDate, NaiveDateTime};
     use std::str::FromStr;
 
@@ -181,6 +238,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -211,7 +271,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let segment = create_sunday_segment(dec("8.0"));
 
-        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 8.0 * 28.54 * 1.75 = 399.56
         assert_eq!(result.pay_line.amount, dec("399.56"));
@@ -230,7 +290,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::PartTime);
         let segment = create_sunday_segment(dec("8.0"));
 
-        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 8.0 * 28.54 * 1.75 = 399.56
         assert_eq!(result.pay_line.amount, dec("399.56"));
@@ -247,7 +307,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::Casual);
         let segment = create_sunday_segment(dec("8.0"));
 
-        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 8.0 * 28.54 * 2.00 = 456.64
         // Note: Casual rate is 200% of base rate, NOT base + casual loading + penalty
@@ -271,7 +331,7 @@ mod tests {
             hours: dec("4.0"),
         };
 
-        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 4.0 * 28.54 * 1.75 = 199.78
         assert_eq!(result.pay_line.amount, dec("199.78"));
@@ -293,7 +353,7 @@ mod tests {
             hours: dec("6.5"),
         };
 
-        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 6.5 * 28.54 * 2.00 = 371.02
         assert_eq!(result.pay_line.amount, dec("371.02"));
@@ -310,41 +370,24 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let segment = create_sunday_segment(dec("8.0"));
 
-        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 5);
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 5, true);
 
-        assert_eq!(result.audit_step.step_number, 5);
-        assert_eq!(result.audit_step.rule_id, "sunday_penalty");
-        assert_eq!(result.audit_step.rule_name, "Sunday Penalty Rate");
-        assert_eq!(result.audit_step.clause_ref, "23.1");
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 5);
+        assert_eq!(step.rule_id, "sunday_penalty");
+        assert_eq!(step.rule_name, "Sunday Penalty Rate");
+        assert_eq!(step.clause_ref, "23.1");
 
         // Check input contains expected fields
-        assert_eq!(result.audit_step.input["hours"].as_str().unwrap(), "8");
-        assert_eq!(
-            result.audit_step.input["base_rate"].as_str().unwrap(),
-            "28.54"
-        );
-        assert_eq!(
-            result.audit_step.input["employment_type"].as_str().unwrap(),
-            "full_time"
-        );
-        assert_eq!(
-            result.audit_step.input["day_type"].as_str().unwrap(),
-            "Sunday"
-        );
+        assert_eq!(step.input["hours"].as_str().unwrap(), "8");
+        assert_eq!(step.input["base_rate"].as_str().unwrap(), "28.54");
+        assert_eq!(step.input["employment_type"].as_str().unwrap(), "full_time");
+        assert_eq!(step.input["day_type"].as_str().unwrap(), "Sunday");
 
         // Check output contains expected fields
-        assert_eq!(
-            result.audit_step.output["multiplier"].as_str().unwrap(),
-            "1.75"
-        );
-        assert_eq!(
-            result.audit_step.output["effective_rate"].as_str().unwrap(),
-            "49.945"
-        );
-        assert_eq!(
-            result.audit_step.output["amount"].as_str().unwrap(),
-            "399.56"
-        );
+        assert_eq!(step.output["multiplier"].as_str().unwrap(), "1.75");
+        assert_eq!(step.output["effective_rate"].as_str().unwrap(), "49.945");
+        assert_eq!(step.output["amount"].as_str().unwrap(), "399.56");
     }
 
     #[test]
@@ -353,13 +396,14 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let segment = create_sunday_segment(dec("8.0"));
 
-        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
-        assert!(result.audit_step.reasoning.contains("Sunday penalty"));
-        assert!(result.audit_step.reasoning.contains("8"));
-        assert!(result.audit_step.reasoning.contains("28.54"));
-        assert!(result.audit_step.reasoning.contains("1.75"));
-        assert!(result.audit_step.reasoning.contains("399.56"));
+        let step = result.audit_step.unwrap();
+        assert!(step.reasoning.contains("Sunday penalty"));
+        assert!(step.reasoning.contains("8"));
+        assert!(step.reasoning.contains("28.54"));
+        assert!(step.reasoning.contains("1.75"));
+        assert!(step.reasoning.contains("399.56"));
     }
 
     #[test]
@@ -368,7 +412,7 @@ mod tests {
         let employee = create_test_employee(EmploymentType::FullTime);
         let segment = create_sunday_segment(dec("8.0"));
 
-        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // 2026-01-18 is a Sunday
         assert_eq!(
@@ -384,11 +428,81 @@ mod tests {
         let employee = create_test_employee(EmploymentType::Casual);
         let segment = create_sunday_segment(dec("8.0"));
 
-        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1);
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
 
         // If it were cumulative: 28.54 * 1.25 * 1.75 = 62.43125 rate, 62.43125 * 8 = 499.45
         // But it should be: 28.54 * 2.00 = 57.08 rate, 57.08 * 8 = 456.64
         assert_eq!(result.pay_line.amount, dec("456.64"));
         assert_ne!(result.pay_line.amount, dec("499.45"));
+        let step = result.audit_step.unwrap();
+        assert!(
+            step.reasoning
+                .contains("(175% penalty + 25% casual loading = 200%)")
+        );
+    }
+
+    #[test]
+    fn test_compounding_composition_applies_penalty_to_loaded_rate() {
+        let config = load_config();
+        let mut penalties = config.penalties().clone();
+        penalties.penalties.casual_composition = CasualPenaltyComposition::Compounding;
+        let config = AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            config.rates().to_vec(),
+            penalties,
+        );
+        let employee = create_test_employee(EmploymentType::Casual);
+        let segment = create_sunday_segment(dec("8.0"));
+
+        let result = calculate_sunday_pay(&segment, &employee, dec("28.54"), &config, 1, true);
+
+        // 28.54 * 1.75 * 1.25 = 62.43125 rate, 62.43125 * 8 = 499.45
+        assert_eq!(result.pay_line.amount, dec("499.45"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.output["multiplier"], "2.1875");
+        assert_eq!(step.output["penalty_multiplier"], "1.75");
+    }
+
+    #[test]
+    fn test_classification_sunday_multiplier_overrides_default() {
+        let config = load_config();
+        let mut penalties = config.penalties().clone();
+        penalties.penalties.classifications.insert(
+            "dce_level_3".to_string(),
+            ClassificationPenalties {
+                sunday: PenaltyRateOverrides {
+                    full_time: Some(dec("2.00")),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        let config = AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            config.rates().to_vec(),
+            penalties,
+        );
+        let segment = create_sunday_segment(dec("8.0"));
+
+        let overridden = create_test_employee(EmploymentType::FullTime);
+        let result = calculate_sunday_pay(&segment, &overridden, dec("28.54"), &config, 1, true);
+
+        // 8.0 * 28.54 * 2.00 = 456.64
+        assert_eq!(result.pay_line.rate, dec("57.08"));
+        assert_eq!(result.pay_line.amount, dec("456.64"));
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.output["multiplier"], "2");
+
+        // Employment types the classification does not override keep the
+        // default, as do other classifications
+        let part_time = create_test_employee(EmploymentType::PartTime);
+        let result = calculate_sunday_pay(&segment, &part_time, dec("28.54"), &config, 1, true);
+        assert_eq!(result.pay_line.rate, dec("49.945")); // 28.54 * 1.75
+        let mut other = create_test_employee(EmploymentType::FullTime);
+        other.classification_code = "dce_level_1".to_string();
+        let result = calculate_sunday_pay(&segment, &other, dec("28.54"), &config, 1, true);
+        assert_eq!(result.pay_line.rate, dec("49.945")); // 28.54 * 1.75
     }
 }
@@ -0,0 +1,116 @@
+//! Employee tag recognition.
+//!
+//! Employee tags switch on allowances. A misspelt tag silently switches
+//! nothing on, so unrecognised tags are reported as warnings.
+
+use crate::models::{AuditWarning, Employee};
+
+use super::{FIRST_AID_ALLOWANCE_TAG, LAUNDRY_ALLOWANCE_TAG, QUALIFICATION_ALLOWANCE_TAG};
+
+/// The employee tags recognised by the engine.
+///
+/// Each entry is the tag constant exported by the module that consumes it.
+pub const KNOWN_TAGS: &[&str] = &[
+    LAUNDRY_ALLOWANCE_TAG,
+    QUALIFICATION_ALLOWANCE_TAG,
+    FIRST_AID_ALLOWANCE_TAG,
+];
+
+/// The warning code for an employee tag the engine does not recognise.
+pub const UNKNOWN_TAG_WARNING: &str = "UNKNOWN_TAG";
+
+/// Checks an employee's tags and returns a warning for each unrecognised tag.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::check_employee_tags;
+/// use award_engine::models::{Employee, EmploymentType};
+/// use chrono::NaiveDate;
+///
+/// let employee = Employee {
+///     id: "emp_001".to_string(),
+///     employment_type: EmploymentType::FullTime,
+///     classification_code: "dce_level_3".to_string(),
+///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
+///     tags: vec!["laundy_allowance".to_string()],
+/// };
+///
+/// let warnings = check_employee_tags(&employee);
+/// assert_eq!(warnings.len(), 1);
+/// assert_eq!(warnings[0].code, "UNKNOWN_TAG");
+/// ```
+pub fn check_employee_tags(employee: &Employee) -> Vec<AuditWarning> {
+    employee
+        .tags
+        .iter()
+        .filter(|tag| !KNOWN_TAGS.contains(&tag.as_str()))
+        .map(|tag| AuditWarning {
+            code: UNKNOWN_TAG_WARNING.to_string(),
+            message: format!(
+                "Employee tag '{}' is not recognised and has no effect (known tags: {})",
+                tag,
+                KNOWN_TAGS.join(", ")
+            ),
+            severity: "medium".to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EmploymentType;
+    use chrono::NaiveDate;
+
+    fn create_test_employee(tags: Vec<&str>) -> Employee {
+        Employee {
+            id: "emp_001".to_string(),
+            employment_type: EmploymentType::FullTime,
+            classification_code: "dce_level_3".to_string(),
+            date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
+            employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
+            tags: tags.into_iter().map(String::from).collect(),
+        }
+    }
+
+    #[test]
+    fn test_known_tags_produce_no_warnings() {
+        let employee = create_test_employee(vec!["laundry_allowance", "qualification_allowance"]);
+
+        assert!(check_employee_tags(&employee).is_empty());
+    }
+
+    #[test]
+    fn test_misspelt_tag_produces_warning() {
+        let employee = create_test_employee(vec!["laundy_allowance"]);
+
+        let warnings = check_employee_tags(&employee);
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "UNKNOWN_TAG");
+        assert_eq!(warnings[0].severity, "medium");
+        assert!(warnings[0].message.contains("'laundy_allowance'"));
+        assert!(warnings[0].message.contains("laundry_allowance"));
+    }
+
+    #[test]
+    fn test_one_warning_per_unknown_tag() {
+        let employee = create_test_employee(vec!["night_shift", "laundry_allowance", "qualified"]);
+
+        let warnings = check_employee_tags(&employee);
+
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].message.contains("'night_shift'"));
+        assert!(warnings[1].message.contains("'qualified'"));
+    }
+}
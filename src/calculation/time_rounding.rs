@@ -0,0 +1,560 @@
+//! Worked time rounding functionality.
+//!
+//! This module rounds worked hours to the configured time increment before
+//! any pay rates are applied. It is distinct from monetary rounding: the
+//! rounded hours are what the rates are multiplied by.
+//!
+//! The increment is configured per award (e.g. the 6-minute units some awards
+//! pay in). Rounding is applied to a shift's worked hours before overtime is
+//! detected, and its day segments are split from the rounded hours at each
+//! segment boundary, so segmentation, overtime and the pay lines all use the
+//! same rounded hours.
+//!
+//! Shift and break times recorded to the second are handled first, as set by
+//! `shift_seconds`: they are either rounded to the nearest minute before the
+//! shift is segmented, or kept so worked time is paid to the second. Either
+//! way the worked hours and the segment hours are measured from the same
+//! times, so they agree.
+
+use chrono::{Duration, NaiveDateTime, Timelike};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::config::TimeRounding;
+use crate::models::{AuditStep, HOURS_PRECISION, Shift};
+
+use super::ShiftSegment;
+use super::rules::RuleInfo;
+
+/// The shift seconds and time rounding rules, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        rule_id: "shift_seconds_rounding",
+        rule_name: "Shift Seconds Rounding",
+        clause_ref: "N/A",
+    },
+    RuleInfo {
+        rule_id: "time_rounding",
+        rule_name: "Time Rounding",
+        clause_ref: "N/A",
+    },
+];
+
+/// The result of rounding shift times to the nearest minute.
+#[derive(Debug, Clone)]
+pub struct ShiftSecondsRoundingResult {
+    /// The shifts, with their start, end and break times on whole minutes.
+    pub shifts: Vec<Shift>,
+    /// An audit step for each shift whose times changed.
+    pub audit_steps: Vec<AuditStep>,
+}
+
+/// Rounds the start, end and break times of each shift to the nearest minute.
+///
+/// Thirty seconds or more rounds up. Shifts already on whole minutes are
+/// returned unchanged and produce no audit step, and no shift does when
+/// `include_audit` is false.
+///
+/// # Example
+///
+/// ```
+/// use award_engine::calculation::round_shift_seconds;
+/// use award_engine::models::Shift;
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let date = NaiveDate::from_ymd_opt(2026, 1, 13).unwrap();
+/// let shift = Shift {
+///     id: "shift_001".to_string(),
+///     date,
+///     start_time: date.and_hms_opt(9, 0, 0).unwrap(),
+///     end_time: date.and_hms_opt(17, 0, 30).unwrap(),
+///     breaks: vec![],
+///     rate_override: None,
+///     classification_code: None,
+///     reference: None,
+///     cost_centre: None,
+/// };
+///
+/// let result = round_shift_seconds(&[shift], 1, true);
+///
+/// assert_eq!(result.shifts[0].end_time, date.and_hms_opt(17, 1, 0).unwrap());
+/// assert_eq!(result.shifts[0].worked_hours(), Decimal::new(80167, 4));
+/// assert_eq!(result.audit_steps.len(), 1);
+/// ```
+pub fn round_shift_seconds(
+    shifts: &[Shift],
+    step_number: u32,
+    include_audit: bool,
+) -> ShiftSecondsRoundingResult {
+    let mut audit_steps = Vec::new();
+    let shifts = shifts
+        .iter()
+        .map(|shift| {
+            let mut rounded = shift.clone();
+            rounded.start_time = nearest_minute(shift.start_time);
+            rounded.end_time = nearest_minute(shift.end_time);
+            let mut breaks_rounded = 0;
+            for brk in &mut rounded.breaks {
+                let (start_time, end_time) =
+                    (nearest_minute(brk.start_time), nearest_minute(brk.end_time));
+                if (start_time, end_time) != (brk.start_time, brk.end_time) {
+                    breaks_rounded += 1;
+                }
+                brk.start_time = start_time;
+                brk.end_time = end_time;
+            }
+            if !include_audit || rounded == *shift {
+                return rounded;
+            }
+
+            let breaks_note = match breaks_rounded {
+                0 => String::new(),
+                1 => " and 1 break".to_string(),
+                n => format!(" and {} breaks", n),
+            };
+            audit_steps.push(AuditStep {
+                step_number: step_number + audit_steps.len() as u32,
+                rule_id: "shift_seconds_rounding".to_string(),
+                rule_name: "Shift Seconds Rounding".to_string(),
+                clause_ref: "N/A".to_string(),
+                input: serde_json::json!({
+                    "shift_id": shift.id,
+                    "start_time": shift.start_time.to_string(),
+                    "end_time": shift.end_time.to_string()
+                }),
+                output: serde_json::json!({
+                    "start_time": rounded.start_time.to_string(),
+                    "end_time": rounded.end_time.to_string(),
+                    "breaks_rounded": breaks_rounded
+                }),
+                reasoning: format!(
+                    "Shift {} times{} rounded to the nearest minute: {} to {} becomes {} to {}",
+                    shift.id,
+                    breaks_note,
+                    shift.start_time.time(),
+                    shift.end_time.time(),
+                    rounded.start_time.time(),
+                    rounded.end_time.time()
+                ),
+            });
+            rounded
+        })
+        .collect();
+
+    ShiftSecondsRoundingResult {
+        shifts,
+        audit_steps,
+    }
+}
+
+/// Rounds a time to the nearest minute, with 30 seconds rounding up.
+fn nearest_minute(time: NaiveDateTime) -> NaiveDateTime {
+    let whole_minute = time
+        .with_second(0)
+        .and_then(|t| t.with_nanosecond(0))
+        .expect("second and nanosecond zero are valid");
+    if time - whole_minute >= Duration::seconds(30) {
+        whole_minute + Duration::minutes(1)
+    } else {
+        whole_minute
+    }
+}
+
+/// The result of applying time rounding to a shift.
+#[derive(Debug, Clone)]
+pub struct TimeRoundingResult {
+    /// The shift's worked hours after rounding.
+    pub worked_hours: Decimal,
+    /// The shift segments with their hours rounded.
+    pub segments: Vec<ShiftSegment>,
+    /// The audit step recording the rounding, or `None` when rounding did
+    /// not change the hours.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Rounds a number of hours to the increment of the given rounding rule.
+///
+/// Nearest rounding rounds midpoints up (e.g. 7 minutes 30 seconds rounds to
+/// 15 minutes under `Nearest(15)`). An increment that does not divide an hour
+/// evenly (e.g. 7 minutes) gives hours rounded to [`HOURS_PRECISION`].
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::round_hours;
+/// use award_engine::config::TimeRounding;
+/// use rust_decimal::Decimal;
+///
+/// // 7 hours 52 minutes
+/// let hours = Decimal::new(472, 0) / Decimal::new(60, 0);
+///
+/// assert_eq!(round_hours(hours, TimeRounding::Up(15)), Decimal::new(80, 1));
+/// assert_eq!(round_hours(hours, TimeRounding::Nearest(15)), Decimal::new(775, 2));
+/// assert_eq!(round_hours(hours, TimeRounding::Nearest(6)), Decimal::new(79, 1));
+/// assert_eq!(round_hours(hours, TimeRounding::Up(6)), Decimal::new(79, 1));
+/// ```
+pub fn round_hours(hours: Decimal, rounding: TimeRounding) -> Decimal {
+    let Some(increment_minutes) = rounding.increment_minutes() else {
+        return hours;
+    };
+    let strategy = if rounding.rounds_up() {
+        RoundingStrategy::AwayFromZero
+    } else {
+        RoundingStrategy::MidpointAwayFromZero
+    };
+    let increment_minutes = Decimal::from(increment_minutes);
+    let minutes_per_hour = Decimal::from(60);
+
+    // Whole increments worked; computed at 6dp so exact multiples stay exact
+    let increments = (hours * minutes_per_hour / increment_minutes).round_dp(6);
+    let minutes = increments.round_dp_with_strategy(0, strategy) * increment_minutes;
+    (minutes / minutes_per_hour).round_dp(HOURS_PRECISION)
+}
+
+/// Applies the configured time rounding to a shift's worked hours and segments.
+///
+/// The worked time elapsed at each segment boundary is rounded, and each
+/// segment is paid the difference between its rounded boundaries, so the
+/// rounded segments always add up to the rounded worked hours.
+///
+/// # Arguments
+///
+/// * `shift_id` - The ID of the shift being rounded
+/// * `worked_hours` - The shift's worked hours before rounding
+/// * `segments` - The shift's day segments before rounding
+/// * `rounding` - The configured rounding rule
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Returns
+///
+/// Returns a `TimeRoundingResult` with the rounded hours. An audit step is
+/// only produced when rounding changes the worked hours or a segment's hours,
+/// so `TimeRounding::None` and shifts already on an increment produce none.
+pub fn apply_time_rounding(
+    shift_id: &str,
+    worked_hours: Decimal,
+    segments: Vec<ShiftSegment>,
+    rounding: TimeRounding,
+    step_number: u32,
+    include_audit: bool,
+) -> TimeRoundingResult {
+    if rounding == TimeRounding::None {
+        return TimeRoundingResult {
+            worked_hours,
+            segments,
+            audit_step: None,
+        };
+    }
+
+    let rounded_worked_hours = round_hours(worked_hours, rounding);
+    let raw_segment_hours: Vec<String> = segments
+        .iter()
+        .map(|s| s.hours.round_dp(4).normalize().to_string())
+        .collect();
+    // Round the elapsed worked time at each segment boundary and take the
+    // differences, so the rounded segments add up to the rounded shift
+    let last_index = segments.len().saturating_sub(1);
+    let mut raw_elapsed = Decimal::ZERO;
+    let mut rounded_elapsed = Decimal::ZERO;
+    let segments: Vec<ShiftSegment> = segments
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut segment)| {
+            raw_elapsed += segment.hours;
+            let boundary = if index == last_index {
+                rounded_worked_hours
+            } else {
+                round_hours(raw_elapsed, rounding)
+            };
+            segment.hours = boundary - rounded_elapsed;
+            rounded_elapsed = boundary;
+            segment
+        })
+        .collect();
+    let rounded_segment_hours: Vec<String> = segments
+        .iter()
+        .map(|s| s.hours.normalize().to_string())
+        .collect();
+    let raw_segments_rounded = raw_segment_hours
+        .iter()
+        .zip(&rounded_segment_hours)
+        .all(|(raw, rounded)| raw == rounded);
+    if rounded_worked_hours == worked_hours && raw_segments_rounded {
+        return TimeRoundingResult {
+            worked_hours,
+            segments,
+            audit_step: None,
+        };
+    }
+
+    let audit_step = include_audit.then(|| {
+        let rule = rounding.to_string();
+        AuditStep {
+            step_number,
+            rule_id: "time_rounding".to_string(),
+            rule_name: "Time Rounding".to_string(),
+            clause_ref: "N/A".to_string(),
+            input: serde_json::json!({
+                "shift_id": shift_id,
+                "rounding": rule,
+                "increment_minutes": rounding.increment_minutes(),
+                "raw_worked_hours": worked_hours.round_dp(4).normalize().to_string(),
+                "raw_segment_hours": raw_segment_hours
+            }),
+            output: serde_json::json!({
+                "rounded_worked_hours": rounded_worked_hours.normalize().to_string(),
+                "rounded_segment_hours": rounded_segment_hours
+            }),
+            reasoning: format!(
+                "Worked time of {} hours rounded to {} hours ({})",
+                worked_hours.round_dp(4).normalize(),
+                rounded_worked_hours.normalize(),
+                rule
+            ),
+        }
+    });
+
+    TimeRoundingResult {
+        worked_hours: rounded_worked_hours,
+        segments,
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculation::{DayType, segment_by_day};
+    use chrono::NaiveDateTime;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn minutes(m: i64) -> Decimal {
+        Decimal::from(m) / Decimal::from(60)
+    }
+
+    fn segment(hours: Decimal) -> ShiftSegment {
+        ShiftSegment {
+            start_time: NaiveDateTime::parse_from_str("2026-01-13 09:00:00", "%Y-%m-%d %H:%M:%S")
+                .unwrap(),
+            end_time: NaiveDateTime::parse_from_str("2026-01-13 17:00:00", "%Y-%m-%d %H:%M:%S")
+                .unwrap(),
+            day_type: DayType::Weekday,
+            hours,
+        }
+    }
+
+    fn shift(start: &str, end: &str) -> Shift {
+        let parse = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+        let start_time = parse(start);
+        Shift {
+            id: "shift_001".to_string(),
+            date: start_time.date(),
+            start_time,
+            end_time: parse(end),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_shift_seconds_round_to_the_nearest_minute() {
+        let mut recorded = shift("2026-01-13 08:59:29", "2026-01-13 17:00:30");
+        recorded.breaks.push(crate::models::Break {
+            start_time: recorded.date.and_hms_opt(12, 0, 10).unwrap(),
+            end_time: recorded.date.and_hms_opt(12, 30, 45).unwrap(),
+            is_paid: false,
+            kind: None,
+        });
+
+        let result = round_shift_seconds(&[recorded], 4, true);
+
+        let rounded = &result.shifts[0];
+        assert_eq!(rounded.start_time.time().to_string(), "08:59:00");
+        assert_eq!(rounded.end_time.time().to_string(), "17:01:00");
+        assert_eq!(rounded.breaks[0].start_time.time().to_string(), "12:00:00");
+        assert_eq!(rounded.breaks[0].end_time.time().to_string(), "12:31:00");
+        // 8 hours 2 minutes less a 31 minute break
+        assert_eq!(rounded.worked_hours(), dec("7.5167"));
+
+        assert_eq!(result.audit_steps.len(), 1);
+        let step = &result.audit_steps[0];
+        assert_eq!(step.step_number, 4);
+        assert_eq!(step.output["breaks_rounded"], 1);
+        assert_eq!(
+            step.reasoning,
+            "Shift shift_001 times and 1 break rounded to the nearest minute: 08:59:29 to 17:00:30 becomes 08:59:00 to 17:01:00"
+        );
+    }
+
+    #[test]
+    fn test_shifts_on_whole_minutes_are_not_audited() {
+        let on_the_minute = shift("2026-01-13 09:00:00", "2026-01-13 17:00:00");
+
+        let result = round_shift_seconds(std::slice::from_ref(&on_the_minute), 1, true);
+
+        assert_eq!(result.shifts, vec![on_the_minute]);
+        assert!(result.audit_steps.is_empty());
+    }
+
+    #[test]
+    fn test_none_leaves_hours_unchanged() {
+        assert_eq!(round_hours(minutes(472), TimeRounding::None), minutes(472));
+    }
+
+    #[test]
+    fn test_up_15_rounds_7h52m_to_8h() {
+        assert_eq!(round_hours(minutes(472), TimeRounding::Up(15)), dec("8.0"));
+    }
+
+    #[test]
+    fn test_nearest_15_rounds_to_closest_quarter_hour() {
+        // 7h52m is 7 minutes past 7h45m and 8 minutes before 8h00m
+        assert_eq!(
+            round_hours(minutes(472), TimeRounding::Nearest(15)),
+            dec("7.75")
+        );
+        // 7h53m is closer to 8h00m
+        assert_eq!(
+            round_hours(minutes(473), TimeRounding::Nearest(15)),
+            dec("8.0")
+        );
+    }
+
+    #[test]
+    fn test_nearest_15_midpoint_rounds_up() {
+        // 7h52m30s is exactly halfway between 7h45m and 8h00m
+        let hours = dec("7.875");
+        assert_eq!(round_hours(hours, TimeRounding::Nearest(15)), dec("8.0"));
+    }
+
+    #[test]
+    fn test_nearest_6_minutes() {
+        assert_eq!(
+            round_hours(minutes(472), TimeRounding::Nearest(6)),
+            dec("7.9")
+        );
+        assert_eq!(
+            round_hours(minutes(482), TimeRounding::Nearest(6)),
+            dec("8.0")
+        );
+    }
+
+    #[test]
+    fn test_exact_increments_are_not_rounded() {
+        assert_eq!(round_hours(minutes(480), TimeRounding::Up(15)), dec("8"));
+        assert_eq!(
+            round_hours(minutes(465), TimeRounding::Nearest(15)),
+            dec("7.75")
+        );
+    }
+
+    #[test]
+    fn test_seven_minute_remainder_rounds_to_configured_increment() {
+        // 8 hours 7 minutes
+        let hours = minutes(487);
+
+        assert_eq!(round_hours(hours, TimeRounding::Nearest(6)), dec("8.1"));
+        assert_eq!(round_hours(hours, TimeRounding::Up(6)), dec("8.2"));
+        assert_eq!(round_hours(hours, TimeRounding::Nearest(15)), dec("8"));
+        // An increment that does not divide the hour is held to 4dp
+        assert_eq!(round_hours(hours, TimeRounding::Nearest(7)), dec("8.1667"));
+    }
+
+    #[test]
+    fn test_rounding_that_changes_nothing_produces_no_audit_step() {
+        let result = apply_time_rounding(
+            "shift_001",
+            minutes(480),
+            vec![segment(minutes(480))],
+            TimeRounding::Up(6),
+            1,
+            true,
+        );
+
+        assert_eq!(result.worked_hours, dec("8"));
+        assert!(result.audit_step.is_none());
+    }
+
+    #[test]
+    fn test_apply_time_rounding_none_produces_no_audit_step() {
+        let result = apply_time_rounding(
+            "shift_001",
+            minutes(472),
+            vec![segment(minutes(472))],
+            TimeRounding::None,
+            1,
+            true,
+        );
+
+        assert_eq!(result.worked_hours, minutes(472));
+        assert_eq!(result.segments[0].hours, minutes(472));
+        assert!(result.audit_step.is_none());
+    }
+
+    #[test]
+    fn test_overnight_segments_add_up_to_the_rounded_shift_under_each_rounding() {
+        // 22:07 to 06:07: 1h53m before midnight and 6h07m after
+        let overnight = shift("2026-01-13 22:07:00", "2026-01-14 06:07:00");
+        let cases = [
+            (TimeRounding::None, "1.8833", "6.1167", "8"),
+            (TimeRounding::Nearest(15), "2", "6", "8"),
+            (TimeRounding::Nearest(6), "1.9", "6.1", "8"),
+            (TimeRounding::Up(15), "2", "6", "8"),
+            (TimeRounding::Nearest(10), "1.8333", "6.1667", "8"),
+            (TimeRounding::Up(6), "1.9", "6.1", "8"),
+        ];
+
+        for (rounding, before_midnight, after_midnight, worked) in cases {
+            let result = apply_time_rounding(
+                "shift_001",
+                overnight.worked_hours(),
+                segment_by_day(&overnight),
+                rounding,
+                1,
+                true,
+            );
+
+            let hours: Vec<Decimal> = result.segments.iter().map(|s| s.hours).collect();
+            assert_eq!(
+                hours,
+                vec![dec(before_midnight), dec(after_midnight)],
+                "{}",
+                rounding
+            );
+            assert_eq!(result.worked_hours, dec(worked), "{}", rounding);
+            assert_eq!(hours.iter().sum::<Decimal>(), result.worked_hours);
+        }
+    }
+
+    #[test]
+    fn test_apply_time_rounding_records_raw_and_rounded_hours() {
+        let result = apply_time_rounding(
+            "shift_001",
+            minutes(472),
+            vec![segment(minutes(472))],
+            TimeRounding::Up(15),
+            3,
+            true,
+        );
+
+        assert_eq!(result.worked_hours, dec("8.0"));
+        assert_eq!(result.segments[0].hours, dec("8.0"));
+
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 3);
+        assert_eq!(step.rule_id, "time_rounding");
+        assert_eq!(step.input["rounding"], "up_15_minutes");
+        assert_eq!(step.input["increment_minutes"], 15);
+        assert_eq!(step.input["raw_worked_hours"], "7.8667");
+        assert_eq!(step.output["rounded_worked_hours"], "8");
+        assert_eq!(step.output["rounded_segment_hours"][0], "8");
+    }
+}
@@ -0,0 +1,271 @@
+//! Timezone-aware worked time functionality.
+//!
+//! Shift times are recorded as local wall-clock times. When a request names
+//! an IANA timezone, this module measures the real elapsed time between those
+//! wall-clock times so that shifts spanning a daylight saving transition are
+//! paid for the hours actually worked (one fewer hour when clocks go forward,
+//! one more when they go back).
+
+use chrono::{Duration, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+use rust_decimal::Decimal;
+
+use crate::models::{AuditStep, hours_from_seconds};
+
+use super::ShiftSegment;
+use super::rules::RuleInfo;
+
+/// The daylight saving adjustment rule, for the rule catalogue.
+pub(super) const RULES: &[RuleInfo] = &[RuleInfo {
+    rule_id: "daylight_saving_adjustment",
+    rule_name: "Daylight Saving Adjustment",
+    clause_ref: "N/A",
+}];
+
+/// The result of applying a timezone to a shift.
+#[derive(Debug, Clone)]
+pub struct TimezoneAdjustmentResult {
+    /// The shift's worked hours after the daylight saving adjustment.
+    pub worked_hours: Decimal,
+    /// The shift segments with their hours adjusted.
+    pub segments: Vec<ShiftSegment>,
+    /// The audit step recording the adjustment, or `None` when the shift does
+    /// not span a daylight saving transition.
+    pub audit_step: Option<AuditStep>,
+}
+
+/// Returns the real elapsed hours between two local wall-clock times.
+///
+/// A wall-clock time that occurs twice (when clocks go back) is taken as its
+/// first occurrence. A wall-clock time skipped when clocks go forward is
+/// treated as if the clocks had not yet changed.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::elapsed_hours;
+/// use chrono::NaiveDateTime;
+/// use rust_decimal::Decimal;
+///
+/// let start = NaiveDateTime::parse_from_str("2025-10-04 22:00", "%Y-%m-%d %H:%M").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2025-10-05 06:00", "%Y-%m-%d %H:%M").unwrap();
+///
+/// // Clocks in Sydney go forward at 2am on 5 October 2025
+/// assert_eq!(elapsed_hours(start, end, chrono_tz::Australia::Sydney), Decimal::from(7));
+/// ```
+pub fn elapsed_hours(start: NaiveDateTime, end: NaiveDateTime, timezone: Tz) -> Decimal {
+    let seconds = (resolve_local(end, timezone) - resolve_local(start, timezone)).num_seconds();
+    hours_from_seconds(seconds)
+}
+
+/// Resolves a local wall-clock time to an instant in the given timezone.
+fn resolve_local(local: NaiveDateTime, timezone: Tz) -> chrono::DateTime<Tz> {
+    match timezone.from_local_datetime(&local).earliest() {
+        Some(instant) => instant,
+        // The time was skipped by a forward transition; resolve it against
+        // the offset in force before the transition
+        None => {
+            let before = timezone
+                .from_local_datetime(&(local - Duration::hours(1)))
+                .earliest()
+                .expect("local time an hour before a transition gap exists");
+            before + Duration::hours(1)
+        }
+    }
+}
+
+/// Adjusts a shift's worked hours and segments for daylight saving.
+///
+/// Each segment's hours are corrected by the difference between the real
+/// elapsed time and the wall-clock time of the segment. Unpaid breaks are
+/// unaffected.
+///
+/// # Arguments
+///
+/// * `shift_id` - The ID of the shift being adjusted
+/// * `worked_hours` - The shift's worked hours measured on the wall clock
+/// * `segments` - The shift's day segments measured on the wall clock
+/// * `timezone` - The timezone the shift times are recorded in
+/// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit step
+///
+/// # Returns
+///
+/// Returns a `TimezoneAdjustmentResult`. No audit step is produced when the
+/// shift does not span a daylight saving transition.
+pub fn apply_timezone(
+    shift_id: &str,
+    worked_hours: Decimal,
+    segments: Vec<ShiftSegment>,
+    timezone: Tz,
+    step_number: u32,
+    include_audit: bool,
+) -> TimezoneAdjustmentResult {
+    let mut adjustment = Decimal::ZERO;
+    let segments: Vec<ShiftSegment> = segments
+        .into_iter()
+        .map(|mut segment| {
+            let wall_clock_hours =
+                hours_from_seconds((segment.end_time - segment.start_time).num_seconds());
+            let delta =
+                elapsed_hours(segment.start_time, segment.end_time, timezone) - wall_clock_hours;
+            segment.hours += delta;
+            adjustment += delta;
+            segment
+        })
+        .collect();
+
+    if adjustment == Decimal::ZERO {
+        return TimezoneAdjustmentResult {
+            worked_hours,
+            segments,
+            audit_step: None,
+        };
+    }
+
+    let adjusted_worked_hours = worked_hours + adjustment;
+    let audit_step = include_audit.then(|| AuditStep {
+        step_number,
+        rule_id: "daylight_saving_adjustment".to_string(),
+        rule_name: "Daylight Saving Adjustment".to_string(),
+        clause_ref: "N/A".to_string(),
+        input: serde_json::json!({
+            "shift_id": shift_id,
+            "timezone": timezone.name(),
+            "wall_clock_worked_hours": worked_hours.round_dp(4).normalize().to_string()
+        }),
+        output: serde_json::json!({
+            "adjustment_hours": adjustment.normalize().to_string(),
+            "worked_hours": adjusted_worked_hours.round_dp(4).normalize().to_string()
+        }),
+        reasoning: format!(
+            "Shift spans a daylight saving transition in {}: {} wall-clock hours adjusted by {} to {} hours actually worked",
+            timezone.name(),
+            worked_hours.round_dp(4).normalize(),
+            adjustment.normalize(),
+            adjusted_worked_hours.round_dp(4).normalize()
+        ),
+    });
+
+    TimezoneAdjustmentResult {
+        worked_hours: adjusted_worked_hours,
+        segments,
+        audit_step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculation::segment_by_day;
+    use crate::models::Shift;
+    use chrono_tz::Australia::Sydney;
+
+    fn datetime(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn shift(start: &str, end: &str) -> Shift {
+        let start_time = datetime(start);
+        Shift {
+            id: "shift_001".to_string(),
+            date: start_time.date(),
+            start_time,
+            end_time: datetime(end),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_spring_forward_shift_is_one_hour_shorter() {
+        let shift = shift("2025-10-04 22:00", "2025-10-05 06:00");
+
+        let result = apply_timezone(
+            &shift.id,
+            shift.worked_hours(),
+            segment_by_day(&shift),
+            Sydney,
+            4,
+            true,
+        );
+
+        assert_eq!(result.worked_hours, Decimal::from(7));
+        // Saturday segment is before the transition; Sunday loses the hour
+        assert_eq!(result.segments[0].hours, Decimal::from(2));
+        assert_eq!(result.segments[1].hours, Decimal::from(5));
+
+        let step = result.audit_step.unwrap();
+        assert_eq!(step.step_number, 4);
+        assert_eq!(step.rule_id, "daylight_saving_adjustment");
+        assert_eq!(step.input["timezone"], "Australia/Sydney");
+        assert_eq!(step.output["adjustment_hours"], "-1");
+    }
+
+    #[test]
+    fn test_fall_back_shift_is_one_hour_longer() {
+        // Clocks in Sydney go back at 3am on 5 April 2026
+        let shift = shift("2026-04-04 22:00", "2026-04-05 06:00");
+
+        let result = apply_timezone(
+            &shift.id,
+            shift.worked_hours(),
+            segment_by_day(&shift),
+            Sydney,
+            1,
+            true,
+        );
+
+        assert_eq!(result.worked_hours, Decimal::from(9));
+        assert_eq!(result.segments[1].hours, Decimal::from(7));
+    }
+
+    #[test]
+    fn test_shift_without_transition_is_unchanged() {
+        let shift = shift("2026-01-13 09:00", "2026-01-13 17:00");
+
+        let result = apply_timezone(
+            &shift.id,
+            shift.worked_hours(),
+            segment_by_day(&shift),
+            Sydney,
+            1,
+            true,
+        );
+
+        assert_eq!(result.worked_hours, Decimal::from(8));
+        assert!(result.audit_step.is_none());
+    }
+
+    #[test]
+    fn test_timezone_without_daylight_saving_is_unchanged() {
+        let shift = shift("2025-10-04 22:00", "2025-10-05 06:00");
+
+        let result = apply_timezone(
+            &shift.id,
+            shift.worked_hours(),
+            segment_by_day(&shift),
+            chrono_tz::Australia::Brisbane,
+            1,
+            true,
+        );
+
+        assert_eq!(result.worked_hours, Decimal::from(8));
+        assert!(result.audit_step.is_none());
+    }
+
+    #[test]
+    fn test_skipped_local_time_resolves_against_earlier_offset() {
+        // 02:30 does not exist in Sydney on 5 October 2025; it is read as
+        // 02:30 standard time (03:30 daylight time)
+        let hours = elapsed_hours(
+            datetime("2025-10-05 01:00"),
+            datetime("2025-10-05 02:30"),
+            Sydney,
+        );
+        assert_eq!(hours, Decimal::new(15, 1));
+    }
+}
@@ -0,0 +1,85 @@
+//! Week boundary logic.
+//!
+//! This module centralises how dates are grouped into weeks, so that weekly
+//! caps and any other weekly rules agree on where a week starts.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Returns the key identifying the week that contains `date`.
+///
+/// The key is the date the week starts on, given the award's week start day.
+/// Two dates are in the same week if and only if they have the same key.
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::calculation::week_key;
+/// use chrono::{NaiveDate, Weekday};
+///
+/// let sunday = NaiveDate::from_ymd_opt(2026, 1, 18).unwrap();
+///
+/// // Monday-start weeks: Sunday ends the week that began on Monday 12th
+/// assert_eq!(week_key(sunday, Weekday::Mon), NaiveDate::from_ymd_opt(2026, 1, 12).unwrap());
+/// // Sunday-start weeks: Sunday begins a new week
+/// assert_eq!(week_key(sunday, Weekday::Sun), sunday);
+/// ```
+pub fn week_key(date: NaiveDate, week_start_day: Weekday) -> NaiveDate {
+    let days_into_week = date.weekday().days_since(week_start_day);
+    date - Duration::days(i64::from(days_into_week))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_sunday_shift_monday_start_week() {
+        // Sunday 18 Jan belongs to the week starting Monday 12 Jan
+        assert_eq!(
+            week_key(date("2026-01-18"), Weekday::Mon),
+            date("2026-01-12")
+        );
+        assert_eq!(
+            week_key(date("2026-01-18"), Weekday::Mon),
+            week_key(date("2026-01-17"), Weekday::Mon)
+        );
+    }
+
+    #[test]
+    fn test_sunday_shift_sunday_start_week() {
+        // Sunday 18 Jan starts a new week, separate from Saturday 17 Jan
+        assert_eq!(
+            week_key(date("2026-01-18"), Weekday::Sun),
+            date("2026-01-18")
+        );
+        assert_ne!(
+            week_key(date("2026-01-18"), Weekday::Sun),
+            week_key(date("2026-01-17"), Weekday::Sun)
+        );
+    }
+
+    #[test]
+    fn test_week_start_day_is_its_own_key() {
+        assert_eq!(
+            week_key(date("2026-01-12"), Weekday::Mon),
+            date("2026-01-12")
+        );
+        assert_eq!(
+            week_key(date("2026-01-14"), Weekday::Wed),
+            date("2026-01-14")
+        );
+    }
+
+    #[test]
+    fn test_week_spans_month_boundary() {
+        // Sunday 1 Feb 2026, Monday-start: week began Monday 26 Jan
+        assert_eq!(
+            week_key(date("2026-02-01"), Weekday::Mon),
+            date("2026-01-26")
+        );
+    }
+}
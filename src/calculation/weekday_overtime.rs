@@ -5,20 +5,48 @@
 //!
 //! ## Rate Structure
 //!
-//! **Weekday overtime is calculated in two tiers:**
+//! **Weekday overtime is paid in the tiers listed in `overtime.weekday.tiers`.**
+//! MA000018 configures two:
 //! - First 2 hours: 150% for non-casuals, 187.5% for casuals (1.5 × 1.25)
 //! - After 2 hours: 200% for non-casuals, 250% for casuals (2.0 × 1.25)
+//!
+//! Casual rates include the casual loading. Setting `overtime.casual_ot_base`
+//! to `base` excludes the loading, paying casual overtime at 150%/200%.
+//!
+//! ## Day Attribution
+//!
+//! These tiers apply to the overtime worked on a weekday, whichever day the
+//! shift started on. A shift's overtime is its last hours, so a Sunday night
+//! shift that runs past its ordinary hours into Monday is paid its Monday
+//! overtime here, while any overtime before midnight is paid at Sunday rates.
+//! Each day's portion starts at the first tier.
 
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::calculation::{
+    CasualOvertimeComposition, casual_overtime_audit_step, compose_casual_overtime,
+};
 use crate::config::AwardConfig;
-use crate::models::{AuditStep, Employee, EmploymentType, PayCategory, PayLine};
-
-/// The threshold in hours for tier 1 weekday overtime.
-/// First 2 hours are paid at a lower rate (150%/187.5%).
-pub const WEEKDAY_OT_TIER_1_THRESHOLD: Decimal = Decimal::from_parts(2, 0, 0, false, 0);
+use crate::models::{AuditStep, Employee, EmploymentType, OvertimeTier, PayLine};
+
+use super::rules::RuleInfo;
+
+/// The weekday overtime tiers configured for MA000018, for the rule
+/// catalogue. Each further configured tier is recorded as `overtime_tier_<n>`.
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        rule_id: "overtime_tier_1",
+        rule_name: "Weekday Overtime Tier 1",
+        clause_ref: "25.1(a)(i)(A)",
+    },
+    RuleInfo {
+        rule_id: "overtime_tier_2",
+        rule_name: "Weekday Overtime Tier 2",
+        clause_ref: "25.1(a)(i)(A)",
+    },
+];
 
 /// The result of weekday overtime calculation.
 ///
@@ -26,18 +54,27 @@ pub const WEEKDAY_OT_TIER_1_THRESHOLD: Decimal = Decimal::from_parts(2, 0, 0, fa
 /// documenting the calculations.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WeekdayOvertimeResult {
-    /// Pay lines for overtime (may be 0, 1, or 2 lines depending on hours).
+    /// Pay lines for overtime (one per tier the hours reach).
     pub pay_lines: Vec<PayLine>,
+    /// The tier behind each pay line, in the same order.
+    pub tiers: Vec<OvertimeTier>,
     /// Audit steps recording each tier calculation.
     pub audit_steps: Vec<AuditStep>,
 }
 
 /// Calculates weekday overtime pay at tiered rates.
 ///
-/// Weekday overtime is calculated in two tiers as per clause 25.1(a)(i)(A):
+/// Weekday overtime is paid in the configured tiers. Each tier covers the
+/// overtime hours from its threshold up to the next tier's threshold, and the
+/// last tier covers the rest. One pay line is produced per tier reached, in
+/// the tier's configured pay category. MA000018 configures two tiers as per
+/// clause 25.1(a)(i)(A):
 /// - **Tier 1 (first 2 hours):** 150% for non-casuals, 187.5% for casuals
 /// - **Tier 2 (after 2 hours):** 200% for non-casuals, 250% for casuals
 ///
+/// For casuals, a final audit step records how each tier's multiplier was
+/// composed under the configured `casual_ot_base`.
+///
 /// # Arguments
 ///
 /// * `overtime_hours` - The total overtime hours to be paid
@@ -47,11 +84,13 @@ pub struct WeekdayOvertimeResult {
 /// * `date` - The date of the shift for pay line records
 /// * `shift_id` - The shift ID for pay line records
 /// * `step_number_start` - The starting step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit steps
 ///
 /// # Returns
 ///
 /// A [`WeekdayOvertimeResult`] containing:
-/// - `pay_lines`: 0-2 pay lines depending on overtime hours
+/// - `pay_lines`: one pay line per tier the overtime hours reach
+/// - `tiers`: the configured threshold and multiplier of each pay line's tier
 /// - `audit_steps`: Documentation of each tier calculation
 ///
 /// # Award Reference
@@ -78,6 +117,9 @@ pub struct WeekdayOvertimeResult {
 ///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
 ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
 ///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
 ///     tags: vec![],
 /// };
 /// let date = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
@@ -90,6 +132,7 @@ pub struct WeekdayOvertimeResult {
 ///     date,
 ///     "shift_001",
 ///     1,
+///     true,
 /// );
 ///
 /// assert_eq!(result.pay_lines.len(), 1);
@@ -114,6 +157,9 @@ pub struct WeekdayOvertimeResult {
 ///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
 ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
 ///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
 ///     tags: vec![],
 /// };
 /// let date = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
@@ -126,12 +172,14 @@ pub struct WeekdayOvertimeResult {
 ///     date,
 ///     "shift_001",
 ///     1,
+///     true,
 /// );
 ///
 /// assert_eq!(result.pay_lines.len(), 2);
 /// assert_eq!(result.pay_lines[0].category, PayCategory::Overtime150);
 /// assert_eq!(result.pay_lines[1].category, PayCategory::Overtime200);
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_weekday_overtime(
     overtime_hours: Decimal,
     base_rate: Decimal,
@@ -140,8 +188,10 @@ pub fn calculate_weekday_overtime(
     date: NaiveDate,
     shift_id: &str,
     step_number_start: u32,
+    include_audit: bool,
 ) -> WeekdayOvertimeResult {
     let mut pay_lines = Vec::new();
+    let mut overtime_tiers = Vec::new();
     let mut audit_steps = Vec::new();
     let mut step_number = step_number_start;
 
@@ -149,172 +199,159 @@ pub fn calculate_weekday_overtime(
     if overtime_hours <= Decimal::ZERO {
         return WeekdayOvertimeResult {
             pay_lines,
+            tiers: overtime_tiers,
             audit_steps,
         };
     }
 
-    // Get overtime rates from config
-    let overtime_config = &config.penalties().overtime.weekday;
-
-    // Get the multipliers based on employment type
-    let (tier1_multiplier, tier2_multiplier) = match employee.employment_type {
-        EmploymentType::FullTime => (
-            overtime_config.first_two_hours.full_time,
-            overtime_config.after_two_hours.full_time,
-        ),
-        EmploymentType::PartTime => (
-            overtime_config.first_two_hours.part_time,
-            overtime_config.after_two_hours.part_time,
-        ),
-        EmploymentType::Casual => (
-            overtime_config.first_two_hours.casual,
-            overtime_config.after_two_hours.casual,
-        ),
-    };
-
-    let employment_type_str = match employee.employment_type {
-        EmploymentType::FullTime => "full_time",
-        EmploymentType::PartTime => "part_time",
-        EmploymentType::Casual => "casual",
-    };
-
-    // Calculate tier 1 overtime (first 2 hours)
-    let tier1_hours = if overtime_hours <= WEEKDAY_OT_TIER_1_THRESHOLD {
-        overtime_hours
-    } else {
-        WEEKDAY_OT_TIER_1_THRESHOLD
-    };
-
-    if tier1_hours > Decimal::ZERO {
-        let tier1_rate = base_rate * tier1_multiplier;
-        let tier1_amount = tier1_hours * tier1_rate;
-
-        let tier1_reasoning = if employee.is_casual() {
-            format!(
-                "First {} hours of weekday overtime at {}% ({}% × 1.25 casual loading): {} hours × ${} = ${}",
-                tier1_hours.normalize(),
-                (tier1_multiplier * Decimal::from(100)).normalize(),
-                Decimal::from(150),
-                tier1_hours.normalize(),
-                tier1_rate.normalize(),
-                tier1_amount.normalize()
-            )
+    // Get overtime tiers from config
+    let tiers = &config.penalties().overtime.weekday.tiers;
+    let casual_ot_base = config.penalties().overtime.casual_ot_base;
+    let employment_type_str = employee.employment_type.as_str();
+    let mut casual_tiers = Vec::new();
+
+    for (index, tier) in tiers.iter().enumerate() {
+        let tier_number = index + 1;
+        let next_threshold = tiers.get(index + 1).map(|next| next.threshold_hours);
+
+        // Hours between this tier's threshold and the next (or all remaining hours)
+        let tier_end = next_threshold.map_or(overtime_hours, |next| overtime_hours.min(next));
+        let tier_hours = tier_end - tier.threshold_hours;
+        if tier_hours <= Decimal::ZERO {
+            continue;
+        }
+
+        // Get the multiplier based on employment type
+        let multiplier = match employee.employment_type {
+            EmploymentType::FullTime => tier.rates.full_time,
+            EmploymentType::PartTime => tier.rates.part_time,
+            EmploymentType::Casual => tier.rates.casual,
+        };
+        let composition = compose_casual_overtime(multiplier, casual_ot_base);
+        let multiplier = if employee.is_casual() {
+            composition.multiplier
         } else {
-            format!(
-                "First {} hours of weekday overtime at {}%: {} hours × ${} = ${}",
-                tier1_hours.normalize(),
-                (tier1_multiplier * Decimal::from(100)).normalize(),
-                tier1_hours.normalize(),
-                tier1_rate.normalize(),
-                tier1_amount.normalize()
-            )
+            multiplier
         };
 
-        let tier1_audit = AuditStep {
-            step_number,
-            rule_id: "overtime_tier_1".to_string(),
-            rule_name: "Weekday Overtime Tier 1".to_string(),
-            clause_ref: "25.1(a)(i)(A)".to_string(),
-            input: serde_json::json!({
-                "hours": tier1_hours.normalize().to_string(),
-                "base_rate": base_rate.normalize().to_string(),
-                "employment_type": employment_type_str
-            }),
-            output: serde_json::json!({
-                "multiplier": tier1_multiplier.normalize().to_string(),
-                "rate": tier1_rate.normalize().to_string(),
-                "amount": tier1_amount.normalize().to_string()
-            }),
-            reasoning: tier1_reasoning,
-        };
+        let rate = base_rate * multiplier;
+        let amount = tier_hours * rate;
+
+        if include_audit {
+            let percentage = if employee.is_casual() {
+                format!(
+                    "{}% ({})",
+                    (multiplier * Decimal::from(100)).normalize(),
+                    casual_composition_note(&composition)
+                )
+            } else {
+                format!("{}%", (multiplier * Decimal::from(100)).normalize())
+            };
+            let description = match (index, next_threshold) {
+                (0, Some(_)) => {
+                    format!("First {} hours of weekday overtime", tier_hours.normalize())
+                }
+                (0, None) => "Weekday overtime".to_string(),
+                (_, Some(next)) => format!(
+                    "Overtime from {} to {} hours",
+                    tier.threshold_hours.normalize(),
+                    next.normalize()
+                ),
+                (_, None) => format!(
+                    "Overtime after first {} hours",
+                    tier.threshold_hours.normalize()
+                ),
+            };
+
+            audit_steps.push(AuditStep {
+                step_number,
+                rule_id: format!("overtime_tier_{}", tier_number),
+                rule_name: format!("Weekday Overtime Tier {}", tier_number),
+                clause_ref: "25.1(a)(i)(A)".to_string(),
+                input: serde_json::json!({
+                    "hours": tier_hours.normalize().to_string(),
+                    "base_rate": base_rate.normalize().to_string(),
+                    "employment_type": employment_type_str
+                }),
+                output: serde_json::json!({
+                    "multiplier": multiplier.normalize().to_string(),
+                    "rate": rate.normalize().to_string(),
+                    "amount": amount.normalize().to_string()
+                }),
+                reasoning: format!(
+                    "{} at {}: {} hours × ${} = ${}",
+                    description,
+                    percentage,
+                    tier_hours.normalize(),
+                    rate.normalize(),
+                    amount.normalize()
+                ),
+            });
+        }
 
-        let tier1_pay_line = PayLine {
+        let pay_line = PayLine {
             date,
             shift_id: shift_id.to_string(),
-            category: PayCategory::Overtime150,
-            hours: tier1_hours,
-            rate: tier1_rate,
-            amount: tier1_amount,
+            category: tier.category,
+            hours: tier_hours,
+            rate,
+            amount,
             clause_ref: "25.1(a)(i)(A)".to_string(),
+            reference: None,
+            cost_centre: None,
         };
-
-        pay_lines.push(tier1_pay_line);
-        audit_steps.push(tier1_audit);
+        overtime_tiers.push(OvertimeTier::from_pay_line(
+            &pay_line,
+            tier_number as u32,
+            tier.threshold_hours,
+            multiplier,
+        ));
+        pay_lines.push(pay_line);
+
+        casual_tiers.push((format!("tier_{}", tier_number), composition));
         step_number += 1;
     }
 
-    // Calculate tier 2 overtime (after 2 hours)
-    let tier2_hours = if overtime_hours > WEEKDAY_OT_TIER_1_THRESHOLD {
-        overtime_hours - WEEKDAY_OT_TIER_1_THRESHOLD
-    } else {
-        Decimal::ZERO
-    };
-
-    if tier2_hours > Decimal::ZERO {
-        let tier2_rate = base_rate * tier2_multiplier;
-        let tier2_amount = tier2_hours * tier2_rate;
-
-        let tier2_reasoning = if employee.is_casual() {
-            format!(
-                "Overtime after first 2 hours at {}% ({}% × 1.25 casual loading): {} hours × ${} = ${}",
-                (tier2_multiplier * Decimal::from(100)).normalize(),
-                Decimal::from(200),
-                tier2_hours.normalize(),
-                tier2_rate.normalize(),
-                tier2_amount.normalize()
-            )
-        } else {
-            format!(
-                "Overtime after first 2 hours at {}%: {} hours × ${} = ${}",
-                (tier2_multiplier * Decimal::from(100)).normalize(),
-                tier2_hours.normalize(),
-                tier2_rate.normalize(),
-                tier2_amount.normalize()
-            )
-        };
-
-        let tier2_audit = AuditStep {
+    if include_audit && employee.is_casual() {
+        let casual_tiers: Vec<(&str, CasualOvertimeComposition)> = casual_tiers
+            .iter()
+            .map(|(tier, composition)| (tier.as_str(), *composition))
+            .collect();
+        audit_steps.push(casual_overtime_audit_step(
+            casual_ot_base,
+            &casual_tiers,
+            "25.1(a)(i)(A)",
             step_number,
-            rule_id: "overtime_tier_2".to_string(),
-            rule_name: "Weekday Overtime Tier 2".to_string(),
-            clause_ref: "25.1(a)(i)(A)".to_string(),
-            input: serde_json::json!({
-                "hours": tier2_hours.normalize().to_string(),
-                "base_rate": base_rate.normalize().to_string(),
-                "employment_type": employment_type_str
-            }),
-            output: serde_json::json!({
-                "multiplier": tier2_multiplier.normalize().to_string(),
-                "rate": tier2_rate.normalize().to_string(),
-                "amount": tier2_amount.normalize().to_string()
-            }),
-            reasoning: tier2_reasoning,
-        };
-
-        let tier2_pay_line = PayLine {
-            date,
-            shift_id: shift_id.to_string(),
-            category: PayCategory::Overtime200,
-            hours: tier2_hours,
-            rate: tier2_rate,
-            amount: tier2_amount,
-            clause_ref: "25.1(a)(i)(A)".to_string(),
-        };
-
-        pay_lines.push(tier2_pay_line);
-        audit_steps.push(tier2_audit);
+        ));
     }
 
     WeekdayOvertimeResult {
         pay_lines,
+        tiers: overtime_tiers,
         audit_steps,
     }
 }
 
+/// Describes a casual overtime multiplier's composition for audit reasoning.
+fn casual_composition_note(composition: &CasualOvertimeComposition) -> String {
+    if composition.casual_loading == Decimal::ONE {
+        "casual loading excluded".to_string()
+    } else {
+        format!(
+            "{}% × {} casual loading",
+            (composition.overtime_multiplier * Decimal::from(100))
+                .round_dp(2)
+                .normalize(),
+            composition.casual_loading.normalize()
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::ConfigLoader;
+    use crate::config::{CasualOvertimeBase, ConfigLoader};
+    use crate::models::PayCategory;
     use std::str::FromStr;
 
     fn dec(s: &str) -> Decimal {
@@ -329,6 +366,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -362,6 +402,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert!(result.pay_lines.is_empty());
@@ -386,6 +427,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines.len(), 1);
@@ -421,6 +463,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines.len(), 1);
@@ -451,6 +494,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines.len(), 2);
@@ -494,6 +538,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines.len(), 2);
@@ -532,6 +577,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert!(result.pay_lines.is_empty());
@@ -556,6 +602,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines.len(), 1);
@@ -588,6 +635,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines.len(), 2);
@@ -629,6 +677,7 @@ mod tests {
             test_date(),
             "shift_001",
             5,
+            true,
         );
 
         assert_eq!(result.audit_steps[0].step_number, 5);
@@ -649,6 +698,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         // Should contain rate information
@@ -670,6 +720,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         // Should mention casual loading
@@ -677,6 +728,106 @@ mod tests {
         assert!(result.audit_steps[1].reasoning.contains("casual loading"));
     }
 
+    /// Loads the MA000018 config with the given casual overtime base.
+    fn load_config_with_casual_ot_base(casual_ot_base: CasualOvertimeBase) -> AwardConfig {
+        let config = load_config();
+        let mut penalties = config.penalties().clone();
+        penalties.overtime.casual_ot_base = casual_ot_base;
+        AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            config.rates().to_vec(),
+            penalties,
+        )
+    }
+
+    #[test]
+    fn test_casual_ot_base_loaded_records_composition() {
+        let config = load_config_with_casual_ot_base(CasualOvertimeBase::Loaded);
+        let employee = create_test_employee(EmploymentType::Casual);
+
+        let result = calculate_weekday_overtime(
+            dec("3.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            test_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        // 28.54 × 1.875 = 53.5125; 28.54 × 2.5 = 71.35
+        assert_eq!(result.pay_lines[0].rate, dec("53.5125"));
+        assert_eq!(result.pay_lines[1].rate, dec("71.35"));
+
+        assert_eq!(result.audit_steps.len(), 3);
+        let step = &result.audit_steps[2];
+        assert_eq!(step.step_number, 3);
+        assert_eq!(step.rule_id, "casual_overtime_composition");
+        assert_eq!(step.input["casual_ot_base"], "loaded");
+        assert_eq!(step.output["tiers"][0]["tier"], "tier_1");
+        assert_eq!(step.output["tiers"][0]["overtime_multiplier"], "1.5");
+        assert_eq!(step.output["tiers"][0]["multiplier"], "1.875");
+        assert_eq!(step.output["tiers"][1]["multiplier"], "2.5");
+    }
+
+    #[test]
+    fn test_casual_ot_base_base_excludes_casual_loading() {
+        let config = load_config_with_casual_ot_base(CasualOvertimeBase::Base);
+        let employee = create_test_employee(EmploymentType::Casual);
+
+        let result = calculate_weekday_overtime(
+            dec("3.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            test_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        // 2h × $42.81 (150%) + 1h × $57.08 (200%)
+        assert_eq!(result.pay_lines[0].rate, dec("42.81"));
+        assert_eq!(result.pay_lines[0].amount, dec("85.62"));
+        assert_eq!(result.pay_lines[1].rate, dec("57.08"));
+        assert!(
+            result.audit_steps[0]
+                .reasoning
+                .contains("casual loading excluded")
+        );
+
+        let step = &result.audit_steps[2];
+        assert_eq!(step.input["casual_ot_base"], "base");
+        assert_eq!(step.output["tiers"][0]["casual_loading"], "1");
+        assert_eq!(step.output["tiers"][1]["multiplier"], "2");
+        assert_eq!(
+            step.reasoning,
+            "Casual overtime paid on the base rate, casual loading excluded: tier_1: 150%; tier_2: 200%"
+        );
+    }
+
+    #[test]
+    fn test_no_composition_step_for_non_casuals() {
+        let config = load_config_with_casual_ot_base(CasualOvertimeBase::Base);
+        let employee = create_test_employee(EmploymentType::PartTime);
+
+        let result = calculate_weekday_overtime(
+            dec("3.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            test_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines[0].rate, dec("42.81"));
+        assert_eq!(result.audit_steps.len(), 2);
+    }
+
     #[test]
     fn test_audit_input_contains_required_fields() {
         let config = load_config();
@@ -691,6 +842,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         let step = &result.audit_steps[0];
@@ -713,6 +865,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         let step = &result.audit_steps[0];
@@ -735,6 +888,7 @@ mod tests {
             test_date(),
             "my_custom_shift_123",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines[0].shift_id, "my_custom_shift_123");
@@ -755,6 +909,7 @@ mod tests {
             date,
             "shift_001",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines[0].date, date);
@@ -774,6 +929,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines[0].clause_ref, "25.1(a)(i)(A)");
@@ -795,6 +951,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         assert_eq!(result.pay_lines.len(), 2);
@@ -824,6 +981,7 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         let pt_result = calculate_weekday_overtime(
@@ -834,10 +992,84 @@ mod tests {
             test_date(),
             "shift_001",
             1,
+            true,
         );
 
         // Part-time and full-time should have the same overtime rates
         assert_eq!(ft_result.pay_lines[0].rate, pt_result.pay_lines[0].rate);
         assert_eq!(ft_result.pay_lines[1].rate, pt_result.pay_lines[1].rate);
     }
+
+    #[test]
+    fn test_third_tier_pays_double_and_a_half() {
+        use crate::config::{OvertimeRates, WeekdayOvertimeTier};
+
+        let config = load_config();
+        let mut penalties = config.penalties().clone();
+        penalties.overtime.weekday.tiers.push(WeekdayOvertimeTier {
+            threshold_hours: dec("4"),
+            category: PayCategory::Overtime250,
+            rates: OvertimeRates {
+                full_time: dec("2.5"),
+                part_time: dec("2.5"),
+                casual: dec("3.125"),
+            },
+        });
+        let config = AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            config.rates().to_vec(),
+            penalties,
+        );
+        let employee = create_test_employee(EmploymentType::FullTime);
+
+        let result = calculate_weekday_overtime(
+            dec("5.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            test_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        // 2h @ 150%, 2h @ 200%, 1h @ 250%
+        let categories: Vec<PayCategory> = result.pay_lines.iter().map(|pl| pl.category).collect();
+        assert_eq!(
+            categories,
+            vec![
+                PayCategory::Overtime150,
+                PayCategory::Overtime200,
+                PayCategory::Overtime250
+            ]
+        );
+        assert_eq!(result.pay_lines[1].hours, dec("2.0"));
+        assert_eq!(result.pay_lines[2].hours, dec("1.0"));
+        // 1h × ($28.54 × 2.5) = $71.35
+        assert_eq!(result.pay_lines[2].amount, dec("71.35"));
+        let thresholds: Vec<(u32, Decimal)> = result
+            .tiers
+            .iter()
+            .map(|tier| (tier.tier, tier.threshold_hours))
+            .collect();
+        assert_eq!(
+            thresholds,
+            vec![(1, dec("0")), (2, dec("2")), (3, dec("4"))]
+        );
+        assert_eq!(result.tiers[2].multiplier, dec("2.5"));
+
+        assert_eq!(result.audit_steps[1].rule_id, "overtime_tier_2");
+        assert!(
+            result.audit_steps[1]
+                .reasoning
+                .starts_with("Overtime from 2 to 4 hours at 200%")
+        );
+        assert_eq!(result.audit_steps[2].rule_id, "overtime_tier_3");
+        assert!(
+            result.audit_steps[2]
+                .reasoning
+                .starts_with("Overtime after first 4 hours at 250%")
+        );
+    }
 }
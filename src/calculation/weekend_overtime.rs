@@ -5,36 +5,103 @@
 //!
 //! ## Rate Structure
 //!
-//! **Weekend overtime is NOT tiered (unlike weekday overtime):**
+//! **Weekend overtime is NOT tiered under MA000018 (unlike weekday overtime):**
 //! - All weekend overtime hours: 200% for non-casuals, 250% for casuals (2.0 × 1.25)
 //!
-//! This differs from weekday overtime where the first 2 hours are at a lower rate.
+//! Awards that tier weekend overtime can enable `overtime.weekend.tiered` and
+//! list `tiers` as weekday overtime does. Each tier is paid from its threshold
+//! until the next tier's, at its Saturday or Sunday rates, under its
+//! configured pay category; the last tier covers all remaining hours.
+//!
+//! ## Stacking Policy
+//!
+//! `overtime.weekend.overtime_stacking` controls how overtime interacts with
+//! the weekend penalty:
+//! - `replace` (default): the overtime multiplier is applied to the base rate
+//! - `stack_on_penalty`: the overtime multiplier is applied to the Saturday or
+//!   Sunday penalty rate (e.g. 150% × 200% = 300% for full-time Saturday overtime)
+//!
+//! ## Day Attribution
+//!
+//! Overtime is paid at the rates of the day it is worked on. A shift's
+//! ordinary hours are taken from its first hours, so its overtime falls in its
+//! last; when those cross midnight, each day's portion of the overtime is
+//! priced on its own. Only the Saturday and Sunday portions are paid here, so
+//! a Friday night shift running into Saturday pays its Saturday overtime at
+//! weekend rates, while a Sunday night shift running into Monday pays its
+//! Monday overtime at the weekday tiers. Under shift commencement the whole
+//! shift is one portion, paid as the day it commences on.
+//!
+//! `overtime.casual_ot_base` controls whether casual overtime replacing the
+//! penalty is paid on the casual-loaded rate (`loaded`) or the base rate
+//! (`base`). When stacking, the casual loading is carried by the casual
+//! penalty rate, so the overtime component never includes it. The casual
+//! penalty rate stacked on is composed as configured by
+//! `penalties.casual_composition`.
 
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::calculation::DayType;
-use crate::config::AwardConfig;
-use crate::models::{AuditStep, Employee, EmploymentType, PayCategory, PayLine};
+use crate::calculation::{
+    CasualOvertimeComposition, DayType, casual_loading_multiplier, casual_overtime_audit_step,
+    compose_casual_overtime, compose_casual_penalty,
+};
+use crate::config::{
+    AwardConfig, CasualOvertimeBase, CasualPenaltyComposition, OvertimeRates, OvertimeStacking,
+    PenaltyRates,
+};
+use crate::models::{AuditStep, Employee, EmploymentType, OvertimeTier, PayCategory, PayLine};
+
+use super::rules::RuleInfo;
+
+/// The weekend overtime rules, flat and tiered, for the rule catalogue. Each
+/// further configured tier is recorded as `weekend_overtime_tier_<n>`.
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        rule_id: "weekend_overtime",
+        rule_name: "Weekend Overtime",
+        clause_ref: "25.1(a)(i)(B)",
+    },
+    RuleInfo {
+        rule_id: "weekend_overtime_tier_1",
+        rule_name: "Weekend Overtime Tier 1",
+        clause_ref: "25.1(a)(i)(B)",
+    },
+    RuleInfo {
+        rule_id: "weekend_overtime_tier_2",
+        rule_name: "Weekend Overtime Tier 2",
+        clause_ref: "25.1(a)(i)(B)",
+    },
+    RuleInfo {
+        rule_id: "weekend_overtime_stacking",
+        rule_name: "Weekend Overtime Stacking",
+        clause_ref: "25.1(a)(i)(B)",
+    },
+];
 
 /// The result of weekend overtime calculation.
 ///
-/// Contains the pay line for weekend overtime and the audit step
+/// Contains the pay lines for weekend overtime and the audit steps
 /// documenting the calculation.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WeekendOvertimeResult {
-    /// Pay line for weekend overtime (may be None if no overtime hours).
-    pub pay_line: Option<PayLine>,
-    /// Audit step recording the calculation.
-    pub audit_step: Option<AuditStep>,
+    /// Pay lines for weekend overtime (empty if no overtime hours, one line when
+    /// flat, one per tier the hours reach when tiered).
+    pub pay_lines: Vec<PayLine>,
+    /// The tier behind each pay line, in the same order; flat overtime is a
+    /// single tier from the first overtime hour.
+    pub tiers: Vec<OvertimeTier>,
+    /// Audit steps recording each calculation.
+    pub audit_steps: Vec<AuditStep>,
 }
 
 /// Calculates weekend overtime pay at flat 200% (or 250% for casuals).
 ///
 /// Weekend overtime is calculated differently from weekday overtime:
 /// - **All hours** are at 200% for non-casuals, 250% for casuals
-/// - There is NO tiered rate (unlike weekday overtime)
+/// - There is NO tiered rate (unlike weekday overtime) unless the award
+///   configuration enables `tiered` weekend overtime
 ///
 /// # Arguments
 ///
@@ -42,16 +109,23 @@ pub struct WeekendOvertimeResult {
 /// * `base_rate` - The base hourly rate (before any loading)
 /// * `employee` - The employee receiving overtime pay
 /// * `config` - The award configuration containing overtime multipliers
-/// * `day_type` - The type of weekend day (Saturday or Sunday)
+/// * `day_type` - The type of weekend day (Saturday or Sunday); a
+///   [`DayType::Weekday`] portion of overtime is paid by
+///   [`calculate_weekday_overtime`](crate::calculation::calculate_weekday_overtime),
+///   so it returns an empty result here
 /// * `date` - The date of the shift for pay line records
 /// * `shift_id` - The shift ID for pay line records
 /// * `step_number` - The step number for audit trail sequencing
+/// * `include_audit` - Whether to build the audit steps
 ///
 /// # Returns
 ///
 /// A [`WeekendOvertimeResult`] containing:
-/// - `pay_line`: Optional pay line (None if overtime_hours <= 0)
-/// - `audit_step`: Optional audit step (None if overtime_hours <= 0)
+/// - `pay_lines`: 0 lines if overtime_hours <= 0, otherwise 1 (flat) or one
+///   per tier reached (tiered)
+/// - `audit_steps`: One audit step per pay line, then (for casuals) a step
+///   recording the casual overtime composition, followed by a step recording
+///   the overtime stacking policy and resulting multiplier
 ///
 /// # Award Reference
 ///
@@ -77,6 +151,9 @@ pub struct WeekendOvertimeResult {
 ///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
 ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
 ///     base_hourly_rate: None,
+///     max_ordinary_hours_per_day: None,
+///     ordinary_days: vec![],
+///     rostered_days_off: vec![],
 ///     tags: vec![],
 /// };
 /// let date = NaiveDate::from_ymd_opt(2026, 1, 17).unwrap(); // Saturday
@@ -90,10 +167,11 @@ pub struct WeekendOvertimeResult {
 ///     date,
 ///     "shift_001",
 ///     1,
+///     true,
 /// );
 ///
-/// assert!(result.pay_line.is_some());
-/// let pay_line = result.pay_line.unwrap();
+/// assert_eq!(result.pay_lines.len(), 1);
+/// let pay_line = &result.pay_lines[0];
 /// assert_eq!(pay_line.category, PayCategory::Overtime200);
 /// // 2h × ($28.54 × 2.0) = 2h × $57.08 = $114.16
 /// assert_eq!(pay_line.amount, Decimal::from_str("114.16").unwrap());
@@ -108,117 +186,449 @@ pub fn calculate_weekend_overtime(
     date: NaiveDate,
     shift_id: &str,
     step_number: u32,
+    include_audit: bool,
 ) -> WeekendOvertimeResult {
-    // If no overtime, return empty result
-    if overtime_hours <= Decimal::ZERO {
+    let mut pay_lines = Vec::new();
+    let mut tiers = Vec::new();
+    let mut audit_steps = Vec::new();
+
+    // Weekday overtime is tiered by calculate_weekday_overtime; a weekday
+    // portion of a shift's overtime is never priced at weekend rates
+    if overtime_hours <= Decimal::ZERO || day_type == DayType::Weekday {
         return WeekendOvertimeResult {
-            pay_line: None,
-            audit_step: None,
+            pay_lines,
+            tiers,
+            audit_steps,
         };
     }
 
     // Get weekend overtime rates from config
     let weekend_overtime = &config.penalties().overtime.weekend;
 
-    // Determine multiplier based on day type and employment type
-    let multiplier = match day_type {
-        DayType::Saturday => match employee.employment_type {
-            EmploymentType::FullTime => weekend_overtime.saturday.full_time,
-            EmploymentType::PartTime => weekend_overtime.saturday.part_time,
-            EmploymentType::Casual => weekend_overtime.saturday.casual,
-        },
-        DayType::Sunday => match employee.employment_type {
-            EmploymentType::FullTime => weekend_overtime.sunday.full_time,
-            EmploymentType::PartTime => weekend_overtime.sunday.part_time,
-            EmploymentType::Casual => weekend_overtime.sunday.casual,
-        },
-        DayType::Weekday => {
-            // Weekend overtime should not be called for weekdays
-            // but handle gracefully by returning empty result
-            return WeekendOvertimeResult {
-                pay_line: None,
-                audit_step: None,
-            };
-        }
+    let flat_rates = match day_type {
+        DayType::Saturday => &weekend_overtime.saturday,
+        _ => &weekend_overtime.sunday,
     };
 
-    let employment_type_str = match employee.employment_type {
-        EmploymentType::FullTime => "full_time",
-        EmploymentType::PartTime => "part_time",
-        EmploymentType::Casual => "casual",
+    let penalties = &config.penalties().penalties;
+    let penalty_rates = match day_type {
+        DayType::Saturday => penalties.saturday_for(&employee.classification_code),
+        _ => penalties.sunday_for(&employee.classification_code),
     };
-
-    let day_type_str = match day_type {
-        DayType::Saturday => "Saturday",
-        DayType::Sunday => "Sunday",
-        DayType::Weekday => "Weekday",
+    let stacking = Stacking {
+        policy: weekend_overtime.overtime_stacking,
+        penalty_multiplier: penalty_multiplier(
+            &penalty_rates,
+            penalties.casual_composition,
+            employee,
+        ),
+        casual_ot_base: config.penalties().overtime.casual_ot_base,
     };
+    if !weekend_overtime.tiered {
+        // Flat weekend overtime: all hours at the same rate
+        let multiplier = stacking.apply(overtime_multiplier(flat_rates, employee), employee);
+        let (pay_line, tier, audit_step) = weekend_overtime_line(
+            WeekendOvertimeLine {
+                tier: 1,
+                threshold_hours: Decimal::ZERO,
+                hours: overtime_hours,
+                multiplier,
+                category: PayCategory::Overtime200,
+                rule_id: "weekend_overtime".to_string(),
+                rule_name: format!("{} Overtime", day_type),
+                description: format!("{} overtime", day_type),
+                casual_loading_excluded: stacking.excludes_casual_loading(),
+            },
+            base_rate,
+            employee,
+            &weekend_overtime.clause,
+            day_type,
+            date,
+            shift_id,
+            step_number,
+            include_audit,
+        );
+        pay_lines.push(pay_line);
+        tiers.push(tier);
+        audit_steps.extend(audit_step);
+        if include_audit {
+            let mut step_number = step_number + 1;
+            if employee.is_casual() {
+                audit_steps.push(casual_overtime_audit_step(
+                    stacking.casual_ot_base(),
+                    &[("weekend", stacking.compose(flat_rates.casual))],
+                    &weekend_overtime.clause,
+                    step_number,
+                ));
+                step_number += 1;
+            }
+            audit_steps.push(stacking_audit_step(
+                &stacking,
+                overtime_multiplier(flat_rates, employee),
+                employee,
+                &weekend_overtime.clause,
+                day_type,
+                step_number,
+            ));
+        }
+        return WeekendOvertimeResult {
+            pay_lines,
+            tiers,
+            audit_steps,
+        };
+    }
 
-    let rate = base_rate * multiplier;
-    let amount = overtime_hours * rate;
-
-    let reasoning = if employee.is_casual() {
-        format!(
-            "{} overtime: {} hours at {}% ({}% × 1.25 casual loading): {} hours × ${} = ${}",
-            day_type_str,
-            overtime_hours.normalize(),
-            (multiplier * Decimal::from(100)).normalize(),
-            Decimal::from(200),
-            overtime_hours.normalize(),
-            rate.normalize(),
-            amount.normalize()
-        )
-    } else {
-        format!(
-            "{} overtime: {} hours at {}%: {} hours × ${} = ${}",
-            day_type_str,
-            overtime_hours.normalize(),
-            (multiplier * Decimal::from(100)).normalize(),
-            overtime_hours.normalize(),
-            rate.normalize(),
-            amount.normalize()
-        )
+    // Tiered weekend overtime: each tier from its threshold until the next,
+    // the last covering all remaining hours, as weekday overtime is tiered
+    let configured_tiers = &weekend_overtime.tiers;
+    let mut step_number = step_number;
+    let mut casual_tiers = Vec::new();
+    let mut last_tier_rates = None;
+    for (index, configured) in configured_tiers.iter().enumerate() {
+        let tier_number = index + 1;
+        let next_threshold = configured_tiers
+            .get(index + 1)
+            .map(|next| next.threshold_hours);
+        let tier_end = next_threshold.map_or(overtime_hours, |next| overtime_hours.min(next));
+        let tier_hours = tier_end - configured.threshold_hours;
+        if tier_hours <= Decimal::ZERO {
+            continue;
+        }
+
+        let tier_rates = match day_type {
+            DayType::Saturday => &configured.saturday,
+            _ => &configured.sunday,
+        };
+        let description = match (index, next_threshold) {
+            (0, Some(next)) => format!("First {} hours of {} overtime", next.normalize(), day_type),
+            (0, None) => format!("{} overtime", day_type),
+            (_, Some(next)) => format!(
+                "{} overtime from {} to {} hours",
+                day_type,
+                configured.threshold_hours.normalize(),
+                next.normalize()
+            ),
+            (_, None) => format!(
+                "{} overtime after first {} hours",
+                day_type,
+                configured.threshold_hours.normalize()
+            ),
+        };
+        let (pay_line, tier, audit_step) = weekend_overtime_line(
+            WeekendOvertimeLine {
+                tier: tier_number as u32,
+                threshold_hours: configured.threshold_hours,
+                hours: tier_hours,
+                multiplier: stacking.apply(overtime_multiplier(tier_rates, employee), employee),
+                category: configured.category,
+                rule_id: format!("weekend_overtime_tier_{}", tier_number),
+                rule_name: format!("{} Overtime Tier {}", day_type, tier_number),
+                description,
+                casual_loading_excluded: stacking.excludes_casual_loading(),
+            },
+            base_rate,
+            employee,
+            &weekend_overtime.clause,
+            day_type,
+            date,
+            shift_id,
+            step_number,
+            include_audit,
+        );
+        pay_lines.push(pay_line);
+        tiers.push(tier);
+        audit_steps.extend(audit_step);
+        casual_tiers.push((
+            format!("tier_{}", tier_number),
+            stacking.compose(tier_rates.casual),
+        ));
+        last_tier_rates = Some(tier_rates);
+        step_number += 1;
+    }
+
+    if include_audit && employee.is_casual() {
+        audit_steps.push(casual_overtime_audit_step(
+            stacking.casual_ot_base(),
+            &casual_tier_refs(&casual_tiers),
+            &weekend_overtime.clause,
+            step_number,
+        ));
+        step_number += 1;
+    }
+
+    // The stacking step records the multiplier of the last tier reached
+    if let Some(tier_rates) = last_tier_rates.filter(|_| include_audit) {
+        audit_steps.push(stacking_audit_step(
+            &stacking,
+            overtime_multiplier(tier_rates, employee),
+            employee,
+            &weekend_overtime.clause,
+            day_type,
+            step_number,
+        ));
+    }
+
+    WeekendOvertimeResult {
+        pay_lines,
+        tiers,
+        audit_steps,
+    }
+}
+
+/// Borrows the casual overtime tiers as `casual_overtime_audit_step` takes them.
+fn casual_tier_refs(
+    casual_tiers: &[(String, CasualOvertimeComposition)],
+) -> Vec<(&str, CasualOvertimeComposition)> {
+    casual_tiers
+        .iter()
+        .map(|(tier, composition)| (tier.as_str(), *composition))
+        .collect()
+}
+
+/// Returns the overtime multiplier for the employee's employment type.
+fn overtime_multiplier(rates: &OvertimeRates, employee: &Employee) -> Decimal {
+    match employee.employment_type {
+        EmploymentType::FullTime => rates.full_time,
+        EmploymentType::PartTime => rates.part_time,
+        EmploymentType::Casual => rates.casual,
+    }
+}
+
+/// Returns the weekend penalty multiplier for the employee's employment type.
+fn penalty_multiplier(
+    rates: &PenaltyRates,
+    casual_composition: CasualPenaltyComposition,
+    employee: &Employee,
+) -> Decimal {
+    match employee.employment_type {
+        EmploymentType::FullTime => rates.full_time,
+        EmploymentType::PartTime => rates.part_time,
+        EmploymentType::Casual => {
+            compose_casual_penalty(rates.casual, casual_composition).multiplier
+        }
+    }
+}
+
+/// Returns the configuration name of a stacking policy.
+fn stacking_policy_name(policy: OvertimeStacking) -> &'static str {
+    match policy {
+        OvertimeStacking::Replace => "replace",
+        OvertimeStacking::StackOnPenalty => "stack_on_penalty",
+    }
+}
+
+/// The overtime stacking policy in effect for a weekend day.
+struct Stacking {
+    policy: OvertimeStacking,
+    penalty_multiplier: Decimal,
+    casual_ot_base: CasualOvertimeBase,
+}
+
+impl Stacking {
+    /// Applies the stacking policy to a configured overtime multiplier.
+    ///
+    /// When replacing the penalty, casual multipliers are composed according
+    /// to the casual overtime base. When stacking, casual overtime multipliers
+    /// already include the casual loading, as does the casual penalty, so the
+    /// loading is removed from the overtime multiplier to avoid applying it twice.
+    fn apply(&self, overtime_multiplier: Decimal, employee: &Employee) -> Decimal {
+        match self.policy {
+            OvertimeStacking::Replace if employee.is_casual() => {
+                self.compose(overtime_multiplier).multiplier
+            }
+            OvertimeStacking::Replace => overtime_multiplier,
+            OvertimeStacking::StackOnPenalty => {
+                let overtime_multiplier = if employee.is_casual() {
+                    overtime_multiplier / casual_loading_multiplier()
+                } else {
+                    overtime_multiplier
+                };
+                self.penalty_multiplier * overtime_multiplier
+            }
+        }
+    }
+
+    /// Returns the casual overtime base in effect under this policy.
+    ///
+    /// When stacking, the casual loading is carried by the casual penalty, so
+    /// the overtime component is always taken on the base rate.
+    fn casual_ot_base(&self) -> CasualOvertimeBase {
+        match self.policy {
+            OvertimeStacking::Replace => self.casual_ot_base,
+            OvertimeStacking::StackOnPenalty => CasualOvertimeBase::Base,
+        }
+    }
+
+    /// Composes a configured casual overtime multiplier as it is paid under this policy.
+    fn compose(&self, casual_multiplier: Decimal) -> CasualOvertimeComposition {
+        compose_casual_overtime(casual_multiplier, self.casual_ot_base())
+    }
+
+    /// Returns true when casual overtime lines exclude the casual loading.
+    fn excludes_casual_loading(&self) -> bool {
+        self.policy == OvertimeStacking::Replace && self.casual_ot_base == CasualOvertimeBase::Base
+    }
+}
+
+/// Builds the audit step recording the stacking policy and resulting multiplier.
+fn stacking_audit_step(
+    stacking: &Stacking,
+    overtime_multiplier: Decimal,
+    employee: &Employee,
+    clause: &str,
+    day_type: DayType,
+    step_number: u32,
+) -> AuditStep {
+    let multiplier = stacking.apply(overtime_multiplier, employee);
+    let percentage = |m: Decimal| (m * Decimal::from(100)).round_dp(2).normalize();
+
+    let reasoning = match stacking.policy {
+        OvertimeStacking::Replace => format!(
+            "{} overtime replaces the {} penalty: overtime paid at {}% of the base rate",
+            day_type,
+            day_type,
+            percentage(multiplier)
+        ),
+        OvertimeStacking::StackOnPenalty => format!(
+            "{} overtime stacks on the {} penalty: {}% penalty × {}% overtime = {}% of the base rate",
+            day_type,
+            day_type,
+            percentage(stacking.penalty_multiplier),
+            percentage(multiplier / stacking.penalty_multiplier),
+            percentage(multiplier)
+        ),
     };
 
-    let audit_step = AuditStep {
+    AuditStep {
         step_number,
-        rule_id: "weekend_overtime".to_string(),
-        rule_name: format!("{} Overtime", day_type_str),
-        clause_ref: weekend_overtime.clause.clone(),
+        rule_id: "weekend_overtime_stacking".to_string(),
+        rule_name: format!("{} Overtime Stacking", day_type),
+        clause_ref: clause.to_string(),
         input: serde_json::json!({
-            "hours": overtime_hours.normalize().to_string(),
-            "base_rate": base_rate.normalize().to_string(),
-            "employment_type": employment_type_str,
-            "day_type": day_type_str
+            "policy": stacking_policy_name(stacking.policy),
+            "employment_type": employee.employment_type.as_str(),
+            "day_type": day_type.to_string(),
+            "overtime_multiplier": overtime_multiplier.normalize().to_string(),
+            "penalty_multiplier": stacking.penalty_multiplier.normalize().to_string()
         }),
         output: serde_json::json!({
-            "multiplier": multiplier.normalize().to_string(),
-            "rate": rate.normalize().to_string(),
-            "amount": amount.normalize().to_string()
+            "multiplier": multiplier.normalize().to_string()
         }),
         reasoning,
-    };
+    }
+}
+
+/// Describes a single weekend overtime pay line to be generated.
+struct WeekendOvertimeLine {
+    tier: u32,
+    threshold_hours: Decimal,
+    hours: Decimal,
+    multiplier: Decimal,
+    category: PayCategory,
+    rule_id: String,
+    rule_name: String,
+    description: String,
+    casual_loading_excluded: bool,
+}
+
+/// Builds the pay line, its tier and, when `include_audit` is set, the audit
+/// step for one weekend overtime line.
+#[allow(clippy::too_many_arguments)]
+fn weekend_overtime_line(
+    line: WeekendOvertimeLine,
+    base_rate: Decimal,
+    employee: &Employee,
+    clause: &str,
+    day_type: DayType,
+    date: NaiveDate,
+    shift_id: &str,
+    step_number: u32,
+    include_audit: bool,
+) -> (PayLine, OvertimeTier, Option<AuditStep>) {
+    let employment_type_str = employee.employment_type.as_str();
+
+    let rate = base_rate * line.multiplier;
+    let amount = line.hours * rate;
+    let percentage = (line.multiplier * Decimal::from(100)).normalize();
+
+    let audit_step = include_audit.then(|| {
+        let reasoning = if employee.is_casual() && line.casual_loading_excluded {
+            format!(
+                "{}: {} hours at {}% (casual loading excluded): {} hours × ${} = ${}",
+                line.description,
+                line.hours.normalize(),
+                percentage,
+                line.hours.normalize(),
+                rate.normalize(),
+                amount.normalize()
+            )
+        } else if employee.is_casual() {
+            let casual_loading = Decimal::new(125, 2);
+            format!(
+                "{}: {} hours at {}% ({}% × 1.25 casual loading): {} hours × ${} = ${}",
+                line.description,
+                line.hours.normalize(),
+                percentage,
+                (line.multiplier / casual_loading * Decimal::from(100))
+                    .round_dp(2)
+                    .normalize(),
+                line.hours.normalize(),
+                rate.normalize(),
+                amount.normalize()
+            )
+        } else {
+            format!(
+                "{}: {} hours at {}%: {} hours × ${} = ${}",
+                line.description,
+                line.hours.normalize(),
+                percentage,
+                line.hours.normalize(),
+                rate.normalize(),
+                amount.normalize()
+            )
+        };
+
+        AuditStep {
+            step_number,
+            rule_id: line.rule_id,
+            rule_name: line.rule_name,
+            clause_ref: clause.to_string(),
+            input: serde_json::json!({
+                "hours": line.hours.normalize().to_string(),
+                "base_rate": base_rate.normalize().to_string(),
+                "employment_type": employment_type_str,
+                "day_type": day_type.to_string()
+            }),
+            output: serde_json::json!({
+                "multiplier": line.multiplier.normalize().to_string(),
+                "rate": rate.normalize().to_string(),
+                "amount": amount.normalize().to_string()
+            }),
+            reasoning,
+        }
+    });
 
     let pay_line = PayLine {
         date,
         shift_id: shift_id.to_string(),
-        category: PayCategory::Overtime200,
-        hours: overtime_hours,
+        category: line.category,
+        hours: line.hours,
         rate,
         amount,
-        clause_ref: weekend_overtime.clause.clone(),
+        clause_ref: clause.to_string(),
+        reference: None,
+        cost_centre: None,
     };
+    let tier =
+        OvertimeTier::from_pay_line(&pay_line, line.tier, line.threshold_hours, line.multiplier);
 
-    WeekendOvertimeResult {
-        pay_line: Some(pay_line),
-        audit_step: Some(audit_step),
-    }
+    (pay_line, tier, audit_step)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::ConfigLoader;
+    use crate::config::{ConfigLoader, OvertimeRates, WeekendOvertimeTier};
     use std::str::FromStr;
 
     fn dec(s: &str) -> Decimal {
@@ -233,6 +643,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -252,6 +665,53 @@ mod tests {
             .clone()
     }
 
+    /// Builds a weekend overtime tier paying the same rates on both days.
+    fn weekend_tier(
+        threshold_hours: &str,
+        category: PayCategory,
+        rates: OvertimeRates,
+    ) -> WeekendOvertimeTier {
+        WeekendOvertimeTier {
+            threshold_hours: dec(threshold_hours),
+            category,
+            saturday: rates.clone(),
+            sunday: rates,
+        }
+    }
+
+    /// Loads the MA000018 config with the given weekend overtime tiers.
+    fn load_config_with_tiers(weekend_tiers: Vec<WeekendOvertimeTier>) -> AwardConfig {
+        let config = load_config();
+        let mut penalties = config.penalties().clone();
+        penalties.overtime.weekend.tiered = true;
+        penalties.overtime.weekend.tiers = weekend_tiers;
+        AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            config.rates().to_vec(),
+            penalties,
+        )
+    }
+
+    /// Loads the MA000018 config with weekend overtime tiered at 150% for the
+    /// first 2 hours (187.5% casual) and 200% (250% casual) thereafter.
+    fn load_tiered_config() -> AwardConfig {
+        let config = load_config();
+        let flat_rates = config.penalties().overtime.weekend.saturday.clone();
+        load_config_with_tiers(vec![
+            weekend_tier(
+                "0",
+                PayCategory::Overtime150,
+                OvertimeRates {
+                    full_time: dec("1.5"),
+                    part_time: dec("1.5"),
+                    casual: dec("1.875"),
+                },
+            ),
+            weekend_tier("2", PayCategory::Overtime200, flat_rates),
+        ])
+    }
+
     // ==========================================================================
     // SATOT-001: fulltime 10h Saturday - 2h overtime
     // Expected: Ordinary 8h @ 1.50 = 342.48, OT 2h @ 2.0 = 114.16
@@ -272,10 +732,11 @@ mod tests {
             saturday_date(),
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.pay_line.is_some());
-        let pay_line = result.pay_line.unwrap();
+        assert_eq!(result.pay_lines.len(), 1);
+        let pay_line = &result.pay_lines[0];
 
         assert_eq!(pay_line.category, PayCategory::Overtime200);
         assert_eq!(pay_line.hours, dec("2.0"));
@@ -305,10 +766,11 @@ mod tests {
             saturday_date(),
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.pay_line.is_some());
-        let pay_line = result.pay_line.unwrap();
+        assert_eq!(result.pay_lines.len(), 1);
+        let pay_line = &result.pay_lines[0];
 
         assert_eq!(pay_line.category, PayCategory::Overtime200);
         assert_eq!(pay_line.hours, dec("2.0"));
@@ -337,10 +799,11 @@ mod tests {
             sunday_date(),
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.pay_line.is_some());
-        let pay_line = result.pay_line.unwrap();
+        assert_eq!(result.pay_lines.len(), 1);
+        let pay_line = &result.pay_lines[0];
 
         assert_eq!(pay_line.category, PayCategory::Overtime200);
         assert_eq!(pay_line.hours, dec("2.0"));
@@ -370,10 +833,11 @@ mod tests {
             sunday_date(),
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.pay_line.is_some());
-        let pay_line = result.pay_line.unwrap();
+        assert_eq!(result.pay_lines.len(), 1);
+        let pay_line = &result.pay_lines[0];
 
         assert_eq!(pay_line.category, PayCategory::Overtime200);
         assert_eq!(pay_line.hours, dec("2.0"));
@@ -400,10 +864,11 @@ mod tests {
             saturday_date(),
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.pay_line.is_none());
-        assert!(result.audit_step.is_none());
+        assert!(result.pay_lines.is_empty());
+        assert!(result.audit_steps.is_empty());
     }
 
     // ==========================================================================
@@ -425,10 +890,11 @@ mod tests {
             saturday_date(),
             "shift_001",
             5,
+            true,
         );
 
-        assert!(result.audit_step.is_some());
-        let step = result.audit_step.unwrap();
+        assert_eq!(result.audit_steps.len(), 2);
+        let step = &result.audit_steps[0];
 
         assert_eq!(step.step_number, 5);
         assert_eq!(step.rule_id, "weekend_overtime");
@@ -465,11 +931,14 @@ mod tests {
             saturday_date(),
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.audit_step.is_some());
-        let step = result.audit_step.unwrap();
+        assert_eq!(result.audit_steps.len(), 3);
+        let step = &result.audit_steps[0];
         assert!(step.reasoning.contains("casual loading"));
+        assert_eq!(result.audit_steps[1].rule_id, "casual_overtime_composition");
+        assert_eq!(result.audit_steps[2].rule_id, "weekend_overtime_stacking");
     }
 
     // ==========================================================================
@@ -492,6 +961,7 @@ mod tests {
             saturday_date(),
             "shift_001",
             1,
+            true,
         );
 
         let pt_result = calculate_weekend_overtime(
@@ -503,12 +973,10 @@ mod tests {
             saturday_date(),
             "shift_001",
             1,
+            true,
         );
 
-        assert_eq!(
-            ft_result.pay_line.unwrap().rate,
-            pt_result.pay_line.unwrap().rate
-        );
+        assert_eq!(ft_result.pay_lines[0].rate, pt_result.pay_lines[0].rate);
     }
 
     // ==========================================================================
@@ -530,10 +998,11 @@ mod tests {
             custom_date,
             "my_shift_123",
             1,
+            true,
         );
 
-        assert!(result.pay_line.is_some());
-        let pay_line = result.pay_line.unwrap();
+        assert_eq!(result.pay_lines.len(), 1);
+        let pay_line = &result.pay_lines[0];
         assert_eq!(pay_line.date, custom_date);
         assert_eq!(pay_line.shift_id, "my_shift_123");
     }
@@ -557,10 +1026,11 @@ mod tests {
             sunday_date(),
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.pay_line.is_some());
-        let pay_line = result.pay_line.unwrap();
+        assert_eq!(result.pay_lines.len(), 1);
+        let pay_line = &result.pay_lines[0];
 
         assert_eq!(pay_line.hours, dec("1.5"));
         // 1.5h × $57.08 = $85.62
@@ -587,10 +1057,11 @@ mod tests {
             saturday_date(),
             "shift_001",
             1,
+            true,
         );
 
-        assert!(result.pay_line.is_some());
-        let pay_line = result.pay_line.unwrap();
+        assert_eq!(result.pay_lines.len(), 1);
+        let pay_line = &result.pay_lines[0];
 
         // All 4 hours at 200%
         assert_eq!(pay_line.hours, dec("4.0"));
@@ -617,9 +1088,386 @@ mod tests {
             NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
             "shift_001",
             1,
+            true,
+        );
+
+        assert!(result.pay_lines.is_empty());
+        assert!(result.audit_steps.is_empty());
+    }
+
+    // ==========================================================================
+    // Test: Tiered weekend overtime splits hours across two pay lines
+    // ==========================================================================
+    #[test]
+    fn test_tiered_weekend_overtime_emits_two_pay_lines() {
+        let config = load_tiered_config();
+        let employee = create_test_employee(EmploymentType::FullTime);
+
+        let result = calculate_weekend_overtime(
+            dec("3.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Saturday,
+            saturday_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines.len(), 2);
+        assert_eq!(result.audit_steps.len(), 3);
+
+        // First 2h at 150%: 2 × $42.81 = $85.62
+        assert_eq!(result.pay_lines[0].category, PayCategory::Overtime150);
+        assert_eq!(result.pay_lines[0].hours, dec("2"));
+        assert_eq!(result.pay_lines[0].rate, dec("42.81"));
+        assert_eq!(result.pay_lines[0].amount, dec("85.62"));
+
+        // Remaining 1h at 200%: 1 × $57.08 = $57.08
+        assert_eq!(result.pay_lines[1].category, PayCategory::Overtime200);
+        assert_eq!(result.pay_lines[1].hours, dec("1.0"));
+        assert_eq!(result.pay_lines[1].amount, dec("57.08"));
+
+        // The second tier starts after the first tier's 2 hours
+        assert_eq!(result.tiers.len(), 2);
+        assert_eq!(result.tiers[1].tier, 2);
+        assert_eq!(result.tiers[1].threshold_hours, dec("2"));
+        assert_eq!(result.tiers[1].multiplier, dec("2.0"));
+
+        assert_eq!(result.audit_steps[0].rule_id, "weekend_overtime_tier_1");
+        assert_eq!(result.audit_steps[0].step_number, 1);
+        assert_eq!(result.audit_steps[1].rule_id, "weekend_overtime_tier_2");
+        assert_eq!(result.audit_steps[1].step_number, 2);
+        assert_eq!(result.audit_steps[2].rule_id, "weekend_overtime_stacking");
+        assert_eq!(result.audit_steps[2].step_number, 3);
+    }
+
+    // ==========================================================================
+    // Test: Tiered weekend overtime within the first tier emits one pay line
+    // ==========================================================================
+    #[test]
+    fn test_tiered_weekend_overtime_within_first_tier() {
+        let config = load_tiered_config();
+        let employee = create_test_employee(EmploymentType::Casual);
+
+        let result = calculate_weekend_overtime(
+            dec("1.5"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Sunday,
+            sunday_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines.len(), 1);
+        assert_eq!(result.pay_lines[0].category, PayCategory::Overtime150);
+        // 28.54 × 1.875 = 53.5125
+        assert_eq!(result.pay_lines[0].rate, dec("53.5125"));
+        assert_eq!(result.audit_steps[0].rule_name, "Sunday Overtime Tier 1");
+    }
+
+    // ==========================================================================
+    // Test: Configured tiers are ignored unless tiered is enabled
+    // ==========================================================================
+    #[test]
+    fn test_tiers_ignored_when_not_tiered() {
+        let tiered = load_tiered_config();
+        let mut penalties = tiered.penalties().clone();
+        penalties.overtime.weekend.tiered = false;
+        let config = AwardConfig::new(
+            tiered.award().clone(),
+            tiered.classifications().clone(),
+            tiered.rates().to_vec(),
+            penalties,
+        );
+        let employee = create_test_employee(EmploymentType::FullTime);
+
+        let result = calculate_weekend_overtime(
+            dec("4.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Saturday,
+            saturday_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines.len(), 1);
+        assert_eq!(result.pay_lines[0].category, PayCategory::Overtime200);
+        assert_eq!(result.pay_lines[0].amount, dec("228.32"));
+    }
+
+    // ==========================================================================
+    // Test: Each configured tier is paid from its threshold under its category
+    // ==========================================================================
+    #[test]
+    fn test_three_weekend_overtime_tiers_use_their_configured_categories() {
+        let rates = |multiplier: &str| OvertimeRates {
+            full_time: dec(multiplier),
+            part_time: dec(multiplier),
+            casual: dec(multiplier),
+        };
+        let config = load_config_with_tiers(vec![
+            weekend_tier("0", PayCategory::Overtime150, rates("1.75")),
+            weekend_tier("1", PayCategory::Overtime200, rates("2.0")),
+            weekend_tier("3", PayCategory::Overtime250, rates("2.5")),
+        ]);
+        let employee = create_test_employee(EmploymentType::FullTime);
+
+        let result = calculate_weekend_overtime(
+            dec("4"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Sunday,
+            sunday_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        let lines: Vec<(PayCategory, Decimal, Decimal)> = result
+            .pay_lines
+            .iter()
+            .map(|pl| (pl.category, pl.hours, pl.rate))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (PayCategory::Overtime150, dec("1"), dec("49.945")),
+                (PayCategory::Overtime200, dec("2"), dec("57.08")),
+                (PayCategory::Overtime250, dec("1"), dec("71.35")),
+            ]
+        );
+        assert_eq!(result.tiers[2].tier, 3);
+        assert_eq!(result.tiers[2].threshold_hours, dec("3"));
+        assert_eq!(result.audit_steps[2].rule_id, "weekend_overtime_tier_3");
+        assert_eq!(
+            result.audit_steps[1].reasoning,
+            "Sunday overtime from 1 to 3 hours: 2 hours at 200%: 2 hours × $57.08 = $114.16"
+        );
+        // The stacking step records the last tier reached
+        assert_eq!(result.audit_steps[3].rule_id, "weekend_overtime_stacking");
+        assert_eq!(result.audit_steps[3].output["multiplier"], "2.5");
+    }
+
+    /// Loads the MA000018 config with the given weekend overtime stacking policy.
+    fn load_config_with_stacking(policy: OvertimeStacking) -> AwardConfig {
+        let config = load_config();
+        let mut penalties = config.penalties().clone();
+        penalties.overtime.weekend.overtime_stacking = policy;
+        AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            config.rates().to_vec(),
+            penalties,
+        )
+    }
+
+    // ==========================================================================
+    // 10h Saturday shift (2h overtime) under the replace policy
+    // Expected: OT 2h @ 200% of base = 2 × $57.08 = $114.16
+    // ==========================================================================
+    #[test]
+    fn test_replace_policy_10h_saturday() {
+        let config = load_config_with_stacking(OvertimeStacking::Replace);
+        let employee = create_test_employee(EmploymentType::FullTime);
+
+        let result = calculate_weekend_overtime(
+            dec("2.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Saturday,
+            saturday_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines.len(), 1);
+        assert_eq!(result.pay_lines[0].rate, dec("57.08"));
+        assert_eq!(result.pay_lines[0].amount, dec("114.16"));
+
+        let step = &result.audit_steps[1];
+        assert_eq!(step.step_number, 2);
+        assert_eq!(step.rule_id, "weekend_overtime_stacking");
+        assert_eq!(step.input["policy"], "replace");
+        assert_eq!(step.input["penalty_multiplier"], "1.5");
+        assert_eq!(step.output["multiplier"], "2");
+        assert!(step.reasoning.contains("replaces the Saturday penalty"));
+    }
+
+    // ==========================================================================
+    // 10h Saturday shift (2h overtime) under the stack_on_penalty policy
+    // Expected: OT 2h @ 150% × 200% = 300% of base = 2 × $85.62 = $171.24
+    // ==========================================================================
+    #[test]
+    fn test_stack_on_penalty_policy_10h_saturday() {
+        let config = load_config_with_stacking(OvertimeStacking::StackOnPenalty);
+        let employee = create_test_employee(EmploymentType::FullTime);
+
+        let result = calculate_weekend_overtime(
+            dec("2.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Saturday,
+            saturday_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines.len(), 1);
+        assert_eq!(result.pay_lines[0].rate, dec("85.62"));
+        assert_eq!(result.pay_lines[0].amount, dec("171.24"));
+        assert_eq!(result.audit_steps[0].output["multiplier"], "3");
+
+        let step = &result.audit_steps[1];
+        assert_eq!(step.input["policy"], "stack_on_penalty");
+        assert_eq!(step.output["multiplier"], "3");
+        assert_eq!(
+            step.reasoning,
+            "Saturday overtime stacks on the Saturday penalty: 150% penalty × 200% overtime = 300% of the base rate"
+        );
+    }
+
+    // ==========================================================================
+    // Stacking for casuals applies the casual loading only once
+    // Expected: 175% penalty × 200% overtime = 350% of base
+    // ==========================================================================
+    #[test]
+    fn test_stack_on_penalty_casual_loading_not_doubled() {
+        let config = load_config_with_stacking(OvertimeStacking::StackOnPenalty);
+        let employee = create_test_employee(EmploymentType::Casual);
+
+        let result = calculate_weekend_overtime(
+            dec("2.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Saturday,
+            saturday_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        // 28.54 × 3.5 = 99.89
+        assert_eq!(result.pay_lines[0].rate, dec("99.89"));
+        assert_eq!(result.audit_steps[2].output["multiplier"], "3.5");
+    }
+
+    /// Loads the MA000018 config with the given casual overtime base.
+    fn load_config_with_casual_ot_base(casual_ot_base: CasualOvertimeBase) -> AwardConfig {
+        let config = load_config();
+        let mut penalties = config.penalties().clone();
+        penalties.overtime.casual_ot_base = casual_ot_base;
+        AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            config.rates().to_vec(),
+            penalties,
+        )
+    }
+
+    // ==========================================================================
+    // Casual 2h Saturday overtime on the loaded rate
+    // Expected: 200% × 1.25 = 250% of base = 2 × $71.35 = $142.70
+    // ==========================================================================
+    #[test]
+    fn test_casual_ot_base_loaded_pays_on_loaded_rate() {
+        let config = load_config_with_casual_ot_base(CasualOvertimeBase::Loaded);
+        let employee = create_test_employee(EmploymentType::Casual);
+
+        let result = calculate_weekend_overtime(
+            dec("2.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Saturday,
+            saturday_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines[0].rate, dec("71.35"));
+        assert_eq!(result.pay_lines[0].amount, dec("142.70"));
+
+        let step = &result.audit_steps[1];
+        assert_eq!(step.step_number, 2);
+        assert_eq!(step.input["casual_ot_base"], "loaded");
+        assert_eq!(step.output["tiers"][0]["overtime_multiplier"], "2");
+        assert_eq!(step.output["tiers"][0]["casual_loading"], "1.25");
+        assert_eq!(step.output["tiers"][0]["multiplier"], "2.5");
+        assert_eq!(result.audit_steps[2].step_number, 3);
+    }
+
+    // ==========================================================================
+    // Casual 2h Saturday overtime on the base rate
+    // Expected: 200% of base (loading excluded) = 2 × $57.08 = $114.16
+    // ==========================================================================
+    #[test]
+    fn test_casual_ot_base_base_excludes_loading() {
+        let config = load_config_with_casual_ot_base(CasualOvertimeBase::Base);
+        let employee = create_test_employee(EmploymentType::Casual);
+
+        let result = calculate_weekend_overtime(
+            dec("2.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Saturday,
+            saturday_date(),
+            "shift_001",
+            1,
+            true,
+        );
+
+        assert_eq!(result.pay_lines[0].rate, dec("57.08"));
+        assert_eq!(result.pay_lines[0].amount, dec("114.16"));
+        assert!(
+            result.audit_steps[0]
+                .reasoning
+                .contains("casual loading excluded")
+        );
+
+        let step = &result.audit_steps[1];
+        assert_eq!(step.input["casual_ot_base"], "base");
+        assert_eq!(step.output["tiers"][0]["casual_loading"], "1");
+        assert_eq!(step.output["tiers"][0]["multiplier"], "2");
+        assert_eq!(result.audit_steps[2].output["multiplier"], "2");
+    }
+
+    // ==========================================================================
+    // The casual overtime base does not affect non-casuals
+    // ==========================================================================
+    #[test]
+    fn test_casual_ot_base_has_no_effect_for_fulltime() {
+        let config = load_config_with_casual_ot_base(CasualOvertimeBase::Base);
+        let employee = create_test_employee(EmploymentType::FullTime);
+
+        let result = calculate_weekend_overtime(
+            dec("2.0"),
+            dec("28.54"),
+            &employee,
+            &config,
+            DayType::Sunday,
+            sunday_date(),
+            "shift_001",
+            1,
+            true,
         );
 
-        assert!(result.pay_line.is_none());
-        assert!(result.audit_step.is_none());
+        assert_eq!(result.pay_lines[0].amount, dec("114.16"));
+        assert_eq!(result.audit_steps.len(), 2);
     }
 }
@@ -11,9 +11,38 @@ use std::path::Path;
 use crate::error::{EngineError, EngineResult};
 use crate::models::EmploymentType;
 
+use super::self_test::{SelfTestReport, self_test};
 use super::types::{
-    AwardConfig, AwardMetadata, Classification, ClassificationsConfig, PenaltyConfig, RateConfig,
+    AwardConfig, AwardMetadata, Classification, ClassificationsConfig, EarningCodes,
+    HolidayCalendar, PenaltyConfig, QualificationAllowanceRate, RateConfig,
 };
+use super::validation::{validate_penalty_config, validate_rate_config};
+
+/// The MA000018 configuration files baked into the crate, as (name, contents) pairs.
+const EMBEDDED_MA000018_AWARD: (&str, &str) = (
+    "award.yaml",
+    include_str!("../../config/ma000018/award.yaml"),
+);
+const EMBEDDED_MA000018_CLASSIFICATIONS: (&str, &str) = (
+    "classifications.yaml",
+    include_str!("../../config/ma000018/classifications.yaml"),
+);
+const EMBEDDED_MA000018_PENALTIES: (&str, &str) = (
+    "penalties.yaml",
+    include_str!("../../config/ma000018/penalties.yaml"),
+);
+const EMBEDDED_MA000018_HOLIDAYS: (&str, &str) = (
+    "holidays.yaml",
+    include_str!("../../config/ma000018/holidays.yaml"),
+);
+const EMBEDDED_MA000018_EARNING_CODES: (&str, &str) = (
+    "earning_codes.yaml",
+    include_str!("../../config/ma000018/earning_codes.yaml"),
+);
+const EMBEDDED_MA000018_RATES: &[(&str, &str)] = &[(
+    "rates/2025-07-01.yaml",
+    include_str!("../../config/ma000018/rates/2025-07-01.yaml"),
+)];
 
 /// Loads and provides access to award configuration.
 ///
@@ -28,6 +57,8 @@ use super::types::{
 /// ├── award.yaml          # Award metadata
 /// ├── classifications.yaml # Employee classifications
 /// ├── penalties.yaml       # Penalty and overtime rates
+/// ├── holidays.yaml        # Public holiday calendar (optional)
+/// ├── earning_codes.yaml   # Payroll earning codes (optional)
 /// └── rates/
 ///     └── 2025-07-01.yaml  # Rates effective from this date
 /// ```
@@ -52,6 +83,8 @@ use super::types::{
 #[derive(Debug, Clone)]
 pub struct ConfigLoader {
     config: AwardConfig,
+    holidays: HolidayCalendar,
+    earning_codes: EarningCodes,
 }
 
 impl ConfigLoader {
@@ -67,6 +100,8 @@ impl ConfigLoader {
     /// - Any required file is missing
     /// - Any file contains invalid YAML
     /// - Any required field is missing from the configuration
+    /// - A classification rate is not positive, an allowance amount is
+    ///   negative, or a penalty or overtime multiplier is below 1.0
     ///
     /// # Example
     ///
@@ -91,11 +126,28 @@ impl ConfigLoader {
         // Load penalties.yaml
         let penalties_path = path.join("penalties.yaml");
         let penalties = Self::load_yaml::<PenaltyConfig>(&penalties_path)?;
+        validate_penalty_config(&penalties, &penalties_path.display().to_string())?;
 
         // Load all rate files from the rates directory
         let rates_dir = path.join("rates");
         let rates = Self::load_rates(&rates_dir)?;
 
+        // Load holidays.yaml, if the award has a holiday calendar
+        let holidays_path = path.join("holidays.yaml");
+        let holidays = if holidays_path.exists() {
+            Self::load_yaml::<HolidayCalendar>(&holidays_path)?
+        } else {
+            HolidayCalendar::default()
+        };
+
+        // Load earning_codes.yaml, if results are exported to a payroll system
+        let earning_codes_path = path.join("earning_codes.yaml");
+        let earning_codes = if earning_codes_path.exists() {
+            Self::load_yaml::<EarningCodes>(&earning_codes_path)?
+        } else {
+            EarningCodes::default()
+        };
+
         let config = AwardConfig::new(
             metadata,
             classifications_config.classifications,
@@ -103,7 +155,62 @@ impl ConfigLoader {
             penalties,
         );
 
-        Ok(Self { config })
+        Ok(Self {
+            config,
+            holidays,
+            earning_codes,
+        })
+    }
+
+    /// Loads the MA000018 configuration embedded in the crate.
+    ///
+    /// The embedded configuration is a copy of `config/ma000018` taken at
+    /// build time, so the engine can be used without a config directory
+    /// (e.g. as a library dependency or in serverless environments).
+    /// File-based loading with [`ConfigLoader::load`] remains the primary
+    /// way to configure the engine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::config::ConfigLoader;
+    ///
+    /// let loader = ConfigLoader::load_embedded()?;
+    /// assert_eq!(loader.award().code, "MA000018");
+    /// # Ok::<(), award_engine::error::EngineError>(())
+    /// ```
+    pub fn load_embedded() -> EngineResult<Self> {
+        let metadata = Self::parse_embedded::<AwardMetadata>(EMBEDDED_MA000018_AWARD)?;
+        let classifications_config =
+            Self::parse_embedded::<ClassificationsConfig>(EMBEDDED_MA000018_CLASSIFICATIONS)?;
+        let penalties = Self::parse_embedded::<PenaltyConfig>(EMBEDDED_MA000018_PENALTIES)?;
+        validate_penalty_config(
+            &penalties,
+            &Self::embedded_path(EMBEDDED_MA000018_PENALTIES.0),
+        )?;
+        let rates = EMBEDDED_MA000018_RATES
+            .iter()
+            .map(|file| {
+                let rate_config = Self::parse_embedded::<RateConfig>(*file)?;
+                validate_rate_config(&rate_config, &Self::embedded_path(file.0))?;
+                Ok(rate_config)
+            })
+            .collect::<EngineResult<Vec<_>>>()?;
+        let holidays = Self::parse_embedded::<HolidayCalendar>(EMBEDDED_MA000018_HOLIDAYS)?;
+        let earning_codes = Self::parse_embedded::<EarningCodes>(EMBEDDED_MA000018_EARNING_CODES)?;
+
+        let config = AwardConfig::new(
+            metadata,
+            classifications_config.classifications,
+            rates,
+            penalties,
+        );
+
+        Ok(Self {
+            config,
+            holidays,
+            earning_codes,
+        })
     }
 
     /// Loads and parses a YAML file.
@@ -114,9 +221,34 @@ impl ConfigLoader {
             path: path_str.clone(),
         })?;
 
-        serde_yaml::from_str(&content).map_err(|e| EngineError::ConfigParseError {
-            path: path_str,
-            message: e.to_string(),
+        Self::parse_yaml(&content, path_str)
+    }
+
+    /// Parses an embedded YAML file.
+    fn parse_embedded<T: serde::de::DeserializeOwned>(
+        (name, content): (&str, &str),
+    ) -> EngineResult<T> {
+        Self::parse_yaml(content, Self::embedded_path(name))
+    }
+
+    /// Returns the path reported in errors for an embedded file.
+    fn embedded_path(name: &str) -> String {
+        format!("embedded:ma000018/{}", name)
+    }
+
+    /// Parses YAML content, reporting errors against the given source path.
+    ///
+    /// The error names the line and column of the problem when the YAML
+    /// parser reports one.
+    fn parse_yaml<T: serde::de::DeserializeOwned>(content: &str, path: String) -> EngineResult<T> {
+        serde_yaml::from_str(content).map_err(|e| {
+            let location = e.location();
+            EngineError::ConfigParseError {
+                path,
+                message: e.to_string(),
+                line: location.as_ref().map(|l| l.line()),
+                column: location.as_ref().map(|l| l.column()),
+            }
         })
     }
 
@@ -144,6 +276,7 @@ impl ConfigLoader {
             let path = entry.path();
             if path.extension().is_some_and(|ext| ext == "yaml") {
                 let rate_config = Self::load_yaml::<RateConfig>(&path)?;
+                validate_rate_config(&rate_config, &path.display().to_string())?;
                 rates.push(rate_config);
             }
         }
@@ -157,11 +290,49 @@ impl ConfigLoader {
         Ok(rates)
     }
 
+    /// Creates a loader around an already-built award configuration.
+    ///
+    /// The loader has an empty holiday calendar and no earning codes.
+    pub fn from_config(config: AwardConfig) -> Self {
+        Self {
+            config,
+            holidays: HolidayCalendar::default(),
+            earning_codes: EarningCodes::default(),
+        }
+    }
+
+    /// Replaces the earning codes results are exported with.
+    pub fn with_earning_codes(mut self, earning_codes: EarningCodes) -> Self {
+        self.earning_codes = earning_codes;
+        self
+    }
+
     /// Returns the underlying award configuration.
     pub fn config(&self) -> &AwardConfig {
         &self.config
     }
 
+    /// Runs the self-test checks against the configuration.
+    ///
+    /// Unlike the checks made while loading, which reject a file at its first
+    /// problem, the self-test reports the outcome of every check with all the
+    /// problems it found. It also checks a configuration built with
+    /// [`ConfigLoader::from_config`], which is not validated.
+    pub fn self_test(&self) -> SelfTestReport {
+        self_test(&self.config)
+    }
+
+    /// Returns the public holiday calendar (empty if none was configured).
+    pub fn holiday_calendar(&self) -> &HolidayCalendar {
+        &self.holidays
+    }
+
+    /// Returns the earning codes for payroll exports (empty if none were
+    /// configured).
+    pub fn earning_codes(&self) -> &EarningCodes {
+        &self.earning_codes
+    }
+
     /// Returns the award metadata.
     pub fn award(&self) -> &AwardMetadata {
         self.config.award()
@@ -295,26 +466,79 @@ impl ConfigLoader {
 
     /// Gets the allowance rates from the most recent rate configuration.
     pub fn get_allowance_rates(&self, date: NaiveDate) -> EngineResult<(Decimal, Decimal)> {
-        let rate_config = self
-            .config
+        let rate_config = self.rate_config_for(date)?;
+
+        Ok((
+            rate_config.allowances.laundry_per_shift,
+            rate_config.allowances.laundry_per_week,
+        ))
+    }
+
+    /// Gets the qualification allowance rate from the most recent rate configuration.
+    ///
+    /// Returns `Ok(None)` when the rate configuration does not define a
+    /// qualification allowance.
+    pub fn get_qualification_allowance_rate(
+        &self,
+        date: NaiveDate,
+    ) -> EngineResult<Option<QualificationAllowanceRate>> {
+        let rate_config = self.rate_config_for(date)?;
+
+        Ok(rate_config.allowances.qualification.clone())
+    }
+
+    /// Gets the broken shift allowance from the most recent rate configuration.
+    ///
+    /// Returns `Ok(None)` when the rate configuration does not define a
+    /// broken shift allowance.
+    pub fn get_broken_shift_allowance_rate(
+        &self,
+        date: NaiveDate,
+    ) -> EngineResult<Option<Decimal>> {
+        let rate_config = self.rate_config_for(date)?;
+
+        Ok(rate_config.allowances.broken_shift)
+    }
+
+    /// Gets the split shift travel reimbursement from the most recent rate
+    /// configuration.
+    ///
+    /// Returns `Ok(None)` when the rate configuration does not define a
+    /// split shift travel reimbursement.
+    pub fn get_split_shift_travel_rate(&self, date: NaiveDate) -> EngineResult<Option<Decimal>> {
+        let rate_config = self.rate_config_for(date)?;
+
+        Ok(rate_config.allowances.split_shift_travel)
+    }
+
+    /// Gets the weekly first aid allowance from the most recent rate
+    /// configuration.
+    ///
+    /// Returns `Ok(None)` when the rate configuration does not define a
+    /// first aid allowance.
+    pub fn get_first_aid_allowance_rate(&self, date: NaiveDate) -> EngineResult<Option<Decimal>> {
+        let rate_config = self.rate_config_for(date)?;
+
+        Ok(rate_config.allowances.first_aid_per_week)
+    }
+
+    /// Finds the most recent rate configuration effective on or before the given date.
+    fn rate_config_for(&self, date: NaiveDate) -> EngineResult<&RateConfig> {
+        self.config
             .rates()
             .iter()
             .rev()
             .find(|rc| rc.effective_date <= date)
             .ok_or_else(|| EngineError::ConfigNotFound {
                 path: "No rate configuration found for date".to_string(),
-            })?;
-
-        Ok((
-            rate_config.allowances.laundry_per_shift,
-            rate_config.allowances.laundry_per_week,
-        ))
+            })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::QualificationAllowanceBasis;
     use std::str::FromStr;
 
     fn config_path() -> &'static str {
@@ -425,6 +649,113 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_load_malformed_yaml_reports_file_and_location() {
+        let result = ConfigLoader::load("./tests/fixtures/malformed_config");
+
+        match result {
+            Err(EngineError::ConfigParseError {
+                path,
+                message,
+                line,
+                column,
+            }) => {
+                assert!(path.ends_with("award.yaml"));
+                assert_eq!(line, Some(6));
+                assert!(column.is_some());
+                assert!(message.contains("line 6"));
+            }
+            _ => panic!("Expected ConfigParseError"),
+        }
+    }
+
+    #[test]
+    fn test_load_zero_rate_reports_offending_entry() {
+        let result = ConfigLoader::load("./tests/fixtures/zero_rate_config");
+
+        match result {
+            Err(EngineError::InvalidConfig {
+                path,
+                field,
+                message,
+            }) => {
+                assert!(path.ends_with("2025-07-01.yaml"));
+                assert_eq!(field, "rates.dce_level_3.hourly");
+                assert_eq!(message, "must be greater than zero (found 0)");
+            }
+            other => panic!("expected InvalidConfig, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_weekday_overtime_tiers_must_ascend_from_zero() {
+        let penalties = fs::read_to_string(Path::new(config_path()).join("penalties.yaml"))
+            .unwrap()
+            .replace("threshold_hours: 2\n", "threshold_hours: 0\n");
+
+        let result = ConfigLoader::parse_yaml::<PenaltyConfig>(&penalties, "penalties.yaml".into());
+
+        match result {
+            Err(EngineError::ConfigParseError { message, .. }) => {
+                assert!(message.contains("ascending threshold order"));
+            }
+            other => panic!("expected ConfigParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_embedded_matches_config_directory() {
+        let embedded = ConfigLoader::load_embedded().unwrap();
+        let loaded = ConfigLoader::load(config_path()).unwrap();
+
+        assert_eq!(embedded.award().code, loaded.award().code);
+        assert_eq!(embedded.award().version, loaded.award().version);
+        assert_eq!(
+            embedded.config().classifications().len(),
+            loaded.config().classifications().len()
+        );
+        assert_eq!(
+            embedded.config().rates().len(),
+            loaded.config().rates().len()
+        );
+
+        let date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+        assert_eq!(
+            embedded.get_hourly_rate("dce_level_3", date).unwrap(),
+            dec("28.54")
+        );
+        assert_eq!(
+            embedded.holiday_calendar().national.len(),
+            loaded.holiday_calendar().national.len()
+        );
+    }
+
+    #[test]
+    fn test_holiday_calendar_includes_national_and_state_holidays() {
+        let loader = ConfigLoader::load(config_path()).unwrap();
+
+        let holidays = loader.holiday_calendar().holidays_between(
+            "VIC",
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 4, 12).unwrap(),
+        );
+
+        let summary: Vec<(String, &str)> = holidays
+            .iter()
+            .map(|h| (h.date.to_string(), h.region.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("2026-03-09".to_string(), "VIC"),
+                ("2026-04-03".to_string(), "national"),
+                ("2026-04-04".to_string(), "VIC"),
+                ("2026-04-05".to_string(), "VIC"),
+                ("2026-04-06".to_string(), "national"),
+            ]
+        );
+    }
+
     #[test]
     fn test_award_metadata_loaded_correctly() {
         let loader = ConfigLoader::load(config_path()).unwrap();
@@ -449,6 +780,30 @@ mod tests {
         assert_eq!(per_week, dec("1.49"));
     }
 
+    #[test]
+    fn test_week_start_day_loaded_correctly() {
+        let loader = ConfigLoader::load(config_path()).unwrap();
+
+        assert_eq!(
+            loader.config().penalties().week_start_day,
+            chrono::Weekday::Mon
+        );
+    }
+
+    #[test]
+    fn test_qualification_allowance_rate_loaded_correctly() {
+        let loader = ConfigLoader::load(config_path()).unwrap();
+
+        let date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+        let rate = loader
+            .get_qualification_allowance_rate(date)
+            .unwrap()
+            .expect("qualification allowance should be configured");
+
+        assert_eq!(rate.amount, dec("21.40"));
+        assert_eq!(rate.basis, QualificationAllowanceBasis::PerWeek);
+    }
+
     #[test]
     fn test_rate_not_found_for_date_before_effective() {
         let loader = ConfigLoader::load(config_path()).unwrap();
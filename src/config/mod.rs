@@ -13,11 +13,20 @@
 //! ```
 
 mod loader;
+mod self_test;
 mod types;
+mod validation;
 
 pub use loader::ConfigLoader;
+pub use self_test::{SelfTestCheck, SelfTestReport};
 pub use types::{
-    AllowanceRates, AwardConfig, AwardMetadata, Classification, ClassificationRate, OvertimeConfig,
-    OvertimeRates, OvertimeSection, Penalties, PenaltyConfig, PenaltyRates, RateConfig,
-    WeekendOvertimeConfig,
+    AccrualRate, AllowanceRates, AwardConfig, AwardMetadata, CalendarHoliday, CasualOvertimeBase,
+    CasualPenaltyComposition, Classification, ClassificationPenalties, ClassificationRate,
+    ConsecutiveDaysConfig, EarlyCommencementConfig, EarningCodes, HolidayCalendar,
+    LeaveAccrualConfig, MealBreakConfig, MinimumDailyPayConfig, OvertimeConfig, OvertimeRates,
+    OvertimeSection, OvertimeStacking, Penalties, PenaltyConfig, PenaltyRateOverrides,
+    PenaltyRates, PublicHolidayInLieuConfig, PublicHolidayNotWorkedConfig,
+    PublicHolidayOvertimeConfig, QualificationAllowanceBasis, QualificationAllowanceRate,
+    RateConfig, ShiftSeconds, SpanOfHoursConfig, TimeRounding, WeekdayOvertimeTier,
+    WeekendDayBasis, WeekendDays, WeekendOvertimeConfig, WeekendOvertimeTier,
 };
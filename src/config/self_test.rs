@@ -0,0 +1,295 @@
+//! Invariant checks over a whole award configuration.
+//!
+//! The range checks in the validation module reject a broken file when it is
+//! loaded, stopping at the first problem. The self-test instead runs a fixed
+//! suite of checks across the configuration as a whole, including ones that
+//! span files (a classification with no rate, a laundry cap below the
+//! per-shift amount), and reports every check with what it found, so an
+//! operator can confirm a configuration before relying on it.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::error::EngineError;
+
+use super::types::{AwardConfig, RateConfig};
+use super::validation::validate_penalty_config;
+
+/// The smallest casual loading, as a fraction of the base rate, that a
+/// configured casual weekend multiplier may imply.
+const MIN_PLAUSIBLE_CASUAL_LOADING: Decimal = Decimal::from_parts(10, 0, 0, false, 2);
+
+/// The largest casual loading, as a fraction of the base rate, that a
+/// configured casual weekend multiplier may imply.
+const MAX_PLAUSIBLE_CASUAL_LOADING: Decimal = Decimal::from_parts(50, 0, 0, false, 2);
+
+/// The outcome of one self-test check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelfTestCheck {
+    /// The check's identifier (e.g. "classification_rates").
+    pub check: String,
+    /// What the check verifies.
+    pub description: String,
+    /// Whether the configuration passed the check.
+    pub passed: bool,
+    /// Each problem the check found (empty when it passed).
+    pub problems: Vec<String>,
+}
+
+impl SelfTestCheck {
+    fn new(check: &str, description: &str, problems: Vec<String>) -> Self {
+        Self {
+            check: check.to_string(),
+            description: description.to_string(),
+            passed: problems.is_empty(),
+            problems,
+        }
+    }
+}
+
+/// The outcome of running every self-test check against a configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelfTestReport {
+    /// Whether every check passed.
+    pub passed: bool,
+    /// Each check, in the order it was run.
+    pub checks: Vec<SelfTestCheck>,
+}
+
+/// Runs every self-test check against an award configuration.
+pub(super) fn self_test(config: &AwardConfig) -> SelfTestReport {
+    let checks = vec![
+        SelfTestCheck::new(
+            "classification_rates",
+            "Every classification has a positive weekly and hourly rate in every rate file",
+            classification_rate_problems(config),
+        ),
+        SelfTestCheck::new(
+            "penalty_multipliers",
+            "Penalty and overtime multipliers are at least 1.0 and overtime thresholds are positive",
+            penalty_multiplier_problems(config),
+        ),
+        SelfTestCheck::new(
+            "casual_loading",
+            "Casual weekend multipliers imply a casual loading between 10% and 50%",
+            casual_loading_problems(config),
+        ),
+        SelfTestCheck::new(
+            "allowance_caps",
+            "The weekly laundry cap exceeds the per-shift laundry amount in every rate file",
+            allowance_cap_problems(config),
+        ),
+    ];
+
+    SelfTestReport {
+        passed: checks.iter().all(|check| check.passed),
+        checks,
+    }
+}
+
+fn classification_rate_problems(config: &AwardConfig) -> Vec<String> {
+    let mut codes: Vec<&String> = config.classifications().keys().collect();
+    codes.sort();
+
+    let mut problems = Vec::new();
+    for rates in config.rates() {
+        for code in &codes {
+            let Some(rate) = rates.rates.get(*code) else {
+                problems.push(format!("{}: {} has no rate", rate_file(rates), code));
+                continue;
+            };
+            for (name, amount) in [("weekly", rate.weekly), ("hourly", rate.hourly)] {
+                if amount <= Decimal::ZERO {
+                    problems.push(format!(
+                        "{}: rates.{}.{} must be greater than zero (found {})",
+                        rate_file(rates),
+                        code,
+                        name,
+                        amount
+                    ));
+                }
+            }
+        }
+    }
+    problems
+}
+
+fn penalty_multiplier_problems(config: &AwardConfig) -> Vec<String> {
+    match validate_penalty_config(config.penalties(), "penalties.yaml") {
+        Ok(()) => vec![],
+        Err(EngineError::InvalidConfig { field, message, .. }) => {
+            vec![format!("{} {}", field, message)]
+        }
+        Err(error) => vec![error.to_string()],
+    }
+}
+
+fn casual_loading_problems(config: &AwardConfig) -> Vec<String> {
+    let penalties = &config.penalties().penalties;
+    let mut codes: Vec<&String> = config.classifications().keys().collect();
+    codes.sort();
+
+    let mut problems = Vec::new();
+    for code in codes {
+        let days = [
+            ("saturday", penalties.saturday_for(code)),
+            ("sunday", penalties.sunday_for(code)),
+        ];
+        for (day, rates) in days {
+            let loading = rates.casual - rates.full_time;
+            if !(MIN_PLAUSIBLE_CASUAL_LOADING..=MAX_PLAUSIBLE_CASUAL_LOADING).contains(&loading) {
+                problems.push(format!(
+                    "{} {}: casual multiplier {} over full-time multiplier {} implies a casual loading of {}",
+                    code, day, rates.casual, rates.full_time, loading
+                ));
+            }
+        }
+    }
+    problems
+}
+
+fn allowance_cap_problems(config: &AwardConfig) -> Vec<String> {
+    config
+        .rates()
+        .iter()
+        .filter(|rates| {
+            let allowances = &rates.allowances;
+            allowances.laundry_per_shift > Decimal::ZERO
+                && allowances.laundry_per_week <= allowances.laundry_per_shift
+        })
+        .map(|rates| {
+            format!(
+                "{}: allowances.laundry_per_week ({}) does not exceed allowances.laundry_per_shift ({})",
+                rate_file(rates),
+                rates.allowances.laundry_per_week,
+                rates.allowances.laundry_per_shift
+            )
+        })
+        .collect()
+}
+
+/// Names a rate file in a problem by its effective date.
+fn rate_file(rates: &RateConfig) -> String {
+    format!("rates effective {}", rates.effective_date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ConfigLoader, PenaltyConfig};
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn load_config() -> AwardConfig {
+        ConfigLoader::load("./config/ma000018")
+            .expect("Failed to load config")
+            .config()
+            .clone()
+    }
+
+    fn check<'a>(report: &'a SelfTestReport, name: &str) -> &'a SelfTestCheck {
+        report
+            .checks
+            .iter()
+            .find(|check| check.check == name)
+            .unwrap()
+    }
+
+    fn rebuild(
+        config: &AwardConfig,
+        edit_rates: impl Fn(&mut RateConfig),
+        edit_penalties: impl Fn(&mut PenaltyConfig),
+    ) -> AwardConfig {
+        let mut rates = config.rates().to_vec();
+        rates.iter_mut().for_each(edit_rates);
+        let mut penalties = config.penalties().clone();
+        edit_penalties(&mut penalties);
+        AwardConfig::new(
+            config.award().clone(),
+            config.classifications().clone(),
+            rates,
+            penalties,
+        )
+    }
+
+    #[test]
+    fn test_shipped_config_passes_every_check() {
+        let report = self_test(&load_config());
+
+        assert!(report.passed, "{:?}", report);
+        assert_eq!(report.checks.len(), 4);
+        assert!(report.checks.iter().all(|check| check.problems.is_empty()));
+    }
+
+    #[test]
+    fn test_missing_and_zero_rates_are_reported() {
+        let config = load_config();
+        let missing = rebuild(
+            &config,
+            |rates| {
+                rates.rates.remove("dce_level_3");
+            },
+            |_| {},
+        );
+        let zero = rebuild(
+            &config,
+            |rates| rates.rates.get_mut("dce_level_3").unwrap().hourly = Decimal::ZERO,
+            |_| {},
+        );
+
+        let report = self_test(&missing);
+        assert!(!report.passed);
+        assert_eq!(
+            check(&report, "classification_rates").problems,
+            vec!["rates effective 2025-07-01: dce_level_3 has no rate".to_string()]
+        );
+        assert!(check(&report, "penalty_multipliers").passed);
+
+        let report = self_test(&zero);
+        assert_eq!(
+            check(&report, "classification_rates").problems,
+            vec![
+                "rates effective 2025-07-01: rates.dce_level_3.hourly must be greater than zero (found 0)"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_penalty_and_casual_loading_problems_are_reported() {
+        let config = rebuild(
+            &load_config(),
+            |_| {},
+            |penalties| penalties.penalties.sunday.casual = dec("0.75"),
+        );
+
+        let report = self_test(&config);
+
+        assert_eq!(
+            check(&report, "penalty_multipliers").problems,
+            vec!["penalties.sunday.casual must be at least 1.0 (found 0.75)".to_string()]
+        );
+        let casual = check(&report, "casual_loading");
+        assert!(!casual.passed);
+        assert!(casual.problems.iter().all(|p| p.contains(" sunday: ")));
+    }
+
+    #[test]
+    fn test_laundry_cap_below_per_shift_amount_is_reported() {
+        let config = rebuild(
+            &load_config(),
+            |rates| rates.allowances.laundry_per_week = rates.allowances.laundry_per_shift,
+            |_| {},
+        );
+
+        let report = self_test(&config);
+
+        let caps = check(&report, "allowance_caps");
+        assert!(!caps.passed);
+        assert_eq!(caps.problems.len(), config.rates().len());
+        assert!(caps.problems[0].contains("does not exceed allowances.laundry_per_shift"));
+    }
+}
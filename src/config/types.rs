@@ -3,16 +3,18 @@
 //! This module contains the strongly-typed configuration structures that
 //! are deserialized from YAML configuration files.
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime, Weekday};
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::models::{AllowanceType, PayCategory, PublicHoliday};
+
 /// Metadata about the award.
 ///
 /// Contains identifying information about the award, including its
 /// Fair Work code, name, version, and source URL.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AwardMetadata {
     /// The Fair Work award code (e.g., "MA000018").
     pub code: String,
@@ -28,7 +30,7 @@ pub struct AwardMetadata {
 ///
 /// Classifications define the various employee categories and their
 /// associated pay grades.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Classification {
     /// The human-readable name of the classification.
     pub name: String,
@@ -39,14 +41,14 @@ pub struct Classification {
 }
 
 /// Classifications configuration file structure.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ClassificationsConfig {
     /// Map of classification code to classification details.
     pub classifications: HashMap<String, Classification>,
 }
 
 /// Rate information for a specific classification.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ClassificationRate {
     /// The weekly rate for this classification.
     pub weekly: Decimal,
@@ -55,16 +57,47 @@ pub struct ClassificationRate {
 }
 
 /// Allowance rates.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AllowanceRates {
     /// The laundry allowance per shift.
     pub laundry_per_shift: Decimal,
     /// The maximum laundry allowance per week.
     pub laundry_per_week: Decimal,
+    /// The qualification allowance, if configured.
+    #[serde(default)]
+    pub qualification: Option<QualificationAllowanceRate>,
+    /// The allowance paid per broken shift, if configured.
+    #[serde(default)]
+    pub broken_shift: Option<Decimal>,
+    /// The travel reimbursement paid per day worked as a broken shift, if configured.
+    #[serde(default)]
+    pub split_shift_travel: Option<Decimal>,
+    /// The first aid allowance per week, if configured.
+    #[serde(default)]
+    pub first_aid_per_week: Option<Decimal>,
+}
+
+/// How a qualification allowance amount is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualificationAllowanceBasis {
+    /// The amount is paid for each hour worked.
+    PerHour,
+    /// The amount is paid once per week.
+    PerWeek,
+}
+
+/// Qualification (certificate) allowance rate.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QualificationAllowanceRate {
+    /// The allowance amount, per hour or per week depending on `basis`.
+    pub amount: Decimal,
+    /// Whether the amount is paid per hour worked or per week.
+    pub basis: QualificationAllowanceBasis,
 }
 
 /// Rate configuration for a specific effective date.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RateConfig {
     /// The effective date for these rates.
     pub effective_date: NaiveDate,
@@ -75,7 +108,7 @@ pub struct RateConfig {
 }
 
 /// Penalty rates by employment type.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PenaltyRates {
     /// Reference to the award clause for these penalties.
     pub clause: String,
@@ -87,8 +120,47 @@ pub struct PenaltyRates {
     pub casual: Decimal,
 }
 
+impl PenaltyRates {
+    /// Returns these rates with any multipliers set in `overrides` in place
+    /// of the configured ones.
+    fn with_overrides(&self, overrides: &PenaltyRateOverrides) -> PenaltyRates {
+        PenaltyRates {
+            clause: self.clause.clone(),
+            full_time: overrides.full_time.unwrap_or(self.full_time),
+            part_time: overrides.part_time.unwrap_or(self.part_time),
+            casual: overrides.casual.unwrap_or(self.casual),
+        }
+    }
+}
+
+/// Penalty multipliers that replace the award-wide rates for one
+/// classification. Employment types left out keep the award-wide rate.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PenaltyRateOverrides {
+    /// Penalty multiplier for full-time employees.
+    #[serde(default)]
+    pub full_time: Option<Decimal>,
+    /// Penalty multiplier for part-time employees.
+    #[serde(default)]
+    pub part_time: Option<Decimal>,
+    /// Penalty multiplier for casual employees.
+    #[serde(default)]
+    pub casual: Option<Decimal>,
+}
+
+/// Weekend penalty multipliers for one classification.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ClassificationPenalties {
+    /// Saturday penalty multipliers.
+    #[serde(default)]
+    pub saturday: PenaltyRateOverrides,
+    /// Sunday penalty multipliers.
+    #[serde(default)]
+    pub sunday: PenaltyRateOverrides,
+}
+
 /// Overtime rates by employment type.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OvertimeRates {
     /// Overtime multiplier for full-time employees.
     pub full_time: Decimal,
@@ -99,61 +171,732 @@ pub struct OvertimeRates {
 }
 
 /// Overtime configuration for weekday.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// Weekday overtime is paid in tiers. Each tier applies from its
+/// `threshold_hours` of overtime until the next tier's threshold, and the
+/// last tier covers all remaining hours. Tiers are listed in ascending
+/// threshold order, starting at zero.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OvertimeConfig {
     /// Reference to the award clause for overtime.
     pub clause: String,
-    /// Rates for the first two hours of overtime.
-    pub first_two_hours: OvertimeRates,
-    /// Rates for overtime after two hours.
-    pub after_two_hours: OvertimeRates,
+    /// The overtime tiers, in ascending threshold order.
+    #[serde(deserialize_with = "deserialize_weekday_overtime_tiers")]
+    pub tiers: Vec<WeekdayOvertimeTier>,
+}
+
+/// Deserializes weekday overtime tiers, rejecting tiers that would leave
+/// overtime hours unpaid or paid twice.
+fn deserialize_weekday_overtime_tiers<'de, D>(
+    deserializer: D,
+) -> Result<Vec<WeekdayOvertimeTier>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let tiers = Vec::<WeekdayOvertimeTier>::deserialize(deserializer)?;
+    match tiers.first() {
+        None => Err(serde::de::Error::custom(
+            "at least one weekday overtime tier is required",
+        )),
+        Some(first) if !first.threshold_hours.is_zero() => Err(serde::de::Error::custom(
+            "the first weekday overtime tier must start at 0 hours",
+        )),
+        _ if tiers
+            .windows(2)
+            .any(|pair| pair[1].threshold_hours <= pair[0].threshold_hours) =>
+        {
+            Err(serde::de::Error::custom(
+                "weekday overtime tiers must be in ascending threshold order",
+            ))
+        }
+        _ => Ok(tiers),
+    }
+}
+
+/// A tier of weekday overtime.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WeekdayOvertimeTier {
+    /// Number of overtime hours after which this tier applies.
+    pub threshold_hours: Decimal,
+    /// The pay category for hours paid at this tier.
+    pub category: PayCategory,
+    /// Overtime multipliers for this tier.
+    #[serde(flatten)]
+    pub rates: OvertimeRates,
 }
 
 /// Weekend overtime configuration.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// Weekend overtime is flat by default: every overtime hour is paid at the
+/// `saturday`/`sunday` rates. When `tiered` is set, overtime is paid in the
+/// configured `tiers` instead, as weekday overtime is: each tier applies from
+/// its `threshold_hours` until the next tier's threshold, and the last tier
+/// covers all remaining hours.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WeekendOvertimeConfig {
     /// Reference to the award clause for weekend overtime.
     pub clause: String,
-    /// Saturday overtime rates.
+    /// Whether weekend overtime is paid in tiers (defaults to flat).
+    #[serde(default)]
+    pub tiered: bool,
+    /// The overtime tiers, in ascending threshold order from zero; used only
+    /// when `tiered` is true.
+    #[serde(default)]
+    pub tiers: Vec<WeekendOvertimeTier>,
+    /// Whether overtime replaces the weekend penalty or stacks on it (defaults to replace).
+    #[serde(default)]
+    pub overtime_stacking: OvertimeStacking,
+    /// Saturday overtime rates, used when weekend overtime is flat.
+    pub saturday: OvertimeRates,
+    /// Sunday overtime rates, used when weekend overtime is flat.
+    pub sunday: OvertimeRates,
+}
+
+/// Overtime rates for shifts worked on a public holiday.
+///
+/// Every overtime hour of such a shift is paid at these rates, whichever day
+/// of the week the holiday falls on.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublicHolidayOvertimeConfig {
+    /// Reference to the award clause for public holiday overtime.
+    pub clause: String,
+    /// Public holiday overtime multipliers.
+    #[serde(flatten)]
+    pub rates: OvertimeRates,
+}
+
+/// How weekend overtime interacts with the Saturday/Sunday penalty.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OvertimeStacking {
+    /// The overtime multiplier replaces the weekend penalty and is applied to the base rate.
+    #[default]
+    Replace,
+    /// The overtime multiplier is applied on top of the weekend penalty rate.
+    StackOnPenalty,
+}
+
+/// A tier of tiered weekend overtime.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WeekendOvertimeTier {
+    /// Number of overtime hours after which this tier applies.
+    pub threshold_hours: Decimal,
+    /// The pay category for hours paid at this tier.
+    pub category: PayCategory,
+    /// Saturday overtime multipliers for this tier.
     pub saturday: OvertimeRates,
-    /// Sunday overtime rates.
+    /// Sunday overtime multipliers for this tier.
     pub sunday: OvertimeRates,
 }
 
 /// Penalty configuration from penalties.yaml.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PenaltyConfig {
     /// Penalty rates configuration.
     pub penalties: Penalties,
     /// Overtime configuration.
     pub overtime: OvertimeSection,
+    /// Rounding applied to worked time before pay lines are generated.
+    #[serde(default)]
+    pub time_rounding: TimeRounding,
+    /// How shift and break times recorded to the second are paid (defaults
+    /// to rounding them to the nearest minute).
+    #[serde(default)]
+    pub shift_seconds: ShiftSeconds,
+    /// How shifts are assigned to Saturday and Sunday for weekend penalties
+    /// (defaults to calendar days).
+    #[serde(default)]
+    pub weekend_days: WeekendDays,
+    /// The day the award's week starts on, used for weekly caps (defaults to Monday).
+    #[serde(default = "default_week_start_day")]
+    pub week_start_day: Weekday,
+    /// The fewest hours a shift must be worked to count towards the laundry
+    /// allowance (defaults to 0, so every shift counts).
+    #[serde(default)]
+    pub laundry_min_shift_hours: Decimal,
+    /// Payment for public holidays not worked (disabled when absent).
+    #[serde(default)]
+    pub public_holiday_not_worked: Option<PublicHolidayNotWorkedConfig>,
+    /// Penalty for working beyond a run of consecutive days (disabled when absent).
+    #[serde(default)]
+    pub consecutive_days: Option<ConsecutiveDaysConfig>,
+    /// Meal break requirement for long shifts (not checked when absent).
+    #[serde(default)]
+    pub meal_break: Option<MealBreakConfig>,
+    /// Penalty for a day's span of hours beyond a limit (not paid when absent).
+    #[serde(default)]
+    pub span_of_hours: Option<SpanOfHoursConfig>,
+    /// Loading for shifts commencing before an early hour (not paid when
+    /// absent).
+    #[serde(default)]
+    pub early_commencement: Option<EarlyCommencementConfig>,
+    /// A guaranteed minimum of paid hours for each day worked (no top-up when
+    /// absent).
+    #[serde(default)]
+    pub minimum_daily_pay: Option<MinimumDailyPayConfig>,
+    /// Leave accrued on the ordinary hours paid (not calculated when absent).
+    #[serde(default)]
+    pub leave_accrual: Option<LeaveAccrualConfig>,
+    /// How far, as a percentage, an employee's override rate may differ from
+    /// the classification rate before a warning is raised (defaults to 25).
+    #[serde(default = "default_override_rate_warning_percent")]
+    pub override_rate_warning_percent: Decimal,
+    /// A gross pay, in dollars, above which a result is flagged as a likely
+    /// data error (not checked when absent).
+    #[serde(default)]
+    pub max_expected_gross: Option<Decimal>,
+    /// Whether pay lines and allowances are rounded to the cent, with the
+    /// rounding residual added to the largest pay line so the lines add up to
+    /// the rounded gross pay (amounts are left unrounded when false).
+    #[serde(default)]
+    pub rounding_reconciliation: bool,
+    /// The most audit steps a result returns; longer traces are truncated
+    /// with a closing step noting how many were omitted (unbounded when absent).
+    #[serde(default)]
+    pub max_audit_steps: Option<usize>,
+}
+
+/// The default week start day (ISO weeks start on Monday).
+fn default_week_start_day() -> Weekday {
+    Weekday::Mon
+}
+
+/// The default override rate warning tolerance, in percent.
+fn default_override_rate_warning_percent() -> Decimal {
+    Decimal::from(25)
+}
+
+/// Payment for a public holiday a permanent employee would ordinarily have
+/// worked but did not.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublicHolidayNotWorkedConfig {
+    /// Reference to the award clause for the payment.
+    pub clause: String,
+    /// Hours paid for the day when the employee has no maximum daily ordinary
+    /// hours of their own.
+    pub ordinary_hours: Decimal,
+}
+
+/// Penalty for days worked beyond a run of consecutive days.
+///
+/// With a `run_length` of 6, the seventh and later consecutive days worked
+/// attract the penalty multipliers.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConsecutiveDaysConfig {
+    /// Number of consecutive days that can be worked before the penalty applies.
+    pub run_length: u32,
+    /// Penalty multipliers for days beyond the run length.
+    #[serde(flatten)]
+    pub penalty: PenaltyRates,
+}
+
+/// Meal break requirement for long shifts.
+///
+/// A shift of more than `max_hours_without_break` worked hours must include an
+/// unpaid break of at least `min_break_minutes` that starts no later than that
+/// point. A shift without one raises a warning and, when `penalty_rate` is
+/// set, is paid the penalty for the hours worked past the point the break was due.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MealBreakConfig {
+    /// Reference to the award clause for the meal break.
+    pub clause: String,
+    /// Hours that can be worked before a meal break is due.
+    pub max_hours_without_break: Decimal,
+    /// Shortest unpaid break, in minutes, that counts as a meal break (defaults to 30).
+    #[serde(default = "default_min_meal_break_minutes")]
+    pub min_break_minutes: i64,
+    /// Multiplier of the base rate paid on top of ordinary pay for each hour
+    /// worked past the point the break was due (warn only when absent).
+    #[serde(default)]
+    pub penalty_rate: Option<Decimal>,
+}
+
+/// The default shortest meal break, in minutes.
+fn default_min_meal_break_minutes() -> i64 {
+    30
+}
+
+/// Penalty for a long span of hours in a day.
+///
+/// A day's span runs from the start of its first shift to the finish of its
+/// last, so unpaid breaks and the gaps of a split shift count towards it. The
+/// hours of the span beyond `max_span_hours` are paid the penalty.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SpanOfHoursConfig {
+    /// Reference to the award clause for the span of hours.
+    pub clause: String,
+    /// The longest span, in hours, worked without a penalty.
+    pub max_span_hours: Decimal,
+    /// Multiplier of the base rate paid on top of ordinary pay for each hour
+    /// of the span beyond `max_span_hours`.
+    pub penalty_rate: Decimal,
+}
+
+/// Loading for shifts commencing early in the morning.
+///
+/// A shift that starts before `before` on the day it starts (e.g. before
+/// 05:00) is paid the loading for every hour it is worked.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EarlyCommencementConfig {
+    /// Reference to the award clause for the loading.
+    pub clause: String,
+    /// Shifts starting before this time attract the loading.
+    pub before: NaiveTime,
+    /// Multiplier of the base rate paid on top of ordinary pay for each hour
+    /// worked in an early shift.
+    pub penalty_rate: Decimal,
+}
+
+/// A minimum daily payment.
+///
+/// A day whose shifts are paid for fewer than `minimum_daily_hours` hours
+/// worked is topped up to the minimum at the ordinary rate. The minimum covers
+/// all of the day's shifts together, not each shift.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MinimumDailyPayConfig {
+    /// Reference to the award clause or arrangement for the guarantee.
+    pub clause: String,
+    /// The fewest hours paid for a day worked.
+    pub minimum_daily_hours: Decimal,
+}
+
+/// Annual and personal leave accrued on the ordinary hours paid in a pay
+/// period. Casual employees accrue no leave.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LeaveAccrualConfig {
+    /// Reference to the entitlement the accruals are made under.
+    pub clause: String,
+    /// The annual leave accrual rate.
+    pub annual_leave: AccrualRate,
+    /// The personal (sick and carer's) leave accrual rate.
+    pub personal_leave: AccrualRate,
+    /// A day in lieu for a public holiday on a rostered day off (not granted
+    /// when absent).
+    #[serde(default)]
+    pub public_holiday_in_lieu: Option<PublicHolidayInLieuConfig>,
+}
+
+/// A day in lieu for a public holiday that falls on a permanent employee's
+/// rostered day off.
+///
+/// The day is recorded as an entitlement in the accruals rather than paid.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublicHolidayInLieuConfig {
+    /// Reference to the award clause for the day in lieu.
+    pub clause: String,
+    /// Hours of the day in lieu when the employee has no maximum daily
+    /// ordinary hours of their own.
+    pub ordinary_hours: Decimal,
+}
+
+/// A leave accrual rate: `hours` of leave for every `per_ordinary_hours`
+/// ordinary hours paid.
+///
+/// Rates are written as a ratio so entitlements such as one hour per 13
+/// ordinary hours are exact rather than a rounded fraction.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct AccrualRate {
+    /// Hours of leave accrued for each `per_ordinary_hours`.
+    pub hours: Decimal,
+    /// The ordinary hours paid that accrue `hours` of leave.
+    pub per_ordinary_hours: Decimal,
+}
+
+impl AccrualRate {
+    /// Returns the leave accrued for a number of ordinary hours paid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::config::AccrualRate;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let annual_leave = AccrualRate {
+    ///     hours: Decimal::ONE,
+    ///     per_ordinary_hours: Decimal::from(13),
+    /// };
+    /// assert_eq!(annual_leave.accrue(Decimal::from(26)), Decimal::from(2));
+    /// ```
+    pub fn accrue(&self, ordinary_hours: Decimal) -> Decimal {
+        ordinary_hours * self.hours / self.per_ordinary_hours
+    }
+}
+
+/// Rounding applied to worked time before rates are applied.
+///
+/// This rounds hours, not money: the rounded hours are what the pay rates
+/// are multiplied by. It is configured by name: `none`, or
+/// `nearest_N_minutes` / `up_N_minutes` for the award's stated increment of
+/// `N` minutes. The older names `nearest_15`, `up_15` and `nearest_6_minutes`
+/// are still read, as the same rules; they are always written out in the
+/// `_N_minutes` form.
+///
+/// # Example
+///
+/// ```
+/// use award_engine::config::TimeRounding;
+///
+/// let rounding: TimeRounding = "up_10_minutes".parse().unwrap();
+/// assert_eq!(rounding, TimeRounding::Up(10));
+/// assert_eq!(rounding.to_string(), "up_10_minutes");
+/// assert_eq!("up_15".parse(), Ok(TimeRounding::Up(15)));
+/// assert_eq!(TimeRounding::Up(15).to_string(), "up_15_minutes");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum TimeRounding {
+    /// Worked time is paid exactly as recorded.
+    #[default]
+    None,
+    /// Worked time is rounded to the nearest multiple of the award's stated
+    /// increment, in minutes (`nearest_N_minutes`).
+    Nearest(u32),
+    /// Worked time is rounded up to the next multiple of the award's stated
+    /// increment, in minutes (`up_N_minutes`).
+    Up(u32),
+}
+
+impl std::str::FromStr for TimeRounding {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let increment = |prefix: &str| {
+            name.strip_prefix(prefix)?
+                .strip_suffix("_minutes")?
+                .parse::<u32>()
+                .ok()
+        };
+        match name {
+            "none" => Ok(TimeRounding::None),
+            // Names from before increments were configurable
+            "nearest_15" => Ok(TimeRounding::Nearest(15)),
+            "up_15" => Ok(TimeRounding::Up(15)),
+            _ => increment("nearest_")
+                .map(TimeRounding::Nearest)
+                .or_else(|| increment("up_").map(TimeRounding::Up))
+                .ok_or_else(|| {
+                    format!(
+                        "unknown time rounding '{}' (expected none, nearest_N_minutes or up_N_minutes)",
+                        name
+                    )
+                }),
+        }
+    }
+}
+
+impl TryFrom<String> for TimeRounding {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+impl std::fmt::Display for TimeRounding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeRounding::None => write!(f, "none"),
+            TimeRounding::Nearest(minutes) => write!(f, "nearest_{}_minutes", minutes),
+            TimeRounding::Up(minutes) => write!(f, "up_{}_minutes", minutes),
+        }
+    }
+}
+
+impl From<TimeRounding> for String {
+    fn from(rounding: TimeRounding) -> Self {
+        rounding.to_string()
+    }
+}
+
+impl TimeRounding {
+    /// Returns the increment worked time is rounded to, in minutes, or `None`
+    /// when it is paid exactly as recorded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::config::TimeRounding;
+    ///
+    /// assert_eq!(TimeRounding::Nearest(6).increment_minutes(), Some(6));
+    /// assert_eq!(TimeRounding::Up(10).increment_minutes(), Some(10));
+    /// assert_eq!(TimeRounding::None.increment_minutes(), None);
+    /// ```
+    pub fn increment_minutes(&self) -> Option<u32> {
+        match self {
+            TimeRounding::None => None,
+            TimeRounding::Nearest(minutes) | TimeRounding::Up(minutes) => Some(*minutes),
+        }
+    }
+
+    /// Returns true when worked time is rounded up rather than to the nearest
+    /// increment.
+    pub fn rounds_up(&self) -> bool {
+        matches!(self, TimeRounding::Up(_))
+    }
+}
+
+/// How shift and break times recorded to the second are paid.
+///
+/// Rostering and timeclock systems that record seconds would otherwise leave
+/// shifts a fraction of a minute long; this makes the treatment explicit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShiftSeconds {
+    /// Each time is rounded to the nearest minute (30 seconds rounds up)
+    /// before the shift is calculated, with an audit step for each shift
+    /// changed.
+    #[default]
+    NearestMinute,
+    /// Times are used as recorded, so worked hours are paid to the second.
+    Exact,
+}
+
+/// How hours are assigned to days for weekend penalties.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeekendDayBasis {
+    /// Each hour is paid as the calendar day it is worked on, so a shift
+    /// crossing midnight is split between the two days.
+    #[default]
+    Calendar,
+    /// A whole shift is paid as the day it commences on, for awards that
+    /// define weekend work by when a shift starts.
+    ShiftCommencement,
+}
+
+/// The definition of Saturday and Sunday used for weekend penalties.
+///
+/// For 24-hour operations where the night shift belongs to the following
+/// day, set `basis` to `shift_commencement` and `next_day_from` to the time
+/// the night shift starts: with `next_day_from: "22:00:00"`, a shift
+/// commencing at 22:00 on a Friday is paid wholly as a Saturday shift.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WeekendDays {
+    /// Whether hours are assigned by calendar day or by shift commencement.
+    #[serde(default)]
+    pub basis: WeekendDayBasis,
+    /// Under shift commencement, shifts commencing at or after this time of
+    /// day belong to the next day (defaults to midnight, so every shift
+    /// belongs to the calendar day it commences on). Ignored for calendar days.
+    #[serde(default)]
+    pub next_day_from: NaiveTime,
+    /// Under calendar days, a trailing segment of a shift shorter than this
+    /// many minutes (e.g. the five minutes past midnight of a shift ending at
+    /// 12:05am Saturday) is paid as the day of the segment before it
+    /// (defaults to 0, so every segment keeps its own day).
+    #[serde(default)]
+    pub min_segment_minutes: u32,
 }
 
 /// Penalties section.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Penalties {
     /// Saturday penalty rates.
     pub saturday: PenaltyRates,
     /// Sunday penalty rates.
     pub sunday: PenaltyRates,
+    /// Weekend penalty multipliers that differ from the rates above, keyed by
+    /// classification code.
+    #[serde(default)]
+    pub classifications: HashMap<String, ClassificationPenalties>,
+    /// How the casual weekend multipliers combine the penalty with the casual
+    /// loading (defaults to additive).
+    #[serde(default)]
+    pub casual_composition: CasualPenaltyComposition,
+}
+
+impl Penalties {
+    /// Returns the Saturday penalty rates for a classification, falling back
+    /// to the award-wide rates for anything it does not override.
+    pub fn saturday_for(&self, classification_code: &str) -> PenaltyRates {
+        match self.classifications.get(classification_code) {
+            Some(overrides) => self.saturday.with_overrides(&overrides.saturday),
+            None => self.saturday.clone(),
+        }
+    }
+
+    /// Returns the Sunday penalty rates for a classification, falling back
+    /// to the award-wide rates for anything it does not override.
+    pub fn sunday_for(&self, classification_code: &str) -> PenaltyRates {
+        match self.classifications.get(classification_code) {
+            Some(overrides) => self.sunday.with_overrides(&overrides.sunday),
+            None => self.sunday.clone(),
+        }
+    }
 }
 
 /// Overtime section in penalties config.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OvertimeSection {
     /// Number of hours before overtime kicks in on a weekday.
     pub daily_threshold_hours: u32,
+    /// Daily overtime thresholds that replace `daily_threshold_hours` on
+    /// particular days of the week (e.g. 10 hours on Saturdays).
+    #[serde(default)]
+    pub daily_thresholds: HashMap<Weekday, Decimal>,
+    /// Whether casual overtime is paid on the casual-loaded rate (defaults to loaded).
+    #[serde(default)]
+    pub casual_ot_base: CasualOvertimeBase,
     /// Weekday overtime rates.
     pub weekday: OvertimeConfig,
     /// Weekend overtime rates.
     pub weekend: WeekendOvertimeConfig,
+    /// Overtime rates for shifts on a public holiday (when absent, overtime on
+    /// a public holiday is paid at the day's usual overtime rates).
+    #[serde(default)]
+    pub public_holiday: Option<PublicHolidayOvertimeConfig>,
+}
+
+impl OvertimeSection {
+    /// Returns the daily overtime threshold for a day of the week: its entry
+    /// in `daily_thresholds`, or `daily_threshold_hours` when it has none.
+    pub fn daily_threshold_for(&self, weekday: Weekday) -> Decimal {
+        self.daily_thresholds
+            .get(&weekday)
+            .copied()
+            .unwrap_or_else(|| Decimal::from(self.daily_threshold_hours))
+    }
+}
+
+/// The rate casual overtime multipliers are applied to.
+///
+/// Configured casual overtime multipliers include the casual loading
+/// (e.g. 1.875 = 1.5 × 1.25).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CasualOvertimeBase {
+    /// Overtime is paid on the casual-loaded rate, as configured.
+    #[default]
+    Loaded,
+    /// Overtime is paid on the base rate; the casual loading is excluded.
+    Base,
+}
+
+/// How a casual weekend penalty combines with the casual loading.
+///
+/// Configured casual weekend multipliers are the award's published casual
+/// rates, which add the casual loading to the penalty (e.g. 1.75 = 1.5 + 0.25).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CasualPenaltyComposition {
+    /// The casual loading is added to the penalty, as configured.
+    #[default]
+    Additive,
+    /// The penalty is applied to the casual-loaded rate (e.g. 1.5 × 1.25 = 1.875).
+    Compounding,
+}
+
+/// A public holiday in the holiday calendar.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CalendarHoliday {
+    /// The date the holiday is observed.
+    pub date: NaiveDate,
+    /// The name of the holiday (e.g. "Australia Day").
+    pub name: String,
+}
+
+/// The public holiday calendar from holidays.yaml.
+///
+/// National holidays apply in every state; state holidays are keyed by
+/// state code (e.g. "NSW", "VIC").
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct HolidayCalendar {
+    /// Holidays observed in every state.
+    #[serde(default)]
+    pub national: Vec<CalendarHoliday>,
+    /// Holidays observed only in a given state, keyed by state code.
+    #[serde(default)]
+    pub states: HashMap<String, Vec<CalendarHoliday>>,
+}
+
+impl HolidayCalendar {
+    /// Returns the holidays observed in a state between two dates (inclusive),
+    /// in date order.
+    pub fn holidays_between(
+        &self,
+        state: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Vec<PublicHoliday> {
+        let national = self.national.iter().map(|h| (h, "national"));
+        let state_holidays = self
+            .states
+            .get(state)
+            .into_iter()
+            .flatten()
+            .map(|h| (h, state));
+
+        let mut holidays: Vec<PublicHoliday> = national
+            .chain(state_holidays)
+            .filter(|(h, _)| h.date >= start_date && h.date <= end_date)
+            .map(|(h, region)| PublicHoliday {
+                date: h.date,
+                name: h.name.clone(),
+                region: region.to_string(),
+            })
+            .collect();
+        holidays.sort_by_key(|h| h.date);
+        holidays
+    }
+}
+
+/// The earning codes from earning_codes.yaml, used to export results to a
+/// payroll system.
+///
+/// Each pay category and allowance type maps to the code the target payroll
+/// system imports it under. One file describes one target system; a
+/// deployment exporting to another system ships its own mapping.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct EarningCodes {
+    /// Earning codes keyed by pay category (e.g. `overtime150: OT150`).
+    #[serde(default)]
+    pub pay_categories: HashMap<PayCategory, String>,
+    /// Earning codes keyed by allowance type (e.g. `laundry: LAUNDRY`).
+    #[serde(default)]
+    pub allowances: HashMap<AllowanceType, String>,
+}
+
+impl EarningCodes {
+    /// Returns the earning code for a pay category, or the category's
+    /// snake_case name when it has none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::config::EarningCodes;
+    /// use award_engine::models::PayCategory;
+    ///
+    /// let mut codes = EarningCodes::default();
+    /// codes.pay_categories.insert(PayCategory::Overtime150, "OT150".to_string());
+    ///
+    /// assert_eq!(codes.pay_category_code(PayCategory::Overtime150), "OT150");
+    /// assert_eq!(codes.pay_category_code(PayCategory::Overtime200), "overtime200");
+    /// ```
+    pub fn pay_category_code(&self, category: PayCategory) -> String {
+        match self.pay_categories.get(&category) {
+            Some(code) => code.clone(),
+            None => serde_json::to_value(category)
+                .ok()
+                .and_then(|name| name.as_str().map(str::to_string))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the earning code for an allowance type, or the type's
+    /// snake_case name when it has none.
+    pub fn allowance_code(&self, allowance_type: &AllowanceType) -> String {
+        self.allowances
+            .get(allowance_type)
+            .cloned()
+            .unwrap_or_else(|| allowance_type.as_str().to_string())
+    }
 }
 
 /// The complete award configuration loaded from YAML files.
 ///
 /// This struct aggregates all configuration loaded from the various
 /// YAML files in an award configuration directory.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AwardConfig {
     /// Award metadata.
     metadata: AwardMetadata,
@@ -174,7 +917,7 @@ impl AwardConfig {
         penalties: PenaltyConfig,
     ) -> Self {
         let mut sorted_rates = rates;
-        sorted_rates.sort_by(|a, b| a.effective_date.cmp(&b.effective_date));
+        sorted_rates.sort_by_key(|r| r.effective_date);
         Self {
             metadata,
             classifications,
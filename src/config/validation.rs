@@ -0,0 +1,549 @@
+//! Range checks on loaded configuration values.
+//!
+//! A configuration can parse cleanly and still describe pay that makes no
+//! sense, such as a zero hourly rate or a weekend penalty that pays less than
+//! ordinary time. These checks run when a configuration is loaded so a broken
+//! file is rejected up front instead of silently underpaying employees.
+
+use rust_decimal::Decimal;
+
+use crate::error::{EngineError, EngineResult};
+
+use super::types::{OvertimeRates, PenaltyConfig, PenaltyRateOverrides, PenaltyRates, RateConfig};
+
+/// Checks a rate file: every classification rate must be positive and every
+/// allowance amount non-negative.
+pub(super) fn validate_rate_config(rates: &RateConfig, path: &str) -> EngineResult<()> {
+    let mut codes: Vec<&String> = rates.rates.keys().collect();
+    codes.sort();
+    for code in codes {
+        let rate = &rates.rates[code];
+        positive(path, format!("rates.{}.weekly", code), rate.weekly)?;
+        positive(path, format!("rates.{}.hourly", code), rate.hourly)?;
+    }
+
+    let allowances = &rates.allowances;
+    let amounts = [
+        ("laundry_per_shift", Some(allowances.laundry_per_shift)),
+        ("laundry_per_week", Some(allowances.laundry_per_week)),
+        (
+            "qualification.amount",
+            allowances.qualification.as_ref().map(|q| q.amount),
+        ),
+        ("broken_shift", allowances.broken_shift),
+        ("split_shift_travel", allowances.split_shift_travel),
+        ("first_aid_per_week", allowances.first_aid_per_week),
+    ];
+    for (name, amount) in amounts {
+        if let Some(amount) = amount
+            && amount < Decimal::ZERO
+        {
+            return Err(invalid(
+                path,
+                format!("allowances.{}", name),
+                format!("must not be negative (found {})", amount),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks a penalties file: no penalty or overtime multiplier may pay less
+/// than ordinary time (1.0), every per-day overtime threshold must be
+/// positive, tiered weekend overtime must list tiers ascending from zero, and
+/// leave accrual rates must be well formed.
+pub(super) fn validate_penalty_config(penalties: &PenaltyConfig, path: &str) -> EngineResult<()> {
+    penalty_rates(path, "penalties.saturday", &penalties.penalties.saturday)?;
+    penalty_rates(path, "penalties.sunday", &penalties.penalties.sunday)?;
+
+    let mut codes: Vec<&String> = penalties.penalties.classifications.keys().collect();
+    codes.sort();
+    for code in codes {
+        let overrides = &penalties.penalties.classifications[code];
+        let days = [
+            ("saturday", &overrides.saturday),
+            ("sunday", &overrides.sunday),
+        ];
+        for (day, rates) in days {
+            rate_overrides(
+                path,
+                &format!("penalties.classifications.{}.{}", code, day),
+                rates,
+            )?;
+        }
+    }
+
+    let overtime = &penalties.overtime;
+    for (i, tier) in overtime.weekday.tiers.iter().enumerate() {
+        overtime_rates(path, &format!("overtime.weekday.tiers[{}]", i), &tier.rates)?;
+    }
+    overtime_rates(
+        path,
+        "overtime.weekend.saturday",
+        &overtime.weekend.saturday,
+    )?;
+    overtime_rates(path, "overtime.weekend.sunday", &overtime.weekend.sunday)?;
+    if let Some(public_holiday) = &overtime.public_holiday {
+        overtime_rates(path, "overtime.public_holiday", &public_holiday.rates)?;
+    }
+    let weekend_tiers = &overtime.weekend.tiers;
+    if overtime.weekend.tiered && weekend_tiers.is_empty() {
+        return Err(invalid(
+            path,
+            "overtime.weekend.tiers".to_string(),
+            "at least one tier is required when weekend overtime is tiered".to_string(),
+        ));
+    }
+    if let Some(first) = weekend_tiers.first()
+        && !first.threshold_hours.is_zero()
+    {
+        return Err(invalid(
+            path,
+            "overtime.weekend.tiers[0].threshold_hours".to_string(),
+            format!(
+                "the first tier must start at 0 hours (found {})",
+                first.threshold_hours
+            ),
+        ));
+    }
+    for (i, pair) in weekend_tiers.windows(2).enumerate() {
+        if pair[1].threshold_hours <= pair[0].threshold_hours {
+            return Err(invalid(
+                path,
+                format!("overtime.weekend.tiers[{}].threshold_hours", i + 1),
+                format!(
+                    "tiers must be in ascending threshold order (found {} after {})",
+                    pair[1].threshold_hours, pair[0].threshold_hours
+                ),
+            ));
+        }
+    }
+    for (i, tier) in weekend_tiers.iter().enumerate() {
+        overtime_rates(
+            path,
+            &format!("overtime.weekend.tiers[{}].saturday", i),
+            &tier.saturday,
+        )?;
+        overtime_rates(
+            path,
+            &format!("overtime.weekend.tiers[{}].sunday", i),
+            &tier.sunday,
+        )?;
+    }
+
+    let mut days: Vec<_> = overtime.daily_thresholds.iter().collect();
+    days.sort_by_key(|(day, _)| day.num_days_from_monday());
+    for (day, threshold) in days {
+        positive(
+            path,
+            format!(
+                "overtime.daily_thresholds.{}",
+                day.to_string().to_lowercase()
+            ),
+            *threshold,
+        )?;
+    }
+
+    if let Some(consecutive_days) = &penalties.consecutive_days {
+        penalty_rates(path, "consecutive_days", &consecutive_days.penalty)?;
+    }
+
+    if let Some(span_of_hours) = &penalties.span_of_hours {
+        positive(
+            path,
+            "span_of_hours.max_span_hours".to_string(),
+            span_of_hours.max_span_hours,
+        )?;
+        positive(
+            path,
+            "span_of_hours.penalty_rate".to_string(),
+            span_of_hours.penalty_rate,
+        )?;
+    }
+
+    if let Some(early_commencement) = &penalties.early_commencement {
+        positive(
+            path,
+            "early_commencement.penalty_rate".to_string(),
+            early_commencement.penalty_rate,
+        )?;
+    }
+
+    if let Some(minimum_daily_pay) = &penalties.minimum_daily_pay {
+        positive(
+            path,
+            "minimum_daily_pay.minimum_daily_hours".to_string(),
+            minimum_daily_pay.minimum_daily_hours,
+        )?;
+    }
+
+    if penalties.laundry_min_shift_hours < Decimal::ZERO {
+        return Err(invalid(
+            path,
+            "laundry_min_shift_hours".to_string(),
+            format!(
+                "must not be negative (found {})",
+                penalties.laundry_min_shift_hours
+            ),
+        ));
+    }
+
+    if let Some(max_expected_gross) = penalties.max_expected_gross {
+        positive(path, "max_expected_gross".to_string(), max_expected_gross)?;
+    }
+
+    if let Some(minutes) = penalties.time_rounding.increment_minutes()
+        && !(1..=60).contains(&minutes)
+    {
+        return Err(invalid(
+            path,
+            "time_rounding".to_string(),
+            format!(
+                "increment must be between 1 and 60 minutes (found {})",
+                minutes
+            ),
+        ));
+    }
+
+    if penalties.max_audit_steps == Some(0) {
+        return Err(invalid(
+            path,
+            "max_audit_steps".to_string(),
+            "must be greater than zero (found 0)".to_string(),
+        ));
+    }
+
+    if let Some(leave_accrual) = &penalties.leave_accrual {
+        let rates = [
+            ("annual_leave", leave_accrual.annual_leave),
+            ("personal_leave", leave_accrual.personal_leave),
+        ];
+        for (leave, rate) in rates {
+            positive(
+                path,
+                format!("leave_accrual.{}.per_ordinary_hours", leave),
+                rate.per_ordinary_hours,
+            )?;
+            if rate.hours < Decimal::ZERO {
+                return Err(invalid(
+                    path,
+                    format!("leave_accrual.{}.hours", leave),
+                    format!("must not be negative (found {})", rate.hours),
+                ));
+            }
+        }
+        if let Some(in_lieu) = &leave_accrual.public_holiday_in_lieu {
+            positive(
+                path,
+                "leave_accrual.public_holiday_in_lieu.ordinary_hours".to_string(),
+                in_lieu.ordinary_hours,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn penalty_rates(path: &str, prefix: &str, rates: &PenaltyRates) -> EngineResult<()> {
+    multipliers(
+        path,
+        prefix,
+        [
+            ("full_time", Some(rates.full_time)),
+            ("part_time", Some(rates.part_time)),
+            ("casual", Some(rates.casual)),
+        ],
+    )
+}
+
+fn rate_overrides(path: &str, prefix: &str, rates: &PenaltyRateOverrides) -> EngineResult<()> {
+    multipliers(
+        path,
+        prefix,
+        [
+            ("full_time", rates.full_time),
+            ("part_time", rates.part_time),
+            ("casual", rates.casual),
+        ],
+    )
+}
+
+fn overtime_rates(path: &str, prefix: &str, rates: &OvertimeRates) -> EngineResult<()> {
+    multipliers(
+        path,
+        prefix,
+        [
+            ("full_time", Some(rates.full_time)),
+            ("part_time", Some(rates.part_time)),
+            ("casual", Some(rates.casual)),
+        ],
+    )
+}
+
+/// Checks that each configured multiplier is at least 1.0.
+fn multipliers(
+    path: &str,
+    prefix: &str,
+    multipliers: [(&str, Option<Decimal>); 3],
+) -> EngineResult<()> {
+    for (name, multiplier) in multipliers {
+        if let Some(multiplier) = multiplier
+            && multiplier < Decimal::ONE
+        {
+            return Err(invalid(
+                path,
+                format!("{}.{}", prefix, name),
+                format!("must be at least 1.0 (found {})", multiplier),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn positive(path: &str, field: String, value: Decimal) -> EngineResult<()> {
+    if value <= Decimal::ZERO {
+        return Err(invalid(
+            path,
+            field,
+            format!("must be greater than zero (found {})", value),
+        ));
+    }
+    Ok(())
+}
+
+fn invalid(path: &str, field: String, message: String) -> EngineError {
+    EngineError::InvalidConfig {
+        path: path.to_string(),
+        field,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        ConfigLoader, PublicHolidayInLieuConfig, TimeRounding, WeekendOvertimeTier,
+    };
+    use crate::models::PayCategory;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn load_config() -> ConfigLoader {
+        ConfigLoader::load("./config/ma000018").expect("Failed to load config")
+    }
+
+    fn invalid_field(result: EngineResult<()>) -> String {
+        match result {
+            Err(EngineError::InvalidConfig { field, .. }) => field,
+            other => panic!("expected InvalidConfig, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_shipped_config_is_valid() {
+        let config = load_config();
+        let config = config.config();
+
+        assert!(validate_penalty_config(config.penalties(), "penalties.yaml").is_ok());
+        for rates in config.rates() {
+            assert!(validate_rate_config(rates, "rates.yaml").is_ok());
+        }
+    }
+
+    #[test]
+    fn test_penalty_multiplier_below_one_is_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties.penalties.sunday.casual = dec("0.75");
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(invalid_field(result), "penalties.sunday.casual");
+    }
+
+    #[test]
+    fn test_overtime_tier_multiplier_below_one_is_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties.overtime.weekday.tiers[1].rates.part_time = dec("0.5");
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(invalid_field(result), "overtime.weekday.tiers[1].part_time");
+    }
+
+    #[test]
+    fn test_tiered_weekend_overtime_without_tiers_is_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties.overtime.weekend.tiered = true;
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(invalid_field(result), "overtime.weekend.tiers");
+    }
+
+    #[test]
+    fn test_weekend_overtime_tiers_must_ascend_from_zero() {
+        let mut penalties = load_config().config().penalties().clone();
+        let tier = |threshold: &str| WeekendOvertimeTier {
+            threshold_hours: dec(threshold),
+            category: PayCategory::Overtime200,
+            saturday: penalties.overtime.weekend.saturday.clone(),
+            sunday: penalties.overtime.weekend.sunday.clone(),
+        };
+        let tiers = vec![tier("0"), tier("2"), tier("2")];
+        penalties.overtime.weekend.tiered = true;
+
+        penalties.overtime.weekend.tiers = tiers[1..].to_vec();
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+        assert_eq!(
+            invalid_field(result),
+            "overtime.weekend.tiers[0].threshold_hours"
+        );
+
+        penalties.overtime.weekend.tiers = tiers;
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+        assert_eq!(
+            invalid_field(result),
+            "overtime.weekend.tiers[2].threshold_hours"
+        );
+    }
+
+    #[test]
+    fn test_zero_daily_threshold_is_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties
+            .overtime
+            .daily_thresholds
+            .insert(chrono::Weekday::Sat, Decimal::ZERO);
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(invalid_field(result), "overtime.daily_thresholds.sat");
+    }
+
+    #[test]
+    fn test_zero_leave_accrual_divisor_is_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties
+            .leave_accrual
+            .as_mut()
+            .unwrap()
+            .personal_leave
+            .per_ordinary_hours = Decimal::ZERO;
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(
+            invalid_field(result),
+            "leave_accrual.personal_leave.per_ordinary_hours"
+        );
+    }
+
+    #[test]
+    fn test_zero_day_in_lieu_hours_are_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties
+            .leave_accrual
+            .as_mut()
+            .unwrap()
+            .public_holiday_in_lieu = Some(PublicHolidayInLieuConfig {
+            clause: "24.3".to_string(),
+            ordinary_hours: Decimal::ZERO,
+        });
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(
+            invalid_field(result),
+            "leave_accrual.public_holiday_in_lieu.ordinary_hours"
+        );
+    }
+
+    #[test]
+    fn test_zero_max_expected_gross_is_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties.max_expected_gross = Some(Decimal::ZERO);
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(invalid_field(result), "max_expected_gross");
+    }
+
+    #[test]
+    fn test_zero_minute_rounding_increment_is_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties.time_rounding = TimeRounding::Up(0);
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(invalid_field(result), "time_rounding");
+    }
+
+    #[test]
+    fn test_rounding_increment_is_read_from_yaml() {
+        let penalties: PenaltyConfig = serde_yaml::from_str(
+            &std::fs::read_to_string("./config/ma000018/penalties.yaml")
+                .unwrap()
+                .replace("time_rounding: none", "time_rounding: nearest_7_minutes"),
+        )
+        .unwrap();
+
+        assert_eq!(penalties.time_rounding, TimeRounding::Nearest(7));
+    }
+
+    #[test]
+    fn test_legacy_rounding_names_read_as_the_same_rule_and_round_trip() {
+        for (legacy, rounding) in [
+            ("nearest_15", TimeRounding::Nearest(15)),
+            ("up_15", TimeRounding::Up(15)),
+            ("nearest_6_minutes", TimeRounding::Nearest(6)),
+        ] {
+            let parsed: TimeRounding = legacy.parse().unwrap();
+            assert_eq!(parsed, rounding);
+            assert_eq!(parsed, parsed.to_string().parse().unwrap());
+            let yaml = serde_yaml::to_string(&parsed).unwrap();
+            assert_eq!(
+                serde_yaml::from_str::<TimeRounding>(&yaml).unwrap(),
+                rounding
+            );
+        }
+        assert_eq!(
+            "nearest_15".parse::<TimeRounding>(),
+            "nearest_15_minutes".parse()
+        );
+    }
+
+    #[test]
+    fn test_zero_max_audit_steps_is_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties.max_audit_steps = Some(0);
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(invalid_field(result), "max_audit_steps");
+    }
+
+    #[test]
+    fn test_negative_laundry_min_shift_hours_are_rejected() {
+        let mut penalties = load_config().config().penalties().clone();
+        penalties.laundry_min_shift_hours = dec("-1");
+
+        let result = validate_penalty_config(&penalties, "penalties.yaml");
+
+        assert_eq!(invalid_field(result), "laundry_min_shift_hours");
+    }
+
+    #[test]
+    fn test_negative_allowance_is_rejected() {
+        let mut rates = load_config().config().rates()[0].clone();
+        rates.allowances.laundry_per_week = dec("-1.49");
+
+        let result = validate_rate_config(&rates, "rates.yaml");
+
+        assert_eq!(invalid_field(result), "allowances.laundry_per_week");
+    }
+}
@@ -4,6 +4,7 @@
 //! for all error conditions that can occur during award interpretation.
 
 use chrono::NaiveDate;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// The main error type for the Award Interpretation Engine.
@@ -37,6 +38,21 @@ pub enum EngineError {
         path: String,
         /// A description of the parse error.
         message: String,
+        /// The line the parse error was found on, if known.
+        line: Option<usize>,
+        /// The column the parse error was found at, if known.
+        column: Option<usize>,
+    },
+
+    /// A configuration value is outside the range the engine accepts.
+    #[error("Invalid configuration in '{path}': {field} {message}")]
+    InvalidConfig {
+        /// The path to the file containing the value.
+        path: String,
+        /// The path of the offending entry (e.g. `rates.dce_level_3.hourly`).
+        field: String,
+        /// What is wrong with the value.
+        message: String,
     },
 
     /// Classification code was not found in the configuration.
@@ -55,6 +71,16 @@ pub enum EngineError {
         date: NaiveDate,
     },
 
+    /// A classification has rates, but none is operative on the given date
+    /// because its earliest rate takes effect later.
+    #[error("No rate operative for classification '{classification}' on {date}")]
+    NoOperativeRate {
+        /// The classification code.
+        classification: String,
+        /// The date for which the rate was requested.
+        date: NaiveDate,
+    },
+
     /// A shift was invalid or contained inconsistent data.
     #[error("Invalid shift '{shift_id}': {message}")]
     InvalidShift {
@@ -64,6 +90,31 @@ pub enum EngineError {
         message: String,
     },
 
+    /// Two or more shifts in a request share an ID.
+    #[error("Duplicate shift IDs: {}", format_shift_ids(.shift_ids))]
+    DuplicateShiftIds {
+        /// Each ID used by more than one shift, in the order first repeated.
+        shift_ids: Vec<String>,
+    },
+
+    /// A break within a shift was invalid.
+    #[error("Invalid break {break_index} in shift '{shift_id}': {message}")]
+    InvalidBreak {
+        /// The ID of the shift containing the break.
+        shift_id: String,
+        /// The zero-based position of the break in the shift's breaks.
+        break_index: usize,
+        /// A description of what made the break invalid.
+        message: String,
+    },
+
+    /// A request that requires shifts has none.
+    #[error("No shifts to calculate for employee '{employee_id}'")]
+    NoShifts {
+        /// The ID of the employee.
+        employee_id: String,
+    },
+
     /// An employee record was invalid or contained inconsistent data.
     #[error("Invalid employee field '{field}': {message}")]
     InvalidEmployee {
@@ -73,6 +124,15 @@ pub enum EngineError {
         message: String,
     },
 
+    /// An employee's base hourly rate override was invalid.
+    #[error("Invalid base hourly rate for employee '{employee_id}': {rate} must not be negative")]
+    InvalidBaseRate {
+        /// The ID of the employee.
+        employee_id: String,
+        /// The override rate that was rejected.
+        rate: Decimal,
+    },
+
     /// A general calculation error occurred.
     #[error("Calculation error: {message}")]
     CalculationError {
@@ -84,6 +144,15 @@ pub enum EngineError {
 /// A type alias for Results that return EngineError.
 pub type EngineResult<T> = Result<T, EngineError>;
 
+/// Formats shift IDs as a quoted, comma-separated list.
+fn format_shift_ids(shift_ids: &[String]) -> String {
+    shift_ids
+        .iter()
+        .map(|id| format!("'{}'", id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +181,8 @@ mod tests {
         let error = EngineError::ConfigParseError {
             path: "/config/bad.yaml".to_string(),
             message: "invalid YAML syntax".to_string(),
+            line: Some(3),
+            column: Some(1),
         };
         assert_eq!(
             error.to_string(),
@@ -119,6 +190,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_config_displays_path_and_entry() {
+        let error = EngineError::InvalidConfig {
+            path: "/config/rates/2025-07-01.yaml".to_string(),
+            field: "rates.dce_level_3.hourly".to_string(),
+            message: "must be greater than zero (found 0)".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Invalid configuration in '/config/rates/2025-07-01.yaml': rates.dce_level_3.hourly must be greater than zero (found 0)"
+        );
+    }
+
     #[test]
     fn test_rate_not_found_displays_classification_and_date() {
         let error = EngineError::RateNotFound {
@@ -131,6 +215,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_no_operative_rate_displays_classification_and_date() {
+        let error = EngineError::NoOperativeRate {
+            classification: "dce_level_4".to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "No rate operative for classification 'dce_level_4' on 2026-01-13"
+        );
+    }
+
+    #[test]
+    fn test_invalid_break_displays_shift_break_and_message() {
+        let error = EngineError::InvalidBreak {
+            shift_id: "shift_001".to_string(),
+            break_index: 1,
+            message: "overlaps break 0".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Invalid break 1 in shift 'shift_001': overlaps break 0"
+        );
+    }
+
     #[test]
     fn test_invalid_shift_displays_id_and_message() {
         let error = EngineError::InvalidShift {
@@ -143,6 +252,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duplicate_shift_ids_displays_each_id() {
+        let error = EngineError::DuplicateShiftIds {
+            shift_ids: vec!["shift_001".to_string(), "shift_004".to_string()],
+        };
+        assert_eq!(
+            error.to_string(),
+            "Duplicate shift IDs: 'shift_001', 'shift_004'"
+        );
+    }
+
+    #[test]
+    fn test_no_shifts_displays_employee() {
+        let error = EngineError::NoShifts {
+            employee_id: "emp_001".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "No shifts to calculate for employee 'emp_001'"
+        );
+    }
+
     #[test]
     fn test_invalid_employee_displays_field_and_message() {
         let error = EngineError::InvalidEmployee {
@@ -155,6 +286,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_base_rate_displays_employee_and_rate() {
+        let error = EngineError::InvalidBaseRate {
+            employee_id: "emp_001".to_string(),
+            rate: Decimal::new(-2854, 2),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Invalid base hourly rate for employee 'emp_001': -28.54 must not be negative"
+        );
+    }
+
     #[test]
     fn test_calculation_error_displays_message() {
         let error = EngineError::CalculationError {
@@ -9,12 +9,17 @@
 //!
 //! ```no_run
 //! use award_engine::api::{create_router, AppState};
-//! use award_engine::config::ConfigLoader;
 //!
 //! #[tokio::main]
 //! async fn main() {
-//!     let config = ConfigLoader::load("./config/ma000018").expect("Failed to load config");
-//!     let state = AppState::new(config);
+//!     // A missing or malformed config file is reported rather than panicking
+//!     let state = match AppState::from_config_path("./config/ma000018") {
+//!         Ok(state) => state,
+//!         Err(err) => {
+//!             eprintln!("Failed to load award configuration: {}", err);
+//!             std::process::exit(1);
+//!         }
+//!     };
 //!     let router = create_router(state);
 //!
 //!     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
@@ -4,12 +4,14 @@
 //! that capture all outputs from a pay calculation, including pay lines, allowances,
 //! totals, and audit traces.
 
+use std::collections::{BTreeMap, BTreeSet};
+
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::PayPeriod;
+use super::{Employee, PayPeriod, Shift};
 
 /// Represents the category of pay for a pay line.
 ///
@@ -21,8 +23,9 @@ use super::PayPeriod;
 /// ```
 /// use award_engine::models::PayCategory;
 ///
-/// let category = PayCategory::Ordinary;
-/// assert_eq!(format!("{:?}", category), "Ordinary");
+/// let category = PayCategory::Overtime150;
+/// assert_eq!(category.to_string(), "Overtime (150%)");
+/// assert_eq!(serde_json::to_string(&category).unwrap(), "\"overtime150\"");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -43,6 +46,98 @@ pub enum PayCategory {
     Overtime150,
     /// Overtime at 200% rate.
     Overtime200,
+    /// Overtime at 250% rate, for awards with a third overtime tier.
+    Overtime250,
+    /// Overtime on a public holiday, at the configured public holiday rate.
+    PublicHolidayOvertime,
+    /// Ordinary hours paid for a public holiday not worked (clause 24).
+    PublicHolidayNotWorked,
+    /// Penalty for hours worked past the point a meal break was due.
+    MealBreakPenalty,
+    /// Penalty for the hours of a day's span beyond the configured limit.
+    SpanPenalty,
+    /// Ordinary hours paid to top a day up to the configured minimum daily pay.
+    MinimumDailyPay,
+    /// Loading for the hours of a shift commencing before the configured
+    /// early hour.
+    EarlyCommencementPenalty,
+}
+
+impl PayCategory {
+    /// Returns the position of this category in payslip order.
+    ///
+    /// Ordinary time comes first, then weekend penalties, then overtime.
+    /// Pay lines on the same date are sorted by this ordinal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::models::PayCategory;
+    ///
+    /// assert!(PayCategory::Ordinary.ordinal() < PayCategory::Saturday.ordinal());
+    /// assert!(PayCategory::SundayCasual.ordinal() < PayCategory::Overtime150.ordinal());
+    /// ```
+    pub fn ordinal(&self) -> u8 {
+        match self {
+            PayCategory::Ordinary => 0,
+            PayCategory::OrdinaryCasual => 1,
+            PayCategory::Saturday => 2,
+            PayCategory::SaturdayCasual => 3,
+            PayCategory::Sunday => 4,
+            PayCategory::SundayCasual => 5,
+            PayCategory::Overtime150 => 6,
+            PayCategory::Overtime200 => 7,
+            PayCategory::Overtime250 => 8,
+            PayCategory::PublicHolidayOvertime => 9,
+            PayCategory::PublicHolidayNotWorked => 10,
+            PayCategory::MealBreakPenalty => 11,
+            PayCategory::SpanPenalty => 12,
+            PayCategory::MinimumDailyPay => 13,
+            PayCategory::EarlyCommencementPenalty => 14,
+        }
+    }
+}
+
+/// Categories are ordered by their [`ordinal`](PayCategory::ordinal), so
+/// collections keyed by category list them in payslip order.
+impl Ord for PayCategory {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.ordinal().cmp(&other.ordinal())
+    }
+}
+
+impl PartialOrd for PayCategory {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Formats the category as a human-readable label for payslips and audit
+/// reasoning.
+///
+/// Labels are stable; use the serialized snake_case name when a
+/// machine-readable identifier is needed.
+impl std::fmt::Display for PayCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            PayCategory::Ordinary => "Ordinary Hours",
+            PayCategory::OrdinaryCasual => "Ordinary Hours (Casual)",
+            PayCategory::Saturday => "Saturday Penalty",
+            PayCategory::SaturdayCasual => "Saturday Penalty (Casual)",
+            PayCategory::Sunday => "Sunday Penalty",
+            PayCategory::SundayCasual => "Sunday Penalty (Casual)",
+            PayCategory::Overtime150 => "Overtime (150%)",
+            PayCategory::Overtime200 => "Overtime (200%)",
+            PayCategory::Overtime250 => "Overtime (250%)",
+            PayCategory::PublicHolidayOvertime => "Public Holiday Overtime",
+            PayCategory::PublicHolidayNotWorked => "Public Holiday Not Worked",
+            PayCategory::MealBreakPenalty => "Meal Break Penalty",
+            PayCategory::SpanPenalty => "Span Penalty",
+            PayCategory::MinimumDailyPay => "Minimum Daily Pay",
+            PayCategory::EarlyCommencementPenalty => "Early Commencement Penalty",
+        };
+        f.write_str(label)
+    }
 }
 
 /// Represents a single line item in a pay calculation.
@@ -66,13 +161,16 @@ pub enum PayCategory {
 ///     rate: Decimal::from_str("28.54").unwrap(),
 ///     amount: Decimal::from_str("228.32").unwrap(),
 ///     clause_ref: "14.2".to_string(),
+///     reference: None,
+///     cost_centre: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PayLine {
     /// The date this pay line applies to.
     pub date: NaiveDate,
-    /// The ID of the shift this pay line originated from.
+    /// The ID of the shift this pay line originated from (empty for a public
+    /// holiday not worked).
     pub shift_id: String,
     /// The category of pay (e.g., Ordinary, Overtime150).
     pub category: PayCategory,
@@ -84,6 +182,171 @@ pub struct PayLine {
     pub amount: Decimal,
     /// Reference to the award clause that justifies this pay line.
     pub clause_ref: String,
+    /// The payroll note or reference of the shift this pay line originated from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// The cost centre of the shift this pay line originated from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_centre: Option<String>,
+}
+
+/// One tier of a day's overtime on a shift, with the threshold and multiplier
+/// behind its pay line.
+///
+/// Each overtime pay line has a matching tier, so the tier structure can be
+/// audited without decoding pay categories.
+///
+/// # Example
+///
+/// ```
+/// use award_engine::models::{OvertimeTier, PayCategory, PayLine};
+/// use chrono::NaiveDate;
+/// use rust_decimal::Decimal;
+///
+/// let pay_line = PayLine {
+///     date: NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+///     shift_id: "shift_001".to_string(),
+///     category: PayCategory::Overtime200,
+///     hours: Decimal::ONE,
+///     rate: Decimal::new(5708, 2),
+///     amount: Decimal::new(5708, 2),
+///     clause_ref: "25.1(a)(i)(A)".to_string(),
+///     reference: None,
+///     cost_centre: None,
+/// };
+///
+/// let tier = OvertimeTier::from_pay_line(&pay_line, 2, Decimal::from(2), Decimal::from(2));
+///
+/// assert_eq!(tier.tier, 2);
+/// assert_eq!(tier.threshold_hours, Decimal::from(2));
+/// assert_eq!(tier.amount, pay_line.amount);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OvertimeTier {
+    /// The date the tier's hours were worked.
+    pub date: NaiveDate,
+    /// The ID of the shift the overtime was worked in.
+    pub shift_id: String,
+    /// The tier's position among the day's overtime tiers, starting at 1.
+    pub tier: u32,
+    /// The category of the tier's pay line (e.g., Overtime150).
+    pub category: PayCategory,
+    /// The hours of the day's overtime worked before this tier applies (zero
+    /// for the first tier).
+    pub threshold_hours: Decimal,
+    /// The overtime hours paid in this tier.
+    pub hours: Decimal,
+    /// The multiplier of the base rate paid for this tier.
+    pub multiplier: Decimal,
+    /// The hourly rate for this tier.
+    pub rate: Decimal,
+    /// The total amount for this tier (hours * rate).
+    pub amount: Decimal,
+    /// Reference to the award clause that sets this tier.
+    pub clause_ref: String,
+}
+
+impl OvertimeTier {
+    /// Describes the tier paid by an overtime pay line.
+    ///
+    /// The date, shift, category, hours, rate, amount and clause are taken
+    /// from the pay line.
+    pub fn from_pay_line(
+        pay_line: &PayLine,
+        tier: u32,
+        threshold_hours: Decimal,
+        multiplier: Decimal,
+    ) -> Self {
+        Self {
+            date: pay_line.date,
+            shift_id: pay_line.shift_id.clone(),
+            tier,
+            category: pay_line.category,
+            threshold_hours,
+            hours: pay_line.hours,
+            multiplier,
+            rate: pay_line.rate,
+            amount: pay_line.amount,
+            clause_ref: pay_line.clause_ref.clone(),
+        }
+    }
+}
+
+/// The type of an allowance payment.
+///
+/// Serialized as its snake_case name (e.g. `"first_aid"`). Names the engine
+/// does not pay itself, such as allowances from another system, are kept as
+/// [`AllowanceType::Custom`].
+///
+/// # Example
+///
+/// ```
+/// use award_engine::models::AllowanceType;
+///
+/// assert_eq!(serde_json::to_string(&AllowanceType::FirstAid).unwrap(), "\"first_aid\"");
+/// assert_eq!(
+///     serde_json::from_str::<AllowanceType>("\"meal\"").unwrap(),
+///     AllowanceType::Custom("meal".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum AllowanceType {
+    /// Laundry allowance (clause 15.2(b)).
+    Laundry,
+    /// Qualification allowance.
+    Qualification,
+    /// First aid allowance.
+    FirstAid,
+    /// Broken shift allowance.
+    BrokenShift,
+    /// Split shift travel allowance.
+    SplitShiftTravel,
+    /// Any other allowance, by name. Names of the variants above are always
+    /// read as those variants.
+    Custom(String),
+}
+
+impl AllowanceType {
+    /// Returns the snake_case name of the allowance type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            AllowanceType::Laundry => "laundry",
+            AllowanceType::Qualification => "qualification",
+            AllowanceType::FirstAid => "first_aid",
+            AllowanceType::BrokenShift => "broken_shift",
+            AllowanceType::SplitShiftTravel => "split_shift_travel",
+            AllowanceType::Custom(name) => name,
+        }
+    }
+}
+
+impl From<String> for AllowanceType {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "laundry" => AllowanceType::Laundry,
+            "qualification" => AllowanceType::Qualification,
+            "first_aid" => AllowanceType::FirstAid,
+            "broken_shift" => AllowanceType::BrokenShift,
+            "split_shift_travel" => AllowanceType::SplitShiftTravel,
+            _ => AllowanceType::Custom(name),
+        }
+    }
+}
+
+impl From<AllowanceType> for String {
+    fn from(allowance_type: AllowanceType) -> Self {
+        match allowance_type {
+            AllowanceType::Custom(name) => name,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for AllowanceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Represents an allowance payment.
@@ -94,12 +357,12 @@ pub struct PayLine {
 /// # Example
 ///
 /// ```
-/// use award_engine::models::AllowancePayment;
+/// use award_engine::models::{AllowancePayment, AllowanceType};
 /// use rust_decimal::Decimal;
 /// use std::str::FromStr;
 ///
 /// let allowance = AllowancePayment {
-///     allowance_type: "laundry".to_string(),
+///     allowance_type: AllowanceType::Laundry,
 ///     description: "Laundry allowance for uniform cleaning".to_string(),
 ///     units: Decimal::from_str("5.0").unwrap(),
 ///     rate: Decimal::from_str("0.32").unwrap(),
@@ -109,9 +372,9 @@ pub struct PayLine {
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AllowancePayment {
-    /// The type of allowance (e.g., "laundry", "travel", "meal").
+    /// The type of allowance.
     #[serde(rename = "type")]
-    pub allowance_type: String,
+    pub allowance_type: AllowanceType,
     /// A description of the allowance.
     pub description: String,
     /// The number of units (e.g., shifts, kilometers).
@@ -141,7 +404,10 @@ pub struct AllowancePayment {
 ///     ordinary_hours: Decimal::from_str("38.0").unwrap(),
 ///     overtime_hours: Decimal::from_str("4.0").unwrap(),
 ///     penalty_hours: Decimal::from_str("8.0").unwrap(),
+///     total_hours_worked: Decimal::from_str("50.0").unwrap(),
 ///     allowances_total: Decimal::from_str("5.60").unwrap(),
+///     allowances_by_type: Default::default(),
+///     gross_by_category: Default::default(),
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -154,8 +420,147 @@ pub struct PayTotals {
     pub overtime_hours: Decimal,
     /// Total penalty hours worked (weekend/holiday).
     pub penalty_hours: Decimal,
+    /// Total hours worked across every pay category, so clients need not sum
+    /// the per-category totals themselves. Public holidays not worked and meal
+    /// break penalties pay for no time worked, so they are left out.
+    #[serde(default)]
+    pub total_hours_worked: Decimal,
     /// Total value of all allowances.
     pub allowances_total: Decimal,
+    /// Total value of allowances by allowance type (e.g. "laundry").
+    #[serde(default)]
+    pub allowances_by_type: BTreeMap<String, Decimal>,
+    /// Total value of pay lines by pay category, in payslip order. Together
+    /// with `allowances_total` these add up to `gross_pay`.
+    #[serde(default)]
+    pub gross_by_category: BTreeMap<PayCategory, Decimal>,
+}
+
+/// Leave accrued for the ordinary hours paid in a pay period, and any days in
+/// lieu granted for public holidays.
+///
+/// # Example
+///
+/// ```
+/// use award_engine::models::LeaveAccruals;
+/// use rust_decimal::Decimal;
+///
+/// let accruals = LeaveAccruals {
+///     accrual_hours: Decimal::from(26),
+///     annual_leave_hours: Decimal::from(2),
+///     personal_leave_hours: Decimal::ONE,
+///     days_in_lieu: vec![],
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaveAccruals {
+    /// The hours paid that leave accrues on: `totals.ordinary_hours` plus the
+    /// Saturday, Sunday and public holiday not worked hours, but not
+    /// overtime. Weekend hours are ordinary hours for leave, so this is more
+    /// than `totals.ordinary_hours` whenever weekend hours are paid.
+    pub accrual_hours: Decimal,
+    /// Annual leave accrued, in hours (zero for casual employees).
+    pub annual_leave_hours: Decimal,
+    /// Personal leave accrued, in hours (zero for casual employees).
+    pub personal_leave_hours: Decimal,
+    /// Days in lieu granted for public holidays on rostered days off.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub days_in_lieu: Vec<DayInLieu>,
+}
+
+/// A day in lieu granted for a public holiday that fell on a rostered day off.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayInLieu {
+    /// The date of the public holiday.
+    pub date: NaiveDate,
+    /// The name of the public holiday.
+    pub name: String,
+    /// The hours of the day in lieu.
+    pub hours: Decimal,
+}
+
+/// The inputs a calculation was performed on, as the engine interpreted them.
+///
+/// These are the request's inputs after validation and normalization: shift
+/// end times derived from durations, public holidays merged from the state
+/// calendar and the resolved pay period type. Any timezone or rounding
+/// adjustment to a shift's hours is recorded in the audit trace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalculationInput {
+    /// The employee the calculation is for.
+    pub employee: Employee,
+    /// The pay period, with the public holidays the calculation used.
+    pub pay_period: PayPeriod,
+    /// The shifts, in the order they were given.
+    pub shifts: Vec<InterpretedShift>,
+}
+
+/// A shift as the engine interpreted it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterpretedShift {
+    /// The shift, with its end time resolved.
+    #[serde(flatten)]
+    pub shift: Shift,
+    /// The shift's [`worked_hours`](Shift::worked_hours): its length less
+    /// unpaid breaks, before any timezone or rounding adjustment.
+    pub worked_hours: Decimal,
+}
+
+impl From<Shift> for InterpretedShift {
+    fn from(shift: Shift) -> Self {
+        Self {
+            worked_hours: shift.worked_hours(),
+            shift,
+        }
+    }
+}
+
+/// A rule the engine supports that a calculation did not apply.
+///
+/// # Example
+///
+/// ```
+/// use award_engine::models::UnappliedRule;
+///
+/// let rule = UnappliedRule {
+///     rule_id: "sunday_penalty".to_string(),
+///     rule_name: "Sunday Penalty Rate".to_string(),
+///     clause_ref: "23.1, 23.2(b)".to_string(),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnappliedRule {
+    /// The rule ID audit steps record when the rule applies.
+    pub rule_id: String,
+    /// The human-readable name of the rule.
+    pub rule_name: String,
+    /// The award clause the rule implements, or "configured" when the clause
+    /// is taken from the award configuration.
+    pub clause_ref: String,
+}
+
+/// The pay lines and subtotal contributed by a single shift.
+///
+/// # Example
+///
+/// ```
+/// use award_engine::models::ShiftResult;
+/// use rust_decimal::Decimal;
+///
+/// let shift_result = ShiftResult {
+///     shift_id: "shift_001".to_string(),
+///     pay_lines: vec![],
+///     subtotal: Decimal::ZERO,
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShiftResult {
+    /// The ID of the shift.
+    pub shift_id: String,
+    /// The pay lines originating from this shift.
+    pub pay_lines: Vec<PayLine>,
+    /// The sum of this shift's pay line amounts (allowances excluded).
+    pub subtotal: Decimal,
 }
 
 /// A single step in the audit trace recording a calculation decision.
@@ -219,6 +624,90 @@ pub struct AuditTrace {
     pub duration_us: u64,
 }
 
+/// Rule ID of the step closing an audit trace that was cut short by
+/// [`AuditTrace::truncate_steps`].
+pub const AUDIT_TRUNCATED_RULE_ID: &str = "audit_truncated";
+
+impl AuditTrace {
+    /// Keeps at most `max_steps` steps, noting how many were left out.
+    ///
+    /// When the trace has more than `max_steps` steps, the remainder are
+    /// dropped and a final [`AUDIT_TRUNCATED_RULE_ID`] step records how many
+    /// were omitted, so the trace holds `max_steps + 1` steps. Shorter traces
+    /// are left unchanged. Only the trace is affected: pay lines and totals are
+    /// calculated before it is truncated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::models::{AUDIT_TRUNCATED_RULE_ID, AuditStep, AuditTrace};
+    ///
+    /// let step = |step_number| AuditStep {
+    ///     step_number,
+    ///     rule_id: "base_rate_lookup".to_string(),
+    ///     rule_name: "Base Rate Lookup".to_string(),
+    ///     clause_ref: "14.2".to_string(),
+    ///     input: serde_json::json!({}),
+    ///     output: serde_json::json!({}),
+    ///     reasoning: String::new(),
+    /// };
+    /// let mut trace = AuditTrace {
+    ///     steps: (1..=5).map(step).collect(),
+    ///     warnings: vec![],
+    ///     duration_us: 0,
+    /// };
+    ///
+    /// trace.truncate_steps(2);
+    ///
+    /// assert_eq!(trace.steps.len(), 3);
+    /// assert_eq!(trace.steps[2].rule_id, AUDIT_TRUNCATED_RULE_ID);
+    /// assert_eq!(trace.steps[2].step_number, 3);
+    /// assert_eq!(trace.steps[2].output["omitted_steps"], 3);
+    /// ```
+    pub fn truncate_steps(&mut self, max_steps: usize) {
+        let total_steps = self.steps.len();
+        if total_steps <= max_steps {
+            return;
+        }
+        self.steps.truncate(max_steps);
+        let omitted_steps = total_steps - max_steps;
+        let step_number = self.steps.last().map_or(1, |step| step.step_number + 1);
+        self.steps.push(AuditStep {
+            step_number,
+            rule_id: AUDIT_TRUNCATED_RULE_ID.to_string(),
+            rule_name: "Audit Trace Truncated".to_string(),
+            clause_ref: "N/A".to_string(),
+            input: serde_json::json!({
+                "max_audit_steps": max_steps,
+                "total_steps": total_steps
+            }),
+            output: serde_json::json!({
+                "omitted_steps": omitted_steps
+            }),
+            reasoning: format!(
+                "The audit trace had {} steps; the {} after the first {} were omitted",
+                total_steps, omitted_steps, max_steps
+            ),
+        });
+    }
+}
+
+/// The version of the [`CalculationResult`] JSON shape.
+///
+/// This is versioned independently of the engine so clients can branch on
+/// the structure they receive. It follows semver:
+///
+/// - **major**: a field is removed or renamed, or its type or meaning changes
+/// - **minor**: a field, pay category or other enum value is added; clients
+///   should ignore fields and values they do not recognise
+/// - **patch**: documentation-only clarifications of existing fields
+///
+/// Engine releases that do not change the response shape leave it unchanged.
+pub const RESULT_SCHEMA_VERSION: &str = "3.0.0";
+
+/// The number of cents in a dollar, for results with amounts in cents.
+pub const CENTS_PER_DOLLAR: Decimal = Decimal::ONE_HUNDRED;
+
 /// The complete result of a pay calculation.
 ///
 /// This struct captures all outputs from the award interpretation engine,
@@ -237,21 +726,35 @@ pub struct AuditTrace {
 ///     calculation_id: Uuid::new_v4(),
 ///     timestamp: Utc::now(),
 ///     engine_version: "1.0.0".to_string(),
+///     schema_version: "1.0.0".to_string(),
+///     build_metadata: None,
+///     correlation_id: None,
 ///     employee_id: "emp_001".to_string(),
 ///     pay_period: PayPeriod {
 ///         start_date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
 ///         end_date: NaiveDate::from_ymd_opt(2026, 1, 26).unwrap(),
 ///         public_holidays: vec![],
+///         period_type: None,
 ///     },
 ///     pay_lines: vec![],
+///     shift_breakdown: vec![],
+///     overtime_detail: vec![],
 ///     allowances: vec![],
+///     applied_clauses: vec![],
+///     unapplied_rules: None,
 ///     totals: PayTotals {
 ///         gross_pay: Decimal::ZERO,
 ///         ordinary_hours: Decimal::ZERO,
 ///         overtime_hours: Decimal::ZERO,
 ///         penalty_hours: Decimal::ZERO,
+///         total_hours_worked: Decimal::ZERO,
 ///         allowances_total: Decimal::ZERO,
+///         allowances_by_type: Default::default(),
+///         gross_by_category: Default::default(),
 ///     },
+///     accruals: None,
+///     input: None,
+///     estimate: false,
 ///     audit_trace: AuditTrace {
 ///         steps: vec![],
 ///         warnings: vec![],
@@ -267,20 +770,193 @@ pub struct CalculationResult {
     pub timestamp: DateTime<Utc>,
     /// The version of the engine that performed the calculation.
     pub engine_version: String,
+    /// The version of this result's JSON shape (see [`RESULT_SCHEMA_VERSION`]).
+    ///
+    /// Empty when deserializing a result produced before schema versioning.
+    #[serde(default)]
+    pub schema_version: String,
+    /// Optional build metadata (e.g. a commit hash or deployment tag).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_metadata: Option<String>,
+    /// The correlation ID of the API request that produced this result (see
+    /// the `X-Correlation-Id` header); absent outside the HTTP API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
     /// The ID of the employee the calculation is for.
     pub employee_id: String,
     /// The pay period for this calculation.
     pub pay_period: PayPeriod,
     /// Individual pay lines making up the calculation.
     pub pay_lines: Vec<PayLine>,
+    /// The pay lines grouped by shift, in shift start order.
+    #[serde(default)]
+    pub shift_breakdown: Vec<ShiftResult>,
+    /// The tiers of each shift's overtime, one per overtime pay line, in the
+    /// order the shifts were calculated.
+    #[serde(default)]
+    pub overtime_detail: Vec<OvertimeTier>,
     /// Allowance payments included in the calculation.
     pub allowances: Vec<AllowancePayment>,
+    /// Every award clause referenced by a pay line or allowance, sorted and
+    /// deduplicated.
+    #[serde(default)]
+    pub applied_clauses: Vec<String>,
+    /// The rules the engine supports that this calculation did not apply;
+    /// only present when diagnostics were requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unapplied_rules: Option<Vec<UnappliedRule>>,
     /// Aggregated totals for the calculation.
     pub totals: PayTotals,
+    /// Leave accrued in the pay period; absent when leave accrual is not
+    /// configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accruals: Option<LeaveAccruals>,
+    /// The inputs as the engine interpreted them; only present when the
+    /// request set `echo_request`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<CalculationInput>,
+    /// Whether the result is an estimate: at least one shift was given as a
+    /// start and `expected_hours` rather than its actual times.
+    #[serde(default)]
+    pub estimate: bool,
     /// Complete audit trace of calculation decisions.
     pub audit_trace: AuditTrace,
 }
 
+impl CalculationResult {
+    /// Collects the award clauses referenced by this result.
+    ///
+    /// Clause references are gathered from the pay lines and allowances, then
+    /// sorted and deduplicated. The audit steps are not read, so the clauses
+    /// are the same whether or not the audit trace was built.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::models::{
+    ///     AllowancePayment, AllowanceType, AuditTrace, CalculationResult, PayPeriod, PayTotals,
+    /// };
+    /// use chrono::{NaiveDate, Utc};
+    /// use rust_decimal::Decimal;
+    /// use uuid::Uuid;
+    ///
+    /// let allowance = AllowancePayment {
+    ///     allowance_type: AllowanceType::Laundry,
+    ///     description: "Laundry allowance".to_string(),
+    ///     units: Decimal::ONE,
+    ///     rate: Decimal::new(32, 2),
+    ///     amount: Decimal::new(32, 2),
+    ///     clause_ref: "15.2(b)".to_string(),
+    /// };
+    /// let result = CalculationResult {
+    ///     calculation_id: Uuid::new_v4(),
+    ///     timestamp: Utc::now(),
+    ///     engine_version: "1.0.0".to_string(),
+    ///     schema_version: "1.0.0".to_string(),
+    ///     build_metadata: None,
+    ///     correlation_id: None,
+    ///     employee_id: "emp_001".to_string(),
+    ///     pay_period: PayPeriod {
+    ///         start_date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
+    ///         end_date: NaiveDate::from_ymd_opt(2026, 1, 26).unwrap(),
+    ///         public_holidays: vec![],
+    ///         period_type: None,
+    ///     },
+    ///     pay_lines: vec![],
+    ///     shift_breakdown: vec![],
+    ///     overtime_detail: vec![],
+    ///     allowances: vec![allowance.clone(), allowance],
+    ///     applied_clauses: vec![],
+    ///     unapplied_rules: None,
+    ///     totals: PayTotals {
+    ///         gross_pay: Decimal::ZERO,
+    ///         ordinary_hours: Decimal::ZERO,
+    ///         overtime_hours: Decimal::ZERO,
+    ///         penalty_hours: Decimal::ZERO,
+    ///         total_hours_worked: Decimal::ZERO,
+    ///         allowances_total: Decimal::ZERO,
+    ///         allowances_by_type: Default::default(),
+    ///         gross_by_category: Default::default(),
+    ///     },
+    ///     accruals: None,
+    ///     input: None,
+    ///     estimate: false,
+    ///     audit_trace: AuditTrace {
+    ///         steps: vec![],
+    ///         warnings: vec![],
+    ///         duration_us: 0,
+    ///     },
+    /// };
+    ///
+    /// assert_eq!(result.collect_applied_clauses(), vec!["15.2(b)".to_string()]);
+    /// ```
+    pub fn collect_applied_clauses(&self) -> Vec<String> {
+        let pay_line_clauses = self.pay_lines.iter().map(|pl| &pl.clause_ref);
+        let allowance_clauses = self.allowances.iter().map(|a| &a.clause_ref);
+
+        pay_line_clauses
+            .chain(allowance_clauses)
+            .filter(|clause| !clause.is_empty() && clause.as_str() != "N/A")
+            .cloned()
+            .collect::<BTreeSet<String>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Converts every monetary amount in this result from dollars to cents.
+    ///
+    /// Pay line rates and amounts, shift subtotals, allowance rates and
+    /// amounts, and the `gross_pay`, `allowances_total`, `allowances_by_type`
+    /// and `gross_by_category` totals are multiplied by 100. Amounts are not
+    /// rounded, so a sub-cent amount keeps its fraction (e.g. $0.125 becomes
+    /// 12.5 cents) and the totals still add up. Hours and the audit trace,
+    /// which records the calculation in dollars, are unchanged.
+    pub fn convert_amounts_to_cents(&mut self) {
+        self.scale_amounts(|amount| (amount * CENTS_PER_DOLLAR).normalize());
+    }
+
+    /// Converts every monetary amount in this result from cents back to
+    /// dollars, reversing [`convert_amounts_to_cents`](Self::convert_amounts_to_cents).
+    pub fn convert_amounts_to_dollars(&mut self) {
+        self.scale_amounts(|amount| amount / CENTS_PER_DOLLAR);
+    }
+
+    fn scale_amounts(&mut self, scale: impl Fn(Decimal) -> Decimal) {
+        let scale_pay_line = |line: &mut PayLine| {
+            line.rate = scale(line.rate);
+            line.amount = scale(line.amount);
+        };
+        self.pay_lines.iter_mut().for_each(scale_pay_line);
+        for shift in &mut self.shift_breakdown {
+            shift.pay_lines.iter_mut().for_each(scale_pay_line);
+            shift.subtotal = scale(shift.subtotal);
+        }
+        for tier in &mut self.overtime_detail {
+            tier.rate = scale(tier.rate);
+            tier.amount = scale(tier.amount);
+        }
+        for allowance in &mut self.allowances {
+            allowance.rate = scale(allowance.rate);
+            allowance.amount = scale(allowance.amount);
+        }
+        let totals = &mut self.totals;
+        totals.gross_pay = scale(totals.gross_pay);
+        totals.allowances_total = scale(totals.allowances_total);
+        totals
+            .allowances_by_type
+            .values_mut()
+            .chain(totals.gross_by_category.values_mut())
+            .for_each(|amount| *amount = scale(*amount));
+        if let Some(input) = &mut self.input {
+            let employee = &mut input.employee;
+            employee.base_hourly_rate = employee.base_hourly_rate.map(&scale);
+            for interpreted in &mut input.shifts {
+                interpreted.shift.rate_override = interpreted.shift.rate_override.map(&scale);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,6 +972,7 @@ mod tests {
             start_date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
             end_date: NaiveDate::from_ymd_opt(2026, 1, 26).unwrap(),
             public_holidays: vec![],
+            period_type: None,
         }
     }
 
@@ -308,12 +985,14 @@ mod tests {
             rate: dec("28.54"),
             amount,
             clause_ref: "14.2".to_string(),
+            reference: None,
+            cost_centre: None,
         }
     }
 
     fn create_sample_allowance(amount: Decimal) -> AllowancePayment {
         AllowancePayment {
-            allowance_type: "laundry".to_string(),
+            allowance_type: AllowanceType::Laundry,
             description: "Laundry allowance".to_string(),
             units: dec("5.0"),
             rate: dec("0.32"),
@@ -346,17 +1025,30 @@ mod tests {
             calculation_id: Uuid::new_v4(),
             timestamp: Utc::now(),
             engine_version: "1.0.0".to_string(),
+            schema_version: "1.0.0".to_string(),
+            build_metadata: None,
+            correlation_id: None,
             employee_id: "emp_001".to_string(),
             pay_period: create_sample_pay_period(),
             pay_lines,
+            shift_breakdown: vec![],
+            overtime_detail: vec![],
             allowances: vec![],
+            applied_clauses: vec![],
+            unapplied_rules: None,
             totals: PayTotals {
                 gross_pay: dec("225.50"),
                 ordinary_hours: dec("24.0"),
                 overtime_hours: dec("0"),
                 penalty_hours: dec("0"),
+                total_hours_worked: dec("24.0"),
                 allowances_total: dec("0"),
+                allowances_by_type: Default::default(),
+                gross_by_category: Default::default(),
             },
+            accruals: None,
+            input: None,
+            estimate: false,
             audit_trace: create_sample_audit_trace(),
         };
 
@@ -391,6 +1083,45 @@ mod tests {
         assert_eq!(category, PayCategory::Overtime200);
     }
 
+    #[test]
+    fn test_pay_category_ordinal_orders_ordinary_penalties_overtime() {
+        let categories = [
+            PayCategory::Ordinary,
+            PayCategory::OrdinaryCasual,
+            PayCategory::Saturday,
+            PayCategory::SaturdayCasual,
+            PayCategory::Sunday,
+            PayCategory::SundayCasual,
+            PayCategory::Overtime150,
+            PayCategory::Overtime200,
+            PayCategory::Overtime250,
+            PayCategory::PublicHolidayOvertime,
+            PayCategory::PublicHolidayNotWorked,
+            PayCategory::MealBreakPenalty,
+            PayCategory::SpanPenalty,
+            PayCategory::MinimumDailyPay,
+            PayCategory::EarlyCommencementPenalty,
+        ];
+        for pair in categories.windows(2) {
+            assert!(pair[0].ordinal() < pair[1].ordinal());
+        }
+    }
+
+    #[test]
+    fn test_pay_category_display_labels() {
+        assert_eq!(PayCategory::Ordinary.to_string(), "Ordinary Hours");
+        assert_eq!(PayCategory::Saturday.to_string(), "Saturday Penalty");
+        assert_eq!(
+            PayCategory::SundayCasual.to_string(),
+            "Sunday Penalty (Casual)"
+        );
+        assert_eq!(PayCategory::Overtime150.to_string(), "Overtime (150%)");
+        assert_eq!(
+            PayCategory::PublicHolidayNotWorked.to_string(),
+            "Public Holiday Not Worked"
+        );
+    }
+
     #[test]
     fn test_pay_line_serialization() {
         let pay_line = PayLine {
@@ -401,6 +1132,8 @@ mod tests {
             rate: dec("28.54"),
             amount: dec("228.32"),
             clause_ref: "14.2".to_string(),
+            reference: None,
+            cost_centre: None,
         };
 
         let json = serde_json::to_string(&pay_line).unwrap();
@@ -435,7 +1168,7 @@ mod tests {
     #[test]
     fn test_allowance_payment_serialization() {
         let allowance = AllowancePayment {
-            allowance_type: "laundry".to_string(),
+            allowance_type: AllowanceType::Laundry,
             description: "Laundry allowance for uniform cleaning".to_string(),
             units: dec("5.0"),
             rate: dec("0.32"),
@@ -449,6 +1182,25 @@ mod tests {
         assert!(json.contains("\"clause_ref\":\"20.2\""));
     }
 
+    #[test]
+    fn test_allowance_type_names_round_trip() {
+        for allowance_type in [
+            AllowanceType::Laundry,
+            AllowanceType::Qualification,
+            AllowanceType::FirstAid,
+            AllowanceType::BrokenShift,
+            AllowanceType::SplitShiftTravel,
+            AllowanceType::Custom("meal".to_string()),
+        ] {
+            let json = serde_json::to_string(&allowance_type).unwrap();
+            assert_eq!(json, format!("\"{}\"", allowance_type));
+            assert_eq!(
+                serde_json::from_str::<AllowanceType>(&json).unwrap(),
+                allowance_type
+            );
+        }
+    }
+
     #[test]
     fn test_allowance_payment_deserialization() {
         let json = r#"{
@@ -461,7 +1213,10 @@ mod tests {
         }"#;
 
         let allowance: AllowancePayment = serde_json::from_str(json).unwrap();
-        assert_eq!(allowance.allowance_type, "meal");
+        assert_eq!(
+            allowance.allowance_type,
+            AllowanceType::Custom("meal".to_string())
+        );
         assert_eq!(allowance.description, "Meal allowance for overtime");
         assert_eq!(allowance.units, dec("1.0"));
         assert_eq!(allowance.rate, dec("15.00"));
@@ -476,7 +1231,10 @@ mod tests {
             ordinary_hours: dec("38.0"),
             overtime_hours: dec("4.0"),
             penalty_hours: dec("8.0"),
+            total_hours_worked: dec("50.0"),
             allowances_total: dec("5.60"),
+            allowances_by_type: Default::default(),
+            gross_by_category: Default::default(),
         };
 
         let json = serde_json::to_string(&totals).unwrap();
@@ -487,6 +1245,31 @@ mod tests {
         assert!(json.contains("\"allowances_total\":\"5.60\""));
     }
 
+    #[test]
+    fn test_pay_totals_allowances_by_type_serialization() {
+        let mut allowances_by_type = BTreeMap::new();
+        allowances_by_type.insert("qualification".to_string(), dec("21.40"));
+        allowances_by_type.insert("laundry".to_string(), dec("1.49"));
+        let totals = PayTotals {
+            gross_pay: dec("1522.89"),
+            ordinary_hours: dec("38.0"),
+            overtime_hours: dec("0"),
+            penalty_hours: dec("0"),
+            total_hours_worked: dec("38.0"),
+            allowances_total: dec("22.89"),
+            allowances_by_type,
+            gross_by_category: Default::default(),
+        };
+
+        let json = serde_json::to_string(&totals).unwrap();
+        // Types are emitted in sorted order
+        assert!(
+            json.contains(
+                "\"allowances_by_type\":{\"laundry\":\"1.49\",\"qualification\":\"21.40\"}"
+            )
+        );
+    }
+
     #[test]
     fn test_pay_totals_deserialization() {
         let json = r#"{
@@ -571,17 +1354,30 @@ mod tests {
                 .unwrap()
                 .with_timezone(&Utc),
             engine_version: "1.0.0".to_string(),
+            schema_version: "1.0.0".to_string(),
+            build_metadata: None,
+            correlation_id: None,
             employee_id: "emp_001".to_string(),
             pay_period: create_sample_pay_period(),
             pay_lines: vec![create_sample_pay_line(dec("228.32"))],
+            shift_breakdown: vec![],
+            overtime_detail: vec![],
             allowances: vec![create_sample_allowance(dec("1.49"))],
+            applied_clauses: vec![],
+            unapplied_rules: None,
             totals: PayTotals {
                 gross_pay: dec("229.81"),
                 ordinary_hours: dec("8.0"),
                 overtime_hours: dec("0"),
                 penalty_hours: dec("0"),
+                total_hours_worked: dec("8.0"),
                 allowances_total: dec("1.49"),
+                allowances_by_type: Default::default(),
+                gross_by_category: Default::default(),
             },
+            accruals: None,
+            input: None,
+            estimate: false,
             audit_trace: create_sample_audit_trace(),
         };
 
@@ -626,6 +1422,7 @@ mod tests {
 
         let result: CalculationResult = serde_json::from_str(json).unwrap();
         assert_eq!(result.engine_version, "1.0.0");
+        assert_eq!(result.schema_version, "");
         assert_eq!(result.employee_id, "emp_001");
         assert!(result.pay_lines.is_empty());
         assert!(result.allowances.is_empty());
@@ -642,6 +1439,13 @@ mod tests {
             PayCategory::SundayCasual,
             PayCategory::Overtime150,
             PayCategory::Overtime200,
+            PayCategory::Overtime250,
+            PayCategory::PublicHolidayOvertime,
+            PayCategory::PublicHolidayNotWorked,
+            PayCategory::MealBreakPenalty,
+            PayCategory::SpanPenalty,
+            PayCategory::MinimumDailyPay,
+            PayCategory::EarlyCommencementPenalty,
         ];
 
         for category in categories {
@@ -661,6 +1465,8 @@ mod tests {
             rate: dec("28.54"),
             amount: dec("214.05"),
             clause_ref: "14.2".to_string(),
+            reference: None,
+            cost_centre: None,
         };
 
         assert_eq!(pay_line.hours * pay_line.rate, dec("214.05"));
@@ -668,7 +1474,7 @@ mod tests {
 
     #[test]
     fn test_multiple_pay_lines_sum() {
-        let pay_lines = vec![
+        let pay_lines = [
             PayLine {
                 date: NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
                 shift_id: "shift_001".to_string(),
@@ -677,6 +1483,8 @@ mod tests {
                 rate: dec("28.54"),
                 amount: dec("228.32"),
                 clause_ref: "14.2".to_string(),
+                reference: None,
+                cost_centre: None,
             },
             PayLine {
                 date: NaiveDate::from_ymd_opt(2026, 1, 16).unwrap(),
@@ -686,6 +1494,8 @@ mod tests {
                 rate: dec("42.81"),
                 amount: dec("342.48"),
                 clause_ref: "23.1".to_string(),
+                reference: None,
+                cost_centre: None,
             },
             PayLine {
                 date: NaiveDate::from_ymd_opt(2026, 1, 17).unwrap(),
@@ -695,6 +1505,8 @@ mod tests {
                 rate: dec("57.08"),
                 amount: dec("228.32"),
                 clause_ref: "23.2".to_string(),
+                reference: None,
+                cost_centre: None,
             },
         ];
 
@@ -742,4 +1554,131 @@ mod tests {
         let step_numbers: Vec<u32> = trace.steps.iter().map(|s| s.step_number).collect();
         assert_eq!(step_numbers, vec![1, 2, 3]);
     }
+
+    #[test]
+    fn test_collect_applied_clauses_sorts_and_deduplicates_without_steps() {
+        let mut overtime_line = create_sample_pay_line(dec("42.81"));
+        overtime_line.clause_ref = "25.1(a)(i)(A)".to_string();
+        let step = |clause_ref: &str| AuditStep {
+            step_number: 1,
+            rule_id: "rule".to_string(),
+            rule_name: "Rule".to_string(),
+            clause_ref: clause_ref.to_string(),
+            input: serde_json::json!({}),
+            output: serde_json::json!({}),
+            reasoning: String::new(),
+        };
+
+        let result = CalculationResult {
+            calculation_id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            engine_version: "1.0.0".to_string(),
+            schema_version: "1.0.0".to_string(),
+            build_metadata: None,
+            correlation_id: None,
+            employee_id: "emp_001".to_string(),
+            pay_period: create_sample_pay_period(),
+            pay_lines: vec![
+                create_sample_pay_line(dec("228.32")),
+                overtime_line,
+                create_sample_pay_line(dec("228.32")),
+            ],
+            shift_breakdown: vec![],
+            overtime_detail: vec![],
+            allowances: vec![create_sample_allowance(dec("1.49"))],
+            applied_clauses: vec![],
+            unapplied_rules: None,
+            totals: PayTotals {
+                gross_pay: Decimal::ZERO,
+                ordinary_hours: Decimal::ZERO,
+                overtime_hours: Decimal::ZERO,
+                penalty_hours: Decimal::ZERO,
+                total_hours_worked: Decimal::ZERO,
+                allowances_total: Decimal::ZERO,
+                allowances_by_type: BTreeMap::new(),
+                gross_by_category: Default::default(),
+            },
+            accruals: None,
+            input: None,
+            estimate: false,
+            audit_trace: AuditTrace {
+                steps: vec![step("14.2"), step("N/A"), step("10.4(b)")],
+                warnings: vec![],
+                duration_us: 0,
+            },
+        };
+
+        // The steps' clauses are left out; 10.4(b) is only referenced by one
+        assert_eq!(
+            result.collect_applied_clauses(),
+            vec!["14.2", "20.2", "25.1(a)(i)(A)"]
+        );
+    }
+
+    #[test]
+    fn test_amounts_convert_to_cents_and_back() {
+        let pay_line = create_sample_pay_line(dec("228.32"));
+        let mut allowance = create_sample_allowance(dec("0.125"));
+        allowance.rate = dec("0.125");
+        let overtime_tier = OvertimeTier::from_pay_line(&pay_line, 1, Decimal::ZERO, Decimal::ONE);
+        let dollars = CalculationResult {
+            calculation_id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            engine_version: "1.0.0".to_string(),
+            schema_version: "1.0.0".to_string(),
+            build_metadata: None,
+            correlation_id: None,
+            employee_id: "emp_001".to_string(),
+            pay_period: create_sample_pay_period(),
+            pay_lines: vec![pay_line.clone()],
+            shift_breakdown: vec![ShiftResult {
+                shift_id: pay_line.shift_id.clone(),
+                pay_lines: vec![pay_line],
+                subtotal: dec("228.32"),
+            }],
+            overtime_detail: vec![overtime_tier],
+            allowances: vec![allowance],
+            applied_clauses: vec![],
+            unapplied_rules: None,
+            totals: PayTotals {
+                gross_pay: dec("228.445"),
+                ordinary_hours: dec("8"),
+                overtime_hours: Decimal::ZERO,
+                penalty_hours: Decimal::ZERO,
+                total_hours_worked: dec("8"),
+                allowances_total: dec("0.125"),
+                allowances_by_type: BTreeMap::from([("laundry".to_string(), dec("0.125"))]),
+                gross_by_category: BTreeMap::from([(PayCategory::Ordinary, dec("228.32"))]),
+            },
+            accruals: None,
+            input: None,
+            estimate: false,
+            audit_trace: create_sample_audit_trace(),
+        };
+
+        let mut cents = dollars.clone();
+        cents.convert_amounts_to_cents();
+
+        assert_eq!(cents.pay_lines[0].rate.to_string(), "2854");
+        assert_eq!(cents.pay_lines[0].amount.to_string(), "22832");
+        assert_eq!(cents.pay_lines[0].hours, dec("8"));
+        assert_eq!(cents.shift_breakdown[0].subtotal.to_string(), "22832");
+        assert_eq!(cents.shift_breakdown[0].pay_lines, cents.pay_lines);
+        assert_eq!(cents.overtime_detail[0].rate.to_string(), "2854");
+        assert_eq!(cents.overtime_detail[0].amount.to_string(), "22832");
+        assert_eq!(cents.overtime_detail[0].multiplier, Decimal::ONE);
+        // Sub-cent amounts keep their fraction
+        assert_eq!(cents.allowances[0].amount.to_string(), "12.5");
+        assert_eq!(cents.totals.gross_pay.to_string(), "22844.5");
+        assert_eq!(cents.totals.allowances_by_type["laundry"], dec("12.5"));
+        assert_eq!(
+            cents.totals.gross_by_category[&PayCategory::Ordinary],
+            dec("22832")
+        );
+        assert_eq!(cents.totals.total_hours_worked, dec("8"));
+        assert_eq!(cents.audit_trace, dollars.audit_trace);
+
+        cents.convert_amounts_to_dollars();
+        assert_eq!(cents, dollars);
+    }
 }
@@ -1,12 +1,19 @@
 //! Employee model and related types.
 //!
 //! This module defines the Employee struct and EmploymentType enum
-//! for representing workers in the award interpretation system.
+//! for representing workers in the award interpretation system, and the
+//! [`EmployeeBuilder`] for constructing employees in code.
 
-use chrono::NaiveDate;
+use std::borrow::Cow;
+
+use chrono::{Datelike, NaiveDate, Weekday};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::error::EngineError;
+
+use super::Shift;
+
 /// Represents the type of employment arrangement.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -19,8 +26,43 @@ pub enum EmploymentType {
     Casual,
 }
 
+impl EmploymentType {
+    /// Returns the snake_case name used for this employment type in requests,
+    /// configuration and audit steps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use award_engine::models::EmploymentType;
+    ///
+    /// assert_eq!(EmploymentType::PartTime.as_str(), "part_time");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmploymentType::FullTime => "full_time",
+            EmploymentType::PartTime => "part_time",
+            EmploymentType::Casual => "casual",
+        }
+    }
+
+    /// Returns true for casual employment.
+    pub fn is_casual(&self) -> bool {
+        *self == EmploymentType::Casual
+    }
+
+    /// Returns true for permanent (full-time or part-time) employment.
+    pub fn is_permanent(&self) -> bool {
+        !self.is_casual()
+    }
+
+    /// Returns true for part-time employment.
+    pub fn is_part_time(&self) -> bool {
+        *self == EmploymentType::PartTime
+    }
+}
+
 /// Represents an employee subject to award interpretation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Employee {
     /// Unique identifier for the employee.
     pub id: String,
@@ -34,12 +76,32 @@ pub struct Employee {
     pub employment_start_date: NaiveDate,
     /// Optional override for the base hourly rate.
     pub base_hourly_rate: Option<Decimal>,
+    /// Optional maximum ordinary hours per day (e.g. a part-timer's agreed
+    /// 6-hour day); hours above it are overtime even below the daily threshold.
+    #[serde(default)]
+    pub max_ordinary_hours_per_day: Option<Decimal>,
+    /// The days of the week the employee ordinarily works, used to decide
+    /// whether a public holiday not worked is paid.
+    #[serde(default)]
+    pub ordinary_days: Vec<Weekday>,
+    /// The employee's rostered days off (RDOs) in the pay period. A public
+    /// holiday on one of them is not paid as a holiday not worked, and may
+    /// instead earn a day in lieu.
+    #[serde(default)]
+    pub rostered_days_off: Vec<NaiveDate>,
     /// Tags for categorizing employees (e.g., qualifications, departments).
     #[serde(default)]
     pub tags: Vec<String>,
 }
 
 impl Employee {
+    /// Starts building an employee with the given ID.
+    ///
+    /// See [`EmployeeBuilder`] for the defaults.
+    pub fn builder(id: impl Into<String>) -> EmployeeBuilder {
+        EmployeeBuilder::new(id)
+    }
+
     /// Returns true if the employee is a casual worker.
     ///
     /// # Examples
@@ -55,13 +117,241 @@ impl Employee {
     ///     date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
     ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
     ///     base_hourly_rate: None,
+    ///     max_ordinary_hours_per_day: None,
+    ///     ordinary_days: vec![],
+    ///     rostered_days_off: vec![],
     ///     tags: vec![],
     /// };
     /// assert!(casual.is_casual());
     /// ```
     pub fn is_casual(&self) -> bool {
-        self.employment_type == EmploymentType::Casual
+        self.employment_type.is_casual()
     }
+
+    /// Returns the employee's age in whole years on the given date.
+    ///
+    /// The employee turns a year older on their birthday. An employee born on
+    /// 29 February turns a year older on 1 March in non-leap years. Dates
+    /// before the date of birth give an age of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use award_engine::models::{Employee, EmploymentType};
+    /// use chrono::NaiveDate;
+    ///
+    /// let employee = Employee {
+    ///     id: "emp_001".to_string(),
+    ///     employment_type: EmploymentType::Casual,
+    ///     classification_code: "dce_level_3".to_string(),
+    ///     date_of_birth: NaiveDate::from_ymd_opt(2008, 2, 29).unwrap(),
+    ///     employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+    ///     base_hourly_rate: None,
+    ///     max_ordinary_hours_per_day: None,
+    ///     ordinary_days: vec![],
+    ///     rostered_days_off: vec![],
+    ///     tags: vec![],
+    /// };
+    /// assert_eq!(employee.age_on(NaiveDate::from_ymd_opt(2026, 2, 28).unwrap()), 17);
+    /// assert_eq!(employee.age_on(NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()), 18);
+    /// ```
+    pub fn age_on(&self, date: NaiveDate) -> u32 {
+        let birth = self.date_of_birth;
+        if date < birth {
+            return 0;
+        }
+
+        // Comparing (month, day) puts 28 February before a 29 February birthday
+        let years = (date.year() - birth.year()) as u32;
+        if (date.month(), date.day()) < (birth.month(), birth.day()) {
+            years - 1
+        } else {
+            years
+        }
+    }
+
+    /// Returns the employee as they work the given shift.
+    ///
+    /// When the shift names a different `classification_code`, the employee
+    /// is classified under it for that shift, and their `base_hourly_rate`
+    /// override (agreed for their own classification) is dropped. Otherwise
+    /// the employee is returned unchanged.
+    pub fn for_shift(&self, shift: &Shift) -> Cow<'_, Employee> {
+        match &shift.classification_code {
+            Some(code) if *code != self.classification_code => Cow::Owned(Employee {
+                classification_code: code.clone(),
+                base_hourly_rate: None,
+                ..self.clone()
+            }),
+            _ => Cow::Borrowed(self),
+        }
+    }
+}
+
+/// Builds an [`Employee`] without spelling out every field.
+///
+/// The employee is full-time, born on 1 January 1970 (so an adult on any pay
+/// period) and employed since 1 January 2000, with no rate override, ordinary
+/// days, rostered days off or tags. Only the classification has no default.
+/// Dates are given as `YYYY-MM-DD`; a date that cannot be read is reported by
+/// [`build`](EmployeeBuilder::build).
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::models::{Employee, EmploymentType};
+///
+/// let employee = Employee::builder("emp_001")
+///     .casual()
+///     .classification("dce_level_3")
+///     .tag("laundry_allowance")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(employee.employment_type, EmploymentType::Casual);
+/// assert_eq!(employee.classification_code, "dce_level_3");
+/// assert_eq!(employee.base_hourly_rate, None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmployeeBuilder {
+    id: String,
+    employment_type: EmploymentType,
+    classification_code: Option<String>,
+    date_of_birth: String,
+    employment_start_date: String,
+    base_hourly_rate: Option<Decimal>,
+    max_ordinary_hours_per_day: Option<Decimal>,
+    ordinary_days: Vec<Weekday>,
+    rostered_days_off: Vec<String>,
+    tags: Vec<String>,
+}
+
+impl EmployeeBuilder {
+    /// Starts building an employee with the given ID.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            employment_type: EmploymentType::FullTime,
+            classification_code: None,
+            date_of_birth: "1970-01-01".to_string(),
+            employment_start_date: "2000-01-01".to_string(),
+            base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: Vec::new(),
+            rostered_days_off: Vec::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    /// Sets the employment type.
+    pub fn employment_type(mut self, employment_type: EmploymentType) -> Self {
+        self.employment_type = employment_type;
+        self
+    }
+
+    /// Makes the employee full-time (the default).
+    pub fn full_time(self) -> Self {
+        self.employment_type(EmploymentType::FullTime)
+    }
+
+    /// Makes the employee part-time.
+    pub fn part_time(self) -> Self {
+        self.employment_type(EmploymentType::PartTime)
+    }
+
+    /// Makes the employee casual.
+    pub fn casual(self) -> Self {
+        self.employment_type(EmploymentType::Casual)
+    }
+
+    /// Sets the award classification code (e.g. `dce_level_3`).
+    pub fn classification(mut self, code: impl Into<String>) -> Self {
+        self.classification_code = Some(code.into());
+        self
+    }
+
+    /// Sets the date of birth, as `YYYY-MM-DD`.
+    pub fn date_of_birth(mut self, date: impl Into<String>) -> Self {
+        self.date_of_birth = date.into();
+        self
+    }
+
+    /// Sets the employment start date, as `YYYY-MM-DD`.
+    pub fn employment_start_date(mut self, date: impl Into<String>) -> Self {
+        self.employment_start_date = date.into();
+        self
+    }
+
+    /// Sets an override for the classification's base hourly rate.
+    pub fn base_hourly_rate(mut self, rate: Decimal) -> Self {
+        self.base_hourly_rate = Some(rate);
+        self
+    }
+
+    /// Sets the maximum ordinary hours per day.
+    pub fn max_ordinary_hours_per_day(mut self, hours: Decimal) -> Self {
+        self.max_ordinary_hours_per_day = Some(hours);
+        self
+    }
+
+    /// Sets the days of the week the employee ordinarily works.
+    pub fn ordinary_days(mut self, days: impl IntoIterator<Item = Weekday>) -> Self {
+        self.ordinary_days = days.into_iter().collect();
+        self
+    }
+
+    /// Adds a rostered day off, as `YYYY-MM-DD`.
+    pub fn rostered_day_off(mut self, date: impl Into<String>) -> Self {
+        self.rostered_days_off.push(date.into());
+        self
+    }
+
+    /// Adds a tag (e.g. `laundry_allowance`).
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Builds the employee.
+    ///
+    /// Fails with [`EngineError::InvalidEmployee`] when no classification was
+    /// set or a date cannot be read.
+    pub fn build(self) -> Result<Employee, EngineError> {
+        let Some(classification_code) = self.classification_code else {
+            return Err(EngineError::InvalidEmployee {
+                field: "classification_code".to_string(),
+                message: "must be set".to_string(),
+            });
+        };
+
+        Ok(Employee {
+            id: self.id,
+            employment_type: self.employment_type,
+            classification_code,
+            date_of_birth: parse_date("date_of_birth", &self.date_of_birth)?,
+            employment_start_date: parse_date(
+                "employment_start_date",
+                &self.employment_start_date,
+            )?,
+            base_hourly_rate: self.base_hourly_rate,
+            max_ordinary_hours_per_day: self.max_ordinary_hours_per_day,
+            ordinary_days: self.ordinary_days,
+            rostered_days_off: self
+                .rostered_days_off
+                .iter()
+                .map(|date| parse_date("rostered_days_off", date))
+                .collect::<Result<_, _>>()?,
+            tags: self.tags,
+        })
+    }
+}
+
+/// Reads a `YYYY-MM-DD` date for the named employee field.
+fn parse_date(field: &str, date: &str) -> Result<NaiveDate, EngineError> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| EngineError::InvalidEmployee {
+        field: field.to_string(),
+        message: format!("'{}' is not a YYYY-MM-DD date", date),
+    })
 }
 
 #[cfg(test)]
@@ -76,6 +366,9 @@ mod tests {
             date_of_birth: NaiveDate::from_ymd_opt(1990, 1, 15).unwrap(),
             employment_start_date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
             base_hourly_rate: None,
+            max_ordinary_hours_per_day: None,
+            ordinary_days: vec![],
+            rostered_days_off: vec![],
             tags: vec![],
         }
     }
@@ -192,4 +485,157 @@ mod tests {
         let deserialized: Employee = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.base_hourly_rate, Some(Decimal::new(3254, 2)));
     }
+
+    #[test]
+    fn test_employment_type_as_str_matches_serde_name() {
+        for employment_type in [
+            EmploymentType::FullTime,
+            EmploymentType::PartTime,
+            EmploymentType::Casual,
+        ] {
+            let serialized = serde_json::to_value(employment_type).unwrap();
+            assert_eq!(serialized, employment_type.as_str());
+        }
+    }
+
+    #[test]
+    fn test_employment_type_predicates() {
+        assert!(EmploymentType::FullTime.is_permanent());
+        assert!(!EmploymentType::FullTime.is_part_time());
+        assert!(!EmploymentType::FullTime.is_casual());
+
+        assert!(EmploymentType::PartTime.is_permanent());
+        assert!(EmploymentType::PartTime.is_part_time());
+        assert!(!EmploymentType::PartTime.is_casual());
+
+        assert!(!EmploymentType::Casual.is_permanent());
+        assert!(!EmploymentType::Casual.is_part_time());
+        assert!(EmploymentType::Casual.is_casual());
+    }
+
+    fn employee_born(year: i32, month: u32, day: u32) -> Employee {
+        let mut employee = create_test_employee(EmploymentType::Casual);
+        employee.date_of_birth = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        employee
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_age_on_day_before_and_after_birthday() {
+        let employee = employee_born(2008, 7, 15);
+
+        assert_eq!(employee.age_on(date(2026, 7, 14)), 17);
+        assert_eq!(employee.age_on(date(2026, 7, 15)), 18);
+        assert_eq!(employee.age_on(date(2026, 7, 16)), 18);
+    }
+
+    #[test]
+    fn test_age_on_leap_day_birthday_in_leap_year() {
+        let employee = employee_born(2004, 2, 29);
+
+        assert_eq!(employee.age_on(date(2024, 2, 28)), 19);
+        assert_eq!(employee.age_on(date(2024, 2, 29)), 20);
+        assert_eq!(employee.age_on(date(2024, 3, 1)), 20);
+    }
+
+    #[test]
+    fn test_age_on_leap_day_birthday_in_non_leap_year() {
+        let employee = employee_born(2004, 2, 29);
+
+        // No 29 February in 2025: the birthday is reached on 1 March
+        assert_eq!(employee.age_on(date(2025, 2, 28)), 20);
+        assert_eq!(employee.age_on(date(2025, 3, 1)), 21);
+    }
+
+    #[test]
+    fn test_age_on_date_of_birth_and_earlier() {
+        let employee = employee_born(2008, 7, 15);
+
+        assert_eq!(employee.age_on(date(2008, 7, 15)), 0);
+        assert_eq!(employee.age_on(date(2000, 1, 1)), 0);
+    }
+
+    #[test]
+    fn test_for_shift_takes_the_shifts_classification() {
+        let mut employee = create_test_employee(EmploymentType::FullTime);
+        employee.base_hourly_rate = Some(Decimal::new(3000, 2));
+        let date = date(2026, 1, 13);
+        let mut shift = Shift {
+            id: "shift_001".to_string(),
+            date,
+            start_time: date.and_hms_opt(9, 0, 0).unwrap(),
+            end_time: date.and_hms_opt(17, 0, 0).unwrap(),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        };
+
+        assert_eq!(*employee.for_shift(&shift), employee);
+
+        shift.classification_code = Some("dce_level_3".to_string());
+        assert_eq!(*employee.for_shift(&shift), employee);
+
+        shift.classification_code = Some("dce_level_4".to_string());
+        let other_role = employee.for_shift(&shift);
+        assert_eq!(other_role.classification_code, "dce_level_4");
+        assert_eq!(other_role.base_hourly_rate, None);
+        assert_eq!(other_role.id, employee.id);
+    }
+
+    #[test]
+    fn test_builder_matches_a_hand_built_employee() {
+        let employee = Employee::builder("emp_001")
+            .classification("dce_level_3")
+            .date_of_birth("1990-01-15")
+            .employment_start_date("2023-06-01")
+            .build()
+            .unwrap();
+
+        assert_eq!(employee, create_test_employee(EmploymentType::FullTime));
+    }
+
+    #[test]
+    fn test_builder_sets_optional_fields() {
+        let employee = Employee::builder("emp_002")
+            .part_time()
+            .classification("dce_level_2")
+            .base_hourly_rate(Decimal::new(3000, 2))
+            .max_ordinary_hours_per_day(Decimal::from(6))
+            .ordinary_days([Weekday::Mon, Weekday::Tue])
+            .rostered_day_off("2026-01-26")
+            .tag("laundry_allowance")
+            .build()
+            .unwrap();
+
+        assert_eq!(employee.employment_type, EmploymentType::PartTime);
+        assert_eq!(employee.base_hourly_rate, Some(Decimal::new(3000, 2)));
+        assert_eq!(employee.max_ordinary_hours_per_day, Some(Decimal::from(6)));
+        assert_eq!(employee.ordinary_days, vec![Weekday::Mon, Weekday::Tue]);
+        assert_eq!(employee.rostered_days_off, vec![date(2026, 1, 26)]);
+        assert_eq!(employee.tags, vec!["laundry_allowance".to_string()]);
+        assert_eq!(employee.age_on(date(2026, 1, 26)), 56);
+    }
+
+    #[test]
+    fn test_builder_rejects_missing_classification_and_bad_dates() {
+        let missing = Employee::builder("emp_001").build();
+        assert!(matches!(
+            missing,
+            Err(EngineError::InvalidEmployee { ref field, .. }) if field == "classification_code"
+        ));
+
+        let bad_date = Employee::builder("emp_001")
+            .classification("dce_level_3")
+            .date_of_birth("15/01/1990")
+            .build();
+        assert!(matches!(
+            bad_date,
+            Err(EngineError::InvalidEmployee { ref field, .. }) if field == "date_of_birth"
+        ));
+    }
 }
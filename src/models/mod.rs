@@ -8,9 +8,14 @@ mod pay_period;
 mod shift;
 
 pub use calculation_result::{
-    AllowancePayment, AuditStep, AuditTrace, AuditWarning, CalculationResult, PayCategory, PayLine,
-    PayTotals,
+    AUDIT_TRUNCATED_RULE_ID, AllowancePayment, AllowanceType, AuditStep, AuditTrace, AuditWarning,
+    CENTS_PER_DOLLAR, CalculationInput, CalculationResult, DayInLieu, InterpretedShift,
+    LeaveAccruals, OvertimeTier, PayCategory, PayLine, PayTotals, RESULT_SCHEMA_VERSION,
+    ShiftResult, UnappliedRule,
+};
+pub use employee::{Employee, EmployeeBuilder, EmploymentType};
+pub use pay_period::{PayPeriod, PayPeriodType, PublicHoliday};
+pub use shift::{
+    Break, BreakKind, HOURS_PRECISION, SHIFT_GAP_THRESHOLD_MINUTES, Shift, ShiftBuilder,
+    hours_from_minutes, hours_from_seconds,
 };
-pub use employee::{Employee, EmploymentType};
-pub use pay_period::{PayPeriod, PublicHoliday};
-pub use shift::{Break, Shift};
@@ -3,9 +3,38 @@
 //! This module contains the [`PayPeriod`] and [`PublicHoliday`] types used to define
 //! the calculation context for pay calculations.
 
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
 
+/// The length of a pay period.
+///
+/// Weekly and fortnightly periods cover a whole number of weeks; a monthly
+/// period covers a calendar month and so spans four or five week starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayPeriodType {
+    /// A 7-day pay period.
+    Weekly,
+    /// A 14-day pay period.
+    Fortnightly,
+    /// A 28-day pay period.
+    FourWeekly,
+    /// A calendar month.
+    Monthly,
+}
+
+impl PayPeriodType {
+    /// Returns the string representation of the pay period type.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PayPeriodType::Weekly => "weekly",
+            PayPeriodType::Fortnightly => "fortnightly",
+            PayPeriodType::FourWeekly => "four_weekly",
+            PayPeriodType::Monthly => "monthly",
+        }
+    }
+}
+
 /// Represents a public holiday within a pay period.
 ///
 /// Public holidays affect penalty rates and are tracked per region
@@ -54,6 +83,7 @@ pub struct PublicHoliday {
 ///             region: "national".to_string(),
 ///         }
 ///     ],
+///     period_type: None,
 /// };
 ///
 /// assert!(pay_period.contains_date(NaiveDate::from_ymd_opt(2026, 1, 15).unwrap()));
@@ -67,6 +97,12 @@ pub struct PayPeriod {
     pub end_date: NaiveDate,
     /// Public holidays that fall within this pay period.
     pub public_holidays: Vec<PublicHoliday>,
+    /// The length of the pay period, when given explicitly.
+    ///
+    /// When absent, the type is inferred from the dates; see
+    /// [`PayPeriod::resolved_period_type`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period_type: Option<PayPeriodType>,
 }
 
 impl PayPeriod {
@@ -92,6 +128,7 @@ impl PayPeriod {
     ///     start_date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
     ///     end_date: NaiveDate::from_ymd_opt(2026, 1, 26).unwrap(),
     ///     public_holidays: vec![],
+    ///     period_type: None,
     /// };
     ///
     /// assert!(period.contains_date(NaiveDate::from_ymd_opt(2026, 1, 13).unwrap())); // start date
@@ -130,6 +167,7 @@ impl PayPeriod {
     ///             region: "national".to_string(),
     ///         }
     ///     ],
+    ///     period_type: None,
     /// };
     ///
     /// assert!(period.is_public_holiday(NaiveDate::from_ymd_opt(2026, 1, 26).unwrap()));
@@ -138,6 +176,87 @@ impl PayPeriod {
     pub fn is_public_holiday(&self, date: NaiveDate) -> bool {
         self.public_holidays.iter().any(|h| h.date == date)
     }
+
+    /// Returns the pay period type, inferring it from the dates when it was
+    /// not given explicitly.
+    ///
+    /// A period of 7, 14 or 28 days is weekly, fortnightly or four-weekly; a
+    /// period from the first to the last day of a calendar month is monthly.
+    /// Any other span has no type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::models::{PayPeriod, PayPeriodType};
+    /// use chrono::NaiveDate;
+    ///
+    /// let period = PayPeriod {
+    ///     start_date: NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+    ///     end_date: NaiveDate::from_ymd_opt(2026, 2, 28).unwrap(),
+    ///     public_holidays: vec![],
+    ///     period_type: None,
+    /// };
+    ///
+    /// // February 2026 is both 28 days and a whole calendar month
+    /// assert_eq!(period.resolved_period_type(), Some(PayPeriodType::Monthly));
+    /// ```
+    pub fn resolved_period_type(&self) -> Option<PayPeriodType> {
+        if self.period_type.is_some() {
+            return self.period_type;
+        }
+
+        let is_calendar_month = self.start_date.day() == 1
+            && self.end_date.succ_opt().is_some_and(|next| next.day() == 1)
+            && self.start_date.year() == self.end_date.year()
+            && self.start_date.month() == self.end_date.month();
+        if is_calendar_month {
+            return Some(PayPeriodType::Monthly);
+        }
+
+        match (self.end_date - self.start_date).num_days() + 1 {
+            7 => Some(PayPeriodType::Weekly),
+            14 => Some(PayPeriodType::Fortnightly),
+            28 => Some(PayPeriodType::FourWeekly),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of award weeks the pay period covers.
+    ///
+    /// This is the number of days in the period that fall on the award's week
+    /// start day, so a weekly period is one week and a fortnightly period is
+    /// two, whichever day they start on. A calendar month covers four or five
+    /// weeks. A period shorter than a week still counts as one week.
+    ///
+    /// # Arguments
+    ///
+    /// * `week_start_day` - The day the award week starts on
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::models::PayPeriod;
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let march = PayPeriod {
+    ///     start_date: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+    ///     end_date: NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+    ///     public_holidays: vec![],
+    ///     period_type: None,
+    /// };
+    ///
+    /// // Mondays 2, 9, 16, 23 and 30 March
+    /// assert_eq!(march.weeks_in_period(Weekday::Mon), 5);
+    /// ```
+    pub fn weeks_in_period(&self, week_start_day: Weekday) -> u32 {
+        let weeks = self
+            .start_date
+            .iter_days()
+            .take_while(|date| *date <= self.end_date)
+            .filter(|date| date.weekday() == week_start_day)
+            .count() as u32;
+        weeks.max(1)
+    }
 }
 
 #[cfg(test)]
@@ -153,6 +272,7 @@ mod tests {
                 name: "Australia Day".to_string(),
                 region: "national".to_string(),
             }],
+            period_type: None,
         }
     }
 
@@ -161,6 +281,7 @@ mod tests {
             start_date: NaiveDate::from_ymd_opt(2026, 1, 13).unwrap(),
             end_date: NaiveDate::from_ymd_opt(2026, 1, 26).unwrap(),
             public_holidays: vec![],
+            period_type: None,
         }
     }
 
@@ -298,6 +419,7 @@ mod tests {
                     region: "national".to_string(),
                 },
             ],
+            period_type: None,
         };
 
         assert!(period.is_public_holiday(NaiveDate::from_ymd_opt(2026, 12, 25).unwrap()));
@@ -305,4 +427,98 @@ mod tests {
         assert!(period.is_public_holiday(NaiveDate::from_ymd_opt(2027, 1, 1).unwrap()));
         assert!(!period.is_public_holiday(NaiveDate::from_ymd_opt(2026, 12, 24).unwrap()));
     }
+
+    fn period(start: (i32, u32, u32), end: (i32, u32, u32)) -> PayPeriod {
+        PayPeriod {
+            start_date: NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap(),
+            public_holidays: vec![],
+            period_type: None,
+        }
+    }
+
+    #[test]
+    fn test_period_type_inferred_from_length() {
+        let weekly = period((2026, 1, 13), (2026, 1, 19));
+        let fortnightly = period((2026, 1, 13), (2026, 1, 26));
+        let four_weekly = period((2026, 1, 13), (2026, 2, 9));
+        let odd = period((2026, 1, 13), (2026, 1, 22));
+
+        assert_eq!(weekly.resolved_period_type(), Some(PayPeriodType::Weekly));
+        assert_eq!(
+            fortnightly.resolved_period_type(),
+            Some(PayPeriodType::Fortnightly)
+        );
+        assert_eq!(
+            four_weekly.resolved_period_type(),
+            Some(PayPeriodType::FourWeekly)
+        );
+        assert_eq!(odd.resolved_period_type(), None);
+    }
+
+    #[test]
+    fn test_calendar_month_is_monthly() {
+        assert_eq!(
+            period((2026, 3, 1), (2026, 3, 31)).resolved_period_type(),
+            Some(PayPeriodType::Monthly)
+        );
+        assert_eq!(
+            period((2026, 12, 1), (2026, 12, 31)).resolved_period_type(),
+            Some(PayPeriodType::Monthly)
+        );
+        // 31 days that do not line up with a calendar month
+        assert_eq!(
+            period((2026, 3, 2), (2026, 4, 1)).resolved_period_type(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_explicit_period_type_is_kept() {
+        let mut explicit = period((2026, 1, 13), (2026, 1, 22));
+        explicit.period_type = Some(PayPeriodType::Fortnightly);
+        assert_eq!(
+            explicit.resolved_period_type(),
+            Some(PayPeriodType::Fortnightly)
+        );
+    }
+
+    #[test]
+    fn test_weeks_in_period() {
+        // Tuesday to Monday fortnight: Mondays 19 and 26 January
+        assert_eq!(
+            period((2026, 1, 13), (2026, 1, 26)).weeks_in_period(Weekday::Mon),
+            2
+        );
+        // February 2026 has four Mondays, March 2026 has five
+        assert_eq!(
+            period((2026, 2, 1), (2026, 2, 28)).weeks_in_period(Weekday::Mon),
+            4
+        );
+        assert_eq!(
+            period((2026, 3, 1), (2026, 3, 31)).weeks_in_period(Weekday::Mon),
+            5
+        );
+        // A part week still counts as one week
+        assert_eq!(
+            period((2026, 1, 13), (2026, 1, 15)).weeks_in_period(Weekday::Mon),
+            1
+        );
+    }
+
+    #[test]
+    fn test_deserialize_period_type() {
+        let json = r#"{
+            "start_date": "2026-02-01",
+            "end_date": "2026-02-28",
+            "public_holidays": [],
+            "period_type": "four_weekly"
+        }"#;
+        let period: PayPeriod = serde_json::from_str(json).unwrap();
+        assert_eq!(period.period_type, Some(PayPeriodType::FourWeekly));
+        assert_eq!(
+            period.resolved_period_type(),
+            Some(PayPeriodType::FourWeekly)
+        );
+    }
 }
@@ -1,14 +1,99 @@
 //! Shift model and related types.
 //!
 //! This module defines the Shift and Break structs for representing
-//! work shifts and breaks in the award interpretation system.
+//! work shifts and breaks in the award interpretation system, and the
+//! [`ShiftBuilder`] for constructing shifts in code.
 
-use chrono::{Datelike, NaiveDate, NaiveDateTime, Weekday};
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::error::EngineError;
+
+/// The number of decimal places worked hours are held to.
+///
+/// Minute durations that do not divide evenly into hours (7 minutes is
+/// 0.11666… hours) are rounded to 4 decimal places, a precision of under half
+/// a second, so long repeating decimals never reach pay amounts or audit output.
+pub const HOURS_PRECISION: u32 = 4;
+
+/// Converts a number of minutes to hours, rounded to [`HOURS_PRECISION`].
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::models::hours_from_minutes;
+/// use rust_decimal::Decimal;
+///
+/// assert_eq!(hours_from_minutes(90), Decimal::new(15, 1));
+/// assert_eq!(hours_from_minutes(7), Decimal::new(1167, 4));
+/// ```
+pub fn hours_from_minutes(minutes: i64) -> Decimal {
+    (Decimal::new(minutes, 0) / Decimal::new(60, 0)).round_dp(HOURS_PRECISION)
+}
+
+/// Converts a number of seconds to hours, rounded to [`HOURS_PRECISION`].
+///
+/// Durations are measured in seconds, so shift times recorded to the second
+/// are paid to the second; whole minutes give the same hours as
+/// [`hours_from_minutes`].
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::models::hours_from_seconds;
+/// use rust_decimal::Decimal;
+///
+/// assert_eq!(hours_from_seconds(5400), Decimal::new(15, 1));
+/// assert_eq!(hours_from_seconds(30), Decimal::new(83, 4));
+/// ```
+pub fn hours_from_seconds(seconds: i64) -> Decimal {
+    (Decimal::new(seconds, 0) / Decimal::new(3600, 0)).round_dp(HOURS_PRECISION)
+}
+
+/// Breaks longer than this many minutes are classified as shift gaps when
+/// no explicit `kind` is given.
+pub const SHIFT_GAP_THRESHOLD_MINUTES: i64 = 60;
+
+/// The kind of break taken during a shift.
+///
+/// A meal break is a short rest within a continuous shift. A shift gap splits
+/// the shift into separate periods of work, making it a broken shift.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakKind {
+    /// A meal or rest break within a continuous shift.
+    MealBreak,
+    /// A gap between two periods of work in a broken shift.
+    ShiftGap,
+}
+
+impl BreakKind {
+    /// Infers the kind of a break from its duration.
+    ///
+    /// Breaks longer than [`SHIFT_GAP_THRESHOLD_MINUTES`] are shift gaps;
+    /// shorter breaks are meal breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use award_engine::models::BreakKind;
+    ///
+    /// assert_eq!(BreakKind::from_duration_minutes(30), BreakKind::MealBreak);
+    /// assert_eq!(BreakKind::from_duration_minutes(60), BreakKind::MealBreak);
+    /// assert_eq!(BreakKind::from_duration_minutes(120), BreakKind::ShiftGap);
+    /// ```
+    pub fn from_duration_minutes(duration_minutes: i64) -> Self {
+        if duration_minutes > SHIFT_GAP_THRESHOLD_MINUTES {
+            BreakKind::ShiftGap
+        } else {
+            BreakKind::MealBreak
+        }
+    }
+}
+
 /// Represents a break taken during a shift.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Break {
     /// The start time of the break.
     pub start_time: NaiveDateTime,
@@ -16,6 +101,9 @@ pub struct Break {
     pub end_time: NaiveDateTime,
     /// Whether the break is paid (true) or unpaid (false).
     pub is_paid: bool,
+    /// The kind of break. When absent, the kind is inferred from the duration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<BreakKind>,
 }
 
 impl Break {
@@ -23,10 +111,22 @@ impl Break {
     fn duration_minutes(&self) -> i64 {
         (self.end_time - self.start_time).num_minutes()
     }
+
+    /// Returns the duration of the break in seconds.
+    fn duration_seconds(&self) -> i64 {
+        (self.end_time - self.start_time).num_seconds()
+    }
+
+    /// Returns the kind of this break, inferring it from the duration when
+    /// no explicit kind was given.
+    pub fn effective_kind(&self) -> BreakKind {
+        self.kind
+            .unwrap_or_else(|| BreakKind::from_duration_minutes(self.duration_minutes()))
+    }
 }
 
 /// Represents a work shift with timing information and breaks.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Shift {
     /// Unique identifier for the shift.
     pub id: String,
@@ -39,17 +139,44 @@ pub struct Shift {
     /// Breaks taken during the shift.
     #[serde(default)]
     pub breaks: Vec<Break>,
+    /// An agreed hourly rate for this shift only (e.g. a backfill
+    /// arrangement), replacing the employee's base rate. Penalties, overtime
+    /// and casual loading are applied to it as they would be to the base rate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_override: Option<Decimal>,
+    /// The classification this shift is worked under, when it differs from
+    /// the employee's (e.g. a different role on some days). The whole shift
+    /// is paid at that classification's rate and penalties instead of the
+    /// employee's, and the employee's `base_hourly_rate` does not apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classification_code: Option<String>,
+    /// A payroll note or reference (e.g. for GL coding). It does not affect
+    /// the calculation and is copied onto every pay line from the shift.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// A cost centre code. It does not affect the calculation and is copied
+    /// onto every pay line from the shift.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_centre: Option<String>,
 }
 
 impl Shift {
+    /// Starts building a shift with the given ID.
+    ///
+    /// See [`ShiftBuilder`] for how times are read.
+    pub fn builder(id: impl Into<String>) -> ShiftBuilder {
+        ShiftBuilder::new(id)
+    }
+
     /// Calculates the total worked hours for the shift.
     ///
     /// This method calculates the total duration of the shift and subtracts
-    /// any unpaid breaks. Paid breaks are NOT subtracted from the total.
+    /// any unpaid breaks, whether meal breaks or shift gaps. Paid breaks are
+    /// NOT subtracted from the total.
     ///
     /// # Returns
     ///
-    /// The number of worked hours as a Decimal.
+    /// The number of worked hours as a Decimal, rounded to [`HOURS_PRECISION`].
     ///
     /// # Examples
     ///
@@ -64,26 +191,76 @@ impl Shift {
     ///     start_time: NaiveDateTime::parse_from_str("2026-01-15 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
     ///     end_time: NaiveDateTime::parse_from_str("2026-01-15 17:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
     ///     breaks: vec![],
+    ///     rate_override: None,
+    ///     classification_code: None,
+    ///     reference: None,
+    ///     cost_centre: None,
     /// };
     /// assert_eq!(shift.worked_hours(), Decimal::new(80, 1)); // 8.0 hours
     /// ```
     pub fn worked_hours(&self) -> Decimal {
-        // Calculate total shift duration in minutes
-        let total_minutes = (self.end_time - self.start_time).num_minutes();
+        // Calculate total shift duration in seconds
+        let total_seconds = (self.end_time - self.start_time).num_seconds();
 
-        // Calculate total unpaid break minutes
-        let unpaid_break_minutes: i64 = self
+        // Calculate total unpaid break seconds
+        let unpaid_break_seconds: i64 = self
             .breaks
             .iter()
             .filter(|b| !b.is_paid)
-            .map(|b| b.duration_minutes())
+            .map(|b| b.duration_seconds())
             .sum();
 
-        // Worked minutes = total - unpaid breaks
-        let worked_minutes = total_minutes - unpaid_break_minutes;
+        // Worked seconds = total - unpaid breaks
+        let worked_seconds = total_seconds - unpaid_break_seconds;
+
+        hours_from_seconds(worked_seconds)
+    }
+
+    /// Calculates the gross hours of the shift, from its start to its finish.
+    ///
+    /// Unlike [`worked_hours`](Shift::worked_hours), breaks are not
+    /// subtracted, so this is the span of time the shift occupies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use award_engine::models::{Break, Shift};
+    /// use chrono::NaiveDate;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+    /// let shift = Shift {
+    ///     id: "shift_001".to_string(),
+    ///     date,
+    ///     start_time: date.and_hms_opt(9, 0, 0).unwrap(),
+    ///     end_time: date.and_hms_opt(17, 30, 0).unwrap(),
+    ///     breaks: vec![Break {
+    ///         start_time: date.and_hms_opt(12, 0, 0).unwrap(),
+    ///         end_time: date.and_hms_opt(12, 30, 0).unwrap(),
+    ///         is_paid: false,
+    ///         kind: None,
+    ///     }],
+    ///     rate_override: None,
+    ///     classification_code: None,
+    ///     reference: None,
+    ///     cost_centre: None,
+    /// };
+    /// assert_eq!(shift.gross_hours(), Decimal::new(85, 1)); // 8.5 hours
+    /// assert_eq!(shift.worked_hours(), Decimal::new(80, 1)); // 8.0 hours
+    /// ```
+    pub fn gross_hours(&self) -> Decimal {
+        hours_from_seconds((self.end_time - self.start_time).num_seconds())
+    }
 
-        // Convert minutes to hours as Decimal
-        Decimal::new(worked_minutes, 0) / Decimal::new(60, 0)
+    /// Returns whether the shift is a broken shift.
+    ///
+    /// A shift is broken when it contains an unpaid break whose kind is
+    /// [`BreakKind::ShiftGap`]. Meal breaks never make a shift broken, however
+    /// long they are when explicitly marked as such.
+    pub fn is_broken_shift(&self) -> bool {
+        self.breaks
+            .iter()
+            .any(|b| !b.is_paid && b.effective_kind() == BreakKind::ShiftGap)
     }
 
     /// Returns the day of the week for the shift.
@@ -104,12 +281,202 @@ impl Shift {
     ///     start_time: NaiveDateTime::parse_from_str("2026-01-15 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
     ///     end_time: NaiveDateTime::parse_from_str("2026-01-15 17:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
     ///     breaks: vec![],
+    ///     rate_override: None,
+    ///     classification_code: None,
+    ///     reference: None,
+    ///     cost_centre: None,
     /// };
     /// assert_eq!(shift.day_of_week(), Weekday::Thu);
     /// ```
     pub fn day_of_week(&self) -> Weekday {
         self.date.weekday()
     }
+
+    /// Returns whether the shift runs past the midnight after it starts.
+    ///
+    /// A shift that ends exactly at midnight does not cross it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use award_engine::models::Shift;
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let shift = Shift {
+    ///     id: "shift_001".to_string(),
+    ///     date: NaiveDate::from_ymd_opt(2026, 1, 16).unwrap(),
+    ///     start_time: NaiveDateTime::parse_from_str("2026-01-16 22:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+    ///     end_time: NaiveDateTime::parse_from_str("2026-01-17 06:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+    ///     breaks: vec![],
+    ///     rate_override: None,
+    ///     classification_code: None,
+    ///     reference: None,
+    ///     cost_centre: None,
+    /// };
+    /// assert!(shift.crosses_midnight());
+    /// ```
+    pub fn crosses_midnight(&self) -> bool {
+        let next_midnight = (self.start_time.date() + Days::new(1)).and_time(NaiveTime::MIN);
+        self.end_time > next_midnight
+    }
+}
+
+/// Builds a [`Shift`] from a date and wall-clock times.
+///
+/// The date is given as `YYYY-MM-DD` and times as `HH:MM` or `HH:MM:SS`. A
+/// finish at or before the start is on the next day, as is a break time
+/// before the shift start, so overnight shifts need no second date. Times that
+/// cannot be read are reported by [`build`](ShiftBuilder::build).
+///
+/// # Examples
+///
+/// ```
+/// use award_engine::models::Shift;
+/// use rust_decimal::Decimal;
+///
+/// let shift = Shift::builder("shift_001")
+///     .shift("2026-01-17", "09:00", "17:30")
+///     .unpaid_break("12:00", "12:30")
+///     .build()
+///     .unwrap();
+/// assert_eq!(shift.worked_hours(), Decimal::from(8));
+///
+/// let night = Shift::builder("shift_002")
+///     .shift("2026-01-17", "22:00", "06:00")
+///     .build()
+///     .unwrap();
+/// assert!(night.crosses_midnight());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShiftBuilder {
+    id: String,
+    times: Option<(String, String, String)>,
+    breaks: Vec<(String, String, bool)>,
+    rate_override: Option<Decimal>,
+    classification_code: Option<String>,
+    reference: Option<String>,
+    cost_centre: Option<String>,
+}
+
+impl ShiftBuilder {
+    /// Starts building a shift with the given ID.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            times: None,
+            breaks: Vec::new(),
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    /// Sets the shift's date and its start and finish times.
+    pub fn shift(
+        mut self,
+        date: impl Into<String>,
+        start: impl Into<String>,
+        end: impl Into<String>,
+    ) -> Self {
+        self.times = Some((date.into(), start.into(), end.into()));
+        self
+    }
+
+    /// Adds an unpaid break.
+    pub fn unpaid_break(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.breaks.push((start.into(), end.into(), false));
+        self
+    }
+
+    /// Adds a paid break.
+    pub fn paid_break(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.breaks.push((start.into(), end.into(), true));
+        self
+    }
+
+    /// Sets an agreed hourly rate for this shift only.
+    pub fn rate_override(mut self, rate: Decimal) -> Self {
+        self.rate_override = Some(rate);
+        self
+    }
+
+    /// Sets the classification the shift is worked under.
+    pub fn classification(mut self, code: impl Into<String>) -> Self {
+        self.classification_code = Some(code.into());
+        self
+    }
+
+    /// Sets the payroll reference copied onto the shift's pay lines.
+    pub fn reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Sets the cost centre copied onto the shift's pay lines.
+    pub fn cost_centre(mut self, cost_centre: impl Into<String>) -> Self {
+        self.cost_centre = Some(cost_centre.into());
+        self
+    }
+
+    /// Builds the shift.
+    ///
+    /// Fails with [`EngineError::InvalidShift`] when the date and times were
+    /// not set or cannot be read. Other checks, such as breaks lying within
+    /// the shift, are left to request validation as for any other shift.
+    pub fn build(self) -> Result<Shift, EngineError> {
+        let invalid = |message: String| EngineError::InvalidShift {
+            shift_id: self.id.clone(),
+            message,
+        };
+        let Some((date, start, end)) = &self.times else {
+            return Err(invalid("date and times must be set".to_string()));
+        };
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| invalid(format!("'{}' is not a YYYY-MM-DD date", date)))?;
+        let time = |time: &str| {
+            NaiveTime::parse_from_str(time, "%H:%M:%S")
+                .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M"))
+                .map_err(|_| invalid(format!("'{}' is not an HH:MM time", time)))
+        };
+
+        let start_time = date.and_time(time(start)?);
+        // A time before the one it follows is on the next day
+        let after = |earliest: NaiveDateTime, time: NaiveTime| {
+            let on_date = date.and_time(time);
+            if on_date < earliest {
+                on_date + Days::new(1)
+            } else {
+                on_date
+            }
+        };
+        let end_time = match date.and_time(time(end)?) {
+            end_time if end_time <= start_time => end_time + Days::new(1),
+            end_time => end_time,
+        };
+        let mut breaks = Vec::with_capacity(self.breaks.len());
+        for (start, end, is_paid) in &self.breaks {
+            let break_start = after(start_time, time(start)?);
+            breaks.push(Break {
+                start_time: break_start,
+                end_time: after(break_start, time(end)?),
+                is_paid: *is_paid,
+                kind: None,
+            });
+        }
+
+        Ok(Shift {
+            id: self.id.clone(),
+            date,
+            start_time,
+            end_time,
+            breaks,
+            rate_override: self.rate_override,
+            classification_code: self.classification_code,
+            reference: self.reference,
+            cost_centre: self.cost_centre,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -134,6 +501,10 @@ mod tests {
             start_time: make_datetime("2026-01-15", "09:00:00"),
             end_time: make_datetime("2026-01-15", "17:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         assert_eq!(shift.worked_hours(), Decimal::new(80, 1)); // 8.0
@@ -151,10 +522,16 @@ mod tests {
                 start_time: make_datetime("2026-01-15", "12:00:00"),
                 end_time: make_datetime("2026-01-15", "12:30:00"),
                 is_paid: false,
+                kind: None,
             }],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         assert_eq!(shift.worked_hours(), Decimal::new(80, 1)); // 8.0
+        assert_eq!(shift.gross_hours(), Decimal::new(85, 1)); // 8.5
     }
 
     /// SH-003: 8.5 hour shift with 30min paid break
@@ -169,7 +546,12 @@ mod tests {
                 start_time: make_datetime("2026-01-15", "12:00:00"),
                 end_time: make_datetime("2026-01-15", "12:30:00"),
                 is_paid: true,
+                kind: None,
             }],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         assert_eq!(shift.worked_hours(), Decimal::new(85, 1)); // 8.5
@@ -184,6 +566,10 @@ mod tests {
             start_time: make_datetime("2026-01-15", "22:00:00"),
             end_time: make_datetime("2026-01-16", "06:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         assert_eq!(shift.worked_hours(), Decimal::new(80, 1)); // 8.0
@@ -198,6 +584,10 @@ mod tests {
             start_time: make_datetime("2026-01-15", "09:00:00"),
             end_time: make_datetime("2026-01-15", "09:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         assert_eq!(shift.worked_hours(), Decimal::new(0, 0)); // 0.0
@@ -212,6 +602,10 @@ mod tests {
             start_time: make_datetime("2026-01-15", "09:00:00"),
             end_time: make_datetime("2026-01-15", "17:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
         assert_eq!(shift.day_of_week(), Weekday::Thu);
 
@@ -222,6 +616,10 @@ mod tests {
             start_time: make_datetime("2026-01-17", "09:00:00"),
             end_time: make_datetime("2026-01-17", "17:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
         assert_eq!(saturday_shift.day_of_week(), Weekday::Sat);
 
@@ -232,6 +630,10 @@ mod tests {
             start_time: make_datetime("2026-01-18", "09:00:00"),
             end_time: make_datetime("2026-01-18", "17:00:00"),
             breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
         assert_eq!(sunday_shift.day_of_week(), Weekday::Sun);
     }
@@ -247,7 +649,12 @@ mod tests {
                 start_time: make_datetime("2026-01-15", "12:00:00"),
                 end_time: make_datetime("2026-01-15", "12:30:00"),
                 is_paid: false,
+                kind: None,
             }],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         let json = serde_json::to_string(&shift).unwrap();
@@ -289,22 +696,194 @@ mod tests {
                     start_time: make_datetime("2026-01-15", "10:00:00"),
                     end_time: make_datetime("2026-01-15", "10:15:00"), // 15 min paid
                     is_paid: true,
+                    kind: None,
                 },
                 Break {
                     start_time: make_datetime("2026-01-15", "12:00:00"),
                     end_time: make_datetime("2026-01-15", "12:30:00"), // 30 min unpaid
                     is_paid: false,
+                    kind: None,
                 },
                 Break {
                     start_time: make_datetime("2026-01-15", "15:00:00"),
                     end_time: make_datetime("2026-01-15", "15:15:00"), // 15 min unpaid
                     is_paid: false,
+                    kind: None,
                 },
             ],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
         };
 
         // 10 hours - 45 min unpaid = 9.25 hours
         // (600 minutes - 45 minutes) / 60 = 555 / 60 = 9.25
         assert_eq!(shift.worked_hours(), Decimal::new(925, 2)); // 9.25
     }
+
+    fn make_break(start: &str, end: &str, is_paid: bool, kind: Option<BreakKind>) -> Break {
+        Break {
+            start_time: make_datetime("2026-01-15", start),
+            end_time: make_datetime("2026-01-15", end),
+            is_paid,
+            kind,
+        }
+    }
+
+    fn make_shift_with_breaks(breaks: Vec<Break>) -> Shift {
+        Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-15"),
+            start_time: make_datetime("2026-01-15", "07:00:00"),
+            end_time: make_datetime("2026-01-15", "19:00:00"),
+            breaks,
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        }
+    }
+
+    #[test]
+    fn test_short_unpaid_break_is_not_broken_shift() {
+        let shift = make_shift_with_breaks(vec![make_break("12:00:00", "12:30:00", false, None)]);
+
+        assert_eq!(shift.breaks[0].effective_kind(), BreakKind::MealBreak);
+        assert!(!shift.is_broken_shift());
+    }
+
+    #[test]
+    fn test_long_unpaid_gap_is_broken_shift() {
+        let shift = make_shift_with_breaks(vec![make_break("11:00:00", "15:00:00", false, None)]);
+
+        assert_eq!(shift.breaks[0].effective_kind(), BreakKind::ShiftGap);
+        assert!(shift.is_broken_shift());
+        // Shift gaps are deducted from worked hours like meal breaks: 12h - 4h
+        assert_eq!(shift.worked_hours(), Decimal::new(80, 1));
+    }
+
+    #[test]
+    fn test_explicit_kind_overrides_duration() {
+        let long_meal = make_shift_with_breaks(vec![make_break(
+            "11:00:00",
+            "13:00:00",
+            false,
+            Some(BreakKind::MealBreak),
+        )]);
+        assert!(!long_meal.is_broken_shift());
+
+        let short_gap = make_shift_with_breaks(vec![make_break(
+            "12:00:00",
+            "12:45:00",
+            false,
+            Some(BreakKind::ShiftGap),
+        )]);
+        assert!(short_gap.is_broken_shift());
+    }
+
+    #[test]
+    fn test_paid_gap_is_not_broken_shift() {
+        let shift = make_shift_with_breaks(vec![make_break("11:00:00", "15:00:00", true, None)]);
+
+        assert!(!shift.is_broken_shift());
+    }
+
+    #[test]
+    fn test_break_kind_deserialization() {
+        let json = r#"{
+            "start_time": "2026-01-15T11:00:00",
+            "end_time": "2026-01-15T15:00:00",
+            "is_paid": false,
+            "kind": "shift_gap"
+        }"#;
+
+        let brk: Break = serde_json::from_str(json).unwrap();
+        assert_eq!(brk.kind, Some(BreakKind::ShiftGap));
+    }
+
+    #[test]
+    fn test_crosses_midnight() {
+        let shift = |end_date: &str, end_time: &str| Shift {
+            id: "shift_001".to_string(),
+            date: make_date("2026-01-16"),
+            start_time: make_datetime("2026-01-16", "22:00:00"),
+            end_time: make_datetime(end_date, end_time),
+            breaks: vec![],
+            rate_override: None,
+            classification_code: None,
+            reference: None,
+            cost_centre: None,
+        };
+
+        assert!(!shift("2026-01-16", "23:30:00").crosses_midnight());
+        assert!(shift("2026-01-17", "06:00:00").crosses_midnight());
+        // Ending exactly at midnight does not cross it
+        assert!(!shift("2026-01-17", "00:00:00").crosses_midnight());
+    }
+
+    #[test]
+    fn test_builder_matches_a_hand_built_shift() {
+        let shift = Shift::builder("shift_001")
+            .shift("2026-01-15", "09:00", "17:30:00")
+            .unpaid_break("12:00", "12:30")
+            .reference("GL-100")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            shift,
+            Shift {
+                id: "shift_001".to_string(),
+                date: make_date("2026-01-15"),
+                start_time: make_datetime("2026-01-15", "09:00:00"),
+                end_time: make_datetime("2026-01-15", "17:30:00"),
+                breaks: vec![Break {
+                    start_time: make_datetime("2026-01-15", "12:00:00"),
+                    end_time: make_datetime("2026-01-15", "12:30:00"),
+                    is_paid: false,
+                    kind: None,
+                }],
+                rate_override: None,
+                classification_code: None,
+                reference: Some("GL-100".to_string()),
+                cost_centre: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_builder_puts_times_after_midnight_on_the_next_day() {
+        let shift = Shift::builder("shift_001")
+            .shift("2026-01-16", "22:00", "06:00")
+            .paid_break("23:45", "00:15")
+            .unpaid_break("02:00", "02:30")
+            .build()
+            .unwrap();
+
+        assert_eq!(shift.end_time, make_datetime("2026-01-17", "06:00:00"));
+        assert_eq!(
+            shift.breaks[0].end_time,
+            make_datetime("2026-01-17", "00:15:00")
+        );
+        assert_eq!(
+            shift.breaks[1].start_time,
+            make_datetime("2026-01-17", "02:00:00")
+        );
+        assert_eq!(shift.worked_hours(), Decimal::new(75, 1));
+    }
+
+    #[test]
+    fn test_builder_rejects_missing_or_unreadable_times() {
+        let missing = Shift::builder("shift_001").build();
+        assert!(matches!(missing, Err(EngineError::InvalidShift { .. })));
+
+        let unreadable = Shift::builder("shift_001")
+            .shift("2026-01-16", "9am", "17:00")
+            .build();
+        assert!(matches!(
+            unreadable,
+            Err(EngineError::InvalidShift { ref message, .. }) if message.contains("'9am'")
+        ));
+    }
 }
@@ -10,19 +10,21 @@
 //! - Casual vs non-casual employment
 //! - Laundry allowance
 //! - Error cases
+//! - Request validation
 
 use axum::{
+    Router,
     body::Body,
     http::{Request, StatusCode},
-    Router,
 };
 use rust_decimal::Decimal;
-use serde_json::{json, Value};
+use serde_json::{Value, json};
 use std::str::FromStr;
 use tower::ServiceExt;
 
-use award_engine::api::{create_router, AppState};
+use award_engine::api::{AppState, create_router};
 use award_engine::config::ConfigLoader;
+use award_engine::models::RESULT_SCHEMA_VERSION;
 
 // =============================================================================
 // Test Helpers
@@ -49,11 +51,19 @@ fn normalize_decimal(s: &str) -> String {
 }
 
 async fn post_calculate(router: Router, body: Value) -> (StatusCode, Value) {
+    post_json(router, "/calculate", body).await
+}
+
+async fn post_validate(router: Router, body: Value) -> (StatusCode, Value) {
+    post_json(router, "/validate", body).await
+}
+
+async fn post_json(router: Router, uri: &str, body: Value) -> (StatusCode, Value) {
     let response = router
         .oneshot(
             Request::builder()
                 .method("POST")
-                .uri("/calculate")
+                .uri(uri)
                 .header("Content-Type", "application/json")
                 .body(Body::from(body.to_string()))
                 .unwrap(),
@@ -611,7 +621,7 @@ async fn test_sunday_10h_includes_overtime() {
 }
 
 // =============================================================================
-// SECTION 4: Overnight Shift Splitting Tests - 5 tests
+// SECTION 4: Overnight Shift Splitting Tests - 7 tests
 // =============================================================================
 
 #[tokio::test]
@@ -756,8 +766,72 @@ async fn test_overnight_parttime_fri_to_sat() {
     assert_penalty_hours_approx(&result, "3");
 }
 
+#[tokio::test]
+async fn test_overnight_across_spring_forward_with_timezone() {
+    // Full-time employee, Saturday 10pm to Sunday 6am in Sydney on the night
+    // clocks go forward (2am becomes 3am), so only 7h are actually worked
+    // Saturday portion (10pm-midnight): 2h * $28.54 * 1.50 = $85.62
+    // Sunday portion (midnight-6am): 5h * $28.54 * 1.75 = $249.725
+    // Total: $335.345
+    let router = create_router_for_test();
+    let mut request = create_request(
+        "emp_ft_on_dst_001",
+        "full_time",
+        vec![],
+        "2025-09-29",
+        "2025-10-05",
+        vec![create_shift(
+            "shift_001",
+            "2025-10-04", // Saturday
+            "2025-10-04T22:00:00",
+            "2025-10-05T06:00:00", // Sunday
+        )],
+    );
+    request["timezone"] = json!("Australia/Sydney");
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_gross_pay_approx(&result, "335.345");
+    assert_penalty_hours_approx(&result, "7");
+    let steps = result["audit_trace"]["steps"].as_array().unwrap();
+    assert!(
+        steps
+            .iter()
+            .any(|s| s["rule_id"] == "daylight_saving_adjustment")
+    );
+}
+
+#[tokio::test]
+async fn test_overnight_across_spring_forward_without_timezone() {
+    // Without a timezone, shift times are naive wall-clock times: 8h are paid
+    // Saturday portion (10pm-midnight): 2h * $28.54 * 1.50 = $85.62
+    // Sunday portion (midnight-6am): 6h * $28.54 * 1.75 = $299.67
+    // Total: $385.29
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_ft_on_dst_002",
+        "full_time",
+        vec![],
+        "2025-09-29",
+        "2025-10-05",
+        vec![create_shift(
+            "shift_001",
+            "2025-10-04", // Saturday
+            "2025-10-04T22:00:00",
+            "2025-10-05T06:00:00", // Sunday
+        )],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_gross_pay_approx(&result, "385.29");
+    assert_penalty_hours_approx(&result, "8");
+}
+
 // =============================================================================
-// SECTION 5: Daily Overtime (Weekday) Tests - 5 tests
+// SECTION 5: Daily Overtime (Weekday) Tests - 7 tests
 // These tests verify overtime calculations based on actual engine behavior
 // =============================================================================
 
@@ -902,6 +976,82 @@ async fn test_weekday_overtime_parttime_12h() {
     assert_overtime_hours_approx(&result, "4");
 }
 
+#[tokio::test]
+async fn test_weekday_overtime_two_5h_shifts_same_day() {
+    // Full-time employee, two separate 5-hour shifts on the same Tuesday
+    // The daily threshold applies to the day's total of 10 hours:
+    // shift_001: 5h ordinary; shift_002: 3h ordinary + 2h overtime
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_ft_ot_split",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![
+            create_shift(
+                "shift_002",
+                "2026-01-13", // Tuesday
+                "2026-01-13T15:00:00",
+                "2026-01-13T20:00:00",
+            ),
+            create_shift(
+                "shift_001",
+                "2026-01-13", // Tuesday
+                "2026-01-13T06:00:00",
+                "2026-01-13T11:00:00",
+            ),
+        ],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_ordinary_hours_approx(&result, "8");
+    assert_overtime_hours_approx(&result, "2");
+    // 8h × $28.54 = $228.32 + 2h × $42.81 = $85.62
+    assert_gross_pay_approx(&result, "313.94");
+
+    // Overtime is attributed to the later shift, even though it was listed first
+    let pay_lines = result["pay_lines"].as_array().unwrap();
+    let overtime_lines: Vec<&Value> = pay_lines
+        .iter()
+        .filter(|pl| pl["category"].as_str().unwrap().contains("overtime"))
+        .collect();
+    assert_eq!(overtime_lines.len(), 1);
+    assert_eq!(overtime_lines[0]["shift_id"], "shift_002");
+}
+
+#[tokio::test]
+async fn test_part_time_ordinary_cap_below_threshold() {
+    // Part-timer with an agreed 6-hour day works 8 hours on a Tuesday
+    // Ordinary: 6h * $28.54 = $171.24
+    // Overtime: 2h * $28.54 * 1.50 = $85.62
+    // Total: $256.86
+    let router = create_router_for_test();
+    let mut request = create_request(
+        "emp_pt_cap_001",
+        "part_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-13",
+            "2026-01-13T09:00:00",
+            "2026-01-13T17:00:00",
+        )],
+    );
+    request["employee"]["max_ordinary_hours_per_day"] = json!("6");
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_ordinary_hours_approx(&result, "6");
+    assert_overtime_hours_approx(&result, "2");
+    assert_gross_pay_approx(&result, "256.86");
+}
+
 // =============================================================================
 // SECTION 6: Daily Overtime (Weekend) Tests - 5 tests
 // =============================================================================
@@ -1042,7 +1192,7 @@ async fn test_saturday_overtime_parttime_12h() {
 }
 
 // =============================================================================
-// SECTION 7: Casual vs Non-Casual Tests - 5 tests
+// SECTION 7: Casual vs Non-Casual Tests - 6 tests
 // =============================================================================
 
 #[tokio::test]
@@ -1218,8 +1368,66 @@ async fn test_parttime_same_as_fulltime_weekday() {
     assert_eq!(ft_pay, pt_pay);
 }
 
+#[tokio::test]
+async fn test_leave_accrues_for_permanent_but_not_casual_employees() {
+    // Weekday 09:00-17:00 and Saturday 09:00-15:00: 14 ordinary hours paid
+    // Expected: 14 / 13 = 1.0769 annual, 14 / 26 = 0.5385 personal leave
+    let shifts = || {
+        vec![
+            create_shift(
+                "shift_001",
+                "2026-01-13",
+                "2026-01-13T09:00:00",
+                "2026-01-13T17:00:00",
+            ),
+            create_shift(
+                "shift_002",
+                "2026-01-17",
+                "2026-01-17T09:00:00",
+                "2026-01-17T15:00:00",
+            ),
+        ]
+    };
+    let request_ft = create_request(
+        "emp_acc_001",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        shifts(),
+    );
+    let (status, result_ft) = post_calculate(create_router_for_test(), request_ft).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let accruals = &result_ft["accruals"];
+    assert_eq!(
+        normalize_decimal(accruals["accrual_hours"].as_str().unwrap()),
+        "14"
+    );
+    // The Saturday hours accrue leave but are not ordinary hours in the totals
+    assert_eq!(
+        normalize_decimal(result_ft["totals"]["ordinary_hours"].as_str().unwrap()),
+        "8"
+    );
+    assert_eq!(accruals["annual_leave_hours"], "1.0769");
+    assert_eq!(accruals["personal_leave_hours"], "0.5385");
+
+    let request_cas = create_request(
+        "emp_acc_002",
+        "casual",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        shifts(),
+    );
+    let (_, result_cas) = post_calculate(create_router_for_test(), request_cas).await;
+
+    assert_eq!(result_cas["accruals"]["annual_leave_hours"], "0");
+    assert_eq!(result_cas["accruals"]["personal_leave_hours"], "0");
+}
+
 // =============================================================================
-// SECTION 8: Laundry Allowance Tests - 6 tests
+// SECTION 8: Laundry & Qualification Allowance Tests - 8 tests
 // =============================================================================
 
 #[tokio::test]
@@ -1249,7 +1457,10 @@ async fn test_laundry_allowance_single_shift() {
     let allowances = result["allowances"].as_array().unwrap();
     assert_eq!(allowances.len(), 1);
     assert_eq!(allowances[0]["type"], "laundry");
-    assert_eq!(normalize_decimal(allowances[0]["amount"].as_str().unwrap()), "0.32");
+    assert_eq!(
+        normalize_decimal(allowances[0]["amount"].as_str().unwrap()),
+        "0.32"
+    );
 }
 
 #[tokio::test]
@@ -1264,10 +1475,30 @@ async fn test_laundry_allowance_4_shifts() {
         "2026-01-12",
         "2026-01-18",
         vec![
-            create_shift("s1", "2026-01-12", "2026-01-12T09:00:00", "2026-01-12T17:00:00"),
-            create_shift("s2", "2026-01-13", "2026-01-13T09:00:00", "2026-01-13T17:00:00"),
-            create_shift("s3", "2026-01-14", "2026-01-14T09:00:00", "2026-01-14T17:00:00"),
-            create_shift("s4", "2026-01-15", "2026-01-15T09:00:00", "2026-01-15T17:00:00"),
+            create_shift(
+                "s1",
+                "2026-01-12",
+                "2026-01-12T09:00:00",
+                "2026-01-12T17:00:00",
+            ),
+            create_shift(
+                "s2",
+                "2026-01-13",
+                "2026-01-13T09:00:00",
+                "2026-01-13T17:00:00",
+            ),
+            create_shift(
+                "s3",
+                "2026-01-14",
+                "2026-01-14T09:00:00",
+                "2026-01-14T17:00:00",
+            ),
+            create_shift(
+                "s4",
+                "2026-01-15",
+                "2026-01-15T09:00:00",
+                "2026-01-15T17:00:00",
+            ),
         ],
     );
 
@@ -1278,7 +1509,10 @@ async fn test_laundry_allowance_4_shifts() {
     let allowances = result["allowances"].as_array().unwrap();
     assert_eq!(allowances.len(), 1);
     // 4 * $0.32 = $1.28 (under cap)
-    assert_eq!(normalize_decimal(allowances[0]["amount"].as_str().unwrap()), "1.28");
+    assert_eq!(
+        normalize_decimal(allowances[0]["amount"].as_str().unwrap()),
+        "1.28"
+    );
 }
 
 #[tokio::test]
@@ -1293,11 +1527,36 @@ async fn test_laundry_allowance_5_shifts_hits_cap() {
         "2026-01-12",
         "2026-01-18",
         vec![
-            create_shift("s1", "2026-01-12", "2026-01-12T09:00:00", "2026-01-12T17:00:00"),
-            create_shift("s2", "2026-01-13", "2026-01-13T09:00:00", "2026-01-13T17:00:00"),
-            create_shift("s3", "2026-01-14", "2026-01-14T09:00:00", "2026-01-14T17:00:00"),
-            create_shift("s4", "2026-01-15", "2026-01-15T09:00:00", "2026-01-15T17:00:00"),
-            create_shift("s5", "2026-01-16", "2026-01-16T09:00:00", "2026-01-16T17:00:00"),
+            create_shift(
+                "s1",
+                "2026-01-12",
+                "2026-01-12T09:00:00",
+                "2026-01-12T17:00:00",
+            ),
+            create_shift(
+                "s2",
+                "2026-01-13",
+                "2026-01-13T09:00:00",
+                "2026-01-13T17:00:00",
+            ),
+            create_shift(
+                "s3",
+                "2026-01-14",
+                "2026-01-14T09:00:00",
+                "2026-01-14T17:00:00",
+            ),
+            create_shift(
+                "s4",
+                "2026-01-15",
+                "2026-01-15T09:00:00",
+                "2026-01-15T17:00:00",
+            ),
+            create_shift(
+                "s5",
+                "2026-01-16",
+                "2026-01-16T09:00:00",
+                "2026-01-16T17:00:00",
+            ),
         ],
     );
 
@@ -1308,7 +1567,10 @@ async fn test_laundry_allowance_5_shifts_hits_cap() {
     let allowances = result["allowances"].as_array().unwrap();
     assert_eq!(allowances.len(), 1);
     // 5 * $0.32 = $1.60, capped at $1.49
-    assert_eq!(normalize_decimal(allowances[0]["amount"].as_str().unwrap()), "1.49");
+    assert_eq!(
+        normalize_decimal(allowances[0]["amount"].as_str().unwrap()),
+        "1.49"
+    );
 }
 
 #[tokio::test]
@@ -1323,13 +1585,48 @@ async fn test_laundry_allowance_weekly_cap() {
         "2026-01-12",
         "2026-01-18",
         vec![
-            create_shift("s1", "2026-01-12", "2026-01-12T09:00:00", "2026-01-12T17:00:00"),
-            create_shift("s2", "2026-01-13", "2026-01-13T09:00:00", "2026-01-13T17:00:00"),
-            create_shift("s3", "2026-01-14", "2026-01-14T09:00:00", "2026-01-14T17:00:00"),
-            create_shift("s4", "2026-01-15", "2026-01-15T09:00:00", "2026-01-15T17:00:00"),
-            create_shift("s5", "2026-01-16", "2026-01-16T09:00:00", "2026-01-16T17:00:00"),
-            create_shift("s6", "2026-01-17", "2026-01-17T09:00:00", "2026-01-17T17:00:00"),
-            create_shift("s7", "2026-01-18", "2026-01-18T09:00:00", "2026-01-18T17:00:00"),
+            create_shift(
+                "s1",
+                "2026-01-12",
+                "2026-01-12T09:00:00",
+                "2026-01-12T17:00:00",
+            ),
+            create_shift(
+                "s2",
+                "2026-01-13",
+                "2026-01-13T09:00:00",
+                "2026-01-13T17:00:00",
+            ),
+            create_shift(
+                "s3",
+                "2026-01-14",
+                "2026-01-14T09:00:00",
+                "2026-01-14T17:00:00",
+            ),
+            create_shift(
+                "s4",
+                "2026-01-15",
+                "2026-01-15T09:00:00",
+                "2026-01-15T17:00:00",
+            ),
+            create_shift(
+                "s5",
+                "2026-01-16",
+                "2026-01-16T09:00:00",
+                "2026-01-16T17:00:00",
+            ),
+            create_shift(
+                "s6",
+                "2026-01-17",
+                "2026-01-17T09:00:00",
+                "2026-01-17T17:00:00",
+            ),
+            create_shift(
+                "s7",
+                "2026-01-18",
+                "2026-01-18T09:00:00",
+                "2026-01-18T17:00:00",
+            ),
         ],
     );
 
@@ -1340,7 +1637,10 @@ async fn test_laundry_allowance_weekly_cap() {
     let allowances = result["allowances"].as_array().unwrap();
     assert_eq!(allowances.len(), 1);
     // 7 * $0.32 = $2.24, capped at $1.49
-    assert_eq!(normalize_decimal(allowances[0]["amount"].as_str().unwrap()), "1.49");
+    assert_eq!(
+        normalize_decimal(allowances[0]["amount"].as_str().unwrap()),
+        "1.49"
+    );
 }
 
 #[tokio::test]
@@ -1398,11 +1698,102 @@ async fn test_casual_with_laundry() {
 
     let allowances = result["allowances"].as_array().unwrap();
     assert_eq!(allowances.len(), 1);
-    assert_eq!(normalize_decimal(allowances[0]["amount"].as_str().unwrap()), "0.32");
+    assert_eq!(
+        normalize_decimal(allowances[0]["amount"].as_str().unwrap()),
+        "0.32"
+    );
+}
+
+#[tokio::test]
+async fn test_qualification_allowance_paid_weekly() {
+    // Employee with qualification and laundry tags, 2 shifts
+    // Qualification: $21.40 per week; Laundry: 2 * $0.32 = $0.64
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_qual_001",
+        "full_time",
+        vec!["laundry_allowance", "qualification_allowance"],
+        "2026-01-12",
+        "2026-01-18",
+        vec![
+            create_shift(
+                "shift_001",
+                "2026-01-13",
+                "2026-01-13T09:00:00",
+                "2026-01-13T17:00:00",
+            ),
+            create_shift(
+                "shift_002",
+                "2026-01-14",
+                "2026-01-14T09:00:00",
+                "2026-01-14T17:00:00",
+            ),
+        ],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+
+    let allowances = result["allowances"].as_array().unwrap();
+    assert_eq!(allowances.len(), 2);
+    assert_eq!(allowances[1]["type"], "qualification");
+    assert_eq!(
+        normalize_decimal(allowances[1]["amount"].as_str().unwrap()),
+        "21.4"
+    );
+    assert_eq!(
+        normalize_decimal(result["totals"]["allowances_total"].as_str().unwrap()),
+        "22.04"
+    );
+    let by_type = &result["totals"]["allowances_by_type"];
+    assert_eq!(
+        normalize_decimal(by_type["laundry"].as_str().unwrap()),
+        "0.64"
+    );
+    assert_eq!(
+        normalize_decimal(by_type["qualification"].as_str().unwrap()),
+        "21.4"
+    );
+    // 16h × $28.54 = $456.64 + $22.04 allowances
+    assert_gross_pay_approx(&result, "478.68");
+}
+
+#[tokio::test]
+async fn test_misspelt_tag_warns_unknown_tag() {
+    // Typo in the laundry tag: no allowance, but an UNKNOWN_TAG warning
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_tag_001",
+        "full_time",
+        vec!["laundy_allowance"],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-13",
+            "2026-01-13T09:00:00",
+            "2026-01-13T17:00:00",
+        )],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(result["allowances"].as_array().unwrap().is_empty());
+    let warnings = result["audit_trace"]["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0]["code"], "UNKNOWN_TAG");
+    assert!(
+        warnings[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("laundy_allowance")
+    );
 }
 
 // =============================================================================
-// SECTION 9: Error Cases Tests - 6 tests
+// SECTION 9: Error Cases Tests - 10 tests
 // =============================================================================
 
 #[tokio::test]
@@ -1452,7 +1843,8 @@ async fn test_error_missing_employee_id() {
     let (status, error) = post_calculate(router, body).await;
 
     assert_eq!(status, StatusCode::BAD_REQUEST);
-    assert!(error["message"].as_str().unwrap().contains("missing field"));
+    assert_eq!(error["code"], "MISSING_FIELD");
+    assert_eq!(error["message"], "missing field: employee.id");
 }
 
 #[tokio::test]
@@ -1501,7 +1893,8 @@ async fn test_error_missing_shifts_array() {
     let (status, error) = post_calculate(router, body).await;
 
     assert_eq!(status, StatusCode::BAD_REQUEST);
-    assert!(error["message"].as_str().unwrap().contains("missing field"));
+    assert_eq!(error["code"], "MISSING_FIELD");
+    assert_eq!(error["message"], "missing field: shifts");
 }
 
 #[tokio::test]
@@ -1527,10 +1920,7 @@ async fn test_error_invalid_employment_type() {
 
     assert_eq!(status, StatusCode::BAD_REQUEST);
     // Should fail validation for unknown employment type
-    assert!(
-        error["code"].as_str().unwrap() == "VALIDATION_ERROR"
-            || error["code"].as_str().unwrap() == "MALFORMED_JSON"
-    );
+    assert_eq!(error["code"], "VALIDATION_ERROR");
 }
 
 #[tokio::test]
@@ -1554,35 +1944,167 @@ async fn test_error_missing_pay_period() {
     assert!(error["message"].as_str().unwrap().contains("missing field"));
 }
 
-// =============================================================================
-// SECTION 10: Audit Trace & Response Field Validation Tests - 4 tests
-// =============================================================================
-
 #[tokio::test]
-async fn test_audit_trace_contains_steps() {
+async fn test_error_break_starting_before_shift() {
     let router = create_router_for_test();
+    let mut shift = create_shift(
+        "shift_001",
+        "2026-01-13",
+        "2026-01-13T09:00:00",
+        "2026-01-13T17:00:00",
+    );
+    shift["breaks"] = json!([{
+        "start_time": "2026-01-13T08:30:00",
+        "end_time": "2026-01-13T09:30:00",
+        "is_paid": false
+    }]);
     let request = create_request(
-        "emp_audit_001",
+        "emp_err_007",
         "full_time",
         vec![],
         "2026-01-12",
         "2026-01-18",
-        vec![create_shift(
-            "shift_001",
-            "2026-01-13",
-            "2026-01-13T09:00:00",
-            "2026-01-13T17:00:00",
-        )],
+        vec![shift],
     );
 
-    let (status, result) = post_calculate(router, request).await;
-
-    assert_eq!(status, StatusCode::OK);
+    let (status, error) = post_calculate(router, request).await;
 
-    let audit_trace = &result["audit_trace"];
-    let steps = audit_trace["steps"].as_array().unwrap();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error["code"], "INVALID_BREAK");
+    let message = error["message"].as_str().unwrap();
+    assert!(message.contains("Invalid break 0 in shift 'shift_001'"));
+    assert!(message.contains("outside the shift"));
+}
 
-    // Should have at least base rate and ordinary hours steps
+#[tokio::test]
+async fn test_error_break_duration_overrunning_shift() {
+    let router = create_router_for_test();
+    let mut shift = create_shift(
+        "shift_001",
+        "2026-01-13",
+        "2026-01-13T09:00:00",
+        "2026-01-13T17:00:00",
+    );
+    shift["breaks"] = json!([{
+        "start_time": "2026-01-13T16:45:00",
+        "duration": "PT30M",
+        "is_paid": false
+    }]);
+    let request = create_request(
+        "emp_err_010",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![shift],
+    );
+
+    let (status, error) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error["code"], "INVALID_BREAK");
+    let message = error["message"].as_str().unwrap();
+    assert!(message.contains("Invalid break 0 in shift 'shift_001'"));
+    assert!(message.contains("outside the shift"));
+}
+
+#[tokio::test]
+async fn test_error_overlapping_breaks() {
+    let router = create_router_for_test();
+    let mut shift = create_shift(
+        "shift_001",
+        "2026-01-13",
+        "2026-01-13T09:00:00",
+        "2026-01-13T17:00:00",
+    );
+    shift["breaks"] = json!([
+        {
+            "start_time": "2026-01-13T12:00:00",
+            "end_time": "2026-01-13T12:30:00",
+            "is_paid": false
+        },
+        {
+            "start_time": "2026-01-13T12:15:00",
+            "end_time": "2026-01-13T12:45:00",
+            "is_paid": false
+        }
+    ]);
+    let request = create_request(
+        "emp_err_008",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![shift],
+    );
+
+    let (status, error) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error["code"], "INVALID_BREAK");
+    assert_eq!(
+        error["message"],
+        "Invalid break 1 in shift 'shift_001': overlaps break 0"
+    );
+}
+
+#[tokio::test]
+async fn test_error_negative_base_rate_override() {
+    let router = create_router_for_test();
+    let mut request = create_request(
+        "emp_err_009",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-13",
+            "2026-01-13T09:00:00",
+            "2026-01-13T17:00:00",
+        )],
+    );
+    request["employee"]["base_hourly_rate"] = json!("-28.54");
+
+    let (status, error) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error["code"], "INVALID_BASE_RATE");
+    assert_eq!(
+        error["message"],
+        "Invalid base hourly rate for employee 'emp_err_009': -28.54 must not be negative"
+    );
+}
+
+// =============================================================================
+// SECTION 10: Audit Trace & Response Field Validation Tests - 13 tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_audit_trace_contains_steps() {
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_audit_001",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-13",
+            "2026-01-13T09:00:00",
+            "2026-01-13T17:00:00",
+        )],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+
+    let audit_trace = &result["audit_trace"];
+    let steps = audit_trace["steps"].as_array().unwrap();
+
+    // Should have at least base rate and ordinary hours steps
     assert!(steps.len() >= 2);
 
     // Each step should have required fields
@@ -1694,3 +2216,739 @@ async fn test_pay_line_contains_required_fields() {
     assert!(pay_line["rate"].is_string());
     assert!(pay_line["amount"].is_string());
 }
+
+#[tokio::test]
+async fn test_engine_version_defaults_to_cargo_version() {
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_ver_001",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-13",
+            "2026-01-13T09:00:00",
+            "2026-01-13T17:00:00",
+        )],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["engine_version"], env!("CARGO_PKG_VERSION"));
+    assert!(result.get("build_metadata").is_none());
+}
+
+#[tokio::test]
+async fn test_engine_version_override_and_build_metadata() {
+    let state = create_test_state()
+        .with_engine_version("2026.10.1-prod")
+        .with_build_metadata("git:4f36973");
+    let router = create_router(state);
+    let request = create_request(
+        "emp_ver_002",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-13",
+            "2026-01-13T09:00:00",
+            "2026-01-13T17:00:00",
+        )],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["engine_version"], "2026.10.1-prod");
+    assert_eq!(result["build_metadata"], "git:4f36973");
+    // The schema version does not follow the engine version
+    assert_eq!(result["schema_version"], RESULT_SCHEMA_VERSION);
+}
+
+#[tokio::test]
+async fn test_pay_lines_sorted_by_date_then_category() {
+    // Mixed week submitted out of order: Sunday, Tuesday (with overtime), Saturday
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_sort_001",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![
+            create_shift(
+                "shift_sun",
+                "2026-01-18",
+                "2026-01-18T09:00:00",
+                "2026-01-18T17:00:00",
+            ),
+            create_shift(
+                "shift_tue",
+                "2026-01-13",
+                "2026-01-13T08:00:00",
+                "2026-01-13T18:00:00",
+            ),
+            create_shift(
+                "shift_sat",
+                "2026-01-17",
+                "2026-01-17T09:00:00",
+                "2026-01-17T17:00:00",
+            ),
+        ],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let order: Vec<(&str, &str)> = result["pay_lines"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|pl| {
+            (
+                pl["date"].as_str().unwrap(),
+                pl["category"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            ("2026-01-13", "ordinary"),
+            ("2026-01-13", "overtime150"),
+            ("2026-01-17", "saturday"),
+            ("2026-01-18", "sunday"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_gross_pay_reconciliation_is_last_audit_step() {
+    // Saturday shift with laundry: 8h × $28.54 × 1.5 = $342.48 + $0.32 laundry
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_recon_001",
+        "full_time",
+        vec!["laundry_allowance"],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-17",
+            "2026-01-17T09:00:00",
+            "2026-01-17T17:00:00",
+        )],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let steps = result["audit_trace"]["steps"].as_array().unwrap();
+    let last = steps.last().unwrap();
+    assert_eq!(last["rule_id"], "gross_pay_reconciliation");
+    assert_eq!(last["rule_name"], "Gross Pay Reconciliation");
+
+    let max_step_number = steps
+        .iter()
+        .map(|s| s["step_number"].as_u64().unwrap())
+        .max()
+        .unwrap();
+    assert_eq!(last["step_number"].as_u64().unwrap(), max_step_number);
+    assert_eq!(max_step_number as usize, steps.len());
+
+    assert_eq!(last["input"]["pay_lines_total"], "342.48");
+    assert_eq!(last["input"]["allowances_total"], "0.32");
+    assert_eq!(last["output"]["gross_pay"], "342.8");
+    assert_gross_pay_approx(&result, "342.8");
+}
+
+#[tokio::test]
+async fn test_shift_breakdown_groups_pay_lines_by_shift() {
+    // Weekday 10h shift: 8h ordinary ($228.32) + 2h at 150% ($85.62) = $313.94
+    // Saturday 8h shift: 8h × $28.54 × 1.5 = $342.48
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_breakdown_001",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![
+            create_shift(
+                "shift_sat",
+                "2026-01-17",
+                "2026-01-17T09:00:00",
+                "2026-01-17T17:00:00",
+            ),
+            create_shift(
+                "shift_tue",
+                "2026-01-13",
+                "2026-01-13T08:00:00",
+                "2026-01-13T18:00:00",
+            ),
+        ],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let breakdown = result["shift_breakdown"].as_array().unwrap();
+    assert_eq!(breakdown.len(), 2);
+
+    // Shifts are listed in start order
+    assert_eq!(breakdown[0]["shift_id"], "shift_tue");
+    assert_eq!(breakdown[0]["pay_lines"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        normalize_decimal(breakdown[0]["subtotal"].as_str().unwrap()),
+        "313.94"
+    );
+    assert_eq!(breakdown[1]["shift_id"], "shift_sat");
+    assert_eq!(breakdown[1]["pay_lines"].as_array().unwrap().len(), 1);
+    assert_eq!(
+        normalize_decimal(breakdown[1]["subtotal"].as_str().unwrap()),
+        "342.48"
+    );
+
+    assert_gross_pay_approx(&result, "656.42");
+}
+
+#[tokio::test]
+async fn test_applied_clauses_lists_each_clause_once_in_order() {
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_clauses_001",
+        "full_time",
+        vec!["laundry_allowance"],
+        "2026-01-12",
+        "2026-01-18",
+        vec![
+            create_shift(
+                "shift_001",
+                "2026-01-13",
+                "2026-01-13T08:00:00",
+                "2026-01-13T18:00:00",
+            ),
+            create_shift(
+                "shift_002",
+                "2026-01-14",
+                "2026-01-14T09:00:00",
+                "2026-01-14T17:00:00",
+            ),
+        ],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let clauses: Vec<&str> = result["applied_clauses"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c.as_str().unwrap())
+        .collect();
+
+    // Collected from the pay lines and allowances; clauses only referenced by
+    // audit steps (the rate lookup, overtime detection) are not listed
+    assert_eq!(clauses, vec!["15.2(b)", "22.1", "25.1(a)(i)(A)"]);
+}
+
+#[tokio::test]
+async fn test_diagnostics_list_the_rules_that_were_not_applied() {
+    let mut request = create_request(
+        "emp_diagnostics_001",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-13",
+            "2026-01-13T09:00:00",
+            "2026-01-13T17:00:00",
+        )],
+    );
+    request["include_audit"] = json!(false);
+
+    let (status, result) = post_json(
+        create_router_for_test(),
+        "/calculate?diagnostics=true",
+        request.clone(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let unapplied: Vec<&str> = result["unapplied_rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|rule| rule["rule_id"].as_str().unwrap())
+        .collect();
+    // An 8-hour weekday shift triggers no weekend, overtime or holiday rules
+    assert!(unapplied.contains(&"saturday_penalty"));
+    assert!(unapplied.contains(&"sunday_penalty"));
+    assert!(unapplied.contains(&"overtime_tier_1"));
+    assert!(unapplied.contains(&"public_holiday_not_worked"));
+    assert!(!unapplied.contains(&"ordinary_hours_calculation"));
+    assert!(!unapplied.contains(&"base_rate_lookup"));
+    // The laundry rule records that the employee is not eligible
+    assert!(!unapplied.contains(&"laundry_allowance"));
+    // The audit trace is read for the diagnostic but still left out
+    assert!(
+        result["audit_trace"]["steps"]
+            .as_array()
+            .unwrap()
+            .is_empty()
+    );
+
+    let (status, result) = post_calculate(create_router_for_test(), request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.get("unapplied_rules").is_none());
+}
+
+#[tokio::test]
+async fn test_state_public_holidays_are_detected_from_calendar() {
+    let router = create_router_for_test();
+    let mut request = create_request(
+        "emp_state_001",
+        "full_time",
+        vec![],
+        "2026-01-19",
+        "2026-01-26",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-20",
+            "2026-01-20T09:00:00",
+            "2026-01-20T17:00:00",
+        )],
+    );
+    request["state"] = json!("nsw");
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let holidays = result["pay_period"]["public_holidays"].as_array().unwrap();
+    assert_eq!(holidays.len(), 1);
+    assert_eq!(holidays[0]["date"], "2026-01-26");
+    assert_eq!(holidays[0]["name"], "Australia Day");
+
+    let step = result["audit_trace"]["steps"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s["rule_id"] == "public_holiday_detection")
+        .expect("public holiday detection step");
+    assert_eq!(step["input"]["state"], "NSW");
+    assert_eq!(step["output"]["public_holidays"][0]["source"], "calendar");
+}
+
+#[tokio::test]
+async fn test_base_rate_override_is_audited_and_flagged() {
+    // An override ten times the classification rate is used, but flagged
+    let router = create_router_for_test();
+    let mut request = create_request(
+        "emp_audit_override",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-13",
+            "2026-01-13T09:00:00",
+            "2026-01-13T17:00:00",
+        )],
+    );
+    request["employee"]["base_hourly_rate"] = json!("285.40");
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let steps = result["audit_trace"]["steps"].as_array().unwrap();
+    let lookup = steps
+        .iter()
+        .find(|s| s["rule_id"] == "base_rate_lookup")
+        .unwrap();
+    assert_eq!(lookup["output"]["source"], "employee_override");
+    assert_eq!(lookup["input"]["classification_rate"], "28.54");
+    let warnings = result["audit_trace"]["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0]["code"], "OVERRIDE_RATE_DEVIATION");
+    // 8h × $285.40
+    assert_gross_pay_approx(&result, "2283.20");
+}
+
+// =============================================================================
+// SECTION 11: Zero-Shift Pay Period Tests - 2 tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_zero_shifts_returns_empty_result() {
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_zero_001",
+        "full_time",
+        vec!["laundry_allowance"],
+        "2026-01-12",
+        "2026-01-18",
+        vec![],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(result["pay_lines"].as_array().unwrap().is_empty());
+    assert!(result["allowances"].as_array().unwrap().is_empty());
+    assert_gross_pay_approx(&result, "0");
+    assert_ordinary_hours_approx(&result, "0");
+    assert_overtime_hours_approx(&result, "0");
+    assert_penalty_hours_approx(&result, "0");
+    assert_eq!(
+        normalize_decimal(result["totals"]["allowances_total"].as_str().unwrap()),
+        "0"
+    );
+}
+
+#[tokio::test]
+async fn test_zero_shifts_audit_records_no_shifts_without_base_rate() {
+    let router = create_router_for_test();
+    // Pay period predates every configured rate: the calculation must still
+    // succeed because no base rate lookup is needed.
+    let request = create_request(
+        "emp_zero_002",
+        "casual",
+        vec![],
+        "2020-01-06",
+        "2020-01-12",
+        vec![],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let steps = result["audit_trace"]["steps"].as_array().unwrap();
+    assert_eq!(steps.len(), 2);
+    assert_eq!(steps[0]["rule_id"], "no_shifts");
+    assert_eq!(steps[1]["rule_id"], "gross_pay_reconciliation");
+    assert!(
+        steps[0]["reasoning"]
+            .as_str()
+            .unwrap()
+            .contains("No shifts to process")
+    );
+    assert!(!steps.iter().any(|s| s["rule_id"] == "base_rate_lookup"));
+}
+
+// =============================================================================
+// SECTION 12: Validate Endpoint Tests - 7 tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_validate_valid_request() {
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_val_001",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-13",
+            "2026-01-13T09:00:00",
+            "2026-01-13T17:00:00",
+        )],
+    );
+
+    let (status, result) = post_validate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result, json!({"valid": true}));
+}
+
+#[tokio::test]
+async fn test_validate_reports_every_problem() {
+    let router = create_router_for_test();
+    let mut request = create_request(
+        "emp_val_002",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![
+            // Ends before it starts
+            create_shift(
+                "shift_001",
+                "2026-01-13",
+                "2026-01-13T17:00:00",
+                "2026-01-13T09:00:00",
+            ),
+            // Outside the pay period
+            create_shift(
+                "shift_002",
+                "2026-01-20",
+                "2026-01-20T09:00:00",
+                "2026-01-20T17:00:00",
+            ),
+        ],
+    );
+    request["employee"]["classification_code"] = json!("unknown_level");
+
+    let (status, result) = post_validate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["valid"], false);
+    let problems = result["problems"].as_array().unwrap();
+    assert_eq!(problems.len(), 3);
+    assert_eq!(problems[0]["code"], "CLASSIFICATION_NOT_FOUND");
+    assert_eq!(problems[1]["code"], "INVALID_SHIFT");
+    assert_eq!(problems[2]["code"], "INVALID_SHIFT");
+}
+
+#[tokio::test]
+async fn test_validate_reports_overlapping_shifts() {
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_val_003",
+        "casual",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![
+            create_shift(
+                "shift_001",
+                "2026-01-13",
+                "2026-01-13T09:00:00",
+                "2026-01-13T17:00:00",
+            ),
+            create_shift(
+                "shift_002",
+                "2026-01-13",
+                "2026-01-13T15:00:00",
+                "2026-01-13T19:00:00",
+            ),
+        ],
+    );
+
+    let (status, result) = post_validate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["valid"], false);
+    let problems = result["problems"].as_array().unwrap();
+    assert_eq!(problems.len(), 1);
+    assert!(
+        problems[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("overlaps shift 'shift_001'")
+    );
+}
+
+#[tokio::test]
+async fn test_calculate_rejects_overlapping_shifts() {
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_val_004",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![
+            create_shift(
+                "shift_001",
+                "2026-01-13",
+                "2026-01-13T09:00:00",
+                "2026-01-13T17:00:00",
+            ),
+            create_shift(
+                "shift_002",
+                "2026-01-13",
+                "2026-01-13T15:00:00",
+                "2026-01-13T19:00:00",
+            ),
+        ],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["code"], "INVALID_SHIFT");
+}
+
+#[tokio::test]
+async fn test_calculate_rejects_duplicate_shift_ids() {
+    let router = create_router_for_test();
+    let request = create_request(
+        "emp_val_005",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![
+            create_shift(
+                "shift_001",
+                "2026-01-13",
+                "2026-01-13T09:00:00",
+                "2026-01-13T17:00:00",
+            ),
+            create_shift(
+                "shift_001",
+                "2026-01-14",
+                "2026-01-14T09:00:00",
+                "2026-01-14T17:00:00",
+            ),
+        ],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["code"], "DUPLICATE_SHIFT_ID");
+    assert_eq!(result["message"], "Duplicate shift IDs: 'shift_001'");
+}
+
+#[tokio::test]
+async fn test_calculate_derives_end_time_from_duration_minutes() {
+    let router = create_router_for_test();
+    let mut shift = create_shift(
+        "shift_001",
+        "2026-01-13",
+        "2026-01-13T09:00:00",
+        "2026-01-13T17:00:00",
+    );
+    shift.as_object_mut().unwrap().remove("end_time");
+    shift["duration_minutes"] = json!(450);
+    let request = create_request(
+        "emp_val_005",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![shift],
+    );
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_ordinary_hours_approx(&result, "7.5");
+}
+
+#[tokio::test]
+async fn test_validate_reports_inconsistent_duration_minutes() {
+    let router = create_router_for_test();
+    let mut shift = create_shift(
+        "shift_001",
+        "2026-01-13",
+        "2026-01-13T09:00:00",
+        "2026-01-13T17:00:00",
+    );
+    shift["duration_minutes"] = json!(450);
+    let request = create_request(
+        "emp_val_006",
+        "full_time",
+        vec![],
+        "2026-01-12",
+        "2026-01-18",
+        vec![shift],
+    );
+
+    let (status, result) = post_validate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["valid"], false);
+    let problems = result["problems"].as_array().unwrap();
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0]["code"], "INVALID_SHIFT");
+    assert!(
+        problems[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("does not match start_time + duration_minutes")
+    );
+}
+
+// =============================================================================
+// SECTION 13: Public Holiday Not Worked Tests - 2 tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_public_holiday_on_ordinary_day_not_worked_is_paid() {
+    let router = create_router_for_test();
+    let mut request = create_request(
+        "emp_ph_001",
+        "full_time",
+        vec![],
+        "2026-01-20",
+        "2026-01-26",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-20",
+            "2026-01-20T09:00:00",
+            "2026-01-20T17:00:00",
+        )],
+    );
+    request["employee"]["ordinary_days"] = json!(["mon", "tue", "wed", "thu", "fri"]);
+    request["pay_period"]["public_holidays"] =
+        json!([{ "date": "2026-01-26", "name": "Australia Day" }]);
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let pay_lines = result["pay_lines"].as_array().unwrap();
+    assert_eq!(pay_lines.len(), 2);
+    let holiday_line = &pay_lines[1];
+    assert_eq!(holiday_line["category"], "public_holiday_not_worked");
+    assert_eq!(holiday_line["date"], "2026-01-26");
+    assert_eq!(
+        normalize_decimal(holiday_line["hours"].as_str().unwrap()),
+        "7.6"
+    );
+    assert_eq!(holiday_line["rate"], pay_lines[0]["rate"]);
+    assert_eq!(holiday_line["clause_ref"], "24");
+
+    // 8 worked hours + 7.6 holiday hours at $28.54
+    assert_gross_pay_approx(&result, "445.224");
+}
+
+#[tokio::test]
+async fn test_casual_is_not_paid_for_public_holiday_not_worked() {
+    let router = create_router_for_test();
+    let mut request = create_request(
+        "emp_ph_002",
+        "casual",
+        vec![],
+        "2026-01-20",
+        "2026-01-26",
+        vec![create_shift(
+            "shift_001",
+            "2026-01-20",
+            "2026-01-20T09:00:00",
+            "2026-01-20T17:00:00",
+        )],
+    );
+    request["employee"]["ordinary_days"] = json!(["mon", "tue"]);
+    request["pay_period"]["public_holidays"] =
+        json!([{ "date": "2026-01-26", "name": "Australia Day" }]);
+
+    let (status, result) = post_calculate(router, request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let pay_lines = result["pay_lines"].as_array().unwrap();
+    assert!(
+        pay_lines
+            .iter()
+            .all(|pl| pl["category"] != "public_holiday_not_worked")
+    );
+    let step = result["audit_trace"]["steps"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s["rule_id"] == "public_holiday_not_worked")
+        .expect("public holiday not worked step");
+    assert_eq!(step["output"]["paid"], false);
+}